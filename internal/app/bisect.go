@@ -0,0 +1,92 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// BisectAPI is the part of the Rollbar client first-seen bisects use.
+type BisectAPI interface {
+	ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error)
+	GetItem(ctx context.Context, itemID domain.ItemID) (rollbar.Item, error)
+	ListDeploys(ctx context.Context, page int) (rollbar.DeployPage, error)
+}
+
+// BisectRange is the span of deploys an item's first occurrence points at.
+// Bad is the deploy live in the environment when the item first occurred,
+// nil when it predates every deploy there. Good is the latest earlier deploy
+// of another revision, nil when Bad is the first.
+type BisectRange struct {
+	Issue           IssueSummary    `json:"issue"`
+	Environment     string          `json:"environment"`
+	FirstOccurrence uint64          `json:"first_occurrence_timestamp"`
+	Bad             *rollbar.Deploy `json:"bad,omitempty"`
+	Good            *rollbar.Deploy `json:"good,omitempty"`
+}
+
+// BisectItem finds the deploys around an item's first occurrence in its
+// environment, or in environment when given. Failed and timed out deploys
+// never went live, and redeploys of Bad's revision change nothing, so both
+// are passed over.
+func BisectItem(ctx context.Context, api BisectAPI, counter domain.ItemCounter, environment string) (BisectRange, error) {
+	itemID, err := api.ResolveItemIDByCounter(ctx, counter)
+	if err != nil {
+		return BisectRange{}, fmt.Errorf("resolve item id: %w", err)
+	}
+	item, err := api.GetItem(ctx, itemID)
+	if err != nil {
+		return BisectRange{}, fmt.Errorf("get item: %w", err)
+	}
+	if item.FirstOccurrenceTimestamp == nil {
+		return BisectRange{}, fmt.Errorf("item %d has no first occurrence time", counter)
+	}
+
+	bisect := BisectRange{Issue: mapSummary(item), Environment: strings.TrimSpace(environment), FirstOccurrence: *item.FirstOccurrenceTimestamp}
+	if bisect.Environment == "" {
+		bisect.Environment = strings.TrimSpace(item.Environment)
+	}
+	for page := 1; bisect.Good == nil; page++ {
+		result, err := api.ListDeploys(ctx, page)
+		if err != nil {
+			return BisectRange{}, fmt.Errorf("list deploys: %w", err)
+		}
+		if len(result.Deploys) == 0 {
+			break
+		}
+		for _, deploy := range result.Deploys {
+			if bisect.place(deploy) {
+				break
+			}
+		}
+	}
+
+	return bisect, nil
+}
+
+// place considers the next older deploy and reports whether the range is
+// complete.
+func (b *BisectRange) place(deploy rollbar.Deploy) bool {
+	started := deployTime(deploy)
+	switch {
+	case started == nil || !matchesTextFilter(strings.TrimSpace(deploy.Environment), b.Environment) || !wentLive(deploy):
+		return false
+	case b.Bad == nil:
+		if *started <= b.FirstOccurrence {
+			b.Bad = &deploy
+		}
+		return false
+	case deploy.Revision == b.Bad.Revision:
+		return false
+	default:
+		b.Good = &deploy
+		return true
+	}
+}
+
+func wentLive(deploy rollbar.Deploy) bool {
+	return deploy.Status != rollbar.DeployStatusFailed && deploy.Status != rollbar.DeployStatusTimedOut
+}
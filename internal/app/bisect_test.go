@@ -0,0 +1,91 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type bisectAPI struct {
+	fakeDeployAPI
+	item rollbar.Item
+	err  error
+}
+
+func (b *bisectAPI) ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error) {
+	return 9, b.err
+}
+
+func (b *bisectAPI) GetItem(ctx context.Context, itemID domain.ItemID) (rollbar.Item, error) {
+	return b.item, nil
+}
+
+// bisectPages lists production deploys 1 to 7, newest first, where 6
+// failed and 3 and 4 ship the same revision, and staging deploy 5.
+func bisectPages() [][]rollbar.Deploy {
+	failed := testDeploy(6, "production", "broken", 2500)
+	failed.Status = rollbar.DeployStatusFailed
+	return [][]rollbar.Deploy{
+		{testDeploy(7, "production", "after", 3000), failed, testDeploy(5, "staging", "stage", 2400)},
+		{testDeploy(4, "production", "culprit", 2000), testDeploy(3, "production", "culprit", 1500)},
+		{testDeploy(2, "production", "known-good", 1000), testDeploy(1, "production", "oldest", 500)},
+	}
+}
+
+func TestBisectItem(t *testing.T) {
+	t.Parallel()
+
+	first := uint64(2600)
+
+	tests := []struct {
+		name        string
+		environment string
+		first       uint64
+		bad         uint64
+		good        uint64
+		calls       int
+	}{
+		{name: "range", first: first, bad: 4, good: 2, calls: 3},
+		{name: "other environment", environment: "staging", first: first, bad: 5, calls: 4},
+		{name: "first deploy", first: 600, bad: 1, calls: 4},
+		{name: "before every deploy", first: 100, calls: 4},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			api := &bisectAPI{fakeDeployAPI: fakeDeployAPI{pages: bisectPages()}, item: rollbar.Item{ID: 9, Counter: 42, Environment: "production", FirstOccurrenceTimestamp: &tt.first}}
+			got, err := BisectItem(context.Background(), api, 42, tt.environment)
+			if err != nil {
+				t.Fatalf("BisectItem() error = %v", err)
+			}
+			if deployID(got.Bad) != tt.bad || deployID(got.Good) != tt.good || api.calls != tt.calls || got.FirstOccurrence != tt.first {
+				t.Fatalf("BisectItem() = bad %d good %d after %d pages, want %d, %d, %d", deployID(got.Bad), deployID(got.Good), api.calls, tt.bad, tt.good, tt.calls)
+			}
+		})
+	}
+}
+
+func TestBisectItemErrors(t *testing.T) {
+	t.Parallel()
+
+	api := &bisectAPI{item: rollbar.Item{Counter: 42}}
+	if _, err := BisectItem(context.Background(), api, 42, ""); err == nil || err.Error() != "item 42 has no first occurrence time" {
+		t.Fatalf("expected a missing first occurrence error, got %v", err)
+	}
+	api.err = errors.New("boom")
+	if _, err := BisectItem(context.Background(), api, 42, ""); err == nil || err.Error() != "resolve item id: boom" {
+		t.Fatalf("expected a resolve error, got %v", err)
+	}
+}
+
+func deployID(deploy *rollbar.Deploy) uint64 {
+	if deploy == nil {
+		return 0
+	}
+
+	return deploy.ID
+}
@@ -0,0 +1,104 @@
+package app
+
+import (
+	"context"
+	"sort"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/vcs"
+)
+
+type BlameOptions struct {
+	// Locate maps a frame filename to its path in the checkout, reporting
+	// false for frames outside the project such as libraries.
+	Locate func(filename string) (string, bool)
+	// Blame finds the commit that last changed a line of a checkout path.
+	Blame func(ctx context.Context, path string, line int) (vcs.BlameLine, error)
+	// Frames is how many in-project frames of the latest trace, most recent
+	// call first, are blamed, 3 by default.
+	Frames int
+}
+
+// FrameBlame is one in-project frame and the commit that last changed its
+// line, or why git could not say.
+type FrameBlame struct {
+	CheckoutFrame
+	Blame *vcs.BlameLine `json:"blame,omitempty"`
+	Error string         `json:"error,omitempty"`
+}
+
+// BlameAuthor is someone who last changed some of the blamed lines, with
+// their most recent such commit.
+type BlameAuthor struct {
+	Name       string `json:"name"`
+	Email      string `json:"email,omitempty"`
+	Lines      int    `json:"lines"`
+	LastCommit string `json:"last_commit"`
+	LastTime   int64  `json:"last_time"`
+}
+
+type BlameResult struct {
+	Issue   IssueSummary  `json:"issue"`
+	Frames  []FrameBlame  `json:"frames"`
+	Authors []BlameAuthor `json:"authors"`
+}
+
+// BlameItem blames the lines of the top in-project frames of an item's
+// latest stack trace and ranks the authors by how many of them they last
+// changed, then by how recently. A frame git cannot blame, such as one whose
+// file is untracked or shorter in the checkout, keeps the error instead.
+func BlameItem(ctx context.Context, api ItemFrameAPI, counter domain.ItemCounter, options BlameOptions) (BlameResult, error) {
+	_, issue, frames, err := checkoutFrames(ctx, api, counter, options.Locate, options.Frames)
+	if err != nil {
+		return BlameResult{}, err
+	}
+
+	result := BlameResult{Issue: issue, Frames: make([]FrameBlame, 0, len(frames)), Authors: []BlameAuthor{}}
+	for _, frame := range frames {
+		blamed := FrameBlame{CheckoutFrame: frame}
+		line, err := options.Blame(ctx, frame.Path, frame.Lineno)
+		if err != nil {
+			blamed.Error = err.Error()
+		} else {
+			blamed.Blame = &line
+		}
+		result.Frames = append(result.Frames, blamed)
+	}
+	result.Authors = blameAuthors(result.Frames)
+
+	return result, nil
+}
+
+// blameAuthors groups the committed lines by author email, or name when the
+// commit has no email.
+func blameAuthors(frames []FrameBlame) []BlameAuthor {
+	authors := []BlameAuthor{}
+	seen := map[string]int{}
+	for _, frame := range frames {
+		if frame.Blame == nil || frame.Blame.Uncommitted() {
+			continue
+		}
+		key := frame.Blame.Email
+		if key == "" {
+			key = frame.Blame.Author
+		}
+		index, ok := seen[key]
+		if !ok {
+			index = len(authors)
+			seen[key] = index
+			authors = append(authors, BlameAuthor{Name: frame.Blame.Author, Email: frame.Blame.Email})
+		}
+		authors[index].Lines++
+		if frame.Blame.Time >= authors[index].LastTime {
+			authors[index].LastCommit, authors[index].LastTime = frame.Blame.Commit, frame.Blame.Time
+		}
+	}
+	sort.SliceStable(authors, func(i int, j int) bool {
+		if authors[i].Lines != authors[j].Lines {
+			return authors[i].Lines > authors[j].Lines
+		}
+		return authors[i].LastTime > authors[j].LastTime
+	})
+
+	return authors
+}
@@ -0,0 +1,100 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/vcs"
+)
+
+// newBlameAPI serves item 42 with four frames under /srv/app and one in the
+// standard library.
+func newBlameAPI(t *testing.T) *ownerAPI {
+	t.Helper()
+
+	instance := traceInstance(t,
+		rollbar.Frame{Filename: "/srv/app/web/view.py", Lineno: 3},
+		rollbar.Frame{Filename: "/srv/app/web/form.py", Lineno: 8},
+		rollbar.Frame{Filename: "/srv/app/pay/retry.py", Lineno: 20},
+		rollbar.Frame{Filename: "/usr/lib/python3/http/client.py", Lineno: 900},
+		rollbar.Frame{Filename: "/srv/app/pay/charge.py", Lineno: 12},
+	)
+	return &ownerAPI{fakeAPI: fakeAPI{item: rollbar.Item{ID: 123, Counter: 42, Title: "KeyError"}, instance: instance}}
+}
+
+// testBlames blames the pay and web frames on Ana and Bo, with web/view.py
+// not committed yet.
+func testBlames() map[string]vcs.BlameLine {
+	return map[string]vcs.BlameLine{
+		"pay/charge.py": {Commit: "aaa", Author: "Ana", Email: "ana@example.com", Time: 100},
+		"pay/retry.py":  {Commit: "bbb", Author: "Bo", Email: "bo@example.com", Time: 300},
+		"web/form.py":   {Commit: "ccc", Author: "Ana", Email: "ana@example.com", Time: 200},
+		"web/view.py":   {Commit: "0000000000000000000000000000000000000000", Author: "Not Committed Yet"},
+	}
+}
+
+// testBlameOptions locates frames under /srv/app and blames them from
+// blames, checking that web/form.py is blamed at its frame's line.
+func testBlameOptions(blames map[string]vcs.BlameLine) BlameOptions {
+	return BlameOptions{
+		Locate: func(filename string) (string, bool) {
+			return strings.TrimPrefix(filename, "/srv/app/"), strings.HasPrefix(filename, "/srv/app/")
+		},
+		Blame: func(ctx context.Context, path string, line int) (vcs.BlameLine, error) {
+			if path == "web/form.py" && line != 8 {
+				return vcs.BlameLine{}, errors.New("wrong line")
+			}
+			if blame, ok := blames[path]; ok {
+				return blame, nil
+			}
+			return vcs.BlameLine{}, errors.New("no such path")
+		},
+		Frames: 4,
+	}
+}
+
+func TestBlameItem(t *testing.T) {
+	t.Parallel()
+
+	result, err := BlameItem(context.Background(), newBlameAPI(t), 42, testBlameOptions(testBlames()))
+	if err != nil {
+		t.Fatalf("BlameItem() error = %v", err)
+	}
+	if len(result.Frames) != 4 || result.Frames[0].Path != "pay/charge.py" || result.Frames[0].Blame.Commit != "aaa" {
+		t.Fatalf("unexpected frames %+v", result.Frames)
+	}
+	want := []BlameAuthor{
+		{Name: "Ana", Email: "ana@example.com", Lines: 2, LastCommit: "ccc", LastTime: 200},
+		{Name: "Bo", Email: "bo@example.com", Lines: 1, LastCommit: "bbb", LastTime: 300},
+	}
+	if !slices.Equal(result.Authors, want) {
+		t.Fatalf("unexpected authors %+v", result.Authors)
+	}
+}
+
+func TestBlameItemUnblamedFrame(t *testing.T) {
+	t.Parallel()
+
+	blames := testBlames()
+	blames["pay/charge.py"] = vcs.BlameLine{Commit: "ddd", Author: "Cy", Time: 400}
+	delete(blames, "pay/retry.py")
+	result, err := BlameItem(context.Background(), newBlameAPI(t), 42, testBlameOptions(blames))
+	if err != nil || result.Frames[1].Error != "no such path" || result.Frames[1].Blame != nil {
+		t.Fatalf("expected the unblamed frame to keep its error, got %+v, %v", result.Frames, err)
+	}
+	if len(result.Authors) != 2 || result.Authors[0].Name != "Cy" || result.Authors[0].Email != "" || result.Authors[1].Name != "Ana" {
+		t.Fatalf("unexpected authors %+v", result.Authors)
+	}
+}
+
+func TestBlameItemResolveError(t *testing.T) {
+	t.Parallel()
+
+	if _, err := BlameItem(context.Background(), &ownerAPI{fakeAPI: fakeAPI{err: errors.New("boom")}}, 42, testBlameOptions(testBlames())); err == nil || err.Error() != "resolve item id: boom" {
+		t.Fatalf("expected a resolve error, got %v", err)
+	}
+}
@@ -0,0 +1,71 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"sync"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// DefaultBulkConcurrency is how many updates BulkUpdate keeps in flight when
+// not told otherwise.
+const DefaultBulkConcurrency = 4
+
+type BulkResult struct {
+	Counter domain.ItemCounter `json:"counter"`
+	ItemID  domain.ItemID      `json:"item_id"`
+	Error   string             `json:"error,omitempty"`
+}
+
+// BulkUpdate applies patch to every issue with at most concurrency requests in
+// flight. Failures are recorded per issue rather than stopping the batch.
+// Results keep the order of issues; onDone, if set, is called once per issue
+// as it finishes and may be called concurrently.
+func (s *Service) BulkUpdate(ctx context.Context, issues []IssueSummary, patch rollbar.ItemPatch, concurrency int, onDone func(BulkResult)) []BulkResult {
+	if concurrency <= 0 {
+		concurrency = DefaultBulkConcurrency
+	}
+
+	results := make([]BulkResult, len(issues))
+	slots := make(chan struct{}, concurrency)
+	var wg sync.WaitGroup
+	for index, issue := range issues {
+		wg.Add(1)
+		slots <- struct{}{}
+		go func() {
+			defer wg.Done()
+			defer func() { <-slots }()
+
+			results[index] = s.bulkUpdateOne(ctx, issue, patch)
+			if onDone != nil {
+				onDone(results[index])
+			}
+		}()
+	}
+	wg.Wait()
+
+	return results
+}
+
+func (s *Service) bulkUpdateOne(ctx context.Context, issue IssueSummary, patch rollbar.ItemPatch) BulkResult {
+	result := BulkResult{Counter: issue.Counter, ItemID: issue.ItemID}
+	if err := s.api.UpdateItem(ctx, issue.ItemID, patch); err != nil {
+		result.Error = fmt.Sprintf("update item: %v", err)
+	}
+
+	return result
+}
+
+// BulkFailures counts the results that carry an error.
+func BulkFailures(results []BulkResult) int {
+	failures := 0
+	for _, result := range results {
+		if result.Error != "" {
+			failures++
+		}
+	}
+
+	return failures
+}
@@ -0,0 +1,71 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"sync"
+	"sync/atomic"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type bulkAPI struct {
+	fakeAPI
+	mu       sync.Mutex
+	patched  []domain.ItemID
+	failID   domain.ItemID
+	inFlight atomic.Int32
+	peak     atomic.Int32
+}
+
+func (b *bulkAPI) UpdateItem(ctx context.Context, itemID domain.ItemID, patch rollbar.ItemPatch) error {
+	current := b.inFlight.Add(1)
+	defer b.inFlight.Add(-1)
+	for {
+		peak := b.peak.Load()
+		if current <= peak || b.peak.CompareAndSwap(peak, current) {
+			break
+		}
+	}
+
+	b.mu.Lock()
+	b.patched = append(b.patched, itemID)
+	b.mu.Unlock()
+	if itemID == b.failID {
+		return errors.New("boom")
+	}
+
+	return nil
+}
+
+func TestServiceBulkUpdate(t *testing.T) {
+	t.Parallel()
+
+	api := &bulkAPI{failID: 3}
+	issues := make([]IssueSummary, 0, 6)
+	for id := range 6 {
+		issues = append(issues, IssueSummary{ItemID: domain.ItemID(id + 1), Counter: domain.ItemCounter(id + 10)})
+	}
+
+	var done atomic.Int32
+	results := NewService(api).BulkUpdate(context.Background(), issues, rollbar.ItemPatch{Status: rollbar.StatusResolved}, 2, func(BulkResult) {
+		done.Add(1)
+	})
+
+	if len(results) != 6 || done.Load() != 6 || len(api.patched) != 6 {
+		t.Fatalf("unexpected bulk run: results=%d done=%d patched=%d", len(results), done.Load(), len(api.patched))
+	}
+	if api.peak.Load() > 2 {
+		t.Fatalf("expected at most 2 concurrent updates, saw %d", api.peak.Load())
+	}
+	for index, result := range results {
+		if result.Counter != issues[index].Counter {
+			t.Fatalf("results out of order at %d: %+v", index, result)
+		}
+	}
+	if BulkFailures(results) != 1 || results[2].Error == "" {
+		t.Fatalf("expected one failure for item 3, got %+v", results)
+	}
+}
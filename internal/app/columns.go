@@ -0,0 +1,192 @@
+package app
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+// Column is a field of an occurrence shown as a listing column, such as
+// data.custom.tenant_id or data.request.headers[0]. Path is dotted from the
+// occurrence, whose payload sits under data; [N] picks a list element.
+type Column struct {
+	Path  string
+	steps []columnStep
+}
+
+type columnStep struct {
+	key     string
+	index   int
+	isIndex bool
+}
+
+// ParseColumns parses --column paths, keeping their order.
+func ParseColumns(values []string) ([]Column, error) {
+	columns := make([]Column, 0, len(values))
+	seen := map[string]bool{}
+	for _, value := range values {
+		column, err := parseColumn(value)
+		if err != nil {
+			return nil, err
+		}
+		if !seen[column.Path] {
+			seen[column.Path] = true
+			columns = append(columns, column)
+		}
+	}
+
+	return columns, nil
+}
+
+// ColumnPaths is the path of each column, for table headers.
+func ColumnPaths(columns []Column) []string {
+	paths := make([]string, 0, len(columns))
+	for _, column := range columns {
+		paths = append(paths, column.Path)
+	}
+
+	return paths
+}
+
+// parseColumn parses one dotted path; a leading "$." is accepted and dropped.
+func parseColumn(value string) (Column, error) {
+	path := strings.TrimPrefix(strings.TrimSpace(value), "$.")
+	if path == "" {
+		return Column{}, fmt.Errorf("invalid column %q: path is empty", value)
+	}
+
+	column := Column{Path: path}
+	for _, part := range strings.Split(path, ".") {
+		steps, err := parseColumnPart(part)
+		if err != nil {
+			return Column{}, fmt.Errorf("invalid column %q: %w", value, err)
+		}
+		column.steps = append(column.steps, steps...)
+	}
+
+	return column, nil
+}
+
+// parseColumnPart reads key, key[N], or key[N][M].
+func parseColumnPart(part string) ([]columnStep, error) {
+	key, rest, found := strings.Cut(part, "[")
+	if key == "" {
+		return nil, fmt.Errorf("empty key in %q", part)
+	}
+	if found {
+		rest = "[" + rest
+	}
+
+	steps := []columnStep{{key: key}}
+	for rest != "" {
+		digits, after, closed := strings.Cut(strings.TrimPrefix(rest, "["), "]")
+		index, err := strconv.Atoi(digits)
+		if !strings.HasPrefix(rest, "[") || !closed || err != nil || index < 0 {
+			return nil, fmt.Errorf("bad index in %q (use key[N])", part)
+		}
+		steps = append(steps, columnStep{index: index, isIndex: true})
+		rest = after
+	}
+
+	return steps, nil
+}
+
+// extract returns the value at the column's path in decoded, or "" when the
+// path is missing. Strings print as-is; objects and lists as compact JSON.
+func (c Column) extract(decoded any) string {
+	current := decoded
+	for _, step := range c.steps {
+		var ok bool
+		if current, ok = columnStepValue(current, step); !ok {
+			return ""
+		}
+	}
+
+	return formatColumnValue(current)
+}
+
+func columnStepValue(value any, step columnStep) (any, bool) {
+	if step.isIndex {
+		list, ok := value.([]any)
+		if !ok || step.index >= len(list) {
+			return nil, false
+		}
+		return list[step.index], true
+	}
+
+	object, ok := value.(map[string]any)
+	if !ok {
+		return nil, false
+	}
+	field, ok := object[step.key]
+
+	return field, ok
+}
+
+func formatColumnValue(value any) string {
+	switch typed := value.(type) {
+	case nil:
+		return ""
+	case string:
+		return typed
+	case json.Number:
+		return typed.String()
+	case bool:
+		return strconv.FormatBool(typed)
+	}
+
+	encoded, err := json.Marshal(value)
+	if err != nil {
+		return ""
+	}
+
+	return string(encoded)
+}
+
+// extractColumns pulls every column out of an occurrence payload, scrubbed of
+// common secrets first so a column on a cookie or password shows the mask.
+// It is nil without columns.
+func extractColumns(columns []Column, raw json.RawMessage) map[string]string {
+	if len(columns) == 0 {
+		return nil
+	}
+
+	var decoded any
+	decoder := json.NewDecoder(bytes.NewReader(redact.DefaultScrubber().ScrubJSON(raw)))
+	decoder.UseNumber()
+	_ = decoder.Decode(&decoded)
+
+	values := make(map[string]string, len(columns))
+	for _, column := range columns {
+		values[column.Path] = column.extract(decoded)
+	}
+
+	return values
+}
+
+// itemColumns fills each issue's columns from its latest occurrence, one
+// request per issue.
+func (s *Service) itemColumns(ctx context.Context, issues []IssueSummary, columns []Column) error {
+	if len(columns) == 0 {
+		return nil
+	}
+
+	for index := range issues {
+		instance, err := s.api.GetLatestInstance(ctx, issues[index].ItemID)
+		if err != nil {
+			return fmt.Errorf("get latest instance of item %d: %w", issues[index].Counter, err)
+		}
+		var raw json.RawMessage
+		if instance != nil {
+			raw = instance.Raw
+		}
+		issues[index].Columns = extractColumns(columns, raw)
+	}
+
+	return nil
+}
@@ -0,0 +1,122 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const columnPayload = `{"id":9,"data":{"custom":{"tenant_id":"acme","shard":7,"beta":true,"tags":["a","b"]},"request":{"url":"https://example.com/pay","headers":{"Cookie":"session=secret"}},"trace":{"frames":[{"filename":"app.go"},{"filename":"main.go"}]}}}`
+
+func TestParseColumns(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name    string
+		values  []string
+		want    []string
+		wantErr bool
+	}{
+		{name: "dotted", values: []string{"data.custom.tenant_id", "data.request.url"}, want: []string{"data.custom.tenant_id", "data.request.url"}},
+		{name: "jsonpath prefix", values: []string{"$.data.custom.tenant_id"}, want: []string{"data.custom.tenant_id"}},
+		{name: "index", values: []string{"data.trace.frames[1].filename"}, want: []string{"data.trace.frames[1].filename"}},
+		{name: "dedupes", values: []string{"data.level", " data.level "}, want: []string{"data.level"}},
+		{name: "empty", values: []string{" "}, wantErr: true},
+		{name: "empty key", values: []string{"data..url"}, wantErr: true},
+		{name: "bad index", values: []string{"data.frames[x]"}, wantErr: true},
+		{name: "unclosed index", values: []string{"data.frames[1"}, wantErr: true},
+		{name: "trailing bracket", values: []string{"data.frames["}, wantErr: true},
+	}
+
+	for _, tc := range tests {
+		columns, err := ParseColumns(tc.values)
+		if (err != nil) != tc.wantErr {
+			t.Fatalf("%s: ParseColumns() error = %v, wantErr %v", tc.name, err, tc.wantErr)
+		}
+		if !tc.wantErr && !slices.Equal(ColumnPaths(columns), tc.want) {
+			t.Fatalf("%s: paths = %v, want %v", tc.name, ColumnPaths(columns), tc.want)
+		}
+	}
+}
+
+func TestExtractColumns(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		path string
+		want string
+	}{
+		{path: "data.custom.tenant_id", want: "acme"},
+		{path: "data.custom.shard", want: "7"},
+		{path: "data.custom.beta", want: "true"},
+		{path: "data.custom.tags", want: `["a","b"]`},
+		{path: "data.custom.tags[1]", want: "b"},
+		{path: "data.trace.frames[1].filename", want: "main.go"},
+		{path: "data.trace.frames[5].filename", want: ""},
+		{path: "data.custom.missing", want: ""},
+		{path: "data.custom.tenant_id.deeper", want: ""},
+		{path: "data.request.headers.Cookie", want: "*****"},
+	}
+
+	for _, tc := range tests {
+		columns, err := ParseColumns([]string{tc.path})
+		if err != nil {
+			t.Fatalf("%s: ParseColumns() error = %v", tc.path, err)
+		}
+		if got := extractColumns(columns, json.RawMessage(columnPayload))[tc.path]; got != tc.want {
+			t.Fatalf("%s: value = %q, want %q", tc.path, got, tc.want)
+		}
+	}
+
+	if got := extractColumns(nil, json.RawMessage(columnPayload)); got != nil {
+		t.Fatalf("extractColumns() without columns = %v, want nil", got)
+	}
+}
+
+func TestServiceListColumns(t *testing.T) {
+	t.Parallel()
+
+	columns, err := ParseColumns([]string{"data.custom.tenant_id"})
+	if err != nil {
+		t.Fatalf("ParseColumns() error = %v", err)
+	}
+	api := &pagedAPI{
+		fakeAPI: fakeAPI{instance: &rollbar.ItemInstance{ID: 9, Raw: json.RawMessage(columnPayload)}},
+		pages:   [][]rollbar.Item{{issueFixture(fixture{counter: 1, level: rollbar.LevelError, lastSeen: 1, occurrences: 1})}},
+	}
+	issues, err := NewService(api).List(context.Background(), ListOptions{Columns: columns})
+	if err != nil || len(issues) != 1 {
+		t.Fatalf("List() = %v, %v", issues, err)
+	}
+	if issues[0].Columns["data.custom.tenant_id"] != "acme" {
+		t.Fatalf("columns = %v", issues[0].Columns)
+	}
+
+	failing := &pagedAPI{fakeAPI: fakeAPI{err: errors.New("boom")}, pages: api.pages}
+	if err := NewService(failing).itemColumns(context.Background(), []IssueSummary{{}}, columns); err == nil {
+		t.Fatal("expected latest instance error")
+	}
+}
+
+func TestServiceOccurrencesColumns(t *testing.T) {
+	t.Parallel()
+
+	columns, err := ParseColumns([]string{"data.request.url"})
+	if err != nil {
+		t.Fatalf("ParseColumns() error = %v", err)
+	}
+	instance := testInstance(9, 900)
+	instance.Raw = json.RawMessage(columnPayload)
+	api := &instancesAPI{pages: [][]rollbar.ItemInstance{{instance}}}
+	occurrences, err := NewService(api).Occurrences(context.Background(), 7, OccurrenceOptions{Columns: columns})
+	if err != nil || len(occurrences) != 1 {
+		t.Fatalf("Occurrences() = %v, %v", occurrences, err)
+	}
+	if occurrences[0].Columns["data.request.url"] != "https://example.com/pay" {
+		t.Fatalf("columns = %v", occurrences[0].Columns)
+	}
+}
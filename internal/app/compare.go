@@ -0,0 +1,198 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"math"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const defaultCompareRatio = 2
+
+// CompareAPI is the part of the Rollbar client environment comparisons use.
+type CompareAPI interface {
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+	OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error)
+}
+
+type CompareOptions struct {
+	A string
+	B string
+	// Status limits both sides to items in that status, active by default.
+	Status string
+	Since  time.Time
+	// Ratio is how many times more often an item must occur in one
+	// environment than the other to count as divergent, 2 by default.
+	Ratio float64
+	Limit int
+}
+
+// RateDivergence is one error recorded in both environments whose
+// occurrences since the window started differ by at least the ratio. Ratio
+// is the larger count over the smaller, absent when one side has none.
+type RateDivergence struct {
+	A            IssueSummary `json:"a"`
+	B            IssueSummary `json:"b"`
+	OccurrencesA uint64       `json:"occurrences_a"`
+	OccurrencesB uint64       `json:"occurrences_b"`
+	Ratio        *float64     `json:"ratio,omitempty"`
+}
+
+// EnvComparison lists the errors only one of two environments has, and the
+// ones both have (Shared) that occur at divergent rates.
+type EnvComparison struct {
+	A         string           `json:"a"`
+	B         string           `json:"b"`
+	Since     int64            `json:"since"`
+	Until     int64            `json:"until"`
+	Shared    int              `json:"shared"`
+	OnlyA     []IssueSummary   `json:"only_a"`
+	OnlyB     []IssueSummary   `json:"only_b"`
+	Divergent []RateDivergence `json:"divergent"`
+}
+
+// CompareEnvironments pairs the items of two environments by normalized
+// title, since Rollbar keeps a separate item per environment, and reports the
+// unpaired ones, most frequent first, and the pairs whose occurrence counts
+// since options.Since diverge, most divergent first. Each side keeps its most
+// frequent item per title.
+func CompareEnvironments(ctx context.Context, api CompareAPI, options CompareOptions, now time.Time) (EnvComparison, error) {
+	a, b, err := compareEnvironments(options, now)
+	if err != nil {
+		return EnvComparison{}, err
+	}
+
+	itemsA, err := itemsByTitle(ctx, api, a, options.Status)
+	if err != nil {
+		return EnvComparison{}, err
+	}
+	itemsB, err := itemsByTitle(ctx, api, b, options.Status)
+	if err != nil {
+		return EnvComparison{}, err
+	}
+
+	comparison := EnvComparison{A: a, B: b, Since: options.Since.Unix(), Until: now.Unix(), OnlyA: unpaired(itemsA, itemsB), OnlyB: unpaired(itemsB, itemsA), Divergent: []RateDivergence{}}
+	for key, itemA := range itemsA {
+		itemB, ok := itemsB[key]
+		if !ok {
+			continue
+		}
+		comparison.Shared++
+		divergence, err := compareRates(ctx, api, itemA, itemB, options, now)
+		if err != nil {
+			return EnvComparison{}, err
+		}
+		if divergence != nil {
+			comparison.Divergent = append(comparison.Divergent, *divergence)
+		}
+	}
+
+	comparison.limit(options.Limit)
+
+	return comparison, nil
+}
+
+func compareEnvironments(options CompareOptions, now time.Time) (string, string, error) {
+	a, b := strings.TrimSpace(options.A), strings.TrimSpace(options.B)
+	switch {
+	case a == "" || b == "":
+		return "", "", errors.New("two environments are required")
+	case strings.EqualFold(a, b):
+		return "", "", errors.New("compare two different environments")
+	case !options.Since.Before(now):
+		return "", "", errors.New("comparison window must start in the past")
+	default:
+		return a, b, nil
+	}
+}
+
+func itemsByTitle(ctx context.Context, api CompareAPI, environment string, status string) (map[string]rollbar.Item, error) {
+	query := activeItemsIn(environment)
+	if status = strings.TrimSpace(status); status != "" {
+		query.Status = status
+	}
+	items, err := scanItems(ctx, api, query, func(item rollbar.Item) bool { return true })
+	if err != nil {
+		return nil, err
+	}
+
+	byTitle := map[string]rollbar.Item{}
+	for _, item := range items {
+		key := strings.Join(titleWords(item.Title), " ")
+		if kept, ok := byTitle[key]; !ok || totalOccurrences(item) > totalOccurrences(kept) {
+			byTitle[key] = item
+		}
+	}
+
+	return byTitle, nil
+}
+
+func unpaired(items map[string]rollbar.Item, other map[string]rollbar.Item) []IssueSummary {
+	only := make([]rollbar.Item, 0)
+	for key, item := range items {
+		if _, ok := other[key]; !ok {
+			only = append(only, item)
+		}
+	}
+	sort.Slice(only, func(i int, j int) bool {
+		if totalOccurrences(only[i]) != totalOccurrences(only[j]) {
+			return totalOccurrences(only[i]) > totalOccurrences(only[j])
+		}
+		return only[i].Counter < only[j].Counter
+	})
+
+	return mapSummaries(only)
+}
+
+func compareRates(ctx context.Context, api CompareAPI, itemA rollbar.Item, itemB rollbar.Item, options CompareOptions, now time.Time) (*RateDivergence, error) {
+	countA, err := occurrencesSince(ctx, api, rollbar.CountReportParams{Environment: itemA.Environment, ItemID: itemA.ID}, options.Since, now)
+	if err != nil {
+		return nil, err
+	}
+	countB, err := occurrencesSince(ctx, api, rollbar.CountReportParams{Environment: itemB.Environment, ItemID: itemB.ID}, options.Since, now)
+	if err != nil {
+		return nil, err
+	}
+	if countA == countB {
+		return nil, nil
+	}
+
+	divergence := &RateDivergence{A: mapSummary(itemA), B: mapSummary(itemB), OccurrencesA: countA, OccurrencesB: countB}
+	if low, high := min(countA, countB), max(countA, countB); low > 0 {
+		ratio := math.Round(float64(high)/float64(low)*100) / 100
+		threshold := options.Ratio
+		if threshold <= 0 {
+			threshold = defaultCompareRatio
+		}
+		if ratio < threshold {
+			return nil, nil
+		}
+		divergence.Ratio = &ratio
+	}
+
+	return divergence, nil
+}
+
+// limit sorts the divergent pairs, one-sided ones first, and caps each list.
+func (c *EnvComparison) limit(limit int) {
+	sort.Slice(c.Divergent, func(i int, j int) bool {
+		left, right := c.Divergent[i], c.Divergent[j]
+		if (left.Ratio == nil) != (right.Ratio == nil) {
+			return left.Ratio == nil
+		}
+		if left.Ratio != nil && *left.Ratio != *right.Ratio {
+			return *left.Ratio > *right.Ratio
+		}
+		return max(left.OccurrencesA, left.OccurrencesB) > max(right.OccurrencesA, right.OccurrencesB)
+	})
+	if limit <= 0 {
+		return
+	}
+	c.OnlyA = c.OnlyA[:min(len(c.OnlyA), limit)]
+	c.OnlyB = c.OnlyB[:min(len(c.OnlyB), limit)]
+	c.Divergent = c.Divergent[:min(len(c.Divergent), limit)]
+}
@@ -0,0 +1,128 @@
+package app
+
+import (
+	"context"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// compareAPI serves one page of items per environment and occurrence counts
+// per item.
+type compareAPI struct {
+	items   map[string][]rollbar.Item
+	counts  map[domain.ItemID]uint64
+	queries []rollbar.ItemQuery
+}
+
+func (a *compareAPI) ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error) {
+	a.queries = append(a.queries, query)
+	if query.Page > 1 || len(query.Environments) != 1 {
+		return rollbar.ItemPage{Page: query.Page}, nil
+	}
+	items := a.items[query.Environments[0]]
+
+	return rollbar.ItemPage{Items: items, Page: 1, TotalCount: uint64(len(items))}, nil
+}
+
+func (a *compareAPI) OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error) {
+	return []rollbar.CountBucket{{Timestamp: 1767225600, Count: a.counts[params.ItemID]}}, nil
+}
+
+// newCompareAPI serves production and staging items that match by
+// normalized title, with items 3 and 16 in only one environment.
+func newCompareAPI() *compareAPI {
+	return &compareAPI{
+		items: map[string][]rollbar.Item{
+			"production": {
+				issueFixture(fixture{counter: 1, environment: "production", title: "TimeoutError: charge took 30s", status: rollbar.StatusActive, occurrences: 90}),
+				issueFixture(fixture{counter: 2, environment: "production", title: "KeyError: 'cart'", status: rollbar.StatusActive, occurrences: 40}),
+				issueFixture(fixture{counter: 3, environment: "production", title: "fixed in staging only", status: rollbar.StatusActive, occurrences: 12}),
+				issueFixture(fixture{counter: 4, environment: "production", title: "steady error", status: rollbar.StatusActive, occurrences: 5}),
+				issueFixture(fixture{counter: 5, environment: "production", title: "quiet in staging", status: rollbar.StatusActive, occurrences: 3}),
+			},
+			"staging": {
+				issueFixture(fixture{counter: 11, environment: "staging", title: "TimeoutError: charge took 45s", status: rollbar.StatusActive, occurrences: 2}),
+				issueFixture(fixture{counter: 12, environment: "staging", title: "KeyError: 'user'", status: rollbar.StatusActive, occurrences: 9}),
+				issueFixture(fixture{counter: 14, environment: "staging", title: "steady error", status: rollbar.StatusActive, occurrences: 5}),
+				issueFixture(fixture{counter: 15, environment: "staging", title: "quiet in staging", status: rollbar.StatusActive, occurrences: 1}),
+				issueFixture(fixture{counter: 16, environment: "staging", title: "new in staging", status: rollbar.StatusActive, occurrences: 4}),
+			},
+		},
+		counts: map[domain.ItemID]uint64{1: 30, 11: 5, 2: 8, 12: 6, 4: 10, 14: 10, 5: 7},
+	}
+}
+
+// checkDivergent fails unless item 5 diverges without a ratio, having no
+// staging occurrences in the window, and item 1 diverges sixfold.
+func checkDivergent(t *testing.T, divergent []RateDivergence) {
+	t.Helper()
+
+	want := []struct {
+		counterA domain.ItemCounter
+		ratio    float64
+	}{
+		{counterA: 5},
+		{counterA: 1, ratio: 6},
+	}
+	if len(divergent) != len(want) {
+		t.Fatalf("divergent = %+v", divergent)
+	}
+	for index, tc := range want {
+		divergence := divergent[index]
+		if divergence.A.Counter != tc.counterA || (divergence.Ratio == nil) != (tc.ratio == 0) || (divergence.Ratio != nil && *divergence.Ratio != tc.ratio) {
+			t.Fatalf("divergent %d = %+v", index, divergence)
+		}
+	}
+}
+
+func TestCompareEnvironments(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 1, 2, 0, 0, 0, 0, time.UTC)
+	since := now.Add(-24 * time.Hour)
+	api := newCompareAPI()
+	comparison, err := CompareEnvironments(context.Background(), api, CompareOptions{A: " production ", B: "staging", Since: since}, now)
+	if err != nil {
+		t.Fatalf("CompareEnvironments() error = %v", err)
+	}
+	if comparison.A != "production" || comparison.Shared != 4 || len(comparison.OnlyA) != 1 || comparison.OnlyA[0].Counter != 3 || len(comparison.OnlyB) != 1 || comparison.OnlyB[0].Counter != 16 {
+		t.Fatalf("unexpected comparison: %+v", comparison)
+	}
+
+	checkDivergent(t, comparison.Divergent)
+	if query := api.queries[0]; query.Status != "active" {
+		t.Fatalf("unexpected default status: %+v", query)
+	}
+}
+
+func TestCompareEnvironmentsLimits(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 1, 2, 0, 0, 0, 0, time.UTC)
+	since := now.Add(-24 * time.Hour)
+	api := newCompareAPI()
+	limited, err := CompareEnvironments(context.Background(), api, CompareOptions{A: "production", B: "staging", Since: since, Ratio: 1.2, Status: "resolved", Limit: 1}, now)
+	if err != nil || len(limited.Divergent) != 1 || api.queries[len(api.queries)-1].Status != "resolved" {
+		t.Fatalf("unexpected limited comparison %+v, %v", limited, err)
+	}
+}
+
+func TestCompareEnvironmentsErrors(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 1, 2, 0, 0, 0, 0, time.UTC)
+	since := now.Add(-24 * time.Hour)
+	api := newCompareAPI()
+	for _, options := range []CompareOptions{
+		{A: "production", Since: since},
+		{A: "production", B: "Production", Since: since},
+		{A: "production", B: "staging", Since: now},
+	} {
+		if _, err := CompareEnvironments(context.Background(), api, options, now); err == nil {
+			t.Fatalf("expected an error for %+v", options)
+		}
+	}
+}
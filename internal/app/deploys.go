@@ -0,0 +1,96 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// DeployAPI is the part of the Rollbar API used to register and list deploys.
+type DeployAPI interface {
+	ReportDeploy(ctx context.Context, report rollbar.DeployReport) (uint64, error)
+	UpdateDeploy(ctx context.Context, deployID uint64, update rollbar.DeployUpdate) error
+	ListDeploys(ctx context.Context, page int) (rollbar.DeployPage, error)
+}
+
+var deployStatuses = []string{rollbar.DeployStatusStarted, rollbar.DeployStatusSucceeded, rollbar.DeployStatusFailed, rollbar.DeployStatusTimedOut}
+
+// ParseDeployStatus validates a deploy status, defaulting to succeeded.
+func ParseDeployStatus(value string) (string, error) {
+	status := strings.ToLower(strings.TrimSpace(value))
+	if status == "" {
+		return rollbar.DeployStatusSucceeded, nil
+	}
+	for _, known := range deployStatuses {
+		if status == known {
+			return status, nil
+		}
+	}
+
+	return "", fmt.Errorf("unsupported deploy status %q (use %s)", value, strings.Join(deployStatuses, ", "))
+}
+
+// ReportDeploy registers a deploy and returns it as recorded. The deploy is
+// not fetched back, since CI usually reports with a post-only token.
+func ReportDeploy(ctx context.Context, api DeployAPI, report rollbar.DeployReport) (rollbar.Deploy, error) {
+	status, err := ParseDeployStatus(report.Status)
+	if err != nil {
+		return rollbar.Deploy{}, err
+	}
+	report.Status = status
+
+	deployID, err := api.ReportDeploy(ctx, report)
+	if err != nil {
+		return rollbar.Deploy{}, fmt.Errorf("report deploy: %w", err)
+	}
+
+	return rollbar.Deploy{
+		ID:            deployID,
+		Environment:   strings.TrimSpace(report.Environment),
+		Revision:      strings.TrimSpace(report.Revision),
+		LocalUsername: report.LocalUsername,
+		Comment:       report.Comment,
+		Status:        report.Status,
+	}, nil
+}
+
+// UpdateDeployStatus moves a deploy reported as started to its final status.
+func UpdateDeployStatus(ctx context.Context, api DeployAPI, deployID uint64, value string) error {
+	status, err := ParseDeployStatus(value)
+	if err != nil {
+		return err
+	}
+	if err := api.UpdateDeploy(ctx, deployID, rollbar.DeployUpdate{Status: status}); err != nil {
+		return fmt.Errorf("update deploy %d: %w", deployID, err)
+	}
+
+	return nil
+}
+
+// ListDeploys pages through deploys, newest first as Rollbar returns them,
+// keeping those in environment (any when empty) until limit are found.
+func ListDeploys(ctx context.Context, api DeployAPI, environment string, limit int) ([]rollbar.Deploy, error) {
+	environment = strings.TrimSpace(environment)
+	deploys := make([]rollbar.Deploy, 0)
+	for page := 1; limit <= 0 || len(deploys) < limit; page++ {
+		result, err := api.ListDeploys(ctx, page)
+		if err != nil {
+			return nil, fmt.Errorf("list deploys: %w", err)
+		}
+		if len(result.Deploys) == 0 {
+			break
+		}
+		for _, deploy := range result.Deploys {
+			if matchesTextFilter(strings.TrimSpace(deploy.Environment), environment) {
+				deploys = append(deploys, deploy)
+			}
+		}
+	}
+	if limit > 0 && len(deploys) > limit {
+		deploys = deploys[:limit]
+	}
+
+	return deploys, nil
+}
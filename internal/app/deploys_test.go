@@ -0,0 +1,113 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeDeployAPI struct {
+	pages    [][]rollbar.Deploy
+	reported rollbar.DeployReport
+	updated  rollbar.DeployUpdate
+	calls    int
+}
+
+func (f *fakeDeployAPI) ReportDeploy(ctx context.Context, report rollbar.DeployReport) (uint64, error) {
+	f.reported = report
+	return 77, nil
+}
+
+func (f *fakeDeployAPI) UpdateDeploy(ctx context.Context, deployID uint64, update rollbar.DeployUpdate) error {
+	if deployID != 77 {
+		return errors.New("deploy not found")
+	}
+	f.updated = update
+	return nil
+}
+
+func (f *fakeDeployAPI) ListDeploys(ctx context.Context, page int) (rollbar.DeployPage, error) {
+	f.calls++
+	if page > len(f.pages) {
+		return rollbar.DeployPage{Page: page}, nil
+	}
+	return rollbar.DeployPage{Deploys: f.pages[page-1], Page: page}, nil
+}
+
+func TestReportDeploy(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		status     string
+		wantStatus string
+		wantErr    string
+	}{
+		{status: "", wantStatus: rollbar.DeployStatusSucceeded},
+		{status: " Started ", wantStatus: rollbar.DeployStatusStarted},
+		{status: "done", wantErr: "unsupported deploy status"},
+	}
+	for _, tc := range tests {
+		api := &fakeDeployAPI{}
+		deploy, err := ReportDeploy(context.Background(), api, rollbar.DeployReport{Environment: " production ", Revision: "abc123", Status: tc.status})
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("status %q: expected %q error, got %v", tc.status, tc.wantErr, err)
+			}
+			continue
+		}
+		if err != nil {
+			t.Fatalf("status %q: ReportDeploy() error = %v", tc.status, err)
+		}
+		if deploy.ID != 77 || deploy.Environment != "production" || deploy.Status != tc.wantStatus || api.reported.Status != tc.wantStatus {
+			t.Fatalf("status %q: unexpected deploy %+v (reported %+v)", tc.status, deploy, api.reported)
+		}
+	}
+}
+
+func TestUpdateDeployStatus(t *testing.T) {
+	t.Parallel()
+
+	api := &fakeDeployAPI{}
+	if err := UpdateDeployStatus(context.Background(), api, 77, "failed"); err != nil || api.updated.Status != "failed" {
+		t.Fatalf("UpdateDeployStatus() = %v, update %+v", err, api.updated)
+	}
+	if err := UpdateDeployStatus(context.Background(), api, 78, "failed"); err == nil || !strings.Contains(err.Error(), "update deploy 78") {
+		t.Fatalf("expected wrapped error, got %v", err)
+	}
+}
+
+func TestListDeploys(t *testing.T) {
+	t.Parallel()
+
+	api := &fakeDeployAPI{pages: [][]rollbar.Deploy{
+		{{ID: 3, Environment: "production"}, {ID: 2, Environment: "staging"}},
+		{{ID: 1, Environment: "Production"}},
+	}}
+
+	tests := []struct {
+		environment string
+		limit       int
+		wantIDs     []uint64
+	}{
+		{environment: "production", limit: 10, wantIDs: []uint64{3, 1}},
+		{limit: 2, wantIDs: []uint64{3, 2}},
+		{limit: 0, wantIDs: []uint64{3, 2, 1}},
+	}
+	for _, tc := range tests {
+		deploys, err := ListDeploys(context.Background(), api, tc.environment, tc.limit)
+		if err != nil {
+			t.Fatalf("ListDeploys() error = %v", err)
+		}
+		if len(deploys) != len(tc.wantIDs) {
+			t.Fatalf("%q/%d: got %+v", tc.environment, tc.limit, deploys)
+		}
+		for index, want := range tc.wantIDs {
+			if deploys[index].ID != want {
+				t.Fatalf("%q/%d: got %+v", tc.environment, tc.limit, deploys)
+			}
+		}
+	}
+}
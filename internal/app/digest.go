@@ -0,0 +1,161 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"math"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// DigestAPI is the part of the Rollbar client triage digests use.
+type DigestAPI interface {
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+	OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error)
+}
+
+type DigestOptions struct {
+	Environment string
+	Since       time.Time
+	// Limit caps the items listed per section; totals count every item.
+	Limit int
+}
+
+// DigestSection lists the most frequent of Total items.
+type DigestSection struct {
+	Total int            `json:"total"`
+	Items []IssueSummary `json:"items"`
+}
+
+// EnvironmentDigest counts one environment's items per section and its
+// occurrences in the window.
+type EnvironmentDigest struct {
+	Environment string `json:"environment"`
+	New         int    `json:"new"`
+	Active      int    `json:"active"`
+	Resolved    int    `json:"resolved"`
+	Regressions int    `json:"regressions"`
+	Occurrences uint64 `json:"occurrences"`
+}
+
+// Digest summarizes a project between Since and Until. New holds items first
+// seen in the window, Top the active items that occurred in it, Resolved the
+// items resolved in it and still resolved, and Regressions the older items
+// reactivated in it.
+type Digest struct {
+	Environment  string              `json:"environment,omitempty"`
+	Since        int64               `json:"since"`
+	Until        int64               `json:"until"`
+	New          DigestSection       `json:"new"`
+	Top          DigestSection       `json:"top"`
+	Resolved     DigestSection       `json:"resolved"`
+	Regressions  DigestSection       `json:"regressions"`
+	Environments []EnvironmentDigest `json:"environments"`
+}
+
+// BuildDigest scans the active and resolved items for the window and counts
+// each environment's occurrences from Rollbar's occurrence count report,
+// which starts the window at the hour holding Since for windows over an hour.
+// Sections list the most frequent items first.
+func BuildDigest(ctx context.Context, api DigestAPI, options DigestOptions, now time.Time) (Digest, error) {
+	if !options.Since.Before(now) {
+		return Digest{}, errors.New("digest window must start in the past")
+	}
+
+	environment := strings.TrimSpace(options.Environment)
+	since := options.Since.Unix()
+	active, err := scanItems(ctx, api, activeItemsIn(environment), func(item rollbar.Item) bool {
+		return atOrAfter(item.LastOccurrenceTimestamp, since)
+	})
+	if err != nil {
+		return Digest{}, err
+	}
+	resolvedQuery := activeItemsIn(environment)
+	resolvedQuery.Status = string(rollbar.StatusResolved)
+	resolved, err := scanItems(ctx, api, resolvedQuery, func(item rollbar.Item) bool {
+		return atOrAfter(item.LastResolvedTimestamp, since)
+	})
+	if err != nil {
+		return Digest{}, err
+	}
+
+	digest := newDigest(environment, since, now.Unix(), active, resolved, options.Limit)
+	for index := range digest.Environments {
+		breakdown := &digest.Environments[index]
+		if breakdown.Environment == "" {
+			continue
+		}
+		breakdown.Occurrences, err = occurrencesSince(ctx, api, rollbar.CountReportParams{Environment: breakdown.Environment}, options.Since, now)
+		if err != nil {
+			return Digest{}, err
+		}
+	}
+
+	return digest, nil
+}
+
+func newDigest(environment string, since int64, until int64, active []rollbar.Item, resolved []rollbar.Item, limit int) Digest {
+	var fresh, regressions []rollbar.Item
+	for _, item := range append(append([]rollbar.Item{}, active...), resolved...) {
+		switch {
+		case atOrAfter(item.FirstOccurrenceTimestamp, since):
+			fresh = append(fresh, item)
+		case item.Status == rollbar.StatusActive && atOrAfter(item.LastActivatedTimestamp, since):
+			regressions = append(regressions, item)
+		}
+	}
+
+	return Digest{
+		Environment:  environment,
+		Since:        since,
+		Until:        until,
+		New:          digestSection(fresh, limit),
+		Top:          digestSection(active, limit),
+		Resolved:     digestSection(resolved, limit),
+		Regressions:  digestSection(regressions, limit),
+		Environments: environmentDigests(fresh, active, resolved, regressions),
+	}
+}
+
+func digestSection(items []rollbar.Item, limit int) DigestSection {
+	sorted := append([]rollbar.Item{}, items...)
+	sort.SliceStable(sorted, func(i int, j int) bool { return totalOccurrences(sorted[i]) > totalOccurrences(sorted[j]) })
+	if limit > 0 && len(sorted) > limit {
+		sorted = sorted[:limit]
+	}
+
+	return DigestSection{Total: len(items), Items: mapSummaries(sorted)}
+}
+
+func environmentDigests(fresh []rollbar.Item, active []rollbar.Item, resolved []rollbar.Item, regressions []rollbar.Item) []EnvironmentDigest {
+	byName := map[string]*EnvironmentDigest{}
+	count := func(items []rollbar.Item, field func(*EnvironmentDigest) *int) {
+		for _, item := range items {
+			breakdown, ok := byName[item.Environment]
+			if !ok {
+				breakdown = &EnvironmentDigest{Environment: item.Environment}
+				byName[item.Environment] = breakdown
+			}
+			*field(breakdown)++
+		}
+	}
+	count(fresh, func(breakdown *EnvironmentDigest) *int { return &breakdown.New })
+	count(active, func(breakdown *EnvironmentDigest) *int { return &breakdown.Active })
+	count(resolved, func(breakdown *EnvironmentDigest) *int { return &breakdown.Resolved })
+	count(regressions, func(breakdown *EnvironmentDigest) *int { return &breakdown.Regressions })
+
+	breakdowns := make([]EnvironmentDigest, 0, len(byName))
+	for _, breakdown := range byName {
+		breakdowns = append(breakdowns, *breakdown)
+	}
+	sort.Slice(breakdowns, func(i int, j int) bool { return breakdowns[i].Environment < breakdowns[j].Environment })
+
+	return breakdowns
+}
+
+func atOrAfter(timestamp *uint64, since int64) bool {
+	return timestamp != nil && *timestamp <= math.MaxInt64 && int64(*timestamp) >= since
+}
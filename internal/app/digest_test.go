@@ -0,0 +1,118 @@
+package app
+
+import (
+	"context"
+	"slices"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// digestAPI serves one page of items per status and occurrence counts per
+// environment.
+type digestAPI struct {
+	items  map[string][]rollbar.Item
+	counts map[string][]rollbar.CountBucket
+	params []rollbar.CountReportParams
+}
+
+func (a *digestAPI) ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error) {
+	if query.Page > 1 {
+		return rollbar.ItemPage{Page: query.Page}, nil
+	}
+	items := a.items[query.Status]
+
+	return rollbar.ItemPage{Items: items, Page: 1, TotalCount: uint64(len(items))}, nil
+}
+
+func (a *digestAPI) OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error) {
+	a.params = append(a.params, params)
+	return a.counts[params.Environment], nil
+}
+
+// newDigestAPI serves items first seen, active, resolved and reactivated
+// before and during the week from since, in production and staging.
+func newDigestAPI(since time.Time) *digestAPI {
+	before, during := uint64(since.Unix())-86400, uint64(since.Unix())+3600
+	hour := uint64(since.Unix())
+
+	return &digestAPI{
+		items: map[string][]rollbar.Item{
+			"active": {
+				issueFixture(fixture{counter: 1, environment: "production", status: rollbar.StatusActive, firstSeen: during, lastSeen: during + 60, activated: during, resolved: during + 60, occurrences: 5}),
+				issueFixture(fixture{counter: 2, environment: "production", status: rollbar.StatusActive, firstSeen: before, lastSeen: during + 60, activated: during, resolved: during + 60, occurrences: 40}),
+				issueFixture(fixture{counter: 3, environment: "staging", status: rollbar.StatusActive, firstSeen: during, lastSeen: during + 60, activated: during, resolved: during + 60, occurrences: 9}),
+				issueFixture(fixture{counter: 4, environment: "production", status: rollbar.StatusActive, firstSeen: before, lastSeen: before + 60, activated: before, resolved: before + 60, occurrences: 1}),
+			},
+			"resolved": {
+				issueFixture(fixture{counter: 5, environment: "production", status: rollbar.StatusResolved, firstSeen: before, lastSeen: during + 60, activated: during, resolved: during + 60, occurrences: 2}),
+				issueFixture(fixture{counter: 6, environment: "staging", status: rollbar.StatusResolved, firstSeen: before, lastSeen: before + 60, activated: before - 86400, resolved: before - 86400 + 60, occurrences: 7}),
+			},
+		},
+		counts: map[string][]rollbar.CountBucket{
+			"production": {{Timestamp: hour - 3600, Count: 100}, {Timestamp: hour, Count: 30}},
+			"staging":    {{Timestamp: hour + 7200, Count: 4}},
+		},
+	}
+}
+
+// sectionCounters lists the counters of the items in section.
+func sectionCounters(section DigestSection) []domain.ItemCounter {
+	counters := make([]domain.ItemCounter, 0, len(section.Items))
+	for _, item := range section.Items {
+		counters = append(counters, item.Counter)
+	}
+	return counters
+}
+
+func TestBuildDigest(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 12, 0, 0, 0, time.UTC)
+	since := now.Add(-7 * 24 * time.Hour)
+	api := newDigestAPI(since)
+
+	digest, err := BuildDigest(context.Background(), api, DigestOptions{Since: since, Limit: 1}, now)
+	if err != nil {
+		t.Fatalf("BuildDigest() error = %v", err)
+	}
+
+	tests := []struct {
+		name     string
+		section  DigestSection
+		total    int
+		counters []domain.ItemCounter
+	}{
+		{name: "new", section: digest.New, total: 2, counters: []domain.ItemCounter{3}},
+		{name: "top", section: digest.Top, total: 3, counters: []domain.ItemCounter{2}},
+		{name: "resolved", section: digest.Resolved, total: 1, counters: []domain.ItemCounter{5}},
+		{name: "regressions", section: digest.Regressions, total: 1, counters: []domain.ItemCounter{2}},
+	}
+	for _, tc := range tests {
+		if tc.section.Total != tc.total || !slices.Equal(sectionCounters(tc.section), tc.counters) {
+			t.Fatalf("%s: section = %+v", tc.name, tc.section)
+		}
+	}
+
+	want := []EnvironmentDigest{
+		{Environment: "production", New: 1, Active: 2, Resolved: 1, Regressions: 1, Occurrences: 30},
+		{Environment: "staging", New: 1, Active: 1, Occurrences: 4},
+	}
+	if !slices.Equal(digest.Environments, want) {
+		t.Fatalf("environments = %+v, want %+v", digest.Environments, want)
+	}
+	if api.params[0].BucketSize != 3600 || digest.Since != since.Unix() || digest.Until != now.Unix() {
+		t.Fatalf("unexpected window %d-%d, params %+v", digest.Since, digest.Until, api.params)
+	}
+}
+
+func TestBuildDigestEmptyWindow(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 12, 0, 0, 0, time.UTC)
+	if _, err := BuildDigest(context.Background(), newDigestAPI(now.Add(-7*24*time.Hour)), DigestOptions{Since: now}, now); err == nil {
+		t.Fatal("expected an error for a window that starts now")
+	}
+}
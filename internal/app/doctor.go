@@ -0,0 +1,183 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"slices"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type CheckStatus string
+
+const (
+	CheckOK   CheckStatus = "ok"
+	CheckWarn CheckStatus = "warn"
+	CheckFail CheckStatus = "fail"
+	CheckSkip CheckStatus = "skip"
+)
+
+// DoctorCheck is one line of `rollbaz doctor`: what was checked, how it went,
+// and for anything short of ok, what to do about it.
+type DoctorCheck struct {
+	Name   string      `json:"name"`
+	Status CheckStatus `json:"status"`
+	Detail string      `json:"detail"`
+	Fix    string      `json:"fix,omitempty"`
+}
+
+// DoctorAPI is the part of the client the API checks use.
+type DoctorAPI interface {
+	Ping(ctx context.Context) (time.Duration, error)
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+	RateLimitStatus() (rollbar.RateLimit, bool)
+}
+
+const (
+	// lowRateLimitShare is the fraction of the window below which doctor warns.
+	lowRateLimitShare = 0.1
+	// slowPingLatency is the status ping round trip above which doctor warns.
+	slowPingLatency = 2 * time.Second
+)
+
+// CheckAPI pings the status endpoint for reachability and latency, then reads
+// one page of items and turns the outcome into checks for the token and
+// project access. It also returns the project id seen on the page, or zero.
+func CheckAPI(ctx context.Context, api DoctorAPI, baseURL string) ([]DoctorCheck, uint64) {
+	latency, pingErr := api.Ping(ctx)
+	if unreachable(pingErr) {
+		return classifyAPIError(pingErr, baseURL), 0
+	}
+
+	page, err := api.ListItemsPage(ctx, rollbar.ItemQuery{Page: 1})
+	if err != nil {
+		return classifyAPIError(err, baseURL), 0
+	}
+	var projectID uint64
+	if len(page.Items) > 0 {
+		projectID = page.Items[0].ProjectID
+	}
+
+	return []DoctorCheck{
+		reachableCheck(baseURL, latency, pingErr),
+		{Name: "token", Status: CheckOK, Detail: "token accepted with read scope"},
+		{Name: "project", Status: CheckOK, Detail: fmt.Sprintf("project readable (%d items)", page.TotalCount)},
+	}, projectID
+}
+
+// unreachable reports whether err means no response arrived at all. A status
+// endpoint that answers with an error, as some proxies do, still counts as
+// reachable, and the item read decides.
+func unreachable(err error) bool {
+	var apiErr *rollbar.Error
+
+	return err != nil && (!errors.As(err, &apiErr) || apiErr.Kind == rollbar.ErrorKindRequest)
+}
+
+func reachableCheck(baseURL string, latency time.Duration, pingErr error) DoctorCheck {
+	check := DoctorCheck{Name: "api", Status: CheckOK, Detail: baseURL + " is reachable"}
+	if pingErr != nil {
+		return check
+	}
+
+	check.Detail += fmt.Sprintf(" (ping %s)", latency.Round(time.Millisecond))
+	if latency > slowPingLatency {
+		check.Status = CheckWarn
+		check.Fix = "check network latency and proxy settings; slow pings make every command slow"
+	}
+
+	return check
+}
+
+func classifyAPIError(err error, baseURL string) []DoctorCheck {
+	reachable := DoctorCheck{Name: "api", Status: CheckOK, Detail: baseURL + " is reachable"}
+	var apiErr *rollbar.Error
+	if !errors.As(err, &apiErr) || apiErr.Kind == rollbar.ErrorKindRequest {
+		return []DoctorCheck{
+			{Name: "api", Status: CheckFail, Detail: err.Error(), Fix: "check network access and proxy settings, or the profile's base_url (" + baseURL + ")"},
+			{Name: "token", Status: CheckSkip, Detail: "API unreachable"},
+			{Name: "project", Status: CheckSkip, Detail: "API unreachable"},
+		}
+	}
+
+	switch {
+	case apiErr.Kind == rollbar.ErrorKindRateLimited:
+		return []DoctorCheck{reachable,
+			{Name: "token", Status: CheckWarn, Detail: "rate limited before the token could be checked", Fix: fmt.Sprintf("wait %s and rerun", apiErr.RetryAfter.Round(time.Second))},
+			{Name: "project", Status: CheckSkip, Detail: "rate limited"},
+		}
+	case apiErr.StatusCode == http.StatusUnauthorized:
+		return []DoctorCheck{reachable,
+			{Name: "token", Status: CheckFail, Detail: "Rollbar rejected the token", Fix: "copy a project access token from Rollbar's project settings and run `rollbaz auth login`"},
+			{Name: "project", Status: CheckSkip, Detail: "token rejected"},
+		}
+	case apiErr.StatusCode == http.StatusForbidden:
+		return []DoctorCheck{reachable,
+			{Name: "token", Status: CheckFail, Detail: "token lacks read scope (post-only tokens cannot list items)", Fix: "use a project access token with the read scope"},
+			{Name: "project", Status: CheckSkip, Detail: "token cannot read"},
+		}
+	default:
+		return []DoctorCheck{reachable,
+			{Name: "token", Status: CheckOK, Detail: "token accepted"},
+			{Name: "project", Status: CheckFail, Detail: err.Error(), Fix: "check --project and that the token belongs to the project you expect"},
+		}
+	}
+}
+
+// CheckWriteScope looks the token up among the project's access tokens, which
+// needs an account token. projectID may be zero, in which case every project
+// the account token can see is searched.
+func CheckWriteScope(ctx context.Context, admin ProjectAdminAPI, projectID uint64, token string) DoctorCheck {
+	projectIDs := []uint64{projectID}
+	if projectID == 0 {
+		projects, err := admin.ListProjects(ctx)
+		if err != nil {
+			return DoctorCheck{Name: "scope", Status: CheckWarn, Detail: "list projects: " + err.Error(), Fix: "check ROLLBAR_ACCOUNT_TOKEN has read scope"}
+		}
+		projectIDs = projectIDs[:0]
+		for _, project := range projects {
+			projectIDs = append(projectIDs, project.ID)
+		}
+	}
+
+	for _, id := range projectIDs {
+		tokens, err := admin.ListProjectAccessTokens(ctx, id)
+		if err != nil {
+			continue
+		}
+		for _, candidate := range tokens {
+			if candidate.AccessToken == token {
+				return scopeCheck(candidate.Scopes)
+			}
+		}
+	}
+
+	return DoctorCheck{Name: "scope", Status: CheckWarn, Detail: "token not found among the account's project tokens", Fix: "check ROLLBAR_ACCOUNT_TOKEN belongs to the same Rollbar account"}
+}
+
+func scopeCheck(scopes []string) DoctorCheck {
+	detail := fmt.Sprintf("token scopes: %v", scopes)
+	if slices.Contains(scopes, "write") {
+		return DoctorCheck{Name: "scope", Status: CheckOK, Detail: detail}
+	}
+
+	return DoctorCheck{Name: "scope", Status: CheckWarn, Detail: detail, Fix: "resolve, mute, and assign need a token with the write scope"}
+}
+
+// CheckRateLimit reports the rate-limit window seen on the earlier calls.
+func CheckRateLimit(api DoctorAPI, now time.Time) DoctorCheck {
+	status, ok := api.RateLimitStatus()
+	if !ok {
+		return DoctorCheck{Name: "rate limit", Status: CheckSkip, Detail: "no rate-limit headers seen"}
+	}
+
+	detail := fmt.Sprintf("%d of %d requests left, resets in %s", status.Remaining, status.Limit, max(status.Reset.Sub(now), 0).Round(time.Second))
+	if status.Limit > 0 && float64(status.Remaining) < float64(status.Limit)*lowRateLimitShare {
+		return DoctorCheck{Name: "rate limit", Status: CheckWarn, Detail: detail, Fix: "pause scripted calls or lower --concurrency until the window resets"}
+	}
+
+	return DoctorCheck{Name: "rate limit", Status: CheckOK, Detail: detail}
+}
@@ -0,0 +1,124 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeDoctorAPI struct {
+	latency time.Duration
+	pingErr error
+	page    rollbar.ItemPage
+	err     error
+	limit   *rollbar.RateLimit
+}
+
+func (f fakeDoctorAPI) Ping(ctx context.Context) (time.Duration, error) {
+	return f.latency, f.pingErr
+}
+
+func (f fakeDoctorAPI) ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error) {
+	return f.page, f.err
+}
+
+func (f fakeDoctorAPI) RateLimitStatus() (rollbar.RateLimit, bool) {
+	if f.limit == nil {
+		return rollbar.RateLimit{}, false
+	}
+	return *f.limit, true
+}
+
+func TestCheckAPI(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name    string
+		latency time.Duration
+		pingErr error
+		err     error
+		want    []CheckStatus
+	}{
+		{name: "success", latency: 80 * time.Millisecond, want: []CheckStatus{CheckOK, CheckOK, CheckOK}},
+		{name: "slow ping", latency: 3 * time.Second, want: []CheckStatus{CheckWarn, CheckOK, CheckOK}},
+		{name: "ping unreachable", pingErr: &rollbar.Error{Kind: rollbar.ErrorKindRequest, Message: "dial tcp"}, want: []CheckStatus{CheckFail, CheckSkip, CheckSkip}},
+		{name: "ping rejected", pingErr: &rollbar.Error{Kind: rollbar.ErrorKindHTTP, StatusCode: http.StatusNotFound}, want: []CheckStatus{CheckOK, CheckOK, CheckOK}},
+		{name: "unreachable", err: &rollbar.Error{Kind: rollbar.ErrorKindRequest, Message: "dial tcp"}, want: []CheckStatus{CheckFail, CheckSkip, CheckSkip}},
+		{name: "plain error", err: errors.New("boom"), want: []CheckStatus{CheckFail, CheckSkip, CheckSkip}},
+		{name: "unauthorized", err: &rollbar.Error{Kind: rollbar.ErrorKindHTTP, StatusCode: http.StatusUnauthorized}, want: []CheckStatus{CheckOK, CheckFail, CheckSkip}},
+		{name: "forbidden", err: &rollbar.Error{Kind: rollbar.ErrorKindHTTP, StatusCode: http.StatusForbidden}, want: []CheckStatus{CheckOK, CheckFail, CheckSkip}},
+		{name: "rate limited", err: &rollbar.Error{Kind: rollbar.ErrorKindRateLimited, StatusCode: http.StatusTooManyRequests, RetryAfter: time.Minute}, want: []CheckStatus{CheckOK, CheckWarn, CheckSkip}},
+		{name: "not found", err: &rollbar.Error{Kind: rollbar.ErrorKindHTTP, StatusCode: http.StatusNotFound}, want: []CheckStatus{CheckOK, CheckOK, CheckFail}},
+	}
+	for _, tc := range tests {
+		api := fakeDoctorAPI{latency: tc.latency, pingErr: tc.pingErr, page: rollbar.ItemPage{Items: []rollbar.Item{{ProjectID: 9}}, TotalCount: 1}, err: tc.err}
+		checks, projectID := CheckAPI(context.Background(), api, "https://api.rollbar.com/api/1")
+		if len(checks) != len(tc.want) {
+			t.Fatalf("%s: got %+v", tc.name, checks)
+		}
+		for index, want := range tc.want {
+			if checks[index].Status != want || (want != CheckOK && want != CheckSkip && checks[index].Fix == "") {
+				t.Fatalf("%s: check %d = %+v, want status %s with a fix", tc.name, index, checks[index], want)
+			}
+		}
+		if (tc.want[2] == CheckOK) != (projectID == 9) {
+			t.Fatalf("%s: unexpected project id %d", tc.name, projectID)
+		}
+	}
+
+	checks, _ := CheckAPI(context.Background(), fakeDoctorAPI{latency: 80 * time.Millisecond}, "https://api.rollbar.com/api/1")
+	if checks[0].Detail != "https://api.rollbar.com/api/1 is reachable (ping 80ms)" {
+		t.Fatalf("unexpected api detail %q", checks[0].Detail)
+	}
+}
+
+func TestCheckWriteScope(t *testing.T) {
+	t.Parallel()
+
+	admin := &fakeProjectAdmin{
+		projects: []rollbar.Project{{ID: 1}, {ID: 2}},
+		tokens: map[uint64][]rollbar.ProjectAccessToken{
+			1: {{AccessToken: "read-only", Scopes: []string{"read"}}},
+			2: {{AccessToken: "read-write", Scopes: []string{"read", "write"}}},
+		},
+	}
+
+	tests := []struct {
+		projectID uint64
+		token     string
+		want      CheckStatus
+	}{
+		{projectID: 2, token: "read-write", want: CheckOK},
+		{token: "read-write", want: CheckOK},
+		{token: "read-only", want: CheckWarn},
+		{token: "unknown", want: CheckWarn},
+	}
+	for _, tc := range tests {
+		if got := CheckWriteScope(context.Background(), admin, tc.projectID, tc.token); got.Status != tc.want {
+			t.Fatalf("%d/%s: got %+v, want %s", tc.projectID, tc.token, got, tc.want)
+		}
+	}
+}
+
+func TestCheckRateLimit(t *testing.T) {
+	t.Parallel()
+
+	now := time.Unix(1000, 0)
+	tests := []struct {
+		limit *rollbar.RateLimit
+		want  CheckStatus
+	}{
+		{want: CheckSkip},
+		{limit: &rollbar.RateLimit{Limit: 5000, Remaining: 4000, Reset: now.Add(time.Minute)}, want: CheckOK},
+		{limit: &rollbar.RateLimit{Limit: 5000, Remaining: 10, Reset: now.Add(time.Minute)}, want: CheckWarn},
+	}
+	for _, tc := range tests {
+		if got := CheckRateLimit(fakeDoctorAPI{limit: tc.limit}, now); got.Status != tc.want {
+			t.Fatalf("%+v: got %+v, want %s", tc.limit, got, tc.want)
+		}
+	}
+}
@@ -0,0 +1,92 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strings"
+)
+
+type EnvironmentCheck struct {
+	Known      bool   `json:"known"`
+	Suggestion string `json:"suggestion,omitempty"`
+}
+
+func (s *Service) Environments(ctx context.Context) ([]string, error) {
+	names := make([]string, 0)
+	for page := 1; ; page++ {
+		environments, err := s.api.ListEnvironments(ctx, page)
+		if err != nil {
+			return nil, fmt.Errorf("list environments: %w", err)
+		}
+		if len(environments) == 0 {
+			break
+		}
+		for _, environment := range environments {
+			names = append(names, environment.Environment)
+		}
+	}
+	sort.Strings(names)
+
+	return names, nil
+}
+
+// CheckEnvironment reports whether the project knows an environment and, if it
+// does not, the closest known name so typos in --env can be pointed out.
+func (s *Service) CheckEnvironment(ctx context.Context, name string) (EnvironmentCheck, error) {
+	names, err := s.Environments(ctx)
+	if err != nil {
+		return EnvironmentCheck{}, err
+	}
+
+	trimmed := strings.TrimSpace(name)
+	for _, candidate := range names {
+		if strings.EqualFold(candidate, trimmed) {
+			return EnvironmentCheck{Known: true}, nil
+		}
+	}
+
+	return EnvironmentCheck{Suggestion: closestMatch(trimmed, names)}, nil
+}
+
+func closestMatch(value string, candidates []string) string {
+	lowered := strings.ToLower(value)
+	best := ""
+	bestDistance := len(lowered)/3 + 2
+	for _, candidate := range candidates {
+		loweredCandidate := strings.ToLower(candidate)
+		if strings.HasPrefix(loweredCandidate, lowered) && lowered != "" {
+			return candidate
+		}
+		if distance := editDistance(lowered, loweredCandidate); distance < bestDistance {
+			best = candidate
+			bestDistance = distance
+		}
+	}
+
+	return best
+}
+
+func editDistance(left string, right string) int {
+	a := []rune(left)
+	b := []rune(right)
+	previous := make([]int, len(b)+1)
+	current := make([]int, len(b)+1)
+	for j := range previous {
+		previous[j] = j
+	}
+
+	for i := 1; i <= len(a); i++ {
+		current[0] = i
+		for j := 1; j <= len(b); j++ {
+			cost := 1
+			if a[i-1] == b[j-1] {
+				cost = 0
+			}
+			current[j] = min(previous[j]+1, current[j-1]+1, previous[j-1]+cost)
+		}
+		previous, current = current, previous
+	}
+
+	return previous[len(b)]
+}
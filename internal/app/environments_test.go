@@ -0,0 +1,64 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestServiceCheckEnvironment(t *testing.T) {
+	t.Parallel()
+
+	service := NewService(fakeAPI{environments: []rollbar.Environment{{Environment: "staging"}, {Environment: "production"}}})
+
+	names, err := service.Environments(context.Background())
+	if err != nil {
+		t.Fatalf("Environments() error = %v", err)
+	}
+	if len(names) != 2 || names[0] != "production" {
+		t.Fatalf("unexpected environment names: %v", names)
+	}
+
+	tests := []struct {
+		name  string
+		input string
+		want  EnvironmentCheck
+	}{
+		{name: "exact", input: "production", want: EnvironmentCheck{Known: true}},
+		{name: "case insensitive", input: " Staging ", want: EnvironmentCheck{Known: true}},
+		{name: "typo", input: "prodution", want: EnvironmentCheck{Suggestion: "production"}},
+		{name: "prefix", input: "prod", want: EnvironmentCheck{Suggestion: "production"}},
+		{name: "unrelated", input: "qa", want: EnvironmentCheck{}},
+	}
+	for _, tc := range tests {
+		got, err := service.CheckEnvironment(context.Background(), tc.input)
+		if err != nil {
+			t.Fatalf("%s: CheckEnvironment() error = %v", tc.name, err)
+		}
+		if got != tc.want {
+			t.Fatalf("%s: CheckEnvironment() = %+v, want %+v", tc.name, got, tc.want)
+		}
+	}
+}
+
+func TestServiceCheckEnvironmentError(t *testing.T) {
+	t.Parallel()
+
+	service := NewService(fakeAPI{err: errors.New("denied")})
+	if _, err := service.CheckEnvironment(context.Background(), "production"); err == nil {
+		t.Fatalf("expected environments error")
+	}
+}
+
+func TestEditDistance(t *testing.T) {
+	t.Parallel()
+
+	if got := editDistance("kitten", "sitting"); got != 3 {
+		t.Fatalf("editDistance() = %d, want 3", got)
+	}
+	if got := editDistance("", "abc"); got != 3 {
+		t.Fatalf("editDistance(empty) = %d, want 3", got)
+	}
+}
@@ -0,0 +1,40 @@
+package app
+
+import (
+	"context"
+	"fmt"
+)
+
+// StreamItems pages through every item matching options, in the order the
+// API returns them, handing each page to emit as it arrives. Sort is ignored
+// and a zero Limit means no limit, so exports are not capped like List.
+func (s *Service) StreamItems(ctx context.Context, options ListOptions, emit func([]IssueSummary) error) error {
+	filters := normalizeIssueFilters(options.Filters)
+	query := listQuery(options, filters)
+
+	sent := 0
+	for page := 1; ; page++ {
+		query.Page = page
+		result, err := s.api.ListItemsPage(ctx, query)
+		if err != nil {
+			return fmt.Errorf("list items: %w", err)
+		}
+		if len(result.Items) == 0 {
+			return nil
+		}
+
+		batch := mapSummaries(filterItems(result.Items, filters))
+		if options.Limit > 0 {
+			batch = batch[:min(len(batch), options.Limit-sent)]
+		}
+		if len(batch) > 0 {
+			if err := emit(batch); err != nil {
+				return err
+			}
+		}
+		sent += len(batch)
+		if (options.Limit > 0 && sent >= options.Limit) || listComplete(result, page, 0, 0) {
+			return nil
+		}
+	}
+}
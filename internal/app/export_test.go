@@ -0,0 +1,70 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// streamPages are 12 pages of two items each, counters 1 to 24 in order;
+// the even counters have 9 occurrences and the odd ones 1.
+func streamPages() [][]rollbar.Item {
+	pages := make([][]rollbar.Item, 0, 12)
+	for page := uint64(0); page < 12; page++ {
+		pages = append(pages, []rollbar.Item{
+			issueFixture(fixture{counter: page*2 + 1, lastSeen: 100, occurrences: 1}),
+			issueFixture(fixture{counter: page*2 + 2, lastSeen: 100, occurrences: 9}),
+		})
+	}
+
+	return pages
+}
+
+func TestServiceStreamItems(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name      string
+		options   ListOptions
+		wantCount int
+		wantCalls int
+	}{
+		{name: "all pages past the list cap", wantCount: 24, wantCalls: 12},
+		{name: "limit mid page", options: ListOptions{Limit: 3}, wantCount: 3, wantCalls: 2},
+		{name: "local filter", options: ListOptions{Filters: IssueFilters{MinOccurrences: uint64Ptr(5)}}, wantCount: 12, wantCalls: 12},
+	}
+	for _, tc := range tests {
+		api := &pagedAPI{pages: streamPages(), total: 24}
+		batches := 0
+		counters := make([]uint64, 0)
+		err := NewService(api).StreamItems(context.Background(), tc.options, func(batch []IssueSummary) error {
+			batches++
+			counters = append(counters, summaryCounters(batch)...)
+			return nil
+		})
+		if err != nil {
+			t.Fatalf("%s: StreamItems() error = %v", tc.name, err)
+		}
+		if len(counters) != tc.wantCount || len(api.queries) != tc.wantCalls || batches != tc.wantCalls {
+			t.Fatalf("%s: got %d items in %d batches over %d calls", tc.name, len(counters), batches, len(api.queries))
+		}
+		if !slices.IsSorted(counters) {
+			t.Fatalf("%s: expected API order, got %v", tc.name, counters)
+		}
+	}
+}
+
+func TestServiceStreamItemsStopsOnEmitError(t *testing.T) {
+	t.Parallel()
+
+	stop := errors.New("disk full")
+	err := NewService(&pagedAPI{pages: streamPages(), total: 24}).StreamItems(context.Background(), ListOptions{}, func([]IssueSummary) error {
+		return stop
+	})
+	if !errors.Is(err, stop) {
+		t.Fatalf("expected emit error to stop the stream, got %v", err)
+	}
+}
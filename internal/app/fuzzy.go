@@ -0,0 +1,142 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+	"unicode"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// Fuzzy match scoring, fzf-style: every matched character scores, more at
+// the start of a word and when it follows the previous match directly, and
+// every character skipped inside a match costs a little.
+const (
+	fuzzyMatchScore       = 2
+	fuzzyWordStartBonus   = 8
+	fuzzyConsecutiveBonus = 4
+	fuzzyGapPenalty       = 1
+)
+
+// FuzzyIndex is the local store fuzzy search reads: every synced item, and
+// the exception class of each item's newest synced occurrence.
+type FuzzyIndex interface {
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+	ExceptionClasses(ctx context.Context) (map[domain.ItemID]string, error)
+}
+
+// FuzzySearch ranks every synced item against text by fuzzy-matching its
+// title and exception class, so typos that drop letters, like "paymnt
+// timout", still find the item. Each word of text must match on its own.
+func (s *Service) FuzzySearch(ctx context.Context, index FuzzyIndex, options SearchOptions) ([]SearchResult, error) {
+	text := strings.TrimSpace(options.Text)
+	if text == "" {
+		return nil, errors.New("search text is required")
+	}
+
+	filters := normalizeIssueFilters(options.Filters)
+	classes, err := index.ExceptionClasses(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("read exception classes: %w", err)
+	}
+
+	query := listQuery(ListOptions{}, filters)
+	results := make([]SearchResult, 0)
+	for page := 1; ; page++ {
+		query.Page = page
+		result, err := index.ListItemsPage(ctx, query)
+		if err != nil {
+			return nil, fmt.Errorf("list synced items: %w", err)
+		}
+		results = append(results, fuzzyMatches(text, filterItems(result.Items, filters), classes)...)
+		if len(result.Items) == 0 || listComplete(result, page, 0, 0) {
+			break
+		}
+	}
+
+	sort.SliceStable(results, func(i int, j int) bool { return results[i].Score > results[j].Score })
+	if options.Limit > 0 && len(results) > options.Limit {
+		results = results[:options.Limit]
+	}
+
+	return results, nil
+}
+
+// fuzzyMatches scores items on their title and exception classes.
+func fuzzyMatches(text string, items []rollbar.Item, classes map[domain.ItemID]string) []SearchResult {
+	matches := make([]SearchResult, 0)
+	for _, item := range items {
+		if score, ok := FuzzyScore(text, item.Title+" "+classes[item.ID]); ok {
+			matches = append(matches, SearchResult{IssueSummary: mapSummary(item), Score: score})
+		}
+	}
+
+	return matches
+}
+
+// FuzzyScore reports whether every space-separated term of pattern appears in
+// text as a case-insensitive subsequence, and how well: higher is better.
+func FuzzyScore(pattern string, text string) (int, bool) {
+	terms := strings.Fields(strings.ToLower(pattern))
+	if len(terms) == 0 {
+		return 0, false
+	}
+
+	haystack := []rune(strings.ToLower(text))
+	total := 0
+	for _, term := range terms {
+		score, ok := bestTermScore([]rune(term), haystack)
+		if !ok {
+			return 0, false
+		}
+		total += score
+	}
+
+	return total, true
+}
+
+// bestTermScore tries the term from every position its first rune occurs at
+// and keeps the best greedy match.
+func bestTermScore(term []rune, haystack []rune) (int, bool) {
+	best, found := 0, false
+	for start := range haystack {
+		if haystack[start] != term[0] {
+			continue
+		}
+		if score, ok := termScoreFrom(term, haystack, start); ok && (!found || score > best) {
+			best, found = score, true
+		}
+	}
+
+	return best, found
+}
+
+func termScoreFrom(term []rune, haystack []rune, start int) (int, bool) {
+	score, matched, previous := 0, 0, start-1
+	for position := start; position < len(haystack) && matched < len(term); position++ {
+		if haystack[position] != term[matched] {
+			continue
+		}
+		score += fuzzyMatchScore
+		if position == 0 || !isWordRune(haystack[position-1]) {
+			score += fuzzyWordStartBonus
+		}
+		if position == previous+1 && matched > 0 {
+			score += fuzzyConsecutiveBonus
+		} else if matched > 0 {
+			score -= fuzzyGapPenalty * (position - previous - 1)
+		}
+		previous = position
+		matched++
+	}
+
+	return score, matched == len(term)
+}
+
+func isWordRune(r rune) bool {
+	return unicode.IsLetter(r) || unicode.IsDigit(r)
+}
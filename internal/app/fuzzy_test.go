@@ -0,0 +1,81 @@
+package app
+
+import (
+	"context"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fuzzyIndex struct {
+	*pagedAPI
+	classes map[domain.ItemID]string
+}
+
+func (f fuzzyIndex) ExceptionClasses(context.Context) (map[domain.ItemID]string, error) {
+	return f.classes, nil
+}
+
+func TestFuzzyScore(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		pattern string
+		text    string
+		ok      bool
+	}{
+		{pattern: "paymnt timout", text: "Payment gateway timeout", ok: true},
+		{pattern: "PAYMNT", text: "payment", ok: true},
+		{pattern: "paymnt refund", text: "Payment gateway timeout", ok: false},
+		{pattern: "tmieout", text: "timeout", ok: false},
+		{pattern: " ", text: "anything", ok: false},
+	}
+	for _, tc := range tests {
+		if _, ok := FuzzyScore(tc.pattern, tc.text); ok != tc.ok {
+			t.Fatalf("FuzzyScore(%q, %q) ok = %v, want %v", tc.pattern, tc.text, ok, tc.ok)
+		}
+	}
+
+	tight, _ := FuzzyScore("timout", "timeout")
+	loose, _ := FuzzyScore("timout", "txixmxexoxuxt")
+	prefix, _ := FuzzyScore("pay", "payment")
+	inner, _ := FuzzyScore("pay", "repay")
+	if tight <= loose || prefix <= inner {
+		t.Fatalf("expected tighter and word-start matches to score higher: %d/%d, %d/%d", tight, loose, prefix, inner)
+	}
+}
+
+func TestServiceFuzzySearch(t *testing.T) {
+	t.Parallel()
+
+	index := fuzzyIndex{
+		pagedAPI: &pagedAPI{pages: [][]rollbar.Item{
+			{
+				{ID: 1, Counter: 1, Title: "Unhandled rejection", Status: rollbar.StatusActive},
+				{ID: 2, Counter: 2, Title: "Payment gateway timeout", Status: rollbar.StatusActive},
+			},
+			{
+				{ID: 3, Counter: 3, Title: "Checkout failed", Status: rollbar.StatusResolved},
+			},
+		}},
+		classes: map[domain.ItemID]string{1: "PaymentTimeoutError"},
+	}
+
+	results, err := NewService(fakeAPI{}).FuzzySearch(context.Background(), index, SearchOptions{Text: "paymnt timout"})
+	if err != nil {
+		t.Fatalf("FuzzySearch() error = %v", err)
+	}
+	var counters []uint64
+	for _, result := range results {
+		counters = append(counters, uint64(result.Counter))
+	}
+	if !slices.Equal(counters, []uint64{2, 1}) || len(index.queries) != 3 {
+		t.Fatalf("FuzzySearch() counters = %v after %d pages", counters, len(index.queries))
+	}
+
+	if _, err := NewService(fakeAPI{}).FuzzySearch(context.Background(), index, SearchOptions{}); err == nil {
+		t.Fatal("expected an error for empty search text")
+	}
+}
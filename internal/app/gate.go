@@ -0,0 +1,234 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"math"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// GateAPI is the part of the Rollbar client CI gates use.
+type GateAPI interface {
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+	OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error)
+}
+
+const (
+	GateNewItems    = "new_items"
+	GateOccurrences = "occurrences"
+)
+
+// GateOptions sets the thresholds a CI gate enforces; a nil threshold is not
+// checked, but at least one must be set.
+type GateOptions struct {
+	Environment    string
+	Since          time.Time
+	MaxNewItems    *uint64
+	MaxOccurrences *uint64
+}
+
+type GateCheck struct {
+	Name   string `json:"name"`
+	Value  uint64 `json:"value"`
+	Max    uint64 `json:"max"`
+	Passed bool   `json:"passed"`
+}
+
+// GateResult passes when every check does. NewItems are the active items
+// first seen since Since, most frequent first, whichever checks ran.
+type GateResult struct {
+	Environment string         `json:"environment,omitempty"`
+	Since       int64          `json:"since"`
+	Passed      bool           `json:"passed"`
+	Checks      []GateCheck    `json:"checks"`
+	NewItems    []IssueSummary `json:"new_items"`
+}
+
+// CheckGate counts the active items first seen since options.Since and, when
+// an occurrence threshold is set, every occurrence since then from Rollbar's
+// occurrence count report, and compares them with the thresholds. The count
+// report is bucketed, so the occurrence window starts at the start of the
+// minute (or, for windows over an hour, the hour) holding Since.
+func CheckGate(ctx context.Context, api GateAPI, options GateOptions, now time.Time) (GateResult, error) {
+	if options.MaxNewItems == nil && options.MaxOccurrences == nil {
+		return GateResult{}, errors.New("set a new item or occurrence threshold")
+	}
+
+	environment := strings.TrimSpace(options.Environment)
+	since := options.Since.Unix()
+	newItems, err := scanItems(ctx, api, activeItemsIn(environment), func(item rollbar.Item) bool {
+		first, ok := item.FirstOccurrenceTime()
+		return ok && first.Unix() >= since
+	})
+	if err != nil {
+		return GateResult{}, err
+	}
+	sort.SliceStable(newItems, func(i int, j int) bool { return totalOccurrences(newItems[i]) > totalOccurrences(newItems[j]) })
+
+	result := GateResult{Environment: environment, Since: since, Passed: true, Checks: []GateCheck{}, NewItems: mapSummaries(newItems)}
+	if options.MaxNewItems != nil {
+		result.add(GateNewItems, uint64(len(newItems)), *options.MaxNewItems)
+	}
+	if options.MaxOccurrences != nil {
+		occurrences, err := occurrencesSince(ctx, api, rollbar.CountReportParams{Environment: environment}, options.Since, now)
+		if err != nil {
+			return GateResult{}, err
+		}
+		result.add(GateOccurrences, occurrences, *options.MaxOccurrences)
+	}
+
+	return result, nil
+}
+
+func (r *GateResult) add(name string, value uint64, limit uint64) {
+	check := GateCheck{Name: name, Value: value, Max: limit, Passed: value <= limit}
+	r.Checks = append(r.Checks, check)
+	r.Passed = r.Passed && check.Passed
+}
+
+// FrameAPI is the part of the Rollbar client LatestFrames uses.
+type FrameAPI interface {
+	GetLatestInstance(ctx context.Context, itemID domain.ItemID) (*rollbar.ItemInstance, error)
+}
+
+// ItemFrames is an item with the exception class and stack frames of its
+// latest occurrence, most recent call first. Both are empty for messages and
+// crash reports.
+type ItemFrames struct {
+	IssueSummary
+	Exception string          `json:"exception,omitempty"`
+	Frames    []rollbar.Frame `json:"frames"`
+}
+
+// LatestFrames loads the latest occurrence of each item for its exception
+// and stack frames, one request per item.
+func LatestFrames(ctx context.Context, api FrameAPI, items []IssueSummary) ([]ItemFrames, error) {
+	located := make([]ItemFrames, 0, len(items))
+	for _, item := range items {
+		instance, err := api.GetLatestInstance(ctx, item.ItemID)
+		if err != nil {
+			return nil, fmt.Errorf("get latest instance of item %d: %w", item.Counter, err)
+		}
+
+		located = append(located, itemFrames(item, instance))
+	}
+
+	return located, nil
+}
+
+// LatestFrames is LatestFrames through the service's Rollbar API.
+func (s *Service) LatestFrames(ctx context.Context, items []IssueSummary) ([]ItemFrames, error) {
+	return LatestFrames(ctx, s.api, items)
+}
+
+func itemFrames(item IssueSummary, instance *rollbar.ItemInstance) ItemFrames {
+	located := ItemFrames{IssueSummary: item, Frames: []rollbar.Frame{}}
+	if instance == nil {
+		return located
+	}
+
+	body := instance.OccurrenceBody()
+	if exception, ok := body.Exception(); ok {
+		located.Exception = exception.Class
+	}
+	frames := body.Frames()
+	for index := len(frames) - 1; index >= 0; index-- {
+		located.Frames = append(located.Frames, frames[index])
+	}
+
+	return located
+}
+
+// ItemFrameAPI is the part of the Rollbar client that loads an item's latest
+// stack trace by counter.
+type ItemFrameAPI interface {
+	FrameAPI
+	ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error)
+	GetItem(ctx context.Context, itemID domain.ItemID) (rollbar.Item, error)
+}
+
+const defaultCheckoutFrames = 3
+
+// CheckoutFrame is a stack frame whose file is in the local checkout at Path.
+type CheckoutFrame struct {
+	rollbar.Frame
+	Path string `json:"path"`
+}
+
+// checkoutFrames loads an item's latest stack trace and keeps its first limit
+// frames (3 when unset), most recent call first, that locate finds in the
+// checkout, skipping library and runtime frames.
+func checkoutFrames(ctx context.Context, api ItemFrameAPI, counter domain.ItemCounter, locate func(filename string) (string, bool), limit int) (domain.ItemID, IssueSummary, []CheckoutFrame, error) {
+	itemID, err := api.ResolveItemIDByCounter(ctx, counter)
+	if err != nil {
+		return 0, IssueSummary{}, nil, fmt.Errorf("resolve item id: %w", err)
+	}
+	item, err := api.GetItem(ctx, itemID)
+	if err != nil {
+		return 0, IssueSummary{}, nil, fmt.Errorf("get item: %w", err)
+	}
+	located, err := LatestFrames(ctx, api, []IssueSummary{mapSummary(item)})
+	if err != nil {
+		return 0, IssueSummary{}, nil, err
+	}
+	if limit <= 0 {
+		limit = defaultCheckoutFrames
+	}
+
+	frames := make([]CheckoutFrame, 0, limit)
+	for _, frame := range located[0].Frames {
+		if len(frames) == limit {
+			break
+		}
+		if path, ok := locate(frame.Filename); ok {
+			frames = append(frames, CheckoutFrame{Frame: frame, Path: path})
+		}
+	}
+
+	return itemID, located[0].IssueSummary, frames, nil
+}
+
+func activeItemsIn(environment string) rollbar.ItemQuery {
+	query := rollbar.ItemQuery{Status: string(rollbar.StatusActive)}
+	if environment != "" {
+		query.Environments = []string{environment}
+	}
+
+	return query
+}
+
+// occurrenceCounter reads Rollbar's occurrence count report.
+type occurrenceCounter interface {
+	OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error)
+}
+
+// occurrencesSince totals the occurrences params selects from the start of
+// the minute holding since, or the hour for windows over an hour.
+func occurrencesSince(ctx context.Context, api occurrenceCounter, params rollbar.CountReportParams, since time.Time, now time.Time) (uint64, error) {
+	bucket := time.Minute
+	if now.Sub(since) > time.Hour {
+		bucket = time.Hour
+	}
+	start := since.Truncate(bucket).Unix()
+
+	params.BucketSize = int(bucket / time.Second)
+	buckets, err := api.OccurrenceCounts(ctx, params)
+	if err != nil {
+		return 0, fmt.Errorf("get occurrence counts: %w", err)
+	}
+
+	total := uint64(0)
+	for _, counted := range buckets {
+		if counted.Timestamp <= math.MaxInt64 && int64(counted.Timestamp) >= start {
+			total += counted.Count
+		}
+	}
+
+	return total, nil
+}
@@ -0,0 +1,131 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type gateAPI struct {
+	pagedAPI
+	buckets []rollbar.CountBucket
+	params  []rollbar.CountReportParams
+}
+
+func (a *gateAPI) OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error) {
+	a.params = append(a.params, params)
+	return a.buckets, nil
+}
+
+// newGateAPI serves an old item and two items first seen a minute after
+// since, and occurrence counts of 100 before, 4 at and 3 after the minute
+// half an hour before now.
+func newGateAPI(now time.Time, since time.Time) *gateAPI {
+	minute := uint64(now.Add(-30 * time.Minute).Unix())
+	first, old := uint64(since.Unix())+60, uint64(since.Unix())-3600
+	few, many := uint64(2), uint64(40)
+
+	return &gateAPI{
+		pagedAPI: pagedAPI{pages: [][]rollbar.Item{{
+			{Counter: 1, Status: rollbar.StatusActive, FirstOccurrenceTimestamp: &old, TotalOccurrences: &many},
+			{Counter: 2, Status: rollbar.StatusActive, FirstOccurrenceTimestamp: &first, TotalOccurrences: &few},
+			{Counter: 3, Status: rollbar.StatusActive, FirstOccurrenceTimestamp: &first, TotalOccurrences: &many},
+		}}},
+		buckets: []rollbar.CountBucket{{Timestamp: minute - 60, Count: 100}, {Timestamp: minute, Count: 4}, {Timestamp: minute + 600, Count: 3}},
+	}
+}
+
+// checkGateResult fails unless result has the wanted outcome and checks and
+// lists the two new items, most frequent first.
+func checkGateResult(t *testing.T, name string, result GateResult, wantPassed bool, wantChecks []GateCheck) {
+	t.Helper()
+
+	if result.Passed != wantPassed || !slices.Equal(result.Checks, wantChecks) {
+		t.Fatalf("%s: CheckGate() = %+v", name, result)
+	}
+	if len(result.NewItems) != 2 || result.NewItems[0].Counter != 3 {
+		t.Fatalf("%s: new items = %+v", name, result.NewItems)
+	}
+}
+
+func TestCheckGate(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 12, 0, 0, 0, time.UTC)
+	since := now.Add(-30 * time.Minute).Add(20 * time.Second)
+	api := newGateAPI(now, since)
+
+	zero, ten := uint64(0), uint64(10)
+	tests := []struct {
+		name       string
+		options    GateOptions
+		wantPassed bool
+		wantChecks []GateCheck
+	}{
+		{
+			name:       "new items over",
+			options:    GateOptions{Environment: " staging ", Since: since, MaxNewItems: &zero, MaxOccurrences: &ten},
+			wantChecks: []GateCheck{{Name: GateNewItems, Value: 2, Max: 0}, {Name: GateOccurrences, Value: 7, Max: 10, Passed: true}},
+		},
+		{
+			name:       "occurrences only",
+			options:    GateOptions{Since: since, MaxOccurrences: &ten},
+			wantPassed: true,
+			wantChecks: []GateCheck{{Name: GateOccurrences, Value: 7, Max: 10, Passed: true}},
+		},
+	}
+	for _, tc := range tests {
+		result, err := CheckGate(context.Background(), api, tc.options, now)
+		if err != nil {
+			t.Fatalf("%s: CheckGate() error = %v", tc.name, err)
+		}
+		checkGateResult(t, tc.name, result, tc.wantPassed, tc.wantChecks)
+	}
+	if query := api.queries[0]; query.Status != "active" || !slices.Equal(query.Environments, []string{"staging"}) {
+		t.Fatalf("unexpected item query %+v", query)
+	}
+	if params := api.params[0]; params.BucketSize != 60 || params.Environment != "staging" {
+		t.Fatalf("unexpected count params %+v", params)
+	}
+}
+
+func TestCheckGateOptions(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 12, 0, 0, 0, time.UTC)
+	since := now.Add(-30 * time.Minute).Add(20 * time.Second)
+	api := newGateAPI(now, since)
+
+	ten := uint64(10)
+	if _, err := CheckGate(context.Background(), api, GateOptions{Since: since}, now); err == nil {
+		t.Fatal("expected an error without thresholds")
+	}
+	if _, err := CheckGate(context.Background(), api, GateOptions{Since: now.Add(-2 * time.Hour), MaxOccurrences: &ten}, now); err != nil || api.params[len(api.params)-1].BucketSize != 3600 {
+		t.Fatalf("expected hourly buckets for a long window, got %+v, %v", api.params, err)
+	}
+}
+
+func TestLatestFrames(t *testing.T) {
+	t.Parallel()
+
+	caller := rollbar.Frame{Filename: "app/routes.py", Method: "checkout", Lineno: 8}
+	failing := rollbar.Frame{Filename: "app/payments.py", Method: "charge", Lineno: 40}
+	api := &similarAPI{instances: map[domain.ItemID]*rollbar.ItemInstance{1: traceInstance(t, caller, failing)}}
+
+	located, err := LatestFrames(context.Background(), api, []IssueSummary{{ItemID: 1, Counter: 7}, {ItemID: 2, Counter: 8}})
+	if err != nil {
+		t.Fatalf("LatestFrames() error = %v", err)
+	}
+	if len(located) != 2 || located[0].Counter != 7 || len(located[0].Frames) != 2 || located[0].Frames[0] != failing || located[0].Exception != "TimeoutError" || len(located[1].Frames) != 0 {
+		t.Fatalf("LatestFrames() = %+v", located)
+	}
+
+	if _, err := LatestFrames(context.Background(), fakeAPI{err: errors.New("boom")}, []IssueSummary{{ItemID: 1, Counter: 7}}); err == nil {
+		t.Fatal("expected an error when the latest instance fails to load")
+	}
+}
@@ -0,0 +1,111 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/url"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// ServiceLinkAPI is the part of the Rollbar client that manages service links.
+type ServiceLinkAPI interface {
+	ListServiceLinks(ctx context.Context) ([]rollbar.ServiceLink, error)
+	CreateServiceLink(ctx context.Context, name string, template string) (rollbar.ServiceLink, error)
+	DeleteServiceLink(ctx context.Context, linkID uint64) error
+}
+
+// ListServiceLinks lists the project's service links by name.
+func ListServiceLinks(ctx context.Context, api ServiceLinkAPI) ([]rollbar.ServiceLink, error) {
+	links, err := api.ListServiceLinks(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("list service links: %w", err)
+	}
+	slices.SortStableFunc(links, func(left rollbar.ServiceLink, right rollbar.ServiceLink) int {
+		return strings.Compare(strings.ToLower(left.Name), strings.ToLower(right.Name))
+	})
+
+	return links, nil
+}
+
+// AddServiceLink creates a service link after checking its template. Names
+// must be unique, since rm accepts a name.
+func AddServiceLink(ctx context.Context, api ServiceLinkAPI, name string, template string) (rollbar.ServiceLink, error) {
+	name, template = strings.TrimSpace(name), strings.TrimSpace(template)
+	if name == "" {
+		return rollbar.ServiceLink{}, errors.New("service link name is required")
+	}
+	if err := ValidateLinkTemplate(template); err != nil {
+		return rollbar.ServiceLink{}, err
+	}
+
+	links, err := api.ListServiceLinks(ctx)
+	if err != nil {
+		return rollbar.ServiceLink{}, fmt.Errorf("list service links: %w", err)
+	}
+	if index := slices.IndexFunc(links, func(link rollbar.ServiceLink) bool { return strings.EqualFold(link.Name, name) }); index >= 0 {
+		return rollbar.ServiceLink{}, fmt.Errorf("service link %q already exists (id %d); remove it first", links[index].Name, links[index].ID)
+	}
+
+	link, err := api.CreateServiceLink(ctx, name, template)
+	if err != nil {
+		return rollbar.ServiceLink{}, fmt.Errorf("create service link %s: %w", name, err)
+	}
+
+	return link, nil
+}
+
+// ValidateLinkTemplate checks that template is an http(s) URL once its
+// {{ }} placeholders are filled in.
+func ValidateLinkTemplate(template string) error {
+	if template == "" {
+		return errors.New("service link URL template is required")
+	}
+	filled, rest := "", template
+	for {
+		before, after, found := strings.Cut(rest, "{{")
+		filled += before
+		if !found {
+			break
+		}
+		placeholder, remaining, closed := strings.Cut(after, "}}")
+		if !closed || strings.TrimSpace(placeholder) == "" {
+			return fmt.Errorf("invalid URL template %q: every {{ needs a name and a closing }}", template)
+		}
+		filled, rest = filled+"x", remaining
+	}
+
+	parsed, err := url.Parse(filled)
+	if err != nil || (parsed.Scheme != "http" && parsed.Scheme != "https") || parsed.Host == "" {
+		return fmt.Errorf("invalid URL template %q: use an http or https URL", template)
+	}
+
+	return nil
+}
+
+// RemoveServiceLink deletes the service link ref names, by id or by name.
+func RemoveServiceLink(ctx context.Context, api ServiceLinkAPI, ref string) (rollbar.ServiceLink, error) {
+	ref = strings.TrimSpace(ref)
+	links, err := api.ListServiceLinks(ctx)
+	if err != nil {
+		return rollbar.ServiceLink{}, fmt.Errorf("list service links: %w", err)
+	}
+	id, idErr := strconv.ParseUint(ref, 10, 64)
+	index := slices.IndexFunc(links, func(link rollbar.ServiceLink) bool {
+		return (idErr == nil && link.ID == id) || strings.EqualFold(link.Name, ref)
+	})
+	if index < 0 {
+		return rollbar.ServiceLink{}, fmt.Errorf("no service link %q", ref)
+	}
+
+	link := links[index]
+	if err := api.DeleteServiceLink(ctx, link.ID); err != nil {
+		return rollbar.ServiceLink{}, fmt.Errorf("delete service link %d: %w", link.ID, err)
+	}
+
+	return link, nil
+}
@@ -0,0 +1,114 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeServiceLinkAPI struct {
+	links   []rollbar.ServiceLink
+	created []rollbar.ServiceLink
+	deleted []uint64
+	err     error
+}
+
+func (f *fakeServiceLinkAPI) ListServiceLinks(ctx context.Context) ([]rollbar.ServiceLink, error) {
+	if f.err != nil {
+		return nil, f.err
+	}
+	return append([]rollbar.ServiceLink{}, f.links...), nil
+}
+
+func (f *fakeServiceLinkAPI) CreateServiceLink(ctx context.Context, name string, template string) (rollbar.ServiceLink, error) {
+	link := rollbar.ServiceLink{ID: 10, Name: name, Template: template}
+	f.created = append(f.created, link)
+	return link, nil
+}
+
+func (f *fakeServiceLinkAPI) DeleteServiceLink(ctx context.Context, linkID uint64) error {
+	f.deleted = append(f.deleted, linkID)
+	return nil
+}
+
+func serviceLinkFixture() []rollbar.ServiceLink {
+	return []rollbar.ServiceLink{
+		{ID: 2, Name: "runbook", Template: "https://runbooks.example.com/{{ title }}"},
+		{ID: 1, Name: "Logs", Template: "https://logs.example.com/?env={{environment}}"},
+	}
+}
+
+func TestListServiceLinks(t *testing.T) {
+	t.Parallel()
+
+	links, err := ListServiceLinks(context.Background(), &fakeServiceLinkAPI{links: serviceLinkFixture()})
+	if err != nil || len(links) != 2 || links[0].Name != "Logs" {
+		t.Fatalf("ListServiceLinks() = %+v, %v", links, err)
+	}
+	if _, err := ListServiceLinks(context.Background(), &fakeServiceLinkAPI{err: errors.New("boom")}); err == nil || !strings.Contains(err.Error(), "list service links") {
+		t.Fatalf("expected a list error, got %v", err)
+	}
+}
+
+func TestAddServiceLink(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		linkName string
+		template string
+		wantErr  string
+	}{
+		{name: "valid", linkName: " Dashboard ", template: "https://grafana.example.com/d/api?var-env={{ environment }}&var-host={{ server.host }}"},
+		{name: "no name", template: "https://example.com", wantErr: "name is required"},
+		{name: "no template", linkName: "Dashboard", wantErr: "URL template is required"},
+		{name: "unclosed", linkName: "Dashboard", template: "https://example.com/{{ title", wantErr: "closing }}"},
+		{name: "empty placeholder", linkName: "Dashboard", template: "https://example.com/{{ }}", wantErr: "needs a name"},
+		{name: "scheme", linkName: "Dashboard", template: "javascript:alert({{ title }})", wantErr: "http or https URL"},
+		{name: "placeholder host", linkName: "Dashboard", template: "https://{{ server.host }}/status"},
+		{name: "duplicate", linkName: "RUNBOOK", template: "https://example.com", wantErr: `service link "runbook" already exists (id 2)`},
+	}
+	for _, tc := range tests {
+		api := &fakeServiceLinkAPI{links: serviceLinkFixture()}
+		link, err := AddServiceLink(context.Background(), api, tc.linkName, tc.template)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) || len(api.created) != 0 {
+				t.Fatalf("%s: expected %q error, got %v", tc.name, tc.wantErr, err)
+			}
+			continue
+		}
+		if err != nil || link.ID != 10 || link.Name != strings.TrimSpace(tc.linkName) {
+			t.Fatalf("%s: AddServiceLink() = %+v, %v", tc.name, link, err)
+		}
+	}
+}
+
+func TestRemoveServiceLink(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		ref     string
+		want    uint64
+		wantErr string
+	}{
+		{ref: "2", want: 2},
+		{ref: "logs", want: 1},
+		{ref: "missing", wantErr: `no service link "missing"`},
+	}
+	for _, tc := range tests {
+		api := &fakeServiceLinkAPI{links: serviceLinkFixture()}
+		link, err := RemoveServiceLink(context.Background(), api, tc.ref)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("%s: expected %q error, got %v", tc.ref, tc.wantErr, err)
+			}
+			continue
+		}
+		if err != nil || link.ID != tc.want || len(api.deleted) != 1 || api.deleted[0] != tc.want {
+			t.Fatalf("%s: RemoveServiceLink() = %+v, %v (deleted %v)", tc.ref, link, err, api.deleted)
+		}
+	}
+}
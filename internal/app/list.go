@@ -0,0 +1,172 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type ItemSort string
+
+const (
+	SortLastSeen    ItemSort = "last-seen"
+	SortOccurrences ItemSort = "occurrences"
+	SortCounter     ItemSort = "counter"
+	SortLevel       ItemSort = "level"
+)
+
+// maxListPages bounds how many /items pages one listing may fetch.
+const maxListPages = 10
+
+type ListOptions struct {
+	Filters IssueFilters
+	Levels  []string
+	// Query is Rollbar's free-text item search.
+	Query     string
+	Sort      ItemSort
+	Ascending bool
+	Limit     int
+	// Complete fails the listing when more items remain after maxListPages
+	// pages, instead of returning what those pages held.
+	Complete bool
+	// Columns are read from each listed item's latest occurrence.
+	Columns []Column
+}
+
+func ParseItemSort(value string) (ItemSort, error) {
+	switch sortKey := ItemSort(strings.TrimSpace(strings.ToLower(value))); sortKey {
+	case "":
+		return SortLastSeen, nil
+	case SortLastSeen, SortOccurrences, SortCounter, SortLevel:
+		return sortKey, nil
+	default:
+		return "", fmt.Errorf("unsupported sort %q (use last-seen, occurrences, counter, or level)", value)
+	}
+}
+
+// List pages through /items with status, level and environment pushed to the
+// API, applies the remaining filters locally, and sorts what was fetched.
+func (s *Service) List(ctx context.Context, options ListOptions) ([]IssueSummary, error) {
+	filters := normalizeIssueFilters(options.Filters)
+	query := listQuery(options, filters)
+
+	items, complete, err := s.listPages(ctx, query, filters, options.Limit)
+	if err != nil {
+		return nil, err
+	}
+	if !complete && options.Complete {
+		return nil, fmt.Errorf("list items: more than %d pages of items match; narrow the filters", maxListPages)
+	}
+
+	sortItems(items, options.Sort, options.Ascending)
+	if options.Limit > 0 && len(items) > options.Limit {
+		items = items[:options.Limit]
+	}
+
+	issues := mapSummaries(items)
+	if err := s.itemColumns(ctx, issues, options.Columns); err != nil {
+		return nil, err
+	}
+
+	return issues, nil
+}
+
+// itemPager lists one /items page, like the Rollbar client and the local
+// store do.
+type itemPager interface {
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+}
+
+// scanItems pages through every item matching query and keeps the ones keep
+// accepts, for checks that cannot be pushed to the API.
+func scanItems(ctx context.Context, pager itemPager, query rollbar.ItemQuery, keep func(rollbar.Item) bool) ([]rollbar.Item, error) {
+	kept := make([]rollbar.Item, 0)
+	for page := 1; ; page++ {
+		query.Page = page
+		result, err := pager.ListItemsPage(ctx, query)
+		if err != nil {
+			return nil, fmt.Errorf("list items: %w", err)
+		}
+		for _, item := range result.Items {
+			if keep(item) {
+				kept = append(kept, item)
+			}
+		}
+		if len(result.Items) == 0 || listComplete(result, page, 0, 0) {
+			return kept, nil
+		}
+	}
+}
+
+// listQuery pushes the filters the /items endpoint understands to the API.
+func listQuery(options ListOptions, filters IssueFilters) rollbar.ItemQuery {
+	query := rollbar.ItemQuery{Status: filters.Status, Levels: options.Levels, Query: strings.TrimSpace(options.Query)}
+	if filters.Environment != "" {
+		query.Environments = []string{filters.Environment}
+	}
+
+	return query
+}
+
+// listPages fetches up to maxListPages pages and reports whether they held
+// every matching item.
+func (s *Service) listPages(ctx context.Context, query rollbar.ItemQuery, filters IssueFilters, limit int) ([]rollbar.Item, bool, error) {
+	items := make([]rollbar.Item, 0)
+	for page := 1; page <= maxListPages; page++ {
+		query.Page = page
+		result, err := s.api.ListItemsPage(ctx, query)
+		if err != nil {
+			return nil, false, fmt.Errorf("list items: %w", err)
+		}
+		items = append(items, filterItems(result.Items, filters)...)
+		if len(result.Items) == 0 || listComplete(result, page, len(items), limit) {
+			return items, true, nil
+		}
+	}
+
+	return items, false, nil
+}
+
+func listComplete(result rollbar.ItemPage, page int, collected int, limit int) bool {
+	if limit > 0 && collected >= limit {
+		return true
+	}
+
+	return result.TotalCount > 0 && uint64(page*len(result.Items)) >= result.TotalCount
+}
+
+func sortItems(items []rollbar.Item, sortKey ItemSort, ascending bool) {
+	less := itemLess(sortKey)
+	sort.SliceStable(items, func(i int, j int) bool {
+		if ascending {
+			return less(items[j], items[i])
+		}
+		return less(items[i], items[j])
+	})
+}
+
+// itemLess orders items "largest first": newest, most frequent, highest
+// counter, or most severe.
+func itemLess(sortKey ItemSort) func(rollbar.Item, rollbar.Item) bool {
+	switch sortKey {
+	case SortOccurrences:
+		return func(left rollbar.Item, right rollbar.Item) bool {
+			return totalOccurrences(left) > totalOccurrences(right)
+		}
+	case SortCounter:
+		return func(left rollbar.Item, right rollbar.Item) bool {
+			return left.Counter > right.Counter
+		}
+	case SortLevel:
+		return func(left rollbar.Item, right rollbar.Item) bool {
+			return left.Level.Severity() > right.Level.Severity()
+		}
+	default:
+		return func(left rollbar.Item, right rollbar.Item) bool {
+			return uint64Value(left.LastOccurrenceTimestamp) > uint64Value(right.LastOccurrenceTimestamp)
+		}
+	}
+}
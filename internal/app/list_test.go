@@ -0,0 +1,210 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type pagedAPI struct {
+	fakeAPI
+	pages   [][]rollbar.Item
+	total   uint64
+	queries []rollbar.ItemQuery
+}
+
+func (p *pagedAPI) ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error) {
+	p.queries = append(p.queries, query)
+	if query.Page > len(p.pages) {
+		return rollbar.ItemPage{Page: query.Page, TotalCount: p.total}, nil
+	}
+
+	return rollbar.ItemPage{Items: p.pages[query.Page-1], Page: query.Page, TotalCount: p.total}, nil
+}
+
+// fixture is the part of an item a test cares about; issueFixture fills in
+// the rest.
+type fixture struct {
+	counter     uint64
+	title       string
+	environment string
+	level       rollbar.Level
+	status      rollbar.Status
+	firstSeen   uint64
+	lastSeen    uint64
+	activated   uint64
+	resolved    uint64
+	occurrences uint64
+}
+
+// issueFixture builds the item f describes. Its ID is its counter, it is in
+// production unless f names another environment, and zero times and counts
+// stay unset, as Rollbar leaves them out.
+func issueFixture(f fixture) rollbar.Item {
+	environment := f.environment
+	if environment == "" {
+		environment = "production"
+	}
+
+	return rollbar.Item{
+		ID:                       domain.ItemID(f.counter),
+		Counter:                  f.counter,
+		Title:                    f.title,
+		Environment:              environment,
+		Level:                    f.level,
+		Status:                   f.status,
+		FirstOccurrenceTimestamp: optionalUint64(f.firstSeen),
+		LastOccurrenceTimestamp:  optionalUint64(f.lastSeen),
+		LastActivatedTimestamp:   optionalUint64(f.activated),
+		LastResolvedTimestamp:    optionalUint64(f.resolved),
+		TotalOccurrences:         optionalUint64(f.occurrences),
+	}
+}
+
+// optionalUint64 is nil for zero.
+func optionalUint64(value uint64) *uint64 {
+	if value == 0 {
+		return nil
+	}
+
+	return &value
+}
+
+func TestServiceList(t *testing.T) {
+	t.Parallel()
+
+	pages := [][]rollbar.Item{
+		{
+			issueFixture(fixture{counter: 1, level: rollbar.LevelWarning, lastSeen: 300, occurrences: 5}),
+			issueFixture(fixture{counter: 2, level: rollbar.LevelCritical, lastSeen: 100, occurrences: 50}),
+		},
+		{issueFixture(fixture{counter: 3, level: rollbar.LevelError, lastSeen: 200, occurrences: 1})},
+	}
+
+	tests := []struct {
+		name      string
+		options   ListOptions
+		want      []uint64
+		wantPages int
+	}{
+		{name: "last seen", options: ListOptions{Sort: SortLastSeen}, want: []uint64{1, 3, 2}, wantPages: 3},
+		{name: "occurrences", options: ListOptions{Sort: SortOccurrences}, want: []uint64{2, 1, 3}, wantPages: 3},
+		{name: "counter ascending", options: ListOptions{Sort: SortCounter, Ascending: true}, want: []uint64{1, 2, 3}, wantPages: 3},
+		{name: "level", options: ListOptions{Sort: SortLevel}, want: []uint64{2, 3, 1}, wantPages: 3},
+		{name: "limit stops paging", options: ListOptions{Limit: 2}, want: []uint64{1, 2}, wantPages: 1},
+		{name: "local filter", options: ListOptions{Filters: IssueFilters{MinOccurrences: uint64Ptr(5)}}, want: []uint64{1, 2}, wantPages: 3},
+	}
+
+	for _, tc := range tests {
+		api := &pagedAPI{pages: pages}
+		issues, err := NewService(api).List(context.Background(), tc.options)
+		if err != nil {
+			t.Fatalf("%s: List() error = %v", tc.name, err)
+		}
+		if got := summaryCounters(issues); !slices.Equal(got, tc.want) {
+			t.Fatalf("%s: List() counters = %v, want %v", tc.name, got, tc.want)
+		}
+		if len(api.queries) != tc.wantPages {
+			t.Fatalf("%s: fetched %d pages, want %d", tc.name, len(api.queries), tc.wantPages)
+		}
+	}
+}
+
+func TestServiceListPushesFiltersToQuery(t *testing.T) {
+	t.Parallel()
+
+	api := &pagedAPI{pages: [][]rollbar.Item{{issueFixture(fixture{counter: 1, level: rollbar.LevelError, lastSeen: 1, occurrences: 1})}}, total: 1}
+	options := ListOptions{Filters: IssueFilters{Environment: " production ", Status: "active"}, Levels: []string{"error"}}
+	if _, err := NewService(api).List(context.Background(), options); err != nil {
+		t.Fatalf("List() error = %v", err)
+	}
+
+	if len(api.queries) != 1 {
+		t.Fatalf("expected total count to stop paging, got %d queries", len(api.queries))
+	}
+	query := api.queries[0]
+	if query.Status != "active" || len(query.Environments) != 1 || query.Environments[0] != "production" || query.Levels[0] != "error" {
+		t.Fatalf("unexpected query: %+v", query)
+	}
+}
+
+func TestServiceListPassesQuery(t *testing.T) {
+	t.Parallel()
+
+	api := &pagedAPI{}
+	if _, err := NewService(api).List(context.Background(), ListOptions{Query: " payment timeout "}); err != nil {
+		t.Fatalf("List() error = %v", err)
+	}
+	if api.queries[0].Query != "payment timeout" {
+		t.Fatalf("unexpected query: %+v", api.queries[0])
+	}
+}
+
+func TestServiceListComplete(t *testing.T) {
+	t.Parallel()
+
+	pages := make([][]rollbar.Item, maxListPages+1)
+	for index := range pages {
+		pages[index] = []rollbar.Item{issueFixture(fixture{counter: uint64(index + 1)})}
+	}
+
+	issues, err := NewService(&pagedAPI{pages: pages}).List(context.Background(), ListOptions{})
+	if err != nil || len(issues) != maxListPages {
+		t.Fatalf("List() = %d issues, %v; want the first %d pages", len(issues), err, maxListPages)
+	}
+	_, err = NewService(&pagedAPI{pages: pages}).List(context.Background(), ListOptions{Complete: true})
+	if err == nil || !strings.Contains(err.Error(), "narrow the filters") {
+		t.Fatalf("expected a truncated listing error, got %v", err)
+	}
+	if _, err := NewService(&pagedAPI{pages: pages[:maxListPages], total: maxListPages}).List(context.Background(), ListOptions{Complete: true}); err != nil {
+		t.Fatalf("List() error = %v", err)
+	}
+}
+
+func TestServiceListError(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewService(fakeAPI{err: errors.New("bad")}).List(context.Background(), ListOptions{}); err == nil {
+		t.Fatalf("expected List error")
+	}
+}
+
+func TestParseItemSort(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		value   string
+		want    ItemSort
+		wantErr bool
+	}{
+		{value: "", want: SortLastSeen},
+		{value: "Occurrences", want: SortOccurrences},
+		{value: "level", want: SortLevel},
+		{value: "title", wantErr: true},
+	}
+
+	for _, tc := range tests {
+		got, err := ParseItemSort(tc.value)
+		if (err != nil) != tc.wantErr || got != tc.want {
+			t.Fatalf("ParseItemSort(%q) = %q, %v", tc.value, got, err)
+		}
+	}
+}
+
+func summaryCounters(issues []IssueSummary) []uint64 {
+	counters := make([]uint64, 0, len(issues))
+	for _, issue := range issues {
+		counters = append(counters, uint64(issue.Counter))
+	}
+
+	return counters
+}
+
+func uint64Ptr(value uint64) *uint64 {
+	return &value
+}
@@ -0,0 +1,51 @@
+package app
+
+import (
+	"context"
+	"sort"
+	"time"
+)
+
+// ItemMetrics is one scrape of active items for the exporter.
+type ItemMetrics struct {
+	Items []IssueSummary
+	// Active counts the scraped items by level and environment.
+	Active    []ActiveItemCount
+	ScrapedAt time.Time
+}
+
+type ActiveItemCount struct {
+	Level       string
+	Environment string
+	Count       int
+}
+
+// CollectItemMetrics lists active items matching options, up to the listing
+// page cap, and counts them by level and environment.
+func (s *Service) CollectItemMetrics(ctx context.Context, options ListOptions, now time.Time) (ItemMetrics, error) {
+	options.Filters.Status = "active"
+	options.Sort = SortCounter
+	options.Limit = 0
+	items, err := s.List(ctx, options)
+	if err != nil {
+		return ItemMetrics{}, err
+	}
+
+	counts := map[ActiveItemCount]int{}
+	for _, item := range items {
+		counts[ActiveItemCount{Level: item.Level, Environment: item.Environment}]++
+	}
+	active := make([]ActiveItemCount, 0, len(counts))
+	for key, count := range counts {
+		key.Count = count
+		active = append(active, key)
+	}
+	sort.Slice(active, func(i, j int) bool {
+		if active[i].Level != active[j].Level {
+			return active[i].Level < active[j].Level
+		}
+		return active[i].Environment < active[j].Environment
+	})
+
+	return ItemMetrics{Items: items, Active: active, ScrapedAt: now}, nil
+}
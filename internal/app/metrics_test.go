@@ -0,0 +1,48 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestCollectItemMetrics(t *testing.T) {
+	t.Parallel()
+
+	items := []rollbar.Item{
+		issueFixture(fixture{counter: 1, level: rollbar.LevelError, lastSeen: 100, occurrences: 5}),
+		issueFixture(fixture{counter: 2, level: rollbar.LevelError, lastSeen: 100, occurrences: 7}),
+		issueFixture(fixture{counter: 3, level: rollbar.LevelWarning, lastSeen: 100, occurrences: 1}),
+		issueFixture(fixture{counter: 4, level: rollbar.LevelError, lastSeen: 100, occurrences: 2}),
+	}
+	items[3].Environment = "staging"
+	for i := range items {
+		items[i].Status = rollbar.StatusActive
+	}
+	api := &pagedAPI{pages: [][]rollbar.Item{items}}
+	now := time.Unix(1700000000, 0)
+
+	metrics, err := NewService(api).CollectItemMetrics(context.Background(), ListOptions{Limit: 2}, now)
+	if err != nil {
+		t.Fatalf("CollectItemMetrics() error = %v", err)
+	}
+	want := []ActiveItemCount{
+		{Level: "error", Environment: "production", Count: 2},
+		{Level: "error", Environment: "staging", Count: 1},
+		{Level: "warning", Environment: "production", Count: 1},
+	}
+	if len(metrics.Items) != 4 || !reflect.DeepEqual(metrics.Active, want) || !metrics.ScrapedAt.Equal(now) {
+		t.Fatalf("unexpected metrics: %+v", metrics)
+	}
+	if api.queries[0].Status != "active" {
+		t.Fatalf("expected an active-only query, got %+v", api.queries[0])
+	}
+
+	if _, err := NewService(fakeAPI{err: errors.New("bad")}).CollectItemMetrics(context.Background(), ListOptions{}, now); err == nil {
+		t.Fatal("expected list error")
+	}
+}
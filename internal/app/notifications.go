@@ -0,0 +1,166 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// NotificationAPI is the part of the Rollbar client that manages notification
+// rules.
+type NotificationAPI interface {
+	ListNotificationRules(ctx context.Context, channel string) ([]rollbar.NotificationRule, error)
+	CreateNotificationRules(ctx context.Context, channel string, rules []rollbar.NotificationRule) ([]rollbar.NotificationRule, error)
+	DeleteNotificationRule(ctx context.Context, channel string, ruleID uint64) error
+}
+
+// ChannelRules are the notification rules of one channel. A list of them is
+// what notifications list prints as JSON and what notifications add --file
+// reads, so rules can be copied between projects.
+type ChannelRules struct {
+	Channel string                     `json:"channel"`
+	Rules   []rollbar.NotificationRule `json:"rules"`
+}
+
+// ParseNotificationChannel validates a notification channel name.
+func ParseNotificationChannel(value string) (string, error) {
+	channel := strings.ToLower(strings.TrimSpace(value))
+	for _, known := range rollbar.NotificationChannels {
+		if channel == known {
+			return channel, nil
+		}
+	}
+
+	return "", fmt.Errorf("unsupported notification channel %q (use %s)", value, strings.Join(rollbar.NotificationChannels, ", "))
+}
+
+// ParseNotificationFilter reads a filter written type:operation:value, such
+// as level:gte:error, or type=value for an eq comparison. Rate filters are
+// rate:period:count, counting occurrences in period seconds.
+func ParseNotificationFilter(value string) (rollbar.NotificationFilter, error) {
+	if filterType, filterValue, ok := strings.Cut(value, "="); ok && !strings.Contains(filterType, ":") {
+		return comparisonFilter(filterType, "eq", filterValue, value)
+	}
+
+	parts := strings.SplitN(value, ":", 3)
+	if len(parts) != 3 {
+		return rollbar.NotificationFilter{}, fmt.Errorf("invalid filter %q (use type:operation:value or type=value)", value)
+	}
+	if strings.TrimSpace(parts[0]) != "rate" {
+		return comparisonFilter(parts[0], parts[1], parts[2], value)
+	}
+
+	period, periodErr := strconv.Atoi(strings.TrimSpace(parts[1]))
+	count, countErr := strconv.Atoi(strings.TrimSpace(parts[2]))
+	if periodErr != nil || countErr != nil || period <= 0 || count <= 0 {
+		return rollbar.NotificationFilter{}, fmt.Errorf("invalid rate filter %q (use rate:period-seconds:count)", value)
+	}
+
+	return rollbar.NotificationFilter{Type: "rate", Period: period, Count: count}, nil
+}
+
+func comparisonFilter(filterType string, operation string, value string, raw string) (rollbar.NotificationFilter, error) {
+	filter := rollbar.NotificationFilter{
+		Type:      strings.TrimSpace(filterType),
+		Operation: strings.ToLower(strings.TrimSpace(operation)),
+		Value:     strings.TrimSpace(value),
+	}
+	if filter.Type == "" || filter.Operation == "" || filter.Value == "" {
+		return rollbar.NotificationFilter{}, fmt.Errorf("invalid filter %q (use type:operation:value or type=value)", raw)
+	}
+
+	return filter, nil
+}
+
+// ListNotificationRules loads the rules of channel, or of every channel when
+// it is empty, in the order Rollbar's settings page shows them.
+func ListNotificationRules(ctx context.Context, api NotificationAPI, channel string) ([]ChannelRules, error) {
+	channels := rollbar.NotificationChannels
+	if strings.TrimSpace(channel) != "" {
+		parsed, err := ParseNotificationChannel(channel)
+		if err != nil {
+			return nil, err
+		}
+		channels = []string{parsed}
+	}
+
+	listed := make([]ChannelRules, 0, len(channels))
+	for _, name := range channels {
+		rules, err := api.ListNotificationRules(ctx, name)
+		if err != nil {
+			return nil, fmt.Errorf("list %s notification rules: %w", name, err)
+		}
+		listed = append(listed, ChannelRules{Channel: name, Rules: rules})
+	}
+
+	return listed, nil
+}
+
+// AddNotificationRules creates each channel's rules alongside the rules
+// already there and returns them as created. Every channel is validated
+// before anything is written; rule ids are ignored. When a channel fails,
+// the channels already created are returned with the error.
+func AddNotificationRules(ctx context.Context, api NotificationAPI, add []ChannelRules) ([]ChannelRules, error) {
+	pending := make([]ChannelRules, 0, len(add))
+	for _, channelRules := range add {
+		channel, err := ParseNotificationChannel(channelRules.Channel)
+		if err != nil {
+			return nil, err
+		}
+		if err := checkRedactedConfig(channel, channelRules.Rules); err != nil {
+			return nil, err
+		}
+		if len(channelRules.Rules) > 0 {
+			pending = append(pending, ChannelRules{Channel: channel, Rules: channelRules.Rules})
+		}
+	}
+	if len(pending) == 0 {
+		return nil, errors.New("no notification rules to add")
+	}
+
+	created := make([]ChannelRules, 0, len(pending))
+	for _, channelRules := range pending {
+		rules, err := api.CreateNotificationRules(ctx, channelRules.Channel, channelRules.Rules)
+		if err != nil {
+			return created, fmt.Errorf("create %s notification rules: %w", channelRules.Channel, err)
+		}
+		created = append(created, ChannelRules{Channel: channelRules.Channel, Rules: rules})
+	}
+
+	return created, nil
+}
+
+// checkRedactedConfig refuses rules copied from notifications list output
+// whose credentials were masked there, so they are not written as the mask.
+func checkRedactedConfig(channel string, rules []rollbar.NotificationRule) error {
+	for _, rule := range rules {
+		for key, value := range rule.Config {
+			if value == redact.Placeholder {
+				return fmt.Errorf("%s %s rule config %q is %s; fill in the real value", channel, rule.Trigger, key, redact.Placeholder)
+			}
+		}
+	}
+
+	return nil
+}
+
+// RemoveNotificationRule deletes a rule from a channel.
+func RemoveNotificationRule(ctx context.Context, api NotificationAPI, channel string, ruleID uint64) error {
+	parsed, err := ParseNotificationChannel(channel)
+	if err != nil {
+		return err
+	}
+	if ruleID == 0 {
+		return errors.New("notification rule id is required")
+	}
+	if err := api.DeleteNotificationRule(ctx, parsed, ruleID); err != nil {
+		return fmt.Errorf("delete %s notification rule %d: %w", parsed, ruleID, err)
+	}
+
+	return nil
+}
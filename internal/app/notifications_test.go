@@ -0,0 +1,162 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeNotificationAPI struct {
+	rules   map[string][]rollbar.NotificationRule
+	created map[string][]rollbar.NotificationRule
+	deleted []string
+	err     error
+}
+
+func (f *fakeNotificationAPI) ListNotificationRules(ctx context.Context, channel string) ([]rollbar.NotificationRule, error) {
+	if f.err != nil {
+		return nil, f.err
+	}
+	return append([]rollbar.NotificationRule{}, f.rules[channel]...), nil
+}
+
+func (f *fakeNotificationAPI) CreateNotificationRules(ctx context.Context, channel string, rules []rollbar.NotificationRule) ([]rollbar.NotificationRule, error) {
+	if f.err != nil {
+		return nil, f.err
+	}
+	if f.created == nil {
+		f.created = map[string][]rollbar.NotificationRule{}
+	}
+	f.created[channel] = append(f.created[channel], rules...)
+	return rules, nil
+}
+
+func (f *fakeNotificationAPI) DeleteNotificationRule(ctx context.Context, channel string, ruleID uint64) error {
+	if f.err != nil {
+		return f.err
+	}
+	f.deleted = append(f.deleted, channel)
+	return nil
+}
+
+func TestParseNotificationFilter(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		value   string
+		want    rollbar.NotificationFilter
+		wantErr string
+	}{
+		{value: "environment=production", want: rollbar.NotificationFilter{Type: "environment", Operation: "eq", Value: "production"}},
+		{value: "level:GTE:error", want: rollbar.NotificationFilter{Type: "level", Operation: "gte", Value: "error"}},
+		{value: "title:regex:^Timeout: db", want: rollbar.NotificationFilter{Type: "title", Operation: "regex", Value: "^Timeout: db"}},
+		{value: "rate:300:10", want: rollbar.NotificationFilter{Type: "rate", Period: 300, Count: 10}},
+		{value: "rate:five:10", wantErr: "invalid rate filter"},
+		{value: "level:gte", wantErr: "invalid filter"},
+		{value: "environment=", wantErr: "invalid filter"},
+	}
+	for _, tc := range tests {
+		got, err := ParseNotificationFilter(tc.value)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("%q: expected %q error, got %v", tc.value, tc.wantErr, err)
+			}
+			continue
+		}
+		if err != nil || got != tc.want {
+			t.Fatalf("%q: got %+v, %v; want %+v", tc.value, got, err, tc.want)
+		}
+	}
+}
+
+func TestListNotificationRules(t *testing.T) {
+	t.Parallel()
+
+	api := &fakeNotificationAPI{rules: map[string][]rollbar.NotificationRule{
+		rollbar.NotificationSlack: {{ID: 5, Trigger: "new_item"}},
+	}}
+
+	all, err := ListNotificationRules(context.Background(), api, "")
+	if err != nil || len(all) != len(rollbar.NotificationChannels) || all[0].Channel != rollbar.NotificationEmail || len(all[1].Rules) != 1 {
+		t.Fatalf("ListNotificationRules() = %+v, %v", all, err)
+	}
+
+	one, err := ListNotificationRules(context.Background(), api, " Slack ")
+	if err != nil || len(one) != 1 || one[0].Channel != rollbar.NotificationSlack {
+		t.Fatalf("ListNotificationRules(slack) = %+v, %v", one, err)
+	}
+}
+
+func TestListNotificationRulesErrors(t *testing.T) {
+	t.Parallel()
+
+	api := &fakeNotificationAPI{}
+
+	if _, err := ListNotificationRules(context.Background(), api, "sms"); err == nil || !strings.Contains(err.Error(), "unsupported notification channel") {
+		t.Fatalf("expected a channel error, got %v", err)
+	}
+	api.err = errors.New("boom")
+	if _, err := ListNotificationRules(context.Background(), api, ""); err == nil || !strings.Contains(err.Error(), "list email notification rules") {
+		t.Fatalf("expected a list error, got %v", err)
+	}
+}
+
+func TestAddNotificationRules(t *testing.T) {
+	t.Parallel()
+
+	api := &fakeNotificationAPI{}
+	created, err := AddNotificationRules(context.Background(), api, []ChannelRules{
+		{Channel: "SLACK", Rules: []rollbar.NotificationRule{{Trigger: "new_item"}}},
+		{Channel: "email"},
+	})
+	if err != nil || len(created) != 1 || created[0].Channel != rollbar.NotificationSlack || len(api.created[rollbar.NotificationSlack]) != 1 {
+		t.Fatalf("AddNotificationRules() = %+v, %v", created, err)
+	}
+}
+
+func TestAddNotificationRulesErrors(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name    string
+		add     []ChannelRules
+		wantErr string
+	}{
+		{name: "nothing", add: []ChannelRules{{Channel: "email"}}, wantErr: "no notification rules"},
+		{name: "redacted", add: []ChannelRules{{Channel: "pagerduty", Rules: []rollbar.NotificationRule{{Trigger: "deploy", Config: map[string]any{"service_key": "[REDACTED]"}}}}}, wantErr: `config "service_key" is [REDACTED]`},
+		{name: "channel", add: []ChannelRules{{Channel: "sms", Rules: []rollbar.NotificationRule{{Trigger: "deploy"}}}}, wantErr: "unsupported notification channel"},
+	}
+	for _, tc := range tests {
+		api := &fakeNotificationAPI{}
+		if _, err := AddNotificationRules(context.Background(), api, tc.add); err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+			t.Fatalf("%s: expected %q error, got %v", tc.name, tc.wantErr, err)
+		}
+		if len(api.created) != 0 {
+			t.Fatalf("%s: expected nothing created, got %+v", tc.name, api.created)
+		}
+	}
+
+	failing := &fakeNotificationAPI{err: errors.New("boom")}
+	if _, err := AddNotificationRules(context.Background(), failing, []ChannelRules{{Channel: "slack", Rules: []rollbar.NotificationRule{{Trigger: "deploy"}}}}); err == nil || !strings.Contains(err.Error(), "create slack notification rules") {
+		t.Fatalf("expected a create error, got %v", err)
+	}
+}
+
+func TestRemoveNotificationRule(t *testing.T) {
+	t.Parallel()
+
+	api := &fakeNotificationAPI{}
+	if err := RemoveNotificationRule(context.Background(), api, "PagerDuty", 9); err != nil || len(api.deleted) != 1 || api.deleted[0] != rollbar.NotificationPagerDuty {
+		t.Fatalf("RemoveNotificationRule() = %v, deleted %v", err, api.deleted)
+	}
+	if err := RemoveNotificationRule(context.Background(), api, "slack", 0); err == nil {
+		t.Fatal("expected an error without a rule id")
+	}
+	api.err = errors.New("boom")
+	if err := RemoveNotificationRule(context.Background(), api, "slack", 9); err == nil || !strings.Contains(err.Error(), "delete slack notification rule 9") {
+		t.Fatalf("expected a delete error, got %v", err)
+	}
+}
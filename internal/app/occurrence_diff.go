@@ -0,0 +1,264 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"reflect"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// OccurrenceDiffAPI is the part of the Rollbar client occurrence diffs use.
+type OccurrenceDiffAPI interface {
+	ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error)
+	GetItem(ctx context.Context, itemID domain.ItemID) (rollbar.Item, error)
+	GetInstance(ctx context.Context, instanceID domain.InstanceID) (rollbar.ItemInstance, error)
+	GetInstanceByUUID(ctx context.Context, uuid string) (rollbar.ItemInstance, error)
+}
+
+type DiffKind string
+
+const (
+	DiffAdded   DiffKind = "added"
+	DiffRemoved DiffKind = "removed"
+	DiffChanged DiffKind = "changed"
+)
+
+// DiffChange is one differing leaf. Path is dotted from the occurrence data
+// ("request.GET.page"), or "error.*" and "frames[N]" for the stack trace,
+// with frames[0] the most recent call.
+type DiffChange struct {
+	Path   string   `json:"path"`
+	Kind   DiffKind `json:"kind"`
+	Before any      `json:"before,omitempty"`
+	After  any      `json:"after,omitempty"`
+}
+
+// OccurrenceDiff groups the changes between two occurrences into the parts
+// that usually explain a regression; Other holds everything else.
+type OccurrenceDiff struct {
+	Before   OccurrenceSummary `json:"before"`
+	After    OccurrenceSummary `json:"after"`
+	Versions []DiffChange      `json:"versions"`
+	Request  []DiffChange      `json:"request"`
+	Trace    []DiffChange      `json:"trace"`
+	Other    []DiffChange      `json:"other"`
+}
+
+// Changes is the total number of changes across all sections.
+func (d OccurrenceDiff) Changes() int {
+	return len(d.Versions) + len(d.Request) + len(d.Trace) + len(d.Other)
+}
+
+// ignoredDiffKeys are top-level data keys that differ between any two
+// occurrences; body is diffed separately as a stack trace.
+var ignoredDiffKeys = []string{"body", "metadata", "timestamp", "uuid"}
+
+var versionDiffPaths = []string{"code_version", "framework", "language", "notifier", "platform", "server.branch", "server.sha"}
+
+// OccurrencesByUUID fetches two occurrences by UUID.
+func OccurrencesByUUID(ctx context.Context, api OccurrenceDiffAPI, before string, after string) (rollbar.ItemInstance, rollbar.ItemInstance, error) {
+	first, err := api.GetInstanceByUUID(ctx, before)
+	if err != nil {
+		return rollbar.ItemInstance{}, rollbar.ItemInstance{}, fmt.Errorf("get occurrence %s: %w", before, err)
+	}
+	second, err := api.GetInstanceByUUID(ctx, after)
+	if err != nil {
+		return rollbar.ItemInstance{}, rollbar.ItemInstance{}, fmt.Errorf("get occurrence %s: %w", after, err)
+	}
+
+	return first, second, nil
+}
+
+// FirstAndLatestOccurrences fetches an item's first and latest occurrences.
+func FirstAndLatestOccurrences(ctx context.Context, api OccurrenceDiffAPI, counter domain.ItemCounter) (rollbar.ItemInstance, rollbar.ItemInstance, error) {
+	itemID, err := api.ResolveItemIDByCounter(ctx, counter)
+	if err != nil {
+		return rollbar.ItemInstance{}, rollbar.ItemInstance{}, fmt.Errorf("resolve item id: %w", err)
+	}
+	item, err := api.GetItem(ctx, itemID)
+	if err != nil {
+		return rollbar.ItemInstance{}, rollbar.ItemInstance{}, fmt.Errorf("get item: %w", err)
+	}
+	if item.FirstOccurrenceID == nil || item.LastOccurrenceID == nil {
+		return rollbar.ItemInstance{}, rollbar.ItemInstance{}, errors.New("item has no recorded first and last occurrence")
+	}
+
+	first, err := api.GetInstance(ctx, domain.InstanceID(*item.FirstOccurrenceID))
+	if err != nil {
+		return rollbar.ItemInstance{}, rollbar.ItemInstance{}, fmt.Errorf("get first occurrence: %w", err)
+	}
+	latest, err := api.GetInstance(ctx, domain.InstanceID(*item.LastOccurrenceID))
+	if err != nil {
+		return rollbar.ItemInstance{}, rollbar.ItemInstance{}, fmt.Errorf("get latest occurrence: %w", err)
+	}
+
+	return first, latest, nil
+}
+
+// DiffOccurrences compares two occurrences' data and stack traces.
+func DiffOccurrences(before rollbar.ItemInstance, after rollbar.ItemInstance) OccurrenceDiff {
+	diff := OccurrenceDiff{
+		Before:   mapOccurrence(before, false),
+		After:    mapOccurrence(after, false),
+		Versions: []DiffChange{},
+		Request:  []DiffChange{},
+		Trace:    diffTraces(before.OccurrenceBody(), after.OccurrenceBody()),
+		Other:    []DiffChange{},
+	}
+
+	for _, change := range diffValues("", decodeDiffData(before.Data), decodeDiffData(after.Data), nil) {
+		switch {
+		case matchesDiffPath(change.Path, "request"):
+			diff.Request = append(diff.Request, change)
+		case isVersionPath(change.Path):
+			diff.Versions = append(diff.Versions, change)
+		default:
+			diff.Other = append(diff.Other, change)
+		}
+	}
+
+	return diff
+}
+
+func decodeDiffData(raw json.RawMessage) map[string]any {
+	data := map[string]any{}
+	_ = json.Unmarshal(raw, &data)
+	for _, key := range ignoredDiffKeys {
+		delete(data, key)
+	}
+
+	return data
+}
+
+// diffValues walks objects key by key and reports any other differing value,
+// arrays included, as one change at its path.
+func diffValues(path string, before any, after any, changes []DiffChange) []DiffChange {
+	beforeObject, beforeIsObject := before.(map[string]any)
+	afterObject, afterIsObject := after.(map[string]any)
+	if beforeIsObject && afterIsObject {
+		return diffObjects(path, beforeObject, afterObject, changes)
+	}
+	if reflect.DeepEqual(before, after) {
+		return changes
+	}
+
+	return append(changes, DiffChange{Path: path, Kind: DiffChanged, Before: before, After: after})
+}
+
+func diffObjects(path string, before map[string]any, after map[string]any, changes []DiffChange) []DiffChange {
+	for _, key := range unionKeys(before, after) {
+		childPath := joinDiffPath(path, key)
+		beforeValue, inBefore := before[key]
+		afterValue, inAfter := after[key]
+		switch {
+		case !inBefore:
+			changes = append(changes, DiffChange{Path: childPath, Kind: DiffAdded, After: afterValue})
+		case !inAfter:
+			changes = append(changes, DiffChange{Path: childPath, Kind: DiffRemoved, Before: beforeValue})
+		default:
+			changes = diffValues(childPath, beforeValue, afterValue, changes)
+		}
+	}
+
+	return changes
+}
+
+// diffTraces compares the error class and message and its frames, most recent
+// first so frames[0] lines up with where each error was raised.
+func diffTraces(before rollbar.OccurrenceBody, after rollbar.OccurrenceBody) []DiffChange {
+	changes := diffValues("error", errorFields(before), errorFields(after), []DiffChange{})
+
+	beforeFrames, afterFrames := frameLabels(before), frameLabels(after)
+	for index := range max(len(beforeFrames), len(afterFrames)) {
+		path := "frames[" + strconv.Itoa(index) + "]"
+		switch {
+		case index >= len(beforeFrames):
+			changes = append(changes, DiffChange{Path: path, Kind: DiffAdded, After: afterFrames[index]})
+		case index >= len(afterFrames):
+			changes = append(changes, DiffChange{Path: path, Kind: DiffRemoved, Before: beforeFrames[index]})
+		case beforeFrames[index] != afterFrames[index]:
+			changes = append(changes, DiffChange{Path: path, Kind: DiffChanged, Before: beforeFrames[index], After: afterFrames[index]})
+		}
+	}
+
+	return changes
+}
+
+// errorFields is the outermost exception's class and message, or the message
+// body for message occurrences.
+func errorFields(body rollbar.OccurrenceBody) map[string]any {
+	exception, _ := body.Exception()
+	fields := map[string]any{"class": exception.Class, "message": exception.Message}
+	if body.Kind == rollbar.BodyKindMessage {
+		fields["message"] = body.Message.Body
+	}
+
+	return fields
+}
+
+// frameLabels renders the outermost trace's frames as "method (file:line)",
+// most recent call first.
+func frameLabels(body rollbar.OccurrenceBody) []string {
+	var frames []rollbar.Frame
+	switch body.Kind {
+	case rollbar.BodyKindTrace:
+		frames = body.Trace.Frames
+	case rollbar.BodyKindTraceChain:
+		frames = body.TraceChain[0].Frames
+	}
+
+	labels := make([]string, 0, len(frames))
+	for index := len(frames) - 1; index >= 0; index-- {
+		frame := frames[index]
+		labels = append(labels, fmt.Sprintf("%s (%s:%d)", frame.Method, frame.Filename, frame.Lineno))
+	}
+
+	return labels
+}
+
+func isVersionPath(path string) bool {
+	if strings.HasSuffix(path, ".code_version") {
+		return true
+	}
+	for _, prefix := range versionDiffPaths {
+		if matchesDiffPath(path, prefix) {
+			return true
+		}
+	}
+
+	return false
+}
+
+func matchesDiffPath(path string, prefix string) bool {
+	return path == prefix || strings.HasPrefix(path, prefix+".")
+}
+
+func joinDiffPath(path string, key string) string {
+	if path == "" {
+		return key
+	}
+
+	return path + "." + key
+}
+
+func unionKeys(before map[string]any, after map[string]any) []string {
+	keys := make([]string, 0, len(before)+len(after))
+	for key := range before {
+		keys = append(keys, key)
+	}
+	for key := range after {
+		if _, ok := before[key]; !ok {
+			keys = append(keys, key)
+		}
+	}
+	sort.Strings(keys)
+
+	return keys
+}
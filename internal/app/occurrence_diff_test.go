@@ -0,0 +1,120 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type diffAPI struct {
+	fakeAPI
+	instances map[string]rollbar.ItemInstance
+}
+
+func (a diffAPI) GetInstance(ctx context.Context, instanceID domain.InstanceID) (rollbar.ItemInstance, error) {
+	return a.GetInstanceByUUID(ctx, instanceID.String())
+}
+
+func (a diffAPI) GetInstanceByUUID(ctx context.Context, uuid string) (rollbar.ItemInstance, error) {
+	instance, ok := a.instances[uuid]
+	if !ok {
+		return rollbar.ItemInstance{}, errors.New("not found")
+	}
+
+	return instance, nil
+}
+
+func diffInstance(id uint64, data string) rollbar.ItemInstance {
+	return rollbar.ItemInstance{ID: id, Data: json.RawMessage(data)}
+}
+
+// diffFixtures are two occurrences of a KeyError that differ in versions,
+// query, the charge frame's line, and custom data.
+func diffFixtures() (rollbar.ItemInstance, rollbar.ItemInstance) {
+	before := diffInstance(1, `{"uuid":"a","timestamp":1,"code_version":"v1","environment":"production","client":{"javascript":{"code_version":"c1"}},
+		"request":{"url":"/pay","GET":{"page":"2"}},
+		"body":{"trace":{"exception":{"class":"KeyError","message":"id"},"frames":[{"filename":"app.py","lineno":3,"method":"main"},{"filename":"pay.py","lineno":10,"method":"charge"}]}}}`)
+	after := diffInstance(2, `{"uuid":"b","timestamp":2,"code_version":"v2","environment":"production","client":{"javascript":{"code_version":"c2"}},
+		"request":{"url":"/pay","GET":{"debug":"1"}},
+		"body":{"trace":{"exception":{"class":"KeyError","message":"id"},"frames":[{"filename":"app.py","lineno":3,"method":"main"},{"filename":"pay.py","lineno":12,"method":"charge"}]}},"custom":{"retry":true}}`)
+
+	return before, after
+}
+
+// diffChangeMatches compares got with want, skipping the values want leaves
+// nil.
+func diffChangeMatches(got DiffChange, want DiffChange) bool {
+	return got.Path == want.Path && got.Kind == want.Kind && (want.Before == nil || got.Before == want.Before) && (want.After == nil || got.After == want.After)
+}
+
+func TestDiffOccurrences(t *testing.T) {
+	t.Parallel()
+
+	diff := DiffOccurrences(diffFixtures())
+
+	tests := []struct {
+		section string
+		got     []DiffChange
+		want    []DiffChange
+	}{
+		{section: "versions", got: diff.Versions, want: []DiffChange{
+			{Path: "client.javascript.code_version", Kind: DiffChanged, Before: "c1", After: "c2"},
+			{Path: "code_version", Kind: DiffChanged, Before: "v1", After: "v2"},
+		}},
+		{section: "request", got: diff.Request, want: []DiffChange{
+			{Path: "request.GET.debug", Kind: DiffAdded, After: "1"},
+			{Path: "request.GET.page", Kind: DiffRemoved, Before: "2"},
+		}},
+		{section: "trace", got: diff.Trace, want: []DiffChange{
+			{Path: "frames[0]", Kind: DiffChanged, Before: "charge (pay.py:10)", After: "charge (pay.py:12)"},
+		}},
+		{section: "other", got: diff.Other, want: []DiffChange{{Path: "custom", Kind: DiffAdded}}},
+	}
+	for _, tc := range tests {
+		if len(tc.got) != len(tc.want) {
+			t.Fatalf("%s: got %+v, want %+v", tc.section, tc.got, tc.want)
+		}
+		for index, want := range tc.want {
+			if got := tc.got[index]; !diffChangeMatches(got, want) {
+				t.Fatalf("%s[%d]: got %+v, want %+v", tc.section, index, got, want)
+			}
+		}
+	}
+	if diff.Changes() != 6 || diff.Before.UUID != "a" || diff.After.UUID != "b" {
+		t.Fatalf("unexpected diff summary: %d changes, %q -> %q", diff.Changes(), diff.Before.UUID, diff.After.UUID)
+	}
+}
+
+func TestFirstAndLatestOccurrences(t *testing.T) {
+	t.Parallel()
+
+	first, latest := uint64(1), uint64(9)
+	instances := map[string]rollbar.ItemInstance{"1": diffInstance(1, `{}`), "9": diffInstance(9, `{}`)}
+
+	tests := []struct {
+		name    string
+		item    rollbar.Item
+		wantErr bool
+	}{
+		{name: "found", item: rollbar.Item{FirstOccurrenceID: &first, LastOccurrenceID: &latest}},
+		{name: "no occurrence ids", item: rollbar.Item{}, wantErr: true},
+	}
+	for _, tc := range tests {
+		api := diffAPI{fakeAPI: fakeAPI{item: tc.item}, instances: instances}
+		before, after, err := FirstAndLatestOccurrences(context.Background(), api, 4)
+		if (err != nil) != tc.wantErr {
+			t.Fatalf("%s: error = %v", tc.name, err)
+		}
+		if !tc.wantErr && (before.ID != 1 || after.ID != 9) {
+			t.Fatalf("%s: got %d and %d", tc.name, before.ID, after.ID)
+		}
+	}
+
+	if _, _, err := OccurrencesByUUID(context.Background(), diffAPI{instances: instances}, "1", "missing"); err == nil {
+		t.Fatal("expected error for a missing occurrence")
+	}
+}
@@ -0,0 +1,144 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"slices"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// OccurrenceSample is a spread of an item's occurrences, oldest first.
+// Available is how many occurrences Rollbar still keeps for the item, which
+// can be fewer than its total once old ones expire.
+type OccurrenceSample struct {
+	ItemID      domain.ItemID          `json:"item_id"`
+	Available   int                    `json:"available"`
+	Occurrences []rollbar.ItemInstance `json:"occurrences"`
+}
+
+// instancePages fetches pages of an item's occurrences at most once each.
+type instancePages struct {
+	api    RollbarAPI
+	itemID domain.ItemID
+	pages  map[int][]rollbar.ItemInstance
+}
+
+func (p *instancePages) get(ctx context.Context, page int) ([]rollbar.ItemInstance, error) {
+	if instances, ok := p.pages[page]; ok {
+		return instances, nil
+	}
+	result, err := p.api.ListInstances(ctx, p.itemID, page)
+	if err != nil {
+		return nil, fmt.Errorf("list occurrences page %d: %w", page, err)
+	}
+	p.pages[page] = result.Instances
+
+	return result.Instances, nil
+}
+
+// SampleOccurrences picks count occurrences evenly spaced from the newest to
+// the oldest one Rollbar keeps, rather than the newest page. Only the pages
+// holding a pick are read, plus a few to find the last page.
+func (s *Service) SampleOccurrences(ctx context.Context, counter domain.ItemCounter, count int) (OccurrenceSample, error) {
+	if count <= 0 {
+		return OccurrenceSample{}, errors.New("sample size must be positive")
+	}
+	itemID, err := s.api.ResolveItemIDByCounter(ctx, counter)
+	if err != nil {
+		return OccurrenceSample{}, fmt.Errorf("resolve item id: %w", err)
+	}
+	pages := &instancePages{api: s.api, itemID: itemID, pages: map[int][]rollbar.ItemInstance{}}
+	first, err := pages.get(ctx, 1)
+	if err != nil || len(first) == 0 {
+		return OccurrenceSample{ItemID: itemID, Occurrences: []rollbar.ItemInstance{}}, err
+	}
+
+	item, err := s.api.GetItem(ctx, itemID)
+	if err != nil {
+		return OccurrenceSample{}, fmt.Errorf("get item: %w", err)
+	}
+	pageSize := len(first)
+	lastPage, err := pages.last(ctx, estimatedPages(item.TotalOccurrences, pageSize))
+	if err != nil {
+		return OccurrenceSample{}, err
+	}
+	available := (lastPage-1)*pageSize + len(pages.pages[lastPage])
+
+	picked, err := pages.pick(ctx, samplePositions(available, count), pageSize)
+	if err != nil {
+		return OccurrenceSample{}, err
+	}
+	slices.Reverse(picked)
+
+	return OccurrenceSample{ItemID: itemID, Available: available, Occurrences: picked}, nil
+}
+
+func estimatedPages(total *uint64, pageSize int) int {
+	if total == nil || *total == 0 {
+		return 1
+	}
+
+	return max(int((*total+uint64(pageSize)-1)/uint64(pageSize)), 1)
+}
+
+// last finds the last non-empty page, at most estimate, by binary search:
+// an item's total counts occurrences Rollbar may no longer keep.
+func (p *instancePages) last(ctx context.Context, estimate int) (int, error) {
+	low, high := 1, estimate
+	for low < high {
+		middle := (low + high + 1) / 2
+		instances, err := p.get(ctx, middle)
+		if err != nil {
+			return 0, err
+		}
+		if len(instances) > 0 {
+			low = middle
+		} else {
+			high = middle - 1
+		}
+	}
+
+	return low, nil
+}
+
+// samplePositions spreads count positions over available occurrences, from
+// the newest (0) to the oldest.
+func samplePositions(available int, count int) []int {
+	count = min(count, available)
+	if count == 1 {
+		return []int{0}
+	}
+
+	positions := make([]int, 0, count)
+	for index := range count {
+		positions = append(positions, index*(available-1)/(count-1))
+	}
+
+	return positions
+}
+
+// pick reads the occurrence at each position, skipping repeats when pages
+// shifted under new occurrences while sampling.
+func (p *instancePages) pick(ctx context.Context, positions []int, pageSize int) ([]rollbar.ItemInstance, error) {
+	picked := make([]rollbar.ItemInstance, 0, len(positions))
+	seen := map[uint64]bool{}
+	for _, position := range positions {
+		instances, err := p.get(ctx, position/pageSize+1)
+		if err != nil {
+			return nil, err
+		}
+		if len(instances) == 0 {
+			continue
+		}
+		instance := instances[min(position%pageSize, len(instances)-1)]
+		if !seen[instance.ID] {
+			seen[instance.ID] = true
+			picked = append(picked, instance)
+		}
+	}
+
+	return picked, nil
+}
@@ -0,0 +1,62 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestServiceSampleOccurrences(t *testing.T) {
+	t.Parallel()
+
+	pages := make([][]rollbar.ItemInstance, 0, 4)
+	for page := range 4 {
+		newest := uint64(12 - page*3)
+		pages = append(pages, []rollbar.ItemInstance{testInstance(newest, newest*100), testInstance(newest-1, (newest-1)*100), testInstance(newest-2, (newest-2)*100)})
+	}
+	// Rollbar's total also counts occurrences that have expired.
+	total := uint64(30)
+
+	tests := []struct {
+		name          string
+		count         int
+		want          []uint64
+		wantAvailable int
+	}{
+		{name: "spread", count: 4, want: []uint64{1, 5, 9, 12}, wantAvailable: 12},
+		{name: "more than kept", count: 50, want: []uint64{1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12}, wantAvailable: 12},
+		{name: "one", count: 1, want: []uint64{12}, wantAvailable: 12},
+	}
+	for _, tc := range tests {
+		api := &instancesAPI{fakeAPI: fakeAPI{item: rollbar.Item{TotalOccurrences: &total}}, pages: pages}
+		sample, err := NewService(api).SampleOccurrences(context.Background(), 7, tc.count)
+		if err != nil {
+			t.Fatalf("%s: SampleOccurrences() error = %v", tc.name, err)
+		}
+		ids := make([]uint64, 0, len(sample.Occurrences))
+		for _, occurrence := range sample.Occurrences {
+			ids = append(ids, occurrence.ID)
+		}
+		if !slices.Equal(ids, tc.want) || sample.Available != tc.wantAvailable {
+			t.Fatalf("%s: ids = %v of %d, want %v of %d", tc.name, ids, sample.Available, tc.want, tc.wantAvailable)
+		}
+	}
+}
+
+func TestServiceSampleOccurrencesEdges(t *testing.T) {
+	t.Parallel()
+
+	sample, err := NewService(&instancesAPI{}).SampleOccurrences(context.Background(), 7, 5)
+	if err != nil || len(sample.Occurrences) != 0 || sample.Available != 0 {
+		t.Fatalf("expected an empty sample, got %+v, %v", sample, err)
+	}
+	if _, err := NewService(&instancesAPI{}).SampleOccurrences(context.Background(), 7, 0); err == nil {
+		t.Fatal("expected an error for a zero sample size")
+	}
+	if _, err := NewService(fakeAPI{err: errors.New("bad")}).SampleOccurrences(context.Background(), 7, 5); err == nil {
+		t.Fatal("expected a resolve error")
+	}
+}
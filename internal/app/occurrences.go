@@ -0,0 +1,192 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"math"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/summary"
+)
+
+type OccurrenceSummary struct {
+	ID          uint64            `json:"id"`
+	UUID        string            `json:"uuid,omitempty"`
+	Timestamp   *uint64           `json:"timestamp,omitempty"`
+	Environment string            `json:"environment,omitempty"`
+	Level       string            `json:"level,omitempty"`
+	CodeVersion string            `json:"code_version,omitempty"`
+	Host        string            `json:"host,omitempty"`
+	PersonID    string            `json:"person_id,omitempty"`
+	Language    string            `json:"language,omitempty"`
+	MainError   string            `json:"main_error"`
+	Columns     map[string]string `json:"columns,omitempty"`
+	Raw         json.RawMessage   `json:"raw,omitempty"`
+}
+
+type OccurrenceOptions struct {
+	Limit int
+	Since *time.Time
+	// Full keeps each occurrence's complete payload in Raw.
+	Full bool
+	// Columns are read from each occurrence into Columns.
+	Columns []Column
+	// Redactor, when set, removes personal data from each occurrence before
+	// anything is read from it.
+	Redactor *redact.Redactor
+}
+
+// occurrenceData holds the fields of an occurrence payload shown in listings.
+type occurrenceData struct {
+	UUID        string `json:"uuid"`
+	Environment string `json:"environment"`
+	Level       string `json:"level"`
+	CodeVersion string `json:"code_version"`
+	Language    string `json:"language"`
+	Server      struct {
+		Host string `json:"host"`
+	} `json:"server"`
+	Person struct {
+		ID json.RawMessage `json:"id"`
+	} `json:"person"`
+}
+
+// Occurrences lists an item's occurrences newest first, paging until limit
+// occurrences are collected or one older than Since is reached.
+func (s *Service) Occurrences(ctx context.Context, counter domain.ItemCounter, options OccurrenceOptions) ([]OccurrenceSummary, error) {
+	occurrences := make([]OccurrenceSummary, 0)
+	err := s.StreamOccurrences(ctx, counter, options, func(batch []OccurrenceSummary) error {
+		occurrences = append(occurrences, batch...)
+		return nil
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	return occurrences, nil
+}
+
+// StreamOccurrences is Occurrences for exports: each page is handed to emit
+// as soon as it arrives instead of being collected.
+func (s *Service) StreamOccurrences(ctx context.Context, counter domain.ItemCounter, options OccurrenceOptions, emit func([]OccurrenceSummary) error) error {
+	itemID, err := s.api.ResolveItemIDByCounter(ctx, counter)
+	if err != nil {
+		return fmt.Errorf("resolve item id: %w", err)
+	}
+
+	sent := 0
+	for page := 1; ; page++ {
+		result, err := s.api.ListInstances(ctx, itemID, page)
+		if err != nil {
+			return fmt.Errorf("list occurrences: %w", err)
+		}
+		if len(result.Instances) == 0 {
+			return nil
+		}
+
+		batch, done := occurrenceBatch(result.Instances, options, sent)
+		if len(batch) > 0 {
+			if err := emit(batch); err != nil {
+				return err
+			}
+		}
+		sent += len(batch)
+		if done {
+			return nil
+		}
+	}
+}
+
+// occurrenceBatch maps one page of instances, reporting done once the limit
+// is reached or an occurrence older than Since turns up.
+func occurrenceBatch(instances []rollbar.ItemInstance, options OccurrenceOptions, sent int) ([]OccurrenceSummary, bool) {
+	batch := make([]OccurrenceSummary, 0, len(instances))
+	for _, instance := range instances {
+		if olderThan(instance.Timestamp, options.Since) {
+			return batch, true
+		}
+		if options.Redactor != nil {
+			instance = ScrubInstance(&options.Redactor.Scrubber, instance)
+		}
+		occurrence := mapOccurrence(instance, options.Full)
+		occurrence.Columns = extractColumns(options.Columns, instance.Raw)
+		batch = append(batch, occurrence)
+		if options.Limit > 0 && sent+len(batch) >= options.Limit {
+			return batch, true
+		}
+	}
+
+	return batch, false
+}
+
+// ScrubInstance scrubs an occurrence's data, body, and raw payload.
+func ScrubInstance(scrubber *redact.Scrubber, instance rollbar.ItemInstance) rollbar.ItemInstance {
+	instance.Data = scrubber.ScrubJSON(instance.Data)
+	instance.Body = scrubber.ScrubJSON(instance.Body)
+	instance.Raw = scrubber.ScrubJSON(instance.Raw)
+
+	return instance
+}
+
+func olderThan(timestamp *uint64, since *time.Time) bool {
+	if since == nil || timestamp == nil || *timestamp > math.MaxInt64 {
+		return false
+	}
+
+	return int64(*timestamp) < since.Unix()
+}
+
+func mapOccurrence(instance rollbar.ItemInstance, full bool) OccurrenceSummary {
+	var data occurrenceData
+	_ = json.Unmarshal(instance.Data, &data)
+
+	occurrence := OccurrenceSummary{
+		ID:          instance.ID,
+		UUID:        data.UUID,
+		Timestamp:   instance.Timestamp,
+		Environment: data.Environment,
+		Level:       data.Level,
+		CodeVersion: data.CodeVersion,
+		Host:        data.Server.Host,
+		PersonID:    personID(data.Person.ID),
+		Language:    data.Language,
+		MainError:   summary.MainError(instance.Body, instance.Data),
+	}
+	if full {
+		occurrence.Raw = instance.Raw
+	}
+
+	return occurrence
+}
+
+// personID accepts person ids sent as either strings or numbers.
+func personID(raw json.RawMessage) string {
+	var text string
+	if err := json.Unmarshal(raw, &text); err == nil {
+		return text
+	}
+
+	var number json.Number
+	if err := json.Unmarshal(raw, &number); err == nil {
+		return number.String()
+	}
+
+	return ""
+}
+
+// OccurrenceDetail is one occurrence with its parsed body, for
+// `occurrence show`.
+type OccurrenceDetail struct {
+	OccurrenceSummary
+	Body rollbar.OccurrenceBody `json:"body"`
+	// ResolvedFrames counts frames rewritten through source maps.
+	ResolvedFrames int `json:"resolved_frames,omitempty"`
+}
+
+func NewOccurrenceDetail(instance rollbar.ItemInstance) OccurrenceDetail {
+	return OccurrenceDetail{OccurrenceSummary: mapOccurrence(instance, true), Body: instance.OccurrenceBody()}
+}
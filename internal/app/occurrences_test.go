@@ -0,0 +1,133 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"slices"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type instancesAPI struct {
+	fakeAPI
+	pages [][]rollbar.ItemInstance
+	calls int
+}
+
+func (a *instancesAPI) ListInstances(ctx context.Context, itemID domain.ItemID, page int) (rollbar.InstancePage, error) {
+	a.calls++
+	if page > len(a.pages) {
+		return rollbar.InstancePage{Page: page}, nil
+	}
+
+	return rollbar.InstancePage{Instances: a.pages[page-1], Page: page}, nil
+}
+
+func testInstance(id uint64, timestamp uint64) rollbar.ItemInstance {
+	data := fmt.Sprintf(`{"uuid":"uuid-%d","environment":"production","level":"error","code_version":"abc","server":{"host":"web-1"},"person":{"id":%d},"body":{"message":{"body":"boom %d"}}}`, id, id, id)
+	return rollbar.ItemInstance{ID: id, Timestamp: &timestamp, Data: json.RawMessage(data), Raw: json.RawMessage(`{"id":1}`)}
+}
+
+func TestServiceOccurrences(t *testing.T) {
+	t.Parallel()
+
+	pages := [][]rollbar.ItemInstance{
+		{testInstance(5, 500), testInstance(4, 400)},
+		{testInstance(3, 300), testInstance(2, 200)},
+	}
+	since := time.Unix(250, 0)
+
+	tests := []struct {
+		name      string
+		options   OccurrenceOptions
+		want      []uint64
+		wantCalls int
+	}{
+		{name: "all pages", options: OccurrenceOptions{}, want: []uint64{5, 4, 3, 2}, wantCalls: 3},
+		{name: "limit", options: OccurrenceOptions{Limit: 3}, want: []uint64{5, 4, 3}, wantCalls: 2},
+		{name: "since stops paging", options: OccurrenceOptions{Since: &since}, want: []uint64{5, 4, 3}, wantCalls: 2},
+	}
+
+	for _, tc := range tests {
+		api := &instancesAPI{pages: pages}
+		occurrences, err := NewService(api).Occurrences(context.Background(), 7, tc.options)
+		if err != nil {
+			t.Fatalf("%s: Occurrences() error = %v", tc.name, err)
+		}
+
+		ids := make([]uint64, 0, len(occurrences))
+		for _, occurrence := range occurrences {
+			ids = append(ids, occurrence.ID)
+		}
+		if !slices.Equal(ids, tc.want) || api.calls != tc.wantCalls {
+			t.Fatalf("%s: ids = %v after %d calls, want %v after %d", tc.name, ids, api.calls, tc.want, tc.wantCalls)
+		}
+	}
+}
+
+func TestServiceOccurrencesMapsFields(t *testing.T) {
+	t.Parallel()
+
+	api := &instancesAPI{pages: [][]rollbar.ItemInstance{{testInstance(9, 900)}}}
+	occurrences, err := NewService(api).Occurrences(context.Background(), 7, OccurrenceOptions{Full: true})
+	if err != nil || len(occurrences) != 1 {
+		t.Fatalf("Occurrences() = %v, %v", occurrences, err)
+	}
+
+	got := occurrences[0]
+	fields := [...]string{got.UUID, got.Environment, got.Level, got.CodeVersion, got.Host, got.PersonID, got.MainError}
+	if fields != [...]string{"uuid-9", "production", "error", "abc", "web-1", "9", "boom 9"} || len(got.Raw) == 0 {
+		t.Fatalf("unexpected occurrence: %+v", got)
+	}
+
+	api = &instancesAPI{pages: [][]rollbar.ItemInstance{{testInstance(9, 900)}}}
+	occurrences, _ = NewService(api).Occurrences(context.Background(), 7, OccurrenceOptions{})
+	if len(occurrences[0].Raw) != 0 {
+		t.Fatalf("expected raw payload only with Full")
+	}
+}
+
+func TestServiceOccurrencesRedactor(t *testing.T) {
+	t.Parallel()
+
+	instance := rollbar.ItemInstance{
+		ID:   9,
+		Data: json.RawMessage(`{"server":{"host":"10.0.0.7"},"person":{"id":"jane@example.com"},"body":{"message":{"body":"charge failed for jane@example.com"}}}`),
+		Raw:  json.RawMessage(`{"data":{"person":{"email":"jane@example.com"}}}`),
+	}
+	api := &instancesAPI{pages: [][]rollbar.ItemInstance{{instance}}}
+	occurrences, err := NewService(api).Occurrences(context.Background(), 7, OccurrenceOptions{Full: true, Redactor: redact.DefaultRedactor()})
+	if err != nil || len(occurrences) != 1 {
+		t.Fatalf("Occurrences() = %v, %v", occurrences, err)
+	}
+
+	got := occurrences[0]
+	if got.Host != "[ip]" || got.PersonID != "[email]" || got.MainError != "charge failed for [email]" || string(got.Raw) != `{"data":{"person":{"email":"[email]"}}}` {
+		t.Fatalf("unexpected redacted occurrence: %+v", got)
+	}
+}
+
+func TestServiceOccurrencesErrors(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewService(fakeAPI{err: errors.New("bad")}).Occurrences(context.Background(), 7, OccurrenceOptions{}); err == nil {
+		t.Fatalf("expected Occurrences error")
+	}
+}
+
+func TestPersonID(t *testing.T) {
+	t.Parallel()
+
+	tests := map[string]string{`"u-1"`: "u-1", `42`: "42", `null`: "", `{}`: ""}
+	for raw, want := range tests {
+		if got := personID(json.RawMessage(raw)); got != want {
+			t.Fatalf("personID(%s) = %q, want %q", raw, got, want)
+		}
+	}
+}
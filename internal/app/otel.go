@@ -0,0 +1,109 @@
+package app
+
+import (
+	"cmp"
+	"encoding/json"
+	"math"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/otel"
+)
+
+// occurrenceCorrelation holds where occurrences carry ids that tie them to
+// traces: custom fields set by the reporting SDK, or the request headers.
+type occurrenceCorrelation struct {
+	Data struct {
+		Custom struct {
+			TraceID   string `json:"trace_id"`
+			SpanID    string `json:"span_id"`
+			RequestID string `json:"request_id"`
+		} `json:"custom"`
+		Request struct {
+			Headers map[string]any `json:"headers"`
+		} `json:"request"`
+	} `json:"data"`
+}
+
+var otelSeverities = map[string]int{
+	"debug":    otel.SeverityDebug,
+	"info":     otel.SeverityInfo,
+	"warning":  otel.SeverityWarn,
+	"error":    otel.SeverityError,
+	"critical": otel.SeverityFatal,
+}
+
+// OccurrenceLogRecord converts an occurrence, listed with Full, into a log
+// record. trace_id and span_id custom fields, or a W3C traceparent request
+// header, link it to its trace; request_id, from custom fields or an
+// X-Request-Id header, becomes an attribute.
+func OccurrenceLogRecord(counter domain.ItemCounter, occurrence OccurrenceSummary) otel.LogRecord {
+	var correlation occurrenceCorrelation
+	_ = json.Unmarshal(occurrence.Raw, &correlation)
+	custom := correlation.Data.Custom
+	headers := stringHeaders(correlation.Data.Request.Headers)
+
+	traceID, spanID := strings.ToLower(custom.TraceID), strings.ToLower(custom.SpanID)
+	if !otel.ValidTraceID(traceID) {
+		traceID, spanID = parseTraceparent(headers["traceparent"])
+	}
+	if !otel.ValidSpanID(spanID) {
+		spanID = ""
+	}
+
+	record := otel.LogRecord{
+		SeverityNumber: otelSeverities[occurrence.Level],
+		SeverityText:   strings.ToUpper(occurrence.Level),
+		Body:           occurrence.MainError,
+		TraceID:        traceID,
+		SpanID:         spanID,
+		Attributes: map[string]string{
+			"exception.message":      occurrence.MainError,
+			"rollbar.item.counter":   counter.String(),
+			"rollbar.occurrence.id":  strconv.FormatUint(occurrence.ID, 10),
+			"rollbar.occurrence.url": occurrenceURL(occurrence.UUID),
+			"rollbar.request_id":     cmp.Or(custom.RequestID, headers["x-request-id"]),
+			"deployment.environment": occurrence.Environment,
+			"service.version":        occurrence.CodeVersion,
+			"host.name":              occurrence.Host,
+			"enduser.id":             occurrence.PersonID,
+		},
+	}
+	if occurrence.Timestamp != nil && *occurrence.Timestamp <= math.MaxInt64 {
+		record.Time = time.Unix(int64(*occurrence.Timestamp), 0)
+	}
+
+	return record
+}
+
+// parseTraceparent reads "00-<trace id>-<span id>-<flags>".
+func parseTraceparent(header string) (string, string) {
+	parts := strings.Split(strings.TrimSpace(strings.ToLower(header)), "-")
+	if len(parts) != 4 || !otel.ValidTraceID(parts[1]) {
+		return "", ""
+	}
+
+	return parts[1], parts[2]
+}
+
+func occurrenceURL(uuid string) string {
+	if uuid == "" {
+		return ""
+	}
+
+	return OccurrenceWebURL(uuid)
+}
+
+// stringHeaders lowercases header names and keeps the string values.
+func stringHeaders(values map[string]any) map[string]string {
+	headers := make(map[string]string, len(values))
+	for key, value := range values {
+		if text, ok := value.(string); ok {
+			headers[strings.ToLower(key)] = text
+		}
+	}
+
+	return headers
+}
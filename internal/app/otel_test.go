@@ -0,0 +1,58 @@
+package app
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/otel"
+)
+
+func TestOccurrenceLogRecord(t *testing.T) {
+	t.Parallel()
+
+	timestamp := uint64(1700000000)
+	tests := []struct {
+		name      string
+		raw       string
+		wantTrace string
+		wantSpan  string
+		wantReqID string
+	}{
+		{
+			name:      "custom fields",
+			raw:       `{"data":{"custom":{"trace_id":"4BF92F3577B34DA6A3CE929D0E0E4736","span_id":"00f067aa0ba902b7","request_id":"req-1"}}}`,
+			wantTrace: "4bf92f3577b34da6a3ce929d0e0e4736",
+			wantSpan:  "00f067aa0ba902b7",
+			wantReqID: "req-1",
+		},
+		{
+			name:      "traceparent header",
+			raw:       `{"data":{"request":{"headers":{"Traceparent":"00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01","X-Request-Id":"req-2"}}}}`,
+			wantTrace: "4bf92f3577b34da6a3ce929d0e0e4736",
+			wantSpan:  "00f067aa0ba902b7",
+			wantReqID: "req-2",
+		},
+		{name: "invalid ids", raw: `{"data":{"custom":{"trace_id":"abc","span_id":"xyz"}}}`},
+	}
+	for _, tc := range tests {
+		occurrence := OccurrenceSummary{ID: 7, UUID: "0f1e", Timestamp: &timestamp, Level: "error", Environment: "production", MainError: "KeyError: id", Raw: json.RawMessage(tc.raw)}
+		record := OccurrenceLogRecord(269, occurrence)
+		if record.TraceID != tc.wantTrace || record.SpanID != tc.wantSpan || record.Attributes["rollbar.request_id"] != tc.wantReqID {
+			t.Fatalf("%s: unexpected correlation: %+v", tc.name, record)
+		}
+		checkLogRecord(t, tc.name, record)
+	}
+}
+
+// checkLogRecord fails unless record carries the error severity, timestamp,
+// body and item attributes of occurrence 0f1e of item 269.
+func checkLogRecord(t *testing.T, name string, record otel.LogRecord) {
+	t.Helper()
+
+	if record.SeverityNumber != otel.SeverityError || record.SeverityText != "ERROR" || record.Time.Unix() != 1700000000 || record.Body != "KeyError: id" {
+		t.Fatalf("%s: unexpected record: %+v", name, record)
+	}
+	if record.Attributes["rollbar.item.counter"] != "269" || record.Attributes["rollbar.occurrence.url"] != "https://rollbar.com/occurrence/uuid/?uuid=0f1e" {
+		t.Fatalf("%s: unexpected attributes: %v", name, record.Attributes)
+	}
+}
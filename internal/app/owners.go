@@ -0,0 +1,148 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// OwnerAPI is the part of the Rollbar client owner suggestions use.
+type OwnerAPI interface {
+	ItemFrameAPI
+	ListUsers(ctx context.Context) ([]rollbar.User, error)
+	UpdateItem(ctx context.Context, itemID domain.ItemID, patch rollbar.ItemPatch) error
+}
+
+type OwnersOptions struct {
+	// Locate maps a frame filename to its path in the checkout, reporting
+	// false for frames outside the project such as libraries.
+	Locate func(filename string) (string, bool)
+	// Owners returns the CODEOWNERS owners of a checkout path and the
+	// pattern that matched, reporting false when no rule does.
+	Owners func(path string) (string, []string, bool)
+	// Frames is how many in-project frames of the latest trace, most recent
+	// call first, are looked up, 3 by default.
+	Frames int
+	Assign bool
+}
+
+// FrameOwners is one in-project frame and the CODEOWNERS rule owning it.
+type FrameOwners struct {
+	CheckoutFrame
+	Pattern string   `json:"pattern,omitempty"`
+	Owners  []string `json:"owners"`
+}
+
+// OwnerCandidate is a CODEOWNERS owner of some of the frames and the Rollbar
+// user it names, when one does: @handles match usernames and emails match
+// emails. Teams never match.
+type OwnerCandidate struct {
+	Owner  string        `json:"owner"`
+	Frames int           `json:"frames"`
+	User   *rollbar.User `json:"user,omitempty"`
+}
+
+// OwnersResult suggests Assignee, the best ranked candidate naming a
+// Rollbar user; Assigned reports whether the item was assigned to them.
+type OwnersResult struct {
+	Issue      IssueSummary     `json:"issue"`
+	Frames     []FrameOwners    `json:"frames"`
+	Candidates []OwnerCandidate `json:"candidates"`
+	Assignee   *rollbar.User    `json:"assignee,omitempty"`
+	Assigned   bool             `json:"assigned"`
+}
+
+// SuggestOwners matches the top in-project frames of an item's latest stack
+// trace against CODEOWNERS and ranks their owners by how many frames each
+// owns, ties going to the owner of the more recent call. With Assign it
+// assigns the item to the suggested user, failing when there is none.
+func SuggestOwners(ctx context.Context, api OwnerAPI, counter domain.ItemCounter, options OwnersOptions) (OwnersResult, error) {
+	itemID, issue, frames, err := checkoutFrames(ctx, api, counter, options.Locate, options.Frames)
+	if err != nil {
+		return OwnersResult{}, err
+	}
+
+	result := OwnersResult{Issue: issue, Frames: frameOwners(frames, options.Owners)}
+	if err := result.rankCandidates(ctx, api); err != nil {
+		return OwnersResult{}, err
+	}
+	if !options.Assign {
+		return result, nil
+	}
+	if result.Assignee == nil {
+		return OwnersResult{}, fmt.Errorf("no CODEOWNERS owner of item %d is a Rollbar user", counter)
+	}
+	if err := api.UpdateItem(ctx, itemID, rollbar.ItemPatch{AssignedUserID: &result.Assignee.ID}); err != nil {
+		return OwnersResult{}, fmt.Errorf("assign item: %w", err)
+	}
+	result.Assigned = true
+
+	return result, nil
+}
+
+func frameOwners(frames []CheckoutFrame, lookup func(path string) (string, []string, bool)) []FrameOwners {
+	owned := make([]FrameOwners, 0, len(frames))
+	for _, frame := range frames {
+		pattern, owners, _ := lookup(frame.Path)
+		owned = append(owned, FrameOwners{CheckoutFrame: frame, Pattern: pattern, Owners: append([]string{}, owners...)})
+	}
+
+	return owned
+}
+
+func (r *OwnersResult) rankCandidates(ctx context.Context, api OwnerAPI) error {
+	r.Candidates = []OwnerCandidate{}
+	seen := map[string]int{}
+	for _, frame := range r.Frames {
+		for _, owner := range frame.Owners {
+			index, ok := seen[owner]
+			if !ok {
+				index = len(r.Candidates)
+				seen[owner] = index
+				r.Candidates = append(r.Candidates, OwnerCandidate{Owner: owner})
+			}
+			r.Candidates[index].Frames++
+		}
+	}
+	if len(r.Candidates) == 0 {
+		return nil
+	}
+	sort.SliceStable(r.Candidates, func(i int, j int) bool { return r.Candidates[i].Frames > r.Candidates[j].Frames })
+
+	users, err := api.ListUsers(ctx)
+	if err != nil {
+		return fmt.Errorf("list users: %w", err)
+	}
+	for index := range r.Candidates {
+		r.Candidates[index].User = ownerUser(r.Candidates[index].Owner, users)
+		if r.Assignee == nil {
+			r.Assignee = r.Candidates[index].User
+		}
+	}
+
+	return nil
+}
+
+// ownerUser finds the user a CODEOWNERS owner names: an @handle by username,
+// anything else by email. @org/team owners name no user.
+func ownerUser(owner string, users []rollbar.User) *rollbar.User {
+	handle, isHandle := strings.CutPrefix(owner, "@")
+	if isHandle && strings.Contains(handle, "/") {
+		return nil
+	}
+	for index := range users {
+		name := users[index].Email
+		if isHandle {
+			name = users[index].Username
+		}
+		if strings.EqualFold(name, handle) {
+			return &users[index]
+		}
+	}
+
+	return nil
+}
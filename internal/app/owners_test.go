@@ -0,0 +1,142 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type ownerAPI struct {
+	fakeAPI
+	users    []rollbar.User
+	usersErr error
+	assigned *uint64
+}
+
+func (o *ownerAPI) ListUsers(ctx context.Context) ([]rollbar.User, error) {
+	return o.users, o.usersErr
+}
+
+func (o *ownerAPI) UpdateItem(ctx context.Context, itemID domain.ItemID, patch rollbar.ItemPatch) error {
+	o.assigned = patch.AssignedUserID
+	return nil
+}
+
+func testOwnersOptions(assign bool) OwnersOptions {
+	rules := map[string][]string{
+		"pay/charge.py": {"@acme/payments", "@ana"},
+		"pay/retry.py":  {"bo@example.com", "@ana"},
+		"web/view.py":   {"@acme/web"},
+	}
+
+	return OwnersOptions{
+		Locate: func(filename string) (string, bool) {
+			path := strings.TrimPrefix(filename, "/srv/app/")
+			_, ok := rules[path]
+			return path, ok || path == "web/unowned.py"
+		},
+		Owners: func(path string) (string, []string, bool) {
+			owners, ok := rules[path]
+			return "/" + path, owners, ok
+		},
+		Frames: 2,
+		Assign: assign,
+	}
+}
+
+// newOwnerAPI serves item 42 with frames in web, pay and the standard
+// library, and users ana and bo.
+func newOwnerAPI(t *testing.T) *ownerAPI {
+	t.Helper()
+
+	instance := traceInstance(t,
+		rollbar.Frame{Filename: "/srv/app/web/view.py", Lineno: 3},
+		rollbar.Frame{Filename: "/srv/app/pay/retry.py", Lineno: 20},
+		rollbar.Frame{Filename: "/usr/lib/python3/http/client.py", Lineno: 900},
+		rollbar.Frame{Filename: "/srv/app/pay/charge.py", Lineno: 12},
+	)
+	users := []rollbar.User{{ID: 7, Username: "ana", Email: "ana@example.com"}, {ID: 8, Username: "bo", Email: "BO@example.com"}}
+	return &ownerAPI{fakeAPI: fakeAPI{item: rollbar.Item{ID: 123, Counter: 42, Title: "KeyError"}, instance: instance}, users: users}
+}
+
+// checkOwnerCandidates fails unless candidates rank ana, who owns both pay
+// frames, then the payments team and bo, matched by email.
+func checkOwnerCandidates(t *testing.T, candidates []OwnerCandidate) {
+	t.Helper()
+
+	want := []string{"@ana", "@acme/payments", "bo@example.com"}
+	for index, candidate := range candidates {
+		if candidate.Owner != want[index] {
+			t.Fatalf("candidate %d = %q, want %q in %+v", index, candidate.Owner, want[index], candidates)
+		}
+	}
+	if candidates[0].Frames != 2 || candidates[1].User != nil || candidates[2].User.ID != 8 {
+		t.Fatalf("unexpected candidates %+v", candidates)
+	}
+}
+
+func TestSuggestOwners(t *testing.T) {
+	t.Parallel()
+
+	api := newOwnerAPI(t)
+	result, err := SuggestOwners(context.Background(), api, 42, testOwnersOptions(false))
+	if err != nil {
+		t.Fatalf("SuggestOwners() error = %v", err)
+	}
+	if len(result.Frames) != 2 || result.Frames[0].Path != "pay/charge.py" || result.Frames[1].Pattern != "/pay/retry.py" {
+		t.Fatalf("unexpected frames %+v", result.Frames)
+	}
+	checkOwnerCandidates(t, result.Candidates)
+	if result.Assignee == nil || result.Assignee.ID != 7 || result.Assigned || api.assigned != nil {
+		t.Fatalf("expected a suggestion only, got %+v", result)
+	}
+}
+
+func TestSuggestOwnersAssigns(t *testing.T) {
+	t.Parallel()
+
+	api := newOwnerAPI(t)
+	result, err := SuggestOwners(context.Background(), api, 42, testOwnersOptions(true))
+	if err != nil || !result.Assigned || api.assigned == nil || *api.assigned != 7 {
+		t.Fatalf("expected the item assigned to user 7, got %+v, %v", result, err)
+	}
+}
+
+func TestSuggestOwnersWithoutUser(t *testing.T) {
+	t.Parallel()
+
+	instance := traceInstance(t, rollbar.Frame{Filename: "/srv/app/web/view.py"}, rollbar.Frame{Filename: "/srv/app/web/unowned.py"})
+	api := &ownerAPI{fakeAPI: fakeAPI{item: rollbar.Item{ID: 123, Counter: 42}, instance: instance}}
+
+	tests := []struct {
+		name    string
+		api     *ownerAPI
+		options OwnersOptions
+		want    string
+	}{
+		{name: "suggestion", api: api, options: testOwnersOptions(false)},
+		{name: "assign", api: api, options: testOwnersOptions(true), want: "no CODEOWNERS owner of item 42 is a Rollbar user"},
+		{name: "users", api: &ownerAPI{fakeAPI: api.fakeAPI, usersErr: errors.New("forbidden")}, options: testOwnersOptions(false), want: "list users: forbidden"},
+		{name: "item", api: &ownerAPI{fakeAPI: fakeAPI{err: errors.New("boom")}}, options: testOwnersOptions(false), want: "resolve item id: boom"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			result, err := SuggestOwners(context.Background(), tt.api, 42, tt.options)
+			if tt.want != "" {
+				if err == nil || err.Error() != tt.want {
+					t.Fatalf("expected %q, got %v", tt.want, err)
+				}
+				return
+			}
+			if err != nil || result.Assignee != nil || len(result.Frames) != 2 || result.Frames[0].Owners == nil || len(result.Candidates) != 1 {
+				t.Fatalf("unexpected result %+v, %v", result, err)
+			}
+		})
+	}
+}
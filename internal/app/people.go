@@ -0,0 +1,134 @@
+package app
+
+import (
+	"cmp"
+	"context"
+	"slices"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// personColumns read the person fields besides the id from each occurrence.
+var personColumns = []Column{
+	{Path: "data.person.username", steps: []columnStep{{key: "data"}, {key: "person"}, {key: "username"}}},
+	{Path: "data.person.email", steps: []columnStep{{key: "data"}, {key: "person"}, {key: "email"}}},
+}
+
+type PeopleOptions struct {
+	// MaxOccurrences caps how many occurrences are read, newest first.
+	MaxOccurrences int
+	Since          *time.Time
+	// Top is how many people to list, most affected first.
+	Top int
+}
+
+// AffectedPerson is one user an item's occurrences reported, with when the
+// read occurrences first and last hit them.
+type AffectedPerson struct {
+	ID          string  `json:"id"`
+	Username    string  `json:"username,omitempty"`
+	Email       string  `json:"email,omitempty"`
+	Occurrences int     `json:"occurrences"`
+	FirstSeen   *uint64 `json:"first_seen,omitempty"`
+	LastSeen    *uint64 `json:"last_seen,omitempty"`
+}
+
+// ItemPeople summarizes who an item affected among the occurrences read.
+// Anonymous counts occurrences without a person; People is the Top most
+// affected, while UniquePeople counts them all.
+type ItemPeople struct {
+	Counter       domain.ItemCounter `json:"counter"`
+	Scanned       int                `json:"scanned"`
+	Anonymous     int                `json:"anonymous"`
+	UniquePeople  int                `json:"unique_people"`
+	FirstAffected *uint64            `json:"first_affected,omitempty"`
+	LastAffected  *uint64            `json:"last_affected,omitempty"`
+	People        []AffectedPerson   `json:"people"`
+}
+
+// ItemPeople pages through an item's occurrences, newest first, until
+// MaxOccurrences are read or one older than Since turns up, and tallies the
+// person each one reported.
+func (s *Service) ItemPeople(ctx context.Context, counter domain.ItemCounter, options PeopleOptions) (ItemPeople, error) {
+	result := ItemPeople{Counter: counter}
+	byID := map[string]*AffectedPerson{}
+	occurrenceOptions := OccurrenceOptions{Limit: options.MaxOccurrences, Since: options.Since, Columns: personColumns}
+	err := s.StreamOccurrences(ctx, counter, occurrenceOptions, func(batch []OccurrenceSummary) error {
+		for _, occurrence := range batch {
+			result.Scanned++
+			if occurrence.PersonID == "" {
+				result.Anonymous++
+				continue
+			}
+			tallyPerson(byID, occurrence)
+			result.FirstAffected = earliest(result.FirstAffected, occurrence.Timestamp)
+			result.LastAffected = latest(result.LastAffected, occurrence.Timestamp)
+		}
+		return nil
+	})
+	if err != nil {
+		return ItemPeople{}, err
+	}
+
+	result.UniquePeople = len(byID)
+	result.People = topPeople(byID, options.Top)
+
+	return result, nil
+}
+
+// tallyPerson counts occurrence against its person, keeping the newest
+// username and email seen since occurrences arrive newest first.
+func tallyPerson(byID map[string]*AffectedPerson, occurrence OccurrenceSummary) {
+	person, ok := byID[occurrence.PersonID]
+	if !ok {
+		person = &AffectedPerson{ID: occurrence.PersonID}
+		byID[occurrence.PersonID] = person
+	}
+	person.Occurrences++
+	person.FirstSeen = earliest(person.FirstSeen, occurrence.Timestamp)
+	person.LastSeen = latest(person.LastSeen, occurrence.Timestamp)
+	if person.Username == "" {
+		person.Username = occurrence.Columns[personColumns[0].Path]
+	}
+	if person.Email == "" {
+		person.Email = occurrence.Columns[personColumns[1].Path]
+	}
+}
+
+// topPeople is the top most affected people, ties broken by the most recently
+// affected and then by id. A non-positive top keeps everyone.
+func topPeople(byID map[string]*AffectedPerson, top int) []AffectedPerson {
+	people := make([]AffectedPerson, 0, len(byID))
+	for _, person := range byID {
+		people = append(people, *person)
+	}
+	slices.SortFunc(people, func(a, b AffectedPerson) int {
+		return cmp.Or(
+			cmp.Compare(b.Occurrences, a.Occurrences),
+			cmp.Compare(uint64Value(b.LastSeen), uint64Value(a.LastSeen)),
+			cmp.Compare(a.ID, b.ID),
+		)
+	})
+	if top > 0 && len(people) > top {
+		people = people[:top]
+	}
+
+	return people
+}
+
+func earliest(current *uint64, candidate *uint64) *uint64 {
+	if candidate == nil || (current != nil && *current <= *candidate) {
+		return current
+	}
+
+	return candidate
+}
+
+func latest(current *uint64, candidate *uint64) *uint64 {
+	if candidate == nil || (current != nil && *current >= *candidate) {
+		return current
+	}
+
+	return candidate
+}
@@ -0,0 +1,78 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func personInstance(id uint64, timestamp uint64, person string) rollbar.ItemInstance {
+	data := fmt.Sprintf(`{"environment":"production","person":%s}`, person)
+	return rollbar.ItemInstance{ID: id, Timestamp: &timestamp, Data: json.RawMessage(data), Raw: json.RawMessage(fmt.Sprintf(`{"id":%d,"data":%s}`, id, data))}
+}
+
+// peoplePages lists six occurrences, newest first, from three people and
+// one anonymous visitor.
+func peoplePages() [][]rollbar.ItemInstance {
+	return [][]rollbar.ItemInstance{
+		{personInstance(6, 600, `{"id":"u1","username":"ann"}`), personInstance(5, 500, `{}`), personInstance(4, 400, `{"id":2,"email":"bo@example.com"}`)},
+		{personInstance(3, 300, `{"id":"u1","username":"ann-old","email":"ann@example.com"}`), personInstance(2, 200, `{"id":"u3"}`), personInstance(1, 100, `{"id":"u1"}`)},
+	}
+}
+
+func TestServiceItemPeople(t *testing.T) {
+	t.Parallel()
+
+	since := time.Unix(450, 0)
+
+	tests := []struct {
+		name    string
+		options PeopleOptions
+		want    ItemPeople
+	}{
+		{
+			name:    "all",
+			options: PeopleOptions{Top: 10},
+			want: ItemPeople{Counter: 7, Scanned: 6, Anonymous: 1, UniquePeople: 3, FirstAffected: uint64Ptr(100), LastAffected: uint64Ptr(600), People: []AffectedPerson{
+				{ID: "u1", Username: "ann", Email: "ann@example.com", Occurrences: 3, FirstSeen: uint64Ptr(100), LastSeen: uint64Ptr(600)},
+				{ID: "2", Email: "bo@example.com", Occurrences: 1, FirstSeen: uint64Ptr(400), LastSeen: uint64Ptr(400)},
+				{ID: "u3", Occurrences: 1, FirstSeen: uint64Ptr(200), LastSeen: uint64Ptr(200)},
+			}},
+		},
+		{
+			name:    "top and max",
+			options: PeopleOptions{MaxOccurrences: 4, Top: 1},
+			want: ItemPeople{Counter: 7, Scanned: 4, Anonymous: 1, UniquePeople: 2, FirstAffected: uint64Ptr(300), LastAffected: uint64Ptr(600), People: []AffectedPerson{
+				{ID: "u1", Username: "ann", Email: "ann@example.com", Occurrences: 2, FirstSeen: uint64Ptr(300), LastSeen: uint64Ptr(600)},
+			}},
+		},
+		{
+			name:    "since",
+			options: PeopleOptions{Since: &since},
+			want: ItemPeople{Counter: 7, Scanned: 2, Anonymous: 1, UniquePeople: 1, FirstAffected: uint64Ptr(600), LastAffected: uint64Ptr(600), People: []AffectedPerson{
+				{ID: "u1", Username: "ann", Occurrences: 1, FirstSeen: uint64Ptr(600), LastSeen: uint64Ptr(600)},
+			}},
+		},
+	}
+
+	for _, tc := range tests {
+		got, err := NewService(&instancesAPI{pages: peoplePages()}).ItemPeople(context.Background(), 7, tc.options)
+		if err != nil || !reflect.DeepEqual(got, tc.want) {
+			t.Fatalf("%s: ItemPeople() = %+v, %v; want %+v", tc.name, got, err, tc.want)
+		}
+	}
+}
+
+func TestServiceItemPeopleResolveError(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewService(fakeAPI{err: errors.New("bad")}).ItemPeople(context.Background(), 7, PeopleOptions{}); err == nil {
+		t.Fatal("expected a resolve error")
+	}
+}
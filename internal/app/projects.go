@@ -0,0 +1,117 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// ProjectAdminAPI is the account-token part of the Rollbar API used to turn a
+// project id or slug into a project token.
+type ProjectAdminAPI interface {
+	ListProjects(ctx context.Context) ([]rollbar.Project, error)
+	ListProjectAccessTokens(ctx context.Context, projectID uint64) ([]rollbar.ProjectAccessToken, error)
+}
+
+// ProjectIDCache remembers which project id a slug resolved to, so only the
+// token lookup hits the API on later runs.
+type ProjectIDCache interface {
+	Lookup(slug string) (uint64, bool)
+	Store(slug string, projectID uint64) error
+}
+
+type ResolvedProject struct {
+	ID    uint64
+	Name  string
+	Token string
+}
+
+// ResolveProject resolves a numeric project id or a project slug (the project
+// name, lowercased with runs of other characters turned into dashes) and
+// picks the project's best enabled token: read+write, else read.
+func ResolveProject(ctx context.Context, api ProjectAdminAPI, cache ProjectIDCache, ref string) (ResolvedProject, error) {
+	ref = strings.TrimSpace(ref)
+	project, err := findProject(ctx, api, cache, ref)
+	if err != nil {
+		return ResolvedProject{}, err
+	}
+
+	tokens, err := api.ListProjectAccessTokens(ctx, project.ID)
+	if err != nil {
+		return ResolvedProject{}, fmt.Errorf("list project tokens: %w", err)
+	}
+	token, ok := pickProjectToken(tokens)
+	if !ok {
+		return ResolvedProject{}, fmt.Errorf("project %q has no enabled token with read scope", ref)
+	}
+
+	return ResolvedProject{ID: project.ID, Name: project.Name, Token: token}, nil
+}
+
+func findProject(ctx context.Context, api ProjectAdminAPI, cache ProjectIDCache, ref string) (rollbar.Project, error) {
+	if id, err := strconv.ParseUint(ref, 10, 64); err == nil && id > 0 {
+		return rollbar.Project{ID: id, Name: ref}, nil
+	}
+	slug := ProjectSlug(ref)
+	if id, ok := cache.Lookup(slug); ok {
+		return rollbar.Project{ID: id, Name: ref}, nil
+	}
+
+	projects, err := api.ListProjects(ctx)
+	if err != nil {
+		return rollbar.Project{}, fmt.Errorf("list projects: %w", err)
+	}
+	for _, project := range projects {
+		if ProjectSlug(project.Name) == slug {
+			_ = cache.Store(slug, project.ID)
+			return project, nil
+		}
+	}
+
+	return rollbar.Project{}, fmt.Errorf("no project named %q in this account", ref)
+}
+
+func pickProjectToken(tokens []rollbar.ProjectAccessToken) (string, bool) {
+	best, bestScore := "", 0
+	for _, token := range tokens {
+		if token.Status != "" && token.Status != "enabled" {
+			continue
+		}
+		score := 0
+		if slices.Contains(token.Scopes, "read") {
+			score = 1
+			if slices.Contains(token.Scopes, "write") {
+				score = 2
+			}
+		}
+		if score > bestScore {
+			best, bestScore = token.AccessToken, score
+		}
+	}
+
+	return best, bestScore > 0
+}
+
+// ProjectSlug normalizes a project name for matching: "Billing API" and
+// "billing-api" share the slug "billing-api".
+func ProjectSlug(name string) string {
+	var builder strings.Builder
+	dash := false
+	for _, r := range strings.ToLower(strings.TrimSpace(name)) {
+		if (r >= 'a' && r <= 'z') || (r >= '0' && r <= '9') {
+			if dash && builder.Len() > 0 {
+				builder.WriteByte('-')
+			}
+			builder.WriteRune(r)
+			dash = false
+			continue
+		}
+		dash = true
+	}
+
+	return builder.String()
+}
@@ -0,0 +1,114 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeProjectAdmin struct {
+	projects     []rollbar.Project
+	tokens       map[uint64][]rollbar.ProjectAccessToken
+	projectCalls int
+}
+
+func (f *fakeProjectAdmin) ListProjects(ctx context.Context) ([]rollbar.Project, error) {
+	f.projectCalls++
+	return f.projects, nil
+}
+
+func (f *fakeProjectAdmin) ListProjectAccessTokens(ctx context.Context, projectID uint64) ([]rollbar.ProjectAccessToken, error) {
+	tokens, ok := f.tokens[projectID]
+	if !ok {
+		return nil, errors.New("project not found")
+	}
+	return tokens, nil
+}
+
+type mapCache map[string]uint64
+
+func (m mapCache) Lookup(slug string) (uint64, bool) {
+	id, ok := m[slug]
+	return id, ok
+}
+
+func (m mapCache) Store(slug string, projectID uint64) error {
+	m[slug] = projectID
+	return nil
+}
+
+// newProjectAdmin has Web, with only a post token, and Billing API, whose one
+// enabled read token is "read-write".
+func newProjectAdmin() *fakeProjectAdmin {
+	return &fakeProjectAdmin{
+		projects: []rollbar.Project{{ID: 1, Name: "Web"}, {ID: 2, Name: "Billing API"}},
+		tokens: map[uint64][]rollbar.ProjectAccessToken{
+			1: {{AccessToken: "post-only", Scopes: []string{"post_server_item"}, Status: "enabled"}},
+			2: {
+				{AccessToken: "read-only", Scopes: []string{"read"}, Status: "enabled"},
+				{AccessToken: "disabled-rw", Scopes: []string{"read", "write"}, Status: "disabled"},
+				{AccessToken: "read-write", Scopes: []string{"read", "write"}, Status: "enabled"},
+			},
+		},
+	}
+}
+
+func TestResolveProject(t *testing.T) {
+	t.Parallel()
+
+	api := newProjectAdmin()
+	cache := mapCache{}
+
+	tests := []struct {
+		ref       string
+		wantToken string
+		wantErr   string
+	}{
+		{ref: "billing-api", wantToken: "read-write"},
+		{ref: "Billing API", wantToken: "read-write"},
+		{ref: "2", wantToken: "read-write"},
+		{ref: "web", wantErr: "no enabled token with read scope"},
+		{ref: "mobile", wantErr: `no project named "mobile"`},
+		{ref: "99", wantErr: "list project tokens"},
+	}
+	for _, tc := range tests {
+		project, err := ResolveProject(context.Background(), api, cache, tc.ref)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("ResolveProject(%q) error = %v, want %q", tc.ref, err, tc.wantErr)
+			}
+			continue
+		}
+		if err != nil || project.Token != tc.wantToken || project.ID != 2 {
+			t.Fatalf("ResolveProject(%q) = %+v, %v", tc.ref, project, err)
+		}
+	}
+
+	if cache["billing-api"] != 2 {
+		t.Fatalf("expected slug cached, got %v", cache)
+	}
+	// "billing-api" and "Billing API" share a slug; the second lookup is a hit.
+	// "web" and "mobile" each list projects once.
+	if api.projectCalls != 3 {
+		t.Fatalf("expected 3 project listings, got %d", api.projectCalls)
+	}
+}
+
+func TestProjectSlug(t *testing.T) {
+	t.Parallel()
+
+	tests := map[string]string{
+		"Billing API":      "billing-api",
+		"  billing--api  ": "billing-api",
+		"web_app (prod)":   "web-app-prod",
+		"---":              "",
+	}
+	for input, want := range tests {
+		if got := ProjectSlug(input); got != want {
+			t.Fatalf("ProjectSlug(%q) = %q, want %q", input, got, want)
+		}
+	}
+}
@@ -0,0 +1,168 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// RegressionAPI is the part of the Rollbar client regression reports use.
+type RegressionAPI interface {
+	ListDeploys(ctx context.Context, page int) (rollbar.DeployPage, error)
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+}
+
+type RegressionKind string
+
+const (
+	RegressionNew         RegressionKind = "new"
+	RegressionReactivated RegressionKind = "reactivated"
+)
+
+type RegressionOptions struct {
+	// Revision is the deployed revision, or a prefix of it such as a short SHA.
+	Revision    string
+	Environment string
+	Limit       int
+}
+
+// Regression is an active item that first occurred, or was reactivated,
+// while the deploy was the latest in its environment.
+type Regression struct {
+	IssueSummary
+	Kind                     RegressionKind `json:"kind"`
+	FirstOccurrenceTimestamp *uint64        `json:"first_occurrence_timestamp,omitempty"`
+	LastActivatedTimestamp   *uint64        `json:"last_activated_timestamp,omitempty"`
+}
+
+// RegressionReport is the window a deploy was live, from its start until the
+// next deploy to the same environment (Until is nil while it is the latest),
+// and the items it introduced.
+type RegressionReport struct {
+	Deploy rollbar.Deploy  `json:"deploy"`
+	Next   *rollbar.Deploy `json:"next_deploy,omitempty"`
+	Since  uint64          `json:"since"`
+	Until  *uint64         `json:"until,omitempty"`
+	Items  []Regression    `json:"items"`
+}
+
+// Regressions finds the newest deploy of a revision and flags the active items
+// in its environment that first occurred after it started (new) or were
+// reactivated after it started (reactivated), up to the next deploy there.
+// New items come first, then the most frequent.
+func Regressions(ctx context.Context, api RegressionAPI, options RegressionOptions) (RegressionReport, error) {
+	report, err := findDeployWindow(ctx, api, options)
+	if err != nil {
+		return RegressionReport{}, err
+	}
+
+	items, err := scanItems(ctx, api, activeItemsIn(report.Deploy.Environment), func(item rollbar.Item) bool {
+		_, ok := report.classify(item)
+		return ok
+	})
+	if err != nil {
+		return RegressionReport{}, err
+	}
+	for _, item := range items {
+		kind, _ := report.classify(item)
+		report.Items = append(report.Items, Regression{
+			IssueSummary:             mapSummary(item),
+			Kind:                     kind,
+			FirstOccurrenceTimestamp: item.FirstOccurrenceTimestamp,
+			LastActivatedTimestamp:   item.LastActivatedTimestamp,
+		})
+	}
+
+	sortRegressions(report.Items)
+	if options.Limit > 0 && len(report.Items) > options.Limit {
+		report.Items = report.Items[:options.Limit]
+	}
+
+	return report, nil
+}
+
+// findDeployWindow pages through deploys, newest first, to the newest one of
+// the revision, remembering the latest deploy seen per environment so the
+// one that followed it is at hand.
+func findDeployWindow(ctx context.Context, api RegressionAPI, options RegressionOptions) (RegressionReport, error) {
+	revision := strings.TrimSpace(options.Revision)
+	if revision == "" {
+		return RegressionReport{}, errors.New("deploy revision is required")
+	}
+	environment := strings.TrimSpace(options.Environment)
+
+	following := map[string]rollbar.Deploy{}
+	for page := 1; ; page++ {
+		result, err := api.ListDeploys(ctx, page)
+		if err != nil {
+			return RegressionReport{}, fmt.Errorf("list deploys: %w", err)
+		}
+		if len(result.Deploys) == 0 {
+			return RegressionReport{}, fmt.Errorf("no deploy of revision %q found", revision)
+		}
+		for _, deploy := range result.Deploys {
+			if strings.HasPrefix(deploy.Revision, revision) && matchesTextFilter(deploy.Environment, environment) {
+				return deployWindow(deploy, following[deploy.Environment])
+			}
+			following[deploy.Environment] = deploy
+		}
+	}
+}
+
+func deployWindow(deploy rollbar.Deploy, next rollbar.Deploy) (RegressionReport, error) {
+	since := deployTime(deploy)
+	if since == nil {
+		return RegressionReport{}, fmt.Errorf("deploy %d has no start or finish time", deploy.ID)
+	}
+
+	report := RegressionReport{Deploy: deploy, Since: *since, Items: []Regression{}}
+	if next.ID != 0 {
+		report.Next = &next
+		report.Until = deployTime(next)
+	}
+
+	return report, nil
+}
+
+// deployTime is when a deploy started, or finished when Rollbar has no start.
+func deployTime(deploy rollbar.Deploy) *uint64 {
+	if deploy.StartTime != nil {
+		return deploy.StartTime
+	}
+
+	return deploy.FinishTime
+}
+
+func (r RegressionReport) classify(item rollbar.Item) (RegressionKind, bool) {
+	switch {
+	case r.inWindow(item.FirstOccurrenceTimestamp):
+		return RegressionNew, true
+	case r.inWindow(item.LastActivatedTimestamp):
+		return RegressionReactivated, true
+	default:
+		return "", false
+	}
+}
+
+func (r RegressionReport) inWindow(timestamp *uint64) bool {
+	if timestamp == nil || *timestamp < r.Since {
+		return false
+	}
+
+	return r.Until == nil || *timestamp < *r.Until
+}
+
+func sortRegressions(regressions []Regression) {
+	sort.SliceStable(regressions, func(i int, j int) bool {
+		left, right := regressions[i], regressions[j]
+		if left.Kind != right.Kind {
+			return left.Kind == RegressionNew
+		}
+
+		return uint64Value(left.Occurrences) > uint64Value(right.Occurrences)
+	})
+}
@@ -0,0 +1,103 @@
+package app
+
+import (
+	"context"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type regressionAPI struct {
+	pagedAPI
+	deploys [][]rollbar.Deploy
+}
+
+func (a *regressionAPI) ListDeploys(ctx context.Context, page int) (rollbar.DeployPage, error) {
+	if page > len(a.deploys) {
+		return rollbar.DeployPage{Page: page}, nil
+	}
+
+	return rollbar.DeployPage{Deploys: a.deploys[page-1], Page: page}, nil
+}
+
+func testDeploy(id uint64, environment string, revision string, start uint64) rollbar.Deploy {
+	return rollbar.Deploy{ID: id, Environment: environment, Revision: revision, StartTime: &start}
+}
+
+// newRegressionAPI serves five active items around production deploys of
+// zzz at 1000, abc123def at 2000 and ccc333 at 3000, and a staging deploy
+// of abc999 at 2500.
+func newRegressionAPI() *regressionAPI {
+	return &regressionAPI{
+		pagedAPI: pagedAPI{pages: [][]rollbar.Item{{
+			issueFixture(fixture{counter: 1, status: rollbar.StatusActive, firstSeen: 2100, activated: 2100, occurrences: 5}),
+			issueFixture(fixture{counter: 2, status: rollbar.StatusActive, firstSeen: 500, activated: 2200, occurrences: 50}),
+			issueFixture(fixture{counter: 3, status: rollbar.StatusActive, firstSeen: 3100, activated: 3100, occurrences: 80}),
+			issueFixture(fixture{counter: 4, status: rollbar.StatusActive, firstSeen: 500, activated: 1500, occurrences: 9}),
+			issueFixture(fixture{counter: 5, status: rollbar.StatusActive, firstSeen: 2999, activated: 2999, occurrences: 1}),
+		}}},
+		deploys: [][]rollbar.Deploy{
+			{testDeploy(4, "production", "ccc333", 3000), testDeploy(3, "staging", "abc999", 2500)},
+			{testDeploy(2, "production", "abc123def", 2000), testDeploy(1, "production", "zzz", 1000)},
+		},
+	}
+}
+
+// checkRegressionWindow fails unless report covers production deploy 2
+// until deploy 4.
+func checkRegressionWindow(t *testing.T, report RegressionReport) {
+	t.Helper()
+
+	if report.Deploy.ID != 2 || report.Next == nil || report.Next.ID != 4 || report.Since != 2000 || report.Until == nil || *report.Until != 3000 {
+		t.Fatalf("unexpected window %+v", report)
+	}
+}
+
+func TestRegressions(t *testing.T) {
+	t.Parallel()
+
+	api := newRegressionAPI()
+	report, err := Regressions(context.Background(), api, RegressionOptions{Revision: " abc ", Environment: "production"})
+	if err != nil {
+		t.Fatalf("Regressions() error = %v", err)
+	}
+	var counters []uint64
+	var kinds []RegressionKind
+	for _, regression := range report.Items {
+		counters = append(counters, uint64(regression.Counter))
+		kinds = append(kinds, regression.Kind)
+	}
+	if !slices.Equal(counters, []uint64{1, 5, 2}) || !slices.Equal(kinds, []RegressionKind{RegressionNew, RegressionNew, RegressionReactivated}) {
+		t.Fatalf("Regressions() = %v %v", counters, kinds)
+	}
+	checkRegressionWindow(t, report)
+	if query := api.queries[0]; query.Status != "active" || !slices.Equal(query.Environments, []string{"production"}) {
+		t.Fatalf("unexpected item query %+v", query)
+	}
+}
+
+func TestRegressionsLatestDeploy(t *testing.T) {
+	t.Parallel()
+
+	latest, err := Regressions(context.Background(), newRegressionAPI(), RegressionOptions{Revision: "abc", Limit: 1})
+	if err != nil || latest.Deploy.ID != 3 || latest.Until != nil || len(latest.Items) != 1 || latest.Items[0].Counter != 3 {
+		t.Fatalf("expected the newest matching deploy, open-ended, got %+v, %v", latest, err)
+	}
+}
+
+func TestRegressionsErrors(t *testing.T) {
+	t.Parallel()
+
+	api := newRegressionAPI()
+	failures := []RegressionOptions{{Revision: " "}, {Revision: "missing"}}
+	for _, options := range failures {
+		if _, err := Regressions(context.Background(), api, options); err == nil {
+			t.Fatalf("%+v: expected an error", options)
+		}
+	}
+	api.deploys = [][]rollbar.Deploy{{{ID: 9, Environment: "production", Revision: "abc"}}}
+	if _, err := Regressions(context.Background(), api, RegressionOptions{Revision: "abc"}); err == nil {
+		t.Fatal("expected an error for a deploy without times")
+	}
+}
@@ -0,0 +1,64 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// RQLRunner submits an RQL query and waits for its rows.
+type RQLRunner interface {
+	RunRQL(ctx context.Context, query string) (rollbar.RQLResult, error)
+}
+
+type QueryResult struct {
+	JobID   uint64              `json:"job_id"`
+	Columns []string            `json:"columns"`
+	Rows    [][]json.RawMessage `json:"rows"`
+}
+
+func RunQuery(ctx context.Context, runner RQLRunner, query string) (QueryResult, error) {
+	result, err := runner.RunRQL(ctx, query)
+	if err != nil {
+		return QueryResult{}, fmt.Errorf("run rql query: %w", err)
+	}
+
+	rows := result.Rows
+	if rows == nil {
+		rows = [][]json.RawMessage{}
+	}
+
+	return QueryResult{JobID: result.JobID, Columns: result.Columns, Rows: rows}, nil
+}
+
+// Cells returns each row as display strings: strings unquoted, null empty,
+// and numbers or nested values as their JSON text.
+func (r QueryResult) Cells() [][]string {
+	cells := make([][]string, 0, len(r.Rows))
+	for _, row := range r.Rows {
+		values := make([]string, 0, len(row))
+		for _, raw := range row {
+			values = append(values, cellText(raw))
+		}
+		cells = append(cells, values)
+	}
+
+	return cells
+}
+
+func cellText(raw json.RawMessage) string {
+	trimmed := strings.TrimSpace(string(raw))
+	if trimmed == "" || trimmed == "null" {
+		return ""
+	}
+
+	var text string
+	if err := json.Unmarshal(raw, &text); err == nil {
+		return text
+	}
+
+	return trimmed
+}
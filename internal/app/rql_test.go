@@ -0,0 +1,52 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeRQLRunner struct {
+	result rollbar.RQLResult
+	err    error
+}
+
+func (f fakeRQLRunner) RunRQL(ctx context.Context, query string) (rollbar.RQLResult, error) {
+	return f.result, f.err
+}
+
+func TestRunQuery(t *testing.T) {
+	t.Parallel()
+
+	runner := fakeRQLRunner{result: rollbar.RQLResult{
+		JobID:   5,
+		Columns: []string{"item.counter", "item.title", "count(*)", "extra"},
+		Rows:    [][]json.RawMessage{{json.RawMessage(`269`), json.RawMessage(`"RST_STREAM"`), json.RawMessage(`"12"`), json.RawMessage(`null`)}},
+	}}
+	result, err := RunQuery(context.Background(), runner, "SELECT 1")
+	if err != nil {
+		t.Fatalf("RunQuery() error = %v", err)
+	}
+	if result.JobID != 5 || len(result.Columns) != 4 {
+		t.Fatalf("unexpected result: %+v", result)
+	}
+	want := [][]string{{"269", "RST_STREAM", "12", ""}}
+	if got := result.Cells(); !reflect.DeepEqual(got, want) {
+		t.Fatalf("Cells() = %v, want %v", got, want)
+	}
+
+	empty, err := RunQuery(context.Background(), fakeRQLRunner{result: rollbar.RQLResult{JobID: 6}}, "SELECT 1")
+	if err != nil || empty.Rows == nil {
+		t.Fatalf("expected empty non-nil rows, got %+v, %v", empty, err)
+	}
+
+	_, err = RunQuery(context.Background(), fakeRQLRunner{err: errors.New("boom")}, "SELECT 1")
+	if err == nil || !strings.Contains(err.Error(), "run rql query") {
+		t.Fatalf("expected wrapped error, got %v", err)
+	}
+}
@@ -0,0 +1,109 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// maxSearchPages bounds how many /items pages one search reads before
+// ranking; the best matches are almost always on the first.
+const maxSearchPages = 3
+
+type SearchOptions struct {
+	Text    string
+	Filters IssueFilters
+	Limit   int
+}
+
+// SearchResult is an item with its relevance to the search text.
+type SearchResult struct {
+	IssueSummary
+	Score int `json:"score"`
+}
+
+// Search runs Rollbar's free-text item search and ranks what comes back by
+// how well each title matches the text: the whole phrase counts most, then
+// each word matched whole, then each word found inside another. Ties go to
+// active items, then to the most recently seen.
+func (s *Service) Search(ctx context.Context, options SearchOptions) ([]SearchResult, error) {
+	text := strings.TrimSpace(options.Text)
+	if text == "" {
+		return nil, errors.New("search text is required")
+	}
+
+	filters := normalizeIssueFilters(options.Filters)
+	query := listQuery(ListOptions{Query: text}, filters)
+	items := make([]rollbar.Item, 0)
+	for page := 1; page <= maxSearchPages; page++ {
+		query.Page = page
+		result, err := s.api.ListItemsPage(ctx, query)
+		if err != nil {
+			return nil, fmt.Errorf("search items: %w", err)
+		}
+		items = append(items, filterItems(result.Items, filters)...)
+		if len(result.Items) == 0 || listComplete(result, page, 0, 0) {
+			break
+		}
+	}
+
+	results := rankItems(items, text)
+	if options.Limit > 0 && len(results) > options.Limit {
+		results = results[:options.Limit]
+	}
+
+	return results, nil
+}
+
+func rankItems(items []rollbar.Item, text string) []SearchResult {
+	phrase := strings.ToLower(text)
+	terms := strings.Fields(phrase)
+
+	results := make([]SearchResult, 0, len(items))
+	for _, item := range items {
+		results = append(results, SearchResult{IssueSummary: mapSummary(item), Score: searchScore(item.Title, phrase, terms)})
+	}
+	sort.SliceStable(results, func(i int, j int) bool {
+		left, right := results[i], results[j]
+		if left.Score != right.Score {
+			return left.Score > right.Score
+		}
+		if leftActive, rightActive := left.Status == string(rollbar.StatusActive), right.Status == string(rollbar.StatusActive); leftActive != rightActive {
+			return leftActive
+		}
+		return uint64Value(left.LastOccurrenceTimestamp) > uint64Value(right.LastOccurrenceTimestamp)
+	})
+
+	return results
+}
+
+func searchScore(title string, phrase string, terms []string) int {
+	lower := strings.ToLower(title)
+	words := strings.FieldsFunc(lower, isSearchSeparator)
+
+	score := 0
+	if len(terms) > 1 && strings.Contains(lower, phrase) {
+		score += 10
+	}
+	for _, term := range terms {
+		switch {
+		case slices.Contains(words, term):
+			score += 3
+		case strings.Contains(lower, term):
+			score++
+		}
+	}
+
+	return score
+}
+
+// isSearchSeparator splits titles into words of ASCII letters, digits,
+// underscores, and any non-ASCII rune.
+func isSearchSeparator(r rune) bool {
+	return !('a' <= r && r <= 'z' || '0' <= r && r <= '9' || r == '_' || r > 127)
+}
@@ -0,0 +1,46 @@
+package app
+
+import (
+	"context"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestServiceSearch(t *testing.T) {
+	t.Parallel()
+
+	api := &pagedAPI{pages: [][]rollbar.Item{
+		{
+			issueFixture(fixture{counter: 1, title: "Checkout failed", status: rollbar.StatusActive, lastSeen: 100}),
+			issueFixture(fixture{counter: 2, title: "java.lang.NullPointerException in Checkout", status: rollbar.StatusResolved, lastSeen: 300}),
+			issueFixture(fixture{counter: 3, title: "NullPointerExceptionWrapper thrown in checkouts", status: rollbar.StatusActive, lastSeen: 200}),
+		},
+		{
+			issueFixture(fixture{counter: 4, title: "NullPointerException checkout retry", status: rollbar.StatusActive, lastSeen: 50}),
+			issueFixture(fixture{counter: 5, title: "checkout failed", status: rollbar.StatusResolved, lastSeen: 400}),
+		},
+	}}
+	service := NewService(api)
+
+	results, err := service.Search(context.Background(), SearchOptions{Text: " NullPointerException checkout ", Limit: 4})
+	if err != nil {
+		t.Fatalf("Search() error = %v", err)
+	}
+
+	var counters []uint64
+	for _, result := range results {
+		counters = append(counters, uint64(result.Counter))
+	}
+	if want := []uint64{4, 2, 1, 5}; !slices.Equal(counters, want) {
+		t.Fatalf("Search() counters = %v, want %v", counters, want)
+	}
+	if results[0].Score != 16 || api.queries[0].Query != "NullPointerException checkout" || len(api.queries) != 3 {
+		t.Fatalf("unexpected score %d or queries %+v", results[0].Score, api.queries)
+	}
+
+	if _, err := service.Search(context.Background(), SearchOptions{Text: "  "}); err == nil {
+		t.Fatal("expected an error for empty search text")
+	}
+}
@@ -19,8 +19,12 @@ type RollbarAPI interface {
 	GetItem(ctx context.Context, itemID domain.ItemID) (rollbar.Item, error)
 	UpdateItem(ctx context.Context, itemID domain.ItemID, patch rollbar.ItemPatch) error
 	GetLatestInstance(ctx context.Context, itemID domain.ItemID) (*rollbar.ItemInstance, error)
+	ListInstances(ctx context.Context, itemID domain.ItemID, page int) (rollbar.InstancePage, error)
 	ListActiveItems(ctx context.Context, limit int) ([]rollbar.Item, error)
 	ListItems(ctx context.Context, status string, page int) ([]rollbar.Item, error)
+	ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error)
+	ListEnvironments(ctx context.Context, page int) ([]rollbar.Environment, error)
+	FindUserByEmail(ctx context.Context, email string) (rollbar.User, error)
 }
 
 type Service struct {
@@ -36,18 +40,21 @@ type IssueSummary struct {
 	Counter                 domain.ItemCounter `json:"counter"`
 	Title                   string             `json:"title"`
 	Status                  string             `json:"status"`
+	Level                   string             `json:"level,omitempty"`
 	Environment             string             `json:"environment"`
 	LastOccurrenceTimestamp *uint64            `json:"last_occurrence_timestamp,omitempty"`
 	Occurrences             *uint64            `json:"occurrences,omitempty"`
+	Columns                 map[string]string  `json:"columns,omitempty"`
 	Raw                     json.RawMessage    `json:"raw,omitempty"`
 }
 
 type IssueDetail struct {
 	IssueSummary
-	MainError   string                `json:"main_error"`
-	ItemRaw     json.RawMessage       `json:"item_raw,omitempty"`
-	Instance    *rollbar.ItemInstance `json:"instance,omitempty"`
-	InstanceRaw json.RawMessage       `json:"instance_raw,omitempty"`
+	FirstOccurrenceTimestamp *uint64               `json:"first_occurrence_timestamp,omitempty"`
+	MainError                string                `json:"main_error"`
+	ItemRaw                  json.RawMessage       `json:"item_raw,omitempty"`
+	Instance                 *rollbar.ItemInstance `json:"instance,omitempty"`
+	InstanceRaw              json.RawMessage       `json:"instance_raw,omitempty"`
 }
 
 type IssueFilters struct {
@@ -109,6 +116,12 @@ func (s *Service) Show(ctx context.Context, counter domain.ItemCounter) (IssueDe
 		return IssueDetail{}, fmt.Errorf("resolve item id: %w", err)
 	}
 
+	return s.ShowByID(ctx, itemID)
+}
+
+// ShowByID is Show for callers that already hold the item id rather than the
+// project counter.
+func (s *Service) ShowByID(ctx context.Context, itemID domain.ItemID) (IssueDetail, error) {
 	item, err := s.api.GetItem(ctx, itemID)
 	if err != nil {
 		return IssueDetail{}, fmt.Errorf("get item: %w", err)
@@ -130,36 +143,56 @@ func (s *Service) Show(ctx context.Context, counter domain.ItemCounter) (IssueDe
 	}
 
 	return IssueDetail{
-		IssueSummary: mapSummary(item),
-		MainError:    mainError,
-		ItemRaw:      item.Raw,
-		Instance:     instance,
-		InstanceRaw:  instanceRaw,
+		IssueSummary:             mapSummary(item),
+		FirstOccurrenceTimestamp: item.FirstOccurrenceTimestamp,
+		MainError:                mainError,
+		ItemRaw:                  item.Raw,
+		Instance:                 instance,
+		InstanceRaw:              instanceRaw,
 	}, nil
 }
 
 func (s *Service) Resolve(ctx context.Context, counter domain.ItemCounter, resolvedInVersion string) (ItemActionResult, error) {
+	patch, err := ResolvePatch(resolvedInVersion)
+	if err != nil {
+		return ItemActionResult{}, err
+	}
+
+	return s.updateItemAndFetch(ctx, counter, patch, "resolved")
+}
+
+// ResolvePatch builds the patch that resolves an item, validating the version
+// Rollbar records alongside it.
+func ResolvePatch(resolvedInVersion string) (rollbar.ItemPatch, error) {
 	trimmedVersion := strings.TrimSpace(resolvedInVersion)
 	if len(trimmedVersion) > maxResolvedVersionLength {
-		return ItemActionResult{}, fmt.Errorf("resolved_in_version must be <= %d characters", maxResolvedVersionLength)
+		return rollbar.ItemPatch{}, fmt.Errorf("resolved_in_version must be <= %d characters", maxResolvedVersionLength)
 	}
 
-	patch := rollbar.ItemPatch{Status: "resolved", ResolvedInVersion: trimmedVersion}
-
-	return s.updateItemAndFetch(ctx, counter, patch, "resolved")
+	return rollbar.ItemPatch{Status: rollbar.StatusResolved, ResolvedInVersion: trimmedVersion}, nil
 }
 
 func (s *Service) Reopen(ctx context.Context, counter domain.ItemCounter) (ItemActionResult, error) {
-	return s.updateItemAndFetch(ctx, counter, rollbar.ItemPatch{Status: "active"}, "reopened")
+	return s.updateItemAndFetch(ctx, counter, rollbar.ItemPatch{Status: rollbar.StatusActive}, "reopened")
 }
 
 func (s *Service) Mute(ctx context.Context, counter domain.ItemCounter, durationSeconds *int64) (ItemActionResult, error) {
 	snoozeEnabled := true
-	patch := rollbar.ItemPatch{Status: "muted", SnoozeEnabled: &snoozeEnabled, SnoozeExpirationInSeconds: durationSeconds}
+	patch := rollbar.ItemPatch{Status: rollbar.StatusMuted, SnoozeEnabled: &snoozeEnabled, SnoozeExpirationInSeconds: durationSeconds}
 
 	return s.updateItemAndFetch(ctx, counter, patch, "muted")
 }
 
+// Assign assigns an item to the project member with the given email.
+func (s *Service) Assign(ctx context.Context, counter domain.ItemCounter, email string) (ItemActionResult, error) {
+	user, err := s.api.FindUserByEmail(ctx, email)
+	if err != nil {
+		return ItemActionResult{}, fmt.Errorf("find user: %w", err)
+	}
+
+	return s.updateItemAndFetch(ctx, counter, rollbar.ItemPatch{AssignedUserID: &user.ID}, "assigned")
+}
+
 func (s *Service) updateItemAndFetch(ctx context.Context, counter domain.ItemCounter, patch rollbar.ItemPatch, action string) (ItemActionResult, error) {
 	itemID, err := s.api.ResolveItemIDByCounter(ctx, counter)
 	if err != nil {
@@ -200,7 +233,7 @@ func filterItems(items []rollbar.Item, filters IssueFilters) []rollbar.Item {
 		if !matchesTextFilter(strings.TrimSpace(item.Environment), normalized.Environment) {
 			continue
 		}
-		if !matchesTextFilter(strings.TrimSpace(item.Status), normalized.Status) {
+		if !matchesTextFilter(strings.TrimSpace(string(item.Status)), normalized.Status) {
 			continue
 		}
 		if !matchesTimeFilter(item.LastOccurrenceTimestamp, sinceUnix, untilUnix) {
@@ -294,7 +327,8 @@ func mapSummary(item rollbar.Item) IssueSummary {
 		ItemID:                  item.ID,
 		Counter:                 domain.ItemCounter(item.Counter),
 		Title:                   item.Title,
-		Status:                  item.Status,
+		Status:                  string(item.Status),
+		Level:                   string(item.Level),
 		Environment:             item.Environment,
 		LastOccurrenceTimestamp: item.LastOccurrenceTimestamp,
 		Occurrences:             occurrences,
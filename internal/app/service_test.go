@@ -14,11 +14,12 @@ import (
 )
 
 type fakeAPI struct {
-	activeItems []rollbar.Item
-	listItems   []rollbar.Item
-	item        rollbar.Item
-	instance    *rollbar.ItemInstance
-	err         error
+	activeItems  []rollbar.Item
+	listItems    []rollbar.Item
+	item         rollbar.Item
+	instance     *rollbar.ItemInstance
+	environments []rollbar.Environment
+	err          error
 }
 
 func (f fakeAPI) ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error) {
@@ -50,6 +51,16 @@ func (f fakeAPI) GetLatestInstance(ctx context.Context, itemID domain.ItemID) (*
 	return f.instance, nil
 }
 
+func (f fakeAPI) ListInstances(ctx context.Context, itemID domain.ItemID, page int) (rollbar.InstancePage, error) {
+	if f.err != nil {
+		return rollbar.InstancePage{}, f.err
+	}
+	if page > 1 || f.instance == nil {
+		return rollbar.InstancePage{Page: page}, nil
+	}
+	return rollbar.InstancePage{Instances: []rollbar.ItemInstance{*f.instance}, Page: page}, nil
+}
+
 func (f fakeAPI) ListActiveItems(ctx context.Context, limit int) ([]rollbar.Item, error) {
 	if f.err != nil {
 		return nil, f.err
@@ -64,6 +75,33 @@ func (f fakeAPI) ListItems(ctx context.Context, status string, page int) ([]roll
 	return f.listItems, nil
 }
 
+func (f fakeAPI) ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error) {
+	if f.err != nil {
+		return rollbar.ItemPage{}, f.err
+	}
+	if query.Page > 1 {
+		return rollbar.ItemPage{Page: query.Page}, nil
+	}
+	return rollbar.ItemPage{Items: f.listItems, Page: 1}, nil
+}
+
+func (f fakeAPI) ListEnvironments(ctx context.Context, page int) ([]rollbar.Environment, error) {
+	if f.err != nil {
+		return nil, f.err
+	}
+	if page > 1 {
+		return nil, nil
+	}
+	return f.environments, nil
+}
+
+func (f fakeAPI) FindUserByEmail(ctx context.Context, email string) (rollbar.User, error) {
+	if f.err != nil {
+		return rollbar.User{}, f.err
+	}
+	return rollbar.User{ID: 5, Email: email}, nil
+}
+
 func TestServiceActive(t *testing.T) {
 	t.Parallel()
 
@@ -160,6 +198,21 @@ func TestServiceShow(t *testing.T) {
 	}
 }
 
+func TestServiceShowByID(t *testing.T) {
+	t.Parallel()
+
+	firstSeen := uint64(1700000000)
+	item := rollbar.Item{ID: 123, Counter: 9, Title: "title", Level: rollbar.LevelError, FirstOccurrenceTimestamp: &firstSeen}
+
+	detail, err := NewService(fakeAPI{item: item}).ShowByID(context.Background(), 123)
+	if err != nil {
+		t.Fatalf("ShowByID() error = %v", err)
+	}
+	if detail.Level != "error" || detail.FirstOccurrenceTimestamp == nil || *detail.FirstOccurrenceTimestamp != firstSeen {
+		t.Fatalf("unexpected detail: %+v", detail)
+	}
+}
+
 func TestServiceErrors(t *testing.T) {
 	t.Parallel()
 
@@ -241,6 +294,10 @@ func (a *actionAPI) GetLatestInstance(ctx context.Context, itemID domain.ItemID)
 	return nil, nil
 }
 
+func (a *actionAPI) ListInstances(ctx context.Context, itemID domain.ItemID, page int) (rollbar.InstancePage, error) {
+	return rollbar.InstancePage{}, nil
+}
+
 func (a *actionAPI) ListActiveItems(ctx context.Context, limit int) ([]rollbar.Item, error) {
 	return nil, nil
 }
@@ -249,6 +306,22 @@ func (a *actionAPI) ListItems(ctx context.Context, status string, page int) ([]r
 	return nil, nil
 }
 
+func (a *actionAPI) ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error) {
+	return rollbar.ItemPage{}, nil
+}
+
+func (a *actionAPI) ListEnvironments(ctx context.Context, page int) ([]rollbar.Environment, error) {
+	return nil, nil
+}
+
+func (a *actionAPI) FindUserByEmail(ctx context.Context, email string) (rollbar.User, error) {
+	if email != "dev@example.com" {
+		return rollbar.User{}, errors.New("no user")
+	}
+
+	return rollbar.User{ID: 42, Email: email}, nil
+}
+
 func TestServiceResolve(t *testing.T) {
 	t.Parallel()
 
@@ -309,6 +382,28 @@ func TestServiceReopen(t *testing.T) {
 	}
 }
 
+func TestServiceAssign(t *testing.T) {
+	t.Parallel()
+
+	api := &actionAPI{
+		resolvedID: 99,
+		item:       rollbar.Item{ID: 99, Counter: 9, Status: "active", Title: "x"},
+	}
+	service := NewService(api)
+
+	result, err := service.Assign(context.Background(), 9, "dev@example.com")
+	if err != nil {
+		t.Fatalf("Assign() error = %v", err)
+	}
+	if result.Action != "assigned" || api.lastPatch.AssignedUserID == nil || *api.lastPatch.AssignedUserID != 42 {
+		t.Fatalf("unexpected assign result: %+v patch=%+v", result, api.lastPatch)
+	}
+
+	if _, err := service.Assign(context.Background(), 9, "nobody@example.com"); err == nil || api.updateCalls != 1 {
+		t.Fatalf("expected unknown user error without update, got %v (updates=%d)", err, api.updateCalls)
+	}
+}
+
 func TestServiceMute(t *testing.T) {
 	t.Parallel()
 
@@ -0,0 +1,280 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"reflect"
+	"slices"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/settings"
+)
+
+// AccessTokenAPI is the account-token part of the Rollbar API that manages a
+// project's access tokens.
+type AccessTokenAPI interface {
+	ListProjectAccessTokens(ctx context.Context, projectID uint64) ([]rollbar.ProjectAccessToken, error)
+	CreateProjectAccessToken(ctx context.Context, projectID uint64, request rollbar.AccessTokenRequest) (rollbar.ProjectAccessToken, error)
+	UpdateProjectAccessTokenRateLimit(ctx context.Context, projectID uint64, accessToken string, limit rollbar.TokenRateLimit) error
+}
+
+type SettingsAction string
+
+const (
+	SettingsCreate SettingsAction = "create"
+	SettingsUpdate SettingsAction = "update"
+	SettingsDelete SettingsAction = "delete"
+	// SettingsConflict is a difference Rollbar's API cannot apply, such as a
+	// token's scopes; apply leaves it for the web UI.
+	SettingsConflict SettingsAction = "conflict"
+)
+
+const (
+	ResourceNotificationRule = "notification_rule"
+	ResourceAccessToken      = "access_token"
+)
+
+// SettingsChange is one difference between a settings file and the project.
+// Rule is the rule to create or delete; Token is the access token as the file
+// declares it and Current as the project has it.
+type SettingsChange struct {
+	Action   SettingsAction              `json:"action"`
+	Resource string                      `json:"resource"`
+	Channel  string                      `json:"channel,omitempty"`
+	Rule     *rollbar.NotificationRule   `json:"rule,omitempty"`
+	Token    *settings.AccessToken       `json:"token,omitempty"`
+	Current  *rollbar.ProjectAccessToken `json:"current,omitempty"`
+	Detail   string                      `json:"detail,omitempty"`
+}
+
+// SettingsPlan is what applying a settings file would change, notification
+// rules by channel in Rollbar's settings page order, then access tokens.
+type SettingsPlan struct {
+	ProjectID uint64           `json:"project_id,omitempty"`
+	Changes   []SettingsChange `json:"changes"`
+}
+
+// Count returns how many changes have action.
+func (p SettingsPlan) Count(action SettingsAction) int {
+	count := 0
+	for _, change := range p.Changes {
+		if change.Action == action {
+			count++
+		}
+	}
+
+	return count
+}
+
+// PlanSettings compares a settings file with the project. A declared
+// channel's rules are matched on trigger, filters in any order, and the
+// config keys the file sets; unmatched file rules are created and unmatched
+// project rules deleted. Declared access tokens are matched by name: missing
+// ones are created and rate limits updated, while scope differences are
+// conflicts. tokens and projectID are only used when the file declares
+// access tokens.
+func PlanSettings(ctx context.Context, notifications NotificationAPI, tokens AccessTokenAPI, file settings.File, projectID uint64) (SettingsPlan, error) {
+	plan := SettingsPlan{ProjectID: projectID, Changes: []SettingsChange{}}
+	for _, channel := range rollbar.NotificationChannels {
+		desired, ok := file.Notifications[channel]
+		if !ok {
+			continue
+		}
+		current, err := notifications.ListNotificationRules(ctx, channel)
+		if err != nil {
+			return SettingsPlan{}, fmt.Errorf("list %s notification rules: %w", channel, err)
+		}
+		plan.Changes = append(plan.Changes, ruleChanges(channel, desired, current)...)
+	}
+	if len(file.AccessTokens) == 0 {
+		return plan, nil
+	}
+
+	current, err := tokens.ListProjectAccessTokens(ctx, projectID)
+	if err != nil {
+		return SettingsPlan{}, fmt.Errorf("list project access tokens: %w", err)
+	}
+	for index := range file.AccessTokens {
+		if change, ok := tokenChange(&file.AccessTokens[index], current); ok {
+			plan.Changes = append(plan.Changes, change)
+		}
+	}
+
+	return plan, nil
+}
+
+func ruleChanges(channel string, desired []rollbar.NotificationRule, current []rollbar.NotificationRule) []SettingsChange {
+	matched := make([]bool, len(current))
+	changes := make([]SettingsChange, 0)
+	for index := range desired {
+		if found := unmatchedRule(desired[index], current, matched); found >= 0 {
+			matched[found] = true
+			continue
+		}
+		changes = append(changes, SettingsChange{Action: SettingsCreate, Resource: ResourceNotificationRule, Channel: channel, Rule: &desired[index]})
+	}
+
+	return append(changes, deletedRules(channel, current, matched)...)
+}
+
+// unmatchedRule is the index of the first project rule not yet matched that
+// matches desired, or -1.
+func unmatchedRule(desired rollbar.NotificationRule, current []rollbar.NotificationRule, matched []bool) int {
+	for index := range current {
+		if !matched[index] && ruleMatches(desired, current[index]) {
+			return index
+		}
+	}
+
+	return -1
+}
+
+func deletedRules(channel string, current []rollbar.NotificationRule, matched []bool) []SettingsChange {
+	changes := make([]SettingsChange, 0)
+	for index := range current {
+		if !matched[index] {
+			changes = append(changes, SettingsChange{Action: SettingsDelete, Resource: ResourceNotificationRule, Channel: channel, Rule: &current[index]})
+		}
+	}
+
+	return changes
+}
+
+// ruleMatches reports whether the project rule current is the file rule
+// desired: same trigger and filters, and the config keys desired sets.
+func ruleMatches(desired rollbar.NotificationRule, current rollbar.NotificationRule) bool {
+	if desired.Trigger != current.Trigger || len(desired.Filters) != len(current.Filters) {
+		return false
+	}
+	for _, filter := range desired.Filters {
+		if !slices.Contains(current.Filters, filter) {
+			return false
+		}
+	}
+	for key, value := range desired.Config {
+		if fmt.Sprint(value) != fmt.Sprint(current.Config[key]) {
+			return false
+		}
+	}
+
+	return true
+}
+
+func tokenChange(desired *settings.AccessToken, current []rollbar.ProjectAccessToken) (SettingsChange, bool) {
+	index := slices.IndexFunc(current, func(token rollbar.ProjectAccessToken) bool { return token.Name == desired.Name })
+	if index < 0 {
+		return SettingsChange{Action: SettingsCreate, Resource: ResourceAccessToken, Token: desired}, true
+	}
+
+	existing := &current[index]
+	change := SettingsChange{Resource: ResourceAccessToken, Token: desired, Current: existing}
+	switch {
+	case !sameScopes(desired.Scopes, existing.Scopes):
+		change.Action = SettingsConflict
+		change.Detail = fmt.Sprintf("scopes are %s, not %s; Rollbar cannot change a token's scopes, so replace it in the project settings", strings.Join(existing.Scopes, ", "), strings.Join(desired.Scopes, ", "))
+	case desired.RateLimitWindowSize != nil && !sameRateLimit(desired, existing):
+		change.Action = SettingsUpdate
+	default:
+		return SettingsChange{}, false
+	}
+
+	return change, true
+}
+
+func sameScopes(left []string, right []string) bool {
+	left, right = slices.Clone(left), slices.Clone(right)
+	slices.Sort(left)
+	slices.Sort(right)
+
+	return slices.Equal(left, right)
+}
+
+func sameRateLimit(desired *settings.AccessToken, current *rollbar.ProjectAccessToken) bool {
+	return reflect.DeepEqual(desired.RateLimitWindowSize, current.RateLimitWindowSize) && reflect.DeepEqual(desired.RateLimitWindowCount, current.RateLimitWindowCount)
+}
+
+// ApplySettings makes a plan's changes: each channel's new rules before its
+// deleted ones, so alerts keep flowing, then access tokens. Conflicts are
+// skipped. It returns how many changes were made, which on an error is how
+// far it got.
+func ApplySettings(ctx context.Context, notifications NotificationAPI, tokens AccessTokenAPI, plan SettingsPlan) (int, error) {
+	applied := 0
+	for _, channel := range rollbar.NotificationChannels {
+		count, err := applyRuleChanges(ctx, notifications, channel, plan.Changes)
+		applied += count
+		if err != nil {
+			return applied, err
+		}
+	}
+
+	for _, change := range plan.Changes {
+		if change.Resource != ResourceAccessToken || change.Action == SettingsConflict {
+			continue
+		}
+		if err := applyTokenChange(ctx, tokens, plan.ProjectID, change); err != nil {
+			return applied, err
+		}
+		applied++
+	}
+
+	return applied, nil
+}
+
+func applyRuleChanges(ctx context.Context, api NotificationAPI, channel string, changes []SettingsChange) (int, error) {
+	created, deleted := make([]rollbar.NotificationRule, 0), make([]uint64, 0)
+	for _, change := range changes {
+		if change.Resource != ResourceNotificationRule || change.Channel != channel {
+			continue
+		}
+		if change.Action == SettingsCreate {
+			created = append(created, *change.Rule)
+		} else {
+			deleted = append(deleted, change.Rule.ID)
+		}
+	}
+
+	applied := 0
+	if len(created) > 0 {
+		if _, err := api.CreateNotificationRules(ctx, channel, created); err != nil {
+			return applied, fmt.Errorf("create %s notification rules: %w", channel, err)
+		}
+		applied += len(created)
+	}
+	for _, ruleID := range deleted {
+		if err := api.DeleteNotificationRule(ctx, channel, ruleID); err != nil {
+			return applied, fmt.Errorf("delete %s notification rule %d: %w", channel, ruleID, err)
+		}
+		applied++
+	}
+
+	return applied, nil
+}
+
+func applyTokenChange(ctx context.Context, api AccessTokenAPI, projectID uint64, change SettingsChange) error {
+	if change.Action == SettingsCreate {
+		request := rollbar.AccessTokenRequest{
+			Name:                 change.Token.Name,
+			Scopes:               change.Token.Scopes,
+			RateLimitWindowSize:  change.Token.RateLimitWindowSize,
+			RateLimitWindowCount: change.Token.RateLimitWindowCount,
+		}
+		if _, err := api.CreateProjectAccessToken(ctx, projectID, request); err != nil {
+			return fmt.Errorf("create access token %s: %w", change.Token.Name, err)
+		}
+		return nil
+	}
+
+	if change.Current == nil {
+		return errors.New("access token update without the current token")
+	}
+	limit := rollbar.TokenRateLimit{WindowSize: *change.Token.RateLimitWindowSize, WindowCount: *change.Token.RateLimitWindowCount}
+	if err := api.UpdateProjectAccessTokenRateLimit(ctx, projectID, change.Current.AccessToken, limit); err != nil {
+		// The token is part of the request path, so it can surface in errors.
+		return fmt.Errorf("update access token %s: %s", change.Token.Name, redact.String(err.Error(), change.Current.AccessToken))
+	}
+
+	return nil
+}
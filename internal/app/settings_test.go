@@ -0,0 +1,162 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/settings"
+)
+
+type fakeAccessTokenAPI struct {
+	tokens  []rollbar.ProjectAccessToken
+	created []rollbar.AccessTokenRequest
+	updated map[string]rollbar.TokenRateLimit
+	err     error
+}
+
+func (f *fakeAccessTokenAPI) ListProjectAccessTokens(ctx context.Context, projectID uint64) ([]rollbar.ProjectAccessToken, error) {
+	return f.tokens, nil
+}
+
+func (f *fakeAccessTokenAPI) CreateProjectAccessToken(ctx context.Context, projectID uint64, request rollbar.AccessTokenRequest) (rollbar.ProjectAccessToken, error) {
+	f.created = append(f.created, request)
+	return rollbar.ProjectAccessToken{Name: request.Name}, nil
+}
+
+func (f *fakeAccessTokenAPI) UpdateProjectAccessTokenRateLimit(ctx context.Context, projectID uint64, accessToken string, limit rollbar.TokenRateLimit) error {
+	if f.err != nil {
+		return f.err
+	}
+	if f.updated == nil {
+		f.updated = map[string]rollbar.TokenRateLimit{}
+	}
+	f.updated[accessToken] = limit
+	return nil
+}
+
+func settingsFixture() (settings.File, *fakeNotificationAPI, *fakeAccessTokenAPI) {
+	production := rollbar.NotificationFilter{Type: "environment", Operation: "eq", Value: "production"}
+	errorLevel := rollbar.NotificationFilter{Type: "level", Operation: "gte", Value: "error"}
+	file := settings.File{
+		Notifications: map[string][]rollbar.NotificationRule{
+			rollbar.NotificationSlack: {
+				{Trigger: "new_item", Filters: []rollbar.NotificationFilter{production, errorLevel}, Config: map[string]any{"channel": "#alerts"}},
+				{Trigger: "deploy", Filters: []rollbar.NotificationFilter{}, Config: map[string]any{"channel": "#deploys"}},
+			},
+			rollbar.NotificationEmail: {},
+		},
+		AccessTokens: []settings.AccessToken{
+			{Name: "ci", Scopes: []string{"post_server_item"}},
+			{Name: "reader", Scopes: []string{"read"}, RateLimitWindowSize: uint64Ptr(60), RateLimitWindowCount: uint64Ptr(1000)},
+			{Name: "admin", Scopes: []string{"read", "write"}},
+			{Name: "steady", Scopes: []string{"read"}},
+		},
+	}
+	notifications := &fakeNotificationAPI{rules: map[string][]rollbar.NotificationRule{
+		rollbar.NotificationSlack: {
+			{ID: 1, Trigger: "new_item", Filters: []rollbar.NotificationFilter{errorLevel, production}, Config: map[string]any{"channel": "#alerts", "show_message_buttons": true}},
+			{ID: 2, Trigger: "occurrence", Filters: []rollbar.NotificationFilter{}},
+		},
+		rollbar.NotificationEmail:     {{ID: 3, Trigger: "daily_summary"}},
+		rollbar.NotificationPagerDuty: {{ID: 4, Trigger: "new_item"}},
+	}}
+	tokens := &fakeAccessTokenAPI{tokens: []rollbar.ProjectAccessToken{
+		{Name: "reader", AccessToken: "reader-token", Scopes: []string{"read"}, RateLimitWindowSize: uint64Ptr(60), RateLimitWindowCount: uint64Ptr(100)},
+		{Name: "admin", AccessToken: "admin-token", Scopes: []string{"read"}},
+		{Name: "steady", AccessToken: "steady-token", Scopes: []string{"read"}},
+		{Name: "legacy", AccessToken: "legacy-token", Scopes: []string{"write"}},
+	}}
+
+	return file, notifications, tokens
+}
+
+// settingsChangeNames joins each change's action with the channel and
+// trigger or the token name it applies to.
+func settingsChangeNames(plan SettingsPlan) string {
+	got := make([]string, 0, len(plan.Changes))
+	for _, change := range plan.Changes {
+		name := change.Channel
+		if change.Token != nil {
+			name = change.Token.Name
+		}
+		if change.Rule != nil {
+			name += " " + change.Rule.Trigger
+		}
+		got = append(got, string(change.Action)+" "+name)
+	}
+	return strings.Join(got, ", ")
+}
+
+func TestPlanSettings(t *testing.T) {
+	t.Parallel()
+
+	file, notifications, tokens := settingsFixture()
+	plan, err := PlanSettings(context.Background(), notifications, tokens, file, 7)
+	if err != nil {
+		t.Fatalf("PlanSettings() error = %v", err)
+	}
+
+	want := "delete email daily_summary, create slack deploy, delete slack occurrence, create ci, update reader, conflict admin"
+	if got := settingsChangeNames(plan); got != want {
+		t.Fatalf("PlanSettings() changes = %s, want %s", got, want)
+	}
+	if plan.ProjectID != 7 || plan.Count(SettingsCreate) != 2 || plan.Count(SettingsDelete) != 2 {
+		t.Fatalf("unexpected plan: %+v", plan)
+	}
+	if !strings.Contains(plan.Changes[5].Detail, "scopes are read, not read, write") {
+		t.Fatalf("unexpected conflict detail %q", plan.Changes[5].Detail)
+	}
+}
+
+func TestPlanSettingsError(t *testing.T) {
+	t.Parallel()
+
+	file, notifications, tokens := settingsFixture()
+	notifications.err = errors.New("boom")
+	if _, err := PlanSettings(context.Background(), notifications, tokens, file, 7); err == nil || !strings.Contains(err.Error(), "list email notification rules") {
+		t.Fatalf("expected a list error, got %v", err)
+	}
+}
+
+func TestApplySettings(t *testing.T) {
+	t.Parallel()
+
+	file, notifications, tokens := settingsFixture()
+	plan, err := PlanSettings(context.Background(), notifications, tokens, file, 7)
+	if err != nil {
+		t.Fatalf("PlanSettings() error = %v", err)
+	}
+
+	applied, err := ApplySettings(context.Background(), notifications, tokens, plan)
+	if err != nil || applied != 5 {
+		t.Fatalf("ApplySettings() = %d, %v", applied, err)
+	}
+	if len(notifications.created[rollbar.NotificationSlack]) != 1 || strings.Join(notifications.deleted, ",") != "email,slack" {
+		t.Fatalf("unexpected rule changes: created %+v, deleted %v", notifications.created, notifications.deleted)
+	}
+	if len(tokens.created) != 1 || tokens.created[0].Name != "ci" || tokens.updated["reader-token"].WindowCount != 1000 {
+		t.Fatalf("unexpected token changes: created %+v, updated %+v", tokens.created, tokens.updated)
+	}
+}
+
+func TestApplySettingsError(t *testing.T) {
+	t.Parallel()
+
+	file, notifications, tokens := settingsFixture()
+	plan, err := PlanSettings(context.Background(), notifications, tokens, file, 7)
+	if err != nil {
+		t.Fatalf("PlanSettings() error = %v", err)
+	}
+
+	tokens.err = errors.New("PATCH /project/7/access_token/reader-token: boom")
+	applied, err := ApplySettings(context.Background(), &fakeNotificationAPI{}, tokens, plan)
+	if err == nil || strings.Contains(err.Error(), "reader-token") || !strings.Contains(err.Error(), "update access token reader") {
+		t.Fatalf("expected a redacted update error, got %v", err)
+	}
+	if applied != 4 {
+		t.Fatalf("expected 4 changes before the failure, got %d", applied)
+	}
+}
@@ -0,0 +1,240 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"math"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	defaultSimilarCandidates = 50
+	defaultSimilarMinScore   = 0.5
+	// similarTitleWeight is the title's share of the score when both items
+	// have a stack trace; the trace decides the rest.
+	similarTitleWeight = 0.4
+	// maxSimilarFrames keeps deep framework stacks from drowning out the
+	// frames nearest the error.
+	maxSimilarFrames = 20
+)
+
+// Title parts that vary between occurrences of one bug, replaced before
+// comparing so "timeout after 30s" matches "timeout after 45s".
+var (
+	quotedPattern = regexp.MustCompile(`'[^']*'|"[^"]*"`)
+	uuidPattern   = regexp.MustCompile(`\b[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}\b`)
+	hexPattern    = regexp.MustCompile(`\b0x[0-9a-f]+\b|\b[0-9a-f]{12,}\b`)
+	numberPattern = regexp.MustCompile(`\d+`)
+)
+
+type SimilarOptions struct {
+	// Candidates is how many active items to compare against, newest first.
+	Candidates int
+	// MinScore drops matches scoring below it, from 0 to 1.
+	MinScore float64
+	Limit    int
+}
+
+// SimilarItem is a probable duplicate and how alike it is, from 0 to 1.
+// TraceScore is absent when either item has no stack trace to compare.
+type SimilarItem struct {
+	IssueSummary
+	Score      float64  `json:"score"`
+	TitleScore float64  `json:"title_score"`
+	TraceScore *float64 `json:"trace_score,omitempty"`
+}
+
+type SimilarReport struct {
+	Item     IssueSummary  `json:"item"`
+	Compared int           `json:"compared"`
+	Similar  []SimilarItem `json:"similar"`
+}
+
+// fingerprint is what two items are compared on: the set of normalized title
+// words and the latest occurrence's frames as "file:method", most recent
+// first, without line numbers so a small edit does not hide a duplicate.
+type fingerprint struct {
+	title  map[string]bool
+	frames []string
+}
+
+// Similar compares an item against the active items in the project and
+// reports the ones that probably record the same error under another
+// grouping, best match first.
+func (s *Service) Similar(ctx context.Context, counter domain.ItemCounter, options SimilarOptions) (SimilarReport, error) {
+	itemID, err := s.api.ResolveItemIDByCounter(ctx, counter)
+	if err != nil {
+		return SimilarReport{}, fmt.Errorf("resolve item id: %w", err)
+	}
+	item, err := s.api.GetItem(ctx, itemID)
+	if err != nil {
+		return SimilarReport{}, fmt.Errorf("get item: %w", err)
+	}
+	target, err := s.fingerprint(ctx, item)
+	if err != nil {
+		return SimilarReport{}, err
+	}
+	candidates, err := s.similarCandidates(ctx, item.ID, options.Candidates)
+	if err != nil {
+		return SimilarReport{}, err
+	}
+
+	report := SimilarReport{Item: mapSummary(item), Compared: len(candidates), Similar: []SimilarItem{}}
+	for _, candidate := range candidates {
+		other, err := s.fingerprint(ctx, candidate)
+		if err != nil {
+			return SimilarReport{}, err
+		}
+		if match := compareFingerprints(target, other); match.Score >= similarMinScore(options) {
+			match.IssueSummary = mapSummary(candidate)
+			report.Similar = append(report.Similar, match)
+		}
+	}
+
+	sort.SliceStable(report.Similar, func(i int, j int) bool { return report.Similar[i].Score > report.Similar[j].Score })
+	if options.Limit > 0 && len(report.Similar) > options.Limit {
+		report.Similar = report.Similar[:options.Limit]
+	}
+
+	return report, nil
+}
+
+func similarMinScore(options SimilarOptions) float64 {
+	if options.MinScore <= 0 {
+		return defaultSimilarMinScore
+	}
+
+	return options.MinScore
+}
+
+// similarCandidates pages through active items until it has enough, leaving
+// out the item being compared.
+func (s *Service) similarCandidates(ctx context.Context, itemID domain.ItemID, limit int) ([]rollbar.Item, error) {
+	if limit <= 0 {
+		limit = defaultSimilarCandidates
+	}
+
+	candidates := make([]rollbar.Item, 0, limit)
+	query := rollbar.ItemQuery{Status: string(rollbar.StatusActive)}
+	for page := 1; len(candidates) < limit; page++ {
+		query.Page = page
+		result, err := s.api.ListItemsPage(ctx, query)
+		if err != nil {
+			return nil, fmt.Errorf("list active items: %w", err)
+		}
+		for _, item := range result.Items {
+			if item.ID != itemID && len(candidates) < limit {
+				candidates = append(candidates, item)
+			}
+		}
+		if len(result.Items) == 0 || listComplete(result, page, 0, 0) {
+			break
+		}
+	}
+
+	return candidates, nil
+}
+
+func (s *Service) fingerprint(ctx context.Context, item rollbar.Item) (fingerprint, error) {
+	instance, err := s.api.GetLatestInstance(ctx, item.ID)
+	if err != nil {
+		return fingerprint{}, fmt.Errorf("get latest instance of item %d: %w", item.Counter, err)
+	}
+
+	result := fingerprint{title: titleTokens(item.Title)}
+	if instance != nil {
+		result.frames = frameSignatures(instance.OccurrenceBody())
+	}
+
+	return result, nil
+}
+
+// titleWords lowercases a title, replaces quoted strings, UUIDs, hex ids,
+// and numbers with placeholders, and splits it into words.
+func titleWords(title string) []string {
+	normalized := strings.ToLower(title)
+	normalized = quotedPattern.ReplaceAllString(normalized, " _quoted_ ")
+	normalized = uuidPattern.ReplaceAllString(normalized, "_uuid_")
+	normalized = hexPattern.ReplaceAllString(normalized, "_hex_")
+	normalized = numberPattern.ReplaceAllString(normalized, "_n_")
+
+	return strings.FieldsFunc(normalized, func(r rune) bool { return !isWordRune(r) && r != '_' })
+}
+
+// titleTokens is the set of a title's normalized words.
+func titleTokens(title string) map[string]bool {
+	tokens := map[string]bool{}
+	for _, word := range titleWords(title) {
+		tokens[word] = true
+	}
+
+	return tokens
+}
+
+func frameSignatures(body rollbar.OccurrenceBody) []string {
+	frames := body.Frames()
+	signatures := make([]string, 0, min(len(frames), maxSimilarFrames))
+	for index := len(frames) - 1; index >= 0 && len(signatures) < maxSimilarFrames; index-- {
+		signatures = append(signatures, frames[index].Filename+":"+frames[index].Method)
+	}
+
+	return signatures
+}
+
+// compareFingerprints scores title words by overlap (Jaccard) and frames by
+// their longest common subsequence, so an extra or missing frame costs
+// little but a different call path costs a lot.
+func compareFingerprints(left fingerprint, right fingerprint) SimilarItem {
+	match := SimilarItem{TitleScore: roundScore(jaccard(left.title, right.title))}
+	match.Score = match.TitleScore
+	if len(left.frames) == 0 || len(right.frames) == 0 {
+		return match
+	}
+
+	trace := roundScore(2 * float64(commonSubsequence(left.frames, right.frames)) / float64(len(left.frames)+len(right.frames)))
+	match.TraceScore = &trace
+	match.Score = roundScore(similarTitleWeight*match.TitleScore + (1-similarTitleWeight)*trace)
+
+	return match
+}
+
+func jaccard(left map[string]bool, right map[string]bool) float64 {
+	shared := 0
+	for token := range left {
+		if right[token] {
+			shared++
+		}
+	}
+	union := len(left) + len(right) - shared
+	if union == 0 {
+		return 0
+	}
+
+	return float64(shared) / float64(union)
+}
+
+func commonSubsequence(left []string, right []string) int {
+	previous := make([]int, len(right)+1)
+	current := make([]int, len(right)+1)
+	for _, leftFrame := range left {
+		for index, rightFrame := range right {
+			if leftFrame == rightFrame {
+				current[index+1] = previous[index] + 1
+			} else {
+				current[index+1] = max(previous[index+1], current[index])
+			}
+		}
+		previous, current = current, previous
+	}
+
+	return previous[len(right)]
+}
+
+func roundScore(score float64) float64 {
+	return math.Round(score*1000) / 1000
+}
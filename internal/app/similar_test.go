@@ -0,0 +1,154 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// similarAPI serves one page of items and each item's latest instance.
+type similarAPI struct {
+	pagedAPI
+	items     map[domain.ItemID]rollbar.Item
+	instances map[domain.ItemID]*rollbar.ItemInstance
+}
+
+func (a *similarAPI) ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error) {
+	for id, item := range a.items {
+		if item.Counter == uint64(counter) {
+			return id, nil
+		}
+	}
+
+	return 0, errors.New("not found")
+}
+
+func (a *similarAPI) GetItem(ctx context.Context, itemID domain.ItemID) (rollbar.Item, error) {
+	return a.items[itemID], nil
+}
+
+func (a *similarAPI) GetLatestInstance(ctx context.Context, itemID domain.ItemID) (*rollbar.ItemInstance, error) {
+	return a.instances[itemID], nil
+}
+
+func traceInstance(t *testing.T, frames ...rollbar.Frame) *rollbar.ItemInstance {
+	t.Helper()
+
+	body, err := json.Marshal(map[string]any{"trace": map[string]any{"frames": frames, "exception": map[string]any{"class": "TimeoutError"}}})
+	if err != nil {
+		t.Fatalf("marshal body: %v", err)
+	}
+
+	return &rollbar.ItemInstance{Body: body}
+}
+
+// newSimilarAPI serves five active items: 2 differs from 1 only in its
+// title's numbers and a frame's line, 3 shares 1's trace under another
+// title, 4 is unrelated, and 5 has a similar title but no trace.
+func newSimilarAPI(t *testing.T) *similarAPI {
+	t.Helper()
+
+	handler := rollbar.Frame{Filename: "app/checkout.py", Method: "charge", Lineno: 40}
+	client := rollbar.Frame{Filename: "app/payments.py", Method: "post", Lineno: 12}
+	moved := rollbar.Frame{Filename: "app/payments.py", Method: "post", Lineno: 19}
+	other := rollbar.Frame{Filename: "app/users.py", Method: "load", Lineno: 3}
+
+	items := []rollbar.Item{
+		{ID: 1, Counter: 1, Title: "TimeoutError: payments took 30s for order 'A-17'", Status: rollbar.StatusActive},
+		{ID: 2, Counter: 2, Title: "TimeoutError: payments took 45s for order 'B-99'", Status: rollbar.StatusActive},
+		{ID: 3, Counter: 3, Title: "Payment gateway timed out", Status: rollbar.StatusActive},
+		{ID: 4, Counter: 4, Title: "KeyError: 'user_id'", Status: rollbar.StatusActive},
+		{ID: 5, Counter: 5, Title: "TimeoutError: payments took 10s", Status: rollbar.StatusActive},
+	}
+	api := &similarAPI{
+		pagedAPI: pagedAPI{pages: [][]rollbar.Item{items}},
+		items:    map[domain.ItemID]rollbar.Item{},
+		instances: map[domain.ItemID]*rollbar.ItemInstance{
+			1: traceInstance(t, handler, client),
+			2: traceInstance(t, handler, moved),
+			3: traceInstance(t, handler, client),
+			4: traceInstance(t, other),
+		},
+	}
+	for _, item := range items {
+		api.items[item.ID] = item
+	}
+
+	return api
+}
+
+// checkSimilarScores checks the scores of the matches for items 2, 3 and 5.
+func checkSimilarScores(t *testing.T, matches []SimilarItem) {
+	t.Helper()
+
+	if first := matches[0]; first.Score != 1 || first.TraceScore == nil || *first.TraceScore != 1 {
+		t.Fatalf("expected normalized titles and line-free frames to match exactly, got %+v", first)
+	}
+	if regrouped := matches[1]; regrouped.TitleScore != 0 || regrouped.Score != 0.6 {
+		t.Fatalf("expected a shared trace to flag a retitled item, got %+v", regrouped)
+	}
+	if untraced := matches[2]; untraced.TraceScore != nil || untraced.Score != untraced.TitleScore {
+		t.Fatalf("expected a title-only score without a trace, got %+v", untraced)
+	}
+}
+
+func TestServiceSimilar(t *testing.T) {
+	t.Parallel()
+
+	api := newSimilarAPI(t)
+	report, err := NewService(api).Similar(context.Background(), domain.ItemCounter(1), SimilarOptions{})
+	if err != nil {
+		t.Fatalf("Similar() error = %v", err)
+	}
+
+	var counters []uint64
+	for _, match := range report.Similar {
+		counters = append(counters, uint64(match.Counter))
+	}
+	if want := []uint64{2, 3, 5}; !slices.Equal(counters, want) {
+		t.Fatalf("Similar() counters = %v, want %v (%+v)", counters, want, report.Similar)
+	}
+	checkSimilarScores(t, report.Similar)
+	if report.Compared != 4 || report.Item.Counter != 1 || api.queries[0].Status != "active" {
+		t.Fatalf("unexpected report %+v or queries %+v", report, api.queries)
+	}
+}
+
+func TestServiceSimilarLimits(t *testing.T) {
+	t.Parallel()
+
+	api := newSimilarAPI(t)
+	limited, err := NewService(api).Similar(context.Background(), domain.ItemCounter(1), SimilarOptions{Candidates: 1, MinScore: 0.9, Limit: 5})
+	if err != nil || limited.Compared != 1 || len(limited.Similar) != 1 {
+		t.Fatalf("Similar() with limits = %+v, %v", limited, err)
+	}
+}
+
+func TestTitleTokens(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		title string
+		want  []string
+	}{
+		{title: "Timeout after 30s", want: []string{"_n_s", "after", "timeout"}},
+		{title: `Missing key "user_id" in 0x7ffe12`, want: []string{"_hex_", "_quoted_", "in", "key", "missing"}},
+		{title: "Order 123e4567-e89b-12d3-a456-426614174000 not found", want: []string{"_uuid_", "found", "not", "order"}},
+	}
+	for _, tc := range tests {
+		tokens := titleTokens(tc.title)
+		got := make([]string, 0, len(tokens))
+		for token := range tokens {
+			got = append(got, token)
+		}
+		slices.Sort(got)
+		if !slices.Equal(got, tc.want) {
+			t.Fatalf("titleTokens(%q) = %v, want %v", tc.title, got, tc.want)
+		}
+	}
+}
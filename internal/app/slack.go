@@ -0,0 +1,21 @@
+package app
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/slack"
+)
+
+type SlackPoster interface {
+	Post(ctx context.Context, message slack.Message) error
+}
+
+// PostToSlack sends one message through poster.
+func PostToSlack(ctx context.Context, poster SlackPoster, message slack.Message) error {
+	if err := poster.Post(ctx, message); err != nil {
+		return fmt.Errorf("post to slack: %w", err)
+	}
+
+	return nil
+}
@@ -0,0 +1,35 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/slack"
+)
+
+type slackPosterFunc func(ctx context.Context, message slack.Message) error
+
+func (f slackPosterFunc) Post(ctx context.Context, message slack.Message) error {
+	return f(ctx, message)
+}
+
+func TestPostToSlack(t *testing.T) {
+	t.Parallel()
+
+	posted := ""
+	poster := slackPosterFunc(func(ctx context.Context, message slack.Message) error {
+		posted = message.Text
+		return nil
+	})
+	if err := PostToSlack(context.Background(), poster, slack.Message{Text: "boom"}); err != nil || posted != "boom" {
+		t.Fatalf("PostToSlack() = %v, posted %q", err, posted)
+	}
+
+	failing := slackPosterFunc(func(ctx context.Context, message slack.Message) error {
+		return errors.New("channel_not_found")
+	})
+	if err := PostToSlack(context.Background(), failing, slack.Message{}); err == nil || err.Error() != "post to slack: channel_not_found" {
+		t.Fatalf("expected wrapped error, got %v", err)
+	}
+}
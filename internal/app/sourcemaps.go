@@ -0,0 +1,98 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/sourcemap"
+)
+
+// FrameResolver maps a position in a minified file to its original source;
+// sourcemap.Resolver is one.
+type FrameResolver interface {
+	Resolve(ctx context.Context, filename string, line int, column int) (sourcemap.Position, bool)
+}
+
+// ResolveSourceMaps rewrites the detail's stack frames to their original
+// file, line, and function where resolver has a map. Frames without one are
+// left as they are.
+func ResolveSourceMaps(ctx context.Context, detail OccurrenceDetail, resolver FrameResolver) OccurrenceDetail {
+	switch detail.Body.Kind {
+	case rollbar.BodyKindTrace:
+		trace, resolved := resolveTrace(ctx, *detail.Body.Trace, resolver)
+		detail.Body.Trace = &trace
+		detail.ResolvedFrames += resolved
+	case rollbar.BodyKindTraceChain:
+		chain := make([]rollbar.Trace, 0, len(detail.Body.TraceChain))
+		for _, trace := range detail.Body.TraceChain {
+			rewritten, resolved := resolveTrace(ctx, trace, resolver)
+			chain = append(chain, rewritten)
+			detail.ResolvedFrames += resolved
+		}
+		detail.Body.TraceChain = chain
+	}
+
+	return detail
+}
+
+func resolveTrace(ctx context.Context, trace rollbar.Trace, resolver FrameResolver) (rollbar.Trace, int) {
+	trace.Frames = slices.Clone(trace.Frames)
+	resolved := 0
+	for index, frame := range trace.Frames {
+		position, ok := resolver.Resolve(ctx, frame.Filename, frame.Lineno, frame.Colno)
+		if !ok {
+			continue
+		}
+		// The SDK's code and context lines are minified, so they are dropped.
+		rewritten := rollbar.Frame{Filename: position.Source, Lineno: position.Line, Colno: position.Column, Method: frame.Method}
+		if position.Name != "" {
+			rewritten.Method = position.Name
+		}
+		trace.Frames[index] = rewritten
+		resolved++
+	}
+
+	return trace, resolved
+}
+
+// SourceMapUploader is the part of the Rollbar API that stores source maps.
+type SourceMapUploader interface {
+	UploadSourceMap(ctx context.Context, upload rollbar.SourceMapUpload) error
+}
+
+// SourceMapUploadResult describes an uploaded map.
+type SourceMapUploadResult struct {
+	Version     string   `json:"version"`
+	MinifiedURL string   `json:"minified_url"`
+	Map         string   `json:"map"`
+	Sources     []string `json:"sources,omitempty"`
+}
+
+// UploadSourceMap checks that the map is JSON before uploading it, since
+// Rollbar accepts anything and only fails later when resolving frames.
+func UploadSourceMap(ctx context.Context, api SourceMapUploader, upload rollbar.SourceMapUpload) (SourceMapUploadResult, error) {
+	if !json.Valid(upload.SourceMap) {
+		return SourceMapUploadResult{}, fmt.Errorf("%s is not a JSON source map", upload.MapName)
+	}
+	if err := api.UploadSourceMap(ctx, upload); err != nil {
+		return SourceMapUploadResult{}, fmt.Errorf("upload sourcemap: %w", err)
+	}
+
+	sources := make([]string, 0, len(upload.Sources))
+	for path := range upload.Sources {
+		sources = append(sources, path)
+	}
+	sort.Strings(sources)
+
+	return SourceMapUploadResult{
+		Version:     strings.TrimSpace(upload.Version),
+		MinifiedURL: strings.TrimSpace(upload.MinifiedURL),
+		Map:         upload.MapName,
+		Sources:     sources,
+	}, nil
+}
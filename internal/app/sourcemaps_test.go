@@ -0,0 +1,108 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/sourcemap"
+)
+
+type stubResolver map[string]sourcemap.Position
+
+func (s stubResolver) Resolve(ctx context.Context, filename string, line int, column int) (sourcemap.Position, bool) {
+	position, ok := s[filename]
+	return position, ok
+}
+
+func TestResolveSourceMaps(t *testing.T) {
+	t.Parallel()
+
+	resolver := stubResolver{"https://cdn.example.com/app.min.js": {Source: "src/pay.ts", Line: 42, Column: 7, Name: "charge"}}
+	frames := `[{"filename":"https://cdn.example.com/app.min.js","lineno":1,"colno":901,"method":"a","code":"a(b)"},{"filename":"https://cdn.example.com/vendor.js","lineno":1,"colno":5,"method":"r"}]`
+
+	tests := []struct {
+		name string
+		data string
+	}{
+		{name: "trace", data: `{"body":{"trace":{"exception":{"class":"Error"},"frames":` + frames + `}}}`},
+		{name: "trace chain", data: `{"body":{"trace_chain":[{"exception":{"class":"Error"},"frames":` + frames + `}]}}`},
+	}
+	for _, tc := range tests {
+		instance := rollbar.ItemInstance{ID: 1, Data: json.RawMessage(tc.data)}
+		original := NewOccurrenceDetail(instance)
+		detail := ResolveSourceMaps(context.Background(), original, resolver)
+
+		trace := detail.Body.TraceChain
+		if detail.Body.Trace != nil {
+			trace = []rollbar.Trace{*detail.Body.Trace}
+		}
+		want := rollbar.Frame{Filename: "src/pay.ts", Lineno: 42, Colno: 7, Method: "charge"}
+		if detail.ResolvedFrames != 1 || trace[0].Frames[0] != want || trace[0].Frames[1].Method != "r" {
+			t.Fatalf("%s: unexpected frames %+v (%d resolved)", tc.name, trace[0].Frames, detail.ResolvedFrames)
+		}
+
+		originalTrace := original.Body.TraceChain
+		if original.Body.Trace != nil {
+			originalTrace = []rollbar.Trace{*original.Body.Trace}
+		}
+		if originalTrace[0].Frames[0].Method != "a" {
+			t.Fatalf("%s: expected the original detail to be left alone", tc.name)
+		}
+	}
+}
+
+// checkUploadResult fails unless result describes the trimmed "abc" upload
+// of app.js.map with its sources sorted.
+func checkUploadResult(t *testing.T, name string, result SourceMapUploadResult, err error) {
+	t.Helper()
+
+	if err != nil || result.Version != "abc" || result.Map != "app.js.map" || strings.Join(result.Sources, ",") != "src/a.ts,src/b.ts" {
+		t.Fatalf("%s: UploadSourceMap() = %+v, %v", name, result, err)
+	}
+}
+
+type uploaderFunc func(ctx context.Context, upload rollbar.SourceMapUpload) error
+
+func (f uploaderFunc) UploadSourceMap(ctx context.Context, upload rollbar.SourceMapUpload) error {
+	return f(ctx, upload)
+}
+
+func TestUploadSourceMap(t *testing.T) {
+	t.Parallel()
+
+	uploads := 0
+	api := uploaderFunc(func(ctx context.Context, upload rollbar.SourceMapUpload) error {
+		uploads++
+		if upload.MinifiedURL == "https://cdn.example.com/broken.js" {
+			return errors.New("boom")
+		}
+		return nil
+	})
+
+	tests := []struct {
+		name    string
+		upload  rollbar.SourceMapUpload
+		wantErr string
+	}{
+		{name: "ok", upload: rollbar.SourceMapUpload{Version: " abc ", MinifiedURL: "https://cdn.example.com/app.js", MapName: "app.js.map", SourceMap: []byte(`{"version":3}`), Sources: map[string][]byte{"src/b.ts": nil, "src/a.ts": nil}}},
+		{name: "not json", upload: rollbar.SourceMapUpload{MapName: "app.js", SourceMap: []byte("var a=1")}, wantErr: "app.js is not a JSON source map"},
+		{name: "api error", upload: rollbar.SourceMapUpload{MinifiedURL: "https://cdn.example.com/broken.js", SourceMap: []byte(`{}`)}, wantErr: "upload sourcemap: boom"},
+	}
+	for _, tc := range tests {
+		result, err := UploadSourceMap(context.Background(), api, tc.upload)
+		if tc.wantErr != "" {
+			if err == nil || err.Error() != tc.wantErr {
+				t.Fatalf("%s: expected %q, got %v", tc.name, tc.wantErr, err)
+			}
+			continue
+		}
+		checkUploadResult(t, tc.name, result, err)
+	}
+	if uploads != 2 {
+		t.Fatalf("expected the invalid map to be rejected before uploading, got %d uploads", uploads)
+	}
+}
@@ -0,0 +1,135 @@
+package app
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// SyncStore is the local copy Sync fills.
+type SyncStore interface {
+	HighWaterMark(ctx context.Context) (uint64, error)
+	SetHighWaterMark(ctx context.Context, mark uint64) error
+	SaveItems(ctx context.Context, items []rollbar.Item) error
+	LatestInstanceID(ctx context.Context, itemID domain.ItemID) (uint64, error)
+	SaveInstances(ctx context.Context, itemID domain.ItemID, instances []rollbar.ItemInstance) error
+}
+
+type SyncOptions struct {
+	// Full ignores the high-water mark and re-reads every item, which picks
+	// up status changes that leave last_occurrence_timestamp alone.
+	Full bool
+	// Occurrences caps the new occurrences fetched per item; 0 fetches all.
+	Occurrences int
+}
+
+type SyncResult struct {
+	Items         int    `json:"items"`
+	Occurrences   int    `json:"occurrences"`
+	HighWaterMark uint64 `json:"high_water_mark"`
+}
+
+// Sync copies items seen since the store's high-water mark, and their new
+// occurrences, into store. Rollbar lists items most recently seen first, so
+// paging stops at the first item no newer than the mark. The mark
+// only advances after every changed item is stored, so an interrupted sync
+// picks up where it left off.
+func (s *Service) Sync(ctx context.Context, store SyncStore, options SyncOptions) (SyncResult, error) {
+	mark, err := store.HighWaterMark(ctx)
+	if err != nil {
+		return SyncResult{}, fmt.Errorf("read high-water mark: %w", err)
+	}
+	if options.Full {
+		mark = 0
+	}
+
+	changed, err := s.syncItems(ctx, store, mark)
+	if err != nil {
+		return SyncResult{}, err
+	}
+
+	result := SyncResult{Items: len(changed), HighWaterMark: mark}
+	for _, item := range changed {
+		count, err := s.syncOccurrences(ctx, store, item.ID, options.Occurrences)
+		if err != nil {
+			return SyncResult{}, err
+		}
+		result.Occurrences += count
+		result.HighWaterMark = max(result.HighWaterMark, uint64Value(item.LastOccurrenceTimestamp))
+	}
+	if err := store.SetHighWaterMark(ctx, result.HighWaterMark); err != nil {
+		return SyncResult{}, fmt.Errorf("save high-water mark: %w", err)
+	}
+
+	return result, nil
+}
+
+// syncItems stores every page it reads and returns the items seen after mark.
+func (s *Service) syncItems(ctx context.Context, store SyncStore, mark uint64) ([]rollbar.Item, error) {
+	changed := make([]rollbar.Item, 0)
+	for page := 1; ; page++ {
+		result, err := s.api.ListItemsPage(ctx, rollbar.ItemQuery{Page: page})
+		if err != nil {
+			return nil, fmt.Errorf("list items: %w", err)
+		}
+		if len(result.Items) == 0 {
+			return changed, nil
+		}
+		if err := store.SaveItems(ctx, result.Items); err != nil {
+			return nil, fmt.Errorf("store items: %w", err)
+		}
+
+		fresh := 0
+		for _, item := range result.Items {
+			if mark == 0 || uint64Value(item.LastOccurrenceTimestamp) > mark {
+				changed = append(changed, item)
+				fresh++
+			}
+		}
+		if fresh < len(result.Items) || listComplete(result, page, 0, 0) {
+			return changed, nil
+		}
+	}
+}
+
+// syncOccurrences fetches an item's occurrences newest first until it
+// reaches one already stored or the limit.
+func (s *Service) syncOccurrences(ctx context.Context, store SyncStore, itemID domain.ItemID, limit int) (int, error) {
+	known, err := store.LatestInstanceID(ctx, itemID)
+	if err != nil {
+		return 0, fmt.Errorf("read stored occurrences: %w", err)
+	}
+
+	stored := 0
+	for page := 1; ; page++ {
+		result, err := s.api.ListInstances(ctx, itemID, page)
+		if err != nil {
+			return stored, fmt.Errorf("list occurrences of item %s: %w", itemID, err)
+		}
+
+		fresh := unseenInstances(result.Instances, known, limit, stored)
+		if err := store.SaveInstances(ctx, itemID, fresh); err != nil {
+			return stored, fmt.Errorf("store occurrences: %w", err)
+		}
+		stored += len(fresh)
+		if len(fresh) == 0 || len(fresh) < len(result.Instances) {
+			return stored, nil
+		}
+	}
+}
+
+// unseenInstances is the leading run of instances newer than known, cut off
+// once stored reaches limit.
+func unseenInstances(instances []rollbar.ItemInstance, known uint64, limit int, stored int) []rollbar.ItemInstance {
+	fresh := make([]rollbar.ItemInstance, 0, len(instances))
+	for _, instance := range instances {
+		if instance.ID <= known || (limit > 0 && stored+len(fresh) >= limit) {
+			break
+		}
+		fresh = append(fresh, instance)
+	}
+
+	return fresh
+}
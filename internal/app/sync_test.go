@@ -0,0 +1,139 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type syncAPI struct {
+	pagedAPI
+	instances map[domain.ItemID][]rollbar.ItemInstance
+}
+
+func (a *syncAPI) ListInstances(ctx context.Context, itemID domain.ItemID, page int) (rollbar.InstancePage, error) {
+	all := a.instances[itemID]
+	start := min((page-1)*2, len(all))
+	end := min(start+2, len(all))
+
+	return rollbar.InstancePage{Instances: all[start:end], Page: page}, nil
+}
+
+type memoryStore struct {
+	mark      uint64
+	items     map[domain.ItemID]rollbar.Item
+	instances map[domain.ItemID][]uint64
+	err       error
+}
+
+func newMemoryStore() *memoryStore {
+	return &memoryStore{items: map[domain.ItemID]rollbar.Item{}, instances: map[domain.ItemID][]uint64{}}
+}
+
+func (m *memoryStore) HighWaterMark(context.Context) (uint64, error) { return m.mark, m.err }
+
+func (m *memoryStore) SetHighWaterMark(_ context.Context, mark uint64) error {
+	m.mark = mark
+	return nil
+}
+
+func (m *memoryStore) SaveItems(_ context.Context, items []rollbar.Item) error {
+	for _, item := range items {
+		m.items[item.ID] = item
+	}
+	return nil
+}
+
+func (m *memoryStore) LatestInstanceID(_ context.Context, itemID domain.ItemID) (uint64, error) {
+	ids := m.instances[itemID]
+	if len(ids) == 0 {
+		return 0, nil
+	}
+	return slices.Max(ids), nil
+}
+
+func (m *memoryStore) SaveInstances(_ context.Context, itemID domain.ItemID, instances []rollbar.ItemInstance) error {
+	for _, instance := range instances {
+		m.instances[itemID] = append(m.instances[itemID], instance.ID)
+	}
+	return nil
+}
+
+// newSyncAPI returns three items, last seen at 300, 200 and 100, where the
+// first two have occurrences.
+func newSyncAPI() *syncAPI {
+	return &syncAPI{
+		pagedAPI: pagedAPI{pages: [][]rollbar.Item{
+			{issueFixture(fixture{counter: 1, lastSeen: 300}), issueFixture(fixture{counter: 2, lastSeen: 200})},
+			{issueFixture(fixture{counter: 3, lastSeen: 100})},
+		}},
+		instances: map[domain.ItemID][]rollbar.ItemInstance{
+			1: {testInstance(13, 300), testInstance(12, 250), testInstance(11, 200)},
+			2: {testInstance(21, 200)},
+		},
+	}
+}
+
+// checkIncrementalSync bumps item 2 to 400 with two new occurrences and
+// checks that a second sync only fetches the first page and the new
+// occurrences.
+func checkIncrementalSync(t *testing.T, service *Service, api *syncAPI, store *memoryStore) {
+	t.Helper()
+
+	api.pages = [][]rollbar.Item{
+		{issueFixture(fixture{counter: 2, lastSeen: 400}), issueFixture(fixture{counter: 1, lastSeen: 300})},
+		{issueFixture(fixture{counter: 3, lastSeen: 100})},
+	}
+	api.instances[2] = append([]rollbar.ItemInstance{testInstance(23, 400), testInstance(22, 350)}, api.instances[2]...)
+	api.queries = nil
+	result, err := service.Sync(context.Background(), store, SyncOptions{})
+	if err != nil {
+		t.Fatalf("second Sync() error = %v", err)
+	}
+	if result != (SyncResult{Items: 1, Occurrences: 2, HighWaterMark: 400}) || len(api.queries) != 1 {
+		t.Fatalf("unexpected incremental sync: %+v after %d pages", result, len(api.queries))
+	}
+	if !slices.Equal(store.instances[2], []uint64{21, 23, 22}) {
+		t.Fatalf("expected only new occurrences, got %v", store.instances[2])
+	}
+}
+
+func TestServiceSync(t *testing.T) {
+	t.Parallel()
+
+	api := newSyncAPI()
+	store := newMemoryStore()
+	service := NewService(api)
+
+	result, err := service.Sync(context.Background(), store, SyncOptions{Occurrences: 2})
+	if err != nil {
+		t.Fatalf("Sync() error = %v", err)
+	}
+	if result != (SyncResult{Items: 3, Occurrences: 3, HighWaterMark: 300}) || store.mark != 300 {
+		t.Fatalf("unexpected first sync: %+v, mark %d", result, store.mark)
+	}
+	if !slices.Equal(store.instances[1], []uint64{13, 12}) {
+		t.Fatalf("expected the occurrence cap per item, got %v", store.instances[1])
+	}
+
+	checkIncrementalSync(t, service, api, store)
+
+	result, err = service.Sync(context.Background(), store, SyncOptions{Full: true})
+	if err != nil || result.Items != 3 || result.Occurrences != 0 || result.HighWaterMark != 400 {
+		t.Fatalf("unexpected full sync: %+v, %v", result, err)
+	}
+}
+
+func TestServiceSyncStoreError(t *testing.T) {
+	t.Parallel()
+
+	store := newMemoryStore()
+	store.err = errors.New("locked")
+	if _, err := NewService(newSyncAPI()).Sync(context.Background(), store, SyncOptions{}); err == nil {
+		t.Fatal("expected store error")
+	}
+}
@@ -0,0 +1,33 @@
+package app
+
+import (
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// OccurrenceTelemetry is the breadcrumb trail an occurrence's SDK recorded,
+// oldest first, and the error it led up to.
+type OccurrenceTelemetry struct {
+	ID        uint64                   `json:"id"`
+	UUID      string                   `json:"uuid,omitempty"`
+	Timestamp *uint64                  `json:"timestamp,omitempty"`
+	MainError string                   `json:"main_error"`
+	Events    []rollbar.TelemetryEvent `json:"events"`
+}
+
+// NewOccurrenceTelemetry reads the telemetry of an occurrence after scrubbing
+// it, since breadcrumbs carry URLs and form input.
+func NewOccurrenceTelemetry(instance rollbar.ItemInstance) OccurrenceTelemetry {
+	scrubber := redact.DefaultScrubber()
+	instance.Body = scrubber.ScrubJSON(instance.Body)
+	instance.Data = scrubber.ScrubJSON(instance.Data)
+	occurrence := mapOccurrence(instance, false)
+
+	return OccurrenceTelemetry{
+		ID:        occurrence.ID,
+		UUID:      occurrence.UUID,
+		Timestamp: occurrence.Timestamp,
+		MainError: occurrence.MainError,
+		Events:    instance.Telemetry(),
+	}
+}
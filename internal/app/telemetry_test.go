@@ -0,0 +1,27 @@
+package app
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestNewOccurrenceTelemetry(t *testing.T) {
+	t.Parallel()
+
+	timestamp := uint64(1_700_000_002)
+	instance := rollbar.ItemInstance{
+		ID:        9,
+		Timestamp: &timestamp,
+		Data:      json.RawMessage(`{"uuid":"u-9","body":{"message":{"body":"boom"},"telemetry":[{"type":"network","timestamp_ms":1700000001000,"body":{"url":"/pay?access_token=abc123"}},{"type":"log","timestamp_ms":1700000000000,"body":{"message":"start"}}]}}`),
+	}
+
+	telemetry := NewOccurrenceTelemetry(instance)
+	if telemetry.ID != 9 || telemetry.UUID != "u-9" || telemetry.MainError != "boom" || *telemetry.Timestamp != timestamp {
+		t.Fatalf("unexpected occurrence fields: %+v", telemetry)
+	}
+	if len(telemetry.Events) != 2 || telemetry.Events[0].Body.Message != "start" || telemetry.Events[1].Body.URL != "/pay?access_token=*****" {
+		t.Fatalf("unexpected events: %+v", telemetry.Events)
+	}
+}
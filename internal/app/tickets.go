@@ -0,0 +1,106 @@
+package app
+
+import (
+	"context"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/github"
+	"github.com/kevinsheth/rollbaz/internal/jira"
+)
+
+// TicketSource is what a ticket in another tracker is built from: the item's
+// detail and a link back to it in Rollbar.
+type TicketSource struct {
+	Detail IssueDetail
+	Link   string
+}
+
+// TicketSource loads an item and its web URL for filing it elsewhere.
+func (s *Service) TicketSource(ctx context.Context, counter domain.ItemCounter, location WebLocation) (TicketSource, error) {
+	detail, err := s.Show(ctx, counter)
+	if err != nil {
+		return TicketSource{}, err
+	}
+
+	link, err := s.ItemURL(ctx, counter, location)
+	if err != nil {
+		return TicketSource{}, err
+	}
+
+	return TicketSource{Detail: detail, Link: link}, nil
+}
+
+// TicketResult is a ticket filed for an item.
+type TicketResult struct {
+	Counter domain.ItemCounter `json:"counter"`
+	Tracker string             `json:"tracker"`
+	Key     string             `json:"key"`
+	URL     string             `json:"url"`
+}
+
+type GitHubIssueAPI interface {
+	CreateIssue(ctx context.Context, repo string, issue github.NewIssue) (github.Issue, error)
+}
+
+// CreateGitHubIssue files issue in repo for the item counter.
+func CreateGitHubIssue(ctx context.Context, api GitHubIssueAPI, repo string, counter domain.ItemCounter, issue github.NewIssue) (TicketResult, error) {
+	created, err := api.CreateIssue(ctx, repo, issue)
+	if err != nil {
+		return TicketResult{}, fmt.Errorf("create github issue: %w", err)
+	}
+
+	return TicketResult{
+		Counter: counter,
+		Tracker: "github",
+		Key:     repo + "#" + strconv.Itoa(created.Number),
+		URL:     created.HTMLURL,
+	}, nil
+}
+
+type JiraIssueAPI interface {
+	CreateIssue(ctx context.Context, issue jira.NewIssue) (jira.Issue, error)
+}
+
+// CreateJiraIssue files issue for the item counter.
+func CreateJiraIssue(ctx context.Context, api JiraIssueAPI, counter domain.ItemCounter, issue jira.NewIssue) (TicketResult, error) {
+	created, err := api.CreateIssue(ctx, issue)
+	if err != nil {
+		return TicketResult{}, fmt.Errorf("create jira issue: %w", err)
+	}
+
+	return TicketResult{Counter: counter, Tracker: "jira", Key: created.Key, URL: created.URL}, nil
+}
+
+// ExpandTicketFields fills the {counter}, {title}, {environment}, {level},
+// {status}, {occurrences}, and {link} placeholders in configured field
+// templates.
+func ExpandTicketFields(templates map[string]string, source TicketSource) map[string]string {
+	if len(templates) == 0 {
+		return nil
+	}
+
+	detail := source.Detail
+	occurrences := ""
+	if detail.Occurrences != nil {
+		occurrences = strconv.FormatUint(*detail.Occurrences, 10)
+	}
+	replacer := strings.NewReplacer(
+		"{counter}", detail.Counter.String(),
+		"{title}", detail.Title,
+		"{environment}", detail.Environment,
+		"{level}", detail.Level,
+		"{status}", detail.Status,
+		"{occurrences}", occurrences,
+		"{link}", source.Link,
+	)
+
+	fields := make(map[string]string, len(templates))
+	for field, template := range templates {
+		fields[field] = replacer.Replace(template)
+	}
+
+	return fields
+}
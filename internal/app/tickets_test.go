@@ -0,0 +1,119 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/github"
+	"github.com/kevinsheth/rollbaz/internal/jira"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestServiceTicketSource(t *testing.T) {
+	t.Parallel()
+
+	api := fakeAPI{
+		item:     rollbar.Item{ID: 123, Counter: 269, Title: "boom"},
+		instance: &rollbar.ItemInstance{ID: 1, Data: json.RawMessage(`{"uuid":"d4c3-b2a1"}`)},
+	}
+	tests := []struct {
+		name     string
+		location WebLocation
+		want     string
+	}{
+		{name: "slugs", location: WebLocation{Account: "acme", Project: "api"}, want: "https://rollbar.com/acme/api/items/269/"},
+		{name: "occurrence fallback", want: "https://rollbar.com/occurrence/uuid/?uuid=d4c3-b2a1"},
+	}
+	for _, tc := range tests {
+		source, err := NewService(api).TicketSource(context.Background(), 269, tc.location)
+		if err != nil || source.Link != tc.want || source.Detail.Title != "boom" {
+			t.Fatalf("%s: TicketSource() = %+v, %v", tc.name, source, err)
+		}
+	}
+
+	if _, err := NewService(fakeAPI{err: errors.New("down")}).TicketSource(context.Background(), 269, WebLocation{}); err == nil {
+		t.Fatal("expected load error")
+	}
+}
+
+type githubIssueFunc func(ctx context.Context, repo string, issue github.NewIssue) (github.Issue, error)
+
+func (f githubIssueFunc) CreateIssue(ctx context.Context, repo string, issue github.NewIssue) (github.Issue, error) {
+	return f(ctx, repo, issue)
+}
+
+func TestCreateGitHubIssue(t *testing.T) {
+	t.Parallel()
+
+	created := githubIssueFunc(func(ctx context.Context, repo string, issue github.NewIssue) (github.Issue, error) {
+		return github.Issue{Number: 42, HTMLURL: "https://github.com/acme/api/issues/42"}, nil
+	})
+	result, err := CreateGitHubIssue(context.Background(), created, "acme/api", 269, github.NewIssue{Title: "boom"})
+	want := TicketResult{Counter: 269, Tracker: "github", Key: "acme/api#42", URL: "https://github.com/acme/api/issues/42"}
+	if err != nil || result != want {
+		t.Fatalf("CreateGitHubIssue() = %+v, %v", result, err)
+	}
+
+	failed := githubIssueFunc(func(ctx context.Context, repo string, issue github.NewIssue) (github.Issue, error) {
+		return github.Issue{}, errors.New("status 404: Not Found")
+	})
+	if _, err := CreateGitHubIssue(context.Background(), failed, "acme/api", 269, github.NewIssue{}); err == nil || !strings.Contains(err.Error(), "create github issue: status 404") {
+		t.Fatalf("expected wrapped error, got %v", err)
+	}
+}
+
+type jiraIssueFunc func(ctx context.Context, issue jira.NewIssue) (jira.Issue, error)
+
+func (f jiraIssueFunc) CreateIssue(ctx context.Context, issue jira.NewIssue) (jira.Issue, error) {
+	return f(ctx, issue)
+}
+
+func TestCreateJiraIssue(t *testing.T) {
+	t.Parallel()
+
+	created := jiraIssueFunc(func(ctx context.Context, issue jira.NewIssue) (jira.Issue, error) {
+		return jira.Issue{Key: "ENG-42", URL: "https://acme.atlassian.net/browse/ENG-42"}, nil
+	})
+	result, err := CreateJiraIssue(context.Background(), created, 269, jira.NewIssue{Summary: "boom"})
+	want := TicketResult{Counter: 269, Tracker: "jira", Key: "ENG-42", URL: "https://acme.atlassian.net/browse/ENG-42"}
+	if err != nil || result != want {
+		t.Fatalf("CreateJiraIssue() = %+v, %v", result, err)
+	}
+
+	failed := jiraIssueFunc(func(ctx context.Context, issue jira.NewIssue) (jira.Issue, error) {
+		return jira.Issue{}, errors.New("status 400")
+	})
+	if _, err := CreateJiraIssue(context.Background(), failed, 269, jira.NewIssue{}); err == nil || !strings.Contains(err.Error(), "create jira issue: status 400") {
+		t.Fatalf("expected wrapped error, got %v", err)
+	}
+}
+
+func TestExpandTicketFields(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(42)
+	source := TicketSource{
+		Detail: IssueDetail{IssueSummary: IssueSummary{Counter: 269, Title: "boom", Environment: "production", Level: "error", Status: "active", Occurrences: &occurrences}},
+		Link:   "https://rollbar.com/acme/api/items/269/",
+	}
+	got := ExpandTicketFields(map[string]string{
+		"labels":            "rollbar,{environment}",
+		"customfield_10042": "{link}",
+		"description_extra": "#{counter} {title} ({level}, {status}, {occurrences}x) {unknown}",
+	}, source)
+	want := map[string]string{
+		"labels":            "rollbar,production",
+		"customfield_10042": "https://rollbar.com/acme/api/items/269/",
+		"description_extra": "#269 boom (error, active, 42x) {unknown}",
+	}
+	if !reflect.DeepEqual(got, want) {
+		t.Fatalf("ExpandTicketFields() = %v, want %v", got, want)
+	}
+	if ExpandTicketFields(nil, source) != nil {
+		t.Fatal("expected nil fields without templates")
+	}
+}
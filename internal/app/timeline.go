@@ -0,0 +1,115 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"math"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// maxTimelineBuckets keeps a timeline to what fits a few terminal lines and a
+// reasonable JSON payload.
+const maxTimelineBuckets = 1000
+
+// TimelineAPI is the part of the Rollbar client timelines use.
+type TimelineAPI interface {
+	ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error)
+	OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error)
+}
+
+type TimelineOptions struct {
+	Since       time.Time
+	Bucket      time.Duration
+	Environment string
+}
+
+// TimelineBucket counts the occurrences from Timestamp, in unix seconds, until
+// the next bucket starts.
+type TimelineBucket struct {
+	Timestamp int64  `json:"timestamp"`
+	Count     uint64 `json:"count"`
+}
+
+// Timeline is an item's occurrence count per bucket, oldest first, with every
+// bucket from Since to now present even when nothing happened in it.
+type Timeline struct {
+	Counter       domain.ItemCounter `json:"counter"`
+	Environment   string             `json:"environment,omitempty"`
+	Since         int64              `json:"since"`
+	BucketSeconds int64              `json:"bucket_seconds"`
+	Total         uint64             `json:"total"`
+	Peak          uint64             `json:"peak"`
+	Buckets       []TimelineBucket   `json:"buckets"`
+}
+
+// ItemTimeline reads an item's occurrence counts from Rollbar's occurrence
+// count report and lays them out in buckets aligned to the bucket size, from
+// the one holding Since to the one holding now. Counts Rollbar reports outside
+// that window are dropped.
+func ItemTimeline(ctx context.Context, api TimelineAPI, counter domain.ItemCounter, options TimelineOptions, now time.Time) (Timeline, error) {
+	timeline, err := emptyTimeline(counter, options, now)
+	if err != nil {
+		return Timeline{}, err
+	}
+
+	itemID, err := api.ResolveItemIDByCounter(ctx, counter)
+	if err != nil {
+		return Timeline{}, fmt.Errorf("resolve item id: %w", err)
+	}
+	reported, err := api.OccurrenceCounts(ctx, rollbar.CountReportParams{
+		BucketSize:  int(timeline.BucketSeconds),
+		Environment: options.Environment,
+		ItemID:      itemID,
+	})
+	if err != nil {
+		return Timeline{}, fmt.Errorf("get occurrence counts: %w", err)
+	}
+
+	for _, bucket := range reported {
+		if bucket.Timestamp > math.MaxInt64 || int64(bucket.Timestamp) < timeline.Since {
+			continue
+		}
+		index := (int64(bucket.Timestamp) - timeline.Since) / timeline.BucketSeconds
+		if index >= int64(len(timeline.Buckets)) {
+			continue
+		}
+		timeline.Buckets[index].Count += bucket.Count
+		timeline.Total += bucket.Count
+		timeline.Peak = max(timeline.Peak, timeline.Buckets[index].Count)
+	}
+
+	return timeline, nil
+}
+
+func emptyTimeline(counter domain.ItemCounter, options TimelineOptions, now time.Time) (Timeline, error) {
+	if options.Bucket < time.Minute {
+		return Timeline{}, errors.New("bucket must be at least 1m")
+	}
+	size := int64(options.Bucket / time.Second)
+	start := options.Since.Unix() / size * size
+	end := now.Unix()
+	if start > end {
+		return Timeline{}, errors.New("since must be in the past")
+	}
+	count := (end-start)/size + 1
+	if count > maxTimelineBuckets {
+		return Timeline{}, fmt.Errorf("timeline would have %d buckets, more than %d; use a larger bucket or a later since", count, maxTimelineBuckets)
+	}
+
+	buckets := make([]TimelineBucket, count)
+	for index := range buckets {
+		buckets[index].Timestamp = start + int64(index)*size
+	}
+
+	return Timeline{
+		Counter:       counter,
+		Environment:   options.Environment,
+		Since:         start,
+		BucketSeconds: size,
+		Buckets:       buckets,
+	}, nil
+}
@@ -0,0 +1,80 @@
+package app
+
+import (
+	"context"
+	"slices"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type timelineAPI struct {
+	buckets []rollbar.CountBucket
+	params  rollbar.CountReportParams
+}
+
+func (a *timelineAPI) ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error) {
+	return domain.ItemID(counter) + 1000, nil
+}
+
+func (a *timelineAPI) OccurrenceCounts(ctx context.Context, params rollbar.CountReportParams) ([]rollbar.CountBucket, error) {
+	a.params = params
+	return a.buckets, nil
+}
+
+func TestItemTimeline(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 3, 20, 0, 0, time.UTC)
+	hour := uint64(3600)
+	start := uint64(time.Date(2026, 10, 15, 0, 0, 0, 0, time.UTC).Unix())
+	api := &timelineAPI{buckets: []rollbar.CountBucket{
+		{Timestamp: start - hour, Count: 50},
+		{Timestamp: start, Count: 2},
+		{Timestamp: start + 2*hour, Count: 5},
+		{Timestamp: start + 2*hour + 60, Count: 1},
+		{Timestamp: start + 5*hour, Count: 9},
+	}}
+
+	options := TimelineOptions{Since: now.Add(-3 * time.Hour), Bucket: time.Hour, Environment: "production"}
+	timeline, err := ItemTimeline(context.Background(), api, domain.ItemCounter(7), options, now)
+	if err != nil {
+		t.Fatalf("ItemTimeline() error = %v", err)
+	}
+
+	counts := make([]uint64, 0, len(timeline.Buckets))
+	for _, bucket := range timeline.Buckets {
+		counts = append(counts, bucket.Count)
+	}
+	if want := []uint64{2, 0, 6, 0}; !slices.Equal(counts, want) {
+		t.Fatalf("ItemTimeline() counts = %v, want %v", counts, want)
+	}
+	if timeline.Since != int64(start) || timeline.Total != 8 || timeline.Peak != 6 || timeline.Buckets[3].Timestamp != int64(start+3*hour) {
+		t.Fatalf("unexpected timeline %+v", timeline)
+	}
+	if api.params != (rollbar.CountReportParams{BucketSize: 3600, Environment: "production", ItemID: 1007}) {
+		t.Fatalf("unexpected report params %+v", api.params)
+	}
+}
+
+func TestItemTimelineErrors(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 3, 20, 0, 0, time.UTC)
+	api := &timelineAPI{}
+	tests := []struct {
+		name    string
+		options TimelineOptions
+	}{
+		{name: "bucket too small", options: TimelineOptions{Since: now.Add(-time.Hour), Bucket: time.Second}},
+		{name: "since in the future", options: TimelineOptions{Since: now.Add(2 * time.Hour), Bucket: time.Hour}},
+		{name: "too many buckets", options: TimelineOptions{Since: now.AddDate(0, -3, 0), Bucket: time.Minute}},
+	}
+	for _, tc := range tests {
+		if _, err := ItemTimeline(context.Background(), api, domain.ItemCounter(7), tc.options, now); err == nil {
+			t.Fatalf("%s: expected an error", tc.name)
+		}
+	}
+}
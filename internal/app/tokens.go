@@ -0,0 +1,172 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// TokenAdminAPI is the account-token part of the Rollbar API that lists,
+// creates, and revokes project access tokens.
+type TokenAdminAPI interface {
+	ListProjectAccessTokens(ctx context.Context, projectID uint64) ([]rollbar.ProjectAccessToken, error)
+	CreateProjectAccessToken(ctx context.Context, projectID uint64, request rollbar.AccessTokenRequest) (rollbar.ProjectAccessToken, error)
+	DisableProjectAccessToken(ctx context.Context, projectID uint64, accessToken string) error
+}
+
+// RotateOptions picks the token to rotate: the one enabled token with exactly
+// Scopes, narrowed by Name when several have them. NewName defaults to the
+// old name, or the scopes, followed by the date.
+type RotateOptions struct {
+	Scopes  []string
+	Name    string
+	NewName string
+}
+
+// TokenRotation is a rotated token and its replacement. Revoked is set once
+// the old token has been disabled.
+type TokenRotation struct {
+	Old     rollbar.ProjectAccessToken `json:"old"`
+	New     rollbar.ProjectAccessToken `json:"new"`
+	Revoked bool                       `json:"revoked"`
+}
+
+// ListTokens lists a project's access tokens, enabled ones first, by name.
+func ListTokens(ctx context.Context, api TokenAdminAPI, projectID uint64) ([]rollbar.ProjectAccessToken, error) {
+	tokens, err := api.ListProjectAccessTokens(ctx, projectID)
+	if err != nil {
+		return nil, fmt.Errorf("list project access tokens: %w", err)
+	}
+	slices.SortStableFunc(tokens, func(left rollbar.ProjectAccessToken, right rollbar.ProjectAccessToken) int {
+		if enabledToken(left) != enabledToken(right) {
+			if enabledToken(left) {
+				return -1
+			}
+			return 1
+		}
+		return strings.Compare(left.Name, right.Name)
+	})
+
+	return tokens, nil
+}
+
+// RotateToken creates a token with the scopes and rate limit of the one
+// options picks. The old token stays enabled; see RevokeToken.
+func RotateToken(ctx context.Context, api TokenAdminAPI, projectID uint64, options RotateOptions, now time.Time) (TokenRotation, error) {
+	if len(options.Scopes) == 0 {
+		return TokenRotation{}, errors.New("at least one scope is required")
+	}
+	tokens, err := api.ListProjectAccessTokens(ctx, projectID)
+	if err != nil {
+		return TokenRotation{}, fmt.Errorf("list project access tokens: %w", err)
+	}
+	old, err := pickRotatedToken(tokens, options)
+	if err != nil {
+		return TokenRotation{}, err
+	}
+
+	name := strings.TrimSpace(options.NewName)
+	if name == "" {
+		name = rotatedName(old, now)
+	}
+	created, err := api.CreateProjectAccessToken(ctx, projectID, rollbar.AccessTokenRequest{
+		Name:                 name,
+		Scopes:               old.Scopes,
+		RateLimitWindowSize:  old.RateLimitWindowSize,
+		RateLimitWindowCount: old.RateLimitWindowCount,
+	})
+	if err != nil {
+		return TokenRotation{}, fmt.Errorf("create access token %s: %w", name, err)
+	}
+
+	return TokenRotation{Old: old, New: created}, nil
+}
+
+func pickRotatedToken(tokens []rollbar.ProjectAccessToken, options RotateOptions) (rollbar.ProjectAccessToken, error) {
+	name := strings.TrimSpace(options.Name)
+	candidates := make([]rollbar.ProjectAccessToken, 0, 1)
+	names := make([]string, 0, 1)
+	for _, token := range tokens {
+		if enabledToken(token) && sameScopes(token.Scopes, options.Scopes) && (name == "" || token.Name == name) {
+			candidates = append(candidates, token)
+			names = append(names, token.Name)
+		}
+	}
+
+	scopes := strings.Join(options.Scopes, ", ")
+	switch {
+	case len(candidates) == 0 && name != "":
+		return rollbar.ProjectAccessToken{}, fmt.Errorf("no enabled token named %q has scopes %s", name, scopes)
+	case len(candidates) == 0:
+		return rollbar.ProjectAccessToken{}, fmt.Errorf("no enabled token has scopes %s", scopes)
+	case len(candidates) > 1:
+		return rollbar.ProjectAccessToken{}, fmt.Errorf("%d enabled tokens have scopes %s (%s); pick one with --name", len(candidates), scopes, strings.Join(names, ", "))
+	}
+
+	return candidates[0], nil
+}
+
+// rotatedName is the old token's name, or its scopes when it has none, with
+// any date suffix from an earlier rotation replaced by now's date.
+func rotatedName(old rollbar.ProjectAccessToken, now time.Time) string {
+	base := strings.TrimSpace(old.Name)
+	if base == "" {
+		base = strings.Join(old.Scopes, "-")
+	}
+	if prefix, suffix, ok := cutLast(base, "-"); ok && len(suffix) == len("20060102") {
+		if _, err := time.Parse("20060102", suffix); err == nil {
+			base = prefix
+		}
+	}
+
+	return base + "-" + now.UTC().Format("20060102")
+}
+
+func cutLast(value string, separator string) (string, string, bool) {
+	index := strings.LastIndex(value, separator)
+	if index < 0 {
+		return value, "", false
+	}
+
+	return value[:index], value[index+len(separator):], true
+}
+
+// RevokeToken disables the enabled token named name.
+func RevokeToken(ctx context.Context, api TokenAdminAPI, projectID uint64, name string) (rollbar.ProjectAccessToken, error) {
+	tokens, err := api.ListProjectAccessTokens(ctx, projectID)
+	if err != nil {
+		return rollbar.ProjectAccessToken{}, fmt.Errorf("list project access tokens: %w", err)
+	}
+	index := slices.IndexFunc(tokens, func(token rollbar.ProjectAccessToken) bool {
+		return enabledToken(token) && token.Name == strings.TrimSpace(name)
+	})
+	if index < 0 {
+		return rollbar.ProjectAccessToken{}, fmt.Errorf("no enabled token named %q", name)
+	}
+
+	if err := DisableToken(ctx, api, projectID, tokens[index]); err != nil {
+		return rollbar.ProjectAccessToken{}, err
+	}
+
+	return tokens[index], nil
+}
+
+// DisableToken disables token. The token value is in the request path, so it
+// is redacted from errors.
+func DisableToken(ctx context.Context, api TokenAdminAPI, projectID uint64, token rollbar.ProjectAccessToken) error {
+	if err := api.DisableProjectAccessToken(ctx, projectID, token.AccessToken); err != nil {
+		return fmt.Errorf("disable access token %s: %s", token.Name, redact.String(err.Error(), token.AccessToken))
+	}
+
+	return nil
+}
+
+func enabledToken(token rollbar.ProjectAccessToken) bool {
+	return token.Status == "" || token.Status == "enabled"
+}
@@ -0,0 +1,128 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeTokenAdminAPI struct {
+	tokens   []rollbar.ProjectAccessToken
+	created  []rollbar.AccessTokenRequest
+	disabled []string
+	err      error
+}
+
+func (f *fakeTokenAdminAPI) ListProjectAccessTokens(ctx context.Context, projectID uint64) ([]rollbar.ProjectAccessToken, error) {
+	return append([]rollbar.ProjectAccessToken{}, f.tokens...), nil
+}
+
+func (f *fakeTokenAdminAPI) CreateProjectAccessToken(ctx context.Context, projectID uint64, request rollbar.AccessTokenRequest) (rollbar.ProjectAccessToken, error) {
+	if f.err != nil {
+		return rollbar.ProjectAccessToken{}, f.err
+	}
+	f.created = append(f.created, request)
+	return rollbar.ProjectAccessToken{Name: request.Name, AccessToken: "new-token", Scopes: request.Scopes, Status: "enabled"}, nil
+}
+
+func (f *fakeTokenAdminAPI) DisableProjectAccessToken(ctx context.Context, projectID uint64, accessToken string) error {
+	if f.err != nil {
+		return f.err
+	}
+	f.disabled = append(f.disabled, accessToken)
+	return nil
+}
+
+func tokenFixture() []rollbar.ProjectAccessToken {
+	return []rollbar.ProjectAccessToken{
+		{Name: "web-20260101", AccessToken: "web-token", Status: "enabled", Scopes: []string{"post_client_item"}},
+		{Name: "ci", AccessToken: "ci-token", Status: "enabled", Scopes: []string{"post_server_item"}, RateLimitWindowSize: uint64Ptr(60), RateLimitWindowCount: uint64Ptr(500)},
+		{Name: "worker", AccessToken: "worker-token", Status: "enabled", Scopes: []string{"post_server_item"}},
+		{Name: "old", AccessToken: "old-token", Status: "disabled", Scopes: []string{"read"}},
+		{Name: "reader", AccessToken: "reader-token", Status: "enabled", Scopes: []string{"write", "read"}},
+	}
+}
+
+func TestListTokens(t *testing.T) {
+	t.Parallel()
+
+	tokens, err := ListTokens(context.Background(), &fakeTokenAdminAPI{tokens: tokenFixture()}, 7)
+	if err != nil {
+		t.Fatalf("ListTokens() error = %v", err)
+	}
+	names := make([]string, 0, len(tokens))
+	for _, token := range tokens {
+		names = append(names, token.Name)
+	}
+	if got := strings.Join(names, ","); got != "ci,reader,web-20260101,worker,old" {
+		t.Fatalf("ListTokens() order = %s", got)
+	}
+}
+
+func TestRotateToken(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 12, 0, 0, 0, time.UTC)
+	tests := []struct {
+		name     string
+		options  RotateOptions
+		wantName string
+		wantErr  string
+	}{
+		{name: "by name", options: RotateOptions{Scopes: []string{"post_server_item"}, Name: "ci"}, wantName: "ci-20261015"},
+		{name: "date suffix", options: RotateOptions{Scopes: []string{"post_client_item"}}, wantName: "web-20261015"},
+		{name: "scopes in any order", options: RotateOptions{Scopes: []string{"read", "write"}, NewName: "reader-v2"}, wantName: "reader-v2"},
+		{name: "ambiguous", options: RotateOptions{Scopes: []string{"post_server_item"}}, wantErr: "2 enabled tokens have scopes post_server_item (ci, worker); pick one with --name"},
+		{name: "disabled", options: RotateOptions{Scopes: []string{"read"}}, wantErr: "no enabled token has scopes read"},
+		{name: "wrong name", options: RotateOptions{Scopes: []string{"post_server_item"}, Name: "reader"}, wantErr: `no enabled token named "reader"`},
+		{name: "no scopes", options: RotateOptions{}, wantErr: "at least one scope"},
+	}
+	for _, tc := range tests {
+		api := &fakeTokenAdminAPI{tokens: tokenFixture()}
+		rotation, err := RotateToken(context.Background(), api, 7, tc.options, now)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("%s: expected %q error, got %v", tc.name, tc.wantErr, err)
+			}
+			continue
+		}
+		if err != nil || rotation.New.Name != tc.wantName || len(api.created) != 1 || rotation.Revoked {
+			t.Fatalf("%s: RotateToken() = %+v, %v", tc.name, rotation, err)
+		}
+	}
+}
+
+func TestRotateTokenCopiesRateLimit(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2026, 10, 15, 12, 0, 0, 0, time.UTC)
+	api := &fakeTokenAdminAPI{tokens: tokenFixture()}
+	if _, err := RotateToken(context.Background(), api, 7, RotateOptions{Scopes: []string{"post_server_item"}, Name: "ci"}, now); err != nil {
+		t.Fatalf("RotateToken() error = %v", err)
+	}
+	if request := api.created[0]; *request.RateLimitWindowSize != 60 || *request.RateLimitWindowCount != 500 {
+		t.Fatalf("expected the rate limit copied, got %+v", request)
+	}
+}
+
+func TestRevokeToken(t *testing.T) {
+	t.Parallel()
+
+	api := &fakeTokenAdminAPI{tokens: tokenFixture()}
+	token, err := RevokeToken(context.Background(), api, 7, " ci ")
+	if err != nil || token.Name != "ci" || len(api.disabled) != 1 || api.disabled[0] != "ci-token" {
+		t.Fatalf("RevokeToken() = %+v, %v (disabled %v)", token, err, api.disabled)
+	}
+	if _, err := RevokeToken(context.Background(), api, 7, "old"); err == nil || !strings.Contains(err.Error(), `no enabled token named "old"`) {
+		t.Fatalf("expected a missing token error, got %v", err)
+	}
+
+	api.err = errors.New("PATCH /project/7/access_token/ci-token: boom")
+	if _, err := RevokeToken(context.Background(), api, 7, "ci"); err == nil || strings.Contains(err.Error(), "ci-token") || !strings.Contains(err.Error(), "disable access token ci") {
+		t.Fatalf("expected a redacted disable error, got %v", err)
+	}
+}
@@ -0,0 +1,72 @@
+package app
+
+import (
+	"context"
+	"slices"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+type WatchEventKind string
+
+const (
+	WatchEventNew        WatchEventKind = "new"
+	WatchEventReoccurred WatchEventKind = "reoccurred"
+)
+
+type WatchEvent struct {
+	Kind WatchEventKind `json:"kind"`
+	// NewOccurrences is how many occurrences arrived since the previous poll,
+	// when Rollbar reports counts.
+	NewOccurrences uint64       `json:"new_occurrences,omitempty"`
+	Issue          IssueSummary `json:"issue"`
+}
+
+type watchMark struct {
+	lastSeen    uint64
+	occurrences uint64
+}
+
+// WatchState remembers what earlier polls saw so each change is reported once.
+type WatchState struct {
+	seen   map[domain.ItemID]watchMark
+	primed bool
+}
+
+// NewWatchState starts a watch. With includeExisting false the first poll only
+// records the current items; otherwise every item it finds is reported as new.
+func NewWatchState(includeExisting bool) *WatchState {
+	return &WatchState{seen: make(map[domain.ItemID]watchMark), primed: includeExisting}
+}
+
+// Poll lists matching items and reports items not seen before and items whose
+// last occurrence moved forward since the previous poll, oldest change first.
+func (s *Service) Poll(ctx context.Context, options ListOptions, state *WatchState) ([]WatchEvent, error) {
+	options.Sort = SortLastSeen
+	options.Ascending = false
+	issues, err := s.List(ctx, options)
+	if err != nil {
+		return nil, err
+	}
+	slices.Reverse(issues)
+
+	events := make([]WatchEvent, 0)
+	for _, issue := range issues {
+		mark := watchMark{lastSeen: uint64Value(issue.LastOccurrenceTimestamp), occurrences: uint64Value(issue.Occurrences)}
+		previous, seen := state.seen[issue.ItemID]
+		state.seen[issue.ItemID] = mark
+		if !state.primed {
+			continue
+		}
+
+		switch {
+		case !seen:
+			events = append(events, WatchEvent{Kind: WatchEventNew, NewOccurrences: mark.occurrences, Issue: issue})
+		case mark.lastSeen > previous.lastSeen:
+			events = append(events, WatchEvent{Kind: WatchEventReoccurred, NewOccurrences: mark.occurrences - min(mark.occurrences, previous.occurrences), Issue: issue})
+		}
+	}
+	state.primed = true
+
+	return events, nil
+}
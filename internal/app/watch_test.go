@@ -0,0 +1,66 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestServicePoll(t *testing.T) {
+	t.Parallel()
+
+	api := &pagedAPI{pages: [][]rollbar.Item{{issueFixture(fixture{counter: 1, lastSeen: 100, occurrences: 1})}}}
+	service := NewService(api)
+	state := NewWatchState(false)
+
+	events, err := service.Poll(context.Background(), ListOptions{}, state)
+	if err != nil || len(events) != 0 {
+		t.Fatalf("first poll should only prime state: %v, %v", events, err)
+	}
+
+	api.pages = [][]rollbar.Item{{
+		issueFixture(fixture{counter: 2, lastSeen: 300, occurrences: 1}),
+		issueFixture(fixture{counter: 1, lastSeen: 200, occurrences: 4}),
+	}}
+	events, err = service.Poll(context.Background(), ListOptions{}, state)
+	if err != nil {
+		t.Fatalf("second poll error = %v", err)
+	}
+	checkSecondPoll(t, events)
+
+	events, err = service.Poll(context.Background(), ListOptions{}, state)
+	if err != nil || len(events) != 0 {
+		t.Fatalf("unchanged poll should be quiet: %v, %v", events, err)
+	}
+}
+
+// checkSecondPoll checks that item 1's new occurrences come before new item 2.
+func checkSecondPoll(t *testing.T, events []WatchEvent) {
+	t.Helper()
+
+	if len(events) != 2 {
+		t.Fatalf("second poll = %v", events)
+	}
+	if events[0].Kind != WatchEventReoccurred || events[0].NewOccurrences != 3 || events[0].Issue.Counter != 1 {
+		t.Fatalf("expected reoccurrence of item 1 first, got %+v", events[0])
+	}
+	if events[1].Kind != WatchEventNew || events[1].Issue.Counter != 2 {
+		t.Fatalf("expected new item 2, got %+v", events[1])
+	}
+}
+
+func TestServicePollIncludeExisting(t *testing.T) {
+	t.Parallel()
+
+	api := &pagedAPI{pages: [][]rollbar.Item{{issueFixture(fixture{counter: 1, lastSeen: 100, occurrences: 1})}}}
+	events, err := NewService(api).Poll(context.Background(), ListOptions{}, NewWatchState(true))
+	if err != nil || len(events) != 1 || events[0].Kind != WatchEventNew {
+		t.Fatalf("Poll() = %v, %v", events, err)
+	}
+
+	if _, err := NewService(fakeAPI{err: errors.New("bad")}).Poll(context.Background(), ListOptions{}, NewWatchState(false)); err == nil {
+		t.Fatalf("expected Poll error")
+	}
+}
@@ -0,0 +1,39 @@
+package app
+
+import (
+	"encoding/json"
+	"errors"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/webhook"
+)
+
+// WebhookIssue summarizes the item a webhook event is about, like list
+// results. Deploy and test events have none.
+func WebhookIssue(event webhook.Event) (IssueSummary, bool) {
+	if event.Data.Item == nil {
+		return IssueSummary{}, false
+	}
+
+	return mapSummary(*event.Data.Item), true
+}
+
+// WebhookItemLink links the event's item without calling the API: by slugs
+// when both are known, otherwise by the payload's URL or occurrence.
+func WebhookItemLink(event webhook.Event, location WebLocation) (string, error) {
+	if event.Data.Item != nil && location.complete() {
+		return ItemWebURL(location, domain.ItemCounter(event.Data.Item.Counter)), nil
+	}
+	if event.Data.URL != "" {
+		return event.Data.URL, nil
+	}
+
+	var occurrence struct {
+		UUID string `json:"uuid"`
+	}
+	if len(event.Data.Occurrence) > 0 && json.Unmarshal(event.Data.Occurrence, &occurrence) == nil && occurrence.UUID != "" {
+		return OccurrenceWebURL(occurrence.UUID), nil
+	}
+
+	return "", errors.New("webhook has no item link; set the profile account and --project to build one")
+}
@@ -0,0 +1,45 @@
+package app
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/webhook"
+)
+
+func TestWebhookIssue(t *testing.T) {
+	t.Parallel()
+
+	total := uint64(3)
+	issue, ok := WebhookIssue(webhook.Event{Name: webhook.EventNewItem, Data: webhook.Data{Item: &rollbar.Item{ID: 9, Counter: 4, Title: "boom", Level: "error", TotalOccurrences: &total}}})
+	if !ok || issue.Counter != 4 || issue.Title != "boom" || *issue.Occurrences != 3 {
+		t.Fatalf("unexpected issue: %+v, %v", issue, ok)
+	}
+	if _, ok := WebhookIssue(webhook.Event{Name: webhook.EventDeploy}); ok {
+		t.Fatal("expected no issue for a deploy")
+	}
+}
+
+func TestWebhookItemLink(t *testing.T) {
+	t.Parallel()
+
+	item := &rollbar.Item{Counter: 4}
+	tests := []struct {
+		name     string
+		data     webhook.Data
+		location WebLocation
+		want     string
+	}{
+		{name: "slugs", data: webhook.Data{Item: item, URL: "https://example.com"}, location: WebLocation{Account: "acme", Project: "api"}, want: "https://rollbar.com/acme/api/items/4/"},
+		{name: "payload url", data: webhook.Data{Item: item, URL: "https://rollbar.com/item/uuid/?uuid=1"}, want: "https://rollbar.com/item/uuid/?uuid=1"},
+		{name: "occurrence", data: webhook.Data{Item: item, Occurrence: json.RawMessage(`{"uuid":"0f1e"}`)}, want: "https://rollbar.com/occurrence/uuid/?uuid=0f1e"},
+		{name: "none", data: webhook.Data{Item: item}},
+	}
+	for _, tc := range tests {
+		got, err := WebhookItemLink(webhook.Event{Data: tc.data}, tc.location)
+		if got != tc.want || (tc.want == "") != (err != nil) {
+			t.Fatalf("%s: WebhookItemLink() = %q, %v; want %q", tc.name, got, err, tc.want)
+		}
+	}
+}
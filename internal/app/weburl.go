@@ -0,0 +1,50 @@
+package app
+
+import (
+	"context"
+	"errors"
+	"net/url"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+const webBaseURL = "https://rollbar.com"
+
+// WebLocation is the account and project slug pair in Rollbar web URLs.
+type WebLocation struct {
+	Account string
+	Project string
+}
+
+func (l WebLocation) complete() bool {
+	return l.Account != "" && l.Project != ""
+}
+
+// ItemWebURL is the canonical web URL of an item.
+func ItemWebURL(location WebLocation, counter domain.ItemCounter) string {
+	return webBaseURL + "/" + url.PathEscape(location.Account) + "/" + url.PathEscape(location.Project) + "/items/" + counter.String() + "/"
+}
+
+// OccurrenceWebURL links an occurrence by UUID; Rollbar redirects it to the
+// occurrence page within its item.
+func OccurrenceWebURL(uuid string) string {
+	return webBaseURL + "/occurrence/uuid/?uuid=" + url.QueryEscape(uuid)
+}
+
+// ItemURL returns the item's web URL. Without both slugs it links the latest
+// occurrence instead, which needs no account knowledge.
+func (s *Service) ItemURL(ctx context.Context, counter domain.ItemCounter, location WebLocation) (string, error) {
+	if location.complete() {
+		return ItemWebURL(location, counter), nil
+	}
+
+	occurrences, err := s.Occurrences(ctx, counter, OccurrenceOptions{Limit: 1})
+	if err != nil {
+		return "", err
+	}
+	if len(occurrences) == 0 || occurrences[0].UUID == "" {
+		return "", errors.New("item has no occurrence to link to; pass the account and project slugs to build its URL")
+	}
+
+	return OccurrenceWebURL(occurrences[0].UUID), nil
+}
@@ -0,0 +1,40 @@
+package app
+
+import (
+	"context"
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestServiceItemURL(t *testing.T) {
+	t.Parallel()
+
+	withUUID := &rollbar.ItemInstance{ID: 1, Data: json.RawMessage(`{"uuid":"d4c3-b2a1"}`)}
+	tests := []struct {
+		name     string
+		api      fakeAPI
+		location WebLocation
+		want     string
+		wantErr  string
+	}{
+		{name: "slugs", location: WebLocation{Account: "acme", Project: "billing api"}, want: "https://rollbar.com/acme/billing%20api/items/269/"},
+		{name: "occurrence fallback", api: fakeAPI{instance: withUUID}, location: WebLocation{Account: "acme"}, want: "https://rollbar.com/occurrence/uuid/?uuid=d4c3-b2a1"},
+		{name: "no occurrences", wantErr: "no occurrence to link"},
+	}
+
+	for _, tc := range tests {
+		got, err := NewService(tc.api).ItemURL(context.Background(), 269, tc.location)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("%s: error = %v, want %q", tc.name, err, tc.wantErr)
+			}
+			continue
+		}
+		if err != nil || got != tc.want {
+			t.Fatalf("%s: ItemURL() = %q, %v; want %q", tc.name, got, err, tc.want)
+		}
+	}
+}
@@ -0,0 +1,223 @@
+package cli
+
+import (
+	"bufio"
+	"errors"
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/spf13/cobra"
+	"golang.org/x/term"
+
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/keyring"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const (
+	keyringService     = "rollbaz"
+	defaultAuthProject = "default"
+)
+
+var systemKeyring = keyring.System
+
+type authStatus struct {
+	Project string `json:"project"`
+	Active  bool   `json:"active"`
+	Storage string `json:"storage"`
+	// Readable reports whether the stored token could be read back.
+	Readable bool   `json:"readable"`
+	Error    string `json:"error,omitempty"`
+}
+
+func newAuthCmd(flags *rootFlags) *cobra.Command {
+	authCmd := &cobra.Command{Use: "auth", Short: "Store project tokens in the system keyring"}
+	authCmd.AddCommand(
+		&cobra.Command{
+			Use:   "login [project]",
+			Short: "Read a token from stdin and store it in the system keyring",
+			Args:  cobra.MaximumNArgs(1),
+			RunE: func(cmd *cobra.Command, args []string) error {
+				return runAuthLogin(*flags, args)
+			},
+		},
+		&cobra.Command{
+			Use:   "status",
+			Short: "Show where each project's token is stored",
+			Args:  cobra.NoArgs,
+			RunE: func(cmd *cobra.Command, args []string) error {
+				return runAuthStatus(*flags)
+			},
+		},
+		&cobra.Command{
+			Use:   "logout [project]",
+			Short: "Delete a project's token from the keyring and config",
+			Args:  cobra.MaximumNArgs(1),
+			RunE: func(cmd *cobra.Command, args []string) error {
+				return runAuthLogout(*flags, args)
+			},
+		},
+	)
+
+	return authCmd
+}
+
+func runAuthLogin(flags rootFlags, args []string) error {
+	store, err := newConfigStore()
+	if err != nil {
+		return err
+	}
+	name := authProjectName(store, flags, args, defaultAuthProject)
+
+	token, err := readToken()
+	if err != nil {
+		return err
+	}
+
+	ring, err := systemKeyring()
+	if err == nil {
+		err = ring.Set(keyringService, name, token)
+	}
+	if err != nil {
+		_, _ = fmt.Fprintf(stderrWriter, "warning: %s; storing the token in %s instead\n", redact.String(err.Error(), token), store.Path())
+		if err := store.AddProject(name, token); err != nil {
+			return fmt.Errorf("save token: %w", err)
+		}
+		_, _ = fmt.Fprintf(stdoutWriter, "Stored token for %q in the config file.\n", name)
+		return nil
+	}
+
+	if err := store.AddKeyringProject(name); err != nil {
+		return fmt.Errorf("save project: %w", err)
+	}
+	_, _ = fmt.Fprintf(stdoutWriter, "Stored token for %q in the system keyring.\n", name)
+
+	return nil
+}
+
+// readToken prompts without echo on a terminal and otherwise reads the first
+// line of stdin, so `pbpaste | rollbaz auth login` works too.
+func readToken() (string, error) {
+	var token string
+	if file, ok := stdinReader.(*os.File); ok && isTerminal(int(file.Fd())) {
+		_, _ = fmt.Fprint(stderrWriter, "Rollbar access token: ")
+		secret, err := term.ReadPassword(int(file.Fd()))
+		_, _ = fmt.Fprintln(stderrWriter)
+		if err != nil {
+			return "", fmt.Errorf("read token: %w", err)
+		}
+		token = string(secret)
+	} else {
+		line, err := bufio.NewReader(stdinReader).ReadString('\n')
+		if err != nil && line == "" {
+			return "", errors.New("read token: no token on stdin")
+		}
+		token = line
+	}
+
+	token = strings.TrimSpace(token)
+	if token == "" {
+		return "", errors.New("token is empty")
+	}
+
+	return token, nil
+}
+
+func runAuthStatus(flags rootFlags) error {
+	store, err := newConfigStore()
+	if err != nil {
+		return err
+	}
+	file, err := store.Load()
+	if err != nil {
+		return fmt.Errorf("load config: %w", err)
+	}
+
+	statuses := make([]authStatus, 0, len(file.Projects))
+	for _, project := range file.Projects {
+		statuses = append(statuses, checkAuth(project, project.Name == file.ActiveProject))
+	}
+
+	return printOutput(flags, renderAuthStatus(statuses), map[string]any{"projects": statuses})
+}
+
+func checkAuth(project config.Project, active bool) authStatus {
+	status := authStatus{Project: project.Name, Active: active, Storage: "config file", Readable: project.Token != ""}
+	if !project.Keyring {
+		return status
+	}
+
+	status.Storage = "keyring"
+	ring, err := systemKeyring()
+	if err == nil {
+		_, err = ring.Get(keyringService, project.Name)
+	}
+	if err != nil {
+		status.Error = err.Error()
+		return status
+	}
+	status.Readable = true
+
+	return status
+}
+
+func renderAuthStatus(statuses []authStatus) string {
+	if len(statuses) == 0 {
+		return "no configured projects"
+	}
+
+	lines := make([]string, 0, len(statuses))
+	for _, status := range statuses {
+		prefix := "  "
+		if status.Active {
+			prefix = "* "
+		}
+		line := prefix + status.Project + " (" + status.Storage + ")"
+		if status.Error != "" {
+			line += ": " + status.Error
+		}
+		lines = append(lines, line)
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+func runAuthLogout(flags rootFlags, args []string) error {
+	store, err := newConfigStore()
+	if err != nil {
+		return err
+	}
+	name := authProjectName(store, flags, args, "")
+	if name == "" {
+		return errors.New("specify a project: no active project configured")
+	}
+
+	if ring, err := systemKeyring(); err == nil {
+		if err := ring.Delete(keyringService, name); err != nil && !errors.Is(err, keyring.ErrNotFound) {
+			return fmt.Errorf("delete keyring token: %w", err)
+		}
+	}
+	if err := store.RemoveProject(name); err != nil {
+		return fmt.Errorf("remove project: %w", err)
+	}
+	_, _ = fmt.Fprintf(stdoutWriter, "Logged out of %q.\n", name)
+
+	return nil
+}
+
+// authProjectName picks the project an auth command acts on: the argument,
+// then --project, then the active project, then fallback.
+func authProjectName(store *config.Store, flags rootFlags, args []string, fallback string) string {
+	if len(args) > 0 {
+		return args[0]
+	}
+	if flags.Project != "" {
+		return flags.Project
+	}
+	if file, err := store.Load(); err == nil && file.ActiveProject != "" {
+		return file.ActiveProject
+	}
+
+	return fallback
+}
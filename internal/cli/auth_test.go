@@ -0,0 +1,131 @@
+package cli
+
+import (
+	"bytes"
+	"errors"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/keyring"
+)
+
+type memoryKeyring map[string]string
+
+func (m memoryKeyring) Set(service string, account string, secret string) error {
+	m[service+"/"+account] = secret
+	return nil
+}
+
+func (m memoryKeyring) Get(service string, account string) (string, error) {
+	secret, ok := m[service+"/"+account]
+	if !ok {
+		return "", keyring.ErrNotFound
+	}
+	return secret, nil
+}
+
+func (m memoryKeyring) Delete(service string, account string) error {
+	if _, ok := m[service+"/"+account]; !ok {
+		return keyring.ErrNotFound
+	}
+	delete(m, service+"/"+account)
+	return nil
+}
+
+func setupAuthTest(t *testing.T, ring keyring.Keyring, ringErr error) (*config.Store, *bytes.Buffer, *bytes.Buffer) {
+	t.Helper()
+	stdout := setupServerAndStdout(t, newSuccessHandler(t))
+	t.Setenv("ROLLBAR_ACCESS_TOKEN", "")
+
+	store := config.NewStoreAtPath(filepath.Join(t.TempDir(), "config.json"))
+	t.Cleanup(overrideConfigStore(func() (*config.Store, error) { return store, nil }))
+
+	originalRing := systemKeyring
+	systemKeyring = func() (keyring.Keyring, error) { return ring, ringErr }
+	stderr := &bytes.Buffer{}
+	stderrWriter = stderr
+	t.Cleanup(func() {
+		systemKeyring = originalRing
+		stderrWriter = os.Stderr
+	})
+
+	return store, stdout, stderr
+}
+
+func runWithStdin(t *testing.T, stdin string, args ...string) error {
+	t.Helper()
+	original := stdinReader
+	stdinReader = strings.NewReader(stdin)
+	defer func() { stdinReader = original }()
+
+	cmd := NewRootCmd()
+	cmd.SetArgs(args)
+	return cmd.Execute()
+}
+
+func TestAuthLoginStatusLogoutWithKeyring(t *testing.T) {
+	ring := memoryKeyring{}
+	store, stdout, _ := setupAuthTest(t, ring, nil)
+
+	loginWithKeyring(t, ring, store)
+
+	runRootCommand(t, "show", "269")
+	if !strings.Contains(stdout.String(), "RST_STREAM") {
+		t.Fatalf("expected show to use the keyring token:\n%s", stdout.String())
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "auth", "status", "--format", "json")
+	if !strings.Contains(stdout.String(), `"storage": "keyring"`) || strings.Contains(stdout.String(), "secret-token") {
+		t.Fatalf("unexpected status output:\n%s", stdout.String())
+	}
+
+	runRootCommand(t, "auth", "logout")
+	if len(ring) != 0 {
+		t.Fatalf("expected keyring entry removed, got %v", ring)
+	}
+	if file, _ := store.Load(); len(file.Projects) != 0 {
+		t.Fatalf("expected project removed, got %+v", file)
+	}
+}
+
+// loginWithKeyring logs in to project work and checks that its token went to
+// ring rather than the config file.
+func loginWithKeyring(t *testing.T, ring memoryKeyring, store *config.Store) {
+	t.Helper()
+
+	if err := runWithStdin(t, "secret-token\n", "auth", "login", "work"); err != nil {
+		t.Fatalf("auth login error = %v", err)
+	}
+	if ring["rollbaz/work"] != "secret-token" {
+		t.Fatalf("expected token in keyring, got %v", ring)
+	}
+	file, err := store.Load()
+	if err != nil || len(file.Projects) != 1 || !file.Projects[0].Keyring || file.Projects[0].Token != "" {
+		t.Fatalf("expected keyring-backed project without file token: %+v (%v)", file, err)
+	}
+}
+
+func TestAuthLoginFallsBackToConfigFile(t *testing.T) {
+	store, stdout, stderr := setupAuthTest(t, nil, errors.New("system keyring unavailable"))
+
+	if err := runWithStdin(t, "  file-token  \n", "auth", "login"); err != nil {
+		t.Fatalf("auth login error = %v", err)
+	}
+	if !strings.Contains(stderr.String(), "warning: system keyring unavailable") {
+		t.Fatalf("expected fallback warning, got %q", stderr.String())
+	}
+	if token, name, err := store.ResolveToken(""); err != nil || token != "file-token" || name != "default" {
+		t.Fatalf("ResolveToken() = %q, %q, %v", token, name, err)
+	}
+	if !strings.Contains(stdout.String(), "config file") {
+		t.Fatalf("unexpected login output: %s", stdout.String())
+	}
+
+	if err := runWithStdin(t, "", "auth", "login"); err == nil || !strings.Contains(err.Error(), "no token on stdin") {
+		t.Fatalf("expected empty stdin error, got %v", err)
+	}
+}
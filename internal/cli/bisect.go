@@ -0,0 +1,61 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+func newBisectCmd(flags *rootFlags) *cobra.Command {
+	gitOnly := false
+	bisectCmd := &cobra.Command{
+		Use:   "bisect <item-counter|url>",
+		Short: "Find the deploy range an item first appeared in, for git bisect",
+		Long:  "Find the deploy that was live in the item's environment (or --env) when it first occurred, and the deploy of another revision before it, and print the git bisect command that searches between their revisions. --git prints only that command, to run it straight away. For example:\n\n  rollbaz item bisect 42\n  eval \"$(rollbaz item bisect 42 --git)\"",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+
+			return runItemBisect(cmd.Context(), *flags, counter, gitOnly)
+		},
+	}
+	bisectCmd.Flags().BoolVar(&gitOnly, "git", false, "Print only the git bisect start command")
+
+	return bisectCmd
+}
+
+func runItemBisect(parent context.Context, flags rootFlags, counter domain.ItemCounter, gitOnly bool) error {
+	ctx, cancel := context.WithTimeout(parent, time.Minute)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	bisect, err := runWithProgress(flags.Format, "Finding deploys", func() (app.BisectRange, error) {
+		return app.BisectItem(ctx, client, counter, flags.Environment)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	human := output.RenderBisectRange(bisect)
+	if gitOnly {
+		if human = output.GitBisectCommand(bisect); human == "" {
+			return errors.New("no deploy went live before the item first occurred; nothing to bisect")
+		}
+	}
+
+	return printOutput(flags, human, redact.Value(bisect, token))
+}
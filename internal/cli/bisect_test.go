@@ -0,0 +1,47 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+// serveBisect serves item 42, first seen between production deploys 42
+// and 43, and item 7, first seen before deploy 41.
+func serveBisect(w http.ResponseWriter, r *http.Request) {
+	switch {
+	case strings.HasPrefix(r.URL.Path, "/api/1/item_by_counter/"):
+		_, _ = fmt.Fprintf(w, `{"err":0,"result":{"itemId":%s}}`, strings.TrimPrefix(r.URL.Path, "/api/1/item_by_counter/"))
+	case r.URL.Path == "/api/1/item/42/":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":42,"counter":42,"title":"KeyError: 'cart'","status":"active","environment":"production","first_occurrence_timestamp":1700005000}}`)
+	case r.URL.Path == "/api/1/item/7/":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":7,"counter":7,"title":"ancient","status":"active","environment":"production","first_occurrence_timestamp":1600000000}}`)
+	case r.URL.Path == "/api/1/deploys" && r.URL.Query().Get("page") == "1":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"deploys":[{"id":43,"environment":"production","revision":"99aa","start_time":1700009000},{"id":42,"environment":"production","revision":"0123456789abcdef","start_time":1700000000},{"id":41,"environment":"production","revision":"fedcba9876543210","start_time":1690000000}],"page":1}}`)
+	case r.URL.Path == "/api/1/deploys":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"deploys":[],"page":2}}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s"}`, r.URL.Path)
+	}
+}
+
+func TestItemBisectCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveBisect))
+
+	runRootCommand(t, "item", "bisect", "42")
+	checkContains(t, "bisect", stdout.String(), "bad   deploy 42 of 0123456789ab", "good  deploy 41 of fedcba987654", "git bisect start 0123456789abcdef fedcba9876543210")
+
+	stdout.Reset()
+	runRootCommand(t, "item", "bisect", "42", "--git")
+	if stdout.String() != "git bisect start 0123456789abcdef fedcba9876543210\n" {
+		t.Fatalf("unexpected --git output %q", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"item", "bisect", "7", "--git"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "nothing to bisect") {
+		t.Fatalf("expected a nothing to bisect error, got %v", err)
+	}
+}
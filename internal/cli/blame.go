@@ -0,0 +1,72 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/source"
+	"github.com/kevinsheth/rollbaz/internal/vcs"
+)
+
+func newBlameCmd(flags *rootFlags) *cobra.Command {
+	sourceRoot := ""
+	frames := 0
+	blameCmd := &cobra.Command{
+		Use:   "blame <item-counter|url>",
+		Short: "Show who last changed the lines of an item's stack frames",
+		Long:  "Run git blame in the checkout at --source-root on the line of each of the top --frames frames of the item's latest stack trace found there, and list the commit, author, and date that last touched each line, then the authors ranked by how many lines they last changed. For example:\n\n  rollbaz item blame 42 --source-root ~/src/api",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+
+			return runItemBlame(cmd.Context(), *flags, counter, sourceRoot, frames)
+		},
+	}
+	blameCmd.Flags().StringVar(&sourceRoot, "source-root", ".", "Git checkout the stack frames are placed in")
+	blameCmd.Flags().IntVar(&frames, "frames", 3, "How many in-project frames to blame, most recent call first")
+	_ = blameCmd.MarkFlagDirname("source-root")
+
+	return blameCmd
+}
+
+func runItemBlame(parent context.Context, flags rootFlags, counter domain.ItemCounter, sourceRoot string, frames int) error {
+	root, err := source.Open(sourceRoot)
+	if err != nil {
+		return fmt.Errorf("--source-root: %w", err)
+	}
+	defer func() { _ = root.Close() }()
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	options := app.BlameOptions{
+		Locate: flags.InApp.Filter(root.Path),
+		Blame: func(ctx context.Context, path string, line int) (vcs.BlameLine, error) {
+			return vcs.Blame(ctx, sourceRoot, path, line)
+		},
+		Frames: frames,
+	}
+	result, err := runWithProgress(flags.Format, "Running git blame", func() (app.BlameResult, error) {
+		return app.BlameItem(ctx, client, counter, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderBlameResultWithWidth(result, terminalRenderWidth()), redact.Value(result, token))
+}
@@ -0,0 +1,71 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// serveBlamedItem serves item 42 with a frame on line 2 of pay/charge.py.
+func serveBlamedItem(w http.ResponseWriter, r *http.Request) {
+	switch r.URL.Path {
+	case "/api/1/item_by_counter/42":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":9}}`)
+	case "/api/1/item/9/":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":9,"counter":42,"title":"KeyError: 'cart'","status":"active"}}`)
+	case "/api/1/item/9/instances":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":1,"body":{"trace":{"frames":[{"filename":"/srv/app/pay/charge.py","lineno":2},{"filename":"/usr/lib/python3/json.py","lineno":300}],"exception":{"class":"KeyError"}}}}]}}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s"}`, r.URL.Path)
+	}
+}
+
+// commitBlameCheckout returns a checkout where Ana Lopez committed
+// pay/charge.py, skipping the test when git is missing.
+func commitBlameCheckout(t *testing.T) string {
+	t.Helper()
+
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git is not installed")
+	}
+	checkout := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(checkout, "pay"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(checkout, "pay", "charge.py"), []byte("def charge(cart):\n    return carts[cart]\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	for _, args := range [][]string{{"init", "-q"}, {"add", "."}, {"-c", "user.name=Ana Lopez", "-c", "user.email=ana@example.com", "commit", "-q", "-m", "Look carts up by id"}} {
+		command := exec.Command("git", args...)
+		command.Dir = checkout
+		if output, err := command.CombinedOutput(); err != nil {
+			t.Fatalf("git %v: %v\n%s", args, err, output)
+		}
+	}
+	return checkout
+}
+
+func TestItemBlameCommand(t *testing.T) {
+	checkout := commitBlameCheckout(t)
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveBlamedItem))
+
+	runRootCommand(t, "item", "blame", "42", "--source-root", checkout)
+	checkContains(t, "blame", stdout.String(), "Blame for #42 KeyError: 'cart'", "pay/charge.py:2", "Look carts up by id", "Ana Lopez <ana@example.com>")
+
+	stdout.Reset()
+	runRootCommand(t, "item", "blame", "42", "--source-root", checkout, "--format", "json")
+	if !strings.Contains(stdout.String(), `"author": "Ana Lopez"`) || !strings.Contains(stdout.String(), `"lines": 1`) {
+		t.Fatalf("unexpected JSON output:\n%s", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"item", "blame", "42", "--source-root", filepath.Join(checkout, "missing")})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "--source-root") {
+		t.Fatalf("expected a --source-root error, got %v", err)
+	}
+}
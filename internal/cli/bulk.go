@@ -0,0 +1,206 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/jedib0t/go-pretty/v6/progress"
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const defaultBulkMax = 500
+
+type bulkFlags struct {
+	Query           string
+	OlderThan       string
+	Levels          []string
+	ResolvedVersion string
+	Concurrency     int
+	Max             int
+	DryRun          bool
+}
+
+func newBulkResolveCmd(flags *rootFlags) *cobra.Command {
+	bulk := bulkFlags{}
+	bulkCmd := &cobra.Command{
+		Use:   "bulk-resolve",
+		Short: "Resolve every item matching a query after a preview and confirmation",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runBulkResolve(cmd.Context(), *flags, bulk)
+		},
+	}
+	bulkCmd.Flags().StringVar(&bulk.Query, "query", "", "Rollbar item search text")
+	bulkCmd.Flags().StringVar(&bulk.OlderThan, "older-than", "", "Only items last seen longer ago than this (examples: 12h, 30d)")
+	bulkCmd.Flags().StringSliceVar(&bulk.Levels, "level", nil, "Filter by level (repeatable)")
+	bulkCmd.Flags().StringVar(&bulk.ResolvedVersion, "until-version", "", "Version to store when resolving")
+	bulkCmd.Flags().IntVar(&bulk.Concurrency, "concurrency", app.DefaultBulkConcurrency, "Maximum concurrent update requests")
+	bulkCmd.Flags().IntVar(&bulk.Max, "max", defaultBulkMax, "Refuse to run when more items than this match")
+	bulkCmd.Flags().BoolVar(&bulk.DryRun, "dry-run", false, "Show matching items without changing them")
+
+	return bulkCmd
+}
+
+func runBulkResolve(parent context.Context, flags rootFlags, bulk bulkFlags) error {
+	options, patch, err := parseBulkResolve(flags, bulk)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 5*time.Minute)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+	client.SetThrottle(bulk.Concurrency)
+	service := app.NewService(client)
+
+	issues, err := runWithProgress(flags.Format, "Finding matching items", func() ([]app.IssueSummary, error) {
+		return service.List(ctx, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+	if err := previewBulk(flags, bulk, issues, token); err != nil || bulk.DryRun || len(issues) == 0 {
+		return err
+	}
+	if err := confirmWrite(flags, "resolve", issueCounters(issues)); err != nil {
+		return err
+	}
+
+	results := runBulkWithProgress(flags.Format, len(issues), func(onDone func(app.BulkResult)) []app.BulkResult {
+		return service.BulkUpdate(ctx, issues, patch, bulk.Concurrency, onDone)
+	})
+
+	return printBulkResults(flags, token, results)
+}
+
+func parseBulkResolve(flags rootFlags, bulk bulkFlags) (app.ListOptions, rollbar.ItemPatch, error) {
+	if !hasBulkFilter(flags, bulk) {
+		return app.ListOptions{}, rollbar.ItemPatch{}, errors.New("bulk-resolve needs at least one filter: --query, --older-than, --level, --env, --since, or --until")
+	}
+	if flags.Status == "" {
+		flags.Status = string(rollbar.StatusActive)
+	}
+
+	options, err := parseListOptions(flags, itemsListFlags{Levels: bulk.Levels})
+	if err != nil {
+		return app.ListOptions{}, rollbar.ItemPatch{}, err
+	}
+	options.Query = bulk.Query
+	options.Limit = bulk.Max + 1
+	options.Complete = true
+
+	if options.Filters.Until, err = applyOlderThan(options.Filters.Until, bulk.OlderThan); err != nil {
+		return app.ListOptions{}, rollbar.ItemPatch{}, err
+	}
+
+	patch, err := app.ResolvePatch(bulk.ResolvedVersion)
+	return options, patch, err
+}
+
+// hasBulkFilter guards against resolving every active item by accident.
+func hasBulkFilter(flags rootFlags, bulk bulkFlags) bool {
+	return strings.TrimSpace(bulk.Query) != "" || bulk.OlderThan != "" || len(bulk.Levels) > 0 ||
+		flags.Environment != "" || flags.Since != "" || flags.Until != ""
+}
+
+// applyOlderThan tightens until so only items last seen before now-olderThan
+// match.
+func applyOlderThan(until *time.Time, olderThan string) (*time.Time, error) {
+	if olderThan == "" {
+		return until, nil
+	}
+
+	age, ok := parseRelativeDuration(olderThan)
+	if !ok {
+		return nil, fmt.Errorf("parse --older-than: invalid duration %q", olderThan)
+	}
+	cutoff := time.Now().Add(-age).UTC()
+	if until != nil && until.Before(cutoff) {
+		return until, nil
+	}
+
+	return &cutoff, nil
+}
+
+// previewBulk shows what would change. Human output gets a table before the
+// prompt; other formats print the preview only for --dry-run so a real run
+// emits a single document.
+func previewBulk(flags rootFlags, bulk bulkFlags, issues []app.IssueSummary, token string) error {
+	if len(issues) > bulk.Max {
+		return fmt.Errorf("more than %d items match; narrow the filters or raise --max", bulk.Max)
+	}
+	if !isHumanFormat(flags.Format) && !bulk.DryRun && len(issues) > 0 {
+		return nil
+	}
+
+	human := fmt.Sprintf("%d matching issues\n\n%s", len(issues), output.RenderItemTableWithWidth(issues, terminalRenderWidth()))
+	jsonPayload := redact.Value(map[string]any{"action": "resolve", "dry_run": bulk.DryRun, "issues": issues}, token)
+
+	return printOutput(flags, human, jsonPayload)
+}
+
+func issueCounters(issues []app.IssueSummary) []domain.ItemCounter {
+	counters := make([]domain.ItemCounter, 0, len(issues))
+	for _, issue := range issues {
+		counters = append(counters, issue.Counter)
+	}
+
+	return counters
+}
+
+func runBulkWithProgress(format string, total int, operation func(func(app.BulkResult)) []app.BulkResult) []app.BulkResult {
+	if !shouldRenderProgress(format) {
+		return operation(nil)
+	}
+
+	writer := newProgressWriter()
+	tracker := progress.Tracker{Message: "Resolving issues", Total: int64(total), Units: progress.UnitsDefault}
+	writer.AppendTracker(&tracker)
+	writer.Style().Visibility.Value = true
+
+	done := make(chan struct{})
+	go func() {
+		writer.Render()
+		close(done)
+	}()
+
+	results := operation(func(app.BulkResult) { tracker.Increment(1) })
+	tracker.MarkAsDone()
+	waitForProgressStop(done)
+
+	return results
+}
+
+func printBulkResults(flags rootFlags, token string, results []app.BulkResult) error {
+	failures := app.BulkFailures(results)
+
+	lines := []string{fmt.Sprintf("resolved %d of %d issues", len(results)-failures, len(results))}
+	for _, result := range results {
+		if result.Error != "" {
+			lines = append(lines, fmt.Sprintf("  %s: %s", result.Counter, result.Error))
+		}
+	}
+	jsonPayload := redact.Value(map[string]any{"action": "resolved", "results": results}, token)
+	if err := printOutput(flags, strings.Join(lines, "\n"), jsonPayload); err != nil {
+		return err
+	}
+
+	if failures > 0 {
+		return fmt.Errorf("%d of %d updates failed", failures, len(results))
+	}
+
+	return nil
+}
@@ -0,0 +1,89 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+func newBulkHandler(t *testing.T, patches *atomic.Int32) http.Handler {
+	t.Helper()
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.URL.Path == "/api/1/items":
+			if r.URL.Query().Get("query") != "payment timeout" || r.URL.Query().Get("status") != "active" {
+				w.WriteHeader(http.StatusBadRequest)
+				_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected query %s"}`, r.URL.RawQuery)
+				return
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":11,"counter":1,"title":"a","status":"active","last_occurrence_timestamp":1000},{"id":12,"counter":2,"title":"b","status":"active","last_occurrence_timestamp":1000},{"id":13,"counter":3,"title":"fresh","status":"active","last_occurrence_timestamp":`+fmt.Sprint(time.Now().Unix())+`}],"total_count":3}}`)
+		case r.Method == http.MethodPatch && strings.HasPrefix(r.URL.Path, "/api/1/item/"):
+			patches.Add(1)
+			if r.URL.Path == "/api/1/item/12" {
+				w.WriteHeader(http.StatusUnprocessableEntity)
+				_, _ = fmt.Fprint(w, `{"err":1,"message":"cannot resolve"}`)
+				return
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+		default:
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+	})
+}
+
+func TestBulkResolve(t *testing.T) {
+	var patches atomic.Int32
+	stdout := setupServerAndStdout(t, newBulkHandler(t, &patches))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"items", "bulk-resolve", "--query", "payment timeout", "--older-than", "30d", "--yes", "--format", "json"})
+	err := cmd.Execute()
+	if err == nil || !strings.Contains(err.Error(), "1 of 2 updates failed") {
+		t.Fatalf("expected partial failure error, got %v", err)
+	}
+	if patches.Load() != 2 {
+		t.Fatalf("expected 2 patches (fresh item excluded), got %d", patches.Load())
+	}
+
+	out := stdout.String()
+	if !strings.Contains(out, `"counter": 1`) || !strings.Contains(out, "cannot resolve") || strings.Contains(out, "fresh") {
+		t.Fatalf("unexpected output: %s", out)
+	}
+}
+
+func TestBulkResolveDryRunAndGuards(t *testing.T) {
+	var patches atomic.Int32
+	stdout := setupServerAndStdout(t, newBulkHandler(t, &patches))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"items", "bulk-resolve", "--query", "payment timeout", "--dry-run"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("dry run error = %v", err)
+	}
+	if patches.Load() != 0 || !strings.Contains(stdout.String(), "3 matching issues") {
+		t.Fatalf("unexpected dry run: patches=%d output=%s", patches.Load(), stdout.String())
+	}
+
+	tests := []struct {
+		args    []string
+		wantErr string
+	}{
+		{args: []string{"items", "bulk-resolve", "--yes"}, wantErr: "at least one filter"},
+		{args: []string{"items", "bulk-resolve", "--query", "payment timeout", "--max", "2", "--yes"}, wantErr: "more than 2 items match"},
+		{args: []string{"items", "bulk-resolve", "--older-than", "soon", "--yes"}, wantErr: "--older-than"},
+		{args: []string{"items", "bulk-resolve", "--query", "payment timeout"}, wantErr: "confirmation required"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.wantErr, err)
+		}
+	}
+	if patches.Load() != 0 {
+		t.Fatalf("expected guards to prevent writes, got %d patches", patches.Load())
+	}
+}
@@ -0,0 +1,42 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"sync"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// cassettes holds one cassette per path for the life of the process, so
+// commands that build several clients record into, or replay from, the
+// same file.
+var (
+	cassettesMu sync.Mutex
+	cassettes = map[string]*rollbar.Cassette{}
+)
+
+// useCassette records or replays client traffic through the file named by
+// ROLLBAZ_CASSETTE, when it is set.
+func useCassette(client *rollbar.Client) error {
+	path := os.Getenv("ROLLBAZ_CASSETTE")
+	if path == "" {
+		return nil
+	}
+
+	cassettesMu.Lock()
+	defer cassettesMu.Unlock()
+
+	cassette, ok := cassettes[path]
+	if !ok {
+		opened, err := rollbar.OpenCassette(path)
+		if err != nil {
+			return fmt.Errorf("ROLLBAZ_CASSETTE: %w", err)
+		}
+		cassette = opened
+		cassettes[path] = cassette
+	}
+	client.Use(cassette.Middleware(client.MaxResponseBytes()))
+
+	return nil
+}
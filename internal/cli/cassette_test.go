@@ -0,0 +1,50 @@
+package cli
+
+import (
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestCassetteRecordsAndReplays(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "show.json")
+	t.Setenv("ROLLBAZ_CASSETTE", path)
+	resetCassettes(t)
+
+	recorded := setupServerAndStdout(t, newSuccessHandler(t))
+	runRootCommand(t, "show", "269")
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("ReadFile() error = %v", err)
+	}
+	if !strings.Contains(string(content), "RST_STREAM") || strings.Contains(string(content), `"token"`) {
+		t.Fatalf("unexpected cassette:\n%s", content)
+	}
+
+	resetCassettes(t)
+	replayed := setupServerAndStdout(t, http.HandlerFunc(func(http.ResponseWriter, *http.Request) {
+		t.Error("expected the replay not to reach the server")
+	}))
+	runRootCommand(t, "show", "269")
+	if replayed.String() != recorded.String() {
+		t.Fatalf("replay differs:\n%s\nwant:\n%s", replayed.String(), recorded.String())
+	}
+}
+
+func resetCassettes(t *testing.T) {
+	t.Helper()
+
+	cassettesMu.Lock()
+	cassettes = map[string]*rollbar.Cassette{}
+	cassettesMu.Unlock()
+	t.Cleanup(func() {
+		cassettesMu.Lock()
+		cassettes = map[string]*rollbar.Cassette{}
+		cassettesMu.Unlock()
+	})
+}
@@ -0,0 +1,150 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const (
+	defaultGateSince = 30 * time.Minute
+	// maxAnnotations matches GitHub's cap on error annotations per step, so
+	// only the most frequent new items cost a trace lookup.
+	maxAnnotations = 10
+)
+
+type gateFlags struct {
+	MaxNewItems    int64
+	MaxOccurrences int64
+	SourceRoot     string
+}
+
+func newCICmd(flags *rootFlags) *cobra.Command {
+	ciCmd := &cobra.Command{Use: "ci", Short: "Gate CI and deploy pipelines on Rollbar data"}
+	ciCmd.AddCommand(newCICheckCmd(flags))
+
+	return ciCmd
+}
+
+func newCICheckCmd(flags *rootFlags) *cobra.Command {
+	gate := gateFlags{}
+	checkCmd := &cobra.Command{
+		Use:         "check",
+		Short:       "Fail when new items or occurrences since --since exceed thresholds",
+		Long:        "Count the active items first seen and the occurrences reported since --since (default 30m) in --env, and exit 2 when either exceeds its threshold so a pipeline can roll back; any other failure exits 1. With --format github each new item is also printed as a GitHub Actions annotation on the most recent frame of its latest stack trace found in --source-root, and --format sarif prints the new items as a SARIF log for code scanning. For example:\n\n  rollbaz ci check --env staging --since 30m --max-new-items 0 --max-occurrences 10",
+		Args:        cobra.NoArgs,
+		Annotations: map[string]string{sarifAnnotation: "true"},
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runCICheck(cmd.Context(), *flags, gate)
+		},
+	}
+	checkCmd.Flags().Int64Var(&gate.MaxNewItems, "max-new-items", -1, "Most new items allowed (-1 to skip the check)")
+	checkCmd.Flags().Int64Var(&gate.MaxOccurrences, "max-occurrences", -1, "Most occurrences allowed (-1 to skip the check)")
+	checkCmd.Flags().StringVar(&gate.SourceRoot, "source-root", ".", "Checkout that --format github and sarif place new items in")
+	_ = checkCmd.MarkFlagDirname("source-root")
+
+	return checkCmd
+}
+
+func runCICheck(parent context.Context, flags rootFlags, gate gateFlags) error {
+	now := time.Now()
+	options, err := gateOptions(flags, gate, now)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, time.Minute)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	result, err := runWithProgress(flags.Format, "Checking thresholds", func() (app.GateResult, error) {
+		return app.CheckGate(ctx, client, options, now)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+	human, payload, err := gateOutput(ctx, client, flags, gate.SourceRoot, result)
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+	if err := printOutput(flags, human, redact.Value(payload, token)); err != nil {
+		return err
+	}
+	if !result.Passed {
+		return &exitCodeError{code: exitCheckFailed, err: fmt.Errorf("ci check failed: %s", failedChecks(result))}
+	}
+
+	return nil
+}
+
+// gateOutput returns what ci check prints: the rendered gate, led with
+// --format github by an annotation per new item, and the payload, which with
+// --format sarif is a SARIF log of the new items.
+func gateOutput(ctx context.Context, api app.FrameAPI, flags rootFlags, sourceRoot string, result app.GateResult) (string, any, error) {
+	parsed, err := output.ParseFormat(flags.Format)
+	if err != nil {
+		return "", nil, err
+	}
+	human := output.RenderGateResult(result)
+	load := func(ctx context.Context, items []app.IssueSummary) ([]app.ItemFrames, error) {
+		return app.LatestFrames(ctx, api, items)
+	}
+
+	switch {
+	case parsed == output.FormatSARIF:
+		log, err := renderItemFrames(ctx, load, result.NewItems, sourceRoot, flags.InApp, sarifRenderer)
+		return human, log, err
+	case parsed == output.FormatGitHub && len(result.NewItems) > 0:
+		annotations, err := renderItemFrames(ctx, load, result.NewItems[:min(len(result.NewItems), maxAnnotations)], sourceRoot, flags.InApp, output.RenderGitHubAnnotations)
+		return annotations + "\n" + human, result, err
+	default:
+		return human, result, nil
+	}
+}
+
+func gateOptions(flags rootFlags, gate gateFlags, now time.Time) (app.GateOptions, error) {
+	options := app.GateOptions{Environment: flags.Environment, Since: now.Add(-defaultGateSince)}
+	if flags.Since != "" {
+		since, err := parseFilterTime(flags.Since)
+		if err != nil {
+			return app.GateOptions{}, fmt.Errorf("parse --since: %w", err)
+		}
+		options.Since = *since
+	}
+	if gate.MaxNewItems >= 0 {
+		limit := uint64(gate.MaxNewItems)
+		options.MaxNewItems = &limit
+	}
+	if gate.MaxOccurrences >= 0 {
+		limit := uint64(gate.MaxOccurrences)
+		options.MaxOccurrences = &limit
+	}
+	if options.MaxNewItems == nil && options.MaxOccurrences == nil {
+		return app.GateOptions{}, errors.New("set --max-new-items, --max-occurrences, or both")
+	}
+
+	return options, nil
+}
+
+func failedChecks(result app.GateResult) string {
+	failed := make([]string, 0, len(result.Checks))
+	for _, check := range result.Checks {
+		if !check.Passed {
+			failed = append(failed, fmt.Sprintf("%s %d > %d", check.Name, check.Value, check.Max))
+		}
+	}
+
+	return strings.Join(failed, ", ")
+}
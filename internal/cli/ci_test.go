@@ -0,0 +1,79 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+)
+
+// ciHandler serves item 7, first seen a minute before now with a frame in
+// pay/charge.py, and occurrence counts of 3 two hours ago and 6 this minute.
+func ciHandler(now int64) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/items":
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"items":[{"id":1,"counter":7,"title":"fresh KeyError","status":"active","level":"error","first_occurrence_timestamp":%d,"total_occurrences":4}],"total_count":1}}`, now-60)
+		case "/api/1/item/1/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":9,"body":{"trace":{"frames":[{"filename":"/srv/app/pay/charge.py","lineno":12},{"filename":"/usr/lib/python3/json.py","lineno":300}],"exception":{"class":"KeyError"}}}}]}}`)
+		case "/api/1/reports/occurrence_counts":
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":[[%d,3],[%d,6]]}`, now-7200, now-now%60)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}
+}
+
+// writeCheckout returns a checkout holding pay/charge.py.
+func writeCheckout(t *testing.T) string {
+	t.Helper()
+
+	checkout := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(checkout, "pay"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(checkout, "pay", "charge.py"), []byte("charge()\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	return checkout
+}
+
+func TestCICheckCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, ciHandler(time.Now().Unix()))
+
+	runRootCommand(t, "ci", "check", "--env", "staging", "--max-new-items", "1", "--max-occurrences", "10")
+	checkContains(t, "passing", stdout.String(), "ci check passed: staging", "ok   occurrences: 6 (max 10)")
+
+	checkout := writeCheckout(t)
+	stdout.Reset()
+	runRootCommand(t, "ci", "check", "--env", "staging", "--max-new-items", "1", "--format", "github", "--source-root", checkout)
+	if rendered := stdout.String(); !strings.HasPrefix(rendered, "::error file=pay/charge.py,line=12,title=Rollbar #7%3A 4 occurrences::fresh KeyError\nci check passed") {
+		t.Fatalf("unexpected github output:\n%s", rendered)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "ci", "check", "--env", "staging", "--max-new-items", "1", "--format", "sarif", "--source-root", checkout)
+	checkContains(t, "sarif", stdout.String(), `"ruleId": "KeyError"`, `"uri": "pay/charge.py"`)
+}
+
+func TestCICheckCommandFails(t *testing.T) {
+	stdout := setupServerAndStdout(t, ciHandler(time.Now().Unix()))
+	stderr := setupStderr(t)
+
+	originalArgs := os.Args
+	t.Cleanup(func() { os.Args = originalArgs })
+	os.Args = []string{"rollbaz", "ci", "check", "--env", "staging", "--max-new-items", "0"}
+	if code := Execute(); code != exitCheckFailed {
+		t.Fatalf("Execute() = %d, want %d", code, exitCheckFailed)
+	}
+	checkContains(t, "failing", stdout.String(), "FAIL new_items: 1 (max 0)", "#7 [error] fresh KeyError")
+	checkContains(t, "failing", stderr.String(), "ci check failed: new_items 1 > 0")
+
+	os.Args = []string{"rollbaz", "ci", "check"}
+	if code := Execute(); code != exitError || !strings.Contains(stderr.String(), "--max-new-items") {
+		t.Fatalf("Execute() without thresholds = %d, %s", code, stderr.String())
+	}
+}
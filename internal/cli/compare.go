@@ -0,0 +1,71 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const defaultCompareSince = 24 * time.Hour
+
+func newItemsCompareEnvCmd(flags *rootFlags) *cobra.Command {
+	options := app.CompareOptions{}
+	compareCmd := &cobra.Command{
+		Use:   "compare-env",
+		Short: "Compare the items and occurrence rates of two environments",
+		Long:  "Pair the items of --a and --b by normalized title and list the ones only one environment has, then the ones both have whose occurrences since --since (default 24h) differ by at least --ratio, to check that a fix rolled out everywhere. Items are active unless --status says otherwise. For example:\n\n  rollbaz items compare-env --a production --b staging --since 6h",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if options.A == "" || options.B == "" {
+				return errors.New("--a and --b are required")
+			}
+			if options.Ratio < 1 {
+				return errors.New("--ratio must be at least 1")
+			}
+
+			return runItemsCompareEnv(cmd.Context(), *flags, options)
+		},
+	}
+	compareCmd.Flags().StringVar(&options.A, "a", "", "First environment, such as production")
+	compareCmd.Flags().StringVar(&options.B, "b", "", "Second environment, such as staging")
+	compareCmd.Flags().Float64Var(&options.Ratio, "ratio", 2, "How many times more often an item must occur in one environment to count as divergent")
+
+	return compareCmd
+}
+
+func runItemsCompareEnv(parent context.Context, flags rootFlags, options app.CompareOptions) error {
+	now := time.Now()
+	options.Since = now.Add(-defaultCompareSince)
+	if flags.Since != "" {
+		since, err := parseFilterTime(flags.Since)
+		if err != nil {
+			return fmt.Errorf("parse --since: %w", err)
+		}
+		options.Since = *since
+	}
+	options.Status, options.Limit = flags.Status, flags.Limit
+
+	ctx, cancel := context.WithTimeout(parent, 2*time.Minute)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	comparison, err := runWithProgress(flags.Format, "Comparing environments", func() (app.EnvComparison, error) {
+		return app.CompareEnvironments(ctx, client, options, now)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderEnvComparisonWithWidth(comparison, terminalRenderWidth()), redact.Value(comparison, token))
+}
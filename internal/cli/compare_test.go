@@ -0,0 +1,63 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+	"time"
+)
+
+// compareEnvHandler serves a shared timeout with 30 production and 5
+// staging occurrences in the hour of bucket, and a production-only KeyError.
+func compareEnvHandler(bucket int64) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		query := r.URL.Query()
+		switch {
+		case r.URL.Path == "/api/1/items" && query.Get("environment") == "production":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":1,"environment":"production","title":"Timeout calling payments","status":"active","total_occurrences":40},{"id":2,"counter":2,"environment":"production","title":"KeyError: 'cart'","status":"active","total_occurrences":3}],"total_count":2}}`)
+		case r.URL.Path == "/api/1/items" && query.Get("environment") == "staging":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":11,"counter":11,"environment":"staging","title":"Timeout calling payments","status":"active","total_occurrences":5}],"total_count":1}}`)
+		case r.URL.Path == "/api/1/reports/occurrence_counts" && query.Get("item_id") == "1":
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":[[%d,30]]}`, bucket)
+		case r.URL.Path == "/api/1/reports/occurrence_counts" && query.Get("item_id") == "11":
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":[[%d,5]]}`, bucket)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s"}`, r.URL.Path, r.URL.RawQuery)
+		}
+	}
+}
+
+func TestItemsCompareEnvCommand(t *testing.T) {
+	now := time.Now().Unix()
+	stdout := setupServerAndStdout(t, compareEnvHandler(now-now%3600))
+
+	runRootCommand(t, "items", "compare-env", "--a", "production", "--b", "staging")
+	checkContains(t, "compare", stdout.String(), "production vs staging since", "1 errors in both", "Only in production (not in staging):", "KeyError: 'cart'", "Only in staging (not in production): none", "6x")
+
+	stdout.Reset()
+	runRootCommand(t, "items", "compare-env", "--a", "production", "--b", "staging", "--ratio", "10", "--format", "json")
+	if !strings.Contains(stdout.String(), `"divergent": []`) || !strings.Contains(stdout.String(), `"shared": 1`) {
+		t.Fatalf("unexpected JSON output:\n%s", stdout.String())
+	}
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "missing environment", args: []string{"items", "compare-env", "--a", "production"}, want: "--a and --b are required"},
+		{name: "ratio below one", args: []string{"items", "compare-env", "--a", "production", "--b", "staging", "--ratio", "0.5"}, want: "--ratio must be at least 1"},
+		{name: "bad since", args: []string{"items", "compare-env", "--a", "production", "--b", "staging", "--since", "soon"}, want: "parse --since"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			cmd := NewRootCmd()
+			cmd.SetArgs(tt.args)
+			if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tt.want) {
+				t.Fatalf("expected %q error, got %v", tt.want, err)
+			}
+		})
+	}
+}
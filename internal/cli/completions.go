@@ -0,0 +1,165 @@
+package cli
+
+import (
+	"fmt"
+	"sort"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/output"
+)
+
+var completionShells = []string{"bash", "zsh", "fish", "powershell"}
+
+func newCompletionsCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:       "completions <bash|zsh|fish|powershell>",
+		Short:     "Print a shell completion script",
+		Long:      "Print a shell completion script. For example:\n\n  rollbaz completions bash > /etc/bash_completion.d/rollbaz\n  rollbaz completions zsh > \"${fpath[1]}/_rollbaz\"\n  rollbaz completions fish > ~/.config/fish/completions/rollbaz.fish",
+		Args:      cobra.MatchAll(cobra.ExactArgs(1), cobra.OnlyValidArgs),
+		ValidArgs: completionShells,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return writeCompletion(cmd.Root(), args[0])
+		},
+	}
+}
+
+func writeCompletion(root *cobra.Command, shell string) error {
+	var err error
+	switch shell {
+	case "bash":
+		err = root.GenBashCompletionV2(stdoutWriter, true)
+	case "zsh":
+		err = root.GenZshCompletion(stdoutWriter)
+	case "fish":
+		err = root.GenFishCompletion(stdoutWriter, true)
+	case "powershell":
+		err = root.GenPowerShellCompletionWithDesc(stdoutWriter)
+	default:
+		return fmt.Errorf("unsupported shell %q", shell)
+	}
+	if err != nil {
+		return fmt.Errorf("generate %s completion: %w", shell, err)
+	}
+
+	return nil
+}
+
+// registerFlagCompletions completes flag values from local state only, so
+// pressing tab never waits on the network.
+func registerFlagCompletions(cmd *cobra.Command) {
+	_ = cmd.RegisterFlagCompletionFunc("project", completeProjects)
+	_ = cmd.RegisterFlagCompletionFunc("profile", completeProfiles)
+	_ = cmd.RegisterFlagCompletionFunc("format", cobra.FixedCompletions(formatNames(), cobra.ShellCompDirectiveNoFileComp))
+	_ = cmd.RegisterFlagCompletionFunc("status", cobra.FixedCompletions([]string{"active", "resolved", "muted", "archived"}, cobra.ShellCompDirectiveNoFileComp))
+	_ = cmd.RegisterFlagCompletionFunc("template", completeTemplates)
+}
+
+// completeTemplates offers the templates in the config dir's templates/, and
+// files, since --template also takes a path.
+func completeTemplates(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	path, err := profilePath()
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveDefault
+	}
+
+	return config.TemplateNames(config.TemplateDir(path)), cobra.ShellCompDirectiveDefault
+}
+
+// completeProjects offers configured project names plus project slugs cached
+// by earlier --project lookups.
+func completeProjects(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	names := configuredProjectNames()
+	if cache, err := config.NewProjectCache(); err == nil {
+		for _, slug := range cache.Slugs() {
+			names[slug] = true
+		}
+	}
+
+	return sortedNames(names), cobra.ShellCompDirectiveNoFileComp
+}
+
+// completeConfiguredProject completes the single project-name argument of the
+// project subcommands.
+func completeConfiguredProject(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	if len(args) > 0 {
+		return nil, cobra.ShellCompDirectiveNoFileComp
+	}
+
+	return sortedNames(configuredProjectNames()), cobra.ShellCompDirectiveNoFileComp
+}
+
+func configuredProjectNames() map[string]bool {
+	names := map[string]bool{}
+	store, err := newConfigStore()
+	if err != nil {
+		return names
+	}
+	file, err := store.Load()
+	if err != nil {
+		return names
+	}
+	for _, project := range file.Projects {
+		names[project.Name] = true
+	}
+
+	return names
+}
+
+func completeSavedQueries(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	queries, err := loadSavedQueries()
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveNoFileComp
+	}
+
+	names := make([]string, 0, len(queries))
+	for _, query := range queries {
+		names = append(names, query.Name)
+	}
+
+	return names, cobra.ShellCompDirectiveNoFileComp
+}
+
+// completeSavedQueryArg completes the query name argument of `queries rm`.
+func completeSavedQueryArg(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	if len(args) > 0 {
+		return nil, cobra.ShellCompDirectiveNoFileComp
+	}
+
+	return completeSavedQueries(cmd, args, toComplete)
+}
+
+func completeProfiles(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	file, err := loadProfileFile()
+	if err != nil {
+		return nil, cobra.ShellCompDirectiveNoFileComp
+	}
+
+	names := map[string]bool{}
+	for name := range file.Profiles {
+		names[name] = true
+	}
+
+	return sortedNames(names), cobra.ShellCompDirectiveNoFileComp
+}
+
+func formatNames() []string {
+	formats := output.Formats()
+	names := make([]string, 0, len(formats))
+	for _, format := range formats {
+		names = append(names, string(format))
+	}
+
+	return names
+}
+
+func sortedNames(names map[string]bool) []string {
+	sorted := make([]string, 0, len(names))
+	for name := range names {
+		sorted = append(sorted, name)
+	}
+	sort.Strings(sorted)
+
+	return sorted
+}
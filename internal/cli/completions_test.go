@@ -0,0 +1,98 @@
+package cli
+
+import (
+	"bytes"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/config"
+)
+
+func TestCompletionsScripts(t *testing.T) {
+	stdout := &bytes.Buffer{}
+	stdoutWriter = stdout
+	t.Cleanup(func() {
+		stdoutWriter = os.Stdout
+	})
+
+	tests := []struct {
+		shell string
+		want  string
+	}{
+		{shell: "bash", want: "__start_rollbaz"},
+		{shell: "zsh", want: "#compdef rollbaz"},
+		{shell: "fish", want: "complete -c rollbaz"},
+		{shell: "powershell", want: "Register-ArgumentCompleter"},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		runRootCommand(t, "completions", tc.shell)
+		if !strings.Contains(stdout.String(), tc.want) {
+			t.Fatalf("%s: expected %q in script, got %.200s", tc.shell, tc.want, stdout.String())
+		}
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"completions", "tcsh"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "invalid argument") {
+		t.Fatalf("expected invalid shell error, got %v", err)
+	}
+}
+
+// setupCompletionSources configures project payments, caches the billing-api
+// slug, and writes the work and staging profiles.
+func setupCompletionSources(t *testing.T) {
+	t.Helper()
+
+	dir := t.TempDir()
+	t.Setenv("XDG_CACHE_HOME", filepath.Join(dir, "cache"))
+	store := config.NewStoreAtPath(filepath.Join(dir, "config.json"))
+	if err := store.AddProject("payments", "secret-token"); err != nil {
+		t.Fatalf("AddProject() error = %v", err)
+	}
+	restoreStore := overrideConfigStore(func() (*config.Store, error) {
+		return store, nil
+	})
+	t.Cleanup(restoreStore)
+	cache, err := config.NewProjectCache()
+	if err != nil {
+		t.Fatalf("NewProjectCache() error = %v", err)
+	}
+	if err := cache.Store("billing-api", 42); err != nil {
+		t.Fatalf("Store() error = %v", err)
+	}
+	writeProfiles(t, "[profiles.work]\ntoken = \"a\"\n\n[profiles.staging]\ntoken = \"b\"\n")
+}
+
+func TestDynamicFlagCompletion(t *testing.T) {
+	setupCompletionSources(t)
+
+	tests := []struct {
+		args []string
+		want []string
+	}{
+		{args: []string{"__complete", "--project", ""}, want: []string{"billing-api", "payments"}},
+		{args: []string{"__complete", "--profile", ""}, want: []string{"staging", "work"}},
+		{args: []string{"__complete", "--format", ""}, want: []string{"human", "ndjson"}},
+		{args: []string{"__complete", "project", "use", ""}, want: []string{"payments"}},
+	}
+	for _, tc := range tests {
+		out := &bytes.Buffer{}
+		cmd := NewRootCmd()
+		cmd.SetOut(out)
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err != nil {
+			t.Fatalf("%v: error = %v", tc.args, err)
+		}
+		for _, want := range tc.want {
+			if !strings.Contains(out.String(), want+"\n") {
+				t.Fatalf("%v: expected %q in completions, got %q", tc.args, want, out.String())
+			}
+		}
+		if strings.Contains(out.String(), "secret-token") {
+			t.Fatalf("%v: completions leaked a token: %q", tc.args, out.String())
+		}
+	}
+}
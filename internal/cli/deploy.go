@@ -0,0 +1,147 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func newDeployCmd(flags *rootFlags) *cobra.Command {
+	deployCmd := &cobra.Command{Use: "deploy", Short: "Report and list deploys"}
+	deployCmd.AddCommand(
+		newDeployReportCmd(flags),
+		newDeployUpdateCmd(flags),
+		newDeployListCmd(flags),
+	)
+
+	return deployCmd
+}
+
+func newDeployReportCmd(flags *rootFlags) *cobra.Command {
+	report := rollbar.DeployReport{}
+	reportCmd := &cobra.Command{
+		Use:   "report",
+		Short: "Register a deploy of --revision to --env",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			report.Environment = flags.Environment
+			return runDeployReport(cmd.Context(), *flags, report)
+		},
+	}
+	reportCmd.Flags().StringVar(&report.Revision, "revision", "", "Deployed revision, usually a commit SHA")
+	reportCmd.Flags().StringVar(&report.LocalUsername, "user", "", "Name of whoever ran the deploy")
+	reportCmd.Flags().StringVar(&report.RollbarUsername, "rollbar-user", "", "Rollbar username of whoever ran the deploy")
+	reportCmd.Flags().StringVar(&report.Comment, "comment", "", "Deploy comment")
+	reportCmd.Flags().StringVar(&report.Status, "status", rollbar.DeployStatusSucceeded, "Deploy status: started, succeeded, failed, or timed_out")
+	_ = reportCmd.RegisterFlagCompletionFunc("status", completeDeployStatus)
+
+	return reportCmd
+}
+
+func newDeployUpdateCmd(flags *rootFlags) *cobra.Command {
+	status := ""
+	updateCmd := &cobra.Command{
+		Use:   "update <deploy-id>",
+		Short: "Set the final status of a deploy reported as started",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			deployID, err := strconv.ParseUint(strings.TrimSpace(args[0]), 10, 64)
+			if err != nil || deployID == 0 {
+				return fmt.Errorf("invalid deploy id %q", args[0])
+			}
+			return runDeployUpdate(cmd.Context(), *flags, deployID, status)
+		},
+	}
+	updateCmd.Flags().StringVar(&status, "status", "", "Deploy status: succeeded, failed, or timed_out")
+	_ = updateCmd.MarkFlagRequired("status")
+	_ = updateCmd.RegisterFlagCompletionFunc("status", completeDeployStatus)
+
+	return updateCmd
+}
+
+func newDeployListCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "list",
+		Short: "List recent deploys, newest first",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runDeployList(cmd.Context(), *flags)
+		},
+	}
+}
+
+func completeDeployStatus(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	return []string{rollbar.DeployStatusStarted, rollbar.DeployStatusSucceeded, rollbar.DeployStatusFailed, rollbar.DeployStatusTimedOut}, cobra.ShellCompDirectiveNoFileComp
+}
+
+func runDeployReport(parent context.Context, flags rootFlags, report rollbar.DeployReport) error {
+	if strings.TrimSpace(report.Environment) == "" {
+		return errors.New("--env is required")
+	}
+	if strings.TrimSpace(report.Revision) == "" {
+		return errors.New("--revision is required")
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	deploy, err := app.ReportDeploy(ctx, client, report)
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderDeployReported(deploy), redact.Value(map[string]any{"deploy": deploy}, token))
+}
+
+func runDeployUpdate(parent context.Context, flags rootFlags, deployID uint64, status string) error {
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	if err := app.UpdateDeployStatus(ctx, client, deployID, status); err != nil {
+		return sanitizeError(err, token)
+	}
+
+	normalized := strings.ToLower(strings.TrimSpace(status))
+	payload := map[string]any{"deploy_id": deployID, "status": normalized}
+	return printOutput(flags, fmt.Sprintf("updated deploy %d: %s", deployID, normalized), payload)
+}
+
+func runDeployList(parent context.Context, flags rootFlags) error {
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	deploys, err := runWithProgress(flags.Format, "Loading deploys", func() ([]rollbar.Deploy, error) {
+		return app.ListDeploys(ctx, client, flags.Environment, flags.Limit)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	jsonPayload := redact.Value(map[string]any{"deploys": deploys}, token)
+	return printOutput(flags, output.RenderDeployTableWithWidth(deploys, terminalRenderWidth()), jsonPayload)
+}
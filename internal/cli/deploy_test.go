@@ -0,0 +1,85 @@
+package cli
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+func newDeployHandler() http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.Method == http.MethodPost && r.URL.Path == "/api/1/deploy":
+			body, _ := io.ReadAll(r.Body)
+			if !isStartedDeploy(body) {
+				w.WriteHeader(http.StatusBadRequest)
+				_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected report %s"}`, body)
+				return
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"deploy_id":42}}`)
+		case r.Method == http.MethodPatch && r.URL.Path == "/api/1/deploy/42":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+		case r.URL.Path == "/api/1/deploys" && r.URL.Query().Get("page") == "1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"deploys":[{"id":42,"environment":"production","revision":"0123456789abcdef","status":"succeeded"},{"id":41,"environment":"staging","revision":"fedcba","status":"failed"}],"page":1}}`)
+		case r.URL.Path == "/api/1/deploys":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"deploys":[],"page":2}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s"}`, r.Method, r.URL.Path)
+		}
+	})
+}
+
+// isStartedDeploy reports whether body starts the production deploy of
+// 0123456789abcdef by ci.
+func isStartedDeploy(body []byte) bool {
+	var report map[string]string
+	if err := json.Unmarshal(body, &report); err != nil {
+		return false
+	}
+
+	return report["environment"] == "production" && report["revision"] == "0123456789abcdef" && report["local_username"] == "ci" && report["status"] == "started"
+}
+
+func TestDeployCommands(t *testing.T) {
+	stdout := setupServerAndStdout(t, newDeployHandler())
+
+	tests := []struct {
+		name    string
+		args    []string
+		want    string
+		wantNot string
+	}{
+		{name: "report", args: []string{"deploy", "report", "--env", "production", "--revision", "0123456789abcdef", "--user", "ci", "--status", "started"}, want: "reported deploy 42: production @ 0123456789ab (started)"},
+		{name: "update", args: []string{"deploy", "update", "42", "--status", "succeeded"}, want: "updated deploy 42: succeeded"},
+		{name: "list filtered", args: []string{"deploy", "list", "--env", "production"}, want: "0123456789ab", wantNot: "fedcba"},
+		{name: "list json", args: []string{"deploy", "list", "--format", "json"}, want: `"id": 41`},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		runRootCommand(t, tc.args...)
+		if !strings.Contains(stdout.String(), tc.want) || (tc.wantNot != "" && strings.Contains(stdout.String(), tc.wantNot)) {
+			t.Fatalf("%s: unexpected output %q", tc.name, stdout.String())
+		}
+	}
+
+	errorTests := []struct {
+		args    []string
+		wantErr string
+	}{
+		{args: []string{"deploy", "report", "--revision", "abc"}, wantErr: "--env is required"},
+		{args: []string{"deploy", "report", "--env", "production"}, wantErr: "--revision is required"},
+		{args: []string{"deploy", "report", "--env", "production", "--revision", "abc", "--status", "done"}, wantErr: "unsupported deploy status"},
+		{args: []string{"deploy", "update", "x", "--status", "failed"}, wantErr: "invalid deploy id"},
+	}
+	for _, tc := range errorTests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.wantErr, err)
+		}
+	}
+}
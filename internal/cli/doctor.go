@@ -0,0 +1,128 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+func newDoctorCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "doctor",
+		Short: "Check config files, token, scopes, and API access, and suggest fixes",
+		Args:  cobra.NoArgs,
+		// A broken config.toml is one of the things doctor reports, so a profile
+		// error must not stop it before it starts.
+		PersistentPreRunE: func(cmd *cobra.Command, args []string) error {
+			_ = applyProfile(cmd, flags)
+			if err := applyOutputFilter(cmd, flags); err != nil {
+				return err
+			}
+			return applyOutputTemplate(flags)
+		},
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runDoctor(cmd.Context(), *flags)
+		},
+	}
+}
+
+func runDoctor(parent context.Context, flags rootFlags) error {
+	checks := []app.DoctorCheck{configFileCheck(), profileFileCheck(flags)}
+
+	token, err := resolveAccessToken(flags)
+	if err != nil {
+		checks = append(checks, app.DoctorCheck{Name: "token", Status: app.CheckFail, Detail: err.Error(), Fix: "run `rollbaz auth login`, pass --token, or set ROLLBAR_ACCESS_TOKEN"})
+	} else {
+		checks = append(checks, apiChecks(parent, flags, token)...)
+	}
+
+	human := redact.String(output.RenderDoctor(checks), token)
+	if err := printOutput(flags, human, redact.Value(map[string]any{"checks": checks}, token)); err != nil {
+		return err
+	}
+
+	failed := 0
+	for _, check := range checks {
+		if check.Status == app.CheckFail {
+			failed++
+		}
+	}
+	if failed > 0 {
+		return fmt.Errorf("%d of %d checks failed", failed, len(checks))
+	}
+
+	return nil
+}
+
+func configFileCheck() app.DoctorCheck {
+	store, err := newConfigStore()
+	if err != nil {
+		return app.DoctorCheck{Name: "config", Status: app.CheckWarn, Detail: err.Error(), Fix: "set HOME or XDG_CONFIG_HOME so rollbaz can find its config directory"}
+	}
+	file, err := store.Load()
+	if err != nil {
+		return app.DoctorCheck{Name: "config", Status: app.CheckFail, Detail: err.Error(), Fix: fmt.Sprintf("fix the JSON in %s, or remove it and re-add projects", store.Path())}
+	}
+	if len(file.Projects) == 0 {
+		return app.DoctorCheck{Name: "config", Status: app.CheckOK, Detail: store.Path() + ": no saved projects"}
+	}
+	if _, err := store.ResolveProject(""); err != nil {
+		return app.DoctorCheck{Name: "config", Status: app.CheckWarn, Detail: err.Error(), Fix: "run `rollbaz project use <name>` to pick a valid active project"}
+	}
+
+	return app.DoctorCheck{Name: "config", Status: app.CheckOK, Detail: fmt.Sprintf("%s: %d projects, active %q", store.Path(), len(file.Projects), file.ActiveProject)}
+}
+
+func profileFileCheck(flags rootFlags) app.DoctorCheck {
+	path, err := profilePath()
+	if err != nil {
+		return app.DoctorCheck{Name: "profiles", Status: app.CheckSkip, Detail: err.Error()}
+	}
+	file, err := config.LoadProfiles(path)
+	if err != nil {
+		return app.DoctorCheck{Name: "profiles", Status: app.CheckFail, Detail: err.Error(), Fix: "fix the syntax in " + path}
+	}
+	if len(file.Profiles) == 0 {
+		return app.DoctorCheck{Name: "profiles", Status: app.CheckSkip, Detail: path + ": no profiles"}
+	}
+
+	name := flags.Profile
+	if name == "" {
+		name = os.Getenv("ROLLBAZ_PROFILE")
+	}
+	if _, _, err := file.Select(name); err != nil {
+		return app.DoctorCheck{Name: "profiles", Status: app.CheckFail, Detail: err.Error(), Fix: "pick one of the profiles in " + path + " or fix default_profile"}
+	}
+
+	return app.DoctorCheck{Name: "profiles", Status: app.CheckOK, Detail: fmt.Sprintf("%s: %d profiles", path, len(file.Profiles))}
+}
+
+func apiChecks(parent context.Context, flags rootFlags, token string) []app.DoctorCheck {
+	ctx, cancel := context.WithTimeout(parent, 20*time.Second)
+	defer cancel()
+
+	client, err := newClientWithToken(flags, token)
+	if err != nil {
+		return []app.DoctorCheck{{Name: "api", Status: app.CheckFail, Detail: err.Error(), Fix: "check the profile's base_url"}}
+	}
+
+	checks, projectID := app.CheckAPI(ctx, client, client.BaseURL())
+	scope := app.DoctorCheck{Name: "scope", Status: app.CheckSkip, Detail: "write scope not checked", Fix: "set ROLLBAR_ACCOUNT_TOKEN to check whether the token can resolve and mute"}
+	if accountToken := resolveAccountToken(flags); accountToken != "" && checks[len(checks)-1].Status == app.CheckOK {
+		admin, err := newClientWithToken(flags, accountToken)
+		if err == nil {
+			scope = app.CheckWriteScope(ctx, admin, projectID, token)
+		}
+		scope.Detail = redact.String(scope.Detail, accountToken)
+	}
+
+	return append(checks, scope, app.CheckRateLimit(client, time.Now()))
+}
@@ -0,0 +1,69 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+func TestDoctorCommand(t *testing.T) {
+	setNoConfigStore(t)
+	t.Setenv("ROLLBAZ_PROFILE", "")
+
+	tests := []struct {
+		name    string
+		status  int
+		want    []string
+		wantErr string
+	}{
+		{name: "healthy", status: http.StatusOK, want: []string{"[ok]   api", "(ping ", "[ok]   token", "[ok]   rate limit", "4900 of 5000 requests left", "[skip] scope"}},
+		{name: "rejected token", status: http.StatusUnauthorized, want: []string{"[fail] token", "fix: copy a project access token"}, wantErr: "1 of 7 checks failed"},
+	}
+	for _, tc := range tests {
+		stdout := setupServerAndStdout(t, doctorHandler(tc.status))
+		t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "")
+
+		cmd := NewRootCmd()
+		cmd.SetArgs([]string{"doctor"})
+		checkError(t, tc.name, cmd.Execute(), tc.wantErr)
+		checkContains(t, tc.name, stdout.String(), tc.want...)
+	}
+}
+
+// doctorHandler answers pings, and item listings with status and a rate
+// limit of 4900 of 5000 requests left.
+func doctorHandler(status int) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path == "/api/1/status/ping" {
+			_, _ = fmt.Fprint(w, "pong")
+			return
+		}
+		if r.URL.Path != "/api/1/items" {
+			w.WriteHeader(http.StatusNotFound)
+			return
+		}
+		w.Header().Set("X-Rate-Limit-Limit", "5000")
+		w.Header().Set("X-Rate-Limit-Remaining", "4900")
+		w.WriteHeader(status)
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[],"total_count":0}}`)
+	})
+}
+
+func TestDoctorReportsBrokenProfiles(t *testing.T) {
+	setNoConfigStore(t)
+	stdout := setupServerAndStdout(t, http.NotFoundHandler())
+	t.Setenv("ROLLBAR_ACCESS_TOKEN", "")
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "")
+	t.Setenv("ROLLBAZ_PROFILE", "")
+	writeProfiles(t, "[profiles.work\ntoken = \"x\"\n")
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"doctor"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "2 of 3 checks failed") {
+		t.Fatalf("expected profile and token failures, got %v", err)
+	}
+	if !strings.Contains(stdout.String(), "[fail] profiles") || !strings.Contains(stdout.String(), "[fail] token") {
+		t.Fatalf("unexpected output:\n%s", stdout.String())
+	}
+}
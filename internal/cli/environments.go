@@ -0,0 +1,68 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+func newEnvironmentsCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "environments",
+		Short: "List environments reported by the project",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runEnvironments(cmd.Context(), *flags)
+		},
+	}
+}
+
+func runEnvironments(parent context.Context, flags rootFlags) error {
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	names, err := runWithProgress(flags.Format, "Loading environments", func() ([]string, error) {
+		return service.Environments(ctx)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	human := "no environments found"
+	if len(names) > 0 {
+		human = strings.Join(names, "\n")
+	}
+	jsonPayload := redact.Value(map[string]any{"environments": names}, token)
+
+	return printOutput(flags, human, jsonPayload)
+}
+
+// checkEnvironmentFilter turns an empty listing caused by an unknown --env into
+// an error with a suggestion. Lookup failures are ignored so the listing still
+// succeeds with tokens that cannot read environments.
+func checkEnvironmentFilter(ctx context.Context, service *app.Service, filters app.IssueFilters, issues []app.IssueSummary) error {
+	environment := strings.TrimSpace(filters.Environment)
+	if len(issues) > 0 || environment == "" {
+		return nil
+	}
+
+	check, err := service.CheckEnvironment(ctx, environment)
+	if err != nil || check.Known {
+		return nil
+	}
+	if check.Suggestion != "" {
+		return fmt.Errorf("unknown environment %q; did you mean %q?", environment, check.Suggestion)
+	}
+
+	return fmt.Errorf("unknown environment %q", environment)
+}
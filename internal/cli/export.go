@@ -0,0 +1,256 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const defaultExportTimeout = 10 * time.Minute
+
+type exportFlags struct {
+	Out     string
+	As      string
+	Columns string
+	Timeout time.Duration
+}
+
+// exportJob is one export: its columns, the noun for the summary line, and a
+// stream that hands each fetched page to write.
+type exportJob struct {
+	noun    string
+	columns []string
+	stream  func(ctx context.Context, service *app.Service, write func(records []any) error) error
+}
+
+func newExportCmd(flags *rootFlags) *cobra.Command {
+	exportCmd := &cobra.Command{Use: "export", Short: "Stream items or occurrences to CSV, NDJSON, or OpenTelemetry"}
+	exportCmd.AddCommand(
+		newExportItemsCmd(flags),
+		newExportOccurrencesCmd(flags),
+		newExportOTelCmd(flags),
+	)
+	addRedactFlag(exportCmd, flags)
+
+	return exportCmd
+}
+
+func newExportItemsCmd(flags *rootFlags) *cobra.Command {
+	export := exportFlags{}
+	listFlags := itemsListFlags{}
+	itemsCmd := &cobra.Command{
+		Use:   "items",
+		Short: "Export every item matching the list filters",
+		Long:  "Export every item matching the list filters, paging through Rollbar and writing each page as it arrives. For example:\n\n  rollbaz export items --since 30d --out items.csv",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			job, err := itemsExportJob(cmd, *flags, listFlags, export.Columns)
+			if err != nil {
+				return err
+			}
+			return runExport(cmd.Context(), *flags, export, job)
+		},
+	}
+	itemsCmd.Flags().StringSliceVar(&listFlags.Levels, "level", nil, "Filter by level: debug, info, warning, error, critical (repeatable)")
+	itemsCmd.Flags().StringVar(&listFlags.Query, "query", "", "Rollbar free-text item search")
+	addExportFlags(itemsCmd, &export, output.ItemColumns)
+
+	return itemsCmd
+}
+
+func newExportOccurrencesCmd(flags *rootFlags) *cobra.Command {
+	export := exportFlags{}
+	occurrencesCmd := &cobra.Command{
+		Use:   "occurrences <item-counter|url>",
+		Short: "Export an item's occurrences, newest first",
+		Long:  "Export an item's occurrences, newest first, paging through Rollbar and writing each page as it arrives. For example:\n\n  rollbaz export occurrences 274 --since 7d --out occ.ndjson",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+			job, err := occurrencesExportJob(cmd, *flags, counter, export.Columns)
+			if err != nil {
+				return err
+			}
+			return runExport(cmd.Context(), *flags, export, job)
+		},
+	}
+	addExportFlags(occurrencesCmd, &export, output.OccurrenceColumns)
+
+	return occurrencesCmd
+}
+
+func addExportFlags(cmd *cobra.Command, export *exportFlags, columns []string) {
+	cmd.Flags().StringVar(&export.Out, "out", "", "File to write (default stdout)")
+	cmd.Flags().StringVar(&export.As, "as", "", "Export format: csv or ndjson (default from the --out extension, ndjson on stdout)")
+	cmd.Flags().StringVar(&export.Columns, "columns", "", "Comma-separated columns to export: "+strings.Join(columns, ", "))
+	cmd.Flags().DurationVar(&export.Timeout, "timeout", defaultExportTimeout, "Maximum time for the whole export")
+}
+
+// exportLimit is --limit when given; otherwise an export has no limit rather
+// than the listing default.
+func exportLimit(cmd *cobra.Command, flags rootFlags) int {
+	if cmd.Flags().Changed("limit") {
+		return flags.Limit
+	}
+
+	return 0
+}
+
+func itemsExportJob(cmd *cobra.Command, flags rootFlags, listFlags itemsListFlags, columnsFlag string) (exportJob, error) {
+	flags.Limit = exportLimit(cmd, flags)
+	options, err := parseListOptions(flags, listFlags)
+	if err != nil {
+		return exportJob{}, err
+	}
+	columns, err := output.ParseColumns(columnsFlag, output.ItemColumns, output.DefaultItemColumns)
+	if err != nil {
+		return exportJob{}, fmt.Errorf("parse --columns: %w", err)
+	}
+
+	return exportJob{
+		noun:    "items",
+		columns: columns,
+		stream: func(ctx context.Context, service *app.Service, write func([]any) error) error {
+			return service.StreamItems(ctx, options, func(issues []app.IssueSummary) error {
+				return write(exportRecords(issues))
+			})
+		},
+	}, nil
+}
+
+func occurrencesExportJob(cmd *cobra.Command, flags rootFlags, counter domain.ItemCounter, columnsFlag string) (exportJob, error) {
+	since, err := parseFilterTime(flags.Since)
+	if err != nil {
+		return exportJob{}, fmt.Errorf("parse --since: %w", err)
+	}
+	columns, err := output.ParseColumns(columnsFlag, output.OccurrenceColumns, output.DefaultOccurrenceColumns)
+	if err != nil {
+		return exportJob{}, fmt.Errorf("parse --columns: %w", err)
+	}
+
+	options := app.OccurrenceOptions{Limit: exportLimit(cmd, flags), Since: since, Full: slices.Contains(columns, "raw")}
+	scrubber := redact.DefaultScrubber()
+
+	return exportJob{
+		noun:    "occurrences",
+		columns: columns,
+		stream: func(ctx context.Context, service *app.Service, write func([]any) error) error {
+			return service.StreamOccurrences(ctx, counter, options, func(occurrences []app.OccurrenceSummary) error {
+				for index := range occurrences {
+					occurrences[index].Raw = scrubber.ScrubJSON(occurrences[index].Raw)
+				}
+				return write(exportRecords(occurrences))
+			})
+		},
+	}, nil
+}
+
+func runExport(parent context.Context, flags rootFlags, export exportFlags, job exportJob) error {
+	if export.Timeout <= 0 {
+		return errors.New("--timeout must be positive")
+	}
+	format, err := output.ExportFormatFor(export.As, export.Out)
+	if err != nil {
+		return err
+	}
+
+	redactor, err := piiRedactor(flags)
+	if err != nil {
+		return err
+	}
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	writer, closeOut, err := openExportWriter(export.Out, format, job.columns)
+	if err != nil {
+		return err
+	}
+	defer closeOut()
+
+	ctx, cancel := context.WithTimeout(parent, export.Timeout)
+	defer cancel()
+
+	_, err = runWithProgress(flags.Format, "Exporting "+job.noun, func() (struct{}, error) {
+		return struct{}{}, job.stream(ctx, service, redactedWrites(writer, token, redactor))
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	if export.Out != "" {
+		_, _ = fmt.Fprintf(stdoutWriter, "Exported %d %s to %s\n", writer.Count(), job.noun, export.Out)
+	}
+
+	return nil
+}
+
+// redactedWrites writes each batch of records with the token redacted, and
+// personal data too when redactor is set.
+func redactedWrites(writer *output.RecordWriter, token string, redactor *redact.Redactor) func([]any) error {
+	return func(records []any) error {
+		for index := range records {
+			records[index] = redact.Value(records[index], token)
+			if redactor != nil {
+				records[index] = redactor.Scrub(records[index])
+			}
+		}
+		return writer.Write(records)
+	}
+}
+
+// exportRecords boxes each record so runExport can redact them one by one.
+func exportRecords[T any](records []T) []any {
+	boxed := make([]any, 0, len(records))
+	for _, record := range records {
+		boxed = append(boxed, record)
+	}
+
+	return boxed
+}
+
+// openExportWriter opens --out and a record writer of format on it.
+func openExportWriter(path string, format output.ExportFormat, columns []string) (*output.RecordWriter, func(), error) {
+	out, closeOut, err := openExportOutput(path)
+	if err != nil {
+		return nil, nil, err
+	}
+	writer, err := output.NewRecordWriter(out, format, columns)
+	if err != nil {
+		closeOut()
+		return nil, nil, err
+	}
+
+	return writer, closeOut, nil
+}
+
+// openExportOutput opens --out for writing, owner-only since exports can hold
+// request data, or returns stdout when --out is empty.
+func openExportOutput(path string) (io.Writer, func(), error) {
+	if path == "" {
+		return stdoutWriter, func() {}, nil
+	}
+
+	file, err := os.OpenFile(filepath.Clean(path), os.O_CREATE|os.O_WRONLY|os.O_TRUNC, 0o600)
+	if err != nil {
+		return nil, nil, fmt.Errorf("open --out: %w", err)
+	}
+
+	return file, func() { _ = file.Close() }, nil
+}
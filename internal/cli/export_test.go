@@ -0,0 +1,126 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func newExportHandler() http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		page := r.URL.Query().Get("page")
+		switch {
+		case r.URL.Path == "/api/1/items" && page == "1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"boom, again","status":"active","level":"error","environment":"production","total_occurrences":4}]}}`)
+		case r.URL.Path == "/api/1/items" && page == "2":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":2,"counter":4,"title":"slow","status":"active","level":"warning","environment":"production","total_occurrences":1}]}}`)
+		case r.URL.Path == "/api/1/items":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[]}}`)
+		case r.URL.Path == "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+		case r.URL.Path == "/api/1/item/1755568172/instances" && page == "1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":2,"timestamp":1700000100,"data":{"uuid":"u-2","request":{"headers":{"Cookie":"sid=abc123"}}}},{"id":1,"timestamp":1700000000,"data":{"uuid":"u-1"}}]}}`)
+		case r.URL.Path == "/api/1/item/1755568172/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[]}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+		}
+	})
+}
+
+func TestExportCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, newExportHandler())
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "items ndjson", args: []string{"export", "items", "--columns", "counter,title"}, want: "{\"counter\":3,\"title\":\"boom, again\"}\n{\"counter\":4,\"title\":\"slow\"}\n"},
+		{name: "items csv limit", args: []string{"export", "items", "--as", "csv", "--columns", "counter,title", "--limit", "1"}, want: "counter,title\n3,\"boom, again\"\n"},
+		{name: "occurrences", args: []string{"export", "occurrences", "269", "--columns", "uuid,timestamp"}, want: "{\"uuid\":\"u-2\",\"timestamp\":1700000100}\n{\"uuid\":\"u-1\",\"timestamp\":1700000000}\n"},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		runRootCommand(t, tc.args...)
+		if stdout.String() != tc.want {
+			t.Fatalf("%s: got %q, want %q", tc.name, stdout.String(), tc.want)
+		}
+	}
+}
+
+func TestExportCommandWritesFile(t *testing.T) {
+	stdout := setupServerAndStdout(t, newExportHandler())
+	path := filepath.Join(t.TempDir(), "occ.csv")
+
+	runRootCommand(t, "export", "occurrences", "269", "--columns", "uuid,raw", "--out", path)
+
+	body, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("read export: %v", err)
+	}
+	if !strings.HasPrefix(string(body), "uuid,raw\nu-2,") || strings.Contains(string(body), "sid=abc123") {
+		t.Fatalf("unexpected export: %q", body)
+	}
+	if info, err := os.Stat(path); err != nil || info.Mode().Perm() != 0o600 {
+		t.Fatalf("expected 0600 export file, got %v, %v", info, err)
+	}
+	if !strings.Contains(stdout.String(), "Exported 2 occurrences to "+path) {
+		t.Fatalf("unexpected summary: %q", stdout.String())
+	}
+}
+
+func TestExportCommandRedact(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.URL.Path == "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1}}`)
+		case r.URL.Path == "/api/1/item/1/instances" && r.URL.Query().Get("page") == "1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":2,"timestamp":1700000100,"data":{"uuid":"u-2","person":{"email":"jane@example.com"},"request":{"user_ip":"203.0.113.7"},"custom":{"order":"ACME-123456"}}}]}}`)
+		default:
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[]}}`)
+		}
+	}))
+	writeProfiles(t, "[redact]\npatterns = ['ACME-\\d{6}']\n")
+
+	runRootCommand(t, "export", "occurrences", "269", "--columns", "uuid,raw")
+	if !strings.Contains(stdout.String(), "jane@example.com") {
+		t.Fatalf("expected personal data without --redact: %s", stdout.String())
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "export", "occurrences", "269", "--columns", "uuid,raw", "--redact")
+	out := stdout.String()
+	for _, want := range []string{`"uuid":"u-2"`, `"email":"[email]"`, `"user_ip":"[ip]"`, `"order":"*****"`} {
+		if !strings.Contains(out, want) {
+			t.Fatalf("expected %s in redacted export: %s", want, out)
+		}
+	}
+
+	writeProfiles(t, "[redact]\npatterns = ['(']\n")
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"export", "occurrences", "269", "--redact"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "config.toml [redact]") {
+		t.Fatalf("expected a [redact] pattern error, got %v", err)
+	}
+}
+
+func TestExportCommandErrors(t *testing.T) {
+	setupServerAndStdout(t, newExportHandler())
+
+	for _, args := range [][]string{
+		{"export", "items", "--columns", "counter,size"},
+		{"export", "items", "--out", "items.xlsx"},
+		{"export", "occurrences", "269", "--timeout", "0s"},
+	} {
+		cmd := NewRootCmd()
+		cmd.SetArgs(args)
+		if err := cmd.Execute(); err == nil {
+			t.Fatalf("%v: expected error", args)
+		}
+	}
+}
@@ -0,0 +1,141 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"net"
+	"net/http"
+	"os"
+	"os/signal"
+	"sync"
+	"syscall"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+)
+
+const (
+	defaultExporterInterval = time.Minute
+	minExporterInterval     = 10 * time.Second
+	exporterScrapeTimeout   = time.Minute
+)
+
+type exporterFlags struct {
+	Listen   string
+	Interval time.Duration
+	Levels   []string
+}
+
+func newExporterCmd(flags *rootFlags) *cobra.Command {
+	exporter := exporterFlags{}
+	exporterCmd := &cobra.Command{
+		Use:   "exporter",
+		Short: "Serve Rollbar item metrics for Prometheus",
+		Long:  "Scrape active items from the Rollbar API every --interval and serve them at /metrics in the Prometheus text format: rollbar_item_occurrences_total{item,level,environment}, rollbar_active_items{level,environment}, and the exporter's own scrape status. --env and --level narrow what is scraped:\n\n  rollbaz exporter --listen 0.0.0.0:9109 --env production",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if exporter.Interval < minExporterInterval {
+				return fmt.Errorf("--interval must be at least %s", minExporterInterval)
+			}
+
+			ctx, stop := signal.NotifyContext(cmd.Context(), os.Interrupt, syscall.SIGTERM)
+			defer stop()
+
+			return runExporter(ctx, *flags, exporter)
+		},
+	}
+	exporterCmd.Flags().StringVar(&exporter.Listen, "listen", "127.0.0.1:9109", "Address to serve /metrics on")
+	exporterCmd.Flags().DurationVar(&exporter.Interval, "interval", defaultExporterInterval, "Time between scrapes of the Rollbar API")
+	exporterCmd.Flags().StringSliceVar(&exporter.Levels, "level", nil, "Filter by level (repeatable)")
+
+	return exporterCmd
+}
+
+// metricsCache holds the last successful scrape; scrape failures keep it and
+// only flip the success gauge.
+type metricsCache struct {
+	mu       sync.RWMutex
+	metrics  app.ItemMetrics
+	scrapeOK bool
+}
+
+func (c *metricsCache) update(metrics app.ItemMetrics, err error) {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	c.scrapeOK = err == nil
+	if err == nil {
+		c.metrics = metrics
+	}
+}
+
+func (c *metricsCache) ServeHTTP(w http.ResponseWriter, _ *http.Request) {
+	c.mu.RLock()
+	body := output.RenderPrometheus(c.metrics, c.scrapeOK)
+	c.mu.RUnlock()
+
+	w.Header().Set("Content-Type", "text/plain; version=0.0.4; charset=utf-8")
+	_, _ = fmt.Fprint(w, body)
+}
+
+// runExporter scrapes on a ticker and serves the cache until ctx is
+// cancelled. Like watch, scrape failures are reported on stderr and retried.
+func runExporter(ctx context.Context, flags rootFlags, exporter exporterFlags) error {
+	options, err := parseListOptions(flags, itemsListFlags{Levels: exporter.Levels})
+	if err != nil {
+		return err
+	}
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	listener, err := net.Listen("tcp", exporter.Listen)
+	if err != nil {
+		return fmt.Errorf("listen: %w", err)
+	}
+	cache := &metricsCache{}
+	mux := http.NewServeMux()
+	mux.Handle("/metrics", cache)
+	server := &http.Server{Handler: mux, ReadHeaderTimeout: 10 * time.Second, WriteTimeout: 30 * time.Second}
+	_, _ = fmt.Fprintf(stderrWriter, "serving metrics on http://%s/metrics\n", listener.Addr())
+
+	served := make(chan error, 1)
+	go func() { served <- server.Serve(listener) }()
+	for {
+		scrapeCtx, cancel := context.WithTimeout(ctx, exporterScrapeTimeout)
+		metrics, err := service.CollectItemMetrics(scrapeCtx, options, time.Now())
+		cancel()
+		if err != nil && ctx.Err() == nil {
+			_, _ = fmt.Fprintf(stderrWriter, "exporter: %s\n", sanitizeError(err, token))
+		}
+		cache.update(metrics, err)
+
+		select {
+		case err := <-served:
+			return fmt.Errorf("serve metrics: %w", err)
+		case <-ctx.Done():
+			return shutdownServer(server, nil)
+		case <-time.After(exporter.Interval):
+		}
+	}
+}
+
+// shutdownServer drains in-flight requests and then, when drain is not nil,
+// work the requests left behind, giving up after serveShutdownTimeout.
+func shutdownServer(server *http.Server, drain func(ctx context.Context) error) error {
+	ctx, cancel := context.WithTimeout(context.Background(), serveShutdownTimeout)
+	defer cancel()
+
+	if err := server.Shutdown(ctx); err != nil {
+		return err //nolint:wrapcheck // Shutdown only reports the drain timing out.
+	}
+	if drain == nil {
+		return nil
+	}
+
+	return drain(ctx)
+}
@@ -0,0 +1,84 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestRunExporter(t *testing.T) {
+	_ = setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":11,"counter":269,"title":"boom","status":"active","level":"error","environment":"production","total_occurrences":42}],"total_count":1}}`)
+	}))
+	probe, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		t.Fatalf("Listen() error = %v", err)
+	}
+	address := probe.Addr().String()
+	_ = probe.Close()
+
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan error, 1)
+	go func() { done <- runExporter(ctx, rootFlags{Format: "human"}, exporterFlags{Listen: address, Interval: time.Hour}) }()
+
+	var body string
+	for attempt := 0; attempt < 50 && !strings.Contains(body, "rollbar_exporter_scrape_success 1"); attempt++ {
+		time.Sleep(20 * time.Millisecond)
+		body = fetchMetrics(t, "http://"+address+"/metrics")
+	}
+	cancel()
+	if err := <-done; err != nil {
+		t.Fatalf("runExporter() error = %v", err)
+	}
+
+	for _, want := range []string{
+		`rollbar_item_occurrences_total{item="269",level="error",environment="production"} 42`,
+		`rollbar_active_items{level="error",environment="production"} 1`,
+	} {
+		if !strings.Contains(body, want) {
+			t.Fatalf("expected %q in:\n%s", want, body)
+		}
+	}
+}
+
+func fetchMetrics(t *testing.T, url string) string {
+	t.Helper()
+
+	request, err := http.NewRequestWithContext(context.Background(), http.MethodGet, url, nil)
+	if err != nil {
+		t.Fatalf("NewRequest() error = %v", err)
+	}
+	response, err := http.DefaultClient.Do(request)
+	if err != nil {
+		return ""
+	}
+	defer func() { _ = response.Body.Close() }()
+	body, _ := io.ReadAll(response.Body)
+
+	return string(body)
+}
+
+func TestExporterCommandErrors(t *testing.T) {
+	_ = setupServerAndStdout(t, http.NotFoundHandler())
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"exporter", "--interval", "1s"}, want: "--interval must be at least"},
+		{args: []string{"exporter", "--listen", "127.0.0.1:-1"}, want: "listen"},
+		{args: []string{"exporter", "--level", "loud"}, want: `unsupported level "loud"`},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.want, err)
+		}
+	}
+}
@@ -0,0 +1,39 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/inapp"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/source"
+)
+
+// sarifAnnotation marks the commands that build a SARIF log for --format
+// sarif; the rest reject the format before running.
+const sarifAnnotation = "rollbaz/sarif"
+
+// renderItemFrames loads the latest stack trace of each item and renders the
+// items with a lookup that places their application frames, by inApp, in the
+// checkout at sourceRoot.
+func renderItemFrames[T any](ctx context.Context, load func(context.Context, []app.IssueSummary) ([]app.ItemFrames, error), items []app.IssueSummary, sourceRoot string, inApp *inapp.Rules, render func([]app.ItemFrames, output.PathLookup) T) (T, error) {
+	var rendered T
+	root, err := source.Open(sourceRoot)
+	if err != nil {
+		return rendered, fmt.Errorf("--source-root: %w", err)
+	}
+	defer func() { _ = root.Close() }()
+
+	located, err := load(ctx, items)
+	if err != nil {
+		return rendered, fmt.Errorf("load item traces: %w", err)
+	}
+
+	return render(located, inApp.Filter(root.Path)), nil
+}
+
+// sarifRenderer builds the SARIF log --format sarif prints.
+func sarifRenderer(items []app.ItemFrames, lookup output.PathLookup) output.SARIFLog {
+	return output.BuildSARIF(items, lookup, version)
+}
@@ -0,0 +1,368 @@
+package cli
+
+import (
+	"bufio"
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/source"
+)
+
+func newItemCmd(flags *rootFlags) *cobra.Command {
+	itemCmd := &cobra.Command{Use: "item", Short: "Inspect and triage a single item"}
+	itemCmd.AddCommand(
+		newShowCmd(flags),
+		newResolveCmd(flags),
+		newReopenCmd(flags),
+		newMuteCmd(flags),
+		newToGitHubCmd(flags),
+		newToJiraCmd(flags),
+		newPostSlackCmd(flags),
+		newTimelineCmd(flags),
+		newPeopleCmd(flags),
+		newOwnersCmd(flags),
+		newBlameCmd(flags),
+		newBisectCmd(flags),
+	)
+
+	return itemCmd
+}
+
+func newShowCmd(flags *rootFlags) *cobra.Command {
+	byID := false
+	sourceRoot := ""
+	showCmd := &cobra.Command{
+		Use:   "show <item-counter|url|id>",
+		Short: "Show details and the top of the latest stack trace for one item",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if byID {
+				itemID, err := parseItemID(args[0])
+				if err != nil {
+					return err
+				}
+				return runShowItem(cmd.Context(), *flags, sourceRoot, func(ctx context.Context, service *app.Service) (app.IssueDetail, error) {
+					return service.ShowByID(ctx, itemID)
+				})
+			}
+
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+			return runShow(cmd.Context(), *flags, counter, sourceRoot)
+		},
+	}
+	showCmd.Flags().BoolVar(&byID, "id", false, "Treat the argument as an item id instead of a project counter")
+	showCmd.Flags().StringVar(&sourceRoot, "source-root", "", "Local checkout to show source lines from under each stack frame")
+	_ = showCmd.MarkFlagDirname("source-root")
+
+	return showCmd
+}
+
+func newResolveCmd(flags *rootFlags) *cobra.Command {
+	resolvedVersion := ""
+	resolveCmd := &cobra.Command{
+		Use:   "resolve <item-counter|url>...",
+		Short: "Resolve one or more issues",
+		Args:  cobra.MinimumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counters, err := parseItemCounters(flags, args)
+			if err != nil {
+				return err
+			}
+
+			return runResolve(cmd.Context(), *flags, counters, resolvedVersion)
+		},
+	}
+	resolveCmd.Flags().StringVar(&resolvedVersion, "resolved-in-version", "", "Version to store when resolving")
+	resolveCmd.Flags().StringVar(&resolvedVersion, "until-version", "", "Alias for --resolved-in-version")
+
+	return resolveCmd
+}
+
+func newReopenCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "reopen <item-counter|url>...",
+		Short: "Reopen one or more resolved or muted issues",
+		Args:  cobra.MinimumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counters, err := parseItemCounters(flags, args)
+			if err != nil {
+				return err
+			}
+
+			return runReopen(cmd.Context(), *flags, counters)
+		},
+	}
+}
+
+func newMuteCmd(flags *rootFlags) *cobra.Command {
+	muteFor := ""
+	muteCmd := &cobra.Command{
+		Use:   "mute <item-counter|url>...",
+		Short: "Mute one or more issues",
+		Args:  cobra.MinimumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counters, err := parseItemCounters(flags, args)
+			if err != nil {
+				return err
+			}
+
+			return runMute(cmd.Context(), *flags, counters, muteFor)
+		},
+	}
+	muteCmd.Flags().StringVar(&muteFor, "for", "", "Mute duration (examples: 30m, 2h, 7d)")
+
+	return muteCmd
+}
+
+func runShow(parent context.Context, flags rootFlags, counter domain.ItemCounter, sourceRoot string) error {
+	return runShowItem(parent, flags, sourceRoot, func(ctx context.Context, service *app.Service) (app.IssueDetail, error) {
+		return service.Show(ctx, counter)
+	})
+}
+
+func runShowItem(parent context.Context, flags rootFlags, sourceRoot string, load func(context.Context, *app.Service) (app.IssueDetail, error)) error {
+	lookup, closeSource, err := openSourceRoot(sourceRoot)
+	if err != nil {
+		return err
+	}
+	defer closeSource()
+
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	detail, err := runWithProgress(flags.Format, "Loading issue detail", func() (app.IssueDetail, error) {
+		return load(ctx, service)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	scrubber := redact.DefaultScrubber()
+	payload := map[string]any{
+		"issue":        detail.IssueSummary,
+		"first_seen":   detail.FirstOccurrenceTimestamp,
+		"main_error":   detail.MainError,
+		"item_raw":     scrubber.ScrubJSON(detail.ItemRaw),
+		"instance":     scrubber.Scrub(detail.Instance),
+		"instance_raw": scrubber.ScrubJSON(detail.InstanceRaw),
+	}
+	jsonPayload := redact.Value(payload, token)
+
+	return printOutput(flags, output.RenderIssueDetailHumanWithSource(detail, terminalRenderWidth(), lookup), jsonPayload)
+}
+
+// openSourceRoot opens --source-root for stack traces; the lookup is nil when
+// the flag is unset.
+func openSourceRoot(dir string) (output.SourceLookup, func(), error) {
+	if dir == "" {
+		return nil, func() {}, nil
+	}
+
+	root, err := source.Open(dir)
+	if err != nil {
+		return nil, nil, fmt.Errorf("--source-root: %w", err)
+	}
+
+	return root.Lines, func() { _ = root.Close() }, nil
+}
+
+func runResolve(parent context.Context, flags rootFlags, counters []domain.ItemCounter, resolvedVersion string) error {
+	return runIssueAction(parent, flags, "resolve", counters, func(ctx context.Context, service *app.Service, counter domain.ItemCounter) (app.ItemActionResult, error) {
+		return service.Resolve(ctx, counter, resolvedVersion)
+	})
+}
+
+func runReopen(parent context.Context, flags rootFlags, counters []domain.ItemCounter) error {
+	return runIssueAction(parent, flags, "reopen", counters, func(ctx context.Context, service *app.Service, counter domain.ItemCounter) (app.ItemActionResult, error) {
+		return service.Reopen(ctx, counter)
+	})
+}
+
+func runMute(parent context.Context, flags rootFlags, counters []domain.ItemCounter, muteFor string) error {
+	durationSeconds, err := parseMuteDuration(muteFor)
+	if err != nil {
+		return err
+	}
+
+	return runIssueAction(parent, flags, "mute", counters, func(ctx context.Context, service *app.Service, counter domain.ItemCounter) (app.ItemActionResult, error) {
+		return service.Mute(ctx, counter, durationSeconds)
+	})
+}
+
+func parseMuteDuration(value string) (*int64, error) {
+	value = strings.TrimSpace(value)
+	if value == "" {
+		return nil, nil
+	}
+
+	parsed, ok := parseRelativeDuration(value)
+	if !ok {
+		return nil, fmt.Errorf("parse --for: invalid duration %q", value)
+	}
+	if parsed < time.Second {
+		return nil, errors.New("--for must be at least 1s")
+	}
+
+	seconds := int64(parsed / time.Second)
+	return &seconds, nil
+}
+
+// runIssueAction applies one write to each counter in order after a single
+// confirmation, stopping at the first failure. The issues already updated are
+// still printed before the error.
+func runIssueAction(parent context.Context, flags rootFlags, action string, counters []domain.ItemCounter, execute func(context.Context, *app.Service, domain.ItemCounter) (app.ItemActionResult, error)) error {
+	if err := confirmWrite(flags, action, counters); err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, time.Duration(len(counters))*10*time.Second)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	results, err := runWithProgress(flags.Format, "Updating issues", func() ([]app.ItemActionResult, error) {
+		return applyIssueAction(ctx, service, action, counters, execute)
+	})
+	if len(results) > 0 {
+		if printErr := printActionResults(flags, token, len(counters) > 1, results); printErr != nil {
+			return printErr
+		}
+	}
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return nil
+}
+
+// applyIssueAction returns the results up to the first failure, with an
+// error naming the counter that failed and those not attempted after it.
+func applyIssueAction(ctx context.Context, service *app.Service, action string, counters []domain.ItemCounter, execute func(context.Context, *app.Service, domain.ItemCounter) (app.ItemActionResult, error)) ([]app.ItemActionResult, error) {
+	results := make([]app.ItemActionResult, 0, len(counters))
+	for index, counter := range counters {
+		result, err := execute(ctx, service, counter)
+		if err != nil {
+			return results, issueActionError(action, counter, counters[index+1:], err)
+		}
+		results = append(results, result)
+	}
+
+	return results, nil
+}
+
+func issueActionError(action string, failed domain.ItemCounter, skipped []domain.ItemCounter, err error) error {
+	if len(skipped) == 0 {
+		return fmt.Errorf("%s issue %s: %w", action, failed, err)
+	}
+
+	names := make([]string, 0, len(skipped))
+	for _, counter := range skipped {
+		names = append(names, counter.String())
+	}
+
+	return fmt.Errorf("%s issue %s: %w (not attempted: %s)", action, failed, err, strings.Join(names, ", "))
+}
+
+// printActionResults prints the issues updated. JSON names a single counter's
+// issue "issue" and a list of them "issues", even when the list stopped short.
+func printActionResults(flags rootFlags, token string, several bool, results []app.ItemActionResult) error {
+	issues := make([]app.IssueSummary, 0, len(results))
+	counters := make([]string, 0, len(results))
+	for _, result := range results {
+		issues = append(issues, result.Issue)
+		counters = append(counters, result.Issue.Counter.String())
+	}
+
+	noun := "issue"
+	payload := map[string]any{"action": results[0].Action, "issue": results[0].Issue}
+	if several {
+		noun = "issues"
+		payload = map[string]any{"action": results[0].Action, "issues": issues}
+	}
+
+	human := fmt.Sprintf("%s %s %s\n\n%s", results[0].Action, noun, strings.Join(counters, ", "), output.RenderIssueListHumanWithWidth(issues, terminalRenderWidth()))
+	return printOutput(flags, human, redact.Value(payload, token))
+}
+
+func confirmWrite(flags rootFlags, action string, counters []domain.ItemCounter) error {
+	return confirmAction(flags, action+" "+describeCounters(counters))
+}
+
+// confirmAction asks before a write described by what, such as "resolve
+// issue #42", unless --yes is set.
+func confirmAction(flags rootFlags, what string) error {
+	if flags.Yes {
+		return nil
+	}
+	if !isHumanFormat(flags.Format) || !canPromptConfirmation() {
+		return errors.New("confirmation required for write operation; rerun with --yes")
+	}
+
+	_, _ = fmt.Fprintf(stdoutWriter, "Confirm %s? [y/N]: ", what)
+	reader := bufio.NewReader(stdinReader)
+	line, err := reader.ReadString('\n')
+	if err != nil && !errors.Is(err, io.EOF) {
+		return fmt.Errorf("read confirmation: %w", err)
+	}
+
+	value := strings.TrimSpace(strings.ToLower(line))
+	if value != "y" && value != "yes" {
+		return errors.New("operation cancelled")
+	}
+
+	return nil
+}
+
+// maxConfirmCounters caps how many counters a confirmation prompt lists.
+const maxConfirmCounters = 10
+
+func describeCounters(counters []domain.ItemCounter) string {
+	if len(counters) == 1 {
+		return "issue " + counters[0].String()
+	}
+
+	names := make([]string, 0, min(len(counters), maxConfirmCounters))
+	for _, counter := range counters[:min(len(counters), maxConfirmCounters)] {
+		names = append(names, counter.String())
+	}
+	if hidden := len(counters) - len(names); hidden > 0 {
+		names = append(names, fmt.Sprintf("+%d more", hidden))
+	}
+
+	return fmt.Sprintf("%d issues (%s)", len(counters), strings.Join(names, ", "))
+}
+
+func canPromptConfirmation() bool {
+	stdoutFile, ok := stdoutFile()
+	if !ok || !isTerminal(int(stdoutFile.Fd())) {
+		return false
+	}
+	input, ok := stdinReader.(*os.File)
+	if !ok || !isTerminal(int(input.Fd())) {
+		return false
+	}
+
+	return true
+}
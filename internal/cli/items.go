@@ -0,0 +1,304 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func newItemsCmd(flags *rootFlags) *cobra.Command {
+	itemsCmd := &cobra.Command{Use: "items", Short: "List and filter items"}
+	itemsCmd.AddCommand(
+		newItemsListCmd(flags),
+		newActiveCmd(flags),
+		newBulkResolveCmd(flags),
+		newItemsSimilarCmd(flags),
+		newItemsCompareEnvCmd(flags),
+	)
+
+	return itemsCmd
+}
+
+type itemsListFlags struct {
+	Levels     []string
+	Query      string
+	Sort       string
+	Ascending  bool
+	Saved      string
+	SourceRoot string
+	Columns    []string
+}
+
+func newItemsListCmd(flags *rootFlags) *cobra.Command {
+	listFlags := itemsListFlags{}
+	listCmd := &cobra.Command{
+		Use:         "list",
+		Short:       "List items as a table, filtered and sorted",
+		Annotations: map[string]string{sarifAnnotation: "true"},
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if listFlags.Saved != "" {
+				if err := applySavedQuery(cmd, flags, &listFlags, listFlags.Saved); err != nil {
+					return err
+				}
+			}
+			return runItemsList(cmd.Context(), *flags, listFlags)
+		},
+	}
+	listCmd.Flags().StringSliceVar(&listFlags.Levels, "level", nil, "Filter by level: debug, info, warning, error, critical (repeatable)")
+	listCmd.Flags().StringVar(&listFlags.Query, "query", "", "Rollbar free-text item search")
+	listCmd.Flags().StringVar(&listFlags.Sort, "sort", string(app.SortLastSeen), "Sort by last-seen, occurrences, counter, or level")
+	listCmd.Flags().BoolVar(&listFlags.Ascending, "asc", false, "Sort ascending instead of descending")
+	listCmd.Flags().StringVar(&listFlags.Saved, "saved", "", "Apply a saved query from config.toml (flags given here still win)")
+	_ = listCmd.RegisterFlagCompletionFunc("saved", completeSavedQueries)
+	listCmd.Flags().StringVar(&listFlags.SourceRoot, "source-root", ".", "Checkout that --format sarif places items in")
+	_ = listCmd.MarkFlagDirname("source-root")
+	listCmd.Flags().StringArrayVar(&listFlags.Columns, "column", nil, "Add a column from each item's latest occurrence, e.g. data.custom.tenant_id (repeatable)")
+
+	return listCmd
+}
+
+func runItemsList(parent context.Context, flags rootFlags, listFlags itemsListFlags) error {
+	options, err := parseListOptions(flags, listFlags)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	issues, err := runWithProgress(flags.Format, "Loading items", func() ([]app.IssueSummary, error) {
+		return service.List(ctx, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+	if err := checkEnvironmentFilter(ctx, service, options.Filters, issues); err != nil {
+		return sanitizeError(err, token)
+	}
+
+	payload, err := itemsListPayload(ctx, service, flags, listFlags.SourceRoot, issues)
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderItemTableWithColumns(issues, app.ColumnPaths(options.Columns), terminalRenderWidth()), redact.Value(payload, token))
+}
+
+// itemsListPayload is the issues, or with --format sarif a SARIF log of them
+// placed on their latest stack traces.
+func itemsListPayload(ctx context.Context, service *app.Service, flags rootFlags, sourceRoot string, issues []app.IssueSummary) (any, error) {
+	if parsed, err := output.ParseFormat(flags.Format); err != nil || parsed != output.FormatSARIF {
+		return map[string]any{"issues": issues}, nil
+	}
+
+	return renderItemFrames(ctx, service.LatestFrames, issues, sourceRoot, flags.InApp, sarifRenderer)
+}
+
+func parseListOptions(flags rootFlags, listFlags itemsListFlags) (app.ListOptions, error) {
+	filters, err := parseIssueFilters(flags)
+	if err != nil {
+		return app.ListOptions{}, err
+	}
+
+	sortKey, err := app.ParseItemSort(listFlags.Sort)
+	if err != nil {
+		return app.ListOptions{}, fmt.Errorf("parse --sort: %w", err)
+	}
+
+	levels := make([]string, 0, len(listFlags.Levels))
+	for _, level := range listFlags.Levels {
+		normalized := strings.ToLower(strings.TrimSpace(level))
+		if rollbar.Level(normalized).Severity() == 0 {
+			return app.ListOptions{}, fmt.Errorf("unsupported level %q", level)
+		}
+		levels = append(levels, normalized)
+	}
+
+	columns, err := app.ParseColumns(listFlags.Columns)
+	if err != nil {
+		return app.ListOptions{}, fmt.Errorf("parse --column: %w", err)
+	}
+
+	return app.ListOptions{Filters: filters, Levels: levels, Query: listFlags.Query, Sort: sortKey, Ascending: listFlags.Ascending, Limit: flags.Limit, Columns: columns}, nil
+}
+
+func newActiveCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "active",
+		Short: "List active issues",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runActive(cmd.Context(), *flags)
+		},
+	}
+}
+
+func newRecentCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "recent",
+		Short: "List most recently seen active issues",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runRecent(cmd.Context(), *flags)
+		},
+	}
+}
+
+func runActive(parent context.Context, flags rootFlags) error {
+	return runIssueList(parent, flags, func(ctx context.Context, service *app.Service, limit int, filters app.IssueFilters) ([]app.IssueSummary, error) {
+		return service.Active(ctx, limit, filters)
+	})
+}
+
+func runRecent(parent context.Context, flags rootFlags) error {
+	return runIssueList(parent, flags, func(ctx context.Context, service *app.Service, limit int, filters app.IssueFilters) ([]app.IssueSummary, error) {
+		return service.Recent(ctx, limit, filters)
+	})
+}
+
+func runIssueList(parent context.Context, flags rootFlags, load func(context.Context, *app.Service, int, app.IssueFilters) ([]app.IssueSummary, error)) error {
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	filters, err := parseIssueFilters(flags)
+	if err != nil {
+		return err
+	}
+
+	issues, err := runWithProgress(flags.Format, "Loading issues", func() ([]app.IssueSummary, error) {
+		return load(ctx, service, flags.Limit, filters)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+	if err := checkEnvironmentFilter(ctx, service, filters, issues); err != nil {
+		return sanitizeError(err, token)
+	}
+
+	jsonPayload := redact.Value(map[string]any{"issues": issues}, token)
+	return printOutput(flags, output.RenderIssueListHumanWithWidth(issues, terminalRenderWidth()), jsonPayload)
+}
+
+func parseIssueFilters(flags rootFlags) (app.IssueFilters, error) {
+	filters := app.IssueFilters{
+		Environment: flags.Environment,
+		Status:      flags.Status,
+	}
+
+	since, err := parseFilterTime(flags.Since)
+	if err != nil {
+		return app.IssueFilters{}, fmt.Errorf("parse --since: %w", err)
+	}
+	filters.Since = since
+
+	until, err := parseFilterTime(flags.Until)
+	if err != nil {
+		return app.IssueFilters{}, fmt.Errorf("parse --until: %w", err)
+	}
+	filters.Until = until
+
+	minOccurrences, err := parseOptionalUint64(flags.MinOccurrences)
+	if err != nil {
+		return app.IssueFilters{}, fmt.Errorf("parse --min-occurrences: %w", err)
+	}
+	filters.MinOccurrences = minOccurrences
+
+	maxOccurrences, err := parseOptionalUint64(flags.MaxOccurrences)
+	if err != nil {
+		return app.IssueFilters{}, fmt.Errorf("parse --max-occurrences: %w", err)
+	}
+	filters.MaxOccurrences = maxOccurrences
+
+	if err := validateIssueFilters(filters); err != nil {
+		return app.IssueFilters{}, err
+	}
+
+	return filters, nil
+}
+
+func validateIssueFilters(filters app.IssueFilters) error {
+	if filters.Since != nil && filters.Until != nil && filters.Since.After(*filters.Until) {
+		return errors.New("--since must be before or equal to --until")
+	}
+	if filters.MinOccurrences != nil && filters.MaxOccurrences != nil && *filters.MinOccurrences > *filters.MaxOccurrences {
+		return errors.New("--min-occurrences must be <= --max-occurrences")
+	}
+
+	return nil
+}
+
+func parseOptionalUint64(value string) (*uint64, error) {
+	if value == "" {
+		return nil, nil
+	}
+
+	parsed, err := strconv.ParseUint(value, 10, 64)
+	if err != nil {
+		return nil, fmt.Errorf("parse uint64: %w", err)
+	}
+
+	return &parsed, nil
+}
+
+func parseFilterTime(value string) (*time.Time, error) {
+	if value == "" {
+		return nil, nil
+	}
+
+	if unixSeconds, err := strconv.ParseInt(value, 10, 64); err == nil {
+		if unixSeconds < 0 {
+			return nil, errors.New("unix seconds must be non-negative")
+		}
+		parsed := time.Unix(unixSeconds, 0).UTC()
+		return &parsed, nil
+	}
+	if ago, ok := parseRelativeDuration(value); ok {
+		parsed := time.Now().Add(-ago).UTC().Truncate(time.Second)
+		return &parsed, nil
+	}
+
+	parsed, err := time.Parse(time.RFC3339, value)
+	if err != nil {
+		return nil, fmt.Errorf("parse rfc3339: %w", err)
+	}
+	utc := parsed.UTC()
+
+	return &utc, nil
+}
+
+// parseRelativeDuration accepts Go durations ("90m", "24h") plus whole days
+// ("7d") for filters such as --since 24h.
+func parseRelativeDuration(value string) (time.Duration, bool) {
+	if days, ok := strings.CutSuffix(value, "d"); ok {
+		count, err := strconv.Atoi(days)
+		if err != nil || count <= 0 {
+			return 0, false
+		}
+		return time.Duration(count) * 24 * time.Hour, true
+	}
+
+	parsed, err := time.ParseDuration(value)
+	if err != nil || parsed <= 0 {
+		return 0, false
+	}
+
+	return parsed, true
+}
@@ -0,0 +1,130 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const linksTimeout = 15 * time.Second
+
+func newLinksCmd(flags *rootFlags) *cobra.Command {
+	linksCmd := &cobra.Command{Use: "links", Short: "Manage the service links shown on the project's items"}
+	linksCmd.AddCommand(
+		newLinksListCmd(flags),
+		newLinksAddCmd(flags),
+		newLinksRemoveCmd(flags),
+	)
+
+	return linksCmd
+}
+
+func newLinksListCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "list",
+		Short: "List service links",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runLinksList(cmd.Context(), *flags)
+		},
+	}
+}
+
+func newLinksAddCmd(flags *rootFlags) *cobra.Command {
+	name, template := "", ""
+	addCmd := &cobra.Command{
+		Use:   "add",
+		Short: "Add a service link",
+		Long:  "Add a link that Rollbar shows on every item of the project. {{ }} placeholders in --url-template are filled from the item, for example:\n\n  rollbaz links add --name Runbook --url-template 'https://runbooks.example.com/search?q={{ title }}'\n  rollbaz links add --name Logs --url-template 'https://logs.example.com/?env={{ environment }}&host={{ server.host }}'",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runLinksAdd(cmd.Context(), *flags, name, template)
+		},
+	}
+	addCmd.Flags().StringVar(&name, "name", "", "Link text shown on items")
+	addCmd.Flags().StringVar(&template, "url-template", "", "http(s) URL with {{ }} placeholders")
+	_ = addCmd.MarkFlagRequired("name")
+	_ = addCmd.MarkFlagRequired("url-template")
+
+	return addCmd
+}
+
+func newLinksRemoveCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:     "rm <id|name>",
+		Aliases: []string{"remove"},
+		Short:   "Remove a service link by id or name",
+		Args:    cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runLinksRemove(cmd.Context(), *flags, args[0])
+		},
+	}
+}
+
+func runLinksList(parent context.Context, flags rootFlags) error {
+	ctx, cancel := context.WithTimeout(parent, linksTimeout)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	links, err := runWithProgress(flags.Format, "Loading service links", func() ([]rollbar.ServiceLink, error) {
+		return app.ListServiceLinks(ctx, client)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderServiceLinksWithWidth(links, terminalRenderWidth()), redact.Value(map[string]any{"service_links": links}, token))
+}
+
+func runLinksAdd(parent context.Context, flags rootFlags, name string, template string) error {
+	if err := app.ValidateLinkTemplate(template); err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, linksTimeout)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	link, err := app.AddServiceLink(ctx, client, name, template)
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, fmt.Sprintf("added service link %d: %s", link.ID, link.Name), redact.Value(link, token))
+}
+
+func runLinksRemove(parent context.Context, flags rootFlags, ref string) error {
+	if err := confirmAction(flags, fmt.Sprintf("remove service link %s", ref)); err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, linksTimeout)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	link, err := app.RemoveServiceLink(ctx, client, ref)
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, fmt.Sprintf("removed service link %d: %s", link.ID, link.Name), redact.Value(map[string]any{"removed": link}, token))
+}
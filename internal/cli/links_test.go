@@ -0,0 +1,59 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+// serveLinks serves the Runbook link, creates the Logs link and deletes
+// link 3.
+func serveLinks(w http.ResponseWriter, r *http.Request) {
+	body, _ := io.ReadAll(r.Body)
+	switch r.Method + " " + r.URL.Path + " " + string(body) {
+	case "GET /api/1/service_links ":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":3,"name":"Runbook","template":"https://runbooks.example.com/{{ title }}"}]}`)
+	case `POST /api/1/service_links {"name":"Logs","template":"https://logs.example.com/?env={{ environment }}"}`:
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":4,"name":"Logs","template":"https://logs.example.com/?env={{ environment }}"}}`)
+	case "DELETE /api/1/service_links/3 ":
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s %s"}`, r.Method, r.URL.Path, body)
+	}
+}
+
+func TestLinksCommands(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveLinks))
+
+	runRootCommand(t, "links", "list")
+	checkContains(t, "list", stdout.String(), "Runbook", "{{ title }}")
+
+	stdout.Reset()
+	runRootCommand(t, "links", "add", "--name", "Logs", "--url-template", "https://logs.example.com/?env={{ environment }}")
+	checkContains(t, "add", stdout.String(), "added service link 4: Logs")
+
+	stdout.Reset()
+	runRootCommand(t, "links", "rm", "runbook", "--yes", "--format", "json")
+	checkContains(t, "rm", stdout.String(), `"id": 3`)
+}
+
+func TestLinksCommandErrors(t *testing.T) {
+	_ = setupServerAndStdout(t, http.HandlerFunc(serveLinks))
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"links", "add", "--name", "Runbook", "--url-template", "https://example.com"}, want: `service link "Runbook" already exists (id 3)`},
+		{args: []string{"links", "add", "--name", "Bad", "--url-template", "ftp://example.com/{{ title }}"}, want: "http or https URL"},
+		{args: []string{"links", "rm", "3"}, want: "rerun with --yes"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		checkError(t, strings.Join(tc.args, " "), cmd.Execute(), tc.want)
+	}
+}
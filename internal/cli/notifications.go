@@ -0,0 +1,232 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type notificationAddFlags struct {
+	Channel string
+	Trigger string
+	Filters []string
+	Config  []string
+	File    string
+}
+
+func newNotificationsCmd(flags *rootFlags) *cobra.Command {
+	notificationsCmd := &cobra.Command{Use: "notifications", Short: "Manage Slack, email, PagerDuty, and webhook notification rules"}
+	notificationsCmd.AddCommand(
+		newNotificationsListCmd(flags),
+		newNotificationsAddCmd(flags),
+		newNotificationsRemoveCmd(flags),
+	)
+
+	return notificationsCmd
+}
+
+func newNotificationsListCmd(flags *rootFlags) *cobra.Command {
+	channel := ""
+	listCmd := &cobra.Command{
+		Use:   "list",
+		Short: "List notification rules of every channel, or of --channel",
+		Long:  "List the project's notification rules. The --format json output can be saved and applied to another project with notifications add --file; credentials in it are redacted and must be filled in first.",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runNotificationsList(cmd.Context(), *flags, channel)
+		},
+	}
+	listCmd.Flags().StringVar(&channel, "channel", "", "Channel: email, slack, pagerduty, or webhook")
+	_ = listCmd.RegisterFlagCompletionFunc("channel", completeNotificationChannel)
+
+	return listCmd
+}
+
+func newNotificationsAddCmd(flags *rootFlags) *cobra.Command {
+	add := notificationAddFlags{}
+	addCmd := &cobra.Command{
+		Use:   "add",
+		Short: "Add a notification rule, or the rules in --file",
+		Long:  "Add a rule to --channel that sends --trigger events passing every --filter, or add the rules in --file, which holds notifications list --format json output. Rules are added alongside the ones already there. For example:\n\n  rollbaz notifications add --channel slack --trigger new_item --filter environment=production --filter level:gte:error --config channel=#alerts\n  rollbaz notifications list --project api --format json > rules.json\n  rollbaz notifications add --project web --file rules.json",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runNotificationsAdd(cmd.Context(), *flags, add)
+		},
+	}
+	addCmd.Flags().StringVar(&add.Channel, "channel", "", "Channel: email, slack, pagerduty, or webhook")
+	addCmd.Flags().StringVar(&add.Trigger, "trigger", "", "Event that fires the rule, such as new_item, reactivated_item, occurrence, or deploy")
+	addCmd.Flags().StringArrayVar(&add.Filters, "filter", nil, "Filter as type:operation:value, type=value, or rate:period-seconds:count (repeatable)")
+	addCmd.Flags().StringArrayVar(&add.Config, "config", nil, "Channel setting as key=value, such as channel=#alerts (repeatable)")
+	addCmd.Flags().StringVar(&add.File, "file", "", "JSON rules from notifications list --format json")
+	addCmd.MarkFlagsMutuallyExclusive("file", "channel")
+	addCmd.MarkFlagsMutuallyExclusive("file", "trigger")
+	_ = addCmd.RegisterFlagCompletionFunc("channel", completeNotificationChannel)
+
+	return addCmd
+}
+
+func newNotificationsRemoveCmd(flags *rootFlags) *cobra.Command {
+	channel := ""
+	removeCmd := &cobra.Command{
+		Use:     "rm <rule-id>",
+		Aliases: []string{"remove"},
+		Short:   "Remove a notification rule from --channel",
+		Args:    cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			ruleID, err := strconv.ParseUint(strings.TrimSpace(args[0]), 10, 64)
+			if err != nil || ruleID == 0 {
+				return fmt.Errorf("invalid rule id %q", args[0])
+			}
+			return runNotificationsRemove(cmd.Context(), *flags, channel, ruleID)
+		},
+	}
+	removeCmd.Flags().StringVar(&channel, "channel", "", "Channel: email, slack, pagerduty, or webhook")
+	_ = removeCmd.MarkFlagRequired("channel")
+	_ = removeCmd.RegisterFlagCompletionFunc("channel", completeNotificationChannel)
+
+	return removeCmd
+}
+
+func completeNotificationChannel(cmd *cobra.Command, args []string, toComplete string) ([]string, cobra.ShellCompDirective) {
+	return rollbar.NotificationChannels, cobra.ShellCompDirectiveNoFileComp
+}
+
+func runNotificationsList(parent context.Context, flags rootFlags, channel string) error {
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	channels, err := runWithProgress(flags.Format, "Loading notification rules", func() ([]app.ChannelRules, error) {
+		return app.ListNotificationRules(ctx, client, channel)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderNotificationRulesWithWidth(channels, terminalRenderWidth()), redact.Value(map[string]any{"channels": channels}, token))
+}
+
+func runNotificationsAdd(parent context.Context, flags rootFlags, add notificationAddFlags) error {
+	rules, err := notificationRules(add)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	created, err := app.AddNotificationRules(ctx, client, rules)
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	count, noun := countNotificationRules(created), "rules"
+	if count == 1 {
+		noun = "rule"
+	}
+	human := fmt.Sprintf("added %d notification %s\n\n%s", count, noun, output.RenderNotificationRulesWithWidth(created, terminalRenderWidth()))
+	return printOutput(flags, human, redact.Value(map[string]any{"channels": created}, token))
+}
+
+func runNotificationsRemove(parent context.Context, flags rootFlags, channel string, ruleID uint64) error {
+	if _, err := app.ParseNotificationChannel(channel); err != nil {
+		return err
+	}
+	if err := confirmAction(flags, fmt.Sprintf("remove %s notification rule %d", channel, ruleID)); err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	if err := app.RemoveNotificationRule(ctx, client, channel, ruleID); err != nil {
+		return sanitizeError(err, token)
+	}
+
+	payload := map[string]any{"channel": strings.ToLower(strings.TrimSpace(channel)), "removed_rule_id": ruleID}
+	return printOutput(flags, fmt.Sprintf("removed %s notification rule %d", channel, ruleID), payload)
+}
+
+// notificationRules reads the rules to add from --file, or builds one from
+// --channel, --trigger, --filter, and --config.
+func notificationRules(add notificationAddFlags) ([]app.ChannelRules, error) {
+	if add.File != "" {
+		return readNotificationRules(add.File)
+	}
+	if strings.TrimSpace(add.Channel) == "" || strings.TrimSpace(add.Trigger) == "" {
+		return nil, errors.New("set --channel and --trigger, or --file")
+	}
+
+	rule := rollbar.NotificationRule{Trigger: strings.TrimSpace(add.Trigger), Filters: []rollbar.NotificationFilter{}}
+	for _, value := range add.Filters {
+		filter, err := app.ParseNotificationFilter(value)
+		if err != nil {
+			return nil, err
+		}
+		rule.Filters = append(rule.Filters, filter)
+	}
+	for _, value := range add.Config {
+		key, setting, ok := strings.Cut(value, "=")
+		if !ok || strings.TrimSpace(key) == "" {
+			return nil, fmt.Errorf("invalid --config %q (use key=value)", value)
+		}
+		if rule.Config == nil {
+			rule.Config = map[string]any{}
+		}
+		rule.Config[strings.TrimSpace(key)] = setting
+	}
+
+	return []app.ChannelRules{{Channel: add.Channel, Rules: []rollbar.NotificationRule{rule}}}, nil
+}
+
+func readNotificationRules(path string) ([]app.ChannelRules, error) {
+	body, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return nil, fmt.Errorf("read rules file: %w", err)
+	}
+
+	var file struct {
+		Channels []app.ChannelRules `json:"channels"`
+	}
+	if err := json.Unmarshal(body, &file); err != nil {
+		return nil, fmt.Errorf("parse rules file %s: %w", path, err)
+	}
+
+	return file.Channels, nil
+}
+
+func countNotificationRules(channels []app.ChannelRules) int {
+	count := 0
+	for _, channel := range channels {
+		count += len(channel.Rules)
+	}
+
+	return count
+}
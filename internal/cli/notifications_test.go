@@ -0,0 +1,99 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// serveNotifications serves one pagerduty rule holding a service key,
+// creates the slack and email rules the tests add and deletes slack rule 9.
+func serveNotifications(w http.ResponseWriter, r *http.Request) {
+	body, _ := io.ReadAll(r.Body)
+	switch r.Method + " " + r.URL.Path + " " + string(body) {
+	case "GET /api/1/notifications/pagerduty/rules ":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":6,"trigger":"new_item","filters":[],"config":{"service_key":"pd-secret"}}]}`)
+	case `POST /api/1/notifications/slack/rules [{"trigger":"new_item","filters":[{"type":"environment","operation":"eq","value":"production"}],"config":{"channel":"#alerts"}}]`:
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":9,"trigger":"new_item","filters":[{"type":"environment","operation":"eq","value":"production"}],"config":{"channel":"#alerts"}}]}`)
+	case `POST /api/1/notifications/email/rules [{"trigger":"deploy","filters":[]}]`:
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":10,"trigger":"deploy","filters":[]}]}`)
+	case "DELETE /api/1/notifications/slack/rule/9 ":
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	default:
+		serveOtherNotifications(w, r, body)
+	}
+}
+
+// serveOtherNotifications lists no rules for the remaining channels and
+// rejects any other request.
+func serveOtherNotifications(w http.ResponseWriter, r *http.Request, body []byte) {
+	if r.Method == http.MethodGet && strings.HasSuffix(r.URL.Path, "/rules") {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[]}`)
+		return
+	}
+	w.WriteHeader(http.StatusNotFound)
+	_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s %s"}`, r.Method, r.URL.Path, body)
+}
+
+// checkNoSecret fails if output leaks the pagerduty service key.
+func checkNoSecret(t *testing.T, name string, output string) {
+	t.Helper()
+	if strings.Contains(output, "pd-secret") {
+		t.Fatalf("%s: service key leaked:\n%s", name, output)
+	}
+}
+
+func TestNotificationsCommands(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveNotifications))
+
+	runRootCommand(t, "notifications", "list")
+	checkContains(t, "list", stdout.String(), "pagerduty", "service_key=[REDACTED]")
+	checkNoSecret(t, "list", stdout.String())
+
+	stdout.Reset()
+	runRootCommand(t, "notifications", "list", "--channel", "pagerduty", "--format", "json")
+	checkContains(t, "list json", stdout.String(), `"service_key": "[REDACTED]"`)
+	checkNoSecret(t, "list json", stdout.String())
+
+	stdout.Reset()
+	runRootCommand(t, "notifications", "add", "--channel", "slack", "--trigger", "new_item", "--filter", "environment=production", "--config", "channel=#alerts")
+	checkContains(t, "add", stdout.String(), "added 1 notification rule", "environment eq production")
+
+	rulesFile := filepath.Join(t.TempDir(), "rules.json")
+	if err := os.WriteFile(rulesFile, []byte(`{"channels":[{"channel":"email","rules":[{"id":3,"trigger":"deploy","filters":[]}]}]}`), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	stdout.Reset()
+	runRootCommand(t, "notifications", "add", "--file", rulesFile)
+	checkContains(t, "add --file", stdout.String(), "added 1 notification rule", "deploy")
+
+	stdout.Reset()
+	runRootCommand(t, "notifications", "rm", "9", "--channel", "slack", "--yes")
+	checkContains(t, "rm", stdout.String(), "removed slack notification rule 9")
+}
+
+func TestNotificationsCommandErrors(t *testing.T) {
+	_ = setupServerAndStdout(t, http.HandlerFunc(serveNotifications))
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"notifications", "add", "--channel", "slack"}, want: "set --channel and --trigger"},
+		{args: []string{"notifications", "add", "--channel", "slack", "--trigger", "new_item", "--filter", "level:gte"}, want: "invalid filter"},
+		{args: []string{"notifications", "add", "--channel", "slack", "--trigger", "new_item", "--config", "channel"}, want: "invalid --config"},
+		{args: []string{"notifications", "add", "--channel", "sms", "--trigger", "new_item"}, want: "unsupported notification channel"},
+		{args: []string{"notifications", "add", "--file", filepath.Join(t.TempDir(), "missing.json")}, want: "read rules file"},
+		{args: []string{"notifications", "rm", "nine", "--channel", "slack"}, want: "invalid rule id"},
+		{args: []string{"notifications", "rm", "9", "--channel", "slack"}, want: "rerun with --yes"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		checkError(t, strings.Join(tc.args, " "), cmd.Execute(), tc.want)
+	}
+}
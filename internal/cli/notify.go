@@ -0,0 +1,101 @@
+package cli
+
+import (
+	"bytes"
+	"errors"
+	"fmt"
+	"os/exec"
+	"runtime"
+	"strings"
+)
+
+// notifyOpenAction is the notify-send action id printed when the
+// notification is clicked.
+const notifyOpenAction = "open"
+
+type desktopNotification struct {
+	Title string
+	Body  string
+	URL   string
+}
+
+// desktopCommand is how a platform shows a notification. wait is set when
+// the command blocks until the notification is clicked or dismissed and
+// prints notifyOpenAction on a click, which rollbaz answers by opening URL.
+type desktopCommand struct {
+	name string
+	args []string
+	wait bool
+}
+
+// sendDesktopNotification shows a notification without waiting for the user;
+// tests replace it.
+var sendDesktopNotification = func(notification desktopNotification) error {
+	command, err := desktopNotifyCommand(runtime.GOOS, notification, exec.LookPath)
+	if err != nil {
+		return err
+	}
+	if !command.wait {
+		return startDetached(command)
+	}
+
+	//nolint:gosec // G204: the notifier is fixed per platform; title and body are passed as separate arguments.
+	cmd := exec.Command(command.name, command.args...)
+	var stdout bytes.Buffer
+	cmd.Stdout = &stdout
+	if err := cmd.Start(); err != nil {
+		return fmt.Errorf("start %s: %w", command.name, err)
+	}
+	go func() {
+		if err := cmd.Wait(); err != nil {
+			// notify-send before libnotify 0.7.10 has no --action or --wait;
+			// show the notification without the click-through instead.
+			_ = startDetached(desktopCommand{name: command.name, args: []string{"--app-name=rollbaz", "--", notification.Title, notification.Body}})
+			return
+		}
+		if strings.TrimSpace(stdout.String()) == notifyOpenAction {
+			_ = openBrowser(notification.URL)
+		}
+	}()
+
+	return nil
+}
+
+// desktopNotifyCommand picks the notifier for goos: notify-send on Linux and
+// the BSDs, and on macOS terminal-notifier when it is installed (it can open
+// the item on click) or osascript otherwise.
+func desktopNotifyCommand(goos string, notification desktopNotification, lookPath func(string) (string, error)) (desktopCommand, error) {
+	switch goos {
+	case "windows":
+		return desktopCommand{}, errors.New("desktop notifications are not supported on windows; use --exec instead")
+	case "darwin":
+		if _, err := lookPath("terminal-notifier"); err == nil {
+			return desktopCommand{name: "terminal-notifier", args: []string{"-title", notification.Title, "-message", notification.Body, "-open", notification.URL}}, nil
+		}
+		// The title and body arrive as argv so they are never parsed as
+		// AppleScript.
+		return desktopCommand{name: "osascript", args: []string{
+			"-e", "on run argv",
+			"-e", "display notification (item 2 of argv) with title (item 1 of argv)",
+			"-e", "end run",
+			notification.Title, notification.Body,
+		}}, nil
+	}
+
+	return desktopCommand{name: "notify-send", args: []string{
+		"--app-name=rollbaz",
+		"--action=" + notifyOpenAction + "=Open in Rollbar",
+		"--wait",
+		"--", notification.Title, notification.Body,
+	}, wait: true}, nil
+}
+
+func startDetached(command desktopCommand) error {
+	//nolint:gosec // G204: the notifier is fixed per platform; title and body are passed as separate arguments.
+	cmd := exec.Command(command.name, command.args...)
+	if err := cmd.Start(); err != nil {
+		return fmt.Errorf("start %s: %w", command.name, err)
+	}
+
+	return cmd.Process.Release() //nolint:wrapcheck // Release only fails for an already-released process.
+}
@@ -0,0 +1,40 @@
+package cli
+
+import (
+	"errors"
+	"strings"
+	"testing"
+)
+
+func TestDesktopNotifyCommand(t *testing.T) {
+	t.Parallel()
+
+	notification := desktopNotification{Title: "Rollbar #2: New item", Body: "-boom", URL: "https://rollbar.com/acme/api/items/2/"}
+	installed := func(string) (string, error) { return "/usr/local/bin/terminal-notifier", nil }
+	missing := func(string) (string, error) { return "", errors.New("not found") }
+
+	tests := []struct {
+		goos     string
+		lookPath func(string) (string, error)
+		want     string
+		wait     bool
+		wantErr  string
+	}{
+		{goos: "linux", lookPath: missing, want: "notify-send --app-name=rollbaz --action=open=Open in Rollbar --wait -- Rollbar #2: New item -boom", wait: true},
+		{goos: "darwin", lookPath: installed, want: "terminal-notifier -title Rollbar #2: New item -message -boom -open https://rollbar.com/acme/api/items/2/"},
+		{goos: "darwin", lookPath: missing, want: "osascript -e on run argv -e display notification (item 2 of argv) with title (item 1 of argv) -e end run Rollbar #2: New item -boom"},
+		{goos: "windows", lookPath: missing, wantErr: "not supported on windows"},
+	}
+	for _, tc := range tests {
+		command, err := desktopNotifyCommand(tc.goos, notification, tc.lookPath)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("%s: expected %q error, got %v", tc.goos, tc.wantErr, err)
+			}
+			continue
+		}
+		if got := command.name + " " + strings.Join(command.args, " "); err != nil || got != tc.want || command.wait != tc.wait {
+			t.Fatalf("%s: desktopNotifyCommand() = %q (wait %v), %v; want %q", tc.goos, got, command.wait, err, tc.want)
+		}
+	}
+}
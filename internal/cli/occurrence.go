@@ -0,0 +1,277 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/sourcemap"
+)
+
+// occurrencePairLoader fetches the two occurrences a diff compares, older
+// (or first named) first.
+type occurrencePairLoader func(context.Context, app.OccurrenceDiffAPI) (rollbar.ItemInstance, rollbar.ItemInstance, error)
+
+type occurrenceDiffFlags struct {
+	First  bool
+	Latest bool
+}
+
+func newOccurrenceCmd(flags *rootFlags) *cobra.Command {
+	occurrenceCmd := &cobra.Command{Use: "occurrence", Short: "Inspect individual occurrences"}
+	occurrenceCmd.AddCommand(
+		newOccurrenceShowCmd(flags),
+		newOccurrenceDiffCmd(flags),
+		newOccurrenceTelemetryCmd(flags),
+	)
+	addRedactFlag(occurrenceCmd, flags)
+
+	return occurrenceCmd
+}
+
+type occurrenceShowFlags struct {
+	ByID              bool
+	SourceRoot        string
+	ResolveSourceMaps bool
+	MapDir            string
+	TraceStyle        string
+}
+
+func newOccurrenceShowCmd(flags *rootFlags) *cobra.Command {
+	showFlags := occurrenceShowFlags{}
+	showCmd := &cobra.Command{
+		Use:   "show <uuid|id>",
+		Short: "Show one occurrence and its full stack trace",
+		Long:  "Show one occurrence and its full stack trace. With --resolve-sourcemaps, minified JavaScript frames are rewritten to their original file, line, and function using .map files from --map-dir or, for http(s) frames, downloaded from each script's sourceMappingURL:\n\n  rollbaz occurrence show <uuid> --resolve-sourcemaps --map-dir dist/",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if err := validateTraceStyle(showFlags.TraceStyle); err != nil {
+				return err
+			}
+			return runOccurrenceShow(cmd.Context(), *flags, showFlags, args[0])
+		},
+	}
+	showCmd.Flags().BoolVar(&showFlags.ByID, "id", false, "Treat the argument as an occurrence id instead of a UUID")
+	showCmd.Flags().StringVar(&showFlags.SourceRoot, "source-root", "", "Local checkout to show source lines from under each stack frame")
+	showCmd.Flags().BoolVar(&showFlags.ResolveSourceMaps, "resolve-sourcemaps", false, "Rewrite minified JavaScript frames through source maps")
+	showCmd.Flags().StringVar(&showFlags.MapDir, "map-dir", "", "Directory of .map files to try before downloading (with --resolve-sourcemaps)")
+	showCmd.Flags().StringVar(&showFlags.TraceStyle, "trace-style", traceStyleAuto, "Print the trace like its language: auto, generic, or "+strings.Join(output.TraceStyleNames(), ", "))
+	_ = showCmd.RegisterFlagCompletionFunc("trace-style", cobra.FixedCompletions(append([]string{traceStyleAuto, traceStyleGeneric}, output.TraceStyleNames()...), cobra.ShellCompDirectiveNoFileComp))
+	_ = showCmd.MarkFlagDirname("source-root")
+	_ = showCmd.MarkFlagDirname("map-dir")
+
+	return showCmd
+}
+
+func runOccurrenceShow(parent context.Context, flags rootFlags, showFlags occurrenceShowFlags, reference string) error {
+	fetch, err := occurrenceFetcher(reference, showFlags.ByID)
+	if err != nil {
+		return err
+	}
+	scrubber, err := occurrenceScrubber(flags)
+	if err != nil {
+		return err
+	}
+	lookup, closeSource, err := openSourceRoot(showFlags.SourceRoot)
+	if err != nil {
+		return err
+	}
+	defer closeSource()
+	resolver, closeResolver, err := openSourceMaps(showFlags)
+	if err != nil {
+		return err
+	}
+	defer closeResolver()
+
+	ctx, cancel := context.WithTimeout(parent, 60*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	detail, err := runWithProgress(flags.Format, "Loading occurrence", func() (app.OccurrenceDetail, error) {
+		return loadOccurrenceDetail(ctx, client, fetch, scrubber, resolver)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	style := pickTraceStyle(showFlags.TraceStyle, detail)
+	human := output.RenderOccurrenceDetail(detail, output.TraceOptions{Context: true, Source: lookup, Style: style, Color: terminalColors(), InApp: flags.InApp})
+	return printOutput(flags, human, redact.Value(map[string]any{"occurrence": detail}, token))
+}
+
+// --trace-style values besides the language names: auto detects the
+// language, and generic prints the plain "at" lines.
+const (
+	traceStyleAuto    = "auto"
+	traceStyleGeneric = "generic"
+)
+
+func validateTraceStyle(name string) error {
+	if _, ok := output.TraceStyleNamed(name); ok || name == traceStyleAuto || name == traceStyleGeneric {
+		return nil
+	}
+
+	return fmt.Errorf("--trace-style must be %s, %s, or one of %s", traceStyleAuto, traceStyleGeneric, strings.Join(output.TraceStyleNames(), ", "))
+}
+
+func pickTraceStyle(name string, detail app.OccurrenceDetail) *output.TraceStyle {
+	if name == traceStyleAuto {
+		return output.DetectTraceStyle(detail.Language, detail.Body)
+	}
+	style, _ := output.TraceStyleNamed(name)
+
+	return style
+}
+
+type occurrenceFetch func(context.Context, *rollbar.Client) (rollbar.ItemInstance, error)
+
+func occurrenceFetcher(reference string, byID bool) (occurrenceFetch, error) {
+	if !byID {
+		return func(ctx context.Context, client *rollbar.Client) (rollbar.ItemInstance, error) {
+			return client.GetInstanceByUUID(ctx, reference)
+		}, nil
+	}
+
+	instanceID, err := parseOccurrenceID(reference)
+	if err != nil {
+		return nil, err
+	}
+
+	return func(ctx context.Context, client *rollbar.Client) (rollbar.ItemInstance, error) {
+		return client.GetInstance(ctx, instanceID)
+	}, nil
+}
+
+// loadOccurrenceDetail fetches an occurrence, scrubs it before anything reads
+// it, and resolves its frames when resolver is set.
+func loadOccurrenceDetail(ctx context.Context, client *rollbar.Client, fetch occurrenceFetch, scrubber *redact.Scrubber, resolver app.FrameResolver) (app.OccurrenceDetail, error) {
+	instance, err := fetch(ctx, client)
+	if err != nil {
+		return app.OccurrenceDetail{}, fmt.Errorf("get occurrence: %w", err)
+	}
+
+	detail := app.NewOccurrenceDetail(app.ScrubInstance(scrubber, instance))
+	if resolver != nil {
+		detail = app.ResolveSourceMaps(ctx, detail, resolver)
+	}
+
+	return detail, nil
+}
+
+// openSourceMaps builds the source map resolver for --resolve-sourcemaps; it
+// is nil when the flag is off.
+func openSourceMaps(showFlags occurrenceShowFlags) (app.FrameResolver, func(), error) {
+	if !showFlags.ResolveSourceMaps {
+		if showFlags.MapDir != "" {
+			return nil, nil, errors.New("--map-dir requires --resolve-sourcemaps")
+		}
+		return nil, func() {}, nil
+	}
+
+	resolver, err := sourcemap.NewResolver(showFlags.MapDir, true)
+	if err != nil {
+		return nil, nil, fmt.Errorf("--map-dir: %w", err)
+	}
+
+	return resolver, func() { _ = resolver.Close() }, nil
+}
+
+func parseOccurrenceID(value string) (domain.InstanceID, error) {
+	parsedID, err := strconv.ParseUint(value, 10, 64)
+	if err != nil {
+		return 0, fmt.Errorf("parse occurrence id: %w", err)
+	}
+	if parsedID == 0 {
+		return 0, errors.New("occurrence id must be greater than 0")
+	}
+
+	return domain.InstanceID(parsedID), nil
+}
+
+func newOccurrenceDiffCmd(flags *rootFlags) *cobra.Command {
+	diffFlags := occurrenceDiffFlags{}
+	diffCmd := &cobra.Command{
+		Use:   "diff <uuid-a> <uuid-b>",
+		Short: "Compare two occurrences' request, versions, and stack trace",
+		Long:  "Compare two occurrences' request params, versions, and stack frames, for example to see why a fixed error came back:\n\n  rollbaz occurrence diff <uuid-a> <uuid-b>\n  rollbaz occurrence diff 274 --first --latest",
+		Args:  cobra.RangeArgs(1, 2),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			load, err := occurrenceDiffLoader(flags, diffFlags, args)
+			if err != nil {
+				return err
+			}
+			return runOccurrenceDiff(cmd.Context(), *flags, load)
+		},
+	}
+	diffCmd.Flags().BoolVar(&diffFlags.First, "first", false, "Diff the item's first occurrence (with --latest)")
+	diffCmd.Flags().BoolVar(&diffFlags.Latest, "latest", false, "Diff against the item's latest occurrence (with --first)")
+
+	return diffCmd
+}
+
+func occurrenceDiffLoader(flags *rootFlags, diffFlags occurrenceDiffFlags, args []string) (occurrencePairLoader, error) {
+	if !diffFlags.First && !diffFlags.Latest {
+		if len(args) != 2 {
+			return nil, errors.New("pass two occurrence UUIDs, or an item counter with --first --latest")
+		}
+		return func(ctx context.Context, api app.OccurrenceDiffAPI) (rollbar.ItemInstance, rollbar.ItemInstance, error) {
+			return app.OccurrencesByUUID(ctx, api, args[0], args[1])
+		}, nil
+	}
+	if !diffFlags.First || !diffFlags.Latest || len(args) != 1 {
+		return nil, errors.New("--first and --latest are used together with one item counter or URL")
+	}
+
+	counter, err := parseItemCounter(flags, args[0])
+	if err != nil {
+		return nil, err
+	}
+
+	return func(ctx context.Context, api app.OccurrenceDiffAPI) (rollbar.ItemInstance, rollbar.ItemInstance, error) {
+		return app.FirstAndLatestOccurrences(ctx, api, counter)
+	}, nil
+}
+
+func runOccurrenceDiff(parent context.Context, flags rootFlags, load occurrencePairLoader) error {
+	scrubber, err := occurrenceScrubber(flags)
+	if err != nil {
+		return err
+	}
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	pair, err := runWithProgress(flags.Format, "Loading occurrences", func() ([2]rollbar.ItemInstance, error) {
+		before, after, err := load(ctx, client)
+		return [2]rollbar.ItemInstance{before, after}, err
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	// Scrub before diffing so secrets neither print nor show up as changes.
+	for index := range pair {
+		pair[index] = app.ScrubInstance(scrubber, pair[index])
+	}
+
+	diff := app.DiffOccurrences(pair[0], pair[1])
+	return printOutput(flags, output.RenderOccurrenceDiff(diff), redact.Value(diff, token))
+}
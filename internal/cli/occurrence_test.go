@@ -0,0 +1,141 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func newOccurrenceDiffHandler() http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+		case "/api/1/item/1755568172/":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1755568172,"counter":269,"first_occurrence_id":11,"last_occurrence_id":12}}`)
+		case "/api/1/instance/11", "/api/1/instance/uuid/u-1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":11,"data":{"uuid":"u-1","code_version":"v1","request":{"GET":{"page":"2"},"headers":{"Cookie":"sid=old"}}}}}`)
+		case "/api/1/instance/12", "/api/1/instance/uuid/u-2":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":12,"data":{"uuid":"u-2","code_version":"v2","request":{"GET":{"page":"3"},"headers":{"Cookie":"sid=new"}}}}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+		}
+	})
+}
+
+func TestOccurrenceDiffCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, newOccurrenceDiffHandler())
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "uuids", args: []string{"occurrence", "diff", "u-1", "u-2"}, want: "~ code_version: \"v1\" -> \"v2\""},
+		{name: "first latest", args: []string{"occurrence", "diff", "269", "--first", "--latest"}, want: "~ request.GET.page: \"2\" -> \"3\""},
+		{name: "json", args: []string{"occurrence", "diff", "u-1", "u-2", "--format", "json"}, want: `"path": "code_version"`},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		runRootCommand(t, tc.args...)
+		if !strings.Contains(stdout.String(), tc.want) {
+			t.Fatalf("%s: expected %q in output, got %q", tc.name, tc.want, stdout.String())
+		}
+		if strings.Contains(stdout.String(), "sid=") {
+			t.Fatalf("%s: expected cookies to be scrubbed: %s", tc.name, stdout.String())
+		}
+	}
+
+	for _, args := range [][]string{
+		{"occurrence", "diff", "u-1"},
+		{"occurrence", "diff", "269", "--first"},
+		{"occurrence", "diff", "u-1", "u-2", "--first", "--latest"},
+	} {
+		cmd := NewRootCmd()
+		cmd.SetArgs(args)
+		if err := cmd.Execute(); err == nil {
+			t.Fatalf("%v: expected error", args)
+		}
+	}
+}
+
+// serveOccurrence serves occurrence 11, u-1, a TypeError in minified
+// JavaScript with a personal email and a session cookie.
+func serveOccurrence(w http.ResponseWriter, r *http.Request) {
+	switch r.URL.Path {
+	case "/api/1/instance/uuid/u-1", "/api/1/instance/11":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":11,"data":{"uuid":"u-1","person":{"email":"jane@example.com"},"request":{"headers":{"Cookie":"sid=abc123"}},"body":{"trace":{"exception":{"class":"TypeError","message":"x is undefined"},"frames":[{"filename":"https://cdn.example.com/static/app.min.js","lineno":1,"colno":15,"method":"a"}]}}}}}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+	}
+}
+
+// writeSourceMapDir writes a source map for static/app.min.js that maps the
+// served frame to src/pay.ts, and returns its directory.
+func writeSourceMapDir(t *testing.T) string {
+	t.Helper()
+
+	mapDir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(mapDir, "static"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	sourceMap := `{"version":3,"sources":["src/pay.ts"],"names":["charge"],"mappings":"AAAAA,UAIEA"}`
+	if err := os.WriteFile(filepath.Join(mapDir, "static", "app.min.js.map"), []byte(sourceMap), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	return mapDir
+}
+
+func TestOccurrenceShowCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveOccurrence))
+	mapDir := writeSourceMapDir(t)
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "uuid", args: []string{"occurrence", "show", "u-1"}, want: "  at a (https://cdn.example.com/static/app.min.js:1:15)"},
+		{name: "id", args: []string{"occurrence", "show", "11", "--id"}, want: "TypeError: x is undefined"},
+		{name: "sourcemaps", args: []string{"occurrence", "show", "u-1", "--resolve-sourcemaps", "--map-dir", mapDir}, want: "  at charge (src/pay.ts:5:3)"},
+		{name: "json", args: []string{"occurrence", "show", "u-1", "--format", "json"}, want: `"uuid": "u-1"`},
+		{name: "detected style", args: []string{"occurrence", "show", "u-1"}, want: "\n    at a (https://cdn.example.com/static/app.min.js:1:15)"},
+		{name: "generic style", args: []string{"occurrence", "show", "u-1", "--trace-style", "generic"}, want: "\n  at a (https://cdn.example.com/static/app.min.js:1:15)"},
+		{name: "python style", args: []string{"occurrence", "show", "u-1", "--trace-style", "python"}, want: "  File \"https://cdn.example.com/static/app.min.js\", line 1, in a\nTypeError: x is undefined"},
+		{name: "redact", args: []string{"occurrence", "show", "u-1", "--redact", "--format", "json"}, want: `"email": "[email]"`},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		runRootCommand(t, tc.args...)
+		if !strings.Contains(stdout.String(), tc.want) {
+			t.Fatalf("%s: expected %q in output, got %q", tc.name, tc.want, stdout.String())
+		}
+		if strings.Contains(stdout.String(), "sid=abc123") {
+			t.Fatalf("%s: expected the cookie to be scrubbed: %s", tc.name, stdout.String())
+		}
+	}
+}
+
+func TestOccurrenceShowErrors(t *testing.T) {
+	setupServerAndStdout(t, http.HandlerFunc(serveOccurrence))
+	mapDir := writeSourceMapDir(t)
+
+	for _, args := range [][]string{
+		{"occurrence", "show", "u-1", "--map-dir", mapDir},
+		{"occurrence", "show", "0", "--id"},
+		{"occurrence", "show", "u-1", "--trace-style", "cobol"},
+		{"occurrence", "show", "u-1", "--resolve-sourcemaps", "--map-dir", filepath.Join(mapDir, "missing")},
+	} {
+		cmd := NewRootCmd()
+		cmd.SetArgs(args)
+		if err := cmd.Execute(); err == nil {
+			t.Fatalf("%v: expected error", args)
+		}
+	}
+}
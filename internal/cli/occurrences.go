@@ -0,0 +1,179 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"math"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func newOccurrencesCmd(flags *rootFlags) *cobra.Command {
+	full, columnValues := false, []string(nil)
+	occurrencesCmd := &cobra.Command{
+		Use:   "occurrences <item-counter|url>",
+		Short: "List an item's occurrences, newest first",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+
+			columns, err := app.ParseColumns(columnValues)
+			if err != nil {
+				return fmt.Errorf("parse --column: %w", err)
+			}
+
+			return runOccurrences(cmd.Context(), *flags, counter, app.OccurrenceOptions{Full: full, Columns: columns})
+		},
+	}
+	occurrencesCmd.Flags().BoolVar(&full, "full", false, "Include each occurrence's complete payload")
+	occurrencesCmd.Flags().StringArrayVar(&columnValues, "column", nil, "Add a column from each occurrence, e.g. data.request.url (repeatable)")
+	occurrencesCmd.AddCommand(newOccurrencesSampleCmd(flags))
+	addRedactFlag(occurrencesCmd, flags)
+
+	return occurrencesCmd
+}
+
+func newOccurrencesSampleCmd(flags *rootFlags) *cobra.Command {
+	count, dir := 0, ""
+	sampleCmd := &cobra.Command{
+		Use:   "sample <item-counter|url>",
+		Short: "Download occurrences spread across an item's lifetime",
+		Long:  "Write -n occurrences, evenly spaced from the newest to the oldest one Rollbar keeps, to --out as one JSON file each. Files are numbered oldest first, so diffing neighbours shows how the payload changed over time. Personal data is scrubbed as in occurrences --full:\n\n  rollbaz occurrences sample 1234 -n 20 --out samples/",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if count <= 0 {
+				return errors.New("-n must be positive")
+			}
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+
+			return runOccurrencesSample(cmd.Context(), *flags, counter, count, dir)
+		},
+	}
+	sampleCmd.Flags().IntVarP(&count, "count", "n", 20, "Number of occurrences to download")
+	sampleCmd.Flags().StringVar(&dir, "out", "", "Directory to write the JSON files to")
+	_ = sampleCmd.MarkFlagRequired("out")
+
+	return sampleCmd
+}
+
+func runOccurrences(parent context.Context, flags rootFlags, counter domain.ItemCounter, options app.OccurrenceOptions) error {
+	since, err := parseFilterTime(flags.Since)
+	if err != nil {
+		return fmt.Errorf("parse --since: %w", err)
+	}
+	if options.Redactor, err = piiRedactor(flags); err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	options.Limit, options.Since = flags.Limit, since
+	occurrences, err := runWithProgress(flags.Format, "Loading occurrences", func() ([]app.OccurrenceSummary, error) {
+		return service.Occurrences(ctx, counter, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	scrubber := redact.DefaultScrubber()
+	for index := range occurrences {
+		occurrences[index].Raw = scrubber.ScrubJSON(occurrences[index].Raw)
+	}
+
+	human := output.RenderOccurrenceListWithColumns(occurrences, app.ColumnPaths(options.Columns), terminalRenderWidth())
+	if options.Full {
+		human = output.RenderOccurrencesFull(occurrences)
+	}
+	jsonPayload := redact.Value(map[string]any{"occurrences": occurrences}, token)
+
+	return printOutput(flags, human, jsonPayload)
+}
+
+func runOccurrencesSample(parent context.Context, flags rootFlags, counter domain.ItemCounter, count int, dir string) error {
+	ctx, cancel := context.WithTimeout(parent, 2*time.Minute)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	sample, err := runWithProgress(flags.Format, "Sampling occurrences", func() (app.OccurrenceSample, error) {
+		return service.SampleOccurrences(ctx, counter, count)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	scrubber, err := occurrenceScrubber(flags)
+	if err != nil {
+		return err
+	}
+	files, err := writeOccurrenceSample(dir, sample, scrubber, token)
+	if err != nil {
+		return err
+	}
+
+	payload := map[string]any{"item_id": sample.ItemID, "available": sample.Available, "files": files}
+	return printOutput(flags, output.RenderOccurrenceSample(sample, files), payload)
+}
+
+// writeOccurrenceSample writes each sampled occurrence, scrubbed of personal
+// data and the token, to its own file named for its place in the sample, its
+// time, and its id.
+func writeOccurrenceSample(dir string, sample app.OccurrenceSample, scrubber *redact.Scrubber, token string) ([]string, error) {
+	dir = filepath.Clean(dir)
+	if err := os.MkdirAll(dir, 0o700); err != nil {
+		return nil, fmt.Errorf("create --out directory: %w", err)
+	}
+
+	files := make([]string, 0, len(sample.Occurrences))
+	for index, occurrence := range sample.Occurrences {
+		body := scrubber.ScrubJSON(occurrence.Raw)
+		var indented bytes.Buffer
+		if json.Indent(&indented, body, "", "  ") == nil {
+			body = indented.Bytes()
+		}
+
+		path := filepath.Join(dir, sampleFileName(index, occurrence))
+		if err := os.WriteFile(path, []byte(redact.String(string(body), token)+"\n"), 0o600); err != nil {
+			return nil, fmt.Errorf("write occurrence %d: %w", occurrence.ID, err)
+		}
+		files = append(files, path)
+	}
+
+	return files, nil
+}
+
+func sampleFileName(index int, occurrence rollbar.ItemInstance) string {
+	when := "unknown"
+	if occurrence.Timestamp != nil && *occurrence.Timestamp <= math.MaxInt64 {
+		when = time.Unix(int64(*occurrence.Timestamp), 0).UTC().Format("20060102T150405Z")
+	}
+
+	return fmt.Sprintf("%03d-%s-%d.json", index+1, when, occurrence.ID)
+}
@@ -0,0 +1,92 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestOccurrencesColumns(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+		case "/api/1/item/1755568172/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":4,"timestamp":1700000400,"data":{"request":{"url":"https://example.com/pay"}}}]}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+		}
+	}))
+
+	runRootCommand(t, "occurrences", "269", "--limit", "1", "--column", "data.request.url")
+	if out := stdout.String(); !strings.Contains(out, "DATA.REQUEST.URL") || !strings.Contains(out, "https://example.com/pay") {
+		t.Fatalf("expected the url column:\n%s", out)
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"occurrences", "269", "--column", ""})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "parse --column") {
+		t.Fatalf("expected a column error, got %v", err)
+	}
+}
+
+// serveSamples serves item 269 with four occurrences over two pages, the
+// oldest of which sent a session cookie.
+func serveSamples(w http.ResponseWriter, r *http.Request) {
+	page := r.URL.Query().Get("page")
+	switch {
+	case r.URL.Path == "/api/1/item_by_counter/269":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+	case r.URL.Path == "/api/1/item/1755568172/":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1755568172,"counter":269,"title":"boom","total_occurrences":4}}`)
+	case r.URL.Path == "/api/1/item/1755568172/instances" && page == "1":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":4,"timestamp":1700000400,"data":{"uuid":"u-4"}},{"id":3,"timestamp":1700000300,"data":{"uuid":"u-3"}}]}}`)
+	case r.URL.Path == "/api/1/item/1755568172/instances" && page == "2":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":2,"timestamp":1700000200,"data":{"uuid":"u-2"}},{"id":1,"timestamp":1700000100,"data":{"uuid":"u-1","request":{"headers":{"Cookie":"sid=abc123"}}}}]}}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+	}
+}
+
+// fileNames lists the names of the files in dir.
+func fileNames(t *testing.T, dir string) []string {
+	t.Helper()
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		t.Fatalf("ReadDir() error = %v", err)
+	}
+	names := make([]string, 0, len(entries))
+	for _, entry := range entries {
+		names = append(names, entry.Name())
+	}
+	return names
+}
+
+func TestOccurrencesSampleCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveSamples))
+
+	dir := filepath.Join(t.TempDir(), "samples")
+	runRootCommand(t, "occurrences", "sample", "269", "-n", "3", "--out", dir)
+	checkContains(t, "sample", stdout.String(), "sampled 3 of 4 occurrences")
+
+	names := fileNames(t, dir)
+	if got := strings.Join(names, ","); got != "001-20231114T221500Z-1.json,002-20231114T221820Z-3.json,003-20231114T222000Z-4.json" {
+		t.Fatalf("unexpected sample files %s", got)
+	}
+	body, err := os.ReadFile(filepath.Join(dir, names[0]))
+	if err != nil {
+		t.Fatalf("ReadFile() error = %v", err)
+	}
+	if !strings.Contains(string(body), `"uuid": "u-1"`) || strings.Contains(string(body), "abc123") {
+		t.Fatalf("expected a scrubbed, indented occurrence:\n%s", body)
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"occurrences", "sample", "269", "-n", "0", "--out", dir})
+	checkError(t, "sample -n 0", cmd.Execute(), "-n must be positive")
+}
@@ -0,0 +1,150 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"os/exec"
+	"runtime"
+	"strconv"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+var openBrowser = func(url string) error {
+	name, args := "xdg-open", []string{url}
+	switch runtime.GOOS {
+	case "darwin":
+		name = "open"
+	case "windows":
+		name, args = "rundll32", []string{"url.dll,FileProtocolHandler", url}
+	}
+
+	//nolint:gosec // G204: the launcher is fixed per platform; the URL is built by rollbaz.
+	cmd := exec.Command(name, args...)
+	if err := cmd.Start(); err != nil {
+		return fmt.Errorf("open browser: %w", err)
+	}
+
+	return cmd.Process.Release() //nolint:wrapcheck // Release only fails for an already-released process.
+}
+
+type openFlags struct {
+	Account string
+	Print   bool
+}
+
+func newOpenCmd(flags *rootFlags) *cobra.Command {
+	open := openFlags{}
+	openCmd := &cobra.Command{
+		Use:   "open <item-counter|url>",
+		Short: "Open an item in the Rollbar web UI",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if open.Account == "" {
+				open.Account = flags.Account
+			}
+			return runOpen(cmd.Context(), *flags, open, args[0])
+		},
+	}
+	openCmd.Flags().StringVar(&open.Account, "account", "", "Rollbar account slug for the item URL (default: profile account or $ROLLBAZ_ACCOUNT)")
+	openCmd.Flags().BoolVar(&open.Print, "print", false, "Print the URL instead of opening a browser")
+
+	return openCmd
+}
+
+// runOpen builds the item's URL from the account and project slugs when they
+// are known (from a pasted URL, or --account plus a --project naming a Rollbar
+// project) and otherwise links the latest occurrence.
+func runOpen(parent context.Context, flags rootFlags, open openFlags, arg string) error {
+	ref, err := domain.ParseItemRef(arg)
+	if err != nil {
+		return fmt.Errorf("parse item %q: %w", arg, err)
+	}
+
+	location := itemWebLocation(ref, open.Account, webProjectSlug(flags))
+
+	var link string
+	if location.Account != "" && location.Project != "" {
+		link = app.ItemWebURL(location, ref.Counter)
+	} else if link, err = lookupItemURL(parent, flags, ref, location); err != nil {
+		return err
+	}
+
+	if open.Print {
+		_, _ = fmt.Fprintln(stdoutWriter, link)
+		return nil
+	}
+
+	return openBrowser(link)
+}
+
+// itemWebLocation takes the slugs from a pasted item URL, falling back to
+// account (or $ROLLBAZ_ACCOUNT) and the project slug.
+func itemWebLocation(ref domain.ItemRef, account string, project string) app.WebLocation {
+	if ref.Account != "" {
+		return app.WebLocation{Account: ref.Account, Project: ref.Project}
+	}
+
+	return app.WebLocation{Account: firstNonEmpty(account, os.Getenv("ROLLBAZ_ACCOUNT")), Project: project}
+}
+
+func lookupItemURL(parent context.Context, flags rootFlags, ref domain.ItemRef, location app.WebLocation) (string, error) {
+	if ref.Project != "" && flags.Project == "" {
+		flags.Project, flags.ProjectFromURL = ref.Project, true
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return "", err
+	}
+	link, err := service.ItemURL(ctx, ref.Counter, location)
+	if err != nil {
+		return "", sanitizeError(err, token)
+	}
+
+	return link, nil
+}
+
+// webProjectSlug uses --project as the web slug only when it is resolved as a
+// Rollbar project slug, with an account token (see resolveProjectToken). A
+// configured project is named locally, as prod is by `project add prod ...`,
+// and a numeric project id has no slug without an extra lookup.
+func webProjectSlug(flags rootFlags) string {
+	if flags.Project == "" || resolveAccountToken(flags) == "" || isConfiguredProject(flags.Project) {
+		return ""
+	}
+	if _, err := strconv.ParseUint(flags.Project, 10, 64); err == nil {
+		return ""
+	}
+
+	return app.ProjectSlug(flags.Project)
+}
+
+// isConfiguredProject reports whether name is a project in the config file.
+func isConfiguredProject(name string) bool {
+	store, err := newConfigStore()
+	if err != nil {
+		return false
+	}
+	_, err = store.ResolveProject(name)
+
+	return err == nil
+}
+
+func firstNonEmpty(values ...string) string {
+	for _, value := range values {
+		if value != "" {
+			return value
+		}
+	}
+
+	return ""
+}
@@ -0,0 +1,71 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+func TestOpenCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveItemOccurrence))
+	t.Setenv("ROLLBAZ_ACCOUNT", "")
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "account-token")
+	runRootCommand(t, "project", "add", "prod", "--token", "token")
+	opened := stubBrowser(t)
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "pasted url", args: []string{"open", "https://app.rollbar.com/a/acme/fix/item/billing-api/12", "--print"}, want: "https://rollbar.com/acme/billing-api/items/12/"},
+		{name: "account and project", args: []string{"open", "269", "--account", "acme", "--project", "Billing API", "--print"}, want: "https://rollbar.com/acme/billing-api/items/269/"},
+		{name: "occurrence fallback", args: []string{"open", "269", "--print"}, want: "https://rollbar.com/occurrence/uuid/?uuid=0f1e-2d3c"},
+		{name: "configured project", args: []string{"open", "269", "--account", "acme", "--project", "prod", "--print"}, want: "https://rollbar.com/occurrence/uuid/?uuid=0f1e-2d3c"},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		runRootCommand(t, tc.args...)
+		if got := strings.TrimSpace(stdout.String()); got != tc.want {
+			t.Fatalf("%s: printed %q, want %q", tc.name, got, tc.want)
+		}
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "open", "269", "--account", "acme", "--project", "web")
+	if *opened != "https://rollbar.com/acme/web/items/269/" || stdout.Len() != 0 {
+		t.Fatalf("expected browser to open item URL without printing, got %q (stdout %q)", *opened, stdout.String())
+	}
+}
+
+// serveItemOccurrence serves item 269 with a single occurrence.
+func serveItemOccurrence(w http.ResponseWriter, r *http.Request) {
+	switch r.URL.Path {
+	case "/api/1/item_by_counter/269":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+	case "/api/1/item/1755568172/instances":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":1,"data":{"uuid":"0f1e-2d3c"}}],"page":1}}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+	}
+}
+
+// stubBrowser replaces openBrowser for the test and returns where the last
+// URL it was given is kept.
+func stubBrowser(t *testing.T) *string {
+	t.Helper()
+
+	opened := new(string)
+	original := openBrowser
+	openBrowser = func(url string) error {
+		*opened = url
+		return nil
+	}
+	t.Cleanup(func() {
+		openBrowser = original
+	})
+
+	return opened
+}
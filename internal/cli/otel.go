@@ -0,0 +1,124 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"maps"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/otel"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+type otelFlags struct {
+	Endpoint    string
+	Headers     []string
+	ServiceName string
+	Timeout     time.Duration
+}
+
+func newExportOTelCmd(flags *rootFlags) *cobra.Command {
+	export := otelFlags{}
+	otelCmd := &cobra.Command{
+		Use:   "otel <item-counter|url>",
+		Short: "Ship an item's occurrences to an OpenTelemetry collector",
+		Long:  "Convert an item's occurrences into OTLP log records and send them to a collector over OTLP/HTTP, newest first. trace_id and span_id custom fields, or a traceparent request header, link each record to its trace. The endpoint and headers default to $OTEL_EXPORTER_OTLP_ENDPOINT and $OTEL_EXPORTER_OTLP_HEADERS:\n\n  rollbaz export otel 274 --since 24h --endpoint http://otel-collector:4318",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+			return runExportOTel(cmd.Context(), *flags, export, counter, exportLimit(cmd, *flags))
+		},
+	}
+	otelCmd.Flags().StringVar(&export.Endpoint, "endpoint", "", "Collector OTLP/HTTP base URL (default: $OTEL_EXPORTER_OTLP_ENDPOINT or "+otel.DefaultEndpoint+")")
+	otelCmd.Flags().StringArrayVar(&export.Headers, "header", nil, "Request header as key=value, such as an API key (repeatable)")
+	otelCmd.Flags().StringVar(&export.ServiceName, "service-name", "", "service.name resource attribute (default: $OTEL_SERVICE_NAME or rollbar)")
+	otelCmd.Flags().DurationVar(&export.Timeout, "timeout", defaultExportTimeout, "Maximum time for the whole export")
+
+	return otelCmd
+}
+
+func runExportOTel(parent context.Context, flags rootFlags, export otelFlags, counter domain.ItemCounter, limit int) error {
+	client, resource, err := otelClient(export)
+	if err != nil {
+		return err
+	}
+	since, err := parseFilterTime(flags.Since)
+	if err != nil {
+		return fmt.Errorf("parse --since: %w", err)
+	}
+	redactor, err := piiRedactor(flags)
+	if err != nil {
+		return err
+	}
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, export.Timeout)
+	defer cancel()
+
+	shipped := 0
+	scrubber := redact.DefaultScrubber()
+	options := app.OccurrenceOptions{Limit: limit, Since: since, Full: true, Redactor: redactor}
+	_, err = runWithProgress(flags.Format, "Shipping occurrences", func() (struct{}, error) {
+		return struct{}{}, service.StreamOccurrences(ctx, counter, options, func(occurrences []app.OccurrenceSummary) error {
+			records := make([]otel.LogRecord, 0, len(occurrences))
+			for _, occurrence := range occurrences {
+				occurrence.Raw = scrubber.ScrubJSON(occurrence.Raw)
+				records = append(records, redactLogRecord(app.OccurrenceLogRecord(counter, occurrence), token))
+			}
+			shipped += len(records)
+			return client.Export(ctx, resource, records)
+		})
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, fmt.Sprintf("shipped %d occurrences of item %s to the collector", shipped, counter), map[string]any{"counter": counter, "shipped": shipped})
+}
+
+// otelClient applies the OTEL_* environment under the flags: --header values
+// override same-named OTEL_EXPORTER_OTLP_HEADERS entries.
+func otelClient(export otelFlags) (*otel.Client, map[string]string, error) {
+	if export.Timeout <= 0 {
+		return nil, nil, errors.New("--timeout must be positive")
+	}
+	headers, err := otel.ParseHeaders(os.Getenv("OTEL_EXPORTER_OTLP_HEADERS"))
+	if err != nil {
+		return nil, nil, fmt.Errorf("OTEL_EXPORTER_OTLP_HEADERS: %w", err)
+	}
+	flagHeaders, err := otel.ParseHeaders(strings.Join(export.Headers, ","))
+	if err != nil {
+		return nil, nil, fmt.Errorf("--header: %w", err)
+	}
+	maps.Copy(headers, flagHeaders)
+
+	client, err := otel.NewClient(firstNonEmpty(export.Endpoint, os.Getenv("OTEL_EXPORTER_OTLP_ENDPOINT")), headers)
+	if err != nil {
+		return nil, nil, fmt.Errorf("build otlp client: %w", err)
+	}
+	resource := map[string]string{"service.name": firstNonEmpty(export.ServiceName, os.Getenv("OTEL_SERVICE_NAME"), "rollbar")}
+
+	return client, resource, nil
+}
+
+func redactLogRecord(record otel.LogRecord, token string) otel.LogRecord {
+	record.Body = redact.String(record.Body, token)
+	for key, value := range record.Attributes {
+		record.Attributes[key] = redact.String(value, token)
+	}
+
+	return record
+}
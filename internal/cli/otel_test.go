@@ -0,0 +1,68 @@
+package cli
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+// newCollector starts an OTLP collector that accepts log batches from the
+// acme tenant with the k1 API key and appends them to batches.
+func newCollector(t *testing.T, batches *[]string) *httptest.Server {
+	t.Helper()
+
+	collector := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/v1/logs" || r.Header.Get("X-Tenant") != "acme" || r.Header.Get("Api-Key") != "k1" {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		var body json.RawMessage
+		_ = json.NewDecoder(r.Body).Decode(&body)
+		*batches = append(*batches, string(body))
+	}))
+	t.Cleanup(collector.Close)
+
+	return collector
+}
+
+func TestExportOTelCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, newExportHandler())
+	t.Setenv("OTEL_EXPORTER_OTLP_HEADERS", "x-tenant=acme")
+	t.Setenv("OTEL_SERVICE_NAME", "")
+
+	var batches []string
+	t.Setenv("OTEL_EXPORTER_OTLP_ENDPOINT", newCollector(t, &batches).URL)
+
+	runRootCommand(t, "export", "otel", "269", "--header", "api-key=k1")
+	if !strings.Contains(stdout.String(), "shipped 2 occurrences of item 269 to the collector") {
+		t.Fatalf("unexpected output: %q", stdout.String())
+	}
+	if len(batches) != 1 {
+		t.Fatalf("expected one batch, got %d", len(batches))
+	}
+	checkContains(t, "batch", batches[0], `"service.name"`, `{"stringValue":"rollbar"}`, `"timeUnixNano":"1700000100000000000"`, `uuid=u-2`)
+}
+
+func TestExportOTelCommandErrors(t *testing.T) {
+	_ = setupServerAndStdout(t, newExportHandler())
+	t.Setenv("OTEL_EXPORTER_OTLP_HEADERS", "x-tenant=acme")
+	t.Setenv("OTEL_SERVICE_NAME", "")
+
+	var batches []string
+	t.Setenv("OTEL_EXPORTER_OTLP_ENDPOINT", newCollector(t, &batches).URL)
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"export", "otel", "269"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "otlp collector returned status 401") {
+		t.Fatalf("expected collector error, got %v", err)
+	}
+
+	t.Setenv("OTEL_EXPORTER_OTLP_ENDPOINT", "collector:4318")
+	cmd = NewRootCmd()
+	cmd.SetArgs([]string{"export", "otel", "269"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "invalid otlp endpoint") {
+		t.Fatalf("expected endpoint error, got %v", err)
+	}
+}
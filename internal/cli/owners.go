@@ -0,0 +1,111 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/codeowners"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/source"
+)
+
+type ownersFlags struct {
+	CodeOwners string
+	SourceRoot string
+	Frames     int
+	Assign     bool
+}
+
+func newOwnersCmd(flags *rootFlags) *cobra.Command {
+	owners := ownersFlags{}
+	ownersCmd := &cobra.Command{
+		Use:   "owners <item-counter|url>",
+		Short: "Suggest an assignee for an item from CODEOWNERS",
+		Long:  "Match the top --frames frames of the item's latest stack trace that are in the checkout at --source-root against CODEOWNERS, rank the owners by how many of those frames each owns, and suggest the first that is a Rollbar user (@handles match usernames, emails match emails). --assign assigns the item to them. For example:\n\n  rollbaz item owners 42 --codeowners .github/CODEOWNERS --source-root . --assign",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+
+			return runItemOwners(cmd.Context(), *flags, counter, owners)
+		},
+	}
+	ownersCmd.Flags().StringVar(&owners.CodeOwners, "codeowners", "", "CODEOWNERS file (default: .github/CODEOWNERS, CODEOWNERS, or docs/CODEOWNERS in --source-root)")
+	ownersCmd.Flags().StringVar(&owners.SourceRoot, "source-root", ".", "Checkout the stack frames are placed in")
+	ownersCmd.Flags().IntVar(&owners.Frames, "frames", 3, "How many in-project frames to match, most recent call first")
+	ownersCmd.Flags().BoolVar(&owners.Assign, "assign", false, "Assign the item to the suggested owner")
+	_ = ownersCmd.MarkFlagFilename("codeowners")
+	_ = ownersCmd.MarkFlagDirname("source-root")
+
+	return ownersCmd
+}
+
+func runItemOwners(parent context.Context, flags rootFlags, counter domain.ItemCounter, owners ownersFlags) error {
+	rules, err := loadCodeOwners(owners)
+	if err != nil {
+		return err
+	}
+	root, err := source.Open(owners.SourceRoot)
+	if err != nil {
+		return fmt.Errorf("--source-root: %w", err)
+	}
+	defer func() { _ = root.Close() }()
+	if owners.Assign {
+		if err := confirmWrite(flags, "assign", []domain.ItemCounter{counter}); err != nil {
+			return err
+		}
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	options := app.OwnersOptions{Locate: flags.InApp.Filter(root.Path), Owners: ownersOf(rules), Frames: owners.Frames, Assign: owners.Assign}
+	result, err := runWithProgress(flags.Format, "Matching CODEOWNERS", func() (app.OwnersResult, error) {
+		return app.SuggestOwners(ctx, client, counter, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderOwnersResult(result), redact.Value(result, token))
+}
+
+// loadCodeOwners reads --codeowners, or the CODEOWNERS file GitHub would use
+// in --source-root.
+func loadCodeOwners(owners ownersFlags) (*codeowners.Ruleset, error) {
+	path := owners.CodeOwners
+	if path == "" {
+		found, err := codeowners.Find(owners.SourceRoot)
+		if err != nil {
+			return nil, fmt.Errorf("%w; pass --codeowners", err)
+		}
+		path = found
+	}
+
+	rules, err := codeowners.Load(path)
+	if err != nil {
+		return nil, fmt.Errorf("--codeowners: %w", err)
+	}
+
+	return rules, nil
+}
+
+func ownersOf(rules *codeowners.Ruleset) func(string) (string, []string, bool) {
+	return func(path string) (string, []string, bool) {
+		rule, ok := rules.Match(path)
+		return rule.Pattern, rule.Owners, ok
+	}
+}
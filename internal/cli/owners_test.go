@@ -0,0 +1,93 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// ownersHandler serves item 42 with a frame in pay/charge.py and user ana,
+// and records the body of an item update in patched.
+func ownersHandler(patched *string) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.URL.Path == "/api/1/item_by_counter/42":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":9}}`)
+		case r.Method == http.MethodPatch && r.URL.Path == "/api/1/item/9":
+			body, _ := io.ReadAll(r.Body)
+			*patched = string(body)
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+		case r.URL.Path == "/api/1/item/9/":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":9,"counter":42,"title":"KeyError: 'cart'","status":"active"}}`)
+		case r.URL.Path == "/api/1/item/9/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":1,"body":{"trace":{"frames":[{"filename":"/srv/app/pay/charge.py","lineno":12,"method":"charge"},{"filename":"/usr/lib/python3/json.py","lineno":300}],"exception":{"class":"KeyError"}}}}]}}`)
+		case r.URL.Path == "/api/1/users":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"users":[{"id":7,"username":"ana","email":"ana@example.com"}]}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s"}`, r.Method, r.URL.Path)
+		}
+	}
+}
+
+// writeOwnersCheckout returns a checkout holding pay/charge.py and a
+// CODEOWNERS file that gives /pay/ to ana.
+func writeOwnersCheckout(t *testing.T) string {
+	t.Helper()
+
+	checkout := writeCheckout(t)
+	if err := os.MkdirAll(filepath.Join(checkout, ".github"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(checkout, ".github", "CODEOWNERS"), []byte("* @acme/platform\n/pay/ @ana\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	return checkout
+}
+
+func TestItemOwnersCommand(t *testing.T) {
+	patched := ""
+	stdout := setupServerAndStdout(t, ownersHandler(&patched))
+	checkout := writeOwnersCheckout(t)
+
+	runRootCommand(t, "item", "owners", "42", "--source-root", checkout)
+	if rendered := stdout.String(); !strings.Contains(rendered, "Suggested assignee: ana <ana@example.com>") || !strings.Contains(rendered, "pay/charge.py:12") || patched != "" {
+		t.Fatalf("unexpected suggestion:\n%s", rendered)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "item", "owners", "42", "--source-root", checkout, "--assign", "--yes", "--format", "json")
+	if !strings.Contains(stdout.String(), `"assigned": true`) || !strings.Contains(patched, `"assigned_user_id":7`) {
+		t.Fatalf("unexpected assignment %q:\n%s", patched, stdout.String())
+	}
+}
+
+func TestItemOwnersCommandErrors(t *testing.T) {
+	patched := ""
+	setupServerAndStdout(t, ownersHandler(&patched))
+	checkout := writeOwnersCheckout(t)
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "no codeowners", args: []string{"item", "owners", "42", "--source-root", t.TempDir()}, want: "pass --codeowners"},
+		{name: "missing codeowners", args: []string{"item", "owners", "42", "--codeowners", filepath.Join(checkout, "missing")}, want: "--codeowners: read CODEOWNERS"},
+		{name: "missing source root", args: []string{"item", "owners", "42", "--codeowners", filepath.Join(checkout, ".github", "CODEOWNERS"), "--source-root", filepath.Join(checkout, "missing")}, want: "--source-root"},
+		{name: "assign without confirmation", args: []string{"item", "owners", "42", "--source-root", checkout, "--assign"}, want: "confirmation required"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			cmd := NewRootCmd()
+			cmd.SetArgs(tt.args)
+			if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tt.want) {
+				t.Fatalf("expected %q error, got %v", tt.want, err)
+			}
+		})
+	}
+}
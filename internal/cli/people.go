@@ -0,0 +1,66 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const defaultPeopleOccurrences = 1000
+
+func newPeopleCmd(flags *rootFlags) *cobra.Command {
+	maxOccurrences := 0
+	peopleCmd := &cobra.Command{
+		Use:   "people <item-counter|url>",
+		Short: "Show the users an item affected",
+		Long:  "Read an item's occurrences, newest first, up to --max-occurrences or back to --since, and tally the person each reported: unique people, the --limit most affected with their first and last occurrence, and how many occurrences had no person. For example:\n\n  rollbaz item people 42 --since 7d --limit 20",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if maxOccurrences <= 0 {
+				return errors.New("--max-occurrences must be positive")
+			}
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+
+			return runPeople(cmd.Context(), *flags, counter, maxOccurrences)
+		},
+	}
+	peopleCmd.Flags().IntVar(&maxOccurrences, "max-occurrences", defaultPeopleOccurrences, "Most occurrences to read")
+
+	return peopleCmd
+}
+
+func runPeople(parent context.Context, flags rootFlags, counter domain.ItemCounter, maxOccurrences int) error {
+	since, err := parseFilterTime(flags.Since)
+	if err != nil {
+		return fmt.Errorf("parse --since: %w", err)
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 2*time.Minute)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	options := app.PeopleOptions{MaxOccurrences: maxOccurrences, Since: since, Top: flags.Limit}
+	people, err := runWithProgress(flags.Format, "Reading occurrences", func() (app.ItemPeople, error) {
+		return service.ItemPeople(ctx, counter, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderItemPeople(people), redact.Value(people, token))
+}
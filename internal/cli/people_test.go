@@ -0,0 +1,37 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"testing"
+)
+
+// servePeople serves item 42 with two occurrences from ann and one
+// without a person.
+func servePeople(w http.ResponseWriter, r *http.Request) {
+	switch {
+	case r.URL.Path == "/api/1/item_by_counter/42":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1042}}`)
+	case r.URL.Path == "/api/1/item/1042/instances" && r.URL.Query().Get("page") == "1":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":3,"timestamp":1700000300,"data":{"person":{"id":"u1","username":"ann"}}},{"id":2,"timestamp":1700000200,"data":{}},{"id":1,"timestamp":1700000100,"data":{"person":{"id":"u1"}}}]}}`)
+	case r.URL.Path == "/api/1/item/1042/instances":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[]}}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+	}
+}
+
+func TestItemPeopleCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(servePeople))
+
+	runRootCommand(t, "item", "people", "42")
+	checkContains(t, "people", stdout.String(), "1 unique people, 1 occurrences without a person", "ann")
+
+	stdout.Reset()
+	runRootCommand(t, "item", "people", "42", "--max-occurrences", "1", "--format", "json")
+	checkContains(t, "people json", stdout.String(), `"scanned": 1`, `"first_affected": 1700000300`)
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"item", "people", "42", "--max-occurrences", "0"})
+	checkError(t, "people --max-occurrences 0", cmd.Execute(), "--max-occurrences")
+}
@@ -0,0 +1,80 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/inapp"
+)
+
+var profilePath = config.DefaultProfilePath
+
+// applyProfile fills in flags the user did not pass from the profile chosen by
+// --profile, ROLLBAZ_PROFILE, or default_profile in config.toml.
+func applyProfile(cmd *cobra.Command, flags *rootFlags) error {
+	name := flags.Profile
+	if name == "" {
+		name = os.Getenv("ROLLBAZ_PROFILE")
+	}
+
+	path, err := profilePath()
+	if err != nil {
+		if name == "" {
+			return nil
+		}
+		return err
+	}
+
+	file, err := config.LoadProfiles(path)
+	if err != nil {
+		return err
+	}
+	flags.Jira, flags.RedactRules = file.Jira, file.Redact
+	profile, ok, err := file.Select(name)
+	if err != nil {
+		return err
+	}
+	if flags.InApp, err = inAppRules(path, file, profile); err != nil || !ok {
+		return err
+	}
+
+	setFlagDefault(cmd, "token", &flags.Token, profile.Token)
+	setFlagDefault(cmd, "project", &flags.Project, profile.Project)
+	setFlagDefault(cmd, "env", &flags.Environment, profile.Environment)
+	setFlagDefault(cmd, "format", &flags.Format, profile.Format)
+	flags.BaseURL = profile.BaseURL
+	flags.AccountToken = profile.AccountToken
+	flags.Account = profile.Account
+	flags.GitHubToken = profile.GitHubToken
+	flags.GitHubURL = profile.GitHubURL
+	flags.SlackWebhookURL = profile.SlackWebhookURL
+	flags.SlackToken = profile.SlackToken
+	flags.SlackChannel = profile.SlackChannel
+
+	return nil
+}
+
+// inAppRules compiles the profile's in_app patterns, or the top-level ones
+// when it has none.
+func inAppRules(path string, file config.ProfileFile, profile config.Profile) (*inapp.Rules, error) {
+	patterns := file.InApp
+	if profile.InApp != nil {
+		patterns = profile.InApp
+	}
+	rules, err := inapp.Parse(patterns)
+	if err != nil {
+		return nil, fmt.Errorf("%s: in_app: %w", path, err)
+	}
+
+	return rules, nil
+}
+
+func setFlagDefault(cmd *cobra.Command, name string, target *string, value string) {
+	if value == "" || cmd.Flags().Changed(name) {
+		return
+	}
+	*target = value
+}
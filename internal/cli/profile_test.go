@@ -0,0 +1,97 @@
+package cli
+
+import (
+	"bytes"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func setProfilePath(t *testing.T, path string) {
+	t.Helper()
+	original := profilePath
+	profilePath = func() (string, error) {
+		return path, nil
+	}
+	t.Cleanup(func() {
+		profilePath = original
+	})
+}
+
+func writeProfiles(t *testing.T, body string) {
+	t.Helper()
+	path := filepath.Join(t.TempDir(), "config.toml")
+	if err := os.WriteFile(path, []byte(body), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	setProfilePath(t, path)
+}
+
+func TestProfileSuppliesTokenBaseURLAndFormat(t *testing.T) {
+	t.Setenv("ROLLBAR_ACCESS_TOKEN", "")
+	setNoConfigStore(t)
+
+	server := httptest.NewServer(newSuccessHandler(t))
+	t.Cleanup(server.Close)
+	writeProfiles(t, "default_profile = \"other\"\n\n[profiles.other]\ntoken = \"unused\"\n\n[profiles.work]\ntoken = \"profile-token\"\nbase_url = \""+server.URL+"/api/1\"\nformat = \"json\"\n")
+
+	stdout := &bytes.Buffer{}
+	stdoutWriter = stdout
+	t.Cleanup(func() {
+		stdoutWriter = os.Stdout
+	})
+
+	tests := []struct {
+		name string
+		env  string
+		args []string
+		want string
+	}{
+		{name: "flag", args: []string{"--profile", "work", "show", "269"}, want: `"counter": 269`},
+		{name: "env", env: "work", args: []string{"show", "269"}, want: `"counter": 269`},
+		{name: "flag overrides profile format", args: []string{"--profile", "work", "--format", "human", "show", "269"}, want: "RST_STREAM"},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		t.Setenv("ROLLBAZ_PROFILE", tc.env)
+		runRootCommand(t, tc.args...)
+		if !strings.Contains(stdout.String(), tc.want) {
+			t.Fatalf("%s: output missing %q:\n%s", tc.name, tc.want, stdout.String())
+		}
+	}
+	if strings.Contains(stdout.String(), `"main_error"`) {
+		t.Fatalf("expected human output when --format is passed:\n%s", stdout.String())
+	}
+}
+
+func TestProfileErrors(t *testing.T) {
+	tests := []struct {
+		name    string
+		body    string
+		args    []string
+		wantErr string
+	}{
+		{name: "unknown profile", body: "[profiles.work]\n", args: []string{"--profile", "play", "active"}, wantErr: `profile "play" not found (known: work)`},
+		{name: "no config file", args: []string{"--profile", "work", "active"}, wantErr: `profile "work" not found (known: none)`},
+		{name: "bad file", body: "[profiles.work]\ntoken = abc\n", args: []string{"active"}, wantErr: "line 2"},
+		{name: "bad profile format", body: "default_profile = \"work\"\n[profiles.work]\nformat = \"xml\"\n", args: []string{"active"}, wantErr: "xml"},
+		{name: "bad in_app", body: "in_app = [\"src/[ab]/**\"]\n", args: []string{"active"}, wantErr: `in_app: unsupported pattern "src/[ab]/**"`},
+	}
+
+	for _, tc := range tests {
+		t.Setenv("ROLLBAZ_PROFILE", "")
+		if tc.body == "" {
+			setProfilePath(t, filepath.Join(t.TempDir(), "missing.toml"))
+		} else {
+			writeProfiles(t, tc.body)
+		}
+
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+			t.Fatalf("%s: error = %v, want %q", tc.name, err, tc.wantErr)
+		}
+	}
+}
@@ -0,0 +1,188 @@
+package cli
+
+import (
+	"errors"
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/config"
+)
+
+func newProjectCmd() *cobra.Command {
+	projectCmd := &cobra.Command{Use: "project", Short: "Manage configured Rollbar projects"}
+	projectCmd.AddCommand(
+		newProjectAddCmd(),
+		newProjectListCmd(),
+		newProjectUseCmd(),
+		newProjectNextCmd(),
+		newProjectRemoveCmd(),
+	)
+
+	return projectCmd
+}
+
+func newProjectAddCmd() *cobra.Command {
+	addToken := ""
+	addCmd := &cobra.Command{
+		Use:   "add <name>",
+		Short: "Add or update a project token",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if err := withConfigStore(func(store *config.Store) error {
+				return store.AddProject(args[0], addToken)
+			}); err != nil {
+				return fmt.Errorf("add project: %w", err)
+			}
+			return nil
+		},
+	}
+	addCmd.Flags().StringVar(&addToken, "token", "", "Project token")
+	_ = addCmd.MarkFlagRequired("token")
+
+	return addCmd
+}
+
+func newProjectListCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:   "list",
+		Short: "List configured projects",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return withConfigStore(printProjects)
+		},
+	}
+}
+
+func newProjectUseCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:               "use <name>",
+		Short:             "Set active project",
+		Args:              cobra.ExactArgs(1),
+		ValidArgsFunction: completeConfiguredProject,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if err := withConfigStore(func(store *config.Store) error {
+				return store.UseProject(args[0])
+			}); err != nil {
+				return fmt.Errorf("use project: %w", err)
+			}
+			return nil
+		},
+	}
+}
+
+func newProjectNextCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:   "next",
+		Short: "Cycle active project",
+		RunE: func(cmd *cobra.Command, args []string) error {
+			name, err := cycleProject()
+			if err != nil {
+				return fmt.Errorf("cycle project: %w", err)
+			}
+			_, _ = fmt.Fprintln(stdoutWriter, name)
+			return nil
+		},
+	}
+}
+
+func newProjectRemoveCmd() *cobra.Command {
+	removeAll := false
+	removeCmd := &cobra.Command{
+		Use:   "remove [name]",
+		Short: "Remove configured project",
+		Args: func(cmd *cobra.Command, args []string) error {
+			return validateProjectRemoveArgs(removeAll, cmd, args)
+		},
+		ValidArgsFunction: completeConfiguredProject,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runProjectRemove(removeAll, args)
+		},
+	}
+	removeCmd.Flags().BoolVar(&removeAll, "all", false, "Remove all configured projects and tokens")
+
+	return removeCmd
+}
+
+func validateProjectRemoveArgs(removeAll bool, cmd *cobra.Command, args []string) error {
+	if removeAll {
+		if len(args) > 0 {
+			return errors.New("cannot use --all with a project name")
+		}
+		return nil
+	}
+	if len(args) == 0 {
+		return errors.New("specify a project name or use --all")
+	}
+	if len(args) > 1 {
+		return cobra.MaximumNArgs(1)(cmd, args)
+	}
+
+	return nil
+}
+
+func runProjectRemove(removeAll bool, args []string) error {
+	if removeAll {
+		if err := withConfigStore(func(store *config.Store) error {
+			return store.RemoveAllProjects()
+		}); err != nil {
+			return fmt.Errorf("remove projects: %w", err)
+		}
+		return nil
+	}
+
+	if err := withConfigStore(func(store *config.Store) error {
+		return store.RemoveProject(args[0])
+	}); err != nil {
+		return fmt.Errorf("remove project: %w", err)
+	}
+
+	return nil
+}
+
+func withConfigStore(action func(*config.Store) error) error {
+	store, err := newConfigStore()
+	if err != nil {
+		return err
+	}
+
+	return action(store)
+}
+
+func printProjects(store *config.Store) error {
+	file, err := store.Load()
+	if err != nil {
+		return fmt.Errorf("load config: %w", err)
+	}
+
+	if len(file.Projects) == 0 {
+		_, _ = fmt.Fprintln(stdoutWriter, "no configured projects")
+		return nil
+	}
+
+	for _, project := range file.Projects {
+		prefix := "  "
+		if project.Name == file.ActiveProject {
+			prefix = "* "
+		}
+		_, _ = fmt.Fprintf(stdoutWriter, "%s%s\n", prefix, project.Name)
+	}
+
+	return nil
+}
+
+func cycleProject() (string, error) {
+	var name string
+	err := withConfigStore(func(store *config.Store) error {
+		next, err := store.CycleProject()
+		if err != nil {
+			return fmt.Errorf("cycle project: %w", err)
+		}
+		name = next
+		return nil
+	})
+	if err != nil {
+		return "", err
+	}
+
+	return name, nil
+}
@@ -0,0 +1,93 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const projectResolveTimeout = 15 * time.Second
+
+var newProjectCache = func() (app.ProjectIDCache, error) {
+	return config.NewProjectCache()
+}
+
+// noProjectCache is used when the user cache directory is unavailable; every
+// slug is then looked up through the API.
+type noProjectCache struct{}
+
+func (noProjectCache) Lookup(string) (uint64, bool) { return 0, false }
+
+func (noProjectCache) Store(string, uint64) error { return nil }
+
+func resolveAccountToken(flags rootFlags) string {
+	if flags.AccountToken != "" {
+		return flags.AccountToken
+	}
+
+	return os.Getenv("ROLLBAR_ACCOUNT_TOKEN")
+}
+
+// resolveProjectToken treats --project as a Rollbar project id or slug and
+// fetches that project's token with the account token.
+func resolveProjectToken(flags rootFlags, accountToken string) (string, error) {
+	project, err := resolveProject(flags, accountToken)
+	if err != nil {
+		return "", err
+	}
+
+	return project.Token, nil
+}
+
+// resolveProject looks --project up as a Rollbar project id or slug with the
+// account token.
+func resolveProject(flags rootFlags, accountToken string) (app.ResolvedProject, error) {
+	client, err := newClientWithToken(flags, accountToken)
+	if err != nil {
+		return app.ResolvedProject{}, err
+	}
+	cache, err := newProjectCache()
+	if err != nil {
+		cache = noProjectCache{}
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), projectResolveTimeout)
+	defer cancel()
+
+	project, err := app.ResolveProject(ctx, client, cache, flags.Project)
+	if err != nil {
+		return app.ResolvedProject{}, errors.New("resolve project: " + redact.String(err.Error(), accountToken))
+	}
+
+	return project, nil
+}
+
+// buildAccountClient returns an account token client and the id of the
+// --project it manages, for project settings only an account token can
+// change. what names the operation in errors.
+func buildAccountClient(flags rootFlags, what string) (*rollbar.Client, uint64, string, error) {
+	accountToken := resolveAccountToken(flags)
+	if accountToken == "" {
+		return nil, 0, "", fmt.Errorf("%s need an account token: set ROLLBAR_ACCOUNT_TOKEN or account_token in the profile", what)
+	}
+	if flags.Project == "" {
+		return nil, 0, "", fmt.Errorf("%s need the Rollbar project: pass --project", what)
+	}
+	project, err := resolveProject(flags, accountToken)
+	if err != nil {
+		return nil, 0, "", err
+	}
+	client, err := newClientWithToken(flags, accountToken)
+	if err != nil {
+		return nil, 0, "", err
+	}
+
+	return client, project.ID, accountToken, nil
+}
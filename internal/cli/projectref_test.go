@@ -0,0 +1,103 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/config"
+)
+
+func newProjectLookupHandler(t *testing.T) http.Handler {
+	t.Helper()
+	items := newSuccessHandler(t)
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		token := r.Header.Get("X-Rollbar-Access-Token")
+		switch r.URL.Path {
+		case "/api/1/projects":
+			if token != "account-token" {
+				w.WriteHeader(http.StatusUnauthorized)
+				_, _ = fmt.Fprint(w, `{"err":1,"message":"account token required"}`)
+				return
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":1,"name":"Web"},{"id":2,"name":"Billing API"}]}`)
+		case "/api/1/project/2/access_tokens":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"access_token":"billing-read","scopes":["read"],"status":"enabled"}]}`)
+		default:
+			if token != "billing-read" {
+				w.WriteHeader(http.StatusUnauthorized)
+				_, _ = fmt.Fprintf(w, `{"err":1,"message":"wrong token %s"}`, token)
+				return
+			}
+			items.ServeHTTP(w, r)
+		}
+	})
+}
+
+func TestProjectSlugResolution(t *testing.T) {
+	stdout := setupServerAndStdout(t, newProjectLookupHandler(t))
+	setNoConfigStore(t)
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "account-token")
+
+	cachePath := filepath.Join(t.TempDir(), "projects.json")
+	original := newProjectCache
+	newProjectCache = func() (app.ProjectIDCache, error) {
+		return config.NewProjectCacheAtPath(cachePath), nil
+	}
+	t.Cleanup(func() {
+		newProjectCache = original
+	})
+
+	for _, project := range []string{"billing-api", "2"} {
+		stdout.Reset()
+		runRootCommand(t, "--project", project, "item", "show", "269")
+		if !strings.Contains(stdout.String(), "RST_STREAM") {
+			t.Fatalf("--project %s: unexpected output:\n%s", project, stdout.String())
+		}
+	}
+	if id, ok := config.NewProjectCacheAtPath(cachePath).Lookup("billing-api"); !ok || id != 2 {
+		t.Fatalf("expected cached slug, got %d %v", id, ok)
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"--project", "mobile", "item", "show", "269"})
+	err := cmd.Execute()
+	if err == nil || !strings.Contains(err.Error(), `no project named "mobile"`) {
+		t.Fatalf("expected unknown project error, got %v", err)
+	}
+}
+
+func TestItemURLSelectsProject(t *testing.T) {
+	stdout := setupServerAndStdout(t, newProjectLookupHandler(t))
+	setNoConfigStore(t)
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "account-token")
+
+	original := newProjectCache
+	newProjectCache = func() (app.ProjectIDCache, error) {
+		return noProjectCache{}, nil
+	}
+	t.Cleanup(func() {
+		newProjectCache = original
+	})
+
+	runRootCommand(t, "item", "show", "https://rollbar.com/acme/Billing-API/items/269/")
+	if !strings.Contains(stdout.String(), "RST_STREAM") {
+		t.Fatalf("unexpected output:\n%s", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"item", "resolve", "https://rollbar.com/acme/web/items/1/", "https://rollbar.com/acme/billing-api/items/2/", "--yes"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "different projects") {
+		t.Fatalf("expected mixed project error, got %v", err)
+	}
+
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "")
+	cmd = NewRootCmd()
+	cmd.SetArgs([]string{"item", "resolve", "https://rollbar.com/acme/billing-api/items/12/", "--yes"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), `project "billing-api" from the item URL is not configured`) {
+		t.Fatalf("expected an unmapped project error, got %v", err)
+	}
+}
@@ -0,0 +1,184 @@
+package cli
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func newQueriesCmd(flags *rootFlags) *cobra.Command {
+	queriesCmd := &cobra.Command{Use: "queries", Short: "Manage saved item queries in config.toml"}
+	queriesCmd.AddCommand(
+		newQueriesListCmd(flags),
+		newQueriesAddCmd(),
+		newQueriesRemoveCmd(),
+	)
+
+	return queriesCmd
+}
+
+func newQueriesListCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "list",
+		Short: "List saved queries",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			queries, err := loadSavedQueries()
+			if err != nil {
+				return err
+			}
+			return printOutput(*flags, renderSavedQueries(queries), map[string]any{"queries": queries})
+		},
+	}
+}
+
+func newQueriesAddCmd() *cobra.Command {
+	query := config.SavedQuery{}
+	levels := []string{}
+	addCmd := &cobra.Command{
+		Use:   "add <name>",
+		Short: "Save filters under a name for `items list --saved`",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			query.Name = strings.TrimSpace(args[0])
+			query.Level = strings.Join(levels, ",")
+			if err := validateSavedQuery(query); err != nil {
+				return err
+			}
+			path, err := profilePath()
+			if err != nil {
+				return err
+			}
+			if err := config.SaveQuery(path, query); err != nil {
+				return fmt.Errorf("save query: %w", err)
+			}
+			_, _ = fmt.Fprintf(stdoutWriter, "saved query %q\n", query.Name)
+			return nil
+		},
+	}
+	addCmd.Flags().StringVar(&query.Status, "status", "", "Item status: active, resolved, muted, or archived")
+	addCmd.Flags().StringSliceVar(&levels, "level", nil, "Levels: debug, info, warning, error, critical (repeatable)")
+	addCmd.Flags().StringVar(&query.Environment, "env", "", "Environment")
+	addCmd.Flags().StringVar(&query.Query, "query", "", "Rollbar free-text item search")
+	addCmd.Flags().StringVar(&query.Since, "since", "", "Last seen within, relative like 24h/7d")
+	addCmd.Flags().StringVar(&query.Sort, "sort", "", "Sort by last-seen, occurrences, counter, or level")
+
+	return addCmd
+}
+
+func newQueriesRemoveCmd() *cobra.Command {
+	return &cobra.Command{
+		Use:               "rm <name>",
+		Aliases:           []string{"remove"},
+		Short:             "Remove a saved query",
+		Args:              cobra.ExactArgs(1),
+		ValidArgsFunction: completeSavedQueryArg,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			path, err := profilePath()
+			if err != nil {
+				return err
+			}
+			if err := config.RemoveQuery(path, args[0]); err != nil {
+				return fmt.Errorf("remove query: %w", err)
+			}
+			_, _ = fmt.Fprintf(stdoutWriter, "removed query %q\n", args[0])
+			return nil
+		},
+	}
+}
+
+func validateSavedQuery(query config.SavedQuery) error {
+	if query.Level != "" {
+		for _, level := range strings.Split(query.Level, ",") {
+			if rollbar.Level(strings.ToLower(strings.TrimSpace(level))).Severity() == 0 {
+				return fmt.Errorf("unsupported level %q", level)
+			}
+		}
+	}
+	if _, err := app.ParseItemSort(query.Sort); err != nil {
+		return fmt.Errorf("parse --sort: %w", err)
+	}
+	if _, err := parseFilterTime(query.Since); err != nil {
+		return fmt.Errorf("parse --since: %w", err)
+	}
+
+	return nil
+}
+
+// applySavedQuery fills list filters from a saved query. Flags given on the
+// command line win; the query wins over profile defaults.
+func applySavedQuery(cmd *cobra.Command, flags *rootFlags, listFlags *itemsListFlags, name string) error {
+	file, err := loadProfileFile()
+	if err != nil {
+		return err
+	}
+	query, ok := file.Queries[name]
+	if !ok {
+		return fmt.Errorf("saved query %q not found (see `rollbaz queries list`)", name)
+	}
+
+	setFlagDefault(cmd, "status", &flags.Status, query.Status)
+	setFlagDefault(cmd, "env", &flags.Environment, query.Environment)
+	setFlagDefault(cmd, "since", &flags.Since, query.Since)
+	setFlagDefault(cmd, "query", &listFlags.Query, query.Query)
+	setFlagDefault(cmd, "sort", &listFlags.Sort, query.Sort)
+	if query.Level != "" && !cmd.Flags().Changed("level") {
+		listFlags.Levels = strings.Split(query.Level, ",")
+	}
+
+	return nil
+}
+
+func loadProfileFile() (config.ProfileFile, error) {
+	path, err := profilePath()
+	if err != nil {
+		return config.ProfileFile{}, err
+	}
+
+	return config.LoadProfiles(path)
+}
+
+// loadSavedQueries returns the saved queries sorted by name.
+func loadSavedQueries() ([]config.SavedQuery, error) {
+	file, err := loadProfileFile()
+	if err != nil {
+		return nil, err
+	}
+
+	queries := make([]config.SavedQuery, 0, len(file.Queries))
+	for _, query := range file.Queries {
+		queries = append(queries, query)
+	}
+	sort.Slice(queries, func(i int, j int) bool {
+		return queries[i].Name < queries[j].Name
+	})
+
+	return queries, nil
+}
+
+func renderSavedQueries(queries []config.SavedQuery) string {
+	if len(queries) == 0 {
+		return "no saved queries"
+	}
+
+	width := 0
+	for _, query := range queries {
+		width = max(width, len(query.Name))
+	}
+	lines := make([]string, 0, len(queries))
+	for _, query := range queries {
+		fields := make([]string, 0)
+		for _, field := range query.Fields() {
+			fields = append(fields, fmt.Sprintf("%s=%q", field.Key, field.Value))
+		}
+		lines = append(lines, fmt.Sprintf("%-*s  %s", width, query.Name, strings.Join(fields, " ")))
+	}
+
+	return strings.Join(lines, "\n")
+}
@@ -0,0 +1,71 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestQueriesCommands(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.NotFoundHandler())
+	path := filepath.Join(t.TempDir(), "config.toml")
+	setProfilePath(t, path)
+
+	runRootCommand(t, "queries", "add", "prod-errors", "--status", "active", "--level", "error,critical", "--env", "production")
+	runRootCommand(t, "queries", "add", "slow", "--query", "timeout", "--sort", "occurrences")
+
+	stdout.Reset()
+	runRootCommand(t, "queries", "list")
+	want := "prod-errors  status=\"active\" level=\"error,critical\" env=\"production\"\nslow         query=\"timeout\" sort=\"occurrences\"\n"
+	if stdout.String() != want {
+		t.Fatalf("queries list =\n%q\nwant\n%q", stdout.String(), want)
+	}
+
+	runRootCommand(t, "queries", "rm", "slow")
+	body, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("ReadFile() error = %v", err)
+	}
+	if strings.Contains(string(body), "slow") || !strings.Contains(string(body), "[queries.prod-errors]") {
+		t.Fatalf("unexpected config.toml:\n%s", body)
+	}
+
+	errorTests := []struct {
+		args    []string
+		wantErr string
+	}{
+		{args: []string{"queries", "add", "x", "--level", "loud"}, wantErr: "unsupported level"},
+		{args: []string{"queries", "add", "x", "--sort", "size"}, wantErr: "unsupported sort"},
+		{args: []string{"queries", "add", "x"}, wantErr: "at least one filter"},
+		{args: []string{"queries", "rm", "missing"}, wantErr: `query "missing" not found`},
+		{args: []string{"items", "list", "--saved", "missing"}, wantErr: `saved query "missing" not found`},
+	}
+	for _, tc := range errorTests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.wantErr, err)
+		}
+	}
+}
+
+func TestItemsListSavedQuery(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		query := r.URL.Query()
+		if r.URL.Path != "/api/1/items" || query.Get("status") != "active" || query.Get("environment") != "staging" || strings.Join(query["level"], ",") != "error,critical" {
+			w.WriteHeader(http.StatusBadRequest)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s"}`, r.URL.RawQuery)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":7,"title":"saved match","status":"active","environment":"staging","level":"error"}],"total_count":1}}`)
+	}))
+	writeProfiles(t, "[queries.prod-errors]\nstatus = \"active\"\nlevel = \"error,critical\"\nenv = \"production\"\n")
+
+	runRootCommand(t, "items", "list", "--saved", "prod-errors", "--env", "staging")
+	if !strings.Contains(stdout.String(), "saved match") {
+		t.Fatalf("unexpected output: %s", stdout.String())
+	}
+}
@@ -0,0 +1,43 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+// addRedactFlag adds --redact to a command and its subcommands.
+func addRedactFlag(cmd *cobra.Command, flags *rootFlags) {
+	cmd.PersistentFlags().BoolVar(&flags.Redact, "redact", false, "Also redact personal data: emails, IP addresses, bearer tokens, card numbers, and config.toml [redact] rules")
+}
+
+// piiRedactor is the Redactor --redact applies, with config.toml's [redact]
+// rules, or nil without --redact.
+func piiRedactor(flags rootFlags) (*redact.Redactor, error) {
+	if !flags.Redact {
+		return nil, nil
+	}
+
+	redactor, err := redact.NewRedactor(flags.RedactRules.Fields, flags.RedactRules.Patterns)
+	if err != nil {
+		return nil, fmt.Errorf("config.toml [redact]: %w", err)
+	}
+
+	return redactor, nil
+}
+
+// occurrenceScrubber is what occurrence payloads are scrubbed with before
+// anything reads them: the default scrubber, or the Redactor with --redact.
+func occurrenceScrubber(flags rootFlags) (*redact.Scrubber, error) {
+	redactor, err := piiRedactor(flags)
+	if err != nil {
+		return nil, err
+	}
+	if redactor == nil {
+		return redact.DefaultScrubber(), nil
+	}
+
+	return &redactor.Scrubber, nil
+}
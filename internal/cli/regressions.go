@@ -0,0 +1,54 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+func newRegressionsCmd(flags *rootFlags) *cobra.Command {
+	revision := ""
+	regressionsCmd := &cobra.Command{
+		Use:   "regressions",
+		Short: "List items a deploy introduced or brought back",
+		Long:  "Find the newest deploy of --since-deploy (a revision or its prefix, in --env when given) and list the active items in its environment that first occurred, or were reactivated, after it started and before the next deploy there. For example:\n\n  rollbaz regressions --since-deploy 3f2a9c1 --env production",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if strings.TrimSpace(revision) == "" {
+				return errors.New("--since-deploy is required")
+			}
+
+			return runRegressions(cmd.Context(), *flags, revision)
+		},
+	}
+	regressionsCmd.Flags().StringVar(&revision, "since-deploy", "", "Deployed revision to check, usually a commit SHA or its prefix")
+
+	return regressionsCmd
+}
+
+func runRegressions(parent context.Context, flags rootFlags, revision string) error {
+	ctx, cancel := context.WithTimeout(parent, time.Minute)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	options := app.RegressionOptions{Revision: revision, Environment: flags.Environment, Limit: flags.Limit}
+	report, err := runWithProgress(flags.Format, "Checking deploy", func() (app.RegressionReport, error) {
+		return app.Regressions(ctx, client, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderRegressionReportWithWidth(report, terminalRenderWidth()), redact.Value(report, token))
+}
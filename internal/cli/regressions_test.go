@@ -0,0 +1,49 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+// serveRegressions serves production deploys 42 and 43 and items first
+// seen after 42, reactivated after 42, and first seen after 43.
+func serveRegressions(w http.ResponseWriter, r *http.Request) {
+	switch {
+	case r.URL.Path == "/api/1/deploys" && r.URL.Query().Get("page") == "1":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"deploys":[{"id":43,"environment":"production","revision":"99aa","start_time":1700009000},{"id":42,"environment":"production","revision":"0123456789abcdef","start_time":1700000000}],"page":1}}`)
+	case r.URL.Path == "/api/1/items" && r.URL.Query().Get("environment") == "production":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[`+
+			`{"id":1,"counter":11,"title":"fresh KeyError","status":"active","first_occurrence_timestamp":1700000500,"last_activated_timestamp":1700000500},`+
+			`{"id":2,"counter":12,"title":"returning Timeout","status":"active","first_occurrence_timestamp":1600000000,"last_activated_timestamp":1700001000},`+
+			`{"id":3,"counter":13,"title":"later bug","status":"active","first_occurrence_timestamp":1700009500,"last_activated_timestamp":1700009500}`+
+			`],"total_count":3}}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s"}`, r.URL.Path, r.URL.RawQuery)
+	}
+}
+
+func TestRegressionsCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveRegressions))
+
+	runRootCommand(t, "regressions", "--since-deploy", "0123456")
+	rendered := stdout.String()
+	checkContains(t, "regressions", rendered, "Deploy 42 of 0123456789ab to production", "fresh KeyError", "returning Timeout")
+	if strings.Contains(rendered, "later bug") {
+		t.Fatalf("unexpected regressions:\n%s", rendered)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "regressions", "--since-deploy", "0123456", "--format", "json")
+	if !strings.Contains(stdout.String(), `"kind": "reactivated"`) || !strings.Contains(stdout.String(), `"until": 1700009000`) {
+		t.Fatalf("unexpected JSON output:\n%s", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"regressions"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "--since-deploy") {
+		t.Fatalf("expected a --since-deploy error, got %v", err)
+	}
+}
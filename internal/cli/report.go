@@ -0,0 +1,121 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const (
+	defaultReportSince = 7 * 24 * time.Hour
+	// reportTimeout covers scanning every active and resolved item.
+	reportTimeout = 2 * time.Minute
+)
+
+type reportFlags struct {
+	Out      string
+	HTML     bool
+	Template string
+}
+
+func newReportCmd(flags *rootFlags) *cobra.Command {
+	report := reportFlags{}
+	reportCmd := &cobra.Command{
+		Use:   "report",
+		Short: "Write a Markdown or HTML triage digest of new, top, resolved, and regressed items",
+		Long:  "Summarize the items first seen, seen, resolved, and reactivated since --since (default 7d), with a per-environment breakdown, listing up to --limit items per section. The digest is Markdown unless --html, --template, or an .html --out asks for HTML, for email. For example:\n\n  rollbaz report --since 7d --out report.md",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runReport(cmd.Context(), *flags, report)
+		},
+	}
+	reportCmd.Flags().StringVar(&report.Out, "out", "", "File to write the report to (default stdout)")
+	reportCmd.Flags().BoolVar(&report.HTML, "html", false, "Write HTML instead of Markdown")
+	reportCmd.Flags().StringVar(&report.Template, "template", "", "Go html/template file to render the report with (implies --html)")
+	_ = reportCmd.MarkFlagFilename("template")
+
+	return reportCmd
+}
+
+func runReport(parent context.Context, flags rootFlags, report reportFlags) error {
+	now := time.Now()
+	since, err := reportSince(flags, now)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, reportTimeout)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	digest, err := runWithProgress(flags.Format, "Building report", func() (app.Digest, error) {
+		return app.BuildDigest(ctx, client, app.DigestOptions{Environment: flags.Environment, Since: since, Limit: flags.Limit}, now)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+	document, err := renderReport(digest, report)
+	if err != nil {
+		return err
+	}
+
+	if report.Out == "" {
+		return printOutput(flags, document, redact.Value(digest, token))
+	}
+	if err := os.WriteFile(filepath.Clean(report.Out), []byte(document+"\n"), 0o600); err != nil {
+		return fmt.Errorf("write --out: %w", err)
+	}
+
+	return printOutput(flags, "Wrote triage report to "+report.Out, redact.Value(digest, token))
+}
+
+func reportSince(flags rootFlags, now time.Time) (time.Time, error) {
+	if flags.Since == "" {
+		return now.Add(-defaultReportSince), nil
+	}
+
+	since, err := parseFilterTime(flags.Since)
+	if err != nil {
+		return time.Time{}, fmt.Errorf("parse --since: %w", err)
+	}
+
+	return *since, nil
+}
+
+// renderReport renders the digest as Markdown, or as HTML when asked for
+// by flag, template, or an .html/.htm --out.
+func renderReport(digest app.Digest, report reportFlags) (string, error) {
+	extension := strings.ToLower(filepath.Ext(report.Out))
+	if !report.HTML && report.Template == "" && extension != ".html" && extension != ".htm" {
+		return output.RenderDigestMarkdown(digest), nil
+	}
+
+	text := ""
+	if report.Template != "" {
+		body, err := os.ReadFile(filepath.Clean(report.Template))
+		if err != nil {
+			return "", fmt.Errorf("read --template: %w", err)
+		}
+		text = string(body)
+	}
+
+	rendered, err := output.RenderDigestHTML(digest, text)
+	if err != nil {
+		return "", fmt.Errorf("--template: %w", err)
+	}
+
+	return strings.TrimRight(rendered, "\n"), nil
+}
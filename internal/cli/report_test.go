@@ -0,0 +1,69 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+)
+
+// reportHandler serves item 7, first seen an hour before now, item 8,
+// resolved two hours before now, and five occurrences this hour.
+func reportHandler(now int64) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.URL.Path == "/api/1/items" && r.URL.Query().Get("status") == "active":
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"items":[{"id":1,"counter":7,"title":"fresh KeyError","status":"active","level":"error","environment":"production","first_occurrence_timestamp":%d,"last_occurrence_timestamp":%d,"total_occurrences":4}],"total_count":1}}`, now-3600, now-60)
+		case r.URL.Path == "/api/1/items" && r.URL.Query().Get("status") == "resolved":
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"items":[{"id":2,"counter":8,"title":"old Timeout","status":"resolved","level":"warning","environment":"production","first_occurrence_timestamp":1600000000,"last_resolved_timestamp":%d,"total_occurrences":90}],"total_count":1}}`, now-7200)
+		case r.URL.Path == "/api/1/reports/occurrence_counts":
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":[[%d,5]]}`, now-now%3600)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s"}`, r.URL.Path, r.URL.RawQuery)
+		}
+	}
+}
+
+func TestReportCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, reportHandler(time.Now().Unix()))
+
+	runRootCommand(t, "report")
+	checkContains(t, "report", stdout.String(), "# Rollbar triage report", "| production | 1 | 1 | 1 | 0 | 5 |", "## New items (1)", "| #7 | error | fresh KeyError | production | 4 |", "## Resolved items (1)", "| #8 | warning | old Timeout |")
+}
+
+func TestReportCommandHTML(t *testing.T) {
+	stdout := setupServerAndStdout(t, reportHandler(time.Now().Unix()))
+
+	out := filepath.Join(t.TempDir(), "report.html")
+	runRootCommand(t, "report", "--since", "1d", "--out", out)
+	body, err := os.ReadFile(out)
+	if err != nil {
+		t.Fatalf("read report: %v", err)
+	}
+	if !strings.Contains(stdout.String(), "Wrote triage report to "+out) || !strings.HasPrefix(string(body), "<!DOCTYPE html>") || !strings.Contains(string(body), "fresh KeyError") {
+		t.Fatalf("unexpected HTML report %q:\n%s", stdout.String(), body)
+	}
+}
+
+func TestReportCommandTemplate(t *testing.T) {
+	stdout := setupServerAndStdout(t, reportHandler(time.Now().Unix()))
+
+	template := filepath.Join(t.TempDir(), "digest.tmpl")
+	if err := os.WriteFile(template, []byte(`{{.New.Total}} new, {{.Resolved.Total}} resolved`), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	runRootCommand(t, "report", "--template", template)
+	if strings.TrimSpace(stdout.String()) != "1 new, 1 resolved" {
+		t.Fatalf("unexpected templated report: %q", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"report", "--template", filepath.Join(t.TempDir(), "missing.tmpl")})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "read --template") {
+		t.Fatalf("expected a missing template error, got %v", err)
+	}
+}
@@ -1,14 +1,13 @@
 package cli
 
 import (
-	"bufio"
-	"context"
 	"errors"
 	"fmt"
 	"io"
 	"os"
+	"path/filepath"
 	"strconv"
-	"strings"
+	"text/template"
 	"time"
 
 	"github.com/jedib0t/go-pretty/v6/progress"
@@ -18,12 +17,15 @@ import (
 	"github.com/kevinsheth/rollbaz/internal/app"
 	"github.com/kevinsheth/rollbaz/internal/config"
 	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/inapp"
+	"github.com/kevinsheth/rollbaz/internal/jq"
 	"github.com/kevinsheth/rollbaz/internal/output"
 	"github.com/kevinsheth/rollbaz/internal/redact"
 	"github.com/kevinsheth/rollbaz/internal/rollbar"
 )
 
 type rootFlags struct {
+	Profile        string
 	Format         string
 	Project        string
 	Token          string
@@ -35,6 +37,50 @@ type rootFlags struct {
 	Until          string
 	MinOccurrences string
 	MaxOccurrences string
+	// ProjectFromURL marks a Project taken from a pasted item URL. It is a
+	// Rollbar slug, so ROLLBAR_ACCESS_TOKEN, which may belong to another
+	// project, is no fallback for it.
+	ProjectFromURL bool
+	// JQ filters machine-readable output; see applyOutputFilter, which
+	// compiles it into Filter for printOutput.
+	JQ     string
+	Filter *jq.Query
+	// Template renders the payload with a user template instead of the
+	// format; see applyOutputTemplate, which parses it into
+	// ParsedTemplate for printOutput.
+	Template       string
+	ParsedTemplate *template.Template
+	// Offline reads from the local store `rollbaz sync` fills instead of the
+	// Rollbar API.
+	Offline bool
+	// HTTPCache sends conditional GET requests backed by a response cache in
+	// the user cache directory.
+	HTTPCache bool
+	// BaseURL comes from the selected profile; empty uses Rollbar's API.
+	BaseURL string
+	// AccountToken comes from the selected profile; ROLLBAR_ACCOUNT_TOKEN is
+	// the fallback.
+	AccountToken string
+	// Account is the web UI account slug from the selected profile.
+	Account string
+	// GitHubToken and GitHubURL come from the selected profile; GITHUB_TOKEN
+	// is the token fallback.
+	GitHubToken string
+	GitHubURL   string
+	// SlackWebhookURL, SlackToken, and SlackChannel come from the selected
+	// profile; SLACK_WEBHOOK_URL and SLACK_TOKEN are the fallbacks.
+	SlackWebhookURL string
+	SlackToken      string
+	SlackChannel    string
+	// Jira holds config.toml's [jira] tables, which apply to every profile.
+	Jira config.JiraConfig
+	// InApp classifies stack frames by config.toml's in_app patterns, the
+	// selected profile's replacing the top-level ones; nil when unset.
+	InApp *inapp.Rules
+	// Redact removes personal data from exports and occurrences; see
+	// addRedactFlag. RedactRules are config.toml's extra [redact] rules.
+	Redact      bool
+	RedactRules config.RedactConfig
 }
 
 var (
@@ -60,521 +106,364 @@ func NewRootCmd() *cobra.Command {
 		Use:          "rollbaz",
 		Short:        "Fast Rollbar triage from your terminal",
 		SilenceUsage: true,
+		PersistentPreRunE: func(cmd *cobra.Command, args []string) error {
+			if err := applyProfile(cmd, flags); err != nil {
+				return err
+			}
+			format, err := output.ParseFormat(flags.Format)
+			if err != nil {
+				return err
+			}
+			if format == output.FormatSARIF && cmd.Annotations[sarifAnnotation] == "" {
+				return errors.New("--format sarif is supported by items list and ci check only")
+			}
+
+			if err := applyOutputFilter(cmd, flags); err != nil {
+				return err
+			}
+
+			return applyOutputTemplate(flags)
+		},
 		RunE: func(cmd *cobra.Command, args []string) error {
 			return runRecent(cmd.Context(), *flags)
 		},
 	}
 	cmd.Version = version
+	// The completions command replaces cobra's default completion command.
+	cmd.CompletionOptions.DisableDefaultCmd = true
+	addRootFlags(cmd, flags)
+	addSubcommands(cmd, flags)
+	registerFlagCompletions(cmd)
 
-	cmd.PersistentFlags().StringVar(&flags.Format, "format", "human", "Output format: human or json")
-	cmd.PersistentFlags().StringVar(&flags.Project, "project", "", "Configured project name")
+	return cmd
+}
+
+func addRootFlags(cmd *cobra.Command, flags *rootFlags) {
+	cmd.PersistentFlags().StringVar(&flags.Profile, "profile", "", "Profile from config.toml (default: $ROLLBAZ_PROFILE, then default_profile)")
+	cmd.PersistentFlags().StringVar(&flags.Format, "format", "human", "Output format: "+output.FormatNames())
+	cmd.PersistentFlags().StringVar(&flags.Project, "project", "", "Configured project name, or a Rollbar project id or slug (needs an account token)")
 	cmd.PersistentFlags().StringVar(&flags.Token, "token", "", "Rollbar project token (overrides configured project token)")
 	cmd.PersistentFlags().BoolVar(&flags.Yes, "yes", false, "Skip confirmation prompts for write commands")
 	cmd.PersistentFlags().IntVar(&flags.Limit, "limit", 10, "Maximum number of issues to show")
 	cmd.PersistentFlags().StringVar(&flags.Environment, "env", "", "Filter by environment")
 	cmd.PersistentFlags().StringVar(&flags.Status, "status", "", "Filter by status")
-	cmd.PersistentFlags().StringVar(&flags.Since, "since", "", "Filter by last seen time (RFC3339 or unix seconds)")
-	cmd.PersistentFlags().StringVar(&flags.Until, "until", "", "Filter by last seen time (RFC3339 or unix seconds)")
+	cmd.PersistentFlags().StringVar(&flags.Since, "since", "", "Filter by last seen time (RFC3339, unix seconds, or relative like 24h/7d)")
+	cmd.PersistentFlags().StringVar(&flags.Until, "until", "", "Filter by last seen time (RFC3339, unix seconds, or relative like 24h/7d)")
 	cmd.PersistentFlags().StringVar(&flags.MinOccurrences, "min-occurrences", "", "Filter by minimum occurrence count")
 	cmd.PersistentFlags().StringVar(&flags.MaxOccurrences, "max-occurrences", "", "Filter by maximum occurrence count")
+	cmd.PersistentFlags().BoolVar(&flags.HTTPCache, "http-cache", false, "Cache API responses and revalidate them with ETag/Last-Modified (default on for watch and tui)")
+	cmd.PersistentFlags().StringVar(&flags.JQ, "jq", "", "Filter JSON output with a jq expression (implies --format json)")
+	cmd.PersistentFlags().StringVar(&flags.Template, "template", "", "Render output with a Go text/template file, or a template named in the config dir's templates/")
+	cmd.PersistentFlags().BoolVar(&flags.Offline, "offline", false, "Read items and occurrences from the local store filled by `rollbaz sync`")
+}
+
+func addSubcommands(cmd *cobra.Command, flags *rootFlags) {
+	cmd.AddCommand(newItemCmd(flags))
+	cmd.AddCommand(newItemsCmd(flags))
+	cmd.AddCommand(newSearchCmd(flags))
+	cmd.AddCommand(newOccurrencesCmd(flags))
+	cmd.AddCommand(newOccurrenceCmd(flags))
+	cmd.AddCommand(newWatchCmd(flags))
+	cmd.AddCommand(newServeCmd(flags))
+	cmd.AddCommand(newExporterCmd(flags))
+	cmd.AddCommand(newTUICmd(flags))
+	cmd.AddCommand(newOpenCmd(flags))
+	cmd.AddCommand(newRQLCmd(flags))
+	cmd.AddCommand(newDeployCmd(flags))
+	cmd.AddCommand(newRegressionsCmd(flags))
+	cmd.AddCommand(newNotificationsCmd(flags))
+	cmd.AddCommand(newConfigCmd(flags))
+	cmd.AddCommand(newTokensCmd(flags))
+	cmd.AddCommand(newLinksCmd(flags))
+	cmd.AddCommand(newCICmd(flags))
+	cmd.AddCommand(newReportCmd(flags))
+	cmd.AddCommand(newSourceMapCmd(flags))
+	cmd.AddCommand(newDoctorCmd(flags))
+	cmd.AddCommand(newQueriesCmd(flags))
+	cmd.AddCommand(newExportCmd(flags))
+	cmd.AddCommand(newSyncCmd(flags))
+	cmd.AddCommand(newEnvironmentsCmd(flags))
+	cmd.AddCommand(newProjectCmd())
+	cmd.AddCommand(newAuthCmd(flags))
+	cmd.AddCommand(newCompletionsCmd())
 
+	// Top-level shortcuts predate the item/items groups and stay for existing scripts.
 	cmd.AddCommand(newActiveCmd(flags))
 	cmd.AddCommand(newRecentCmd(flags))
 	cmd.AddCommand(newShowCmd(flags))
 	cmd.AddCommand(newResolveCmd(flags))
 	cmd.AddCommand(newReopenCmd(flags))
 	cmd.AddCommand(newMuteCmd(flags))
-	cmd.AddCommand(newProjectCmd())
+}
 
-	return cmd
+// Exit codes: commands that ran but whose check failed exit with
+// exitCheckFailed so scripts can tell that apart from rollbaz failing.
+const (
+	exitError       = 1
+	exitCheckFailed = 2
+)
+
+// exitCodeError makes Execute exit with code instead of exitError.
+type exitCodeError struct {
+	code int
+	err  error
+}
+
+func (e *exitCodeError) Error() string {
+	return e.err.Error()
+}
+
+func (e *exitCodeError) Unwrap() error {
+	return e.err
 }
 
 func Execute() int {
 	root := NewRootCmd()
 	if err := root.Execute(); err != nil {
 		_, _ = fmt.Fprintln(stderrWriter, err)
-		return 1
+		return exitCode(err)
 	}
 
 	return 0
 }
 
-func newActiveCmd(flags *rootFlags) *cobra.Command {
-	return &cobra.Command{
-		Use:   "active",
-		Short: "List active issues",
-		RunE: func(cmd *cobra.Command, args []string) error {
-			return runActive(cmd.Context(), *flags)
-		},
+func exitCode(err error) int {
+	var coded *exitCodeError
+	if errors.As(err, &coded) {
+		return coded.code
 	}
-}
 
-func newRecentCmd(flags *rootFlags) *cobra.Command {
-	return &cobra.Command{
-		Use:   "recent",
-		Short: "List most recently seen active issues",
-		RunE: func(cmd *cobra.Command, args []string) error {
-			return runRecent(cmd.Context(), *flags)
-		},
-	}
+	return exitError
 }
 
-func newShowCmd(flags *rootFlags) *cobra.Command {
-	return &cobra.Command{
-		Use:   "show <item-counter>",
-		Short: "Show details for one item counter",
-		Args:  cobra.ExactArgs(1),
-		RunE: func(cmd *cobra.Command, args []string) error {
-			counter, err := parseItemCounter(args[0])
-			if err != nil {
-				return err
-			}
-			return runShow(cmd.Context(), *flags, counter)
-		},
-	}
-}
-
-func newResolveCmd(flags *rootFlags) *cobra.Command {
-	resolvedVersion := ""
-	resolveCmd := &cobra.Command{
-		Use:   "resolve <item-counter>",
-		Short: "Resolve an issue",
-		Args:  cobra.ExactArgs(1),
-		RunE: func(cmd *cobra.Command, args []string) error {
-			counter, err := parseItemCounter(args[0])
-			if err != nil {
-				return err
-			}
-
-			return runResolve(cmd.Context(), *flags, counter, resolvedVersion)
-		},
+// parseItemCounter parses one item argument: a counter or a pasted Rollbar
+// item URL. See parseItemCounters for how a URL's project is used.
+func parseItemCounter(flags *rootFlags, value string) (domain.ItemCounter, error) {
+	counters, err := parseItemCounters(flags, []string{value})
+	if err != nil {
+		return 0, err
 	}
-	resolveCmd.Flags().StringVar(&resolvedVersion, "resolved-in-version", "", "Version to store when resolving")
 
-	return resolveCmd
+	return counters[0], nil
 }
 
-func newReopenCmd(flags *rootFlags) *cobra.Command {
-	return &cobra.Command{
-		Use:   "reopen <item-counter>",
-		Short: "Reopen a resolved or muted issue",
-		Args:  cobra.ExactArgs(1),
-		RunE: func(cmd *cobra.Command, args []string) error {
-			counter, err := parseItemCounter(args[0])
-			if err != nil {
-				return err
-			}
-
-			return runReopen(cmd.Context(), *flags, counter)
-		},
+// parseItemCounters parses every item argument and rejects duplicates so a
+// bulk write never touches the same item twice. When the arguments are
+// Rollbar URLs and --project was not given, their project becomes --project;
+// URLs from different projects cannot be mixed.
+func parseItemCounters(flags *rootFlags, values []string) ([]domain.ItemCounter, error) {
+	counters := make([]domain.ItemCounter, 0, len(values))
+	seen := make(map[domain.ItemCounter]bool, len(values))
+	project := ""
+	for _, value := range values {
+		ref, err := domain.ParseItemRef(value)
+		if err != nil {
+			return nil, fmt.Errorf("parse item %q: %w", value, err)
+		}
+		if ref.Project != "" && project != "" && ref.Project != project {
+			return nil, fmt.Errorf("items come from different projects (%s and %s)", project, ref.Project)
+		}
+		if ref.Project != "" {
+			project = ref.Project
+		}
+		if seen[ref.Counter] {
+			return nil, fmt.Errorf("item counter %s given more than once", ref.Counter)
+		}
+		seen[ref.Counter] = true
+		counters = append(counters, ref.Counter)
 	}
-}
-
-func newMuteCmd(flags *rootFlags) *cobra.Command {
-	muteFor := ""
-	muteCmd := &cobra.Command{
-		Use:   "mute <item-counter>",
-		Short: "Mute an issue",
-		Args:  cobra.ExactArgs(1),
-		RunE: func(cmd *cobra.Command, args []string) error {
-			counter, err := parseItemCounter(args[0])
-			if err != nil {
-				return err
-			}
-
-			return runMute(cmd.Context(), *flags, counter, muteFor)
-		},
+	if project != "" && flags.Project == "" {
+		flags.Project, flags.ProjectFromURL = project, true
 	}
-	muteCmd.Flags().StringVar(&muteFor, "for", "", "Mute duration (examples: 30m, 2h, 24h)")
 
-	return muteCmd
+	return counters, nil
 }
 
-func parseItemCounter(value string) (domain.ItemCounter, error) {
-	parsedCounter, err := strconv.ParseUint(value, 10, 64)
+func parseItemID(value string) (domain.ItemID, error) {
+	parsedID, err := strconv.ParseUint(value, 10, 64)
 	if err != nil {
-		return 0, fmt.Errorf("parse item counter: %w", err)
-	}
-	if parsedCounter == 0 {
-		return 0, errors.New("item counter must be greater than 0")
-	}
-
-	return domain.ItemCounter(parsedCounter), nil
-}
-
-func newProjectCmd() *cobra.Command {
-	projectCmd := &cobra.Command{Use: "project", Short: "Manage configured Rollbar projects"}
-	projectCmd.AddCommand(
-		newProjectAddCmd(),
-		newProjectListCmd(),
-		newProjectUseCmd(),
-		newProjectNextCmd(),
-		newProjectRemoveCmd(),
-	)
-
-	return projectCmd
-}
-
-func newProjectAddCmd() *cobra.Command {
-	addToken := ""
-	addCmd := &cobra.Command{
-		Use:   "add <name>",
-		Short: "Add or update a project token",
-		Args:  cobra.ExactArgs(1),
-		RunE: func(cmd *cobra.Command, args []string) error {
-			if err := withConfigStore(func(store *config.Store) error {
-				return store.AddProject(args[0], addToken)
-			}); err != nil {
-				return fmt.Errorf("add project: %w", err)
-			}
-			return nil
-		},
+		return 0, fmt.Errorf("parse item id: %w", err)
 	}
-	addCmd.Flags().StringVar(&addToken, "token", "", "Project token")
-	_ = addCmd.MarkFlagRequired("token")
-
-	return addCmd
-}
-
-func newProjectListCmd() *cobra.Command {
-	return &cobra.Command{
-		Use:   "list",
-		Short: "List configured projects",
-		RunE: func(cmd *cobra.Command, args []string) error {
-			return withConfigStore(printProjects)
-		},
+	if parsedID == 0 {
+		return 0, errors.New("item id must be greater than 0")
 	}
-}
 
-func newProjectUseCmd() *cobra.Command {
-	return &cobra.Command{
-		Use:   "use <name>",
-		Short: "Set active project",
-		Args:  cobra.ExactArgs(1),
-		RunE: func(cmd *cobra.Command, args []string) error {
-			if err := withConfigStore(func(store *config.Store) error {
-				return store.UseProject(args[0])
-			}); err != nil {
-				return fmt.Errorf("use project: %w", err)
-			}
-			return nil
-		},
-	}
+	return domain.ItemID(parsedID), nil
 }
 
-func newProjectNextCmd() *cobra.Command {
-	return &cobra.Command{
-		Use:   "next",
-		Short: "Cycle active project",
-		RunE: func(cmd *cobra.Command, args []string) error {
-			name, err := cycleProject()
-			if err != nil {
-				return fmt.Errorf("cycle project: %w", err)
-			}
-			_, _ = fmt.Fprintln(stdoutWriter, name)
-			return nil
-		},
+func printOutput(flags rootFlags, human string, payload any) error {
+	parsed, err := output.ParseFormat(flags.Format)
+	if err != nil {
+		return err
 	}
-}
 
-func newProjectRemoveCmd() *cobra.Command {
-	removeAll := false
-	removeCmd := &cobra.Command{
-		Use:   "remove [name]",
-		Short: "Remove configured project",
-		Args: func(cmd *cobra.Command, args []string) error {
-			return validateProjectRemoveArgs(removeAll, cmd, args)
-		},
-		RunE: func(cmd *cobra.Command, args []string) error {
-			return runProjectRemove(removeAll, args)
-		},
+	rendered, err := renderPayload(flags, parsed, human, payload)
+	if err != nil {
+		return fmt.Errorf("render %s: %w", parsed, err)
 	}
-	removeCmd.Flags().BoolVar(&removeAll, "all", false, "Remove all configured projects and tokens")
-
-	return removeCmd
-}
-
-func validateProjectRemoveArgs(removeAll bool, cmd *cobra.Command, args []string) error {
-	if removeAll {
-		if len(args) > 0 {
-			return errors.New("cannot use --all with a project name")
-		}
+	if rendered == "" && flags.Filter != nil {
 		return nil
 	}
-	if len(args) == 0 {
-		return errors.New("specify a project name or use --all")
-	}
-	if len(args) > 1 {
-		return cobra.MaximumNArgs(1)(cmd, args)
-	}
+	_, _ = fmt.Fprintln(stdoutWriter, rendered)
 
 	return nil
 }
 
-func runProjectRemove(removeAll bool, args []string) error {
-	if removeAll {
-		if err := withConfigStore(func(store *config.Store) error {
-			return store.RemoveAllProjects()
-		}); err != nil {
-			return fmt.Errorf("remove projects: %w", err)
-		}
+// applyOutputFilter compiles --jq into flags.Filter for renderPayload. It
+// switches a human format to json unless --format was given, since the filter
+// only applies to machine-readable output.
+func applyOutputFilter(cmd *cobra.Command, flags *rootFlags) error {
+	flags.Filter = nil
+	if flags.JQ == "" {
 		return nil
 	}
-
-	if err := withConfigStore(func(store *config.Store) error {
-		return store.RemoveProject(args[0])
-	}); err != nil {
-		return fmt.Errorf("remove project: %w", err)
-	}
-
-	return nil
-}
-
-func runActive(parent context.Context, flags rootFlags) error {
-	return runIssueList(parent, flags, func(ctx context.Context, service *app.Service, limit int, filters app.IssueFilters) ([]app.IssueSummary, error) {
-		return service.Active(ctx, limit, filters)
-	})
-}
-
-func runRecent(parent context.Context, flags rootFlags) error {
-	return runIssueList(parent, flags, func(ctx context.Context, service *app.Service, limit int, filters app.IssueFilters) ([]app.IssueSummary, error) {
-		return service.Recent(ctx, limit, filters)
-	})
-}
-
-func runIssueList(parent context.Context, flags rootFlags, load func(context.Context, *app.Service, int, app.IssueFilters) ([]app.IssueSummary, error)) error {
-	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
-	defer cancel()
-
-	service, token, err := buildService(flags)
+	format, err := output.ParseFormat(flags.Format)
 	if err != nil {
 		return err
 	}
-
-	filters, err := parseIssueFilters(flags)
-	if err != nil {
-		return err
+	if format.Human() {
+		if cmd.Flags().Changed("format") {
+			return fmt.Errorf("--jq needs --format json, yaml, ndjson, or sarif, not %s", format)
+		}
+		flags.Format = string(output.FormatJSON)
 	}
 
-	issues, err := runWithProgress(flags.Format, "Loading issues", func() ([]app.IssueSummary, error) {
-		return load(ctx, service, flags.Limit, filters)
-	})
+	query, err := jq.Compile(flags.JQ)
 	if err != nil {
-		return sanitizeError(err, token)
+		return fmt.Errorf("parse --jq: %w", err)
 	}
+	flags.Filter = query
 
-	jsonPayload := redact.Value(map[string]any{"issues": issues}, token)
-	return printOutput(flags.Format, output.RenderIssueListHumanWithWidth(issues, terminalRenderWidth()), jsonPayload)
+	return nil
 }
 
-func withConfigStore(action func(*config.Store) error) error {
-	store, err := newConfigStore()
-	if err != nil {
-		return err
+// applyOutputTemplate loads --template into flags.ParsedTemplate for
+// renderPayload, from a file or by name from the templates directory next to
+// config.toml.
+func applyOutputTemplate(flags *rootFlags) error {
+	flags.ParsedTemplate = nil
+	if flags.Template == "" {
+		return nil
+	}
+	if flags.JQ != "" {
+		return errors.New("--template and --jq cannot be used together")
 	}
 
-	return action(store)
-}
-
-func printProjects(store *config.Store) error {
-	file, err := store.Load()
+	path, err := profilePath()
 	if err != nil {
-		return fmt.Errorf("load config: %w", err)
+		return err
 	}
-
-	if len(file.Projects) == 0 {
-		_, _ = fmt.Fprintln(stdoutWriter, "no configured projects")
-		return nil
+	name, text, err := config.LoadTemplate(config.TemplateDir(path), flags.Template)
+	if err != nil {
+		return fmt.Errorf("--template: %w", err)
 	}
-
-	for _, project := range file.Projects {
-		prefix := "  "
-		if project.Name == file.ActiveProject {
-			prefix = "* "
-		}
-		_, _ = fmt.Fprintf(stdoutWriter, "%s%s\n", prefix, project.Name)
+	parsed, err := output.ParseTemplate(name, text)
+	if err != nil {
+		return fmt.Errorf("--template: %w", err)
 	}
+	flags.ParsedTemplate = parsed
 
 	return nil
 }
 
-func cycleProject() (string, error) {
-	var name string
-	err := withConfigStore(func(store *config.Store) error {
-		next, err := store.CycleProject()
-		if err != nil {
-			return fmt.Errorf("cycle project: %w", err)
-		}
-		name = next
-		return nil
-	})
-	if err != nil {
-		return "", err
+// renderPayload is output.Render with --template or the --jq filter applied
+// to the payload.
+func renderPayload(flags rootFlags, format output.Format, human string, payload any) (string, error) {
+	if flags.ParsedTemplate != nil {
+		return output.RenderTemplate(flags.ParsedTemplate, payload)
 	}
-
-	return name, nil
-}
-
-func runShow(parent context.Context, flags rootFlags, counter domain.ItemCounter) error {
-	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
-	defer cancel()
-
-	service, token, err := buildService(flags)
-	if err != nil {
-		return err
+	if flags.Filter == nil || format.Human() {
+		return output.Render(format, human, payload)
 	}
-
-	detail, err := runWithProgress(flags.Format, "Loading issue detail", func() (app.IssueDetail, error) {
-		return service.Show(ctx, counter)
-	})
+	results, err := flags.Filter.Run(payload)
 	if err != nil {
-		return sanitizeError(err, token)
-	}
-
-	payload := map[string]any{
-		"issue":        detail.IssueSummary,
-		"main_error":   detail.MainError,
-		"item_raw":     detail.ItemRaw,
-		"instance":     detail.Instance,
-		"instance_raw": detail.InstanceRaw,
+		return "", fmt.Errorf("--jq %s: %w", flags.Filter, err)
 	}
-	jsonPayload := redact.Value(payload, token)
 
-	return printOutput(flags.Format, output.RenderIssueDetailHumanWithWidth(detail, terminalRenderWidth()), jsonPayload)
+	return output.RenderFiltered(format, results)
 }
 
-func runResolve(parent context.Context, flags rootFlags, counter domain.ItemCounter, resolvedVersion string) error {
-	return runIssueAction(parent, flags, "resolve", counter, func(ctx context.Context, service *app.Service) (app.ItemActionResult, error) {
-		return service.Resolve(ctx, counter, resolvedVersion)
-	})
+// isHumanFormat reports whether output goes to a person, which is when
+// progress and confirmation prompts make sense.
+func isHumanFormat(format string) bool {
+	parsed, err := output.ParseFormat(format)
+	return err == nil && parsed.Human()
 }
 
-func runReopen(parent context.Context, flags rootFlags, counter domain.ItemCounter) error {
-	return runIssueAction(parent, flags, "reopen", counter, func(ctx context.Context, service *app.Service) (app.ItemActionResult, error) {
-		return service.Reopen(ctx, counter)
-	})
-}
-
-func runMute(parent context.Context, flags rootFlags, counter domain.ItemCounter, muteFor string) error {
-	durationSeconds, err := parseMuteDuration(muteFor)
-	if err != nil {
-		return err
-	}
-
-	return runIssueAction(parent, flags, "mute", counter, func(ctx context.Context, service *app.Service) (app.ItemActionResult, error) {
-		return service.Mute(ctx, counter, durationSeconds)
-	})
-}
-
-func parseMuteDuration(value string) (*int64, error) {
-	value = strings.TrimSpace(value)
-	if value == "" {
-		return nil, nil
+func buildService(flags rootFlags) (*app.Service, string, error) {
+	if flags.Offline {
+		return buildOfflineService(flags)
 	}
 
-	parsed, err := time.ParseDuration(value)
+	client, token, err := buildClient(flags)
 	if err != nil {
-		return nil, fmt.Errorf("parse --for: %w", err)
-	}
-	if parsed < time.Second {
-		return nil, errors.New("--for must be at least 1s")
+		return nil, token, err
 	}
 
-	seconds := int64(parsed / time.Second)
-	return &seconds, nil
+	return app.NewService(client), token, nil
 }
 
-func runIssueAction(parent context.Context, flags rootFlags, action string, counter domain.ItemCounter, execute func(context.Context, *app.Service) (app.ItemActionResult, error)) error {
-	if err := confirmWrite(flags, action, counter); err != nil {
-		return err
+// buildClient is buildService for commands that tune the client, such as
+// throttling bulk writes.
+func buildClient(flags rootFlags) (*rollbar.Client, string, error) {
+	if flags.Offline {
+		return nil, "", errOfflineUnsupported
 	}
 
-	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
-	defer cancel()
-
-	service, token, err := buildService(flags)
+	token, err := resolveAccessToken(flags)
 	if err != nil {
-		return err
+		return nil, "", err
 	}
 
-	result, err := runWithProgress(flags.Format, "Updating issue", func() (app.ItemActionResult, error) {
-		return execute(ctx, service)
-	})
+	client, err := newClientWithToken(flags, token)
 	if err != nil {
-		return sanitizeError(err, token)
+		return nil, token, err
 	}
 
-	human := fmt.Sprintf("%s issue %s\n\n%s", result.Action, result.Issue.Counter.String(), output.RenderIssueListHumanWithWidth([]app.IssueSummary{result.Issue}, terminalRenderWidth()))
-	jsonPayload := redact.Value(map[string]any{"action": result.Action, "issue": result.Issue}, token)
-
-	return printOutput(flags.Format, human, jsonPayload)
+	return client, token, nil
 }
 
-func confirmWrite(flags rootFlags, action string, counter domain.ItemCounter) error {
-	if flags.Yes {
-		return nil
-	}
-	if flags.Format != "human" || !canPromptConfirmation() {
-		return errors.New("confirmation required for write operation; rerun with --yes")
-	}
-
-	_, _ = fmt.Fprintf(stdoutWriter, "Confirm %s issue %s? [y/N]: ", action, counter.String())
-	reader := bufio.NewReader(stdinReader)
-	line, err := reader.ReadString('\n')
-	if err != nil && !errors.Is(err, io.EOF) {
-		return fmt.Errorf("read confirmation: %w", err)
+// newClientWithToken builds a client for an already-resolved token, honouring
+// the profile's base URL.
+func newClientWithToken(flags rootFlags, token string) (*rollbar.Client, error) {
+	newClient := newRollbarClient
+	if flags.BaseURL != "" {
+		newClient = func(token string) (*rollbar.Client, error) {
+			return rollbar.NewWithBaseURL(token, flags.BaseURL)
+		}
 	}
 
-	value := strings.TrimSpace(strings.ToLower(line))
-	if value != "y" && value != "yes" {
-		return errors.New("operation cancelled")
+	client, err := newClient(token)
+	if err != nil {
+		return nil, sanitizeError(err, token)
 	}
-
-	return nil
-}
-
-func canPromptConfirmation() bool {
-	stdoutFile, ok := stdoutFile()
-	if !ok || !isTerminal(int(stdoutFile.Fd())) {
-		return false
+	if flags.HTTPCache {
+		client.SetResponseCache(newResponseCache())
 	}
-	input, ok := stdinReader.(*os.File)
-	if !ok || !isTerminal(int(input.Fd())) {
-		return false
+	if err := useCassette(client); err != nil {
+		return nil, err
 	}
 
-	return true
+	return client, nil
 }
 
-func printOutput(format string, human string, payload any) error {
-	switch format {
-	case "human":
-		_, _ = fmt.Fprintln(stdoutWriter, human)
-		return nil
-	case "json":
-		rendered, err := output.RenderJSON(payload)
-		if err != nil {
-			return fmt.Errorf("render json: %w", err)
-		}
-		_, _ = fmt.Fprintln(stdoutWriter, rendered)
-		return nil
-	default:
-		return fmt.Errorf("unsupported format %q", format)
-	}
-}
-
-func buildService(flags rootFlags) (*app.Service, string, error) {
-	token, err := resolveAccessToken(flags)
+// newResponseCache keeps responses on disk under the user cache directory,
+// or only in memory when there is none.
+func newResponseCache() *rollbar.ResponseCache {
+	cacheRoot, err := os.UserCacheDir()
 	if err != nil {
-		return nil, "", err
+		return rollbar.NewResponseCache("")
 	}
 
-	client, err := newRollbarClient(token)
-	if err != nil {
-		return nil, token, sanitizeError(err, token)
-	}
+	return rollbar.NewResponseCache(filepath.Join(cacheRoot, "rollbaz", "http"))
+}
 
-	return app.NewService(client), token, nil
+// defaultHTTPCache turns the response cache on for commands that refetch the
+// same items, unless --http-cache was given explicitly.
+func defaultHTTPCache(cmd *cobra.Command, flags *rootFlags) {
+	if !cmd.Flags().Changed("http-cache") {
+		flags.HTTPCache = true
+	}
 }
 
 func resolveAccessToken(flags rootFlags) (string, error) {
@@ -582,18 +471,20 @@ func resolveAccessToken(flags rootFlags) (string, error) {
 		return flags.Token, nil
 	}
 
-	store, err := newConfigStore()
-	if err == nil {
-		token, _, resolveErr := store.ResolveToken(flags.Project)
-		if resolveErr == nil {
-			return token, nil
-		}
+	if token, ok := storedToken(flags.Project); ok {
+		return token, nil
+	}
+	if accountToken := resolveAccountToken(flags); flags.Project != "" && accountToken != "" {
+		return resolveProjectToken(flags, accountToken)
+	}
+	if flags.ProjectFromURL {
+		return "", fmt.Errorf("project %q from the item URL is not configured (add it with `rollbaz project add`, or set ROLLBAR_ACCOUNT_TOKEN to look it up)", flags.Project)
 	}
 
 	token := os.Getenv("ROLLBAR_ACCESS_TOKEN")
 	if token == "" {
 		if flags.Project != "" {
-			return "", fmt.Errorf("project %q not configured and ROLLBAR_ACCESS_TOKEN is missing", flags.Project)
+			return "", fmt.Errorf("project %q not configured and ROLLBAR_ACCESS_TOKEN is missing (set ROLLBAR_ACCOUNT_TOKEN to look up Rollbar project ids and slugs)", flags.Project)
 		}
 		return "", errors.New("no token available: add a project via `rollbaz project add ...` or set ROLLBAR_ACCESS_TOKEN")
 	}
@@ -601,87 +492,32 @@ func resolveAccessToken(flags rootFlags) (string, error) {
 	return token, nil
 }
 
-func parseIssueFilters(flags rootFlags) (app.IssueFilters, error) {
-	filters := app.IssueFilters{
-		Environment: flags.Environment,
-		Status:      flags.Status,
-	}
-
-	since, err := parseFilterTime(flags.Since)
-	if err != nil {
-		return app.IssueFilters{}, fmt.Errorf("parse --since: %w", err)
-	}
-	filters.Since = since
-
-	until, err := parseFilterTime(flags.Until)
-	if err != nil {
-		return app.IssueFilters{}, fmt.Errorf("parse --until: %w", err)
-	}
-	filters.Until = until
-
-	minOccurrences, err := parseOptionalUint64(flags.MinOccurrences)
-	if err != nil {
-		return app.IssueFilters{}, fmt.Errorf("parse --min-occurrences: %w", err)
-	}
-	filters.MinOccurrences = minOccurrences
-
-	maxOccurrences, err := parseOptionalUint64(flags.MaxOccurrences)
+// storedToken reads the project's token from the config file or, for projects
+// added with `auth login`, the system keyring. Keyring failures are reported
+// as warnings so the ROLLBAR_ACCESS_TOKEN fallback still applies.
+func storedToken(projectName string) (string, bool) {
+	store, err := newConfigStore()
 	if err != nil {
-		return app.IssueFilters{}, fmt.Errorf("parse --max-occurrences: %w", err)
-	}
-	filters.MaxOccurrences = maxOccurrences
-
-	if err := validateIssueFilters(filters); err != nil {
-		return app.IssueFilters{}, err
-	}
-
-	return filters, nil
-}
-
-func validateIssueFilters(filters app.IssueFilters) error {
-	if filters.Since != nil && filters.Until != nil && filters.Since.After(*filters.Until) {
-		return errors.New("--since must be before or equal to --until")
-	}
-	if filters.MinOccurrences != nil && filters.MaxOccurrences != nil && *filters.MinOccurrences > *filters.MaxOccurrences {
-		return errors.New("--min-occurrences must be <= --max-occurrences")
-	}
-
-	return nil
-}
-
-func parseOptionalUint64(value string) (*uint64, error) {
-	if value == "" {
-		return nil, nil
+		return "", false
 	}
-
-	parsed, err := strconv.ParseUint(value, 10, 64)
+	project, err := store.ResolveProject(projectName)
 	if err != nil {
-		return nil, fmt.Errorf("parse uint64: %w", err)
+		return "", false
 	}
-
-	return &parsed, nil
-}
-
-func parseFilterTime(value string) (*time.Time, error) {
-	if value == "" {
-		return nil, nil
+	if !project.Keyring {
+		return project.Token, true
 	}
 
-	if unixSeconds, err := strconv.ParseInt(value, 10, 64); err == nil {
-		if unixSeconds < 0 {
-			return nil, errors.New("unix seconds must be non-negative")
+	ring, err := systemKeyring()
+	if err == nil {
+		var token string
+		if token, err = ring.Get(keyringService, project.Name); err == nil {
+			return token, true
 		}
-		parsed := time.Unix(unixSeconds, 0).UTC()
-		return &parsed, nil
-	}
-
-	parsed, err := time.Parse(time.RFC3339, value)
-	if err != nil {
-		return nil, fmt.Errorf("parse rfc3339: %w", err)
 	}
-	utc := parsed.UTC()
+	_, _ = fmt.Fprintf(stderrWriter, "warning: read keyring token for %q: %s\n", project.Name, err)
 
-	return &utc, nil
+	return "", false
 }
 
 func sanitizeError(err error, token string) error {
@@ -742,7 +578,7 @@ func waitForProgressStop(done <-chan struct{}) {
 }
 
 func shouldRenderProgress(format string) bool {
-	if format != "human" {
+	if !isHumanFormat(format) {
 		return false
 	}
 	if os.Getenv("CI") != "" {
@@ -756,6 +592,17 @@ func shouldRenderProgress(format string) bool {
 	return isTerminal(int(file.Fd()))
 }
 
+// terminalColors reports whether human output may use ANSI colors: stdout is
+// a terminal and NO_COLOR is unset.
+func terminalColors() bool {
+	if os.Getenv("NO_COLOR") != "" {
+		return false
+	}
+	file, ok := stdoutFile()
+
+	return ok && isTerminal(int(file.Fd()))
+}
+
 func terminalRenderWidth() int {
 	file, ok := stdoutFile()
 	if !ok {
@@ -14,8 +14,11 @@ import (
 	"testing"
 	"time"
 
+	"github.com/spf13/cobra"
+
 	"github.com/kevinsheth/rollbaz/internal/config"
 	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/redact"
 	"github.com/kevinsheth/rollbaz/internal/rollbar"
 )
 
@@ -30,6 +33,25 @@ func TestRunShowHuman(t *testing.T) {
 	}
 }
 
+func TestItemShowByID(t *testing.T) {
+	stdout := setupServerAndStdout(t, newSuccessHandler(t))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"item", "show", "--id", "1755568172"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("item show error = %v", err)
+	}
+	if !strings.Contains(stdout.String(), "RST_STREAM") || !strings.Contains(stdout.String(), "First Seen") {
+		t.Fatalf("unexpected output: %q", stdout.String())
+	}
+
+	cmd = NewRootCmd()
+	cmd.SetArgs([]string{"item", "show", "--id", "0"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "item id must be greater than 0") {
+		t.Fatalf("expected item id validation error, got %v", err)
+	}
+}
+
 func TestRunActiveJSON(t *testing.T) {
 	stdout, err := runIssueListCommand(
 		t,
@@ -109,7 +131,7 @@ func TestResolveAccessTokenMissing(t *testing.T) {
 }
 
 func TestPrintOutputUnsupportedFormat(t *testing.T) {
-	if err := printOutput("xml", "", nil); err == nil {
+	if err := printOutput(rootFlags{Format: "xml"}, "", nil); err == nil {
 		t.Fatalf("expected unsupported format error")
 	}
 }
@@ -281,6 +303,87 @@ func TestActiveAndRecentSubcommands(t *testing.T) {
 	}
 }
 
+func TestGroupedSubcommands(t *testing.T) {
+	setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/reports/top_active_items":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"item":{"id":1,"counter":2,"title":"x","status":"active","environment":"production"}}]}`)
+		case "/api/1/items":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"y","status":"active","environment":"production"}]}}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+
+	tests := []struct {
+		args    []string
+		wantErr string
+	}{
+		{args: []string{"items", "list"}},
+		{args: []string{"items", "active"}},
+		{args: []string{"item", "resolve", "269"}, wantErr: "confirmation required"},
+		{args: []string{"item", "show", "0"}, wantErr: "greater than 0"},
+	}
+
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		err := cmd.Execute()
+		if tc.wantErr == "" && err != nil {
+			t.Fatalf("%v error = %v", tc.args, err)
+		}
+		if tc.wantErr != "" && (err == nil || !strings.Contains(err.Error(), tc.wantErr)) {
+			t.Fatalf("%v error = %v, want %q", tc.args, err, tc.wantErr)
+		}
+	}
+}
+
+func TestActiveCommandSuggestsEnvironment(t *testing.T) {
+	setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/reports/top_active_items":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"item":{"id":1,"counter":2,"title":"x","status":"active","environment":"production"}}]}`)
+		case "/api/1/environments":
+			if r.URL.RawQuery == "page=1" {
+				_, _ = fmt.Fprint(w, `{"err":0,"result":{"environments":[{"environment":"production"}]}}`)
+				return
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"environments":[]}}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"active", "--env", "prodution"})
+	err := cmd.Execute()
+	if err == nil || !strings.Contains(err.Error(), `did you mean "production"`) {
+		t.Fatalf("expected environment suggestion error, got %v", err)
+	}
+}
+
+func TestEnvironmentsCommandJSON(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/api/1/environments" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		if r.URL.RawQuery == "page=1" {
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"environments":[{"environment":"staging"},{"environment":"production"}]}}`)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"environments":[]}}`)
+	}))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"environments", "--format", "json"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("environments command error = %v", err)
+	}
+	if !strings.Contains(stdout.String(), `"production"`) || !strings.Contains(stdout.String(), `"environments"`) {
+		t.Fatalf("unexpected environments output: %q", stdout.String())
+	}
+}
+
 func TestResolveCommandRequiresConfirmation(t *testing.T) {
 	setupServerAndStdout(t, newActionSuccessHandler(t, nil))
 
@@ -307,8 +410,101 @@ func TestResolveCommandWithYes(t *testing.T) {
 	}
 }
 
+func TestResolveCommandMultipleCounters(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		path := r.URL.Path
+		switch {
+		case strings.HasPrefix(path, "/api/1/item_by_counter/"):
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"itemId":%s}}`, strings.TrimPrefix(path, "/api/1/item_by_counter/"))
+		case r.Method == http.MethodPatch:
+			var patch rollbar.ItemPatch
+			if err := json.NewDecoder(r.Body).Decode(&patch); err != nil || patch.ResolvedInVersion != "abc123" {
+				w.WriteHeader(http.StatusBadRequest)
+				_, _ = fmt.Fprint(w, `{"err":1,"message":"unexpected patch"}`)
+				return
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+		default:
+			counter := strings.Trim(strings.TrimPrefix(path, "/api/1/item/"), "/")
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"id":%s,"counter":%s,"title":"t","status":"resolved"}}`, counter, counter)
+		}
+	}))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"resolve", "269", "270", "--yes", "--until-version", "abc123", "--format", "json"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("resolve command error = %v", err)
+	}
+	if !strings.Contains(stdout.String(), `"issues"`) || !strings.Contains(stdout.String(), `"counter": 270`) {
+		t.Fatalf("unexpected output: %s", stdout.String())
+	}
+
+	cmd = NewRootCmd()
+	cmd.SetArgs([]string{"resolve", "269", "269", "--yes"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "more than once") {
+		t.Fatalf("expected duplicate counter error, got %v", err)
+	}
+}
+
+func TestResolveCommandPartialFailure(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		path := r.URL.Path
+		switch {
+		case strings.HasPrefix(path, "/api/1/item_by_counter/"):
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"itemId":%s}}`, strings.TrimPrefix(path, "/api/1/item_by_counter/"))
+		case r.Method == http.MethodPatch && path == "/api/1/item/270":
+			w.WriteHeader(http.StatusUnprocessableEntity)
+			_, _ = fmt.Fprint(w, `{"err":1,"message":"item is locked"}`)
+		case r.Method == http.MethodPatch:
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+		default:
+			counter := strings.Trim(strings.TrimPrefix(path, "/api/1/item/"), "/")
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"id":%s,"counter":%s,"title":"t","status":"resolved"}}`, counter, counter)
+		}
+	}))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"resolve", "269", "270", "271", "272", "--yes", "--format", "json"})
+	err := cmd.Execute()
+	if err == nil || !strings.Contains(err.Error(), "resolve issue 270") || !strings.Contains(err.Error(), "not attempted: 271, 272") {
+		t.Fatalf("expected an error naming the failed and skipped counters, got %v", err)
+	}
+	if !strings.Contains(stdout.String(), `"counter": 269`) || strings.Contains(stdout.String(), `"counter": 271`) {
+		t.Fatalf("expected only the resolved issue in the output, got %s", stdout.String())
+	}
+}
+
+func TestParseMuteDuration(t *testing.T) {
+	tests := []struct {
+		value   string
+		want    int64
+		wantErr bool
+	}{
+		{value: "2h", want: 7200},
+		{value: "7d", want: 604800},
+		{value: "500ms", wantErr: true},
+		{value: "soon", wantErr: true},
+	}
+
+	for _, tc := range tests {
+		got, err := parseMuteDuration(tc.value)
+		if (err != nil) != tc.wantErr || (!tc.wantErr && (got == nil || *got != tc.want)) {
+			t.Fatalf("parseMuteDuration(%q) = %v, %v", tc.value, got, err)
+		}
+	}
+}
+
+func TestDescribeCounters(t *testing.T) {
+	if got := describeCounters([]domain.ItemCounter{7}); got != "issue 7" {
+		t.Fatalf("describeCounters(single) = %q", got)
+	}
+	if got := describeCounters([]domain.ItemCounter{7, 8}); got != "2 issues (7, 8)" {
+		t.Fatalf("describeCounters(multiple) = %q", got)
+	}
+}
+
 func TestMuteCommandInvalidDuration(t *testing.T) {
-	if err := runMute(context.Background(), rootFlags{}, 269, "500ms"); err == nil {
+	if err := runMute(context.Background(), rootFlags{}, []domain.ItemCounter{269}, "500ms"); err == nil {
 		t.Fatalf("expected invalid duration error")
 	}
 }
@@ -333,6 +529,7 @@ func TestMuteCommandWithYesAndDuration(t *testing.T) {
 func setupServerAndStdout(t *testing.T, handler http.Handler) *bytes.Buffer {
 	t.Helper()
 	t.Setenv("ROLLBAR_ACCESS_TOKEN", "token")
+	setProfilePath(t, filepath.Join(t.TempDir(), "config.toml"))
 
 	server := httptest.NewServer(handler)
 	t.Cleanup(server.Close)
@@ -360,6 +557,28 @@ func runRootCommand(t *testing.T, args ...string) {
 	}
 }
 
+// checkError fails unless err matches want: nil when want is empty, and an
+// error containing want otherwise.
+func checkError(t *testing.T, name string, err error, want string) {
+	t.Helper()
+	if want == "" && err != nil {
+		t.Fatalf("%s: error = %v", name, err)
+	}
+	if want != "" && (err == nil || !strings.Contains(err.Error(), want)) {
+		t.Fatalf("%s: expected %q error, got %v", name, want, err)
+	}
+}
+
+// checkContains fails unless output contains every one of wants.
+func checkContains(t *testing.T, name string, output string, wants ...string) {
+	t.Helper()
+	for _, want := range wants {
+		if !strings.Contains(output, want) {
+			t.Fatalf("%s: expected %q in output:\n%s", name, want, output)
+		}
+	}
+}
+
 func runIssueListCommand(t *testing.T, expectedPath string, responseBody string, run func() error) (*bytes.Buffer, error) {
 	t.Helper()
 
@@ -402,6 +621,58 @@ func TestRunShowJSON(t *testing.T) {
 	}
 }
 
+func TestRunShowJSONScrubsOccurrence(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+		case "/api/1/item/1755568172/":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1755568172,"counter":269,"title":"RST_STREAM","status":"active"}}`)
+		case "/api/1/item/1755568172/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":1,"data":{"request":{"headers":{"Cookie":"sid=abc123"}},"body":{"message":{"body":"x"}}}}]}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+
+	if err := runShow(context.Background(), rootFlags{Format: "json"}, domain.ItemCounter(269), ""); err != nil {
+		t.Fatalf("runShow() error = %v", err)
+	}
+	if strings.Contains(stdout.String(), "sid=abc123") || !strings.Contains(stdout.String(), redact.Scrubbed) {
+		t.Fatalf("expected cookie to be scrubbed, got %s", stdout.String())
+	}
+}
+
+func TestShowSourceRoot(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+		case "/api/1/item/1755568172/":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1755568172,"counter":269,"title":"boom","status":"active"}}`)
+		case "/api/1/item/1755568172/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":1,"data":{"body":{"trace":{"exception":{"class":"Error"},"frames":[{"filename":"/srv/app/pay.go","lineno":2,"method":"charge"}]}}}}]}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+	dir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(dir, "pay.go"), []byte("package pay\npanic(err)\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	runRootCommand(t, "show", "269", "--source-root", dir)
+	if !strings.Contains(stdout.String(), "      1 | package pay\n    > 2 | panic(err)") {
+		t.Fatalf("expected local source under the frame, got %s", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"show", "269", "--source-root", filepath.Join(dir, "missing")})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "--source-root") {
+		t.Fatalf("expected source root error, got %v", err)
+	}
+}
+
 func TestBuildServiceErrors(t *testing.T) {
 	setNoConfigStore(t)
 
@@ -569,6 +840,333 @@ func TestParseFilterTime(t *testing.T) {
 	}
 }
 
+func TestParseFilterTimeRelative(t *testing.T) {
+	tests := []struct {
+		value string
+		ago   time.Duration
+	}{
+		{value: "24h", ago: 24 * time.Hour},
+		{value: "90m", ago: 90 * time.Minute},
+		{value: "7d", ago: 7 * 24 * time.Hour},
+	}
+
+	for _, tc := range tests {
+		parsed, err := parseFilterTime(tc.value)
+		if err != nil || parsed == nil {
+			t.Fatalf("parseFilterTime(%q) = %v, %v", tc.value, parsed, err)
+		}
+		if drift := time.Since(*parsed) - tc.ago; drift < 0 || drift > time.Minute {
+			t.Fatalf("parseFilterTime(%q) = %v, drift %v", tc.value, parsed, drift)
+		}
+	}
+
+	for _, value := range []string{"0d", "-2h", "xd"} {
+		if _, err := parseFilterTime(value); err == nil {
+			t.Fatalf("expected parseFilterTime(%q) error", value)
+		}
+	}
+}
+
+func TestItemsListCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/api/1/items" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		if r.URL.RawQuery != "status=active&level=error&level=warning&environment=production&page=1" {
+			w.WriteHeader(http.StatusBadRequest)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected query %s"}`, r.URL.RawQuery)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"low","level":"warning","status":"active","environment":"production","total_occurrences":2},{"id":2,"counter":4,"title":"high","level":"error","status":"active","environment":"production","total_occurrences":9}],"total_count":2}}`)
+	}))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"items", "list", "--status", "active", "--level", "error,warning", "--env", "production", "--sort", "occurrences"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("items list error = %v", err)
+	}
+
+	out := stdout.String()
+	if !strings.Contains(out, "LEVEL") || strings.Index(out, "high") > strings.Index(out, "low") {
+		t.Fatalf("unexpected output: %q", out)
+	}
+}
+
+func TestItemsListColumns(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/items":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"boom","level":"error","status":"active","environment":"production"}],"total_count":1}}`)
+		case "/api/1/item/1/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":9,"data":{"custom":{"tenant_id":"acme"}}}]}}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+
+	runRootCommand(t, "items", "list", "--column", "data.custom.tenant_id")
+	if out := stdout.String(); !strings.Contains(out, "DATA.CUSTOM.TENANT_ID") || !strings.Contains(out, "acme") {
+		t.Fatalf("expected the tenant column:\n%s", out)
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"items", "list", "--column", "data.frames[x]"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "parse --column") {
+		t.Fatalf("expected a column error, got %v", err)
+	}
+}
+
+func TestItemsListSARIF(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/items":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"KeyError: 'cart'","level":"error","status":"active","environment":"production"}],"total_count":1}}`)
+		case "/api/1/item/1/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":9,"body":{"trace":{"frames":[{"filename":"/srv/app/pay/charge.py","lineno":12,"colno":4}],"exception":{"class":"KeyError"}}}}]}}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+	checkout := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(checkout, "pay"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(checkout, "pay", "charge.py"), []byte("charge()\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	runRootCommand(t, "items", "list", "--format", "sarif", "--source-root", checkout)
+	out := stdout.String()
+	for _, want := range []string{`"version": "2.1.0"`, `"ruleId": "KeyError"`, `"uri": "pay/charge.py"`, `"startLine": 12`, `"rollbarItem/v1": "3"`} {
+		if !strings.Contains(out, want) {
+			t.Fatalf("expected %s in SARIF output:\n%s", want, out)
+		}
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"recent", "--format", "sarif"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "items list and ci check only") {
+		t.Fatalf("expected recent to reject sarif, got %v", err)
+	}
+}
+
+func TestItemsListSARIFInApp(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/items":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"KeyError: 'cart'","level":"error","status":"active"}],"total_count":1}}`)
+		case "/api/1/item/1/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":9,"body":{"trace":{"frames":[{"filename":"/srv/app/pay/charge.py","lineno":12},{"filename":"/srv/app/vendor/requests/api.py","lineno":59}],"exception":{"class":"KeyError"}}}}]}}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+	writeProfiles(t, "in_app = [\"!vendor/**\"]\n")
+	checkout := t.TempDir()
+	for _, file := range []string{"pay/charge.py", "vendor/requests/api.py"} {
+		if err := os.MkdirAll(filepath.Dir(filepath.Join(checkout, file)), 0o750); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(filepath.Join(checkout, file), []byte("call()\n"), 0o600); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	runRootCommand(t, "items", "list", "--format", "sarif", "--source-root", checkout)
+	if out := stdout.String(); !strings.Contains(out, `"uri": "pay/charge.py"`) || strings.Contains(out, "vendor") {
+		t.Fatalf("expected the vendored frame to be skipped:\n%s", out)
+	}
+}
+
+func TestHTTPCacheFlag(t *testing.T) {
+	t.Setenv("XDG_CACHE_HOME", t.TempDir())
+	revalidated := 0
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Header.Get("If-None-Match") == `"items-v1"` {
+			revalidated++
+			w.WriteHeader(http.StatusNotModified)
+			return
+		}
+		w.Header().Set("ETag", `"items-v1"`)
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"cached item","status":"active","environment":"production"}],"total_count":1}}`)
+	}))
+
+	runRootCommand(t, "items", "list")
+	runRootCommand(t, "items", "list", "--http-cache")
+	runRootCommand(t, "items", "list", "--http-cache")
+	if revalidated != 1 || strings.Count(stdout.String(), "cached item") != 3 {
+		t.Fatalf("expected one revalidated listing, got %d:\n%s", revalidated, stdout.String())
+	}
+
+	for _, tc := range []struct {
+		args []string
+		want bool
+	}{
+		{args: nil, want: true},
+		{args: []string{"--http-cache=false"}, want: false},
+	} {
+		cmd := &cobra.Command{Use: "watch"}
+		cmd.Flags().Bool("http-cache", false, "")
+		if err := cmd.ParseFlags(tc.args); err != nil {
+			t.Fatalf("ParseFlags() error = %v", err)
+		}
+		flags := rootFlags{}
+		defaultHTTPCache(cmd, &flags)
+		if flags.HTTPCache != tc.want {
+			t.Fatalf("%v: HTTPCache = %v, want %v", tc.args, flags.HTTPCache, tc.want)
+		}
+	}
+}
+
+func TestOccurrencesCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+		case "/api/1/item/1755568172/instances":
+			if r.URL.RawQuery != "page=1" {
+				t.Fatalf("unexpected query: %s", r.URL.RawQuery)
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":2,"timestamp":1700000100,"data":{"uuid":"u-2","request":{"headers":{"Cookie":"sid=abc123"}},"body":{"message":{"body":"second"}}}},{"id":1,"timestamp":1700000000,"data":{"uuid":"u-1"}}]}}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	}))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"occurrences", "269", "--limit", "1", "--full", "--format", "json"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("occurrences error = %v", err)
+	}
+
+	out := stdout.String()
+	if !strings.Contains(out, `"uuid": "u-2"`) || strings.Contains(out, `"u-1"`) || !strings.Contains(out, `"main_error": "second"`) {
+		t.Fatalf("unexpected output: %s", out)
+	}
+	if strings.Contains(out, "sid=abc123") || !strings.Contains(out, redact.Scrubbed) {
+		t.Fatalf("expected full payload to be scrubbed: %s", out)
+	}
+}
+
+func TestGlobalFormatFlag(t *testing.T) {
+	tests := []struct {
+		format string
+		want   string
+	}{
+		{format: "ndjson", want: `{"counter":3,"environment":"production","item_id":1,`},
+		{format: "yaml", want: "issues:\n  - counter: 3\n    environment: production\n    item_id: 1\n"},
+	}
+
+	for _, tc := range tests {
+		stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"y","status":"active","environment":"production"}]}}`)
+		}))
+
+		cmd := NewRootCmd()
+		cmd.SetArgs([]string{"recent", "--format", tc.format})
+		if err := cmd.Execute(); err != nil {
+			t.Fatalf("%s: recent error = %v", tc.format, err)
+		}
+		if !strings.HasPrefix(stdout.String(), tc.want) || strings.Count(stdout.String(), "\n") < 1 {
+			t.Fatalf("%s: unexpected output:\n%s", tc.format, stdout.String())
+		}
+	}
+
+	setNoConfigStore(t)
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"items", "list", "--format", "xml"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "unsupported format") {
+		t.Fatalf("expected format validation error, got %v", err)
+	}
+}
+
+func TestGlobalJQFlag(t *testing.T) {
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"recent", "--jq", ".issues | map(.counter) | sort | .[]"}, want: "3\n4\n"},
+		{args: []string{"recent", "--format", "ndjson", "--jq", ".issues | sort_by(.counter) | .[] | {counter, title}"}, want: `{"counter":3,"title":"y"}` + "\n" + `{"counter":4,"title":"z"}` + "\n"},
+		{args: []string{"recent", "--format", "yaml", "--jq", "{count: (.issues | length)}"}, want: "count: 2\n"},
+		{args: []string{"recent", "--jq", ".issues[] | select(.counter > 9)"}, want: ""},
+	}
+
+	for _, tc := range tests {
+		stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"y","status":"active","environment":"production"},{"id":2,"counter":4,"title":"z","status":"active","environment":"production"}]}}`)
+		}))
+
+		runRootCommand(t, tc.args...)
+		if got := stdout.String(); got != tc.want {
+			t.Fatalf("%v: output = %q, want %q", tc.args, got, tc.want)
+		}
+	}
+
+	setNoConfigStore(t)
+	for args, want := range map[string]string{
+		"recent --format human --jq .": "--jq needs --format json",
+		"recent --jq .issues[":         "parse --jq",
+		"recent --jq .issues.title":    "expected an object but got: array",
+	} {
+		cmd := NewRootCmd()
+		cmd.SetArgs(strings.Fields(args))
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), want) {
+			t.Fatalf("%s: expected %q, got %v", args, want, err)
+		}
+	}
+}
+
+func TestGlobalTemplateFlag(t *testing.T) {
+	root := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(root, "templates"), 0o700); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(root, "templates", "brief.tmpl"), []byte("{{range .issues}}#{{.counter}} {{upper .title}}\n{{end}}"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	file := filepath.Join(root, "count.txt")
+	if err := os.WriteFile(file, []byte("{{len .issues}} items"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	for _, args := range [][]string{{"recent", "--template", "brief"}, {"recent", "--format", "yaml", "--template", "brief"}, {"recent", "--template", file}} {
+		stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":3,"title":"y","status":"active","environment":"production"},{"id":2,"counter":4,"title":"z","status":"active","environment":"production"}]}}`)
+		}))
+		setProfilePath(t, filepath.Join(root, "config.toml"))
+
+		runRootCommand(t, args...)
+		want := "#3 Y\n#4 Z\n"
+		if args[len(args)-1] == file {
+			want = "2 items\n"
+		}
+		if got := stdout.String(); got != want {
+			t.Fatalf("%v: output = %q, want %q", args, got, want)
+		}
+	}
+
+	setNoConfigStore(t)
+	for args, want := range map[string]string{
+		"recent --template brief --jq .": "--template and --jq cannot be used together",
+		"recent --template missing":      `no template "missing"`,
+	} {
+		cmd := NewRootCmd()
+		cmd.SetArgs(strings.Fields(args))
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), want) {
+			t.Fatalf("%s: expected %q, got %v", args, want, err)
+		}
+	}
+}
+
+func TestParseListOptionsErrors(t *testing.T) {
+	if _, err := parseListOptions(rootFlags{}, itemsListFlags{Sort: "title"}); err == nil || !strings.Contains(err.Error(), "--sort") {
+		t.Fatalf("expected sort error, got %v", err)
+	}
+	if _, err := parseListOptions(rootFlags{}, itemsListFlags{Levels: []string{"fatal"}}); err == nil || !strings.Contains(err.Error(), "unsupported level") {
+		t.Fatalf("expected level error, got %v", err)
+	}
+}
+
 func overrideClientFactory(factory func(string) (*rollbar.Client, error)) func() {
 	original := newRollbarClient
 	newRollbarClient = factory
@@ -591,7 +1189,7 @@ func runShowForFormat(t *testing.T, format string) (*bytes.Buffer, error) {
 	t.Helper()
 	stdout := setupServerAndStdout(t, newSuccessHandler(t))
 
-	err := runShow(context.Background(), rootFlags{Format: format}, domain.ItemCounter(269))
+	err := runShow(context.Background(), rootFlags{Format: format}, domain.ItemCounter(269), "")
 
 	return stdout, err
 }
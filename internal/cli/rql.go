@@ -0,0 +1,74 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const defaultRQLTimeout = 2 * time.Minute
+
+type rqlFlags struct {
+	CSV     bool
+	Timeout time.Duration
+}
+
+func newRQLCmd(flags *rootFlags) *cobra.Command {
+	rql := rqlFlags{}
+	rqlCmd := &cobra.Command{
+		Use:   "rql <query>",
+		Short: "Run an RQL query and print its rows",
+		Long:  "Run an RQL query, wait for the job to finish, and print its rows. For example:\n\n  rollbaz rql \"SELECT item.counter, count(*) FROM item_occurrence WHERE timestamp > unix_timestamp() - 86400 GROUP BY 1\"",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runRQL(cmd.Context(), *flags, rql, args[0])
+		},
+	}
+	rqlCmd.Flags().BoolVar(&rql.CSV, "csv", false, "Print rows as CSV instead of --format output")
+	rqlCmd.Flags().DurationVar(&rql.Timeout, "timeout", defaultRQLTimeout, "Maximum time to wait for the query job")
+
+	return rqlCmd
+}
+
+func runRQL(parent context.Context, flags rootFlags, rql rqlFlags, query string) error {
+	if strings.TrimSpace(query) == "" {
+		return errors.New("rql query is required")
+	}
+	if rql.Timeout <= 0 {
+		return errors.New("--timeout must be positive")
+	}
+
+	ctx, cancel := context.WithTimeout(parent, rql.Timeout)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	result, err := runWithProgress(flags.Format, "Running query", func() (app.QueryResult, error) {
+		return app.RunQuery(ctx, client, query)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	if rql.CSV {
+		rendered, err := output.RenderQueryCSV(result)
+		if err != nil {
+			return fmt.Errorf("render csv: %w", err)
+		}
+		_, _ = fmt.Fprintln(stdoutWriter, rendered)
+		return nil
+	}
+
+	return printOutput(flags, output.RenderQueryTableWithWidth(result, terminalRenderWidth()), redact.Value(result, token))
+}
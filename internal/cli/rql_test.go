@@ -0,0 +1,52 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+func newRQLHandler() http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.Method == http.MethodPost && r.URL.Path == "/api/1/rql/jobs":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":5,"status":"success"}}`)
+		case r.URL.Path == "/api/1/rql/job/5/result":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"job_id":5,"result":{"columns":["item.counter","count(*)"],"rows":[[269,12],[270,3]],"rowcount":2}}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+		}
+	})
+}
+
+func TestRQLCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, newRQLHandler())
+	query := "SELECT item.counter, count(*) FROM item_occurrence GROUP BY 1"
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "table", args: []string{"rql", query}, want: "2 rows"},
+		{name: "csv", args: []string{"rql", query, "--csv"}, want: "item.counter,count(*)\n269,12\n270,3\n"},
+		{name: "json", args: []string{"rql", query, "--format", "json"}, want: `"job_id": 5`},
+	}
+	for _, tc := range tests {
+		stdout.Reset()
+		runRootCommand(t, tc.args...)
+		if !strings.Contains(stdout.String(), tc.want) {
+			t.Fatalf("%s: expected %q in output, got %q", tc.name, tc.want, stdout.String())
+		}
+	}
+
+	for _, args := range [][]string{{"rql", " "}, {"rql", query, "--timeout", "0s"}} {
+		cmd := NewRootCmd()
+		cmd.SetArgs(args)
+		if err := cmd.Execute(); err == nil {
+			t.Fatalf("%v: expected error", args)
+		}
+	}
+}
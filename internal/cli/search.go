@@ -0,0 +1,80 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/store"
+)
+
+func newSearchCmd(flags *rootFlags) *cobra.Command {
+	fuzzy := false
+	searchCmd := &cobra.Command{
+		Use:   "search <text>...",
+		Short: "Find items by free text, best matches first",
+		Long:  "Run Rollbar's free-text item search and rank the matches by how well their titles fit the text: the whole phrase first, then whole words, then words inside longer ones. For example:\n\n  rollbaz search \"NullPointerException checkout\" --env production",
+		Args:  cobra.MinimumNArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runSearch(cmd.Context(), *flags, strings.Join(args, " "), fuzzy)
+		},
+	}
+	searchCmd.Flags().BoolVar(&fuzzy, "fuzzy", false, "Fuzzy-match titles and exception classes in the local store filled by `rollbaz sync`")
+
+	return searchCmd
+}
+
+func runSearch(parent context.Context, flags rootFlags, text string, fuzzy bool) error {
+	filters, err := parseIssueFilters(flags)
+	if err != nil {
+		return err
+	}
+	options := app.SearchOptions{Text: text, Filters: filters, Limit: flags.Limit}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	search, token, err := searcher(ctx, flags, options, fuzzy)
+	if err != nil {
+		return err
+	}
+	results, err := runWithProgress(flags.Format, "Searching items", search)
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	jsonPayload := redact.Value(map[string]any{"query": text, "results": results}, token)
+	return printOutput(flags, output.RenderSearchResultsWithWidth(text, results, terminalRenderWidth()), jsonPayload)
+}
+
+// searcher picks Rollbar's item search, or fuzzy matching over the local
+// store with --fuzzy.
+func searcher(ctx context.Context, flags rootFlags, options app.SearchOptions, fuzzy bool) (func() ([]app.SearchResult, error), string, error) {
+	if !fuzzy {
+		service, token, err := buildService(flags)
+		if err != nil {
+			return nil, token, err
+		}
+		return func() ([]app.SearchResult, error) { return service.Search(ctx, options) }, token, nil
+	}
+
+	token, err := resolveAccessToken(flags)
+	if err != nil {
+		return nil, "", err
+	}
+	db, err := openStore(ctx, token)
+	if err != nil {
+		return nil, token, err
+	}
+	if mark, err := db.HighWaterMark(ctx); err == nil && mark == 0 {
+		return nil, token, fmt.Errorf("fuzzy search: %w", store.ErrNotSynced)
+	}
+
+	return func() ([]app.SearchResult, error) { return app.NewService(db).FuzzySearch(ctx, db, options) }, token, nil
+}
@@ -0,0 +1,48 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os/exec"
+	"strings"
+	"testing"
+)
+
+func TestSearchCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/api/1/items" || r.URL.Query().Get("query") != "NullPointerException checkout" || r.URL.Query().Get("environment") != "production" {
+			w.WriteHeader(http.StatusBadRequest)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s"}`, r.URL.RawQuery)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":7,"title":"checkout slow","status":"active","environment":"production"},{"id":2,"counter":8,"title":"NullPointerException checkout","status":"active","environment":"production"}],"total_count":2}}`)
+	}))
+
+	runRootCommand(t, "search", "NullPointerException", "checkout", "--env", "production")
+	rendered := stdout.String()
+	if best, other := strings.Index(rendered, "NullPointerException checkout"), strings.Index(rendered, "checkout slow"); best < 0 || other < best {
+		t.Fatalf("expected the exact match first:\n%s", rendered)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "search", "NullPointerException checkout", "--env", "production", "--format", "json")
+	if !strings.Contains(stdout.String(), `"score": 16`) || !strings.Contains(stdout.String(), `"counter": 8`) {
+		t.Fatalf("unexpected JSON output:\n%s", stdout.String())
+	}
+}
+
+func TestFuzzySearchNeedsSync(t *testing.T) {
+	if _, err := exec.LookPath("sqlite3"); err != nil {
+		t.Skip("sqlite3 not installed")
+	}
+	t.Setenv("XDG_CACHE_HOME", t.TempDir())
+	setupServerAndStdout(t, http.HandlerFunc(func(http.ResponseWriter, *http.Request) {
+		t.Error("expected fuzzy search not to call the API")
+	}))
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"search", "--fuzzy", "paymnt timout"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "run `rollbaz sync`") {
+		t.Fatalf("expected a sync hint, got %v", err)
+	}
+}
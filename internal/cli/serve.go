@@ -0,0 +1,295 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"os"
+	"os/signal"
+	"path/filepath"
+	"slices"
+	"strconv"
+	"syscall"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/webhook"
+)
+
+const (
+	serveShutdownTimeout = 10 * time.Second
+	// webhookQueueSize is how many verified deliveries can wait for the
+	// worker before the handler refuses more.
+	webhookQueueSize = 64
+)
+
+type serveFlags struct {
+	Host   string
+	Port   int
+	Secret string
+	Events []string
+	Exec   string
+	Notify []string
+	Log    string
+}
+
+func newServeCmd(flags *rootFlags) *cobra.Command {
+	serve := serveFlags{}
+	serveCmd := &cobra.Command{
+		Use:   "serve",
+		Short: "Receive Rollbar webhooks and act on them",
+		Long:  "Run an HTTP server that receives Rollbar webhooks, authenticates them with a shared secret, and prints each event, optionally running a command, forwarding to Slack, or appending to an ndjson log. Point a Rollbar webhook notification at http://host:port/?secret=<secret>, or sign requests with the X-Rollbar-Signature header:\n\n  rollbaz serve --port 8080 --secret \"$ROLLBAZ_WEBHOOK_SECRET\" --notify slack",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			ctx, stop := signal.NotifyContext(cmd.Context(), os.Interrupt, syscall.SIGTERM)
+			defer stop()
+
+			return runServe(ctx, *flags, serve)
+		},
+	}
+	serveCmd.Flags().StringVar(&serve.Host, "host", "", "Interface to listen on (default: all)")
+	serveCmd.Flags().IntVar(&serve.Port, "port", 8080, "Port to listen on")
+	serveCmd.Flags().StringVar(&serve.Secret, "secret", "", "Shared secret webhooks must carry (default: $ROLLBAZ_WEBHOOK_SECRET)")
+	serveCmd.Flags().StringSliceVar(&serve.Events, "event", nil, "Only handle these events, such as new_item or deploy (repeatable)")
+	serveCmd.Flags().StringVar(&serve.Exec, "exec", "", "Shell command to run per event; the payload is on stdin and item fields are ROLLBAZ_* environment variables")
+	serveCmd.Flags().StringSliceVar(&serve.Notify, "notify", nil, "Also send item events to: slack (repeatable)")
+	serveCmd.Flags().StringVar(&serve.Log, "log", "", "Append each payload to this ndjson file")
+
+	return serveCmd
+}
+
+// runServe serves webhooks until ctx is cancelled, then drains in-flight
+// deliveries and the events queued for the worker.
+func runServe(ctx context.Context, flags rootFlags, serve serveFlags) error {
+	secret := firstNonEmpty(serve.Secret, os.Getenv("ROLLBAZ_WEBHOOK_SECRET"))
+	if secret == "" {
+		return errors.New("--secret or ROLLBAZ_WEBHOOK_SECRET is required")
+	}
+	dispatcher, closeLog, err := newWebhookDispatcher(flags, serve)
+	if err != nil {
+		return err
+	}
+	defer closeLog()
+
+	listener, err := net.Listen("tcp", net.JoinHostPort(serve.Host, strconv.Itoa(serve.Port)))
+	if err != nil {
+		return fmt.Errorf("listen: %w", err)
+	}
+	// Actions outlive ctx, which ends at the first signal, so queued events
+	// still run while the server drains.
+	workCtx, cancelWork := context.WithCancel(context.WithoutCancel(ctx))
+	defer cancelWork()
+	dispatcher.start(workCtx)
+	server := &http.Server{
+		Handler:           webhook.Handler(secret, dispatcher.enqueue),
+		ReadHeaderTimeout: 10 * time.Second,
+		ReadTimeout:       30 * time.Second,
+		WriteTimeout:      2 * time.Minute,
+	}
+	_, _ = fmt.Fprintf(stderrWriter, "listening for Rollbar webhooks on %s\n", listener.Addr())
+
+	served := make(chan error, 1)
+	go func() { served <- server.Serve(listener) }()
+	select {
+	case err := <-served:
+		return fmt.Errorf("serve: %w", err)
+	case <-ctx.Done():
+		return shutdownServer(server, dispatcher.drain)
+	}
+}
+
+// webhookAction is something done with each received event besides printing
+// it.
+type webhookAction struct {
+	name string
+	run  func(ctx context.Context, event webhook.Event) error
+}
+
+// webhookDispatcher hands deliveries to a single worker, so the handler can
+// answer Rollbar without waiting on --exec or Slack, and printed events and
+// log lines never interleave.
+type webhookDispatcher struct {
+	format  string
+	events  []string
+	actions []webhookAction
+	queue   chan webhook.Event
+	done    chan struct{}
+}
+
+// newWebhookDispatcher builds the configured actions. The returned func
+// closes the --log file.
+func newWebhookDispatcher(flags rootFlags, serve serveFlags) (*webhookDispatcher, func(), error) {
+	if _, err := output.ParseFormat(flags.Format); err != nil {
+		return nil, nil, err
+	}
+	dispatcher := &webhookDispatcher{format: flags.Format, events: serve.Events}
+	if serve.Exec != "" {
+		dispatcher.actions = append(dispatcher.actions, webhookAction{name: "--exec", run: func(ctx context.Context, event webhook.Event) error {
+			return runWebhookExec(ctx, serve.Exec, event)
+		}})
+	}
+	for _, target := range serve.Notify {
+		if target != "slack" {
+			return nil, nil, fmt.Errorf("unknown --notify target %q; use slack", target)
+		}
+		action, err := slackWebhookAction(flags)
+		if err != nil {
+			return nil, nil, err
+		}
+		dispatcher.actions = append(dispatcher.actions, action)
+	}
+	if serve.Log == "" {
+		return dispatcher, func() {}, nil
+	}
+
+	file, err := os.OpenFile(filepath.Clean(serve.Log), os.O_WRONLY|os.O_CREATE|os.O_APPEND, 0o600)
+	if err != nil {
+		return nil, nil, fmt.Errorf("open --log: %w", err)
+	}
+	dispatcher.actions = append(dispatcher.actions, webhookAction{name: "--log", run: func(_ context.Context, event webhook.Event) error {
+		return appendNDJSON(file, event.Raw)
+	}})
+
+	return dispatcher, func() { _ = file.Close() }, nil
+}
+
+// start runs the worker, which handles queued deliveries in order with ctx
+// until the queue is drained or ctx ends.
+func (d *webhookDispatcher) start(ctx context.Context) {
+	d.queue = make(chan webhook.Event, webhookQueueSize)
+	d.done = make(chan struct{})
+	go func() {
+		defer close(d.done)
+		for {
+			select {
+			case event, ok := <-d.queue:
+				if !ok {
+					return
+				}
+				d.dispatch(ctx, event)
+			case <-ctx.Done():
+				return
+			}
+		}
+	}()
+}
+
+// enqueue queues a verified delivery for the worker, skipping events that
+// --event leaves out. It never waits: with the queue full it reports false,
+// and the delivery is refused with a 503 rather than holding Rollbar's
+// request open.
+func (d *webhookDispatcher) enqueue(event webhook.Event) bool {
+	if len(d.events) > 0 && !slices.Contains(d.events, event.Name) {
+		return true
+	}
+	select {
+	case d.queue <- event:
+		return true
+	default:
+		_, _ = fmt.Fprintf(stderrWriter, "serve: %d deliveries queued; refused %s\n", webhookQueueSize, event.Name)
+		return false
+	}
+}
+
+// drain closes the queue once the server has stopped handing over
+// deliveries, and waits for the worker to finish it or for ctx to end.
+func (d *webhookDispatcher) drain(ctx context.Context) error {
+	close(d.queue)
+	select {
+	case <-d.done:
+		return nil
+	case <-ctx.Done():
+		return fmt.Errorf("drain webhook queue: %w", ctx.Err())
+	}
+}
+
+// dispatch prints a delivery and runs every action on it. The payload is
+// scrubbed of sensitive values first, since it is echoed and stored.
+func (d *webhookDispatcher) dispatch(ctx context.Context, event webhook.Event) {
+	event.Raw = redact.DefaultScrubber().ScrubJSON(event.Raw)
+
+	if err := printWebhookEvent(d.format, event); err != nil {
+		_, _ = fmt.Fprintf(stderrWriter, "serve: %s\n", err)
+	}
+	for _, action := range d.actions {
+		if err := action.run(ctx, event); err != nil {
+			_, _ = fmt.Fprintf(stderrWriter, "serve: %s for %s: %s\n", action.name, event.Name, err)
+		}
+	}
+}
+
+// printWebhookEvent prints one line per delivery; JSON output is the payload
+// itself, one compact document per line as with watch.
+func printWebhookEvent(format string, event webhook.Event) error {
+	parsed, err := output.ParseFormat(format)
+	if err != nil {
+		return err
+	}
+	if parsed.Human() {
+		_, _ = fmt.Fprintln(stdoutWriter, output.RenderWebhookEvent(event, time.Now()))
+		return nil
+	}
+
+	return appendNDJSON(stdoutWriter, event.Raw)
+}
+
+func appendNDJSON(writer io.Writer, raw json.RawMessage) error {
+	var line bytes.Buffer
+	if err := json.Compact(&line, raw); err != nil {
+		return fmt.Errorf("compact payload: %w", err)
+	}
+	line.WriteByte('\n')
+	if _, err := writer.Write(line.Bytes()); err != nil {
+		return fmt.Errorf("write payload: %w", err)
+	}
+
+	return nil
+}
+
+func runWebhookExec(ctx context.Context, command string, event webhook.Event) error {
+	env := []string{"ROLLBAZ_EVENT=" + event.Name}
+	if issue, ok := app.WebhookIssue(event); ok {
+		env = append(env, itemEnv(issue)...)
+	}
+
+	return runEventCommand(ctx, command, env, event.Raw)
+}
+
+// slackWebhookAction posts item events as cards, using the same destination
+// settings as `item post-slack`. Deploy and test events are not forwarded.
+func slackWebhookAction(flags rootFlags) (webhookAction, error) {
+	client, secret, err := newSlackClient(flags, slackFlags{})
+	if err != nil {
+		return webhookAction{}, err
+	}
+	location := itemWebLocation(domain.ItemRef{}, flags.Account, webProjectSlug(flags))
+
+	run := func(ctx context.Context, event webhook.Event) error {
+		issue, ok := app.WebhookIssue(event)
+		if !ok {
+			return nil
+		}
+		link, err := app.WebhookItemLink(event, location)
+		if err != nil {
+			return err
+		}
+		card := output.SlackCard{Heading: output.WebhookEventHeading(event.Name), Issue: issue, Link: link}
+		if err := app.PostToSlack(ctx, client, output.RenderSlackMessage(card)); err != nil {
+			return sanitizeError(err, secret)
+		}
+
+		return nil
+	}
+
+	return webhookAction{name: "slack", run: run}, nil
+}
@@ -0,0 +1,165 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/webhook"
+)
+
+const serveNewItemPayload = `{"event_name":"new_item","data":{"item":{"id":9,"counter":4,"title":"boom","environment":"production","level":40,"status":1},"occurrence":{"uuid":"0f1e","access_token":"leaked"}}}`
+
+func postWebhook(t *testing.T, url string, body string) {
+	t.Helper()
+
+	request, err := http.NewRequestWithContext(context.Background(), http.MethodPost, url, strings.NewReader(body))
+	if err != nil {
+		t.Fatalf("NewRequest() error = %v", err)
+	}
+	response, err := http.DefaultClient.Do(request)
+	if err != nil {
+		t.Fatalf("POST error = %v", err)
+	}
+	_ = response.Body.Close()
+	if response.StatusCode != http.StatusNoContent {
+		t.Fatalf("POST status = %d", response.StatusCode)
+	}
+}
+
+func TestWebhookDispatcher(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.NotFoundHandler())
+	slackServer, posted := newSlackServer(t)
+	logPath := filepath.Join(t.TempDir(), "events.ndjson")
+
+	flags := rootFlags{Format: "human", SlackWebhookURL: slackServer.URL + "/hook"}
+	serve := serveFlags{
+		Events: []string{webhook.EventNewItem, webhook.EventDeploy},
+		Exec:   "echo exec-$ROLLBAZ_EVENT-$ROLLBAZ_ITEM_COUNTER; grep -c 0f1e || true",
+		Notify: []string{"slack"},
+		Log:    logPath,
+	}
+	dispatcher, closeLog, err := newWebhookDispatcher(flags, serve)
+	if err != nil {
+		t.Fatalf("newWebhookDispatcher() error = %v", err)
+	}
+	t.Cleanup(closeLog)
+	dispatcher.start(context.Background())
+	server := httptest.NewServer(webhook.Handler("s3cret", dispatcher.enqueue))
+
+	postWebhook(t, server.URL+"/?secret=s3cret", serveNewItemPayload)
+	postWebhook(t, server.URL+"/?secret=s3cret", `{"event_name":"resolved_item","data":{}}`)
+	postWebhook(t, server.URL+"/?secret=s3cret", `{"event_name":"deploy","data":{"deploy":{"environment":"production","revision":"abc123"}}}`)
+	server.Close()
+	if err := dispatcher.drain(context.Background()); err != nil {
+		t.Fatalf("drain() error = %v", err)
+	}
+
+	checkContains(t, "dispatcher", stdout.String(), "new_item #4 error production boom", "exec-new_item-4\n1\n", "deploy production abc123", "exec-deploy-\n0\n")
+	if strings.Contains(stdout.String(), "resolved_item") {
+		t.Fatalf("expected resolved_item to be filtered out:\n%s", stdout.String())
+	}
+
+	messages := posted()
+	if len(messages) != 1 || !strings.HasPrefix(messages[0].Text, "New item: #4 boom") || messages[0].Blocks[2].Elements[0].URL != "https://rollbar.com/occurrence/uuid/?uuid=0f1e" {
+		t.Fatalf("unexpected slack messages: %+v", messages)
+	}
+
+	checkEventLog(t, logPath)
+}
+
+// checkEventLog fails unless the event log at path holds the new item and
+// deploy events with the occurrence access token scrubbed.
+func checkEventLog(t *testing.T, path string) {
+	t.Helper()
+
+	logged, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("ReadFile() error = %v", err)
+	}
+	lines := bytes.Split(bytes.TrimSpace(logged), []byte("\n"))
+	if len(lines) != 2 || !bytes.Contains(lines[0], []byte(`"counter":4`)) || bytes.Contains(logged, []byte("leaked")) {
+		t.Fatalf("unexpected log:\n%s", logged)
+	}
+}
+
+func TestWebhookDispatcherAnswersBeforeActions(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.NotFoundHandler())
+	release := filepath.Join(t.TempDir(), "release")
+	dispatcher, closeLog, err := newWebhookDispatcher(rootFlags{Format: "human"}, serveFlags{Exec: "while [ ! -e '" + release + "' ]; do sleep 0.01; done; echo ran"})
+	if err != nil {
+		t.Fatalf("newWebhookDispatcher() error = %v", err)
+	}
+	t.Cleanup(closeLog)
+	dispatcher.start(context.Background())
+	server := httptest.NewServer(webhook.Handler("s3cret", dispatcher.enqueue))
+
+	// The --exec command waits for the release file, so these only return
+	// because the deliveries are answered before the worker runs them.
+	postWebhook(t, server.URL+"/?secret=s3cret", serveNewItemPayload)
+	postWebhook(t, server.URL+"/?secret=s3cret", serveNewItemPayload)
+	server.Close()
+	if err := os.WriteFile(release, nil, 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), serveShutdownTimeout)
+	defer cancel()
+	if err := dispatcher.drain(ctx); err != nil {
+		t.Fatalf("drain() error = %v", err)
+	}
+	if got := strings.Count(stdout.String(), "ran\n"); got != 2 {
+		t.Fatalf("expected both queued events to run on drain, got %d:\n%s", got, stdout.String())
+	}
+}
+
+func TestWebhookDispatcherRefusesWhenFull(t *testing.T) {
+	stderr := setupStderr(t)
+	dispatcher, closeLog, err := newWebhookDispatcher(rootFlags{Format: "human"}, serveFlags{Events: []string{webhook.EventNewItem}})
+	if err != nil {
+		t.Fatalf("newWebhookDispatcher() error = %v", err)
+	}
+	t.Cleanup(closeLog)
+	dispatcher.queue = make(chan webhook.Event, 1)
+
+	newItem := webhook.Event{Name: webhook.EventNewItem}
+	if !dispatcher.enqueue(newItem) || dispatcher.enqueue(newItem) {
+		t.Fatal("expected the second delivery to be refused once the queue is full")
+	}
+	if !dispatcher.enqueue(webhook.Event{Name: "deploy"}) {
+		t.Fatal("expected a filtered-out event to be accepted and dropped")
+	}
+	if !strings.Contains(stderr.String(), "refused new_item") {
+		t.Fatalf("expected a refusal warning, got %q", stderr.String())
+	}
+}
+
+func TestRunServe(t *testing.T) {
+	_ = setupServerAndStdout(t, http.NotFoundHandler())
+	t.Setenv("ROLLBAZ_WEBHOOK_SECRET", "")
+
+	tests := []struct {
+		serve serveFlags
+		want  string
+	}{
+		{serve: serveFlags{}, want: "--secret or ROLLBAZ_WEBHOOK_SECRET is required"},
+		{serve: serveFlags{Secret: "s3cret", Notify: []string{"pager"}}, want: "unknown --notify target"},
+		{serve: serveFlags{Secret: "s3cret", Port: -1}, want: "listen"},
+	}
+	for _, tc := range tests {
+		if err := runServe(context.Background(), rootFlags{Format: "human"}, tc.serve); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%+v: expected %q error, got %v", tc.serve, tc.want, err)
+		}
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+	if err := runServe(ctx, rootFlags{Format: "human"}, serveFlags{Host: "127.0.0.1", Secret: "s3cret"}); err != nil {
+		t.Fatalf("runServe() after cancel error = %v", err)
+	}
+}
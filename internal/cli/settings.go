@@ -0,0 +1,182 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/settings"
+)
+
+const settingsTimeout = 2 * time.Minute
+
+// settingsTarget is the project a settings file is compared with: a project
+// token client for notification rules and, when the file declares access
+// tokens, an account token client and the resolved project id.
+type settingsTarget struct {
+	notifications app.NotificationAPI
+	tokens        app.AccessTokenAPI
+	projectID     uint64
+	secrets       []string
+}
+
+func newConfigCmd(flags *rootFlags) *cobra.Command {
+	configCmd := &cobra.Command{Use: "config", Short: "Manage project notification rules and access tokens from a settings file"}
+	configCmd.AddCommand(
+		newConfigDiffCmd(flags),
+		newConfigApplyCmd(flags),
+	)
+
+	return configCmd
+}
+
+func newConfigDiffCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "diff <settings.yaml>",
+		Short: "Show what config apply would change",
+		Long:  "Compare the project with a settings file and print what config apply would change. Exits 2 when the project differs, so CI can catch settings changed by hand.",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runConfigDiff(cmd.Context(), *flags, args[0])
+		},
+	}
+}
+
+func newConfigApplyCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "apply <settings.yaml>",
+		Short: "Change the project to match a settings file",
+		Long:  "Create and delete notification rules and create or update access tokens until the project matches a settings file. The changes are printed and confirmed first (--yes skips the prompt). Access token scopes cannot be changed through Rollbar's API and are reported as conflicts.",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runConfigApply(cmd.Context(), *flags, args[0])
+		},
+	}
+}
+
+func runConfigDiff(parent context.Context, flags rootFlags, path string) error {
+	ctx, cancel := context.WithTimeout(parent, settingsTimeout)
+	defer cancel()
+
+	target, plan, err := planSettings(ctx, flags, path)
+	if err != nil {
+		return err
+	}
+	if err := printOutput(flags, output.RenderSettingsPlan(plan), target.redact(plan)); err != nil {
+		return err
+	}
+	if len(plan.Changes) > 0 {
+		return &exitCodeError{code: exitCheckFailed, err: fmt.Errorf("project differs from %s", path)}
+	}
+
+	return nil
+}
+
+func runConfigApply(parent context.Context, flags rootFlags, path string) error {
+	ctx, cancel := context.WithTimeout(parent, settingsTimeout)
+	defer cancel()
+
+	target, plan, err := planSettings(ctx, flags, path)
+	if err != nil {
+		return err
+	}
+	pending := len(plan.Changes) - plan.Count(app.SettingsConflict)
+	if pending == 0 {
+		return printOutput(flags, output.RenderSettingsPlan(plan), target.redact(map[string]any{"applied": 0, "plan": plan}))
+	}
+	if isHumanFormat(flags.Format) {
+		_, _ = fmt.Fprintln(stdoutWriter, output.RenderSettingsPlan(plan)+"\n")
+	}
+	if err := confirmAction(flags, fmt.Sprintf("apply %d changes", pending)); err != nil {
+		return err
+	}
+
+	applied, err := app.ApplySettings(ctx, target.notifications, target.tokens, plan)
+	if err != nil {
+		return target.sanitize(fmt.Errorf("applied %d of %d changes: %w", applied, pending, err))
+	}
+
+	human := fmt.Sprintf("changes applied: %d", applied)
+	if conflicts := plan.Count(app.SettingsConflict); conflicts > 0 {
+		human += fmt.Sprintf("; %d conflicts need the project settings page", conflicts)
+	}
+	return printOutput(flags, human, target.redact(map[string]any{"applied": applied, "plan": plan}))
+}
+
+func planSettings(ctx context.Context, flags rootFlags, path string) (settingsTarget, app.SettingsPlan, error) {
+	file, err := settings.Load(path)
+	if err != nil {
+		return settingsTarget{}, app.SettingsPlan{}, err
+	}
+	if flags.Project == "" {
+		flags.Project = file.Project
+	}
+	target, err := newSettingsTarget(flags, file)
+	if err != nil {
+		return settingsTarget{}, app.SettingsPlan{}, err
+	}
+
+	plan, err := runWithProgress(flags.Format, "Comparing settings", func() (app.SettingsPlan, error) {
+		return app.PlanSettings(ctx, target.notifications, target.tokens, file, target.projectID)
+	})
+	if err != nil {
+		return settingsTarget{}, app.SettingsPlan{}, target.sanitize(err)
+	}
+
+	return target, plan, nil
+}
+
+// newSettingsTarget builds only the clients the file needs, so a file of
+// notification rules works without an account token.
+func newSettingsTarget(flags rootFlags, file settings.File) (settingsTarget, error) {
+	target := settingsTarget{}
+	if len(file.Notifications) > 0 {
+		client, token, err := buildClient(flags)
+		if err != nil {
+			return settingsTarget{}, err
+		}
+		target.notifications = client
+		target.secrets = append(target.secrets, token)
+	}
+	if len(file.AccessTokens) == 0 {
+		return target, nil
+	}
+
+	if flags.Project == "" {
+		return settingsTarget{}, errors.New("access_tokens need the Rollbar project: set project in the settings file or pass --project")
+	}
+	client, projectID, accountToken, err := buildAccountClient(flags, "access_tokens")
+	if err != nil {
+		return settingsTarget{}, err
+	}
+	target.tokens = client
+	target.projectID = projectID
+	target.secrets = append(target.secrets, accountToken)
+
+	return target, nil
+}
+
+func (t settingsTarget) sanitize(err error) error {
+	for _, secret := range t.secrets {
+		err = sanitizeError(err, secret)
+	}
+
+	return err
+}
+
+// redact masks credential keys, such as the access tokens in a plan, and the
+// tokens rollbaz authenticated with.
+func (t settingsTarget) redact(payload any) any {
+	redacted := redact.Value(payload, "")
+	for _, secret := range t.secrets {
+		redacted = redact.Value(redacted, secret)
+	}
+
+	return redacted
+}
@@ -0,0 +1,139 @@
+package cli
+
+import (
+	"bytes"
+	"fmt"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+const settingsFile = `project: 7
+notifications:
+  slack:
+    - trigger: new_item
+      config:
+        channel: "#alerts"
+access_tokens:
+  - name: reader
+    scopes: [read]
+    rate_limit_window_size: 60
+    rate_limit_window_count: 1000
+  - name: ci
+    scopes: [post_server_item]
+`
+
+// serveSettings serves slack rule 2 and a reader token limited to 100
+// requests a minute, and accepts the changes settingsFile plans.
+func serveSettings(w http.ResponseWriter, r *http.Request) {
+	body, _ := io.ReadAll(r.Body)
+	switch r.Method + " " + r.URL.Path + " " + string(body) {
+	case "GET /api/1/project/7/access_tokens ":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"name":"reader","access_token":"reader-token","status":"enabled","scopes":["read"],"rate_limit_window_size":60,"rate_limit_window_count":100}]}`)
+	case "GET /api/1/notifications/slack/rules ":
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":2,"trigger":"occurrence","filters":[]}]}`)
+	case `POST /api/1/notifications/slack/rules [{"trigger":"new_item","filters":[],"config":{"channel":"#alerts"}}]`:
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":3,"trigger":"new_item","filters":[]}]}`)
+	case "DELETE /api/1/notifications/slack/rule/2 ":
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	case `POST /api/1/project/7/access_tokens {"name":"ci","scopes":["post_server_item"]}`:
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"name":"ci","access_token":"ci-token","scopes":["post_server_item"]}}`)
+	case `PATCH /api/1/project/7/access_token/reader-token {"rate_limit_window_size":60,"rate_limit_window_count":1000}`:
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	default:
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s %s"}`, r.Method, r.URL.Path, body)
+	}
+}
+
+// useAccountToken sets an account token and stops project ids from being
+// cached between tests.
+func useAccountToken(t *testing.T) {
+	t.Helper()
+
+	setNoConfigStore(t)
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "account-token")
+	original := newProjectCache
+	newProjectCache = func() (app.ProjectIDCache, error) {
+		return noProjectCache{}, nil
+	}
+	t.Cleanup(func() {
+		newProjectCache = original
+	})
+}
+
+// setupSettings points the commands at serveSettings with an account
+// token and returns their output and the path of settingsFile.
+func setupSettings(t *testing.T) (*bytes.Buffer, string) {
+	t.Helper()
+
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveSettings))
+	useAccountToken(t)
+	path := filepath.Join(t.TempDir(), "settings.yaml")
+	if err := os.WriteFile(path, []byte(settingsFile), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	return stdout, path
+}
+
+func TestConfigDiff(t *testing.T) {
+	stdout, path := setupSettings(t)
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"config", "diff", path})
+	if err := cmd.Execute(); err == nil || exitCode(err) != exitCheckFailed {
+		t.Fatalf("expected exit code %d, got %v", exitCheckFailed, err)
+	}
+	checkContains(t, "diff", stdout.String(), "+ slack rule: new_item [-] {channel=#alerts}", "- slack rule 2: occurrence", "~ access token reader: rate limit 100/60s -> 1000/60s", "+ access token ci: post_server_item", "2 to create, 1 to update, 1 to delete")
+
+	stdout.Reset()
+	cmd = NewRootCmd()
+	cmd.SetArgs([]string{"config", "diff", path, "--format", "json"})
+	_ = cmd.Execute()
+	if !strings.Contains(stdout.String(), `"action": "update"`) || strings.Contains(stdout.String(), "reader-token") {
+		t.Fatalf("unexpected JSON diff:\n%s", stdout.String())
+	}
+}
+
+func TestConfigApply(t *testing.T) {
+	stdout, path := setupSettings(t)
+
+	runRootCommand(t, "config", "apply", path, "--yes")
+	checkContains(t, "apply", stdout.String(), "changes applied: 4")
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"config", "apply", path})
+	checkError(t, "apply without --yes", cmd.Execute(), "rerun with --yes")
+}
+
+func TestConfigErrors(t *testing.T) {
+	t.Setenv("ROLLBAR_ACCESS_TOKEN", "token")
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "")
+	setProfilePath(t, filepath.Join(t.TempDir(), "config.toml"))
+	dir := t.TempDir()
+
+	tests := []struct {
+		name string
+		body string
+		want string
+	}{
+		{name: "grouping", body: "grouping: []", want: "no API for custom grouping rules"},
+		{name: "account", body: "project: 7\naccess_tokens:\n  - {name: ci, scopes: [read]}", want: "access_tokens need an account token"},
+	}
+	for _, tc := range tests {
+		path := filepath.Join(dir, tc.name+".yaml")
+		if err := os.WriteFile(path, []byte(tc.body), 0o600); err != nil {
+			t.Fatalf("WriteFile() error = %v", err)
+		}
+		cmd := NewRootCmd()
+		cmd.SetArgs([]string{"config", "diff", path})
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%s: expected %q error, got %v", tc.name, tc.want, err)
+		}
+	}
+}
@@ -0,0 +1,59 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+func newItemsSimilarCmd(flags *rootFlags) *cobra.Command {
+	options := app.SimilarOptions{}
+	similarCmd := &cobra.Command{
+		Use:   "similar <item-counter|url>",
+		Short: "Report active items that probably duplicate an item",
+		Long:  "Compare an item's normalized title and latest stack trace against the project's active items and list probable duplicates with a similarity score from 0 to 1, to find errors Rollbar grouped apart. For example:\n\n  rollbaz items similar 42 --candidates 100 --min-score 0.7",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+			if options.MinScore < 0 || options.MinScore > 1 {
+				return errors.New("--min-score must be between 0 and 1")
+			}
+
+			return runItemsSimilar(cmd.Context(), *flags, counter, options)
+		},
+	}
+	similarCmd.Flags().IntVar(&options.Candidates, "candidates", 50, "How many active items to compare against, newest first")
+	similarCmd.Flags().Float64Var(&options.MinScore, "min-score", 0.5, "Leave out items scoring below this, from 0 to 1")
+
+	return similarCmd
+}
+
+func runItemsSimilar(parent context.Context, flags rootFlags, counter domain.ItemCounter, options app.SimilarOptions) error {
+	ctx, cancel := context.WithTimeout(parent, 2*time.Minute)
+	defer cancel()
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	options.Limit = flags.Limit
+	report, err := runWithProgress(flags.Format, "Comparing items", func() (app.SimilarReport, error) {
+		return service.Similar(ctx, counter, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderSimilarReportWithWidth(report, terminalRenderWidth()), redact.Value(report, token))
+}
@@ -0,0 +1,36 @@
+package cli
+
+import (
+	"net/http"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/rollbartest"
+)
+
+func TestItemsSimilarCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.NotFoundHandler())
+	server := rollbartest.NewServer(t)
+	server.AddItem(rollbar.Item{ID: 1004, Counter: 4, Title: "TypeError: Cannot read properties of null (reading 'name')", Status: rollbar.StatusActive})
+	server.AddInstances(1004, rollbar.ItemInstance{ID: 6001, Body: []byte(`{"trace":{"exception":{"class":"TypeError"},"frames":[{"filename":"app/checkout.js","lineno":57,"method":"submit"}]}}`)})
+	t.Cleanup(overrideClientFactory(func(string) (*rollbar.Client, error) { return server.Client(t), nil }))
+
+	runRootCommand(t, "items", "similar", "1", "--min-score", "0.7")
+	rendered := stdout.String()
+	if !strings.Contains(rendered, "compared 2 active items") || !strings.Contains(rendered, "reading 'name'") || strings.Contains(rendered, "Timeout talking to payments") {
+		t.Fatalf("expected only the retitled TypeError:\n%s", rendered)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "items", "similar", "1", "--format", "json")
+	if !strings.Contains(stdout.String(), `"score": 0.911,`) || !strings.Contains(stdout.String(), `"score": 0.6,`) {
+		t.Fatalf("unexpected JSON output:\n%s", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"items", "similar", "1", "--min-score", "2"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "--min-score") {
+		t.Fatalf("expected a --min-score error, got %v", err)
+	}
+}
@@ -0,0 +1,135 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/slack"
+)
+
+// slackAPIURL is the Web API root for token posts; tests point it at a fake.
+var slackAPIURL = slack.DefaultAPIURL
+
+type slackFlags struct {
+	WebhookURL string
+	Channel    string
+	DryRun     bool
+}
+
+func newPostSlackCmd(flags *rootFlags) *cobra.Command {
+	post := slackFlags{}
+	postSlackCmd := &cobra.Command{
+		Use:   "post-slack <item-counter|url>",
+		Short: "Post an item to Slack",
+		Long:  "Post a compact card for an item to Slack: its title, main error, level, environment, occurrence count, and a button that opens it in Rollbar. Messages go to an incoming webhook from --webhook-url, slack_webhook_url in the profile, or $SLACK_WEBHOOK_URL; or, with a channel and a bot token from slack_token or $SLACK_TOKEN, through chat.postMessage:\n\n  rollbaz item post-slack 269 --channel '#incidents'",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runPostSlack(cmd.Context(), *flags, post, args[0])
+		},
+	}
+	postSlackCmd.Flags().StringVar(&post.WebhookURL, "webhook-url", "", "Incoming webhook URL (default: slack_webhook_url or $SLACK_WEBHOOK_URL)")
+	postSlackCmd.Flags().StringVar(&post.Channel, "channel", "", "Channel to post to with the bot token (default: slack_channel)")
+	postSlackCmd.Flags().BoolVar(&post.DryRun, "dry-run", false, "Print the message instead of posting it")
+
+	return postSlackCmd
+}
+
+func runPostSlack(parent context.Context, flags rootFlags, post slackFlags, arg string) error {
+	var client *slack.Client
+	var secret string
+	if !post.DryRun {
+		var err error
+		if client, secret, err = newSlackClient(flags, post); err != nil {
+			return err
+		}
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	source, token, err := loadTicketSource(ctx, flags, arg)
+	if err != nil {
+		return err
+	}
+	message := redactSlackMessage(output.RenderSlackMessage(output.SlackCard{
+		Issue:     source.Detail.IssueSummary,
+		MainError: source.Detail.MainError,
+		Link:      source.Link,
+	}), token)
+	if post.DryRun {
+		return printOutput(flags, message.Text, map[string]any{"message": message})
+	}
+
+	_, err = runWithProgress(flags.Format, "Posting to Slack", func() (struct{}, error) {
+		return struct{}{}, app.PostToSlack(ctx, client, message)
+	})
+	if err != nil {
+		return sanitizeError(err, secret)
+	}
+
+	return printOutput(flags, output.RenderSlackPosted(source.Detail.Counter), map[string]any{"counter": source.Detail.Counter, "posted": true})
+}
+
+// newSlackClient picks where Slack posts go: an explicit webhook wins, then a
+// channel with a bot token, then the configured webhook. It returns the
+// webhook URL or token as well so errors can be redacted.
+func newSlackClient(flags rootFlags, post slackFlags) (*slack.Client, string, error) {
+	if post.WebhookURL != "" {
+		client, err := slack.NewWebhookClient(post.WebhookURL)
+		return client, post.WebhookURL, wrapSlackClientError(err)
+	}
+
+	channel := firstNonEmpty(post.Channel, flags.SlackChannel)
+	token := firstNonEmpty(flags.SlackToken, os.Getenv("SLACK_TOKEN"))
+	if channel != "" && token != "" {
+		client, err := slack.NewAPIClient(token, channel, slackAPIURL)
+		return client, token, wrapSlackClientError(err)
+	}
+
+	webhookURL := firstNonEmpty(flags.SlackWebhookURL, os.Getenv("SLACK_WEBHOOK_URL"))
+	if webhookURL == "" {
+		return nil, "", errors.New("slack destination is missing; set slack_webhook_url in the profile or SLACK_WEBHOOK_URL, or a channel with slack_token or SLACK_TOKEN")
+	}
+	client, err := slack.NewWebhookClient(webhookURL)
+
+	return client, webhookURL, wrapSlackClientError(err)
+}
+
+func wrapSlackClientError(err error) error {
+	if err != nil {
+		return fmt.Errorf("build slack client: %w", err)
+	}
+
+	return nil
+}
+
+// redactSlackMessage removes token from every piece of text in message.
+func redactSlackMessage(message slack.Message, token string) slack.Message {
+	message.Text = redact.String(message.Text, token)
+	blocks := make([]slack.Block, len(message.Blocks))
+	for i, block := range message.Blocks {
+		if block.Text != nil {
+			text := *block.Text
+			text.Text = redact.String(text.Text, token)
+			block.Text = &text
+		}
+		fields := make([]slack.Text, len(block.Fields))
+		for j, field := range block.Fields {
+			field.Text = redact.String(field.Text, token)
+			fields[j] = field
+		}
+		block.Fields = fields
+		blocks[i] = block
+	}
+	message.Blocks = blocks
+
+	return message
+}
@@ -0,0 +1,100 @@
+package cli
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"sync"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/slack"
+)
+
+// newSlackServer records messages posted to /hook, and to chat.postMessage
+// with the xoxb-1 bot token.
+func newSlackServer(t *testing.T) (*httptest.Server, func() []slack.Message) {
+	t.Helper()
+
+	var mu sync.Mutex
+	var posted []slack.Message
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		var message slack.Message
+		_ = json.NewDecoder(r.Body).Decode(&message)
+		mu.Lock()
+		defer mu.Unlock()
+		switch {
+		case r.URL.Path == "/hook":
+			posted = append(posted, message)
+			_, _ = fmt.Fprint(w, "ok")
+		case r.URL.Path == "/api/chat.postMessage" && r.Header.Get("Authorization") == "Bearer xoxb-1":
+			posted = append(posted, message)
+			_, _ = fmt.Fprint(w, `{"ok":true}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}))
+	t.Cleanup(server.Close)
+
+	return server, func() []slack.Message {
+		mu.Lock()
+		defer mu.Unlock()
+		return append([]slack.Message(nil), posted...)
+	}
+}
+
+func TestPostSlackCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, ticketItemHandler(t))
+	t.Setenv("ROLLBAZ_ACCOUNT", "")
+	t.Setenv("SLACK_WEBHOOK_URL", "")
+	t.Setenv("SLACK_TOKEN", "xoxb-1")
+	server, posted := newSlackServer(t)
+	previous := slackAPIURL
+	slackAPIURL = server.URL + "/api"
+	t.Cleanup(func() { slackAPIURL = previous })
+
+	runRootCommand(t, "item", "post-slack", "269", "--webhook-url", server.URL+"/hook")
+	runRootCommand(t, "item", "post-slack", "269", "--channel", "#incidents")
+	if got := strings.Count(stdout.String(), "posted item 269 to slack"); got != 2 {
+		t.Fatalf("expected two confirmations, got %q", stdout.String())
+	}
+
+	messages := posted()
+	if len(messages) != 2 || messages[0].Channel != "" || messages[1].Channel != "#incidents" {
+		t.Fatalf("unexpected messages: %+v", messages)
+	}
+	if messages[0].Text != "#269 KeyError: id (error, production)" || messages[0].Blocks[2].Elements[0].URL != "https://rollbar.com/occurrence/uuid/?uuid=0f1e-2d3c" {
+		t.Fatalf("unexpected message: %+v", messages[0])
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "item", "post-slack", "269", "--dry-run", "--format", "json")
+	if !strings.Contains(stdout.String(), `"text": "#269 KeyError: id (error, production)"`) || len(posted()) != 2 {
+		t.Fatalf("unexpected dry run: %s", stdout.String())
+	}
+}
+
+func TestPostSlackCommandErrors(t *testing.T) {
+	_ = setupServerAndStdout(t, ticketItemHandler(t))
+	t.Setenv("SLACK_WEBHOOK_URL", "")
+	t.Setenv("SLACK_TOKEN", "")
+	server, _ := newSlackServer(t)
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"item", "post-slack", "269"}, want: "slack destination is missing"},
+		{args: []string{"item", "post-slack", "269", "--channel", "#incidents"}, want: "slack destination is missing"},
+		{args: []string{"item", "post-slack", "269", "--webhook-url", server.URL + "/secret-hook"}, want: "slack returned status 404"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		err := cmd.Execute()
+		if err == nil || !strings.Contains(err.Error(), tc.want) || strings.Contains(err.Error(), "secret-hook") {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.want, err)
+		}
+	}
+}
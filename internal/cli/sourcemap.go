@@ -0,0 +1,112 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type sourceMapUploadFlags struct {
+	Version     string
+	MinifiedURL string
+	Map         string
+	Sources     []string
+}
+
+func newSourceMapCmd(flags *rootFlags) *cobra.Command {
+	sourceMapCmd := &cobra.Command{Use: "sourcemap", Short: "Upload JavaScript source maps"}
+	sourceMapCmd.AddCommand(newSourceMapUploadCmd(flags))
+
+	return sourceMapCmd
+}
+
+func newSourceMapUploadCmd(flags *rootFlags) *cobra.Command {
+	upload := sourceMapUploadFlags{}
+	uploadCmd := &cobra.Command{
+		Use:   "upload",
+		Short: "Upload the source map for one minified file",
+		Long:  "Upload the source map for one minified file so Rollbar can show original frames for occurrences with the same code version. For example:\n\n  rollbaz sourcemap upload --version \"$(git rev-parse HEAD)\" --minified-url https://cdn.example.com/js/app.min.js --map dist/app.min.js.map",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runSourceMapUpload(cmd.Context(), *flags, upload)
+		},
+	}
+	uploadCmd.Flags().StringVar(&upload.Version, "version", "", "Code version the map belongs to, usually a commit SHA")
+	uploadCmd.Flags().StringVar(&upload.MinifiedURL, "minified-url", "", "Full URL the minified file is served from")
+	uploadCmd.Flags().StringVar(&upload.Map, "map", "", "Source map file")
+	uploadCmd.Flags().StringArrayVar(&upload.Sources, "source", nil, "Original source to include, as <path-in-map>=<file> or <file> (repeatable)")
+	_ = uploadCmd.MarkFlagRequired("version")
+	_ = uploadCmd.MarkFlagRequired("minified-url")
+	_ = uploadCmd.MarkFlagRequired("map")
+	_ = uploadCmd.MarkFlagFilename("map", "map")
+
+	return uploadCmd
+}
+
+func runSourceMapUpload(parent context.Context, flags rootFlags, upload sourceMapUploadFlags) error {
+	request, err := readSourceMapUpload(upload)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 60*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	result, err := runWithProgress(flags.Format, "Uploading source map", func() (app.SourceMapUploadResult, error) {
+		return app.UploadSourceMap(ctx, client, request)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderSourceMapUploaded(result), redact.Value(map[string]any{"sourcemap": result}, token))
+}
+
+// readSourceMapUpload reads the map and any --source files up front so a
+// missing file fails before anything is sent.
+func readSourceMapUpload(upload sourceMapUploadFlags) (rollbar.SourceMapUpload, error) {
+	sourceMap, err := os.ReadFile(filepath.Clean(upload.Map))
+	if err != nil {
+		return rollbar.SourceMapUpload{}, fmt.Errorf("read --map: %w", err)
+	}
+
+	sources := make(map[string][]byte, len(upload.Sources))
+	for _, value := range upload.Sources {
+		name, file, ok := strings.Cut(value, "=")
+		if !ok {
+			file = name
+		}
+		if strings.TrimSpace(name) == "" || strings.TrimSpace(file) == "" {
+			return rollbar.SourceMapUpload{}, errors.New("--source must be <path-in-map>=<file> or <file>")
+		}
+		content, err := os.ReadFile(filepath.Clean(file))
+		if err != nil {
+			return rollbar.SourceMapUpload{}, fmt.Errorf("read --source: %w", err)
+		}
+		sources[name] = content
+	}
+
+	return rollbar.SourceMapUpload{
+		Version:     upload.Version,
+		MinifiedURL: upload.MinifiedURL,
+		SourceMap:   sourceMap,
+		MapName:     filepath.Base(upload.Map),
+		Sources:     sources,
+	}, nil
+}
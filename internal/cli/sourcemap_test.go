@@ -0,0 +1,58 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// serveSourceMapUpload accepts a sourcemap upload for version abc123 that
+// carries the src/app.ts source.
+func serveSourceMapUpload(w http.ResponseWriter, r *http.Request) {
+	if r.Method != http.MethodPost || r.URL.Path != "/api/1/sourcemap" {
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s"}`, r.Method, r.URL.Path)
+		return
+	}
+	if err := r.ParseMultipartForm(1 << 20); err != nil || r.FormValue("version") != "abc123" || r.MultipartForm.File["src/app.ts"] == nil {
+		w.WriteHeader(http.StatusBadRequest)
+		_, _ = fmt.Fprint(w, `{"err":1,"message":"unexpected upload"}`)
+		return
+	}
+	_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+}
+
+func TestSourceMapUploadCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(serveSourceMapUpload))
+
+	dir := t.TempDir()
+	mapPath := filepath.Join(dir, "app.min.js.map")
+	sourcePath := filepath.Join(dir, "app.ts")
+	if err := os.WriteFile(mapPath, []byte(`{"version":3,"sources":["src/app.ts"],"mappings":""}`), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(sourcePath, []byte("export {}\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	runRootCommand(t, "sourcemap", "upload", "--version", "abc123", "--minified-url", "https://cdn.example.com/app.min.js", "--map", mapPath, "--source", "src/app.ts="+sourcePath)
+	if want := "uploaded app.min.js.map for https://cdn.example.com/app.min.js @ abc123 with 1 sources"; !strings.Contains(stdout.String(), want) {
+		t.Fatalf("expected %q, got %q", want, stdout.String())
+	}
+
+	for _, args := range [][]string{
+		{"sourcemap", "upload", "--version", "abc123", "--minified-url", "u"},
+		{"sourcemap", "upload", "--version", "abc123", "--minified-url", "u", "--map", filepath.Join(dir, "missing.map")},
+		{"sourcemap", "upload", "--version", "abc123", "--minified-url", "u", "--map", mapPath, "--source", "src/app.ts="},
+		{"sourcemap", "upload", "--version", "abc123", "--minified-url", "u", "--map", sourcePath},
+	} {
+		cmd := NewRootCmd()
+		cmd.SetArgs(args)
+		if err := cmd.Execute(); err == nil {
+			t.Fatalf("%v: expected error", args)
+		}
+	}
+}
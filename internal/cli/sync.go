@@ -0,0 +1,103 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/store"
+)
+
+const (
+	defaultSyncOccurrences = 100
+	defaultSyncTimeout     = 10 * time.Minute
+)
+
+var errOfflineUnsupported = errors.New("this command needs the Rollbar API; drop --offline")
+
+type syncFlags struct {
+	Full        bool
+	Occurrences int
+	Timeout     time.Duration
+}
+
+func newSyncCmd(flags *rootFlags) *cobra.Command {
+	syncOptions := syncFlags{}
+	syncCmd := &cobra.Command{
+		Use:   "sync",
+		Short: "Copy items and occurrences into the local store for --offline",
+		Long:  "Copy the project's items, and up to --occurrences new occurrences of each, into a local SQLite database. Each run only fetches what changed since the last one; --full re-reads every item to pick up status changes. Afterwards --offline serves items, occurrences, and the TUI from the store without the network. Needs the sqlite3 tool on PATH:\n\n  rollbaz sync && rollbaz items list --offline --env production",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runSync(cmd.Context(), *flags, syncOptions)
+		},
+	}
+	syncCmd.Flags().BoolVar(&syncOptions.Full, "full", false, "Re-read every item instead of only those seen since the last sync")
+	syncCmd.Flags().IntVar(&syncOptions.Occurrences, "occurrences", defaultSyncOccurrences, "Maximum new occurrences to fetch per item (0 for all)")
+	syncCmd.Flags().DurationVar(&syncOptions.Timeout, "timeout", defaultSyncTimeout, "Maximum time for the whole sync")
+
+	return syncCmd
+}
+
+func runSync(parent context.Context, flags rootFlags, syncOptions syncFlags) error {
+	if flags.Offline {
+		return errOfflineUnsupported
+	}
+	if syncOptions.Occurrences < 0 {
+		return errors.New("--occurrences must not be negative")
+	}
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, syncOptions.Timeout)
+	defer cancel()
+
+	db, err := openStore(ctx, token)
+	if err != nil {
+		return err
+	}
+	options := app.SyncOptions{Full: syncOptions.Full, Occurrences: syncOptions.Occurrences}
+	result, err := runWithProgress(flags.Format, "Syncing", func() (app.SyncResult, error) {
+		return service.Sync(ctx, db, options)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderSyncResult(result, db.Path()), result)
+}
+
+// buildOfflineService serves the app layer from the store for the project
+// token, which still picks the database but is never sent anywhere.
+func buildOfflineService(flags rootFlags) (*app.Service, string, error) {
+	token, err := resolveAccessToken(flags)
+	if err != nil {
+		return nil, "", err
+	}
+	db, err := openStore(context.Background(), token)
+	if err != nil {
+		return nil, token, err
+	}
+
+	return app.NewService(db), token, nil
+}
+
+func openStore(ctx context.Context, token string) (*store.Store, error) {
+	path, err := store.DefaultPath(token)
+	if err != nil {
+		return nil, fmt.Errorf("locate local store: %w", err)
+	}
+	db, err := store.Open(ctx, path)
+	if err != nil {
+		return nil, fmt.Errorf("open local store: %w", err)
+	}
+
+	return db, nil
+}
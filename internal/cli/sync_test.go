@@ -0,0 +1,70 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os/exec"
+	"strings"
+	"sync/atomic"
+	"testing"
+)
+
+// syncHandler serves item 269 with occurrences u-2 and u-1, and fails the
+// test if it is called while online is false.
+func syncHandler(t *testing.T, online *atomic.Bool) http.HandlerFunc {
+	t.Helper()
+
+	return func(w http.ResponseWriter, r *http.Request) {
+		page := r.URL.Query().Get("page")
+		switch {
+		case !online.Load():
+			t.Errorf("unexpected request while offline: %s", r.URL)
+			w.WriteHeader(http.StatusServiceUnavailable)
+		case r.URL.Path == "/api/1/items" && page == "1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1755568172,"counter":269,"title":"boom","status":"active","level":"error","environment":"production","last_occurrence_timestamp":1700000100,"total_occurrences":2}]}}`)
+		case r.URL.Path == "/api/1/item/1755568172/instances" && page == "1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":2,"timestamp":1700000100,"data":{"uuid":"u-2"}},{"id":1,"timestamp":1700000000,"data":{"uuid":"u-1"}}]}}`)
+		default:
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[],"instances":[]}}`)
+		}
+	}
+}
+
+func TestSyncAndOffline(t *testing.T) {
+	if _, err := exec.LookPath("sqlite3"); err != nil {
+		t.Skip("sqlite3 not installed")
+	}
+	t.Setenv("XDG_CACHE_HOME", t.TempDir())
+	var online atomic.Bool
+	online.Store(true)
+	stdout := setupServerAndStdout(t, syncHandler(t, &online))
+
+	runRootCommand(t, "sync")
+	if !strings.Contains(stdout.String(), "synced 1 items and 2 occurrences into ") || !strings.Contains(stdout.String(), "2023-11-14T22:15:00Z") {
+		t.Fatalf("unexpected sync output: %q", stdout.String())
+	}
+
+	online.Store(false)
+	stdout.Reset()
+	runRootCommand(t, "items", "list", "--offline", "--env", "production")
+	runRootCommand(t, "occurrences", "269", "--offline", "--format", "json")
+	runRootCommand(t, "search", "--fuzzy", "bom", "--format", "json")
+	checkContains(t, "offline", stdout.String(), "boom", `"counter": 269`, `"uuid": "u-2"`, `"uuid": "u-1"`)
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"item", "show", "404", "--offline"}, want: "run `rollbaz sync`"},
+		{args: []string{"resolve", "269", "--offline", "--yes"}, want: "not available offline"},
+		{args: []string{"sync", "--offline"}, want: "drop --offline"},
+		{args: []string{"rql", "SELECT 1", "--offline"}, want: "drop --offline"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.want, err)
+		}
+	}
+}
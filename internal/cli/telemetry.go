@@ -0,0 +1,61 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+func newOccurrenceTelemetryCmd(flags *rootFlags) *cobra.Command {
+	byID := false
+	telemetryCmd := &cobra.Command{
+		Use:   "telemetry <uuid|id>",
+		Short: "Show the breadcrumbs leading up to an occurrence",
+		Long:  "Show the telemetry an SDK such as rollbar.js recorded before an occurrence, oldest first: logs, network requests, navigation, DOM events, and connectivity changes, each with how long before the error it happened. Colors follow the terminal and NO_COLOR:\n\n  rollbaz occurrence telemetry <uuid>",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			fetch, err := occurrenceFetcher(args[0], byID)
+			if err != nil {
+				return err
+			}
+
+			return runOccurrenceTelemetry(cmd.Context(), *flags, fetch)
+		},
+	}
+	telemetryCmd.Flags().BoolVar(&byID, "id", false, "Treat the argument as an occurrence id instead of a UUID")
+
+	return telemetryCmd
+}
+
+func runOccurrenceTelemetry(parent context.Context, flags rootFlags, fetch occurrenceFetch) error {
+	scrubber, err := occurrenceScrubber(flags)
+	if err != nil {
+		return err
+	}
+	ctx, cancel := context.WithTimeout(parent, 10*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	telemetry, err := runWithProgress(flags.Format, "Loading occurrence", func() (app.OccurrenceTelemetry, error) {
+		instance, err := fetch(ctx, client)
+		if err != nil {
+			return app.OccurrenceTelemetry{}, fmt.Errorf("get occurrence: %w", err)
+		}
+		return app.NewOccurrenceTelemetry(app.ScrubInstance(scrubber, instance)), nil
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderTelemetry(telemetry, terminalColors()), redact.Value(telemetry, token))
+}
@@ -0,0 +1,54 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"os"
+	"strings"
+	"testing"
+)
+
+func TestOccurrenceTelemetryCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/instance/uuid/u-1", "/api/1/instance/11":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":11,"timestamp":1700000002,"data":{"uuid":"u-1","body":{"trace":{"exception":{"class":"TypeError","message":"x is undefined"},"frames":[]},"telemetry":[{"level":"info","type":"network","timestamp_ms":1700000001000,"body":{"method":"GET","url":"/api/cart?access_token=abc123","status_code":500}},{"level":"info","type":"navigation","timestamp_ms":1700000000000,"body":{"from":"/","to":"/cart"}}]}}}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}))
+
+	runRootCommand(t, "occurrence", "telemetry", "u-1")
+	rendered := stdout.String()
+	if !strings.Contains(rendered, "-2.000s  navigation") || !strings.Contains(rendered, "GET /api/cart?access_token=***** → 500") || !strings.HasSuffix(rendered, "✖ x is undefined\n") {
+		t.Fatalf("unexpected telemetry:\n%s", rendered)
+	}
+	if strings.Contains(rendered, "\x1b[") {
+		t.Fatalf("expected no colors off a terminal:\n%q", rendered)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "occurrence", "telemetry", "11", "--id", "--format", "json")
+	if !strings.Contains(stdout.String(), `"type": "navigation"`) || !strings.Contains(stdout.String(), `"main_error": "x is undefined"`) {
+		t.Fatalf("unexpected JSON output:\n%s", stdout.String())
+	}
+}
+
+func TestTerminalColors(t *testing.T) {
+	originalStdout, originalIsTerminal := stdoutWriter, isTerminal
+	t.Cleanup(func() {
+		stdoutWriter = originalStdout
+		isTerminal = originalIsTerminal
+	})
+	stdoutWriter = os.Stdout
+	isTerminal = func(int) bool { return true }
+
+	t.Setenv("NO_COLOR", "")
+	if !terminalColors() {
+		t.Fatal("expected colors on a terminal")
+	}
+	t.Setenv("NO_COLOR", "1")
+	if terminalColors() {
+		t.Fatal("expected NO_COLOR to disable colors")
+	}
+}
@@ -0,0 +1,201 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/config"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/github"
+	"github.com/kevinsheth/rollbaz/internal/jira"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+type gitHubTicketFlags struct {
+	Repo   string
+	Labels []string
+	DryRun bool
+}
+
+func newToGitHubCmd(flags *rootFlags) *cobra.Command {
+	ticket := gitHubTicketFlags{}
+	toGitHubCmd := &cobra.Command{
+		Use:   "to-github <item-counter|url>",
+		Short: "Create a GitHub issue for an item",
+		Long:  "Create a GitHub issue for an item with its main error, occurrence count, latest stack trace, and a link back to Rollbar. The GitHub token comes from github_token in the profile or $GITHUB_TOKEN and needs permission to create issues in the repository:\n\n  rollbaz item to-github 269 --repo acme/billing-api --label bug",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runToGitHub(cmd.Context(), *flags, ticket, args[0])
+		},
+	}
+	toGitHubCmd.Flags().StringVar(&ticket.Repo, "repo", "", "Repository to file the issue in, as owner/name")
+	toGitHubCmd.Flags().StringArrayVar(&ticket.Labels, "label", nil, "Label to add to the issue (repeatable)")
+	toGitHubCmd.Flags().BoolVar(&ticket.DryRun, "dry-run", false, "Print the issue instead of creating it")
+	_ = toGitHubCmd.MarkFlagRequired("repo")
+
+	return toGitHubCmd
+}
+
+func runToGitHub(parent context.Context, flags rootFlags, ticket gitHubTicketFlags, arg string) error {
+	if _, _, err := github.ParseRepo(ticket.Repo); err != nil {
+		return fmt.Errorf("--repo: %w", err)
+	}
+	gitHubToken := firstNonEmpty(flags.GitHubToken, os.Getenv("GITHUB_TOKEN"))
+	if gitHubToken == "" && !ticket.DryRun {
+		return errors.New("GitHub token is missing; set github_token in the profile or GITHUB_TOKEN")
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	source, token, err := loadTicketSource(ctx, flags, arg)
+	if err != nil {
+		return err
+	}
+	issue := output.RenderGitHubIssue(source)
+	issue.Title = redact.String(issue.Title, token)
+	issue.Body = redact.String(issue.Body, token)
+	issue.Labels = ticket.Labels
+	if ticket.DryRun {
+		return printOutput(flags, issue.Title+"\n\n"+issue.Body, map[string]any{"repo": ticket.Repo, "issue": issue})
+	}
+
+	client, err := github.NewClient(gitHubToken, flags.GitHubURL)
+	if err != nil {
+		return fmt.Errorf("build github client: %w", err)
+	}
+	result, err := runWithProgress(flags.Format, "Creating GitHub issue", func() (app.TicketResult, error) {
+		return app.CreateGitHubIssue(ctx, client, ticket.Repo, source.Detail.Counter, issue)
+	})
+	if err != nil {
+		return sanitizeError(err, gitHubToken)
+	}
+
+	return printOutput(flags, output.RenderTicketCreated(result), map[string]any{"ticket": result})
+}
+
+type jiraTicketFlags struct {
+	Project   string
+	IssueType string
+	DryRun    bool
+}
+
+func newToJiraCmd(flags *rootFlags) *cobra.Command {
+	ticket := jiraTicketFlags{}
+	toJiraCmd := &cobra.Command{
+		Use:   "to-jira <item-counter|url>",
+		Short: "Create a Jira issue for an item",
+		Long:  "Create a Jira issue for an item with its main error, occurrence count, latest stack trace, and a link back to Rollbar. The Jira site, credentials, defaults, and extra field mappings come from the [jira] and [jira.fields] tables in config.toml, or $JIRA_URL, $JIRA_EMAIL, and $JIRA_API_TOKEN:\n\n  rollbaz item to-jira 269 --jira-project ENG --type Bug",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runToJira(cmd.Context(), *flags, ticket, args[0])
+		},
+	}
+	toJiraCmd.Flags().StringVar(&ticket.Project, "jira-project", "", "Jira project key (default: project under [jira])")
+	toJiraCmd.Flags().StringVar(&ticket.IssueType, "type", "", "Jira issue type, such as Bug (default: issue_type under [jira])")
+	toJiraCmd.Flags().BoolVar(&ticket.DryRun, "dry-run", false, "Print the issue instead of creating it")
+
+	return toJiraCmd
+}
+
+func runToJira(parent context.Context, flags rootFlags, ticket jiraTicketFlags, arg string) error {
+	settings, err := jiraSettings(flags.Jira, ticket)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	source, token, err := loadTicketSource(ctx, flags, arg)
+	if err != nil {
+		return err
+	}
+	issue := output.RenderJiraIssue(source)
+	issue.Summary = redact.String(issue.Summary, token)
+	issue.Description = redact.String(issue.Description, token)
+	issue.Project = settings.Project
+	issue.IssueType = settings.IssueType
+	issue.Fields = app.ExpandTicketFields(settings.Fields, source)
+	if ticket.DryRun {
+		return printOutput(flags, issue.Summary+"\n\n"+issue.Description, map[string]any{"issue": issue})
+	}
+
+	client, err := jira.NewClient(settings.URL, settings.Email, settings.Token)
+	if err != nil {
+		return fmt.Errorf("build jira client: %w", err)
+	}
+	result, err := runWithProgress(flags.Format, "Creating Jira issue", func() (app.TicketResult, error) {
+		return app.CreateJiraIssue(ctx, client, source.Detail.Counter, issue)
+	})
+	if err != nil {
+		return sanitizeError(err, settings.Token)
+	}
+
+	return printOutput(flags, output.RenderTicketCreated(result), map[string]any{"ticket": result})
+}
+
+// jiraSettings applies the command's flags and the JIRA_* environment over
+// the [jira] table: flags win, and the environment fills in what the file
+// leaves unset.
+func jiraSettings(settings config.JiraConfig, ticket jiraTicketFlags) (config.JiraConfig, error) {
+	settings.URL = firstNonEmpty(settings.URL, os.Getenv("JIRA_URL"))
+	settings.Email = firstNonEmpty(settings.Email, os.Getenv("JIRA_EMAIL"))
+	settings.Token = firstNonEmpty(settings.Token, os.Getenv("JIRA_API_TOKEN"))
+	settings.Project = firstNonEmpty(ticket.Project, settings.Project)
+	settings.IssueType = firstNonEmpty(ticket.IssueType, settings.IssueType)
+
+	switch {
+	case settings.Project == "" || settings.IssueType == "":
+		return config.JiraConfig{}, errors.New("--jira-project and --type are required unless project and issue_type are set under [jira]")
+	case ticket.DryRun:
+		return settings, nil
+	case settings.URL == "" || settings.Token == "":
+		return config.JiraConfig{}, errors.New("jira url or token is missing; set url and token under [jira] in config.toml, or JIRA_URL and JIRA_API_TOKEN")
+	}
+
+	return settings, nil
+}
+
+// loadTicketSource loads the item a ticket is filed for, scrubbed, and
+// returns the Rollbar token so callers can redact what they build from it.
+// The link back to Rollbar uses the slugs from a pasted URL, or the profile
+// account and --project, like `open`.
+func loadTicketSource(ctx context.Context, flags rootFlags, arg string) (app.TicketSource, string, error) {
+	ref, err := domain.ParseItemRef(arg)
+	if err != nil {
+		return app.TicketSource{}, "", fmt.Errorf("parse item %q: %w", arg, err)
+	}
+	location := itemWebLocation(ref, flags.Account, webProjectSlug(flags))
+	if ref.Project != "" && flags.Project == "" {
+		flags.Project, flags.ProjectFromURL = ref.Project, true
+	}
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return app.TicketSource{}, "", err
+	}
+	source, err := runWithProgress(flags.Format, "Loading issue detail", func() (app.TicketSource, error) {
+		return service.TicketSource(ctx, ref.Counter, location)
+	})
+	if err != nil {
+		return app.TicketSource{}, "", sanitizeError(err, token)
+	}
+
+	if instance := source.Detail.Instance; instance != nil {
+		scrubbed := *instance
+		scrubber := redact.DefaultScrubber()
+		scrubbed.Data = scrubber.ScrubJSON(scrubbed.Data)
+		scrubbed.Body = scrubber.ScrubJSON(scrubbed.Body)
+		source.Detail.Instance = &scrubbed
+	}
+
+	return source, token, nil
+}
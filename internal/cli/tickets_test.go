@@ -0,0 +1,168 @@
+package cli
+
+import (
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/github"
+)
+
+func ticketItemHandler(t *testing.T) http.Handler {
+	t.Helper()
+
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/item_by_counter/269":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1755568172}}`)
+		case "/api/1/item/1755568172/":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1755568172,"counter":269,"title":"KeyError: id","status":"active","environment":"production","level":"error","total_occurrences":42}}`)
+		case "/api/1/item/1755568172/instances":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":1,"data":{"uuid":"0f1e-2d3c","body":{"trace":{"exception":{"class":"KeyError","message":"id"},"frames":[{"filename":"app.py","lineno":7,"method":"charge"}]}}}}],"page":1}}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected path %s"}`, r.URL.Path)
+		}
+	})
+}
+
+// newGitHubServer starts a GitHub API that files issue 42 in acme/api for
+// the gh-token token and decodes the filed issue into filed.
+func newGitHubServer(t *testing.T, filed *github.NewIssue) *httptest.Server {
+	t.Helper()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/repos/acme/api/issues" || r.Header.Get("Authorization") != "Bearer gh-token" {
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprint(w, `{"message":"Not Found"}`)
+			return
+		}
+		_ = json.NewDecoder(r.Body).Decode(filed)
+		w.WriteHeader(http.StatusCreated)
+		_, _ = fmt.Fprint(w, `{"number":42,"html_url":"https://github.com/acme/api/issues/42"}`)
+	}))
+	t.Cleanup(server.Close)
+
+	return server
+}
+
+func TestToGitHubCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, ticketItemHandler(t))
+	t.Setenv("ROLLBAZ_ACCOUNT", "")
+	t.Setenv("GITHUB_TOKEN", "")
+
+	var filed github.NewIssue
+	gitHub := newGitHubServer(t, &filed)
+	writeProfiles(t, fmt.Sprintf("default_profile = \"work\"\n\n[profiles.work]\ngithub_token = \"gh-token\"\ngithub_url = %q\n", gitHub.URL))
+
+	runRootCommand(t, "item", "to-github", "269", "--repo", "acme/api", "--label", "bug")
+	if want := "created github issue acme/api#42 for item 269\nhttps://github.com/acme/api/issues/42"; !strings.Contains(stdout.String(), want) {
+		t.Fatalf("expected %q, got %q", want, stdout.String())
+	}
+	if filed.Title != "[Rollbar #269] KeyError: id" || len(filed.Labels) != 1 || filed.Labels[0] != "bug" {
+		t.Fatalf("unexpected issue: %+v", filed)
+	}
+	for _, want := range []string{"| 42 |", "at charge (app.py:7)", "[View in Rollbar](https://rollbar.com/occurrence/uuid/?uuid=0f1e-2d3c)"} {
+		if !strings.Contains(filed.Body, want) {
+			t.Fatalf("expected %q in issue body:\n%s", want, filed.Body)
+		}
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "item", "to-github", "269", "--repo", "acme/api", "--dry-run", "--format", "json")
+	if !strings.Contains(stdout.String(), `"title": "[Rollbar #269] KeyError: id"`) || !strings.Contains(stdout.String(), `"repo": "acme/api"`) {
+		t.Fatalf("unexpected dry run output: %s", stdout.String())
+	}
+}
+
+func TestToGitHubCommandErrors(t *testing.T) {
+	_ = setupServerAndStdout(t, ticketItemHandler(t))
+	t.Setenv("GITHUB_TOKEN", "")
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"item", "to-github", "269"}, want: `required flag(s) "repo" not set`},
+		{args: []string{"item", "to-github", "269", "--repo", "acme"}, want: "use owner/name"},
+		{args: []string{"item", "to-github", "269", "--repo", "acme/api"}, want: "GitHub token is missing"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.want, err)
+		}
+	}
+}
+
+// newJiraServer starts a Jira API that files OPS-7 for me@acme.com with the
+// jira-token token and decodes the filed issue into filed.
+func newJiraServer(t *testing.T, filed *map[string]map[string]any) *httptest.Server {
+	t.Helper()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if email, token, ok := r.BasicAuth(); r.URL.Path != "/rest/api/2/issue" || !ok || email != "me@acme.com" || token != "jira-token" {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		_ = json.NewDecoder(r.Body).Decode(filed)
+		w.WriteHeader(http.StatusCreated)
+		_, _ = fmt.Fprint(w, `{"id":"10001","key":"OPS-7"}`)
+	}))
+	t.Cleanup(server.Close)
+
+	return server
+}
+
+func TestToJiraCommand(t *testing.T) {
+	stdout := setupServerAndStdout(t, ticketItemHandler(t))
+	t.Setenv("ROLLBAZ_ACCOUNT", "")
+	t.Setenv("JIRA_API_TOKEN", "jira-token")
+
+	var filed map[string]map[string]any
+	jiraServer := newJiraServer(t, &filed)
+	writeProfiles(t, fmt.Sprintf("[jira]\nurl = %q\nemail = \"me@acme.com\"\nproject = \"ENG\"\nissue_type = \"Bug\"\n\n[jira.fields]\nlabels = \"rollbar,{environment}\"\n", jiraServer.URL))
+
+	runRootCommand(t, "item", "to-jira", "269", "--jira-project", "OPS", "--type", "Incident")
+	if want := "created jira issue OPS-7 for item 269\n" + jiraServer.URL + "/browse/OPS-7"; !strings.Contains(stdout.String(), want) {
+		t.Fatalf("expected %q, got %q", want, stdout.String())
+	}
+	fields := filed["fields"]
+	if fields["summary"] != "[Rollbar #269] KeyError: id" || fmt.Sprint(fields["project"], fields["issuetype"], fields["labels"]) != "map[key:OPS] map[name:Incident] [rollbar production]" {
+		t.Fatalf("unexpected fields: %v", fields)
+	}
+	if description, _ := fields["description"].(string); !strings.Contains(description, "[View in Rollbar|https://rollbar.com/occurrence/uuid/?uuid=0f1e-2d3c]") {
+		t.Fatalf("unexpected description: %q", description)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "item", "to-jira", "269", "--dry-run", "--format", "json")
+	if !strings.Contains(stdout.String(), `"key": "ENG"`) || !strings.Contains(stdout.String(), `"name": "Bug"`) {
+		t.Fatalf("unexpected dry run output: %s", stdout.String())
+	}
+}
+
+func TestToJiraCommandErrors(t *testing.T) {
+	_ = setupServerAndStdout(t, ticketItemHandler(t))
+	t.Setenv("JIRA_URL", "")
+	t.Setenv("JIRA_API_TOKEN", "")
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"item", "to-jira", "269", "--type", "Bug"}, want: "--jira-project and --type are required"},
+		{args: []string{"item", "to-jira", "269", "--jira-project", "ENG", "--type", "Bug"}, want: "jira url or token is missing"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%v: expected %q error, got %v", tc.args, tc.want, err)
+		}
+	}
+}
@@ -0,0 +1,80 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const defaultTimelineSince = 7 * 24 * time.Hour
+
+func newTimelineCmd(flags *rootFlags) *cobra.Command {
+	bucket := ""
+	timelineCmd := &cobra.Command{
+		Use:   "timeline <item-counter|url>",
+		Short: "Chart an item's occurrences over time as a sparkline",
+		Long:  "Count an item's occurrences per --bucket from --since (default 7d) until now and draw them as a sparkline; --format json returns the buckets for dashboards. For example:\n\n  rollbaz item timeline 42 --since 7d --bucket 1h",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			counter, err := parseItemCounter(flags, args[0])
+			if err != nil {
+				return err
+			}
+
+			return runTimeline(cmd.Context(), *flags, counter, bucket)
+		},
+	}
+	timelineCmd.Flags().StringVar(&bucket, "bucket", "1h", "Bucket size, at least 1m (like 15m, 1h, or 1d)")
+
+	return timelineCmd
+}
+
+func runTimeline(parent context.Context, flags rootFlags, counter domain.ItemCounter, bucket string) error {
+	now := time.Now()
+	options, err := timelineOptions(flags, bucket, now)
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, 30*time.Second)
+	defer cancel()
+
+	client, token, err := buildClient(flags)
+	if err != nil {
+		return err
+	}
+
+	timeline, err := runWithProgress(flags.Format, "Loading occurrence counts", func() (app.Timeline, error) {
+		return app.ItemTimeline(ctx, client, counter, options, now)
+	})
+	if err != nil {
+		return sanitizeError(err, token)
+	}
+
+	return printOutput(flags, output.RenderTimelineWithWidth(timeline, terminalRenderWidth()), redact.Value(timeline, token))
+}
+
+func timelineOptions(flags rootFlags, bucket string, now time.Time) (app.TimelineOptions, error) {
+	size, ok := parseRelativeDuration(bucket)
+	if !ok {
+		return app.TimelineOptions{}, fmt.Errorf("parse --bucket: invalid duration %q", bucket)
+	}
+
+	since := now.Add(-defaultTimelineSince)
+	if flags.Since != "" {
+		parsed, err := parseFilterTime(flags.Since)
+		if err != nil {
+			return app.TimelineOptions{}, fmt.Errorf("parse --since: %w", err)
+		}
+		since = *parsed
+	}
+
+	return app.TimelineOptions{Since: since, Bucket: size, Environment: flags.Environment}, nil
+}
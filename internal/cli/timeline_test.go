@@ -0,0 +1,48 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+	"time"
+)
+
+// timelineHandler serves item 42 with three occurrences in the hour before
+// current and six in the hour starting at current.
+func timelineHandler(current int64) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/api/1/item_by_counter/42":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"itemId":1042}}`)
+		case "/api/1/reports/occurrence_counts":
+			if r.URL.Query().Get("item_id") != "1042" || r.URL.Query().Get("bucket_size") != "3600" {
+				w.WriteHeader(http.StatusBadRequest)
+				return
+			}
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":[[%d,3],[%d,6]]}`, current-3600, current)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+		}
+	}
+}
+
+func TestItemTimelineCommand(t *testing.T) {
+	current := time.Now().Unix() / 3600 * 3600
+	stdout := setupServerAndStdout(t, timelineHandler(current))
+
+	runRootCommand(t, "item", "timeline", "42", "--since", "3h", "--bucket", "1h")
+	checkContains(t, "timeline", stdout.String(), "in 1h buckets", "▅█\n", "total 9, peak 6")
+
+	stdout.Reset()
+	runRootCommand(t, "item", "timeline", "42", "--since", "3h", "--format", "json")
+	if !strings.Contains(stdout.String(), `"bucket_seconds": 3600`) || !strings.Contains(stdout.String(), fmt.Sprintf(`"timestamp": %d`, current)) {
+		t.Fatalf("unexpected JSON output:\n%s", stdout.String())
+	}
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"item", "timeline", "42", "--bucket", "soon"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "--bucket") {
+		t.Fatalf("expected a --bucket error, got %v", err)
+	}
+}
@@ -0,0 +1,204 @@
+package cli
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"os/signal"
+	"regexp"
+	"strings"
+	"syscall"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const tokensTimeout = 30 * time.Second
+
+var shellVariable = regexp.MustCompile(`^[A-Za-z_][A-Za-z0-9_]*$`)
+
+type tokenRotateFlags struct {
+	Scopes  []string
+	Name    string
+	NewName string
+	Print   bool
+	Export  string
+	Revoke  bool
+	Grace   time.Duration
+}
+
+func newTokensCmd(flags *rootFlags) *cobra.Command {
+	tokensCmd := &cobra.Command{Use: "tokens", Short: "List, rotate, and revoke project access tokens"}
+	tokensCmd.AddCommand(
+		newTokensListCmd(flags),
+		newTokensRotateCmd(flags),
+		newTokensRevokeCmd(flags),
+	)
+
+	return tokensCmd
+}
+
+func newTokensListCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "list",
+		Short: "List the access tokens of --project",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runTokensList(cmd.Context(), *flags)
+		},
+	}
+}
+
+func newTokensRotateCmd(flags *rootFlags) *cobra.Command {
+	rotate := tokenRotateFlags{}
+	rotateCmd := &cobra.Command{
+		Use:   "rotate",
+		Short: "Replace the access token with --scope by a new one",
+		Long:  "Create an access token with the scopes and rate limit of the enabled token that has exactly --scope (narrowed by --name when several do). The new token is only shown with --print, which writes it alone for a secret store, or --export, which writes a shell export line. With --revoke the old token is disabled once --grace has passed, so deployments can pick up the new one first:\n\n  rollbaz tokens rotate --project api --scope post_server_item --print | vault kv put secret/rollbar token=-\n  eval \"$(rollbaz tokens rotate --project api --scope post_server_item --export ROLLBAR_TOKEN --revoke --grace 10m --yes)\"",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if rotate.Export != "" && !shellVariable.MatchString(rotate.Export) {
+				return fmt.Errorf("invalid --export variable name %q", rotate.Export)
+			}
+			if rotate.Grace < 0 {
+				return errors.New("--grace cannot be negative")
+			}
+
+			ctx, stop := signal.NotifyContext(cmd.Context(), os.Interrupt, syscall.SIGTERM)
+			defer stop()
+
+			return runTokensRotate(ctx, *flags, rotate)
+		},
+	}
+	rotateCmd.Flags().StringArrayVar(&rotate.Scopes, "scope", nil, "Scope of the token to rotate: read, write, post_server_item, or post_client_item (repeatable)")
+	rotateCmd.Flags().StringVar(&rotate.Name, "name", "", "Name of the token to rotate when several have the scopes")
+	rotateCmd.Flags().StringVar(&rotate.NewName, "new-name", "", "Name of the new token (default: the old name with today's date)")
+	rotateCmd.Flags().BoolVar(&rotate.Print, "print", false, "Print only the new token")
+	rotateCmd.Flags().StringVar(&rotate.Export, "export", "", "Print the new token as a shell export of this variable")
+	rotateCmd.Flags().BoolVar(&rotate.Revoke, "revoke", false, "Disable the old token after --grace")
+	rotateCmd.Flags().DurationVar(&rotate.Grace, "grace", 0, "Time to keep the old token enabled with --revoke")
+	_ = rotateCmd.MarkFlagRequired("scope")
+	rotateCmd.MarkFlagsMutuallyExclusive("print", "export")
+
+	return rotateCmd
+}
+
+func newTokensRevokeCmd(flags *rootFlags) *cobra.Command {
+	return &cobra.Command{
+		Use:   "revoke <name>",
+		Short: "Disable the access token named name",
+		Args:  cobra.ExactArgs(1),
+		RunE: func(cmd *cobra.Command, args []string) error {
+			return runTokensRevoke(cmd.Context(), *flags, args[0])
+		},
+	}
+}
+
+func runTokensList(parent context.Context, flags rootFlags) error {
+	ctx, cancel := context.WithTimeout(parent, tokensTimeout)
+	defer cancel()
+
+	client, projectID, accountToken, err := buildAccountClient(flags, "tokens")
+	if err != nil {
+		return err
+	}
+
+	tokens, err := runWithProgress(flags.Format, "Loading access tokens", func() ([]rollbar.ProjectAccessToken, error) {
+		return app.ListTokens(ctx, client, projectID)
+	})
+	if err != nil {
+		return sanitizeError(err, accountToken)
+	}
+
+	return printOutput(flags, output.RenderTokenTableWithWidth(tokens, terminalRenderWidth()), redact.Value(map[string]any{"tokens": tokens}, accountToken))
+}
+
+func runTokensRotate(ctx context.Context, flags rootFlags, rotate tokenRotateFlags) error {
+	client, projectID, accountToken, err := buildAccountClient(flags, "tokens")
+	if err != nil {
+		return err
+	}
+	if rotate.Revoke {
+		if err := confirmAction(flags, fmt.Sprintf("rotate the %s token and revoke the old one after %s", strings.Join(rotate.Scopes, ", "), rotate.Grace)); err != nil {
+			return err
+		}
+	}
+
+	createCtx, cancel := context.WithTimeout(ctx, tokensTimeout)
+	defer cancel()
+	rotation, err := app.RotateToken(createCtx, client, projectID, app.RotateOptions{Scopes: rotate.Scopes, Name: rotate.Name, NewName: rotate.NewName}, time.Now())
+	if err != nil {
+		return sanitizeError(err, accountToken)
+	}
+	if secret, ok := rotatedSecret(rotate, rotation.New.AccessToken); ok {
+		_, _ = fmt.Fprintln(stdoutWriter, secret)
+	}
+
+	if rotate.Revoke {
+		if err := revokeAfterGrace(ctx, client, projectID, rotation.Old, rotate.Grace); err != nil {
+			return sanitizeError(err, accountToken)
+		}
+		rotation.Revoked = true
+	}
+	if rotate.Print || rotate.Export != "" {
+		return nil
+	}
+
+	return printOutput(flags, output.RenderTokenRotation(rotation), redact.Value(rotation, accountToken))
+}
+
+// rotatedSecret is the new token as --print or --export asked for it; without
+// either it is never written out.
+func rotatedSecret(rotate tokenRotateFlags, token string) (string, bool) {
+	switch {
+	case rotate.Print:
+		return token, true
+	case rotate.Export != "":
+		return fmt.Sprintf("export %s='%s'", rotate.Export, token), true
+	default:
+		return "", false
+	}
+}
+
+// revokeAfterGrace disables old once grace has passed. An interrupt while
+// waiting leaves it enabled.
+func revokeAfterGrace(ctx context.Context, api app.TokenAdminAPI, projectID uint64, old rollbar.ProjectAccessToken, grace time.Duration) error {
+	if grace > 0 {
+		_, _ = fmt.Fprintf(stderrWriter, "revoking access token %s in %s; interrupt to keep it\n", old.Name, grace)
+		select {
+		case <-ctx.Done():
+			return fmt.Errorf("access token %s left enabled: %w", old.Name, ctx.Err())
+		case <-time.After(grace):
+		}
+	}
+
+	revokeCtx, cancel := context.WithTimeout(context.WithoutCancel(ctx), tokensTimeout)
+	defer cancel()
+
+	return app.DisableToken(revokeCtx, api, projectID, old)
+}
+
+func runTokensRevoke(parent context.Context, flags rootFlags, name string) error {
+	client, projectID, accountToken, err := buildAccountClient(flags, "tokens")
+	if err != nil {
+		return err
+	}
+	if err := confirmAction(flags, "revoke access token "+name); err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(parent, tokensTimeout)
+	defer cancel()
+	token, err := app.RevokeToken(ctx, client, projectID, name)
+	if err != nil {
+		return sanitizeError(err, accountToken)
+	}
+
+	return printOutput(flags, "revoked access token "+token.Name, redact.Value(map[string]any{"revoked": token}, accountToken))
+}
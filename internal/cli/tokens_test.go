@@ -0,0 +1,103 @@
+package cli
+
+import (
+	"fmt"
+	"io"
+	"net/http"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+// tokensHandler serves the ci and reader tokens, creates ci-next and
+// records each time ci is disabled in disabled.
+func tokensHandler(disabled *[]string) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+		switch r.Method + " " + r.URL.Path + " " + string(body) {
+		case "GET /api/1/project/7/access_tokens ":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"name":"ci","access_token":"old-secret-1234","status":"enabled","scopes":["post_server_item"]},{"name":"reader","access_token":"reader-secret-9999","status":"enabled","scopes":["read"]}]}`)
+		case `POST /api/1/project/7/access_tokens {"name":"ci-next","scopes":["post_server_item"]}`:
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"name":"ci-next","access_token":"new-secret-5678","status":"enabled","scopes":["post_server_item"]}}`)
+		case `PATCH /api/1/project/7/access_token/old-secret-1234 {"status":"disabled"}`:
+			*disabled = append(*disabled, "ci")
+			_, _ = fmt.Fprint(w, `{"err":0}`)
+		default:
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s %s %s"}`, r.Method, r.URL.Path, body)
+		}
+	}
+}
+
+// checkNoTokenSecret fails if output leaks any part of a token other than
+// its last four characters.
+func checkNoTokenSecret(t *testing.T, name string, output string) {
+	t.Helper()
+	for _, secret := range []string{"old-secret", "new-secret"} {
+		if strings.Contains(output, secret) {
+			t.Fatalf("%s: token leaked:\n%s", name, output)
+		}
+	}
+}
+
+func TestTokensListAndRevoke(t *testing.T) {
+	disabled := []string{}
+	stdout := setupServerAndStdout(t, tokensHandler(&disabled))
+	useAccountToken(t)
+
+	runRootCommand(t, "tokens", "list", "--project", "7")
+	checkContains(t, "list", stdout.String(), "post_server_item", "...1234")
+	checkNoTokenSecret(t, "list", stdout.String())
+
+	stdout.Reset()
+	runRootCommand(t, "tokens", "revoke", "ci", "--project", "7", "--yes", "--format", "json")
+	checkContains(t, "revoke", stdout.String(), `"name": "ci"`)
+	checkNoTokenSecret(t, "revoke", stdout.String())
+	if len(disabled) != 1 {
+		t.Fatalf("expected ci disabled once, got %v", disabled)
+	}
+}
+
+func TestTokensRotate(t *testing.T) {
+	disabled := []string{}
+	stdout := setupServerAndStdout(t, tokensHandler(&disabled))
+	useAccountToken(t)
+
+	runRootCommand(t, "tokens", "rotate", "--project", "7", "--scope", "post_server_item", "--new-name", "ci-next")
+	checkContains(t, "rotate", stdout.String(), "created access token ci-next", "ci is still enabled")
+	checkNoTokenSecret(t, "rotate", stdout.String())
+
+	stdout.Reset()
+	runRootCommand(t, "tokens", "rotate", "--project", "7", "--scope", "post_server_item", "--new-name", "ci-next", "--export", "ROLLBAR_TOKEN", "--revoke", "--yes")
+	if got := stdout.String(); got != "export ROLLBAR_TOKEN='new-secret-5678'\n" || len(disabled) != 1 {
+		t.Fatalf("unexpected export output %q (disabled %v)", got, disabled)
+	}
+
+	stdout.Reset()
+	runRootCommand(t, "tokens", "rotate", "--project", "7", "--scope", "post_server_item", "--new-name", "ci-next", "--print")
+	if got := stdout.String(); got != "new-secret-5678\n" {
+		t.Fatalf("unexpected print output %q", got)
+	}
+}
+
+func TestTokensErrors(t *testing.T) {
+	t.Setenv("ROLLBAR_ACCESS_TOKEN", "token")
+	t.Setenv("ROLLBAR_ACCOUNT_TOKEN", "")
+	setProfilePath(t, filepath.Join(t.TempDir(), "config.toml"))
+
+	tests := []struct {
+		name string
+		args []string
+		want string
+	}{
+		{name: "account", args: []string{"tokens", "list", "--project", "7"}, want: "tokens need an account token"},
+		{name: "scope", args: []string{"tokens", "rotate", "--project", "7"}, want: `required flag(s) "scope" not set`},
+		{name: "export", args: []string{"tokens", "rotate", "--scope", "read", "--export", "NOT-A-VAR"}, want: "invalid --export variable name"},
+		{name: "grace", args: []string{"tokens", "rotate", "--scope", "read", "--grace", "-1m"}, want: "--grace cannot be negative"},
+	}
+	for _, tc := range tests {
+		cmd := NewRootCmd()
+		cmd.SetArgs(tc.args)
+		checkError(t, tc.name, cmd.Execute(), tc.want)
+	}
+}
@@ -0,0 +1,121 @@
+package cli
+
+import (
+	"context"
+	"os"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/tui"
+)
+
+const (
+	tuiListLimit       = 100
+	tuiOccurrenceLimit = 20
+	defaultTUIMuteFor  = "24h"
+)
+
+var runTUI = tui.Run
+
+type tuiFlags struct {
+	Levels  []string
+	MuteFor string
+}
+
+func newTUICmd(flags *rootFlags) *cobra.Command {
+	tuiOptions := tuiFlags{}
+	tuiCmd := &cobra.Command{
+		Use:   "tui",
+		Short: "Browse, inspect and triage items interactively",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			defaultHTTPCache(cmd, flags)
+			backend, err := newTUIBackend(*flags, tuiOptions, cmd.Flags().Changed("limit"))
+			if err != nil {
+				return err
+			}
+
+			return runTUI(cmd.Context(), backend, os.Stdin, os.Stdout)
+		},
+	}
+	tuiCmd.Flags().StringSliceVar(&tuiOptions.Levels, "level", nil, "Filter by level (repeatable)")
+	tuiCmd.Flags().StringVar(&tuiOptions.MuteFor, "mute-for", defaultTUIMuteFor, "How long the mute key mutes an item (empty mutes until reopened)")
+
+	return tuiCmd
+}
+
+// tuiBackend adapts app.Service to the TUI, redacting errors before they are
+// drawn on screen.
+type tuiBackend struct {
+	service *app.Service
+	token   string
+	options app.ListOptions
+	muteFor *int64
+}
+
+func newTUIBackend(flags rootFlags, tuiOptions tuiFlags, limitSet bool) (*tuiBackend, error) {
+	options, err := parseListOptions(flags, itemsListFlags{Levels: tuiOptions.Levels})
+	if err != nil {
+		return nil, err
+	}
+	if !limitSet {
+		options.Limit = tuiListLimit
+	}
+
+	muteFor, err := parseMuteDuration(tuiOptions.MuteFor)
+	if err != nil {
+		return nil, err
+	}
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return nil, err
+	}
+
+	return &tuiBackend{service: service, token: token, options: options, muteFor: muteFor}, nil
+}
+
+func (b *tuiBackend) List(ctx context.Context) ([]app.IssueSummary, error) {
+	issues, err := b.service.List(ctx, b.options)
+	if err != nil {
+		return nil, sanitizeError(err, b.token)
+	}
+
+	return issues, nil
+}
+
+func (b *tuiBackend) Show(ctx context.Context, counter domain.ItemCounter) (app.IssueDetail, []app.OccurrenceSummary, error) {
+	detail, err := b.service.Show(ctx, counter)
+	if err != nil {
+		return app.IssueDetail{}, nil, sanitizeError(err, b.token)
+	}
+
+	occurrences, err := b.service.Occurrences(ctx, counter, app.OccurrenceOptions{Limit: tuiOccurrenceLimit})
+	if err != nil {
+		return app.IssueDetail{}, nil, sanitizeError(err, b.token)
+	}
+
+	return detail, occurrences, nil
+}
+
+func (b *tuiBackend) Resolve(ctx context.Context, counter domain.ItemCounter) (app.ItemActionResult, error) {
+	return b.write(b.service.Resolve(ctx, counter, ""))
+}
+
+func (b *tuiBackend) Mute(ctx context.Context, counter domain.ItemCounter) (app.ItemActionResult, error) {
+	return b.write(b.service.Mute(ctx, counter, b.muteFor))
+}
+
+func (b *tuiBackend) Assign(ctx context.Context, counter domain.ItemCounter, email string) (app.ItemActionResult, error) {
+	return b.write(b.service.Assign(ctx, counter, email))
+}
+
+func (b *tuiBackend) write(result app.ItemActionResult, err error) (app.ItemActionResult, error) {
+	if err != nil {
+		return app.ItemActionResult{}, sanitizeError(err, b.token)
+	}
+
+	return result, nil
+}
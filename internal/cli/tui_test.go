@@ -0,0 +1,79 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"os"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/tui"
+)
+
+func overrideRunTUI(t *testing.T, run func(context.Context, tui.Backend, *os.File, *os.File) error) {
+	t.Helper()
+	original := runTUI
+	runTUI = run
+	t.Cleanup(func() {
+		runTUI = original
+	})
+}
+
+func TestTUICommandBackend(t *testing.T) {
+	setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/api/1/items" || r.URL.Query().Get("level") != "error" {
+			w.WriteHeader(http.StatusBadRequest)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected request %s"}`, r.URL)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":11,"counter":1,"title":"a","level":"error","status":"active"}],"total_count":1}}`)
+	}))
+
+	listed := 0
+	overrideRunTUI(t, func(ctx context.Context, backend tui.Backend, in *os.File, out *os.File) error {
+		issues, err := backend.List(ctx)
+		if err != nil {
+			return err
+		}
+		listed = len(issues)
+		return nil
+	})
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"tui", "--level", "error"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("tui error = %v", err)
+	}
+	if listed != 1 {
+		t.Fatalf("expected 1 listed issue, got %d", listed)
+	}
+}
+
+func TestTUICommandRedactsErrorsAndValidatesFlags(t *testing.T) {
+	setupServerAndStdout(t, http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusBadRequest)
+		_, _ = fmt.Fprint(w, `{"err":1,"message":"bad token token"}`)
+	}))
+
+	var listErr error
+	overrideRunTUI(t, func(ctx context.Context, backend tui.Backend, in *os.File, out *os.File) error {
+		_, listErr = backend.List(ctx)
+		return nil
+	})
+
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"tui"})
+	if err := cmd.Execute(); err != nil {
+		t.Fatalf("tui error = %v", err)
+	}
+	if listErr == nil || strings.Contains(listErr.Error(), "bad token token") {
+		t.Fatalf("expected redacted list error, got %v", listErr)
+	}
+
+	cmd = NewRootCmd()
+	cmd.SetArgs([]string{"tui", "--mute-for", "soon"})
+	if err := cmd.Execute(); err == nil || !strings.Contains(err.Error(), "invalid duration") {
+		t.Fatalf("expected mute duration error, got %v", err)
+	}
+}
@@ -0,0 +1,287 @@
+package cli
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"os/exec"
+	"os/signal"
+	"syscall"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/output"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+const (
+	defaultWatchInterval = 30 * time.Second
+	minWatchInterval     = time.Second
+	watchPageSize        = 100
+	watchPollTimeout     = 30 * time.Second
+)
+
+type watchFlags struct {
+	Levels          []string
+	Interval        time.Duration
+	Exec            string
+	Notify          []string
+	IncludeExisting bool
+	// maxPolls stops the loop after that many polls; zero runs until
+	// interrupted. Only tests set it.
+	maxPolls int
+}
+
+func newWatchCmd(flags *rootFlags) *cobra.Command {
+	watch := watchFlags{}
+	watchCmd := &cobra.Command{
+		Use:   "watch",
+		Short: "Stream new and reoccurring items until interrupted",
+		Args:  cobra.NoArgs,
+		RunE: func(cmd *cobra.Command, args []string) error {
+			if watch.Interval < minWatchInterval {
+				return fmt.Errorf("--interval must be at least %s", minWatchInterval)
+			}
+			defaultHTTPCache(cmd, flags)
+
+			ctx, stop := signal.NotifyContext(cmd.Context(), os.Interrupt, syscall.SIGTERM)
+			defer stop()
+
+			return runWatch(ctx, *flags, watch)
+		},
+	}
+	watchCmd.Flags().StringSliceVar(&watch.Levels, "level", nil, "Filter by level (repeatable)")
+	watchCmd.Flags().DurationVar(&watch.Interval, "interval", defaultWatchInterval, "Time between polls")
+	watchCmd.Flags().StringVar(&watch.Exec, "exec", "", "Shell command to run per event; item fields are passed as ROLLBAZ_* environment variables")
+	watchCmd.Flags().StringSliceVar(&watch.Notify, "notify", nil, "Also send each event to: slack, desktop (repeatable)")
+	watchCmd.Flags().BoolVar(&watch.IncludeExisting, "include-existing", false, "Report items that already exist on the first poll")
+
+	return watchCmd
+}
+
+// runWatch polls until ctx is cancelled. Poll failures are reported on stderr
+// and retried on the next tick so a flaky network does not end the watch.
+func runWatch(ctx context.Context, flags rootFlags, watch watchFlags) error {
+	options, err := parseListOptions(flags, itemsListFlags{Levels: watch.Levels})
+	if err != nil {
+		return err
+	}
+	options.Limit = watchPageSize
+
+	service, token, err := buildService(flags)
+	if err != nil {
+		return err
+	}
+	notifiers, err := watchNotifiers(flags, watch, service, token)
+	if err != nil {
+		return err
+	}
+
+	state := app.NewWatchState(watch.IncludeExisting)
+	for poll := 1; ; poll++ {
+		if err := watchOnce(ctx, service, options, state, flags, notifiers, token); err != nil {
+			return err
+		}
+		if watch.maxPolls > 0 && poll >= watch.maxPolls {
+			return nil
+		}
+
+		select {
+		case <-ctx.Done():
+			return nil
+		case <-time.After(watch.Interval):
+		}
+	}
+}
+
+// watchNotifier is something done with each event besides printing it.
+type watchNotifier struct {
+	name   string
+	notify func(ctx context.Context, event app.WatchEvent) error
+}
+
+func watchNotifiers(flags rootFlags, watch watchFlags, service *app.Service, token string) ([]watchNotifier, error) {
+	notifiers := []watchNotifier{}
+	if watch.Exec != "" {
+		notifiers = append(notifiers, watchNotifier{name: "--exec", notify: func(ctx context.Context, event app.WatchEvent) error {
+			return runWatchExec(ctx, watch.Exec, event)
+		}})
+	}
+
+	link := watchItemLinker(flags, service, token)
+	for _, target := range watch.Notify {
+		switch target {
+		case "slack":
+			notifier, err := slackWatchNotifier(flags, link, token)
+			if err != nil {
+				return nil, err
+			}
+			notifiers = append(notifiers, notifier)
+		case "desktop":
+			notifiers = append(notifiers, desktopWatchNotifier(link))
+		default:
+			return nil, fmt.Errorf("unknown --notify target %q; use slack or desktop", target)
+		}
+	}
+
+	return notifiers, nil
+}
+
+type itemLinker func(ctx context.Context, counter domain.ItemCounter) (string, error)
+
+// watchItemLinker links items in Rollbar like `open` does: directly from the
+// profile account and --project when both are known, otherwise through the
+// latest occurrence.
+func watchItemLinker(flags rootFlags, service *app.Service, token string) itemLinker {
+	location := itemWebLocation(domain.ItemRef{}, flags.Account, webProjectSlug(flags))
+
+	return func(ctx context.Context, counter domain.ItemCounter) (string, error) {
+		link, err := service.ItemURL(ctx, counter, location)
+		if err != nil {
+			return "", sanitizeError(err, token)
+		}
+
+		return link, nil
+	}
+}
+
+// slackWatchNotifier posts each event as a card, using the same destination
+// settings as `item post-slack`.
+func slackWatchNotifier(flags rootFlags, link itemLinker, token string) (watchNotifier, error) {
+	client, secret, err := newSlackClient(flags, slackFlags{})
+	if err != nil {
+		return watchNotifier{}, err
+	}
+
+	notify := func(ctx context.Context, event app.WatchEvent) error {
+		url, err := link(ctx, event.Issue.Counter)
+		if err != nil {
+			return err
+		}
+		card := output.SlackCard{Heading: output.WatchEventHeading(event), Issue: event.Issue, Link: url}
+		if err := app.PostToSlack(ctx, client, redactSlackMessage(output.RenderSlackMessage(card), token)); err != nil {
+			return sanitizeError(err, secret)
+		}
+
+		return nil
+	}
+
+	return watchNotifier{name: "slack", notify: notify}, nil
+}
+
+// desktopWatchNotifier pops up an OS notification per event; clicking it
+// opens the item where the platform's notifier supports actions.
+func desktopWatchNotifier(link itemLinker) watchNotifier {
+	notify := func(ctx context.Context, event app.WatchEvent) error {
+		url, err := link(ctx, event.Issue.Counter)
+		if err != nil {
+			return err
+		}
+		title, body := output.RenderWatchNotification(event)
+
+		return sendDesktopNotification(desktopNotification{Title: title, Body: body, URL: url})
+	}
+
+	return watchNotifier{name: "desktop notification", notify: notify}
+}
+
+func watchOnce(ctx context.Context, service *app.Service, options app.ListOptions, state *app.WatchState, flags rootFlags, notifiers []watchNotifier, token string) error {
+	pollCtx, cancel := context.WithTimeout(ctx, watchPollTimeout)
+	defer cancel()
+
+	events, err := service.Poll(pollCtx, options, state)
+	if err != nil {
+		if ctx.Err() == nil {
+			_, _ = fmt.Fprintf(stderrWriter, "watch: %s\n", sanitizeError(err, token))
+		}
+		return nil
+	}
+
+	for _, event := range events {
+		if err := printWatchEvent(flags, event, token); err != nil {
+			return err
+		}
+		for _, notifier := range notifiers {
+			if err := notifier.notify(ctx, event); err != nil && ctx.Err() == nil {
+				_, _ = fmt.Fprintf(stderrWriter, "watch: %s for #%s: %s\n", notifier.name, event.Issue.Counter, err)
+			}
+		}
+	}
+
+	return nil
+}
+
+// printWatchEvent streams one event. JSON output is written one compact
+// document per line, as with ndjson, so it can be piped while the watch runs.
+func printWatchEvent(flags rootFlags, event app.WatchEvent, token string) error {
+	parsed, err := output.ParseFormat(flags.Format)
+	if err != nil {
+		return err
+	}
+	if parsed.Human() {
+		_, _ = fmt.Fprintln(stdoutWriter, output.RenderWatchEvent(event, time.Now()))
+		return nil
+	}
+	if parsed == output.FormatJSON {
+		parsed = output.FormatNDJSON
+	}
+
+	rendered, err := renderPayload(flags, parsed, "", redact.Value(event, token))
+	if err != nil {
+		return fmt.Errorf("render %s: %w", parsed, err)
+	}
+	if parsed == output.FormatYAML {
+		rendered = "---\n" + rendered
+	}
+	_, _ = fmt.Fprintln(stdoutWriter, rendered)
+
+	return nil
+}
+
+func runWatchExec(ctx context.Context, command string, event app.WatchEvent) error {
+	env := append(itemEnv(event.Issue),
+		"ROLLBAZ_EVENT="+string(event.Kind),
+		fmt.Sprintf("ROLLBAZ_NEW_OCCURRENCES=%d", event.NewOccurrences),
+	)
+
+	return runEventCommand(ctx, command, env, nil)
+}
+
+// itemEnv is the ROLLBAZ_ITEM_* environment for an --exec command.
+func itemEnv(issue app.IssueSummary) []string {
+	return []string{
+		fmt.Sprintf("ROLLBAZ_ITEM_ID=%d", issue.ItemID),
+		"ROLLBAZ_ITEM_COUNTER=" + issue.Counter.String(),
+		"ROLLBAZ_ITEM_TITLE=" + issue.Title,
+		"ROLLBAZ_ITEM_LEVEL=" + issue.Level,
+		"ROLLBAZ_ITEM_ENVIRONMENT=" + issue.Environment,
+	}
+}
+
+// runEventCommand runs an --exec command with env added to rollbaz's own
+// environment and stdin, if any, as its input.
+func runEventCommand(ctx context.Context, command string, env []string, stdin []byte) error {
+	//nolint:gosec // G204: --exec deliberately runs the user's own command; event fields travel in the environment, not the command line.
+	cmd := exec.CommandContext(ctx, "sh", "-c", command)
+	cmd.Env = append(os.Environ(), env...)
+	if stdin != nil {
+		cmd.Stdin = bytes.NewReader(stdin)
+	}
+	cmd.Stdout = stdoutWriter
+	cmd.Stderr = stderrWriter
+
+	if err := cmd.Run(); err != nil {
+		var exitErr *exec.ExitError
+		if errors.As(err, &exitErr) {
+			return fmt.Errorf("exited with status %d", exitErr.ExitCode())
+		}
+		return fmt.Errorf("run command: %w", err)
+	}
+
+	return nil
+}
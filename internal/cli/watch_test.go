@@ -0,0 +1,124 @@
+package cli
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+func newWatchHandler(t *testing.T) http.Handler {
+	t.Helper()
+	var polls atomic.Int32
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/api/1/items" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		if r.URL.Query().Get("level") != "error" {
+			w.WriteHeader(http.StatusBadRequest)
+			_, _ = fmt.Fprintf(w, `{"err":1,"message":"unexpected query %s"}`, r.URL.RawQuery)
+			return
+		}
+		if polls.Add(1) == 1 {
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":11,"counter":1,"title":"old","level":"error","last_occurrence_timestamp":100,"total_occurrences":2}],"total_count":1}}`)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":12,"counter":2,"title":"brand new","level":"error","last_occurrence_timestamp":300,"total_occurrences":1},{"id":11,"counter":1,"title":"old","level":"error","last_occurrence_timestamp":200,"total_occurrences":5}],"total_count":2}}`)
+	})
+}
+
+func TestRunWatch(t *testing.T) {
+	tests := []struct {
+		name   string
+		format string
+		exec   string
+		want   []string
+	}{
+		{
+			name:   "human with exec",
+			format: "human",
+			exec:   "echo exec-$ROLLBAZ_EVENT-$ROLLBAZ_ITEM_COUNTER",
+			want:   []string{"REOCCURRED #1 error", "old (+3)", "NEW        #2 error", "exec-reoccurred-1", "exec-new-2"},
+		},
+		{
+			name:   "json lines",
+			format: "json",
+			want:   []string{`{"issue":{"counter":1,`, `"kind":"reoccurred","new_occurrences":3}`, `"kind":"new"`},
+		},
+	}
+
+	for _, tc := range tests {
+		stdout := setupServerAndStdout(t, newWatchHandler(t))
+		flags := rootFlags{Format: tc.format, Limit: 10}
+		watch := watchFlags{Levels: []string{"error"}, Interval: time.Millisecond, Exec: tc.exec, maxPolls: 2}
+		if err := runWatch(context.Background(), flags, watch); err != nil {
+			t.Fatalf("%s: runWatch() error = %v", tc.name, err)
+		}
+
+		out := stdout.String()
+		for _, want := range tc.want {
+			if !strings.Contains(out, want) {
+				t.Fatalf("%s: output missing %q:\n%s", tc.name, want, out)
+			}
+		}
+		if strings.Index(out, "#1") > strings.Index(out, "#2") && tc.format == "human" {
+			t.Fatalf("%s: expected oldest change first:\n%s", tc.name, out)
+		}
+	}
+}
+
+func TestRunWatchNotifySlack(t *testing.T) {
+	_ = setupServerAndStdout(t, newWatchHandler(t))
+	server, posted := newSlackServer(t)
+	flags := rootFlags{Format: "human", Limit: 10, Token: "token", AccountToken: "account-token", Account: "acme", Project: "billing", SlackWebhookURL: server.URL + "/hook"}
+	watch := watchFlags{Levels: []string{"error"}, Interval: time.Millisecond, Notify: []string{"slack"}, maxPolls: 2}
+	if err := runWatch(context.Background(), flags, watch); err != nil {
+		t.Fatalf("runWatch() error = %v", err)
+	}
+
+	messages := posted()
+	if len(messages) != 2 || !strings.HasPrefix(messages[0].Text, "Occurred again (+3): #1 old") || !strings.Contains(messages[1].Text, "#2 brand new") {
+		t.Fatalf("unexpected messages: %+v", messages)
+	}
+	if url := messages[1].Blocks[2].Elements[0].URL; !strings.HasSuffix(url, "/acme/billing/items/2/") {
+		t.Fatalf("unexpected item link %q", url)
+	}
+
+	watch.Notify = []string{"pager"}
+	if err := runWatch(context.Background(), flags, watch); err == nil || !strings.Contains(err.Error(), "unknown --notify target") {
+		t.Fatalf("expected unknown target error, got %v", err)
+	}
+}
+
+func TestRunWatchNotifyDesktop(t *testing.T) {
+	_ = setupServerAndStdout(t, newWatchHandler(t))
+	var shown []desktopNotification
+	previous := sendDesktopNotification
+	sendDesktopNotification = func(notification desktopNotification) error {
+		shown = append(shown, notification)
+		return nil
+	}
+	t.Cleanup(func() { sendDesktopNotification = previous })
+
+	flags := rootFlags{Format: "human", Limit: 10, Token: "token", AccountToken: "account-token", Account: "acme", Project: "billing"}
+	watch := watchFlags{Levels: []string{"error"}, Interval: time.Millisecond, Notify: []string{"desktop"}, maxPolls: 2}
+	if err := runWatch(context.Background(), flags, watch); err != nil {
+		t.Fatalf("runWatch() error = %v", err)
+	}
+
+	if len(shown) != 2 || shown[1].Title != "Rollbar #2: New item (+1)" || shown[1].Body != "brand new" || !strings.HasSuffix(shown[1].URL, "/acme/billing/items/2/") {
+		t.Fatalf("unexpected notifications: %+v", shown)
+	}
+}
+
+func TestWatchCommandRejectsShortInterval(t *testing.T) {
+	cmd := NewRootCmd()
+	cmd.SetArgs([]string{"watch", "--interval", "10ms"})
+	err := cmd.Execute()
+	if err == nil || !strings.Contains(err.Error(), "--interval") {
+		t.Fatalf("expected interval error, got %v", err)
+	}
+}
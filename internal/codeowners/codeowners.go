@@ -0,0 +1,155 @@
+// Package codeowners reads GitHub CODEOWNERS files and matches paths to their
+// owners.
+package codeowners
+
+import (
+	"bufio"
+	"bytes"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+)
+
+// Locations are where GitHub looks for a CODEOWNERS file, in its order.
+var Locations = []string{".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"}
+
+// Rule is one CODEOWNERS line. A rule without owners unassigns its paths.
+type Rule struct {
+	Pattern string   `json:"pattern"`
+	Owners  []string `json:"owners"`
+	Line    int      `json:"line"`
+	match   *regexp.Regexp
+}
+
+// Ruleset is a parsed CODEOWNERS file, rules in file order.
+type Ruleset struct {
+	Rules []Rule
+}
+
+// Find returns the first of Locations that exists under dir.
+func Find(dir string) (string, error) {
+	for _, location := range Locations {
+		path := filepath.Join(dir, filepath.FromSlash(location))
+		if info, err := os.Stat(path); err == nil && info.Mode().IsRegular() {
+			return path, nil
+		}
+	}
+
+	return "", fmt.Errorf("no CODEOWNERS file in %s (tried %s)", dir, strings.Join(Locations, ", "))
+}
+
+// Load reads and parses the CODEOWNERS file at path.
+func Load(path string) (*Ruleset, error) {
+	body, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return nil, fmt.Errorf("read CODEOWNERS: %w", err)
+	}
+
+	return Parse(body)
+}
+
+// Parse reads CODEOWNERS rules, skipping blank lines and comments. Patterns
+// follow GitHub's subset of gitignore syntax: no negation or brackets.
+func Parse(body []byte) (*Ruleset, error) {
+	rules := make([]Rule, 0)
+	scanner := bufio.NewScanner(bytes.NewReader(body))
+	for line := 1; scanner.Scan(); line++ {
+		fields := strings.Fields(scanner.Text())
+		if len(fields) == 0 || strings.HasPrefix(fields[0], "#") {
+			continue
+		}
+		if strings.HasPrefix(fields[0], "!") || strings.ContainsAny(fields[0], "[]") {
+			return nil, fmt.Errorf("CODEOWNERS line %d: unsupported pattern %q", line, fields[0])
+		}
+
+		rules = append(rules, Rule{Pattern: fields[0], Owners: ownerFields(fields[1:]), Line: line, match: Compile(fields[0])})
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, fmt.Errorf("scan CODEOWNERS: %w", err)
+	}
+	if len(rules) == 0 {
+		return nil, errors.New("CODEOWNERS has no rules")
+	}
+
+	return &Ruleset{Rules: rules}, nil
+}
+
+// Match returns the rule owning path, relative to the repository root: the
+// last one matching it, as on GitHub.
+func (r *Ruleset) Match(path string) (Rule, bool) {
+	path = strings.TrimPrefix(filepath.ToSlash(path), "/")
+	for index := len(r.Rules) - 1; index >= 0; index-- {
+		if r.Rules[index].match.MatchString(path) {
+			return r.Rules[index], true
+		}
+	}
+
+	return Rule{}, false
+}
+
+// ownerFields drops a trailing comment from a rule's owners.
+func ownerFields(fields []string) []string {
+	owners := make([]string, 0, len(fields))
+	for _, field := range fields {
+		if strings.HasPrefix(field, "#") {
+			break
+		}
+		owners = append(owners, field)
+	}
+
+	return owners
+}
+
+// Compile turns a pattern into a regexp over slash-separated paths relative
+// to the repository root.
+func Compile(pattern string) *regexp.Regexp {
+	prefix, trimmed, suffix := anchors(pattern)
+
+	var expression strings.Builder
+	for index := 0; index < len(trimmed); index++ {
+		switch {
+		case strings.HasPrefix(trimmed[index:], "**/"):
+			expression.WriteString("(?:.*/)?")
+			index += 2
+		case strings.HasPrefix(trimmed[index:], "**"):
+			expression.WriteString(".*")
+			index++
+		case trimmed[index] == '*':
+			expression.WriteString("[^/]*")
+		case trimmed[index] == '?':
+			expression.WriteString("[^/]")
+		case trimmed[index] == '\\' && index+1 < len(trimmed):
+			index++
+			expression.WriteString(regexp.QuoteMeta(trimmed[index : index+1]))
+		default:
+			expression.WriteString(regexp.QuoteMeta(trimmed[index : index+1]))
+		}
+	}
+
+	return regexp.MustCompile(prefix + expression.String() + suffix)
+}
+
+// anchors splits off what a pattern says about where it matches. Patterns
+// with a slash before their end are anchored at the root and the rest match
+// at any depth. Every pattern also matches everything under a directory it
+// names, except one ending in /* which matches only that directory's
+// entries; a trailing slash matches only a directory's contents.
+func anchors(pattern string) (string, string, string) {
+	trimmed := strings.TrimSuffix(pattern, "/")
+	prefix := "^(?:.*/)?"
+	if strings.Contains(trimmed, "/") {
+		prefix = "^"
+	}
+	suffix := "(?:/.*)?$"
+	switch {
+	case trimmed != pattern:
+		suffix = "/.*$"
+	case strings.HasSuffix(trimmed, "/*"):
+		suffix = "$"
+	}
+
+	return prefix, strings.TrimPrefix(trimmed, "/"), suffix
+}
@@ -0,0 +1,128 @@
+package codeowners
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+const sample = `# Default owners
+*                 @acme/platform
+
+*.js              @frontend-dev   # JavaScript
+/docs/            docs@example.com
+apps/             @apps-owner
+/build/logs/      @build-owner
+payments/*        @payments
+**/migrations     @dba
+/vendor/
+`
+
+func TestRulesetMatch(t *testing.T) {
+	t.Parallel()
+
+	rules, err := Parse([]byte(sample))
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+
+	tests := []struct {
+		path    string
+		pattern string
+		owners  []string
+	}{
+		{path: "main.go", pattern: "*", owners: []string{"@acme/platform"}},
+		{path: "web/static/app.js", pattern: "*.js", owners: []string{"@frontend-dev"}},
+		{path: "docs/guide/setup.md", pattern: "/docs/", owners: []string{"docs@example.com"}},
+		{path: "api/docs/readme.md", pattern: "*", owners: []string{"@acme/platform"}},
+		{path: "src/apps/billing/invoice.py", pattern: "apps/", owners: []string{"@apps-owner"}},
+		{path: "build/logs/today.log", pattern: "/build/logs/", owners: []string{"@build-owner"}},
+		{path: "payments/charge.py", pattern: "payments/*", owners: []string{"@payments"}},
+		{path: "payments/stripe/charge.py", pattern: "*", owners: []string{"@acme/platform"}},
+		{path: "db/migrations/0001_init.sql", pattern: "**/migrations", owners: []string{"@dba"}},
+		{path: "/vendor/lib/lib.go", pattern: "/vendor/", owners: []string{}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.path, func(t *testing.T) {
+			t.Parallel()
+
+			rule, ok := rules.Match(tt.path)
+			if !ok || rule.Pattern != tt.pattern || !reflect.DeepEqual(rule.Owners, tt.owners) {
+				t.Fatalf("Match(%q) = %+v, %v; want %q owned by %v", tt.path, rule, ok, tt.pattern, tt.owners)
+			}
+		})
+	}
+}
+
+func TestRulesetMatchAnchored(t *testing.T) {
+	t.Parallel()
+
+	anchored, err := Parse([]byte("/docs/*.md @writer"))
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+	if _, ok := anchored.Match("guide/docs/intro.md"); ok {
+		t.Fatal("expected an anchored pattern to skip nested directories")
+	}
+}
+
+func TestParseErrors(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name string
+		body string
+		want string
+	}{
+		{name: "empty", body: "# nothing here\n\n", want: "no rules"},
+		{name: "negation", body: "* @a\n!docs/ @b\n", want: "line 2: unsupported pattern"},
+		{name: "brackets", body: "[Rr]eadme @a\n", want: "line 1: unsupported pattern"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			if _, err := Parse([]byte(tt.body)); err == nil || !strings.Contains(err.Error(), tt.want) {
+				t.Fatalf("Parse() error = %v, want %q", err, tt.want)
+			}
+		})
+	}
+}
+
+func TestFindAndLoadMissing(t *testing.T) {
+	t.Parallel()
+
+	dir := t.TempDir()
+	if _, err := Find(dir); err == nil || !strings.Contains(err.Error(), "no CODEOWNERS file") {
+		t.Fatalf("expected a missing CODEOWNERS error, got %v", err)
+	}
+	if _, err := Load(filepath.Join(dir, "CODEOWNERS")); err == nil {
+		t.Fatal("expected a read error")
+	}
+}
+
+func TestFindAndLoad(t *testing.T) {
+	t.Parallel()
+
+	dir := t.TempDir()
+	if err := os.WriteFile(filepath.Join(dir, "CODEOWNERS"), []byte("* @root\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.MkdirAll(filepath.Join(dir, "docs"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(dir, "docs", "CODEOWNERS"), []byte("* @docs\n"), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	path, err := Find(dir)
+	if err != nil || path != filepath.Join(dir, "CODEOWNERS") {
+		t.Fatalf("Find() = %q, %v", path, err)
+	}
+	rules, err := Load(path)
+	if err != nil || len(rules.Rules) != 1 || rules.Rules[0].Owners[0] != "@root" {
+		t.Fatalf("Load() = %+v, %v", rules, err)
+	}
+}
@@ -0,0 +1,58 @@
+package config
+
+import (
+	"errors"
+	"fmt"
+)
+
+// JiraConfig is the [jira] table of config.toml, the defaults for
+// `item to-jira`, plus its [jira.fields] table:
+//
+//	[jira]
+//	url = "https://acme.atlassian.net"
+//	email = "me@acme.com"
+//	project = "ENG"
+//	issue_type = "Bug"
+//
+//	[jira.fields]
+//	labels = "rollbar,{environment}"
+//	priority = "High"
+//	customfield_10042 = "{link}"
+type JiraConfig struct {
+	URL       string
+	Email     string
+	Token     string
+	Project   string
+	IssueType string
+	// Fields maps Jira field ids to value templates.
+	Fields map[string]string
+}
+
+func setJiraField(jira *JiraConfig, key string, value string) error {
+	fields := map[string]*string{
+		"url":        &jira.URL,
+		"email":      &jira.Email,
+		"token":      &jira.Token,
+		"project":    &jira.Project,
+		"issue_type": &jira.IssueType,
+	}
+	field, ok := fields[key]
+	if !ok {
+		return fmt.Errorf("unknown jira key %q", key)
+	}
+	*field = value
+
+	return nil
+}
+
+func setJiraFieldTemplate(jira *JiraConfig, key string, value string) error {
+	if key == "" {
+		return errors.New("empty jira field name")
+	}
+	if jira.Fields == nil {
+		jira.Fields = map[string]string{}
+	}
+	jira.Fields[key] = value
+
+	return nil
+}
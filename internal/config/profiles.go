@@ -0,0 +1,449 @@
+package config
+
+import (
+	"bufio"
+	"bytes"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+)
+
+// Profile is a named set of defaults from config.toml. Empty fields leave the
+// corresponding flag or setting alone.
+type Profile struct {
+	Name  string
+	Token string
+	// AccountToken resolves --project ids and slugs through the projects API.
+	AccountToken string
+	// Account is the account slug used in web UI links.
+	Account     string
+	Project     string
+	BaseURL     string
+	Environment string
+	Format      string
+	// GitHubToken files GitHub issues; GITHUB_TOKEN is the fallback.
+	GitHubToken string
+	// GitHubURL is the GitHub API root, for GitHub Enterprise Server.
+	GitHubURL string
+	// SlackWebhookURL, or SlackToken with SlackChannel, is where Slack
+	// posts go.
+	SlackWebhookURL string
+	SlackToken      string
+	SlackChannel    string
+	// InApp replaces the top-level in_app patterns for this profile.
+	InApp []string
+}
+
+// ProfileFile is the decoded config.toml:
+//
+//	default_profile = "work"
+//	in_app = ["src/**", "!src/vendor/**"]
+//
+//	[profiles.work]
+//	token = "..."
+//	project = "billing-api"
+//	base_url = "https://api.rollbar.com/api/1"
+//	environment = "production"
+//	format = "json"
+//	github_token = "..."
+//
+//	[queries.prod-errors]
+//	status = "active"
+//	level = "error"
+//	env = "production"
+//
+// and the [jira] and [redact] tables described by JiraConfig and
+// RedactConfig. in_app, at the top level or in a profile, lists the patterns
+// of application code in stack traces.
+type ProfileFile struct {
+	DefaultProfile string
+	InApp          []string
+	Profiles       map[string]Profile
+	Queries        map[string]SavedQuery
+	Jira           JiraConfig
+	Redact         RedactConfig
+}
+
+// DefaultProfilePath is config.toml next to the project store's config.json.
+func DefaultProfilePath() (string, error) {
+	configRoot, err := os.UserConfigDir()
+	if err != nil {
+		return "", fmt.Errorf("resolve config dir: %w", err)
+	}
+
+	return filepath.Join(configRoot, "rollbaz", "config.toml"), nil
+}
+
+// LoadProfiles reads config.toml. A missing file is an empty ProfileFile.
+func LoadProfiles(path string) (ProfileFile, error) {
+	body, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return ProfileFile{Profiles: map[string]Profile{}, Queries: map[string]SavedQuery{}}, nil
+	}
+	if err != nil {
+		return ProfileFile{}, fmt.Errorf("read profiles: %w", err)
+	}
+
+	file, err := ParseProfiles(body)
+	if err != nil {
+		return ProfileFile{}, fmt.Errorf("parse %s: %w", path, err)
+	}
+
+	return file, nil
+}
+
+// Select returns the named profile, falling back to default_profile when name
+// is empty. It reports false when no profile applies.
+func (f ProfileFile) Select(name string) (Profile, bool, error) {
+	target := strings.TrimSpace(name)
+	if target == "" {
+		target = f.DefaultProfile
+	}
+	if target == "" {
+		return Profile{}, false, nil
+	}
+
+	profile, ok := f.Profiles[target]
+	if !ok {
+		return Profile{}, false, fmt.Errorf("profile %q not found (known: %s)", target, f.profileNames())
+	}
+
+	return profile, true, nil
+}
+
+func (f ProfileFile) profileNames() string {
+	if len(f.Profiles) == 0 {
+		return "none"
+	}
+
+	names := make([]string, 0, len(f.Profiles))
+	for name := range f.Profiles {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	return strings.Join(names, ", ")
+}
+
+// ParseProfiles decodes the subset of TOML config.toml uses: comments, a
+// top-level default_profile and in_app, and [profiles.<name>],
+// [queries.<name>], [jira], [jira.fields], and [redact] tables. Keys are bare
+// and values are one-line strings, except that in_app and the [redact] keys
+// are arrays of strings written on one line. Other TOML, such as dotted or
+// quoted keys, multi-line strings, inline tables, and arrays of tables, is an
+// error naming its line.
+func ParseProfiles(data []byte) (ProfileFile, error) {
+	file := ProfileFile{Profiles: map[string]Profile{}, Queries: map[string]SavedQuery{}}
+	current := tomlTable{}
+
+	scanner := bufio.NewScanner(bytes.NewReader(data))
+	for lineNumber := 1; scanner.Scan(); lineNumber++ {
+		line := strings.TrimSpace(stripComment(scanner.Text()))
+		if line == "" {
+			continue
+		}
+
+		var err error
+		if strings.HasPrefix(line, "[") {
+			current, err = parseTableHeader(line, file)
+		} else {
+			err = parseTableKey(line, current, &file)
+		}
+		if err != nil {
+			return ProfileFile{}, fmt.Errorf("line %d: %w", lineNumber, err)
+		}
+	}
+	if err := scanner.Err(); err != nil {
+		return ProfileFile{}, fmt.Errorf("scan profiles: %w", err)
+	}
+
+	return file, nil
+}
+
+// tomlTable is the [kind.name] table keys currently belong to; kind is empty
+// at the top level.
+type tomlTable struct {
+	kind string
+	name string
+}
+
+var tableKinds = []struct {
+	kind   string
+	prefix string
+}{
+	{kind: "profile", prefix: "profiles."},
+	{kind: "query", prefix: "queries."},
+}
+
+// tomlBareKey is a key TOML allows without quotes.
+var tomlBareKey = regexp.MustCompile(`^[A-Za-z0-9_-]+$`)
+
+func parseTableHeaderName(line string) (tomlTable, error) {
+	if strings.HasPrefix(line, "[[") {
+		return tomlTable{}, fmt.Errorf("arrays of tables are not supported, got %q", line)
+	}
+	if !strings.HasSuffix(line, "]") {
+		return tomlTable{}, fmt.Errorf("unterminated table header %q", line)
+	}
+	header := strings.TrimSpace(line[1 : len(line)-1])
+	switch header {
+	case "jira":
+		return tomlTable{kind: "jira"}, nil
+	case "jira.fields":
+		return tomlTable{kind: "jira field"}, nil
+	case "redact":
+		return tomlTable{kind: "redact"}, nil
+	}
+	for _, table := range tableKinds {
+		if name, ok := strings.CutPrefix(header, table.prefix); ok {
+			name, err := parseTOMLKey(strings.TrimSpace(name), table.kind)
+			return tomlTable{kind: table.kind, name: name}, err
+		}
+	}
+
+	return tomlTable{}, fmt.Errorf("unsupported table [%s] (use [profiles.<name>], [queries.<name>], [jira], [jira.fields], or [redact])", header)
+}
+
+func parseTableHeader(line string, file ProfileFile) (tomlTable, error) {
+	table, err := parseTableHeaderName(line)
+	if err != nil {
+		return tomlTable{}, err
+	}
+
+	exists := false
+	switch table.kind {
+	case "profile":
+		_, exists = file.Profiles[table.name]
+		file.Profiles[table.name] = Profile{Name: table.name}
+	case "query":
+		_, exists = file.Queries[table.name]
+		file.Queries[table.name] = SavedQuery{Name: table.name}
+	}
+	if exists {
+		return tomlTable{}, fmt.Errorf("%s %q defined twice", table.kind, table.name)
+	}
+
+	return table, nil
+}
+
+func parseTableKey(line string, current tomlTable, file *ProfileFile) error {
+	key, rawValue, ok := strings.Cut(line, "=")
+	if !ok {
+		return fmt.Errorf("expected key = value, got %q", line)
+	}
+	key = strings.TrimSpace(key)
+	if !tomlBareKey.MatchString(key) {
+		return fmt.Errorf("expected a bare key (quoted and dotted keys are not supported), got %q", key)
+	}
+	if current.takesArray(key) {
+		return setArray(key, strings.TrimSpace(rawValue), current, file)
+	}
+	value, err := parseTOMLString(strings.TrimSpace(rawValue))
+	if err != nil {
+		return fmt.Errorf("%s: %w", key, err)
+	}
+
+	switch current.kind {
+	case "profile":
+		profile := file.Profiles[current.name]
+		err = setProfileField(&profile, key, value)
+		file.Profiles[current.name] = profile
+	case "query":
+		query := file.Queries[current.name]
+		err = setQueryField(&query, key, value)
+		file.Queries[current.name] = query
+	case "jira":
+		err = setJiraField(&file.Jira, key, value)
+	case "jira field":
+		err = setJiraFieldTemplate(&file.Jira, key, value)
+	default:
+		if key != "default_profile" {
+			return fmt.Errorf("unknown top-level key %q", key)
+		}
+		file.DefaultProfile = value
+	}
+
+	return err
+}
+
+const inAppKey = "in_app"
+
+// takesArray reports whether key holds an array of strings: in_app at the
+// top level or in a profile, and every [redact] key.
+func (t tomlTable) takesArray(key string) bool {
+	switch t.kind {
+	case "", "profile":
+		return key == inAppKey
+	case "redact":
+		return true
+	default:
+		return false
+	}
+}
+
+// setArray sets the top-level in_app patterns, a profile's, or a [redact]
+// key.
+func setArray(key string, rawValue string, current tomlTable, file *ProfileFile) error {
+	values, err := parseTOMLStringArray(rawValue)
+	if err != nil {
+		return fmt.Errorf("%s: %w", key, err)
+	}
+
+	switch current.kind {
+	case "redact":
+		return setRedactField(&file.Redact, key, values)
+	case "profile":
+		profile := file.Profiles[current.name]
+		profile.InApp = values
+		file.Profiles[current.name] = profile
+	default:
+		file.InApp = values
+	}
+
+	return nil
+}
+
+func setProfileField(profile *Profile, key string, value string) error {
+	fields := map[string]*string{
+		"token":             &profile.Token,
+		"account_token":     &profile.AccountToken,
+		"account":           &profile.Account,
+		"project":           &profile.Project,
+		"base_url":          &profile.BaseURL,
+		"environment":       &profile.Environment,
+		"format":            &profile.Format,
+		"github_token":      &profile.GitHubToken,
+		"github_url":        &profile.GitHubURL,
+		"slack_webhook_url": &profile.SlackWebhookURL,
+		"slack_token":       &profile.SlackToken,
+		"slack_channel":     &profile.SlackChannel,
+	}
+	field, ok := fields[key]
+	if !ok {
+		return fmt.Errorf("unknown profile key %q", key)
+	}
+	*field = value
+
+	return nil
+}
+
+func parseTOMLKey(value string, kind string) (string, error) {
+	if strings.HasPrefix(value, `"`) || strings.HasPrefix(value, "'") {
+		return parseTOMLString(value)
+	}
+	if value == "" || strings.ContainsAny(value, ". \t") {
+		return "", fmt.Errorf("invalid %s name %q (quote names containing dots or spaces)", kind, value)
+	}
+
+	return value, nil
+}
+
+func parseTOMLString(value string) (string, error) {
+	switch {
+	case strings.HasPrefix(value, `"""`) || strings.HasPrefix(value, "'''"):
+		return "", fmt.Errorf("multi-line strings are not supported, got %s", value)
+	case strings.HasPrefix(value, "{"):
+		return "", fmt.Errorf("inline tables are not supported, got %s", value)
+	case len(value) < 2 || value[0] != value[len(value)-1]:
+		return "", fmt.Errorf("expected a quoted string, got %q", value)
+	case value[0] == '\'':
+		// A literal string has no escapes, so it cannot hold its own quote.
+		if literal := value[1 : len(value)-1]; !strings.Contains(literal, "'") {
+			return literal, nil
+		}
+		return "", fmt.Errorf("invalid string %s", value)
+	case value[0] == '"':
+		unquoted, err := strconv.Unquote(value)
+		if err != nil {
+			return "", fmt.Errorf("invalid string %s", value)
+		}
+		return unquoted, nil
+	}
+
+	return "", fmt.Errorf("expected a quoted string, got %q", value)
+}
+
+// parseTOMLStringArray reads a one-line array of strings, such as
+// ["a", 'b',], into a non-nil slice.
+func parseTOMLStringArray(value string) ([]string, error) {
+	inner, ok := strings.CutPrefix(value, "[")
+	if !ok || !strings.HasSuffix(inner, "]") {
+		return nil, fmt.Errorf("expected an array of strings on one line, got %q", value)
+	}
+	inner = strings.TrimSpace(strings.TrimSuffix(inner, "]"))
+
+	values := []string{}
+	for inner != "" {
+		end := quotedLength(inner)
+		item, err := parseTOMLString(inner[:end])
+		if err != nil {
+			return nil, err
+		}
+		values = append(values, item)
+
+		rest, separated := strings.CutPrefix(strings.TrimSpace(inner[end:]), ",")
+		if !separated && strings.TrimSpace(rest) != "" {
+			return nil, fmt.Errorf("expected , between array items, got %q", rest)
+		}
+		inner = strings.TrimSpace(rest)
+	}
+
+	return values, nil
+}
+
+// quotedLength is the length of the quoted string value starts with, or all
+// of value when it does not start with a closed one.
+func quotedLength(value string) int {
+	if value[0] != '"' && value[0] != '\'' {
+		return len(value)
+	}
+	quote, escaped := value[0], false
+	for index := 1; index < len(value); index++ {
+		if quote, escaped = advanceQuoted(quote, escaped, value[index]); quote == 0 {
+			return index + 1
+		}
+	}
+
+	return len(value)
+}
+
+// stripComment drops a trailing # comment that is not inside a string.
+func stripComment(line string) string {
+	var quote byte
+	escaped := false
+	for index := 0; index < len(line); index++ {
+		char := line[index]
+		if quote != 0 {
+			quote, escaped = advanceQuoted(quote, escaped, char)
+			continue
+		}
+		switch char {
+		case '"', '\'':
+			quote = char
+		case '#':
+			return line[:index]
+		}
+	}
+
+	return line
+}
+
+// advanceQuoted steps through a quoted string, returning the quote still open
+// (zero once it closes) and whether the next byte is escaped.
+func advanceQuoted(quote byte, escaped bool, char byte) (byte, bool) {
+	switch {
+	case escaped:
+		return quote, false
+	case char == '\\' && quote == '"':
+		return quote, true
+	case char == quote:
+		return 0, false
+	default:
+		return quote, false
+	}
+}
@@ -0,0 +1,195 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+const sampleProfiles = `# rollbaz profiles
+default_profile = "work"
+in_app = ["src/**", "!src/vendor/**"] # application code
+
+[profiles.work]
+token = "tok-work" # trailing comment
+project = "billing-api"
+base_url = "https://rollbar.example.com/api/1"
+environment = "production"
+format = 'json'
+github_token = "gh-work"
+slack_channel = "#alerts"
+
+[profiles."side.project"]
+token = "tok-#side"
+in_app = [ 'lib/**', "app/#main", ]
+`
+
+func TestParseProfiles(t *testing.T) {
+	t.Parallel()
+
+	file, err := ParseProfiles([]byte(sampleProfiles))
+	if err != nil {
+		t.Fatalf("ParseProfiles() error = %v", err)
+	}
+
+	want := Profile{Name: "work", Token: "tok-work", Project: "billing-api", BaseURL: "https://rollbar.example.com/api/1", Environment: "production", Format: "json", GitHubToken: "gh-work", SlackChannel: "#alerts"}
+	if file.DefaultProfile != "work" || !reflect.DeepEqual(file.Profiles["work"], want) {
+		t.Fatalf("unexpected work profile: %+v", file)
+	}
+	if file.Profiles["side.project"].Token != "tok-#side" {
+		t.Fatalf("unexpected quoted profile: %+v", file.Profiles["side.project"])
+	}
+	if !reflect.DeepEqual(file.InApp, []string{"src/**", "!src/vendor/**"}) || !reflect.DeepEqual(file.Profiles["side.project"].InApp, []string{"lib/**", "app/#main"}) {
+		t.Fatalf("unexpected in_app: %q, %q", file.InApp, file.Profiles["side.project"].InApp)
+	}
+}
+
+func TestParseProfilesErrors(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		input   string
+		wantErr string
+	}{
+		{input: "[profiles.a]\ncolour = \"red\"", wantErr: `line 2: unknown profile key "colour"`},
+		{input: "token = \"x\"", wantErr: `unknown top-level key "token"`},
+		{input: "[servers.a]", wantErr: "unsupported table"},
+		{input: "[profiles.a]\n[profiles.a]", wantErr: "defined twice"},
+		{input: "[profiles.a]\ntoken = x", wantErr: "expected a quoted string"},
+		{input: "[profiles.a]\ntoken", wantErr: "expected key = value"},
+		{input: "[profiles.a", wantErr: "unterminated"},
+		{input: "[profiles.a.b]", wantErr: "invalid profile name"},
+		{input: "in_app = \"src/**\"", wantErr: "in_app: expected an array of strings"},
+		{input: "in_app = [src]", wantErr: "expected a quoted string"},
+		{input: "in_app = [\"a\" \"b\"]", wantErr: "expected , between array items"},
+		{input: "[[profiles]]\nname = \"a\"", wantErr: "line 1: arrays of tables are not supported"},
+		{input: "[profiles.a]\ntoken.value = \"x\"", wantErr: `line 2: expected a bare key (quoted and dotted keys are not supported), got "token.value"`},
+		{input: "[jira.fields]\n\"customfield\" = \"x\"", wantErr: "line 2: expected a bare key"},
+		{input: "[profiles.a]\ntoken = \"\"\"\nx\n\"\"\"", wantErr: "line 2: token: multi-line strings are not supported"},
+		{input: "[profiles.a]\ntoken = '''x'''", wantErr: "line 2: token: multi-line strings are not supported"},
+		{input: "[profiles.a]\ntoken = 'a' 'b'", wantErr: "line 2: token: invalid string"},
+		{input: "[profiles.a]\ntoken = {value = \"x\"}", wantErr: "line 2: token: inline tables are not supported"},
+	}
+
+	for _, tc := range tests {
+		if _, err := ParseProfiles([]byte(tc.input)); err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+			t.Fatalf("ParseProfiles(%q) error = %v, want %q", tc.input, err, tc.wantErr)
+		}
+	}
+}
+
+func TestLoadMissingProfiles(t *testing.T) {
+	t.Parallel()
+
+	missing, err := LoadProfiles(filepath.Join(t.TempDir(), "missing.toml"))
+	if err != nil {
+		t.Fatalf("LoadProfiles(missing) error = %v", err)
+	}
+	if _, ok, err := missing.Select(""); ok || err != nil {
+		t.Fatalf("expected no profile from missing file, got ok=%v err=%v", ok, err)
+	}
+}
+
+func TestLoadAndSelectProfile(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "config.toml")
+	if err := os.WriteFile(path, []byte(sampleProfiles), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	file, err := LoadProfiles(path)
+	if err != nil {
+		t.Fatalf("LoadProfiles() error = %v", err)
+	}
+
+	tests := []struct {
+		name      string
+		wantName  string
+		wantError string
+	}{
+		{name: "", wantName: "work"},
+		{name: "side.project", wantName: "side.project"},
+		{name: "nope", wantError: `profile "nope" not found (known: side.project, work)`},
+	}
+	for _, tc := range tests {
+		profile, _, err := file.Select(tc.name)
+		gotError := ""
+		if err != nil {
+			gotError = err.Error()
+		}
+		if profile.Name != tc.wantName || gotError != tc.wantError {
+			t.Fatalf("Select(%q) = %+v, %v", tc.name, profile, err)
+		}
+	}
+}
+
+func TestParseSavedQueries(t *testing.T) {
+	t.Parallel()
+
+	file, err := ParseProfiles([]byte("[queries.prod-errors]\nstatus = \"active\"\nlevel = \"error,critical\"\nenv = \"production\"\n"))
+	if err != nil {
+		t.Fatalf("ParseProfiles() error = %v", err)
+	}
+	want := SavedQuery{Name: "prod-errors", Status: "active", Level: "error,critical", Environment: "production"}
+	if file.Queries["prod-errors"] != want {
+		t.Fatalf("unexpected query: %+v", file.Queries)
+	}
+
+	for input, wantErr := range map[string]string{
+		"[queries.a]\ncolour = \"red\"": `unknown query key "colour"`,
+		"[queries.a]\n[queries.a]":      `query "a" defined twice`,
+		"[queries.a.b]":                 "invalid query name",
+	} {
+		if _, err := ParseProfiles([]byte(input)); err == nil || !strings.Contains(err.Error(), wantErr) {
+			t.Fatalf("ParseProfiles(%q) error = %v, want %q", input, err, wantErr)
+		}
+	}
+}
+
+func TestParseJiraConfig(t *testing.T) {
+	t.Parallel()
+
+	file, err := ParseProfiles([]byte("[jira]\nurl = \"https://acme.atlassian.net\"\nemail = \"me@acme.com\"\nproject = \"ENG\"\nissue_type = \"Bug\"\n\n[jira.fields]\nlabels = \"rollbar,{environment}\"\ncustomfield_10042 = \"{link}\"\n"))
+	if err != nil {
+		t.Fatalf("ParseProfiles() error = %v", err)
+	}
+	jira := file.Jira
+	if jira.URL != "https://acme.atlassian.net" || jira.Email != "me@acme.com" || jira.Project != "ENG" || jira.IssueType != "Bug" {
+		t.Fatalf("unexpected jira config: %+v", jira)
+	}
+	if len(jira.Fields) != 2 || jira.Fields["labels"] != "rollbar,{environment}" || jira.Fields["customfield_10042"] != "{link}" {
+		t.Fatalf("unexpected jira fields: %+v", jira.Fields)
+	}
+}
+
+func TestParseJiraConfigUnknownKey(t *testing.T) {
+	t.Parallel()
+
+	if _, err := ParseProfiles([]byte("[jira]\nboard = \"x\"")); err == nil || !strings.Contains(err.Error(), `unknown jira key "board"`) {
+		t.Fatalf("expected unknown jira key error, got %v", err)
+	}
+}
+
+func TestParseRedactConfig(t *testing.T) {
+	t.Parallel()
+
+	file, err := ParseProfiles([]byte("[redact]\nfields = [\"address\", \"customer_name\"]\npatterns = ['(order=)\\d+', 'ACME-\\d{6}']\n"))
+	if err != nil {
+		t.Fatalf("ParseProfiles() error = %v", err)
+	}
+	want := RedactConfig{Fields: []string{"address", "customer_name"}, Patterns: []string{`(order=)\d+`, `ACME-\d{6}`}}
+	if !reflect.DeepEqual(file.Redact, want) {
+		t.Fatalf("unexpected redact config: %+v", file.Redact)
+	}
+
+	for input, wantErr := range map[string]string{
+		"[redact]\nkeys = []":          `unknown redact key "keys"`,
+		"[redact]\nfields = \"email\"": "fields: expected an array of strings",
+	} {
+		if _, err := ParseProfiles([]byte(input)); err == nil || !strings.Contains(err.Error(), wantErr) {
+			t.Fatalf("ParseProfiles(%q) error = %v, want %q", input, err, wantErr)
+		}
+	}
+}
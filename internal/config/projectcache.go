@@ -0,0 +1,110 @@
+package config
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"time"
+)
+
+const projectCacheTTL = 24 * time.Hour
+
+// ProjectCache maps project slugs to Rollbar project ids in the user cache
+// directory. It never holds tokens; entries expire after a day so renamed
+// projects resolve again.
+type ProjectCache struct {
+	path string
+	now  func() time.Time
+}
+
+type projectCacheEntry struct {
+	ID       uint64 `json:"id"`
+	CachedAt int64  `json:"cached_at"`
+}
+
+func NewProjectCache() (*ProjectCache, error) {
+	cacheRoot, err := os.UserCacheDir()
+	if err != nil {
+		return nil, fmt.Errorf("resolve cache dir: %w", err)
+	}
+
+	return NewProjectCacheAtPath(filepath.Join(cacheRoot, "rollbaz", "projects.json")), nil
+}
+
+func NewProjectCacheAtPath(path string) *ProjectCache {
+	return &ProjectCache{path: path, now: time.Now}
+}
+
+// Lookup returns the cached id for slug, ignoring expired entries and any
+// read or decode failure.
+func (c *ProjectCache) Lookup(slug string) (uint64, bool) {
+	entries, err := c.load()
+	if err != nil {
+		return 0, false
+	}
+	entry, ok := entries[slug]
+	if !ok || c.now().Sub(time.Unix(entry.CachedAt, 0)) > projectCacheTTL {
+		return 0, false
+	}
+
+	return entry.ID, true
+}
+
+// Slugs lists the unexpired cached slugs in sorted order.
+func (c *ProjectCache) Slugs() []string {
+	entries, err := c.load()
+	if err != nil {
+		return nil
+	}
+
+	slugs := make([]string, 0, len(entries))
+	for slug, entry := range entries {
+		if c.now().Sub(time.Unix(entry.CachedAt, 0)) <= projectCacheTTL {
+			slugs = append(slugs, slug)
+		}
+	}
+	sort.Strings(slugs)
+
+	return slugs
+}
+
+func (c *ProjectCache) Store(slug string, projectID uint64) error {
+	entries, err := c.load()
+	if err != nil {
+		entries = map[string]projectCacheEntry{}
+	}
+	entries[slug] = projectCacheEntry{ID: projectID, CachedAt: c.now().Unix()}
+
+	if err := os.MkdirAll(filepath.Dir(c.path), 0o700); err != nil {
+		return fmt.Errorf("create cache directory: %w", err)
+	}
+	body, err := json.MarshalIndent(entries, "", "  ")
+	if err != nil {
+		return fmt.Errorf("encode project cache: %w", err)
+	}
+	if err := os.WriteFile(c.path, append(body, '\n'), 0o600); err != nil {
+		return fmt.Errorf("write project cache: %w", err)
+	}
+
+	return nil
+}
+
+func (c *ProjectCache) load() (map[string]projectCacheEntry, error) {
+	body, err := os.ReadFile(c.path)
+	if errors.Is(err, os.ErrNotExist) {
+		return map[string]projectCacheEntry{}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("read project cache: %w", err)
+	}
+
+	entries := map[string]projectCacheEntry{}
+	if err := json.Unmarshal(body, &entries); err != nil {
+		return nil, fmt.Errorf("decode project cache: %w", err)
+	}
+
+	return entries, nil
+}
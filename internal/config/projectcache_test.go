@@ -0,0 +1,53 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func TestProjectCache(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "cache", "projects.json")
+	cache := NewProjectCacheAtPath(path)
+	now := time.Unix(1_000_000, 0)
+	cache.now = func() time.Time { return now }
+
+	if _, ok := cache.Lookup("billing-api"); ok {
+		t.Fatal("expected empty cache miss")
+	}
+	if err := cache.Store("billing-api", 42); err != nil {
+		t.Fatalf("Store() error = %v", err)
+	}
+	if id, ok := cache.Lookup("billing-api"); !ok || id != 42 {
+		t.Fatalf("Lookup() = %d, %v", id, ok)
+	}
+
+	info, err := os.Stat(path)
+	if err != nil || info.Mode().Perm() != 0o600 {
+		t.Fatalf("unexpected cache file: %v %v", info, err)
+	}
+
+	now = now.Add(25 * time.Hour)
+	if _, ok := cache.Lookup("billing-api"); ok {
+		t.Fatal("expected expired entry to miss")
+	}
+}
+
+func TestProjectCacheCorruptFile(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "projects.json")
+	cache := NewProjectCacheAtPath(path)
+	if err := os.WriteFile(path, []byte("{"), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	if _, ok := cache.Lookup("billing-api"); ok {
+		t.Fatal("expected corrupt cache to miss")
+	}
+	if err := cache.Store("billing-api", 7); err != nil {
+		t.Fatalf("Store() over corrupt cache error = %v", err)
+	}
+}
@@ -0,0 +1,165 @@
+package config
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+)
+
+// SavedQuery is a named set of list filters from a [queries.<name>] table in
+// config.toml. Level may list several levels separated by commas.
+type SavedQuery struct {
+	Name        string `json:"name"`
+	Status      string `json:"status,omitempty"`
+	Level       string `json:"level,omitempty"`
+	Environment string `json:"env,omitempty"`
+	Query       string `json:"query,omitempty"`
+	Since       string `json:"since,omitempty"`
+	Sort        string `json:"sort,omitempty"`
+}
+
+// QueryField is one key = value line of a saved query.
+type QueryField struct {
+	Key   string
+	Value string
+}
+
+// Fields returns the query's non-empty keys in the order they are written.
+func (q SavedQuery) Fields() []QueryField {
+	all := []QueryField{
+		{Key: "status", Value: q.Status},
+		{Key: "level", Value: q.Level},
+		{Key: "env", Value: q.Environment},
+		{Key: "query", Value: q.Query},
+		{Key: "since", Value: q.Since},
+		{Key: "sort", Value: q.Sort},
+	}
+	fields := make([]QueryField, 0, len(all))
+	for _, field := range all {
+		if field.Value != "" {
+			fields = append(fields, field)
+		}
+	}
+
+	return fields
+}
+
+func setQueryField(query *SavedQuery, key string, value string) error {
+	fields := map[string]*string{
+		"status":      &query.Status,
+		"level":       &query.Level,
+		"env":         &query.Environment,
+		"environment": &query.Environment,
+		"query":       &query.Query,
+		"since":       &query.Since,
+		"sort":        &query.Sort,
+	}
+	field, ok := fields[key]
+	if !ok {
+		return fmt.Errorf("unknown query key %q", key)
+	}
+	*field = value
+
+	return nil
+}
+
+// SaveQuery adds or replaces the [queries.<name>] table in the config.toml at
+// path, leaving the rest of the file as written.
+func SaveQuery(path string, query SavedQuery) error {
+	if strings.TrimSpace(query.Name) == "" {
+		return errors.New("query name is required")
+	}
+	if len(query.Fields()) == 0 {
+		return errors.New("query needs at least one filter")
+	}
+
+	lines, _, err := readTOMLWithoutQuery(path, query.Name)
+	if err != nil {
+		return err
+	}
+	if len(lines) > 0 {
+		lines = append(lines, "")
+	}
+	lines = append(lines, "[queries."+tomlTableName(query.Name)+"]")
+	for _, field := range query.Fields() {
+		lines = append(lines, field.Key+" = "+strconv.Quote(field.Value))
+	}
+
+	return writeTOML(path, lines)
+}
+
+// RemoveQuery deletes the [queries.<name>] table from the config.toml at path.
+func RemoveQuery(path string, name string) error {
+	lines, found, err := readTOMLWithoutQuery(path, name)
+	if err != nil {
+		return err
+	}
+	if !found {
+		return fmt.Errorf("query %q not found", name)
+	}
+
+	return writeTOML(path, lines)
+}
+
+// readTOMLWithoutQuery returns the file's lines minus the named query table,
+// refusing files that do not parse so a bad edit never compounds.
+func readTOMLWithoutQuery(path string, name string) ([]string, bool, error) {
+	body, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) {
+		return nil, false, nil
+	}
+	if err != nil {
+		return nil, false, fmt.Errorf("read profiles: %w", err)
+	}
+	if _, err := ParseProfiles(body); err != nil {
+		return nil, false, fmt.Errorf("parse %s: %w", path, err)
+	}
+
+	kept, found := withoutQueryTable(strings.Split(strings.TrimRight(string(body), "\n"), "\n"), name)
+
+	return kept, found, nil
+}
+
+// withoutQueryTable drops the [query.<name>] table from lines, along with the
+// blank lines it leaves at the end.
+func withoutQueryTable(lines []string, name string) ([]string, bool) {
+	kept := make([]string, 0, len(lines))
+	found, skipping := false, false
+	for _, line := range lines {
+		if trimmed := strings.TrimSpace(stripComment(line)); strings.HasPrefix(trimmed, "[") {
+			table, _ := parseTableHeaderName(trimmed)
+			skipping = table.kind == "query" && table.name == name
+			found = found || skipping
+		}
+		if !skipping {
+			kept = append(kept, line)
+		}
+	}
+	for len(kept) > 0 && strings.TrimSpace(kept[len(kept)-1]) == "" {
+		kept = kept[:len(kept)-1]
+	}
+
+	return kept, found
+}
+
+func writeTOML(path string, lines []string) error {
+	if err := os.MkdirAll(filepath.Dir(path), 0o700); err != nil {
+		return fmt.Errorf("create config directory: %w", err)
+	}
+	if err := os.WriteFile(path, []byte(strings.Join(lines, "\n")+"\n"), 0o600); err != nil {
+		return fmt.Errorf("write profiles: %w", err)
+	}
+
+	return nil
+}
+
+func tomlTableName(name string) string {
+	if strings.ContainsAny(name, ". \t\"'") {
+		return strconv.Quote(name)
+	}
+
+	return name
+}
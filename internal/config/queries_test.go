@@ -0,0 +1,80 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestSaveQuery(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "rollbaz", "config.toml")
+	if err := SaveQuery(path, SavedQuery{Name: "prod errors", Status: "active", Environment: "production"}); err != nil {
+		t.Fatalf("SaveQuery(new file) error = %v", err)
+	}
+	if err := os.WriteFile(path, []byte("# mine\ndefault_profile = \"work\"\n\n[queries.\"prod errors\"]\nstatus = \"active\"\n\n[profiles.work]\ntoken = \"tok\"\n"), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+
+	if err := SaveQuery(path, SavedQuery{Name: "prod errors", Level: "error"}); err != nil {
+		t.Fatalf("SaveQuery(replace) error = %v", err)
+	}
+	if err := SaveQuery(path, SavedQuery{Name: "recent", Since: "24h", Sort: "occurrences"}); err != nil {
+		t.Fatalf("SaveQuery(add) error = %v", err)
+	}
+	file, err := LoadProfiles(path)
+	if err != nil {
+		t.Fatalf("LoadProfiles() error = %v", err)
+	}
+	if file.Queries["prod errors"] != (SavedQuery{Name: "prod errors", Level: "error"}) || file.Queries["recent"].Since != "24h" || file.Profiles["work"].Token != "tok" {
+		t.Fatalf("unexpected file after saves: %+v", file)
+	}
+}
+
+func TestRemoveQuery(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "config.toml")
+	if err := os.WriteFile(path, []byte("# mine\n[queries.\"prod errors\"]\nlevel = \"error\"\n\n[queries.recent]\nsince = \"24h\"\n"), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+
+	if err := RemoveQuery(path, "prod errors"); err != nil {
+		t.Fatalf("RemoveQuery() error = %v", err)
+	}
+	body, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("ReadFile() error = %v", err)
+	}
+	if strings.Contains(string(body), "prod errors") || !strings.HasPrefix(string(body), "# mine\n") || !strings.Contains(string(body), "[queries.recent]") {
+		t.Fatalf("unexpected file after remove:\n%s", body)
+	}
+}
+
+func TestSaveQueryErrors(t *testing.T) {
+	t.Parallel()
+
+	dir := t.TempDir()
+	broken := filepath.Join(dir, "broken.toml")
+	if err := os.WriteFile(broken, []byte("[servers.a]\n"), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+
+	tests := []struct {
+		name    string
+		run     func() error
+		wantErr string
+	}{
+		{name: "no name", run: func() error { return SaveQuery(filepath.Join(dir, "a.toml"), SavedQuery{Status: "active"}) }, wantErr: "name is required"},
+		{name: "no filters", run: func() error { return SaveQuery(filepath.Join(dir, "a.toml"), SavedQuery{Name: "x"}) }, wantErr: "at least one filter"},
+		{name: "broken file", run: func() error { return SaveQuery(broken, SavedQuery{Name: "x", Status: "active"}) }, wantErr: "unsupported table"},
+		{name: "missing query", run: func() error { return RemoveQuery(filepath.Join(dir, "a.toml"), "x") }, wantErr: `query "x" not found`},
+	}
+	for _, tc := range tests {
+		if err := tc.run(); err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+			t.Fatalf("%s: expected %q error, got %v", tc.name, tc.wantErr, err)
+		}
+	}
+}
@@ -0,0 +1,31 @@
+package config
+
+import "fmt"
+
+// RedactConfig is the [redact] table of config.toml, rules --redact applies
+// on top of its built-in personal data rules:
+//
+//	[redact]
+//	fields = ["address", "customer_name"]
+//	patterns = ['(order=)\d+', 'ACME-\d{6}']
+type RedactConfig struct {
+	// Fields are object keys whose whole value is redacted, matched
+	// case-insensitively anywhere in the key.
+	Fields []string
+	// Patterns are regular expressions redacted from string values; a first
+	// capture group that prefixes the match is kept.
+	Patterns []string
+}
+
+func setRedactField(rules *RedactConfig, key string, values []string) error {
+	switch key {
+	case "fields":
+		rules.Fields = values
+	case "patterns":
+		rules.Patterns = values
+	default:
+		return fmt.Errorf("unknown redact key %q", key)
+	}
+
+	return nil
+}
@@ -13,6 +13,9 @@ import (
 type Project struct {
 	Name  string `json:"name"`
 	Token string `json:"token"`
+	// Keyring marks a project whose token lives in the system keyring rather
+	// than in this file.
+	Keyring bool `json:"keyring,omitempty"`
 }
 
 type File struct {
@@ -84,22 +87,32 @@ func (s *Store) AddProject(name string, token string) error {
 		return errors.New("project token is required")
 	}
 
+	return s.upsertProject(Project{Name: name, Token: token})
+}
+
+// AddKeyringProject records a project whose token is kept in the system
+// keyring, dropping any token previously stored in the file.
+func (s *Store) AddKeyringProject(name string) error {
+	if strings.TrimSpace(name) == "" {
+		return errors.New("project name is required")
+	}
+
+	return s.upsertProject(Project{Name: name, Keyring: true})
+}
+
+func (s *Store) upsertProject(project Project) error {
 	file, err := s.Load()
 	if err != nil {
 		return err
 	}
 
-	if index, ok := projectIndexByName(file.Projects, name); ok {
-		file.Projects[index].Token = token
-		if file.ActiveProject == "" {
-			file.ActiveProject = name
-		}
-		return s.Save(file)
+	if index, ok := projectIndexByName(file.Projects, project.Name); ok {
+		file.Projects[index] = project
+	} else {
+		file.Projects = append(file.Projects, project)
 	}
-
-	file.Projects = append(file.Projects, Project{Name: name, Token: token})
 	if file.ActiveProject == "" {
-		file.ActiveProject = name
+		file.ActiveProject = project.Name
 	}
 
 	return s.Save(file)
@@ -182,13 +195,27 @@ func (s *Store) CycleProject() (string, error) {
 }
 
 func (s *Store) ResolveToken(projectName string) (string, string, error) {
-	file, err := s.Load()
+	project, err := s.ResolveProject(projectName)
 	if err != nil {
 		return "", "", err
 	}
+	if project.Keyring {
+		return "", "", fmt.Errorf("project %q keeps its token in the system keyring", project.Name)
+	}
+
+	return project.Token, project.Name, nil
+}
+
+// ResolveProject returns the named project, or the active one when name is
+// empty. Projects without a token must be keyring-backed.
+func (s *Store) ResolveProject(projectName string) (Project, error) {
+	file, err := s.Load()
+	if err != nil {
+		return Project{}, err
+	}
 
 	if len(file.Projects) == 0 {
-		return "", "", errors.New("no configured projects")
+		return Project{}, errors.New("no configured projects")
 	}
 
 	target := projectName
@@ -196,18 +223,19 @@ func (s *Store) ResolveToken(projectName string) (string, string, error) {
 		target = file.ActiveProject
 	}
 	if target == "" {
-		return "", "", errors.New("no active project configured")
+		return Project{}, errors.New("no active project configured")
 	}
 
 	index, ok := projectIndexByName(file.Projects, target)
-	if ok {
-		if strings.TrimSpace(file.Projects[index].Token) == "" {
-			return "", "", fmt.Errorf("project %q has no token", target)
-		}
-		return file.Projects[index].Token, target, nil
+	if !ok {
+		return Project{}, fmt.Errorf("project %q not found", target)
+	}
+	project := file.Projects[index]
+	if !project.Keyring && strings.TrimSpace(project.Token) == "" {
+		return Project{}, fmt.Errorf("project %q has no token", target)
 	}
 
-	return "", "", fmt.Errorf("project %q not found", target)
+	return project, nil
 }
 
 func normalize(file File) File {
@@ -217,7 +245,7 @@ func normalize(file File) File {
 		if name == "" {
 			continue
 		}
-		trimmedProjects = append(trimmedProjects, Project{Name: name, Token: strings.TrimSpace(project.Token)})
+		trimmedProjects = append(trimmedProjects, Project{Name: name, Token: strings.TrimSpace(project.Token), Keyring: project.Keyring})
 	}
 	sort.Slice(trimmedProjects, func(i int, j int) bool {
 		return trimmedProjects[i].Name < trimmedProjects[j].Name
@@ -128,6 +128,29 @@ func TestStoreAddProjectUpdatesExisting(t *testing.T) {
 	}
 }
 
+func TestStoreKeyringProject(t *testing.T) {
+	t.Parallel()
+
+	store, _ := newTempStore(t)
+	if err := store.AddProject("work", "file-token"); err != nil {
+		t.Fatalf("AddProject() error = %v", err)
+	}
+	if err := store.AddKeyringProject("work"); err != nil {
+		t.Fatalf("AddKeyringProject() error = %v", err)
+	}
+
+	project, err := store.ResolveProject("")
+	if err != nil || !project.Keyring || project.Token != "" {
+		t.Fatalf("ResolveProject() = %+v, %v", project, err)
+	}
+	if _, _, err := store.ResolveToken("work"); err == nil {
+		t.Fatal("expected ResolveToken to refuse keyring-backed project")
+	}
+	if err := store.AddKeyringProject(" "); err == nil {
+		t.Fatal("expected empty name error")
+	}
+}
+
 func TestStoreLoadDecodeError(t *testing.T) {
 	t.Parallel()
 
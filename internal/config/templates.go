@@ -0,0 +1,59 @@
+package config
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+)
+
+// templateExtension marks the files in the templates directory that --template
+// can name.
+const templateExtension = ".tmpl"
+
+// TemplateDir is the templates directory next to the config.toml at
+// profilePath.
+func TemplateDir(profilePath string) string {
+	return filepath.Join(filepath.Dir(profilePath), "templates")
+}
+
+// TemplateNames lists the templates in dir by name, without the extension. A
+// missing directory has none.
+func TemplateNames(dir string) []string {
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		return nil
+	}
+
+	names := make([]string, 0, len(entries))
+	for _, entry := range entries {
+		if name, ok := strings.CutSuffix(entry.Name(), templateExtension); ok && !entry.IsDir() {
+			names = append(names, name)
+		}
+	}
+	slices.Sort(names)
+
+	return names
+}
+
+// LoadTemplate reads the template ref names: a file when ref is a path, that
+// is, has a directory or names an existing file, and otherwise the template
+// dir/<ref>.tmpl. It returns the template's name and text.
+func LoadTemplate(dir string, ref string) (string, string, error) {
+	path := filepath.Clean(ref)
+	if _, err := os.Stat(path); err != nil && !strings.ContainsRune(ref, filepath.Separator) && !strings.ContainsRune(ref, '/') {
+		path = filepath.Join(dir, strings.TrimSuffix(ref, templateExtension)+templateExtension)
+	}
+
+	body, err := os.ReadFile(path)
+	if errors.Is(err, os.ErrNotExist) && filepath.Dir(path) == dir {
+		return "", "", fmt.Errorf("no template %q in %s", ref, dir)
+	}
+	if err != nil {
+		return "", "", fmt.Errorf("read template: %w", err)
+	}
+
+	return strings.TrimSuffix(filepath.Base(path), templateExtension), string(body), nil
+}
@@ -0,0 +1,78 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+// writeTemplates writes two templates, a text file and a directory that
+// looks like a template into the template dir of a temporary config, and
+// returns the config's directory and the template dir.
+func writeTemplates(t *testing.T) (string, string) {
+	t.Helper()
+	root := t.TempDir()
+	dir := TemplateDir(filepath.Join(root, "config.toml"))
+	if err := os.MkdirAll(filepath.Join(dir, "nested.tmpl"), 0o700); err != nil {
+		t.Fatal(err)
+	}
+	for name, body := range map[string]string{"slack.tmpl": "{{.title}}", "brief.tmpl": "{{.id}}", "notes.txt": "x"} {
+		if err := os.WriteFile(filepath.Join(dir, name), []byte(body), 0o600); err != nil {
+			t.Fatal(err)
+		}
+	}
+	return root, dir
+}
+
+func TestTemplates(t *testing.T) {
+	t.Parallel()
+
+	root := t.TempDir()
+	dir := TemplateDir(filepath.Join(root, "config.toml"))
+	if dir != filepath.Join(root, "templates") {
+		t.Fatalf("TemplateDir() = %q", dir)
+	}
+	if names := TemplateNames(dir); names != nil {
+		t.Fatalf("TemplateNames() without dir = %v", names)
+	}
+
+	_, dir = writeTemplates(t)
+	if names := TemplateNames(dir); !reflect.DeepEqual(names, []string{"brief", "slack"}) {
+		t.Fatalf("TemplateNames() = %v", names)
+	}
+}
+
+func TestLoadTemplate(t *testing.T) {
+	t.Parallel()
+
+	_, dir := writeTemplates(t)
+	tests := []struct {
+		ref      string
+		wantName string
+		wantText string
+	}{
+		{ref: "slack", wantName: "slack", wantText: "{{.title}}"},
+		{ref: "brief.tmpl", wantName: "brief", wantText: "{{.id}}"},
+		{ref: filepath.Join(dir, "notes.txt"), wantName: "notes.txt", wantText: "x"},
+	}
+	for _, tc := range tests {
+		name, text, err := LoadTemplate(dir, tc.ref)
+		if err != nil || name != tc.wantName || text != tc.wantText {
+			t.Fatalf("LoadTemplate(%q) = %q, %q, %v", tc.ref, name, text, err)
+		}
+	}
+}
+
+func TestLoadTemplateErrors(t *testing.T) {
+	t.Parallel()
+
+	root, dir := writeTemplates(t)
+	if _, _, err := LoadTemplate(dir, "missing"); err == nil || !strings.Contains(err.Error(), `no template "missing"`) {
+		t.Fatalf("expected missing template error, got %v", err)
+	}
+	if _, _, err := LoadTemplate(dir, filepath.Join(root, "absent.tmpl")); err == nil || !strings.Contains(err.Error(), "read template") {
+		t.Fatalf("expected read error, got %v", err)
+	}
+}
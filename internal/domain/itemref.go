@@ -0,0 +1,72 @@
+package domain
+
+import (
+	"errors"
+	"fmt"
+	"net/url"
+	"strings"
+)
+
+// ItemRef is an item as written on the command line: a project counter
+// ("269" or "#269") or a Rollbar web URL, which also names the account and
+// project. Both the classic https://rollbar.com/acme/billing-api/items/269/
+// and the newer https://app.rollbar.com/a/acme/fix/item/billing-api/269 forms
+// are accepted.
+type ItemRef struct {
+	Counter ItemCounter
+	Account string
+	Project string
+}
+
+// ParseItemRef parses a counter or a Rollbar item URL. Anything after the
+// counter in a URL (occurrence paths, query, fragment) is ignored.
+func ParseItemRef(value string) (ItemRef, error) {
+	value = strings.TrimSpace(value)
+	if strings.HasPrefix(value, "http://") || strings.HasPrefix(value, "https://") {
+		return parseItemURL(value)
+	}
+
+	counter, err := ParseItemCounter(strings.TrimPrefix(value, "#"))
+	if err != nil {
+		return ItemRef{}, err
+	}
+	if counter == 0 {
+		return ItemRef{}, errors.New("item counter must be greater than 0")
+	}
+
+	return ItemRef{Counter: counter}, nil
+}
+
+func parseItemURL(value string) (ItemRef, error) {
+	parsed, err := url.Parse(value)
+	if err != nil {
+		return ItemRef{}, fmt.Errorf("parse item url: %w", err)
+	}
+
+	account, project, counter, ok := itemURLParts(strings.Split(strings.Trim(parsed.Path, "/"), "/"))
+	if !ok {
+		return ItemRef{}, fmt.Errorf("not a Rollbar item URL (want https://rollbar.com/<account>/<project>/items/<counter>/): %s", value)
+	}
+
+	ref, err := ParseItemRef(counter)
+	if err != nil {
+		return ItemRef{}, fmt.Errorf("item url %s: %w", value, err)
+	}
+	ref.Account = account
+	ref.Project = project
+
+	return ref, nil
+}
+
+// itemURLParts matches <account>/<project>/items/<counter> and
+// a/<account>/fix/item/<project>/<counter>, ignoring trailing segments.
+func itemURLParts(segments []string) (string, string, string, bool) {
+	if len(segments) >= 6 && segments[0] == "a" && segments[2] == "fix" && segments[3] == "item" {
+		return segments[1], segments[4], segments[5], segments[1] != "" && segments[4] != ""
+	}
+	if len(segments) >= 4 && segments[2] == "items" {
+		return segments[0], segments[1], segments[3], segments[0] != "" && segments[1] != ""
+	}
+
+	return "", "", "", false
+}
@@ -0,0 +1,40 @@
+package domain
+
+import (
+	"strings"
+	"testing"
+)
+
+func TestParseItemRef(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		input   string
+		want    ItemRef
+		wantErr string
+	}{
+		{input: "269", want: ItemRef{Counter: 269}},
+		{input: " #269 ", want: ItemRef{Counter: 269}},
+		{input: "https://rollbar.com/acme/billing-api/items/12345/", want: ItemRef{Counter: 12345, Account: "acme", Project: "billing-api"}},
+		{input: "https://app.rollbar.com/a/acme/fix/item/billing-api/7", want: ItemRef{Counter: 7, Account: "acme", Project: "billing-api"}},
+		{input: "https://app.rollbar.com/a/acme/fix/items", wantErr: "not a Rollbar item URL"},
+		{input: "https://rollbar.com/acme/web/items/42/occurrences/99/?item_page=0#trace", want: ItemRef{Counter: 42, Account: "acme", Project: "web"}},
+		{input: "https://rollbar.com/acme/web/deploys/", wantErr: "not a Rollbar item URL"},
+		{input: "https://rollbar.com/acme/web/items/abc/", wantErr: "parse item counter"},
+		{input: "0", wantErr: "greater than 0"},
+		{input: "abc", wantErr: "parse item counter"},
+	}
+
+	for _, tc := range tests {
+		got, err := ParseItemRef(tc.input)
+		if tc.wantErr != "" {
+			if err == nil || !strings.Contains(err.Error(), tc.wantErr) {
+				t.Fatalf("ParseItemRef(%q) error = %v, want %q", tc.input, err, tc.wantErr)
+			}
+			continue
+		}
+		if err != nil || got != tc.want {
+			t.Fatalf("ParseItemRef(%q) = %+v, %v; want %+v", tc.input, got, err, tc.want)
+		}
+	}
+}
@@ -25,3 +25,9 @@ type ItemID uint64
 func (id ItemID) String() string {
 	return strconv.FormatUint(uint64(id), 10)
 }
+
+type InstanceID uint64
+
+func (id InstanceID) String() string {
+	return strconv.FormatUint(uint64(id), 10)
+}
@@ -33,4 +33,8 @@ func TestStringers(t *testing.T) {
 	if ItemID(99).String() != "99" {
 		t.Fatalf("unexpected item id string")
 	}
+
+	if InstanceID(7).String() != "7" {
+		t.Fatalf("unexpected instance id string")
+	}
 }
@@ -0,0 +1,134 @@
+// Package github is a minimal GitHub REST client for filing issues from
+// Rollbar items.
+package github
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// DefaultBaseURL is github.com's API; GitHub Enterprise Server uses
+// https://<host>/api/v3.
+const DefaultBaseURL = "https://api.github.com"
+
+const (
+	requestTimeout   = 15 * time.Second
+	maxResponseBytes = 1 << 20
+)
+
+type Client struct {
+	http    *http.Client
+	baseURL string
+	token   string
+}
+
+// NewClient builds a client for token; an empty baseURL uses DefaultBaseURL.
+func NewClient(token string, baseURL string) (*Client, error) {
+	if strings.TrimSpace(token) == "" {
+		return nil, errors.New("github token is required")
+	}
+	if baseURL == "" {
+		baseURL = DefaultBaseURL
+	}
+
+	return &Client{
+		http:    &http.Client{Timeout: requestTimeout},
+		baseURL: strings.TrimRight(baseURL, "/"),
+		token:   token,
+	}, nil
+}
+
+// NewIssue is the body of a create-issue request.
+type NewIssue struct {
+	Title  string   `json:"title"`
+	Body   string   `json:"body"`
+	Labels []string `json:"labels,omitempty"`
+}
+
+type Issue struct {
+	Number  int    `json:"number"`
+	HTMLURL string `json:"html_url"`
+}
+
+// ParseRepo splits "owner/name" and rejects anything else.
+func ParseRepo(value string) (string, string, error) {
+	owner, name, ok := strings.Cut(strings.TrimSpace(value), "/")
+	if !ok || owner == "" || name == "" || strings.Contains(name, "/") {
+		return "", "", fmt.Errorf("invalid repository %q (use owner/name)", value)
+	}
+
+	return owner, name, nil
+}
+
+// CreateIssue opens an issue in repo, given as "owner/name".
+func (c *Client) CreateIssue(ctx context.Context, repo string, issue NewIssue) (Issue, error) {
+	owner, name, err := ParseRepo(repo)
+	if err != nil {
+		return Issue{}, err
+	}
+
+	payload, err := json.Marshal(issue)
+	if err != nil {
+		return Issue{}, fmt.Errorf("encode github issue: %w", err)
+	}
+
+	endpoint := c.baseURL + "/repos/" + url.PathEscape(owner) + "/" + url.PathEscape(name) + "/issues"
+	request, err := http.NewRequestWithContext(ctx, http.MethodPost, endpoint, bytes.NewReader(payload))
+	if err != nil {
+		return Issue{}, fmt.Errorf("build github request: %w", err)
+	}
+	request.Header.Set("Accept", "application/vnd.github+json")
+	request.Header.Set("Authorization", "Bearer "+c.token)
+	request.Header.Set("Content-Type", "application/json")
+	request.Header.Set("X-GitHub-Api-Version", "2022-11-28")
+
+	var created Issue
+	if err := c.do(request, &created); err != nil {
+		return Issue{}, err
+	}
+
+	return created, nil
+}
+
+func (c *Client) do(request *http.Request, target any) error {
+	response, err := c.http.Do(request)
+	if err != nil {
+		return fmt.Errorf("github request: %w", err)
+	}
+	defer func() { _ = response.Body.Close() }()
+
+	body, err := io.ReadAll(io.LimitReader(response.Body, maxResponseBytes))
+	if err != nil {
+		return fmt.Errorf("read github response: %w", err)
+	}
+	if response.StatusCode < 200 || response.StatusCode > 299 {
+		return statusError(response.StatusCode, body)
+	}
+	if err := json.Unmarshal(body, target); err != nil {
+		return fmt.Errorf("decode github response: %w", err)
+	}
+
+	return nil
+}
+
+// statusError reports GitHub's own message, which names the missing scope or
+// unknown repository, when the body has one.
+func statusError(status int, body []byte) error {
+	var payload struct {
+		Message string `json:"message"`
+	}
+	_ = json.Unmarshal(body, &payload)
+	if payload.Message == "" {
+		return fmt.Errorf("github returned status %d", status)
+	}
+
+	return fmt.Errorf("github returned status %d: %s", status, payload.Message)
+}
@@ -0,0 +1,97 @@
+package github
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+// issueHandler files issue 42 in acme/api after checking that the request
+// carries the gh-token token and the "boom" issue labelled bug.
+func issueHandler(t *testing.T) http.HandlerFunc {
+	t.Helper()
+
+	return func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/repos/acme/api/issues" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		if r.Header.Get("Authorization") != "Bearer gh-token" {
+			t.Fatalf("unexpected authorization header %q", r.Header.Get("Authorization"))
+		}
+		var issue NewIssue
+		if err := json.NewDecoder(r.Body).Decode(&issue); err != nil {
+			t.Fatalf("decode request: %v", err)
+		}
+		if issue.Title != "boom" || issue.Body != "body" || len(issue.Labels) != 1 || issue.Labels[0] != "bug" {
+			t.Fatalf("unexpected issue: %+v", issue)
+		}
+		w.WriteHeader(http.StatusCreated)
+		_, _ = fmt.Fprint(w, `{"number":42,"html_url":"https://github.com/acme/api/issues/42"}`)
+	}
+}
+
+func TestCreateIssue(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(issueHandler(t))
+	t.Cleanup(server.Close)
+
+	client, err := NewClient("gh-token", server.URL+"/")
+	if err != nil {
+		t.Fatalf("NewClient() error = %v", err)
+	}
+	issue, err := client.CreateIssue(context.Background(), "acme/api", NewIssue{Title: "boom", Body: "body", Labels: []string{"bug"}})
+	if err != nil {
+		t.Fatalf("CreateIssue() error = %v", err)
+	}
+	if issue.Number != 42 || issue.HTMLURL != "https://github.com/acme/api/issues/42" {
+		t.Fatalf("unexpected issue: %+v", issue)
+	}
+}
+
+func TestCreateIssueErrors(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if strings.HasPrefix(r.URL.Path, "/repos/acme/empty/") {
+			w.WriteHeader(http.StatusBadGateway)
+			return
+		}
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprint(w, `{"message":"Not Found"}`)
+	}))
+	t.Cleanup(server.Close)
+
+	client, err := NewClient("gh-token", server.URL)
+	if err != nil {
+		t.Fatalf("NewClient() error = %v", err)
+	}
+
+	tests := []struct {
+		repo string
+		want string
+	}{
+		{repo: "acme", want: "use owner/name"},
+		{repo: "acme/api/extra", want: "use owner/name"},
+		{repo: "acme/missing", want: "status 404: Not Found"},
+		{repo: "acme/empty", want: "status 502"},
+	}
+	for _, tc := range tests {
+		_, err := client.CreateIssue(context.Background(), tc.repo, NewIssue{Title: "boom"})
+		if err == nil || !strings.Contains(err.Error(), tc.want) || strings.Contains(err.Error(), "gh-token") {
+			t.Fatalf("%s: expected %q error, got %v", tc.repo, tc.want, err)
+		}
+	}
+}
+
+func TestNewClientRequiresToken(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewClient(" ", ""); err == nil {
+		t.Fatal("expected missing token error")
+	}
+}
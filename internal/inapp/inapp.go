@@ -0,0 +1,103 @@
+// Package inapp tells application stack frames from library and framework
+// frames using the in_app patterns in config.toml.
+package inapp
+
+import (
+	"fmt"
+	"regexp"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/codeowners"
+)
+
+// Rules are in_app patterns in config order. A nil *Rules has no patterns,
+// and callers fall back to their own notion of library code.
+type Rules struct {
+	patterns []pattern
+	// defaultInApp is what a file no pattern matches is: application code
+	// when every pattern is a negation, and otherwise library code.
+	defaultInApp bool
+}
+
+type pattern struct {
+	negated bool
+	match   *regexp.Regexp
+}
+
+// Parse compiles in_app patterns, which use CODEOWNERS syntax with a leading
+// "!" marking library code, as in ["src/**", "!src/vendor/**"]. It is nil
+// when there are none.
+func Parse(patterns []string) (*Rules, error) {
+	if len(patterns) == 0 {
+		return nil, nil
+	}
+
+	rules := &Rules{defaultInApp: true}
+	for _, text := range patterns {
+		glob, negated := strings.CutPrefix(strings.TrimSpace(text), "!")
+		if glob == "" || strings.ContainsAny(glob, "[]") {
+			return nil, fmt.Errorf("unsupported pattern %q", text)
+		}
+		if !negated {
+			rules.defaultInApp = false
+		}
+		rules.patterns = append(rules.patterns, pattern{negated: negated, match: codeowners.Compile(glob)})
+	}
+
+	return rules, nil
+}
+
+// InApp reports whether a frame's file is application code: the last pattern
+// matching it decides. Frames carry deployed paths such as
+// /srv/app/src/cart.py, so a pattern matches the file name or any of its
+// trailing paths, and src/** matches that file.
+func (r *Rules) InApp(filename string) bool {
+	if r == nil {
+		return true
+	}
+
+	paths := trailingPaths(filename)
+	for index := len(r.patterns) - 1; index >= 0; index-- {
+		for _, path := range paths {
+			if r.patterns[index].match.MatchString(path) {
+				return !r.patterns[index].negated
+			}
+		}
+	}
+
+	return r.defaultInApp
+}
+
+// Filter narrows a checkout lookup to application frames, so owner
+// suggestions and SARIF results skip vendored code in the checkout. Without
+// rules it is lookup itself.
+func (r *Rules) Filter(lookup func(filename string) (string, bool)) func(filename string) (string, bool) {
+	if r == nil {
+		return lookup
+	}
+
+	return func(filename string) (string, bool) {
+		if !r.InApp(filename) {
+			return "", false
+		}
+		return lookup(filename)
+	}
+}
+
+// trailingPaths is a slash-separated file name and each path after one of
+// its slashes: a/b/c.py, b/c.py, and c.py.
+func trailingPaths(filename string) []string {
+	path := strings.TrimPrefix(strings.ReplaceAll(strings.TrimSpace(filename), "\\", "/"), "./")
+	path = strings.TrimLeft(path, "/")
+	paths := []string{}
+	for path != "" {
+		paths = append(paths, path)
+		_, rest, ok := strings.Cut(path, "/")
+		if !ok {
+			break
+		}
+		path = strings.TrimLeft(rest, "/")
+	}
+
+	return paths
+}
@@ -0,0 +1,73 @@
+package inapp
+
+import "testing"
+
+func TestRulesInApp(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		patterns []string
+		filename string
+		want     bool
+	}{
+		{name: "matched", patterns: []string{"src/**", "!src/vendor/**"}, filename: "src/cart.py", want: true},
+		{name: "deployed path", patterns: []string{"src/**", "!src/vendor/**"}, filename: "/srv/app/src/cart.py", want: true},
+		{name: "negation wins", patterns: []string{"src/**", "!src/vendor/**"}, filename: "/srv/app/src/vendor/rack.rb"},
+		{name: "unmatched", patterns: []string{"src/**"}, filename: "/usr/lib/python3.12/json/decoder.py"},
+		{name: "later pattern wins", patterns: []string{"!vendor/**", "vendor/acme/**"}, filename: "vendor/acme/sdk.go", want: true},
+		{name: "only negations", patterns: []string{"!node_modules/"}, filename: "webpack:///./app/index.js", want: true},
+		{name: "only negations matched", patterns: []string{"!node_modules/"}, filename: "webpack:///./node_modules/react/index.js"},
+		{name: "windows", patterns: []string{"App/**"}, filename: `C:\build\App\Program.cs`, want: true},
+		{name: "extension", patterns: []string{"*.rb", "!gems/"}, filename: "/gems/rack-3/lib/rack.rb"},
+		{name: "empty", patterns: []string{"src/**"}, filename: ""},
+	}
+
+	for _, tc := range tests {
+		rules, err := Parse(tc.patterns)
+		if err != nil {
+			t.Fatalf("%s: Parse() error = %v", tc.name, err)
+		}
+		if got := rules.InApp(tc.filename); got != tc.want {
+			t.Fatalf("%s: InApp(%q) = %t, want %t", tc.name, tc.filename, got, tc.want)
+		}
+	}
+}
+
+func TestParseErrors(t *testing.T) {
+	t.Parallel()
+
+	for _, patterns := range [][]string{{"!"}, {" "}, {"src/[ab]/**"}} {
+		if _, err := Parse(patterns); err == nil {
+			t.Fatalf("Parse(%q) expected error", patterns)
+		}
+	}
+	rules, err := Parse(nil)
+	if err != nil || rules != nil {
+		t.Fatalf("Parse(nil) = %v, %v", rules, err)
+	}
+	if !rules.InApp("vendor/x.go") {
+		t.Fatal("expected nil rules to treat frames as in app")
+	}
+}
+
+func TestRulesFilter(t *testing.T) {
+	t.Parallel()
+
+	lookup := func(filename string) (string, bool) { return "repo/" + filename, true }
+	if path, ok := (*Rules)(nil).Filter(lookup)("vendor/x.go"); !ok || path != "repo/vendor/x.go" {
+		t.Fatalf("nil Filter = %q, %t", path, ok)
+	}
+
+	rules, err := Parse([]string{"!vendor/**"})
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+	filtered := rules.Filter(lookup)
+	if _, ok := filtered("vendor/x.go"); ok {
+		t.Fatal("expected vendor frame to be filtered out")
+	}
+	if path, ok := filtered("main.go"); !ok || path != "repo/main.go" {
+		t.Fatalf("Filter(main.go) = %q, %t", path, ok)
+	}
+}
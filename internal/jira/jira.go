@@ -0,0 +1,190 @@
+// Package jira is a minimal Jira REST client for filing issues from Rollbar
+// items.
+package jira
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"sort"
+	"strings"
+	"time"
+)
+
+const (
+	requestTimeout   = 15 * time.Second
+	maxResponseBytes = 1 << 20
+)
+
+// Client talks to Jira Cloud with an account email and API token, or to Jira
+// Server and Data Center with a personal access token when email is empty.
+type Client struct {
+	http    *http.Client
+	baseURL string
+	email   string
+	token   string
+}
+
+func NewClient(baseURL string, email string, token string) (*Client, error) {
+	switch {
+	case strings.TrimSpace(baseURL) == "":
+		return nil, errors.New("jira url is required")
+	case strings.TrimSpace(token) == "":
+		return nil, errors.New("jira token is required")
+	}
+
+	return &Client{
+		http:    &http.Client{Timeout: requestTimeout},
+		baseURL: strings.TrimRight(baseURL, "/"),
+		email:   email,
+		token:   token,
+	}, nil
+}
+
+// NewIssue is the body of a create-issue request.
+type NewIssue struct {
+	Project     string
+	IssueType   string
+	Summary     string
+	Description string
+	// Fields holds extra fields by id, each shaped by FieldValue.
+	Fields map[string]string
+}
+
+// MarshalJSON nests everything under "fields" the way the create-issue
+// endpoint expects.
+func (i NewIssue) MarshalJSON() ([]byte, error) {
+	fields := make(map[string]any, len(i.Fields)+4)
+	for field, value := range i.Fields {
+		fields[field] = FieldValue(field, value)
+	}
+	fields["project"] = map[string]string{"key": i.Project}
+	fields["issuetype"] = map[string]string{"name": i.IssueType}
+	fields["summary"] = i.Summary
+	fields["description"] = i.Description
+
+	body, err := json.Marshal(map[string]any{"fields": fields})
+	if err != nil {
+		return nil, fmt.Errorf("encode jira issue: %w", err)
+	}
+
+	return body, nil
+}
+
+// FieldValue shapes a configured value for a field: labels is a
+// comma-separated list, components a list of names, priority a name,
+// assignee and reporter account ids, and anything else a string.
+func FieldValue(field string, value string) any {
+	switch field {
+	case "labels":
+		return splitList(value)
+	case "components":
+		names := splitList(value)
+		components := make([]map[string]string, 0, len(names))
+		for _, name := range names {
+			components = append(components, map[string]string{"name": name})
+		}
+		return components
+	case "priority":
+		return map[string]string{"name": value}
+	case "assignee", "reporter":
+		return map[string]string{"accountId": value}
+	default:
+		return value
+	}
+}
+
+func splitList(value string) []string {
+	items := make([]string, 0)
+	for _, item := range strings.Split(value, ",") {
+		if item = strings.TrimSpace(item); item != "" {
+			items = append(items, item)
+		}
+	}
+
+	return items
+}
+
+type Issue struct {
+	ID  string `json:"id"`
+	Key string `json:"key"`
+	// URL is the issue's browse link, built from the client's base URL.
+	URL string `json:"-"`
+}
+
+func (c *Client) CreateIssue(ctx context.Context, issue NewIssue) (Issue, error) {
+	payload, err := json.Marshal(issue)
+	if err != nil {
+		return Issue{}, fmt.Errorf("encode jira issue: %w", err)
+	}
+
+	request, err := http.NewRequestWithContext(ctx, http.MethodPost, c.baseURL+"/rest/api/2/issue", bytes.NewReader(payload))
+	if err != nil {
+		return Issue{}, fmt.Errorf("build jira request: %w", err)
+	}
+	request.Header.Set("Accept", "application/json")
+	request.Header.Set("Content-Type", "application/json")
+	if c.email != "" {
+		request.SetBasicAuth(c.email, c.token)
+	} else {
+		request.Header.Set("Authorization", "Bearer "+c.token)
+	}
+
+	var created Issue
+	if err := c.do(request, &created); err != nil {
+		return Issue{}, err
+	}
+	created.URL = c.baseURL + "/browse/" + created.Key
+
+	return created, nil
+}
+
+func (c *Client) do(request *http.Request, target any) error {
+	response, err := c.http.Do(request)
+	if err != nil {
+		return fmt.Errorf("jira request: %w", err)
+	}
+	defer func() { _ = response.Body.Close() }()
+
+	body, err := io.ReadAll(io.LimitReader(response.Body, maxResponseBytes))
+	if err != nil {
+		return fmt.Errorf("read jira response: %w", err)
+	}
+	if response.StatusCode < 200 || response.StatusCode > 299 {
+		return statusError(response.StatusCode, body)
+	}
+	if err := json.Unmarshal(body, target); err != nil {
+		return fmt.Errorf("decode jira response: %w", err)
+	}
+
+	return nil
+}
+
+// statusError reports Jira's error messages, including per-field ones such
+// as a required custom field the project needs.
+func statusError(status int, body []byte) error {
+	var payload struct {
+		ErrorMessages []string          `json:"errorMessages"`
+		Errors        map[string]string `json:"errors"`
+	}
+	_ = json.Unmarshal(body, &payload)
+
+	messages := append([]string(nil), payload.ErrorMessages...)
+	fields := make([]string, 0, len(payload.Errors))
+	for field := range payload.Errors {
+		fields = append(fields, field)
+	}
+	sort.Strings(fields)
+	for _, field := range fields {
+		messages = append(messages, field+": "+payload.Errors[field])
+	}
+	if len(messages) == 0 {
+		return fmt.Errorf("jira returned status %d", status)
+	}
+
+	return fmt.Errorf("jira returned status %d: %s", status, strings.Join(messages, "; "))
+}
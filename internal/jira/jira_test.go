@@ -0,0 +1,131 @@
+package jira
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"reflect"
+	"strings"
+	"testing"
+)
+
+// issueHandler files ENG-42 after checking the me@acme.com credentials and
+// decodes the filed issue into got.
+func issueHandler(t *testing.T, got *map[string]map[string]any) http.HandlerFunc {
+	t.Helper()
+
+	return func(w http.ResponseWriter, r *http.Request) {
+		email, token, ok := r.BasicAuth()
+		if r.Method != http.MethodPost || r.URL.Path != "/rest/api/2/issue" || !ok || email != "me@acme.com" || token != "jira-token" {
+			t.Fatalf("unexpected request: %s %s (auth %v)", r.Method, r.URL.Path, ok)
+		}
+		if err := json.NewDecoder(r.Body).Decode(got); err != nil {
+			t.Fatalf("decode request: %v", err)
+		}
+		w.WriteHeader(http.StatusCreated)
+		_, _ = fmt.Fprint(w, `{"id":"10001","key":"ENG-42"}`)
+	}
+}
+
+func TestCreateIssue(t *testing.T) {
+	t.Parallel()
+
+	var got map[string]map[string]any
+	server := httptest.NewServer(issueHandler(t, &got))
+	t.Cleanup(server.Close)
+
+	client, err := NewClient(server.URL+"/", "me@acme.com", "jira-token")
+	if err != nil {
+		t.Fatalf("NewClient() error = %v", err)
+	}
+	issue, err := client.CreateIssue(context.Background(), NewIssue{
+		Project:     "ENG",
+		IssueType:   "Bug",
+		Summary:     "boom",
+		Description: "details",
+		Fields:      map[string]string{"labels": "rollbar, production", "customfield_10042": "269"},
+	})
+	if err != nil {
+		t.Fatalf("CreateIssue() error = %v", err)
+	}
+	if issue.Key != "ENG-42" || issue.URL != server.URL+"/browse/ENG-42" {
+		t.Fatalf("unexpected issue: %+v", issue)
+	}
+
+	want := map[string]any{
+		"project":           map[string]any{"key": "ENG"},
+		"issuetype":         map[string]any{"name": "Bug"},
+		"summary":           "boom",
+		"description":       "details",
+		"labels":            []any{"rollbar", "production"},
+		"customfield_10042": "269",
+	}
+	if !reflect.DeepEqual(got["fields"], want) {
+		t.Fatalf("fields = %#v, want %#v", got["fields"], want)
+	}
+}
+
+func TestCreateIssueBearerTokenAndErrors(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Header.Get("Authorization") != "Bearer pat" {
+			w.WriteHeader(http.StatusUnauthorized)
+			return
+		}
+		w.WriteHeader(http.StatusBadRequest)
+		_, _ = fmt.Fprint(w, `{"errorMessages":["Bad request"],"errors":{"summary":"required","components":"invalid"}}`)
+	}))
+	t.Cleanup(server.Close)
+
+	tests := []struct {
+		token string
+		want  string
+	}{
+		{token: "pat", want: "status 400: Bad request; components: invalid; summary: required"},
+		{token: "wrong", want: "status 401"},
+	}
+	for _, tc := range tests {
+		client, err := NewClient(server.URL, "", tc.token)
+		if err != nil {
+			t.Fatalf("NewClient() error = %v", err)
+		}
+		_, err = client.CreateIssue(context.Background(), NewIssue{Project: "ENG", IssueType: "Bug"})
+		if err == nil || !strings.HasSuffix(err.Error(), tc.want) || strings.Contains(err.Error(), tc.token) {
+			t.Fatalf("token %s: expected %q error, got %v", tc.token, tc.want, err)
+		}
+	}
+}
+
+func TestFieldValue(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		field string
+		value string
+		want  any
+	}{
+		{field: "labels", value: "a, ,b", want: []string{"a", "b"}},
+		{field: "components", value: "api,web", want: []map[string]string{{"name": "api"}, {"name": "web"}}},
+		{field: "priority", value: "High", want: map[string]string{"name": "High"}},
+		{field: "assignee", value: "5b10", want: map[string]string{"accountId": "5b10"}},
+		{field: "customfield_1", value: "x", want: "x"},
+	}
+	for _, tc := range tests {
+		if got := FieldValue(tc.field, tc.value); !reflect.DeepEqual(got, tc.want) {
+			t.Fatalf("FieldValue(%q, %q) = %#v, want %#v", tc.field, tc.value, got, tc.want)
+		}
+	}
+}
+
+func TestNewClientValidation(t *testing.T) {
+	t.Parallel()
+
+	for _, args := range [][2]string{{"", "token"}, {"https://acme.atlassian.net", " "}} {
+		if _, err := NewClient(args[0], "", args[1]); err == nil {
+			t.Fatalf("NewClient(%q, %q) expected error", args[0], args[1])
+		}
+	}
+}
@@ -0,0 +1,74 @@
+// Package jq runs jq filters over JSON values for the --jq flag, so output
+// can be shaped without an external jq, which many CI images lack. The
+// language is jq's own, implemented by gojq.
+package jq
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"github.com/itchyny/gojq"
+)
+
+// Query is a compiled filter.
+type Query struct {
+	expr string
+	code *gojq.Code
+}
+
+// Compile parses and compiles expr.
+func Compile(expr string) (*Query, error) {
+	parsed, err := gojq.Parse(expr)
+	if err != nil {
+		return nil, fmt.Errorf("syntax: %w", err)
+	}
+	code, err := gojq.Compile(parsed)
+	if err != nil {
+		return nil, fmt.Errorf("compile: %w", err)
+	}
+
+	return &Query{expr: expr, code: code}, nil
+}
+
+// String is the expression the query was compiled from.
+func (q *Query) String() string {
+	return q.expr
+}
+
+// Run applies the query to value, which may be any JSON-encodable value; it
+// is read through its JSON encoding so json tags apply. It returns every
+// output, stopping at the first error; halt ends the outputs without one.
+func (q *Query) Run(value any) ([]any, error) {
+	body, err := json.Marshal(value)
+	if err != nil {
+		return nil, fmt.Errorf("encode jq input: %w", err)
+	}
+	var input any
+	if err := json.Unmarshal(body, &input); err != nil {
+		return nil, fmt.Errorf("decode jq input: %w", err)
+	}
+
+	outputs := []any{}
+	results := q.code.Run(input)
+	for {
+		output, ok := results.Next()
+		if !ok {
+			return outputs, nil
+		}
+		if err, isErr := output.(error); isErr {
+			return outputs, runError(err)
+		}
+		outputs = append(outputs, output)
+	}
+}
+
+// runError is nil for a bare halt, which only ends the outputs.
+func runError(err error) error {
+	var halt *gojq.HaltError
+	if errors.As(err, &halt) && halt.Value() == nil {
+		return nil
+	}
+
+	return err //nolint:wrapcheck // gojq's message already names the failing step, and the caller prefixes the expression.
+}
@@ -0,0 +1,85 @@
+package jq
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+)
+
+type issue struct {
+	Counter     int    `json:"counter"`
+	Title       string `json:"title"`
+	Occurrences int    `json:"occurrences"`
+}
+
+var testInput = map[string]any{"issues": []issue{
+	{Counter: 3, Title: "boom", Occurrences: 9},
+	{Counter: 1, Title: "bang", Occurrences: 2},
+	{Counter: 2, Title: "Bust", Occurrences: 5},
+}}
+
+func TestQueryRun(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		expr string
+		want string
+	}{
+		{expr: ".issues[0].title", want: `"boom"`},
+		{expr: ".issues | map(.counter) | sort | .[]", want: "1\n2\n3"},
+		{expr: `.issues[] | select(.occurrences > 4) | {counter, title}`, want: `{"counter":3,"title":"boom"}` + "\n" + `{"counter":2,"title":"Bust"}`},
+		{expr: ".issues as $all | $all | length", want: "3"},
+		{expr: "reduce .issues[] as $issue (0; . + $issue.occurrences)", want: "16"},
+		{expr: `.issues[] | "#\(.counter) \(.title)"`, want: `"#3 boom"` + "\n" + `"#1 bang"` + "\n" + `"#2 Bust"`},
+		{expr: ".issues[] | select(.counter > 9)", want: ""},
+		{expr: ".issues[0].counter, halt, 2", want: "3"},
+	}
+
+	for _, tc := range tests {
+		query, err := Compile(tc.expr)
+		if err != nil {
+			t.Fatalf("Compile(%q) error = %v", tc.expr, err)
+		}
+		outputs, err := query.Run(testInput)
+		if err != nil {
+			t.Fatalf("%s: Run() error = %v", tc.expr, err)
+		}
+		if got := encodeLines(t, outputs); got != tc.want || query.String() != tc.expr {
+			t.Fatalf("%s = %q, want %q", tc.expr, got, tc.want)
+		}
+	}
+}
+
+func TestQueryErrors(t *testing.T) {
+	t.Parallel()
+
+	for expr, want := range map[string]string{".issues[": "syntax:", "nope(1)": "compile:"} {
+		if _, err := Compile(expr); err == nil || !strings.Contains(err.Error(), want) {
+			t.Fatalf("Compile(%q) error = %v, want %q", expr, err, want)
+		}
+	}
+
+	query, err := Compile(`.issues[0].counter, .issues.title`)
+	if err != nil {
+		t.Fatalf("Compile() error = %v", err)
+	}
+	outputs, err := query.Run(testInput)
+	if err == nil || !strings.Contains(err.Error(), "expected an object") || len(outputs) != 1 {
+		t.Fatalf("Run() = %v, %v; want the first output and an index error", outputs, err)
+	}
+}
+
+func encodeLines(t *testing.T, outputs []any) string {
+	t.Helper()
+
+	lines := make([]string, 0, len(outputs))
+	for _, output := range outputs {
+		line, err := json.Marshal(output)
+		if err != nil {
+			t.Fatalf("encode output: %v", err)
+		}
+		lines = append(lines, string(line))
+	}
+
+	return strings.Join(lines, "\n")
+}
@@ -0,0 +1,202 @@
+// Package keyring stores secrets in the operating system's credential store
+// by driving its command-line tool: `security` on macOS and `secret-tool`
+// (libsecret) on Linux. Other platforms report ErrUnavailable.
+package keyring
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"os/exec"
+	"runtime"
+	"strings"
+	"time"
+)
+
+var (
+	// ErrNotFound is returned when no secret is stored for the account.
+	ErrNotFound = errors.New("secret not found in keyring")
+	// ErrUnavailable is returned when the platform has no supported keyring.
+	ErrUnavailable = errors.New("system keyring unavailable")
+)
+
+const commandTimeout = 10 * time.Second
+
+// Keyring stores one secret per service and account.
+type Keyring interface {
+	Set(service string, account string, secret string) error
+	Get(service string, account string) (string, error)
+	Delete(service string, account string) error
+}
+
+// runner executes a credential tool, feeding stdin and returning stdout.
+type runner func(ctx context.Context, stdin string, name string, args ...string) (string, error)
+
+// System returns the keyring for the current platform, or ErrUnavailable when
+// its tool is not installed.
+func System() (Keyring, error) {
+	switch runtime.GOOS {
+	case "darwin":
+		return lookup("security", func(run runner) Keyring { return macKeyring{run: run} })
+	case "linux", "freebsd", "openbsd":
+		return lookup("secret-tool", func(run runner) Keyring { return secretToolKeyring{run: run} })
+	default:
+		return nil, fmt.Errorf("%w on %s", ErrUnavailable, runtime.GOOS)
+	}
+}
+
+func lookup(tool string, build func(runner) Keyring) (Keyring, error) {
+	if _, err := exec.LookPath(tool); err != nil {
+		return nil, fmt.Errorf("%w: %s not found", ErrUnavailable, tool)
+	}
+
+	return build(runCommand), nil
+}
+
+func runCommand(ctx context.Context, stdin string, name string, args ...string) (string, error) {
+	ctx, cancel := context.WithTimeout(ctx, commandTimeout)
+	defer cancel()
+
+	//nolint:gosec // G204: name is one of this package's fixed credential tools.
+	cmd := exec.CommandContext(ctx, name, args...)
+	cmd.Stdin = strings.NewReader(stdin)
+	var stdout, stderr bytes.Buffer
+	cmd.Stdout = &stdout
+	cmd.Stderr = &stderr
+
+	if err := cmd.Run(); err != nil {
+		var exitErr *exec.ExitError
+		if errors.As(err, &exitErr) {
+			return "", &commandError{tool: name, code: exitErr.ExitCode(), stderr: strings.TrimSpace(stderr.String())}
+		}
+		return "", fmt.Errorf("run %s: %w", name, err)
+	}
+
+	return stdout.String(), nil
+}
+
+// commandError is a non-zero exit from a credential tool. Its stderr never
+// contains the secret, which only travels on stdin.
+type commandError struct {
+	tool   string
+	code   int
+	stderr string
+}
+
+func (e *commandError) Error() string {
+	if e.stderr == "" {
+		return fmt.Sprintf("%s exited with status %d", e.tool, e.code)
+	}
+
+	return fmt.Sprintf("%s exited with status %d: %s", e.tool, e.code, e.stderr)
+}
+
+func exitCode(err error) int {
+	var cmdErr *commandError
+	if errors.As(err, &cmdErr) {
+		return cmdErr.code
+	}
+
+	return -1
+}
+
+// macKeyring drives the macOS `security` tool. Its item-not-found exit
+// status is 44.
+type macKeyring struct {
+	run runner
+}
+
+const macNotFound = 44
+
+func (k macKeyring) Set(service string, account string, secret string) error {
+	// `security -i` reads its commands from stdin, which keeps the secret out
+	// of the argument list other local users can see with ps.
+	command, err := macCommand("add-generic-password", "-U", "-s", service, "-a", account, "-w", secret)
+	if err != nil {
+		return err
+	}
+	if _, err := k.run(context.Background(), command, "security", "-i"); err != nil {
+		return fmt.Errorf("store secret: %w", err)
+	}
+
+	return nil
+}
+
+func (k macKeyring) Get(service string, account string) (string, error) {
+	out, err := k.run(context.Background(), "", "security", "find-generic-password", "-s", service, "-a", account, "-w")
+	if exitCode(err) == macNotFound {
+		return "", ErrNotFound
+	}
+	if err != nil {
+		return "", fmt.Errorf("read secret: %w", err)
+	}
+
+	return strings.TrimRight(out, "\r\n"), nil
+}
+
+func (k macKeyring) Delete(service string, account string) error {
+	_, err := k.run(context.Background(), "", "security", "delete-generic-password", "-s", service, "-a", account)
+	if exitCode(err) == macNotFound {
+		return ErrNotFound
+	}
+	if err != nil {
+		return fmt.Errorf("delete secret: %w", err)
+	}
+
+	return nil
+}
+
+var macQuoter = strings.NewReplacer(`\`, `\\`, `"`, `\"`)
+
+// macCommand is one line of input for `security -i`, which splits words on
+// spaces outside double quotes and takes backslash escapes inside them.
+func macCommand(args ...string) (string, error) {
+	words := make([]string, 0, len(args))
+	for _, arg := range args {
+		if strings.ContainsAny(arg, "\r\n") {
+			return "", errors.New("keyring values cannot contain line breaks")
+		}
+		words = append(words, `"`+macQuoter.Replace(arg)+`"`)
+	}
+
+	return strings.Join(words, " ") + "\n", nil
+}
+
+// secretToolKeyring drives libsecret's `secret-tool`, which reads the secret
+// from stdin and exits 1 with no output when nothing matches.
+type secretToolKeyring struct {
+	run runner
+}
+
+func (k secretToolKeyring) Set(service string, account string, secret string) error {
+	label := "--label=" + service + " (" + account + ")"
+	if _, err := k.run(context.Background(), secret, "secret-tool", "store", label, "service", service, "account", account); err != nil {
+		return fmt.Errorf("store secret: %w", err)
+	}
+
+	return nil
+}
+
+func (k secretToolKeyring) Get(service string, account string) (string, error) {
+	out, err := k.run(context.Background(), "", "secret-tool", "lookup", "service", service, "account", account)
+	if exitCode(err) == 1 || (err == nil && out == "") {
+		return "", ErrNotFound
+	}
+	if err != nil {
+		return "", fmt.Errorf("read secret: %w", err)
+	}
+
+	return strings.TrimRight(out, "\r\n"), nil
+}
+
+func (k secretToolKeyring) Delete(service string, account string) error {
+	if _, err := k.Get(service, account); err != nil {
+		return err
+	}
+	if _, err := k.run(context.Background(), "", "secret-tool", "clear", "service", service, "account", account); err != nil {
+		return fmt.Errorf("delete secret: %w", err)
+	}
+
+	return nil
+}
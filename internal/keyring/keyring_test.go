@@ -0,0 +1,111 @@
+package keyring
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+)
+
+type call struct {
+	stdin string
+	args  string
+}
+
+// fakeTool records calls and answers from a map of joined arguments.
+type fakeTool struct {
+	calls   []call
+	outputs map[string]string
+	errs    map[string]error
+}
+
+func (f *fakeTool) run(ctx context.Context, stdin string, name string, args ...string) (string, error) {
+	joined := name + " " + strings.Join(args, " ")
+	f.calls = append(f.calls, call{stdin: stdin, args: joined})
+	for prefix, err := range f.errs {
+		if strings.HasPrefix(joined, prefix) {
+			return "", err
+		}
+	}
+
+	return f.outputs[joined], nil
+}
+
+func TestMacKeyring(t *testing.T) {
+	t.Parallel()
+
+	tool := &fakeTool{
+		outputs: map[string]string{"security find-generic-password -s rollbaz -a work -w": "secret-1\n"},
+		errs:    map[string]error{"security find-generic-password -s rollbaz -a gone": &commandError{tool: "security", code: macNotFound}},
+	}
+	ring := macKeyring{run: tool.run}
+
+	if err := ring.Set("rollbaz", "work", "secret-1"); err != nil {
+		t.Fatalf("Set() error = %v", err)
+	}
+	if set := tool.calls[0]; set.args != "security -i" || set.stdin != `"add-generic-password" "-U" "-s" "rollbaz" "-a" "work" "-w" "secret-1"`+"\n" {
+		t.Fatalf("expected the secret on stdin only: %+v", set)
+	}
+	if secret, err := ring.Get("rollbaz", "work"); err != nil || secret != "secret-1" {
+		t.Fatalf("Get() = %q, %v", secret, err)
+	}
+	if _, err := ring.Get("rollbaz", "gone"); !errors.Is(err, ErrNotFound) {
+		t.Fatalf("expected ErrNotFound, got %v", err)
+	}
+	if err := ring.Set("rollbaz", "work", "line\nbreak"); err == nil {
+		t.Fatal("expected an error for a secret with a line break")
+	}
+}
+
+func TestMacCommand(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		args []string
+		want string
+	}{
+		{args: []string{"-s", "rollbaz"}, want: `"-s" "rollbaz"` + "\n"},
+		{args: []string{"-a", "my work"}, want: `"-a" "my work"` + "\n"},
+		{args: []string{"-w", `a"b\c`}, want: `"-w" "a\"b\\c"` + "\n"},
+	}
+
+	for _, tc := range tests {
+		if got, err := macCommand(tc.args...); err != nil || got != tc.want {
+			t.Fatalf("macCommand(%q) = %q, %v, want %q", tc.args, got, err, tc.want)
+		}
+	}
+}
+
+func TestSecretToolKeyring(t *testing.T) {
+	t.Parallel()
+
+	tool := &fakeTool{
+		outputs: map[string]string{"secret-tool lookup service rollbaz account work": "secret-2"},
+		errs: map[string]error{
+			"secret-tool lookup service rollbaz account gone": &commandError{tool: "secret-tool", code: 1},
+			"secret-tool store":                               &commandError{tool: "secret-tool", code: 2, stderr: "no session bus"},
+		},
+	}
+	ring := secretToolKeyring{run: tool.run}
+
+	if secret, err := ring.Get("rollbaz", "work"); err != nil || secret != "secret-2" {
+		t.Fatalf("Get() = %q, %v", secret, err)
+	}
+	if err := ring.Delete("rollbaz", "work"); err != nil {
+		t.Fatalf("Delete() error = %v", err)
+	}
+	if last := tool.calls[len(tool.calls)-1]; last.args != "secret-tool clear service rollbaz account work" {
+		t.Fatalf("unexpected delete call: %+v", last)
+	}
+	if err := ring.Delete("rollbaz", "gone"); !errors.Is(err, ErrNotFound) {
+		t.Fatalf("expected ErrNotFound, got %v", err)
+	}
+
+	err := ring.Set("rollbaz", "work", "secret-3")
+	if err == nil || !strings.Contains(err.Error(), "no session bus") {
+		t.Fatalf("expected store error, got %v", err)
+	}
+	if last := tool.calls[len(tool.calls)-1]; last.stdin != "secret-3" || strings.Contains(last.args, "secret-3") {
+		t.Fatalf("expected secret on stdin only: %+v", last)
+	}
+}
@@ -0,0 +1,225 @@
+// Package otel ships log records to an OpenTelemetry collector over OTLP/HTTP
+// with JSON encoding, so no protobuf or SDK dependency is needed.
+package otel
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// DefaultEndpoint is the OTLP/HTTP port of a local collector.
+const DefaultEndpoint = "http://localhost:4318"
+
+const (
+	requestTimeout   = 15 * time.Second
+	maxResponseBytes = 1 << 20
+	scopeName        = "rollbaz"
+)
+
+// Severity numbers from the OpenTelemetry log data model.
+const (
+	SeverityDebug = 5
+	SeverityInfo  = 9
+	SeverityWarn  = 13
+	SeverityError = 17
+	SeverityFatal = 21
+)
+
+// LogRecord is one log record. TraceID and SpanID are lowercase hex (32 and
+// 16 digits) and link the record to a trace when set.
+type LogRecord struct {
+	Time           time.Time
+	SeverityNumber int
+	SeverityText   string
+	Body           string
+	TraceID        string
+	SpanID         string
+	Attributes     map[string]string
+}
+
+type Client struct {
+	http     *http.Client
+	endpoint string
+	headers  map[string]string
+}
+
+// NewClient sends to endpoint, the collector's base URL as in
+// OTEL_EXPORTER_OTLP_ENDPOINT; an empty endpoint uses DefaultEndpoint.
+// headers are added to every request, for collector authentication.
+func NewClient(endpoint string, headers map[string]string) (*Client, error) {
+	if endpoint == "" {
+		endpoint = DefaultEndpoint
+	}
+	parsed, err := url.Parse(endpoint)
+	if err != nil || (parsed.Scheme != "http" && parsed.Scheme != "https") || parsed.Host == "" {
+		return nil, fmt.Errorf("invalid otlp endpoint %q (use http(s)://host:port)", endpoint)
+	}
+
+	return &Client{http: &http.Client{Timeout: requestTimeout}, endpoint: strings.TrimRight(endpoint, "/"), headers: headers}, nil
+}
+
+// ParseHeaders reads the OTEL_EXPORTER_OTLP_HEADERS form, "k1=v1,k2=v2".
+func ParseHeaders(value string) (map[string]string, error) {
+	headers := map[string]string{}
+	for _, pair := range strings.Split(value, ",") {
+		if strings.TrimSpace(pair) == "" {
+			continue
+		}
+		key, val, ok := strings.Cut(pair, "=")
+		if !ok || strings.TrimSpace(key) == "" {
+			return nil, fmt.Errorf("invalid otlp header %q (use key=value)", strings.TrimSpace(pair))
+		}
+		unescaped, err := url.QueryUnescape(strings.TrimSpace(val))
+		if err != nil {
+			return nil, fmt.Errorf("invalid otlp header %q: %w", strings.TrimSpace(key), err)
+		}
+		headers[strings.TrimSpace(key)] = unescaped
+	}
+
+	return headers, nil
+}
+
+// Export sends records as one batch under a resource with resource
+// attributes, such as service.name.
+func (c *Client) Export(ctx context.Context, resource map[string]string, records []LogRecord) error {
+	if len(records) == 0 {
+		return nil
+	}
+
+	payload, err := json.Marshal(exportRequest(resource, records))
+	if err != nil {
+		return fmt.Errorf("encode otlp logs: %w", err)
+	}
+	request, err := http.NewRequestWithContext(ctx, http.MethodPost, c.endpoint+"/v1/logs", bytes.NewReader(payload))
+	if err != nil {
+		return fmt.Errorf("build otlp request: %w", err)
+	}
+	request.Header.Set("Content-Type", "application/json")
+	for key, value := range c.headers {
+		request.Header.Set(key, value)
+	}
+
+	response, err := c.http.Do(request)
+	if err != nil {
+		return fmt.Errorf("otlp request: %w", err)
+	}
+	defer func() { _ = response.Body.Close() }()
+
+	body, _ := io.ReadAll(io.LimitReader(response.Body, maxResponseBytes))
+	if response.StatusCode < 200 || response.StatusCode > 299 {
+		return fmt.Errorf("otlp collector returned status %d: %s", response.StatusCode, strings.TrimSpace(string(body)))
+	}
+
+	return nil
+}
+
+// The types below are the OTLP JSON encoding of ExportLogsServiceRequest.
+
+type logsRequest struct {
+	ResourceLogs []resourceLogs `json:"resourceLogs"`
+}
+
+type resourceLogs struct {
+	Resource  resource    `json:"resource"`
+	ScopeLogs []scopeLogs `json:"scopeLogs"`
+}
+
+type resource struct {
+	Attributes []keyValue `json:"attributes"`
+}
+
+type scopeLogs struct {
+	Scope      scope       `json:"scope"`
+	LogRecords []logRecord `json:"logRecords"`
+}
+
+type scope struct {
+	Name string `json:"name"`
+}
+
+type logRecord struct {
+	TimeUnixNano         string     `json:"timeUnixNano"`
+	ObservedTimeUnixNano string     `json:"observedTimeUnixNano"`
+	SeverityNumber       int        `json:"severityNumber,omitempty"`
+	SeverityText         string     `json:"severityText,omitempty"`
+	Body                 anyValue   `json:"body"`
+	Attributes           []keyValue `json:"attributes,omitempty"`
+	TraceID              string     `json:"traceId,omitempty"`
+	SpanID               string     `json:"spanId,omitempty"`
+}
+
+type keyValue struct {
+	Key   string   `json:"key"`
+	Value anyValue `json:"value"`
+}
+
+type anyValue struct {
+	StringValue string `json:"stringValue"`
+}
+
+func exportRequest(attributes map[string]string, records []LogRecord) logsRequest {
+	encoded := make([]logRecord, 0, len(records))
+	for _, record := range records {
+		// Zero means unknown in OTLP.
+		nanos := "0"
+		if !record.Time.IsZero() {
+			nanos = strconv.FormatInt(record.Time.UnixNano(), 10)
+		}
+		encoded = append(encoded, logRecord{
+			TimeUnixNano:         nanos,
+			ObservedTimeUnixNano: nanos,
+			SeverityNumber:       record.SeverityNumber,
+			SeverityText:         record.SeverityText,
+			Body:                 anyValue{StringValue: record.Body},
+			Attributes:           keyValues(record.Attributes),
+			TraceID:              record.TraceID,
+			SpanID:               record.SpanID,
+		})
+	}
+
+	return logsRequest{ResourceLogs: []resourceLogs{{
+		Resource:  resource{Attributes: keyValues(attributes)},
+		ScopeLogs: []scopeLogs{{Scope: scope{Name: scopeName}, LogRecords: encoded}},
+	}}}
+}
+
+// keyValues drops empty values and sorts by key so requests are stable.
+func keyValues(attributes map[string]string) []keyValue {
+	values := make([]keyValue, 0, len(attributes))
+	for key, value := range attributes {
+		if value != "" {
+			values = append(values, keyValue{Key: key, Value: anyValue{StringValue: value}})
+		}
+	}
+	sort.Slice(values, func(i, j int) bool { return values[i].Key < values[j].Key })
+
+	return values
+}
+
+// ValidTraceID reports whether id is a usable OTLP trace id: 32 lowercase
+// hex digits, not all zero. ValidSpanID is the same for 16-digit span ids.
+func ValidTraceID(id string) bool { return validHexID(id, 32) }
+
+func ValidSpanID(id string) bool { return validHexID(id, 16) }
+
+func validHexID(id string, length int) bool {
+	if len(id) != length || strings.Trim(id, "0") == "" {
+		return false
+	}
+	for _, r := range id {
+		if (r < '0' || r > '9') && (r < 'a' || r > 'f') {
+			return false
+		}
+	}
+
+	return true
+}
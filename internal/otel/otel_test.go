@@ -0,0 +1,132 @@
+package otel
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"reflect"
+	"strings"
+	"testing"
+	"time"
+)
+
+// testRecord is an error log record correlated with a trace and span.
+var testRecord = LogRecord{
+	Time:           time.Unix(1700000000, 5),
+	SeverityNumber: SeverityError,
+	SeverityText:   "ERROR",
+	Body:           "KeyError: id",
+	TraceID:        "4bf92f3577b34da6a3ce929d0e0e4736",
+	SpanID:         "00f067aa0ba902b7",
+	Attributes:     map[string]string{"rollbar.item.counter": "269", "empty": ""},
+}
+
+// newCollectorServer starts an OTLP collector under /otlp that accepts JSON
+// batches with the otlp bearer token and decodes the last one into got.
+func newCollectorServer(t *testing.T, got *map[string]any) *httptest.Server {
+	t.Helper()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/otlp/v1/logs" || r.Header.Get("Authorization") != "Bearer otlp" || r.Header.Get("Content-Type") != "application/json" {
+			w.WriteHeader(http.StatusUnauthorized)
+			_, _ = fmt.Fprint(w, "denied")
+			return
+		}
+		_ = json.NewDecoder(r.Body).Decode(got)
+		_, _ = fmt.Fprint(w, `{}`)
+	}))
+	t.Cleanup(server.Close)
+
+	return server
+}
+
+func TestExport(t *testing.T) {
+	t.Parallel()
+
+	var got map[string]any
+	server := newCollectorServer(t, &got)
+
+	client, err := NewClient(server.URL+"/otlp/", map[string]string{"Authorization": "Bearer otlp"})
+	if err != nil {
+		t.Fatalf("NewClient() error = %v", err)
+	}
+	if err := client.Export(context.Background(), map[string]string{"service.name": "billing-api"}, []LogRecord{testRecord}); err != nil {
+		t.Fatalf("Export() error = %v", err)
+	}
+
+	want := map[string]any{
+		"timeUnixNano":         "1700000000000000005",
+		"observedTimeUnixNano": "1700000000000000005",
+		"severityNumber":       float64(SeverityError),
+		"severityText":         "ERROR",
+		"body":                 map[string]any{"stringValue": "KeyError: id"},
+		"attributes":           []any{map[string]any{"key": "rollbar.item.counter", "value": map[string]any{"stringValue": "269"}}},
+		"traceId":              "4bf92f3577b34da6a3ce929d0e0e4736",
+		"spanId":               "00f067aa0ba902b7",
+	}
+	resourceLogs := got["resourceLogs"].([]any)[0].(map[string]any)
+	scopeLogs := resourceLogs["scopeLogs"].([]any)[0].(map[string]any)
+	if logged := scopeLogs["logRecords"].([]any)[0]; !reflect.DeepEqual(logged, want) {
+		t.Fatalf("log record = %#v, want %#v", logged, want)
+	}
+	if resource := fmt.Sprint(resourceLogs["resource"]); !strings.Contains(resource, "service.name") {
+		t.Fatalf("unexpected resource: %s", resource)
+	}
+}
+
+func TestExportErrors(t *testing.T) {
+	t.Parallel()
+
+	var got map[string]any
+	server := newCollectorServer(t, &got)
+
+	denied, _ := NewClient(server.URL, nil)
+	if err := denied.Export(context.Background(), nil, []LogRecord{testRecord}); err == nil || err.Error() != "otlp collector returned status 401: denied" {
+		t.Fatalf("expected status error, got %v", err)
+	}
+	if err := denied.Export(context.Background(), nil, nil); err != nil {
+		t.Fatalf("empty Export() error = %v", err)
+	}
+}
+
+func TestNewClientAndParseHeaders(t *testing.T) {
+	t.Parallel()
+
+	for _, endpoint := range []string{"localhost:4318", "ftp://collector", "http://"} {
+		if _, err := NewClient(endpoint, nil); err == nil {
+			t.Fatalf("NewClient(%q) expected error", endpoint)
+		}
+	}
+
+	headers, err := ParseHeaders("api-key=abc%3D, x-tenant = acme ,")
+	if err != nil || !reflect.DeepEqual(headers, map[string]string{"api-key": "abc=", "x-tenant": "acme"}) {
+		t.Fatalf("ParseHeaders() = %v, %v", headers, err)
+	}
+	if _, err := ParseHeaders("novalue"); err == nil {
+		t.Fatal("expected invalid header error")
+	}
+}
+
+func TestValidIDs(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		id   string
+		want bool
+	}{
+		{id: "4bf92f3577b34da6a3ce929d0e0e4736", want: true},
+		{id: "00000000000000000000000000000000"},
+		{id: "4BF92F3577B34DA6A3CE929D0E0E4736"},
+		{id: "4bf92f35"},
+	}
+	for _, tc := range tests {
+		if got := ValidTraceID(tc.id); got != tc.want {
+			t.Fatalf("ValidTraceID(%q) = %v, want %v", tc.id, got, tc.want)
+		}
+	}
+	if !ValidSpanID("00f067aa0ba902b7") || ValidSpanID("00f067aa") {
+		t.Fatal("unexpected ValidSpanID result")
+	}
+}
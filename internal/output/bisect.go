@@ -0,0 +1,70 @@
+package output
+
+import (
+	"fmt"
+	"regexp"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// shellSafe matches revisions a shell reads back unchanged without quoting.
+var shellSafe = regexp.MustCompile(`^[A-Za-z0-9._/@:+=-]+$`)
+
+// RenderBisectRange names the deploys around an item's first occurrence and
+// the git bisect command that searches between them.
+func RenderBisectRange(bisect app.BisectRange) string {
+	environment := fallback(bisect.Environment)
+	heading := fmt.Sprintf("Item #%s %s first occurred at %s in %s", bisect.Issue.Counter, fallback(bisect.Issue.Title), formatTimestamp(&bisect.FirstOccurrence), environment)
+	if bisect.Bad == nil {
+		return heading + "\n\nno deploy to " + environment + " started before then, so the item predates every deploy Rollbar has"
+	}
+
+	good := "good  none: it is the first deploy to " + environment + " Rollbar has, so mark a known good commit yourself"
+	if bisect.Good != nil {
+		good = "good  " + describeDeploy(*bisect.Good)
+	}
+	lines := []string{
+		heading,
+		"",
+		"bad   " + describeDeploy(*bisect.Bad),
+		good,
+		"",
+		"Bisect locally from the checkout:",
+		"  " + GitBisectCommand(bisect),
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+// GitBisectCommand is the git bisect start command for the range, with the
+// revisions quoted for the shell, or "" without a bad deploy.
+func GitBisectCommand(bisect app.BisectRange) string {
+	if bisect.Bad == nil {
+		return ""
+	}
+	command := []string{"git", "bisect", "start", shellQuote(bisect.Bad.Revision)}
+	if bisect.Good != nil {
+		command = append(command, shellQuote(bisect.Good.Revision))
+	}
+
+	return strings.Join(command, " ")
+}
+
+func describeDeploy(deploy rollbar.Deploy) string {
+	started := deploy.StartTime
+	if started == nil {
+		started = deploy.FinishTime
+	}
+
+	return fmt.Sprintf("deploy %d of %s at %s", deploy.ID, ShortRevision(deploy.Revision), formatTimestamp(started))
+}
+
+func shellQuote(value string) string {
+	if shellSafe.MatchString(value) {
+		return value
+	}
+
+	return "'" + strings.ReplaceAll(value, "'", `'\''`) + "'"
+}
@@ -0,0 +1,44 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderBisectRange(t *testing.T) {
+	t.Parallel()
+
+	badStart, goodFinish := uint64(1767225600), uint64(1767139200)
+	bad := &rollbar.Deploy{ID: 43, Revision: "0123456789abcdef0123", StartTime: &badStart}
+	good := &rollbar.Deploy{ID: 41, Revision: "v1.2 'rc'", FinishTime: &goodFinish}
+	base := app.BisectRange{Issue: app.IssueSummary{Counter: 42, Title: "KeyError"}, Environment: "production", FirstOccurrence: 1767229200}
+
+	tests := []struct {
+		name    string
+		bisect  app.BisectRange
+		want    []string
+		command string
+	}{
+		{name: "no deploy", bisect: base, want: []string{"Item #42 KeyError first occurred at 2026-01-01T01:00:00Z in production\n\nno deploy to production started before then"}},
+		{name: "first deploy", bisect: app.BisectRange{Issue: base.Issue, Environment: "production", FirstOccurrence: base.FirstOccurrence, Bad: bad}, want: []string{"bad   deploy 43 of 0123456789ab at 2026-01-01T00:00:00Z", "good  none"}, command: "git bisect start 0123456789abcdef0123"},
+		{name: "range", bisect: app.BisectRange{Issue: base.Issue, Environment: "production", FirstOccurrence: base.FirstOccurrence, Bad: bad, Good: good}, want: []string{"good  deploy 41 of v1.2 'rc' at 2025-12-31T00:00:00Z", "Bisect locally"}, command: `git bisect start 0123456789abcdef0123 'v1.2 '\''rc'\'''`},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			rendered := RenderBisectRange(tt.bisect)
+			for _, want := range append(tt.want, tt.command) {
+				if !strings.Contains(rendered, want) {
+					t.Fatalf("expected %q in:\n%s", want, rendered)
+				}
+			}
+			if got := GitBisectCommand(tt.bisect); got != tt.command {
+				t.Fatalf("GitBisectCommand() = %q, want %q", got, tt.command)
+			}
+		})
+	}
+}
@@ -0,0 +1,87 @@
+package output
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+const (
+	// blameNonSummaryWidth is the room the frame, commit, author, and date
+	// columns take in the blame table.
+	blameNonSummaryWidth = 80
+	blameCommitLength    = 8
+	blameDateLayout      = "2006-01-02"
+)
+
+// RenderBlameResultWithWidth lists who last changed the line of each
+// in-project frame, most recent call first, then the authors ranked.
+func RenderBlameResultWithWidth(result app.BlameResult, maxWidth int) string {
+	heading := fmt.Sprintf("Blame for #%s %s", result.Issue.Counter, fallback(result.Issue.Title))
+	if len(result.Frames) == 0 {
+		return heading + "\n\nno frames of the latest stack trace are in the checkout"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	frames := table.NewWriter()
+	frames.SetStyle(table.StyleLight)
+	frames.SetAllowedRowLength(targetWidth)
+	frames.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 5, WidthMax: min(max(targetWidth-blameNonSummaryWidth, minListTitleWidth), maxListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	frames.AppendHeader(table.Row{"FRAME", "COMMIT", "AUTHOR", "DATE", "SUMMARY"})
+	for _, frame := range result.Frames {
+		frames.AppendRow(append(table.Row{frame.Path + ":" + strconv.Itoa(frame.Lineno)}, blameColumns(frame)...))
+	}
+
+	sections := []string{heading, strings.TrimRight(frames.Render(), "\n")}
+	if len(result.Authors) > 0 {
+		authors := table.NewWriter()
+		authors.SetStyle(table.StyleLight)
+		authors.SetColumnConfigs([]table.ColumnConfig{{Number: 2, Align: prettytext.AlignRight}})
+		authors.AppendHeader(table.Row{"AUTHOR", "LINES", "LAST COMMIT", "DATE"})
+		for _, author := range result.Authors {
+			authors.AppendRow(table.Row{blameAuthor(author.Name, author.Email), author.Lines, shortCommit(author.LastCommit), blameDate(author.LastTime)})
+		}
+		sections = append(sections, strings.TrimRight(authors.Render(), "\n"))
+	}
+
+	return strings.Join(sections, "\n\n")
+}
+
+func blameColumns(frame app.FrameBlame) table.Row {
+	switch {
+	case frame.Blame == nil:
+		return table.Row{"-", "-", "-", fallback(frame.Error)}
+	case frame.Blame.Uncommitted():
+		return table.Row{"uncommitted", "-", "-", "local changes not committed yet"}
+	default:
+		return table.Row{shortCommit(frame.Blame.Commit), blameAuthor(frame.Blame.Author, ""), blameDate(frame.Blame.Time), fallback(frame.Blame.Summary)}
+	}
+}
+
+func blameAuthor(name string, email string) string {
+	if email == "" {
+		return fallback(name)
+	}
+
+	return fmt.Sprintf("%s <%s>", fallback(name), email)
+}
+
+func shortCommit(commit string) string {
+	return commit[:min(len(commit), blameCommitLength)]
+}
+
+func blameDate(unixSeconds int64) string {
+	if unixSeconds <= 0 {
+		return "-"
+	}
+
+	return time.Unix(unixSeconds, 0).UTC().Format(blameDateLayout)
+}
@@ -0,0 +1,35 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/vcs"
+)
+
+func TestRenderBlameResultWithWidth(t *testing.T) {
+	t.Parallel()
+
+	issue := app.IssueSummary{Counter: 42, Title: "KeyError"}
+	if got := RenderBlameResultWithWidth(app.BlameResult{Issue: issue}, 120); got != "Blame for #42 KeyError\n\nno frames of the latest stack trace are in the checkout" {
+		t.Fatalf("unexpected empty render %q", got)
+	}
+
+	result := app.BlameResult{
+		Issue: issue,
+		Frames: []app.FrameBlame{
+			{CheckoutFrame: app.CheckoutFrame{Frame: rollbar.Frame{Lineno: 12}, Path: "pay/charge.py"}, Blame: &vcs.BlameLine{Commit: "0123456789abcdef", Author: "Ana", Time: 1767225600, Summary: "Retry declined charges"}},
+			{CheckoutFrame: app.CheckoutFrame{Frame: rollbar.Frame{Lineno: 3}, Path: "web/view.py"}, Blame: &vcs.BlameLine{Commit: "0000000000000000"}},
+			{CheckoutFrame: app.CheckoutFrame{Frame: rollbar.Frame{Lineno: 90}, Path: "web/form.py"}, Error: "git blame web/form.py: fatal: file web/form.py has only 40 lines"},
+		},
+		Authors: []app.BlameAuthor{{Name: "Ana", Email: "ana@example.com", Lines: 1, LastCommit: "0123456789abcdef", LastTime: 1767225600}},
+	}
+	rendered := RenderBlameResultWithWidth(result, 160)
+	for _, want := range []string{"pay/charge.py:12", "01234567", "2026-01-01", "Retry declined charges", "uncommitted", "has only 40 lines", "Ana <ana@example.com>", "LAST COMMIT"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+}
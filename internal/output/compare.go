@@ -0,0 +1,92 @@
+package output
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+const (
+	compareOnlyNonTitle      = 44
+	compareDivergentNonTitle = 70
+)
+
+// RenderEnvComparisonWithWidth lists the errors only one environment has,
+// then the shared ones whose occurrence counts in the window diverge.
+func RenderEnvComparisonWithWidth(comparison app.EnvComparison, maxWidth int) string {
+	since := uint64(max(comparison.Since, 0))
+	sections := []string{
+		fmt.Sprintf("%s vs %s since %s: %d errors in both", comparison.A, comparison.B, formatTimestamp(&since), comparison.Shared),
+		compareOnlySection(comparison.A, comparison.B, comparison.OnlyA, maxWidth),
+		compareOnlySection(comparison.B, comparison.A, comparison.OnlyB, maxWidth),
+		compareDivergentSection(comparison, maxWidth),
+	}
+
+	return strings.Join(sections, "\n\n")
+}
+
+func compareOnlySection(environment string, other string, issues []app.IssueSummary, maxWidth int) string {
+	heading := fmt.Sprintf("Only in %s (not in %s):", environment, other)
+	if len(issues) == 0 {
+		return heading + " none"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 3, Align: prettytext.AlignRight},
+		{Number: 4, WidthMax: min(max(targetWidth-compareOnlyNonTitle, minListTitleWidth), maxListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	tw.AppendHeader(table.Row{"COUNTER", "LEVEL", "OCCURRENCES", "TITLE"})
+	for _, issue := range issues {
+		tw.AppendRow(table.Row{issue.Counter.String(), fallback(issue.Level), formatOccurrences(issue.Occurrences), fallback(issue.Title)})
+	}
+
+	return heading + "\n" + strings.TrimRight(tw.Render(), "\n")
+}
+
+func compareDivergentSection(comparison app.EnvComparison, maxWidth int) string {
+	heading := "Divergent occurrence rates in the window:"
+	if len(comparison.Divergent) == 0 {
+		return heading + " none"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 3, Align: prettytext.AlignRight},
+		{Number: 4, Align: prettytext.AlignRight},
+		{Number: 5, Align: prettytext.AlignRight},
+		{Number: 6, WidthMax: min(max(targetWidth-compareDivergentNonTitle, minListTitleWidth), maxListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	a, b := strings.ToUpper(comparison.A), strings.ToUpper(comparison.B)
+	tw.AppendHeader(table.Row{a, b, a + " OCC", b + " OCC", "RATIO", "TITLE"})
+	for _, divergence := range comparison.Divergent {
+		ratio := "only " + comparison.A
+		if divergence.OccurrencesA == 0 {
+			ratio = "only " + comparison.B
+		}
+		if divergence.Ratio != nil {
+			ratio = strconv.FormatFloat(*divergence.Ratio, 'f', -1, 64) + "x"
+		}
+		tw.AppendRow(table.Row{
+			divergence.A.Counter.String(),
+			divergence.B.Counter.String(),
+			strconv.FormatUint(divergence.OccurrencesA, 10),
+			strconv.FormatUint(divergence.OccurrencesB, 10),
+			ratio,
+			fallback(divergence.A.Title),
+		})
+	}
+
+	return heading + "\n" + strings.TrimRight(tw.Render(), "\n")
+}
@@ -0,0 +1,32 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderEnvComparisonWithWidth(t *testing.T) {
+	t.Parallel()
+
+	comparison := app.EnvComparison{A: "production", B: "staging", Since: 1767225600, OnlyA: []app.IssueSummary{}, OnlyB: []app.IssueSummary{}, Divergent: []app.RateDivergence{}}
+	want := "production vs staging since 2026-01-01T00:00:00Z: 0 errors in both\n\nOnly in production (not in staging): none\n\nOnly in staging (not in production): none\n\nDivergent occurrence rates in the window: none"
+	if got := RenderEnvComparisonWithWidth(comparison, 120); got != want {
+		t.Fatalf("unexpected empty render %q", got)
+	}
+
+	ratio := 6.5
+	comparison.Shared = 2
+	comparison.OnlyB = []app.IssueSummary{{Counter: 16, Title: "staging-only KeyError", Level: "error"}}
+	comparison.Divergent = []app.RateDivergence{
+		{A: app.IssueSummary{Counter: 5, Title: "NoMethodError"}, B: app.IssueSummary{Counter: 15}, OccurrencesB: 4},
+		{A: app.IssueSummary{Counter: 1, Title: "Timeout"}, B: app.IssueSummary{Counter: 11}, OccurrencesA: 13, OccurrencesB: 2, Ratio: &ratio},
+	}
+	rendered := RenderEnvComparisonWithWidth(comparison, 120)
+	for _, want := range []string{"2 errors in both", "Only in production (not in staging): none", "staging-only KeyError", "PRODUCTION OCC", "only staging", "6.5x", "Timeout"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+}
@@ -0,0 +1,62 @@
+package output
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	shortRevisionLength = 12
+	deployNonComment    = 84
+)
+
+func RenderDeployTableWithWidth(deploys []rollbar.Deploy, maxWidth int) string {
+	if len(deploys) == 0 {
+		return "no deploys found"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 7, WidthMax: max(targetWidth-deployNonComment, minListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	tw.AppendHeader(table.Row{"ID", "ENV", "REVISION", "STATUS", "USER", "STARTED", "COMMENT"})
+
+	for _, deploy := range deploys {
+		tw.AppendRow(table.Row{
+			strconv.FormatUint(deploy.ID, 10),
+			fallback(deploy.Environment),
+			ShortRevision(deploy.Revision),
+			fallback(deploy.Status),
+			fallback(deploy.LocalUsername),
+			formatTimestamp(deploy.StartTime),
+			deploy.Comment,
+		})
+	}
+
+	return strings.TrimRight(tw.Render(), "\n")
+}
+
+// RenderDeployReported is the one-line confirmation printed after reporting a
+// deploy, short enough to read in CI logs.
+func RenderDeployReported(deploy rollbar.Deploy) string {
+	return fmt.Sprintf("reported deploy %d: %s @ %s (%s)", deploy.ID, deploy.Environment, ShortRevision(deploy.Revision), deploy.Status)
+}
+
+// ShortRevision trims a commit SHA to the usual abbreviated length.
+func ShortRevision(revision string) string {
+	revision = strings.TrimSpace(revision)
+	if len(revision) > shortRevisionLength {
+		return revision[:shortRevisionLength]
+	}
+
+	return fallback(revision)
+}
@@ -0,0 +1,34 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderDeploys(t *testing.T) {
+	t.Parallel()
+
+	started := uint64(1700000000)
+	deploys := []rollbar.Deploy{
+		{ID: 9, Environment: "production", Revision: "0123456789abcdef0123", Status: "succeeded", LocalUsername: "ci", StartTime: &started, Comment: "release 1.2"},
+	}
+
+	rendered := RenderDeployTableWithWidth(deploys, 120)
+	for _, want := range []string{"0123456789ab", "production", "2023-11-14T22:13:20Z", "release 1.2"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in table:\n%s", want, rendered)
+		}
+	}
+	if strings.Contains(rendered, "0123456789abc") {
+		t.Fatalf("expected revision to be shortened:\n%s", rendered)
+	}
+	if got := RenderDeployTableWithWidth(nil, 120); got != "no deploys found" {
+		t.Fatalf("unexpected empty table: %q", got)
+	}
+
+	if got := RenderDeployReported(deploys[0]); got != "reported deploy 9: production @ 0123456789ab (succeeded)" {
+		t.Fatalf("RenderDeployReported() = %q", got)
+	}
+}
@@ -0,0 +1,156 @@
+package output
+
+import (
+	"bytes"
+	"fmt"
+	"html/template"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// DigestSection is one section of a triage report as the report templates
+// see it, from the sections template function.
+type DigestSection struct {
+	Title string
+	app.DigestSection
+}
+
+var markdownCell = strings.NewReplacer("|", `\|`, "\r", "", "\n", " ")
+
+// DigestSections lists a digest's item sections in report order.
+func DigestSections(digest app.Digest) []DigestSection {
+	return []DigestSection{
+		{Title: "New items", DigestSection: digest.New},
+		{Title: "Top items by occurrences", DigestSection: digest.Top},
+		{Title: "Resolved items", DigestSection: digest.Resolved},
+		{Title: "Regressions", DigestSection: digest.Regressions},
+	}
+}
+
+// RenderDigestMarkdown renders a triage report: the window, a per-environment
+// breakdown, then each section as a table of its most frequent items.
+func RenderDigestMarkdown(digest app.Digest) string {
+	lines := []string{
+		"# Rollbar triage report",
+		"",
+		digestWindow(digest),
+		"",
+		"## Environments",
+		"",
+	}
+	if len(digest.Environments) == 0 {
+		lines = append(lines, "No items in this window.")
+	} else {
+		lines = append(lines, "| Environment | New | Active | Resolved | Regressions | Occurrences |", "| --- | ---: | ---: | ---: | ---: | ---: |")
+		for _, breakdown := range digest.Environments {
+			lines = append(lines, fmt.Sprintf("| %s | %d | %d | %d | %d | %d |", markdownCell.Replace(fallback(breakdown.Environment)), breakdown.New, breakdown.Active, breakdown.Resolved, breakdown.Regressions, breakdown.Occurrences))
+		}
+	}
+
+	for _, section := range DigestSections(digest) {
+		lines = append(lines, "", fmt.Sprintf("## %s (%d)", section.Title, section.Total), "")
+		lines = append(lines, markdownDigestSection(section)...)
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+func markdownDigestSection(section DigestSection) []string {
+	if section.Total == 0 {
+		return []string{"None."}
+	}
+
+	lines := []string{"| Item | Level | Title | Environment | Total occurrences |", "| --- | --- | --- | --- | ---: |"}
+	for _, issue := range section.Items {
+		lines = append(lines, fmt.Sprintf("| #%s | %s | %s | %s | %s |", issue.Counter, fallback(issue.Level), markdownCell.Replace(fallback(issue.Title)), markdownCell.Replace(fallback(issue.Environment)), formatOccurrences(issue.Occurrences)))
+	}
+	if len(section.Items) < section.Total {
+		lines = append(lines, "", fmt.Sprintf("Showing the %d most frequent of %d.", len(section.Items), section.Total))
+	}
+
+	return lines
+}
+
+func digestWindow(digest app.Digest) string {
+	scope := "All environments"
+	if digest.Environment != "" {
+		scope = "Environment " + digest.Environment
+	}
+
+	return fmt.Sprintf("%s, %s to %s.", scope, formatDigestTime(digest.Since), formatDigestTime(digest.Until))
+}
+
+func formatDigestTime(unixSeconds int64) string {
+	return time.Unix(unixSeconds, 0).UTC().Format("2006-01-02 15:04 UTC")
+}
+
+// DigestTemplateFuncs are the functions report templates may call besides
+// the html/template builtins.
+var DigestTemplateFuncs = template.FuncMap{
+	"sections":    DigestSections,
+	"time":        formatDigestTime,
+	"window":      digestWindow,
+	"occurrences": formatOccurrences,
+	"fallback":    fallback,
+}
+
+// RenderDigestHTML renders a triage report with an html/template, the
+// built-in one when text is empty. The template runs against app.Digest with
+// DigestTemplateFuncs.
+func RenderDigestHTML(digest app.Digest, text string) (string, error) {
+	if strings.TrimSpace(text) == "" {
+		text = defaultDigestTemplate
+	}
+	parsed, err := template.New("report").Funcs(DigestTemplateFuncs).Parse(text)
+	if err != nil {
+		return "", fmt.Errorf("parse report template: %w", err)
+	}
+
+	var rendered bytes.Buffer
+	if err := parsed.Execute(&rendered, digest); err != nil {
+		return "", fmt.Errorf("render report template: %w", err)
+	}
+
+	return rendered.String(), nil
+}
+
+// defaultDigestTemplate keeps its styles inline, since mail clients drop
+// style sheets.
+const defaultDigestTemplate = `<!DOCTYPE html>
+<html>
+<head><meta charset="utf-8"><title>Rollbar triage report</title></head>
+<body style="font-family: -apple-system, Segoe UI, Helvetica, Arial, sans-serif; color: #1f2328; max-width: 860px;">
+<h1 style="font-size: 22px;">Rollbar triage report</h1>
+<p>{{window .}}</p>
+<h2 style="font-size: 18px;">Environments</h2>
+{{- if .Environments}}
+<table style="border-collapse: collapse;" cellpadding="6">
+<tr style="text-align: left; border-bottom: 1px solid #d0d7de;"><th>Environment</th><th>New</th><th>Active</th><th>Resolved</th><th>Regressions</th><th>Occurrences</th></tr>
+{{- range .Environments}}
+<tr><td>{{fallback .Environment}}</td><td>{{.New}}</td><td>{{.Active}}</td><td>{{.Resolved}}</td><td>{{.Regressions}}</td><td>{{.Occurrences}}</td></tr>
+{{- end}}
+</table>
+{{- else}}
+<p>No items in this window.</p>
+{{- end}}
+{{- range sections .}}
+<h2 style="font-size: 18px;">{{.Title}} ({{.Total}})</h2>
+{{- if .Items}}
+<table style="border-collapse: collapse;" cellpadding="6">
+<tr style="text-align: left; border-bottom: 1px solid #d0d7de;"><th>Item</th><th>Level</th><th>Title</th><th>Environment</th><th>Total occurrences</th></tr>
+{{- range .Items}}
+<tr><td>#{{.Counter}}</td><td>{{fallback .Level}}</td><td>{{fallback .Title}}</td><td>{{fallback .Environment}}</td><td>{{occurrences .Occurrences}}</td></tr>
+{{- end}}
+</table>
+{{- if lt (len .Items) .Total}}
+<p>Showing the {{len .Items}} most frequent of {{.Total}}.</p>
+{{- end}}
+{{- else}}
+<p>None.</p>
+{{- end}}
+{{- end}}
+</body>
+</html>
+`
@@ -0,0 +1,78 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func testDigest() app.Digest {
+	occurrences := uint64(40)
+	return app.Digest{
+		Environment:  "production",
+		Since:        1767225600,
+		Until:        1767830400,
+		New:          app.DigestSection{Total: 3, Items: []app.IssueSummary{{Counter: 7, Level: "error", Title: "KeyError: a|b <script>", Environment: "production", Occurrences: &occurrences}}},
+		Top:          app.DigestSection{Total: 1, Items: []app.IssueSummary{{Counter: 7, Level: "error", Title: "KeyError", Environment: "production", Occurrences: &occurrences}}},
+		Resolved:     app.DigestSection{Items: []app.IssueSummary{}},
+		Regressions:  app.DigestSection{Items: []app.IssueSummary{}},
+		Environments: []app.EnvironmentDigest{{Environment: "production", New: 3, Active: 1, Occurrences: 120}},
+	}
+}
+
+func TestRenderDigestMarkdown(t *testing.T) {
+	t.Parallel()
+
+	rendered := RenderDigestMarkdown(testDigest())
+	for _, want := range []string{
+		"# Rollbar triage report\n\nEnvironment production, 2026-01-01 00:00 UTC to 2026-01-08 00:00 UTC.",
+		"| production | 3 | 1 | 0 | 0 | 120 |",
+		"## New items (3)\n\n| Item | Level | Title | Environment | Total occurrences |",
+		`| #7 | error | KeyError: a\|b <script> | production | 40 |`,
+		"Showing the 1 most frequent of 3.",
+		"## Resolved items (0)\n\nNone.",
+	} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+
+	empty := RenderDigestMarkdown(app.Digest{Since: 1767225600, Until: 1767830400})
+	if !strings.Contains(empty, "All environments, 2026-01-01") || !strings.Contains(empty, "No items in this window.") {
+		t.Fatalf("unexpected empty digest:\n%s", empty)
+	}
+}
+
+func TestRenderDigestHTML(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		template string
+		want     []string
+		wantErr  bool
+	}{
+		{
+			name: "default",
+			want: []string{"<h2 style=\"font-size: 18px;\">New items (3)</h2>", "KeyError: a|b &lt;script&gt;", "<p>Showing the 1 most frequent of 3.</p>", "<td>production</td><td>3</td>"},
+		},
+		{
+			name:     "custom",
+			template: `{{range sections .}}{{.Title}}={{.Total}};{{end}} {{time .Since}}`,
+			want:     []string{"New items=3;Top items by occurrences=1;Resolved items=0;Regressions=0; 2026-01-01 00:00 UTC"},
+		},
+		{name: "broken", template: `{{range}`, wantErr: true},
+	}
+	for _, tc := range tests {
+		rendered, err := RenderDigestHTML(testDigest(), tc.template)
+		if (err != nil) != tc.wantErr {
+			t.Fatalf("%s: RenderDigestHTML() error = %v", tc.name, err)
+		}
+		for _, want := range tc.want {
+			if !strings.Contains(rendered, want) {
+				t.Fatalf("%s: expected %q in:\n%s", tc.name, want, rendered)
+			}
+		}
+	}
+}
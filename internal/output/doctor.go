@@ -0,0 +1,21 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// RenderDoctor prints one line per check with its fix indented underneath.
+func RenderDoctor(checks []app.DoctorCheck) string {
+	lines := make([]string, 0, len(checks)*2)
+	for _, check := range checks {
+		lines = append(lines, fmt.Sprintf("%-6s %-10s %s", "["+string(check.Status)+"]", check.Name, check.Detail))
+		if check.Fix != "" {
+			lines = append(lines, strings.Repeat(" ", 18)+"fix: "+check.Fix)
+		}
+	}
+
+	return strings.Join(lines, "\n")
+}
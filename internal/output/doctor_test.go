@@ -0,0 +1,22 @@
+package output
+
+import (
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderDoctor(t *testing.T) {
+	t.Parallel()
+
+	got := RenderDoctor([]app.DoctorCheck{
+		{Name: "api", Status: app.CheckOK, Detail: "reachable"},
+		{Name: "token", Status: app.CheckFail, Detail: "rejected", Fix: "run `rollbaz auth login`"},
+	})
+	want := "[ok]   api        reachable\n" +
+		"[fail] token      rejected\n" +
+		"                  fix: run `rollbaz auth login`"
+	if got != want {
+		t.Fatalf("RenderDoctor() =\n%s\nwant\n%s", got, want)
+	}
+}
@@ -0,0 +1,195 @@
+package output
+
+import (
+	"encoding/csv"
+	"encoding/json"
+	"fmt"
+	"io"
+	"path/filepath"
+	"slices"
+	"strconv"
+	"strings"
+)
+
+type ExportFormat string
+
+const (
+	ExportCSV    ExportFormat = "csv"
+	ExportNDJSON ExportFormat = "ndjson"
+)
+
+// Export columns are the JSON field names of app.IssueSummary and
+// app.OccurrenceSummary. raw is opt-in since it is the whole payload.
+var (
+	ItemColumns              = []string{"counter", "item_id", "title", "status", "level", "environment", "occurrences", "last_occurrence_timestamp", "raw"}
+	DefaultItemColumns       = ItemColumns[:len(ItemColumns)-1]
+	OccurrenceColumns        = []string{"id", "uuid", "timestamp", "environment", "level", "code_version", "host", "person_id", "main_error", "raw"}
+	DefaultOccurrenceColumns = OccurrenceColumns[:len(OccurrenceColumns)-1]
+)
+
+// ExportFormatFor picks the export format: an explicit --as wins, then the
+// --out extension, and NDJSON for stdout.
+func ExportFormatFor(explicit string, path string) (ExportFormat, error) {
+	value := strings.ToLower(strings.TrimSpace(explicit))
+	if value == "" {
+		switch strings.ToLower(filepath.Ext(path)) {
+		case ".csv":
+			value = string(ExportCSV)
+		case "", ".ndjson", ".jsonl":
+			value = string(ExportNDJSON)
+		default:
+			return "", fmt.Errorf("cannot tell the export format from %q; pass --as csv or --as ndjson", path)
+		}
+	}
+
+	switch format := ExportFormat(value); format {
+	case ExportCSV, ExportNDJSON:
+		return format, nil
+	default:
+		return "", fmt.Errorf("unsupported export format %q (use csv or ndjson)", explicit)
+	}
+}
+
+// ParseColumns splits a --columns value, checking each name against allowed.
+// An empty value selects defaults.
+func ParseColumns(value string, allowed []string, defaults []string) ([]string, error) {
+	if strings.TrimSpace(value) == "" {
+		return defaults, nil
+	}
+
+	columns := make([]string, 0)
+	for _, column := range strings.Split(value, ",") {
+		column = strings.ToLower(strings.TrimSpace(column))
+		if !slices.Contains(allowed, column) {
+			return nil, fmt.Errorf("unknown column %q (use %s)", column, strings.Join(allowed, ", "))
+		}
+		columns = append(columns, column)
+	}
+
+	return columns, nil
+}
+
+// RecordWriter streams records as CSV rows or NDJSON lines restricted to the
+// chosen columns, in column order.
+type RecordWriter struct {
+	out     io.Writer
+	csv     *csv.Writer
+	columns []string
+	count   int
+}
+
+// NewRecordWriter starts an export, writing the CSV header straight away so
+// an export with no records still has one.
+func NewRecordWriter(out io.Writer, format ExportFormat, columns []string) (*RecordWriter, error) {
+	writer := &RecordWriter{out: out, columns: columns}
+	if format == ExportCSV {
+		writer.csv = csv.NewWriter(out)
+		if err := writer.csv.Write(columns); err != nil {
+			return nil, fmt.Errorf("write csv header: %w", err)
+		}
+	}
+
+	return writer, nil
+}
+
+// Write encodes each element of records, a slice of JSON-tagged structs, and
+// flushes so every page reaches the output before the next is fetched.
+func (w *RecordWriter) Write(records any) error {
+	generic, err := toGeneric(records)
+	if err != nil {
+		return err
+	}
+	list, ok := generic.([]any)
+	if !ok {
+		return fmt.Errorf("export records must be a list, got %T", generic)
+	}
+
+	for _, record := range list {
+		object, _ := record.(map[string]any)
+		if err := w.writeRecord(object); err != nil {
+			return err
+		}
+		w.count++
+	}
+	if w.csv != nil {
+		w.csv.Flush()
+		if err := w.csv.Error(); err != nil {
+			return fmt.Errorf("write csv: %w", err)
+		}
+	}
+
+	return nil
+}
+
+// Count is the number of records written so far.
+func (w *RecordWriter) Count() int {
+	return w.count
+}
+
+func (w *RecordWriter) writeRecord(object map[string]any) error {
+	if w.csv != nil {
+		row := make([]string, 0, len(w.columns))
+		for _, column := range w.columns {
+			row = append(row, exportCell(object[column]))
+		}
+		if err := w.csv.Write(row); err != nil {
+			return fmt.Errorf("write csv row: %w", err)
+		}
+		return nil
+	}
+
+	line, err := orderedJSON(object, w.columns)
+	if err != nil {
+		return err
+	}
+	if _, err := fmt.Fprintln(w.out, line); err != nil {
+		return fmt.Errorf("write ndjson: %w", err)
+	}
+
+	return nil
+}
+
+func exportCell(value any) string {
+	switch typed := value.(type) {
+	case nil:
+		return ""
+	case string:
+		return typed
+	case json.Number:
+		return typed.String()
+	case bool:
+		return strconv.FormatBool(typed)
+	default:
+		body, err := json.Marshal(typed)
+		if err != nil {
+			return ""
+		}
+		return string(body)
+	}
+}
+
+// orderedJSON encodes the chosen columns as one JSON object, keeping column
+// order rather than the sorted order encoding/json gives maps.
+func orderedJSON(object map[string]any, columns []string) (string, error) {
+	var builder strings.Builder
+	builder.WriteByte('{')
+	for index, column := range columns {
+		if index > 0 {
+			builder.WriteByte(',')
+		}
+		key, err := json.Marshal(column)
+		if err != nil {
+			return "", fmt.Errorf("marshal column %q: %w", column, err)
+		}
+		value, err := json.Marshal(object[column])
+		if err != nil {
+			return "", fmt.Errorf("marshal column %q: %w", column, err)
+		}
+		builder.Write(key)
+		builder.WriteByte(':')
+		builder.Write(value)
+	}
+	builder.WriteByte('}')
+
+	return builder.String(), nil
+}
@@ -0,0 +1,83 @@
+package output
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+)
+
+type exportRow struct {
+	Counter uint64  `json:"counter"`
+	Title   string  `json:"title"`
+	Seen    *uint64 `json:"seen,omitempty"`
+}
+
+func TestExportFormatFor(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		explicit string
+		path     string
+		want     ExportFormat
+		wantErr  bool
+	}{
+		{path: "items.csv", want: ExportCSV},
+		{path: "occ.ndjson", want: ExportNDJSON},
+		{path: "", want: ExportNDJSON},
+		{explicit: "CSV", path: "items.txt", want: ExportCSV},
+		{path: "items.xlsx", wantErr: true},
+		{explicit: "xml", wantErr: true},
+	}
+	for _, tc := range tests {
+		got, err := ExportFormatFor(tc.explicit, tc.path)
+		if (err != nil) != tc.wantErr || got != tc.want {
+			t.Fatalf("ExportFormatFor(%q, %q) = %q, %v", tc.explicit, tc.path, got, err)
+		}
+	}
+}
+
+func TestParseColumns(t *testing.T) {
+	t.Parallel()
+
+	columns, err := ParseColumns(" Title , counter", ItemColumns, DefaultItemColumns)
+	if err != nil || strings.Join(columns, ",") != "title,counter" {
+		t.Fatalf("ParseColumns() = %v, %v", columns, err)
+	}
+	if columns, _ := ParseColumns("", ItemColumns, DefaultItemColumns); len(columns) != len(ItemColumns)-1 {
+		t.Fatalf("expected default columns without raw, got %v", columns)
+	}
+	if _, err := ParseColumns("counter,size", ItemColumns, DefaultItemColumns); err == nil || !strings.Contains(err.Error(), `unknown column "size"`) {
+		t.Fatalf("expected unknown column error, got %v", err)
+	}
+}
+
+func TestRecordWriter(t *testing.T) {
+	t.Parallel()
+
+	seen := uint64(1700000000)
+	rows := []exportRow{{Counter: 1, Title: "a, b", Seen: &seen}, {Counter: 2, Title: "plain"}}
+
+	tests := []struct {
+		format ExportFormat
+		want   string
+	}{
+		{format: ExportCSV, want: "title,counter,seen\n\"a, b\",1,1700000000\nplain,2,\n"},
+		{format: ExportNDJSON, want: "{\"title\":\"a, b\",\"counter\":1,\"seen\":1700000000}\n{\"title\":\"plain\",\"counter\":2,\"seen\":null}\n"},
+	}
+	for _, tc := range tests {
+		var buffer bytes.Buffer
+		writer, err := NewRecordWriter(&buffer, tc.format, []string{"title", "counter", "seen"})
+		if err != nil {
+			t.Fatalf("%s: NewRecordWriter() error = %v", tc.format, err)
+		}
+		if err := writer.Write(rows[:1]); err != nil {
+			t.Fatalf("%s: Write() error = %v", tc.format, err)
+		}
+		if err := writer.Write(rows[1:]); err != nil {
+			t.Fatalf("%s: Write() error = %v", tc.format, err)
+		}
+		if buffer.String() != tc.want || writer.Count() != 2 {
+			t.Fatalf("%s: wrote %q (count %d), want %q", tc.format, buffer.String(), writer.Count(), tc.want)
+		}
+	}
+}
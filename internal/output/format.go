@@ -0,0 +1,175 @@
+package output
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strings"
+)
+
+// Format selects how a command prints its result. Human and github print the
+// rendered view, github leading it with GitHub Actions annotations
+// where the command has them (ci check); the others print the command's JSON
+// payload, which for sarif is a SARIF log from the commands that build one.
+type Format string
+
+const (
+	FormatHuman  Format = "human"
+	FormatJSON   Format = "json"
+	FormatYAML   Format = "yaml"
+	FormatNDJSON Format = "ndjson"
+	FormatGitHub Format = "github"
+	FormatSARIF  Format = "sarif"
+)
+
+var formats = []Format{FormatHuman, FormatJSON, FormatYAML, FormatNDJSON, FormatGitHub, FormatSARIF}
+
+func ParseFormat(value string) (Format, error) {
+	format := Format(strings.ToLower(strings.TrimSpace(value)))
+	for _, known := range formats {
+		if format == known {
+			return format, nil
+		}
+	}
+
+	return "", fmt.Errorf("unsupported format %q (use %s)", value, FormatNames())
+}
+
+// Formats returns the accepted --format values in display order.
+func Formats() []Format {
+	return append([]Format(nil), formats...)
+}
+
+// FormatNames lists the accepted --format values for help and error text.
+func FormatNames() string {
+	names := make([]string, 0, len(formats))
+	for _, format := range formats {
+		names = append(names, string(format))
+	}
+
+	return strings.Join(names, ", ")
+}
+
+// Human reports whether the format prints the rendered view rather than a
+// machine-readable payload.
+func (f Format) Human() bool {
+	return f == FormatHuman || f == FormatGitHub
+}
+
+// Render returns the text to print for a command: human for the rendered
+// formats, otherwise payload encoded as JSON (sarif included), YAML, or NDJSON.
+func Render(format Format, human string, payload any) (string, error) {
+	switch format {
+	case FormatHuman, FormatGitHub:
+		return human, nil
+	case FormatJSON, FormatSARIF:
+		return RenderJSON(payload)
+	case FormatYAML:
+		return RenderYAML(payload)
+	case FormatNDJSON:
+		return RenderNDJSON(payload)
+	default:
+		return "", fmt.Errorf("unsupported format %q", format)
+	}
+}
+
+// RenderFiltered renders the outputs of a --jq filter, which stand in for the
+// payload: each is its own JSON document for json and sarif, one line for
+// ndjson, and a "---"-separated document for yaml.
+func RenderFiltered(format Format, results []any) (string, error) {
+	separator, render := "\n", RenderJSON
+	switch format {
+	case FormatNDJSON:
+		render = renderCompactJSON
+	case FormatYAML:
+		separator, render = "\n---\n", RenderYAML
+	}
+
+	parts := make([]string, 0, len(results))
+	for _, result := range results {
+		part, err := render(result)
+		if err != nil {
+			return "", err
+		}
+		parts = append(parts, part)
+	}
+
+	return strings.Join(parts, separator), nil
+}
+
+func renderCompactJSON(value any) (string, error) {
+	body, err := json.Marshal(value)
+	if err != nil {
+		return "", fmt.Errorf("marshal json output: %w", err)
+	}
+
+	return string(body), nil
+}
+
+// RenderNDJSON writes one compact JSON document per line. A payload wrapping a
+// single list, such as {"issues": [...]}, is unwrapped so each element gets its
+// own line; anything else is written as one line.
+func RenderNDJSON(value any) (string, error) {
+	generic, err := toGeneric(value)
+	if err != nil {
+		return "", err
+	}
+
+	records := []any{generic}
+	if list, ok := singleList(generic); ok {
+		records = list
+	}
+
+	lines := make([]string, 0, len(records))
+	for _, record := range records {
+		line, err := json.Marshal(record)
+		if err != nil {
+			return "", fmt.Errorf("marshal ndjson record: %w", err)
+		}
+		lines = append(lines, string(line))
+	}
+
+	return strings.Join(lines, "\n"), nil
+}
+
+func singleList(value any) ([]any, bool) {
+	object, ok := value.(map[string]any)
+	if !ok || len(object) != 1 {
+		return nil, false
+	}
+	for _, field := range object {
+		list, ok := field.([]any)
+		return list, ok
+	}
+
+	return nil, false
+}
+
+// toGeneric round-trips value through JSON so the text encoders only deal
+// with maps, slices, and scalars, and honour the payload's json tags.
+func toGeneric(value any) (any, error) {
+	body, err := json.Marshal(value)
+	if err != nil {
+		return nil, fmt.Errorf("marshal output: %w", err)
+	}
+
+	decoder := json.NewDecoder(bytes.NewReader(body))
+	decoder.UseNumber()
+	var generic any
+	if err := decoder.Decode(&generic); err != nil {
+		return nil, fmt.Errorf("decode output: %w", err)
+	}
+
+	return generic, nil
+}
+
+func sortedKeys(object map[string]any) []string {
+	keys := make([]string, 0, len(object))
+	for key := range object {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	return keys
+}
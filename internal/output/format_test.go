@@ -0,0 +1,135 @@
+package output
+
+import (
+	"strings"
+	"testing"
+)
+
+type formatRow struct {
+	Counter uint64  `json:"counter"`
+	Title   string  `json:"title"`
+	Seen    *uint64 `json:"seen"`
+}
+
+func TestParseFormat(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		value   string
+		want    Format
+		wantErr bool
+	}{
+		{value: "human", want: FormatHuman},
+		{value: " YAML ", want: FormatYAML},
+		{value: "ndjson", want: FormatNDJSON},
+		{value: "GitHub", want: FormatGitHub},
+		{value: "sarif", want: FormatSARIF},
+		{value: "table", wantErr: true},
+		{value: "xml", wantErr: true},
+	}
+
+	for _, tc := range tests {
+		got, err := ParseFormat(tc.value)
+		if (err != nil) != tc.wantErr || got != tc.want {
+			t.Fatalf("ParseFormat(%q) = %q, %v", tc.value, got, err)
+		}
+	}
+	if !FormatHuman.Human() || !FormatGitHub.Human() || FormatJSON.Human() || FormatSARIF.Human() {
+		t.Fatalf("unexpected Human() classification")
+	}
+}
+
+func TestRender(t *testing.T) {
+	t.Parallel()
+
+	payload := map[string]any{"issues": []formatRow{{Counter: 1, Title: "a"}, {Counter: 2, Title: "b: c"}}}
+
+	tests := []struct {
+		format Format
+		want   string
+	}{
+		{format: FormatHuman, want: "rendered"},
+		{format: FormatGitHub, want: "rendered"},
+		{format: FormatJSON, want: "{\n  \"issues\": [\n    {\n      \"counter\": 1,"},
+		{format: FormatNDJSON, want: "{\"counter\":1,\"seen\":null,\"title\":\"a\"}\n{\"counter\":2,\"seen\":null,\"title\":\"b: c\"}"},
+		{format: FormatYAML, want: "issues:\n  - counter: 1\n    seen: null\n    title: a\n  - counter: 2\n    seen: null\n    title: \"b: c\""},
+	}
+
+	for _, tc := range tests {
+		got, err := Render(tc.format, "rendered", payload)
+		if err != nil || !strings.HasPrefix(got, tc.want) {
+			t.Fatalf("Render(%s) = %q, %v", tc.format, got, err)
+		}
+	}
+	if _, err := Render(Format("xml"), "", payload); err == nil {
+		t.Fatalf("expected unsupported format error")
+	}
+}
+
+func TestRenderFiltered(t *testing.T) {
+	t.Parallel()
+
+	results := []any{map[string]any{"counter": 3}, "boom"}
+	tests := []struct {
+		format Format
+		want   string
+	}{
+		{format: FormatJSON, want: "{\n  \"counter\": 3\n}\n\"boom\""},
+		{format: FormatNDJSON, want: "{\"counter\":3}\n\"boom\""},
+		{format: FormatYAML, want: "counter: 3\n---\nboom"},
+	}
+
+	for _, tc := range tests {
+		if got, err := RenderFiltered(tc.format, results); err != nil || got != tc.want {
+			t.Fatalf("RenderFiltered(%s) = %q, %v", tc.format, got, err)
+		}
+	}
+	if got, err := RenderFiltered(FormatJSON, nil); err != nil || got != "" {
+		t.Fatalf("RenderFiltered() without results = %q, %v", got, err)
+	}
+}
+
+func TestRenderNDJSONSingleRecord(t *testing.T) {
+	t.Parallel()
+
+	got, err := RenderNDJSON(map[string]any{"action": "resolved", "issue": formatRow{Counter: 3}})
+	if err != nil || got != `{"action":"resolved","issue":{"counter":3,"seen":null,"title":""}}` {
+		t.Fatalf("RenderNDJSON() = %q, %v", got, err)
+	}
+}
+
+func TestRenderYAMLScalars(t *testing.T) {
+	t.Parallel()
+
+	value := map[string]any{
+		"empty_list": []string{},
+		"empty_map":  map[string]string{},
+		"flag":       true,
+		"nested":     [][]int{{1, 2}},
+		"number_str": "42",
+		"reserved":   "yes",
+		"multiline":  "a\nb",
+		"blank":      "",
+	}
+
+	got, err := RenderYAML(value)
+	if err != nil {
+		t.Fatalf("RenderYAML() error = %v", err)
+	}
+
+	want := strings.Join([]string{
+		`blank: ""`,
+		"empty_list: []",
+		"empty_map: {}",
+		"flag: true",
+		`multiline: "a\nb"`,
+		"nested:",
+		"  - - 1",
+		"    - 2",
+		`number_str: "42"`,
+		`reserved: "yes"`,
+	}, "\n")
+	if got != want {
+		t.Fatalf("RenderYAML() =\n%s\nwant\n%s", got, want)
+	}
+}
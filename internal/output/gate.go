@@ -0,0 +1,41 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// RenderGateResult prints a CI gate as plain lines that read well in build
+// logs: the verdict, one line per check, then the new items.
+func RenderGateResult(result app.GateResult) string {
+	verdict := "passed"
+	if !result.Passed {
+		verdict = "FAILED"
+	}
+	scope := "all environments"
+	if result.Environment != "" {
+		scope = result.Environment
+	}
+	lines := []string{fmt.Sprintf("ci check %s: %s since %s", verdict, scope, time.Unix(result.Since, 0).UTC().Format(time.RFC3339))}
+
+	for _, check := range result.Checks {
+		status := "ok  "
+		if !check.Passed {
+			status = "FAIL"
+		}
+		lines = append(lines, fmt.Sprintf("%s %s: %d (max %d)", status, check.Name, check.Value, check.Max))
+	}
+
+	if len(result.NewItems) == 0 {
+		return strings.Join(append(lines, "no new items"), "\n")
+	}
+	lines = append(lines, "new items:")
+	for _, issue := range result.NewItems {
+		lines = append(lines, fmt.Sprintf("  #%s [%s] %s (%s occurrences)", issue.Counter, fallback(issue.Level), fallback(issue.Title), formatOccurrences(issue.Occurrences)))
+	}
+
+	return strings.Join(lines, "\n")
+}
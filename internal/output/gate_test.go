@@ -0,0 +1,38 @@
+package output
+
+import (
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderGateResult(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(12)
+	tests := []struct {
+		name   string
+		result app.GateResult
+		want   string
+	}{
+		{
+			name: "passed",
+			result: app.GateResult{Since: 1767225600, Passed: true, Checks: []app.GateCheck{
+				{Name: app.GateOccurrences, Value: 3, Max: 10, Passed: true},
+			}},
+			want: "ci check passed: all environments since 2026-01-01T00:00:00Z\nok   occurrences: 3 (max 10)\nno new items",
+		},
+		{
+			name: "failed",
+			result: app.GateResult{Environment: "staging", Since: 1767225600, Checks: []app.GateCheck{
+				{Name: app.GateNewItems, Value: 1, Max: 0},
+			}, NewItems: []app.IssueSummary{{Counter: 7, Level: "error", Title: "KeyError", Occurrences: &occurrences}}},
+			want: "ci check FAILED: staging since 2026-01-01T00:00:00Z\nFAIL new_items: 1 (max 0)\nnew items:\n  #7 [error] KeyError (12 occurrences)",
+		},
+	}
+	for _, tc := range tests {
+		if got := RenderGateResult(tc.result); got != tc.want {
+			t.Fatalf("%s: RenderGateResult() = %q, want %q", tc.name, got, tc.want)
+		}
+	}
+}
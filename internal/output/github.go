@@ -0,0 +1,70 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// PathLookup maps a frame filename to its path in the checkout, or reports
+// false when the file is not there. source.Root.Path is one.
+type PathLookup func(filename string) (string, bool)
+
+var (
+	annotationData     = strings.NewReplacer("%", "%25", "\r", "%0D", "\n", "%0A")
+	annotationProperty = strings.NewReplacer("%", "%25", "\r", "%0D", "\n", "%0A", ":", "%3A", ",", "%2C")
+)
+
+// RenderGitHubAnnotations prints one GitHub Actions workflow command per item,
+// placed on its most recent frame found in the checkout so GitHub shows it on
+// that line of the pull request. Items without such a frame are annotated
+// without a file, which GitHub lists in the run summary only.
+func RenderGitHubAnnotations(items []app.ItemFrames, lookup PathLookup) string {
+	lines := make([]string, 0, len(items))
+	for _, item := range items {
+		properties := []string{}
+		if file, frame, ok := checkoutFrame(item.Frames, lookup); ok {
+			properties = append(properties, "file="+annotationProperty.Replace(file), fmt.Sprintf("line=%d", frame.Lineno))
+			if frame.Colno > 0 {
+				properties = append(properties, fmt.Sprintf("col=%d", frame.Colno))
+			}
+		}
+		title := fmt.Sprintf("Rollbar #%s: %s occurrences", item.Counter, formatOccurrences(item.Occurrences))
+		properties = append(properties, "title="+annotationProperty.Replace(title))
+
+		lines = append(lines, fmt.Sprintf("::%s %s::%s", annotationCommand(item.Level), strings.Join(properties, ","), annotationData.Replace(fallback(item.Title))))
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+func checkoutFrame(frames []rollbar.Frame, lookup PathLookup) (string, rollbar.Frame, bool) {
+	if lookup == nil {
+		return "", rollbar.Frame{}, false
+	}
+	for _, frame := range frames {
+		if frame.Lineno <= 0 {
+			continue
+		}
+		if file, ok := lookup(frame.Filename); ok {
+			return file, frame, true
+		}
+	}
+
+	return "", rollbar.Frame{}, false
+}
+
+// annotationCommand keeps warnings and lower out of the error annotations,
+// which GitHub caps at ten per step.
+func annotationCommand(level string) string {
+	switch strings.ToLower(level) {
+	case "warning":
+		return "warning"
+	case "info", "debug":
+		return "notice"
+	default:
+		return "error"
+	}
+}
@@ -0,0 +1,55 @@
+package output
+
+import (
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// checkoutLookup finds /srv/app/pay/charge.py at pay/charge.py in the
+// checkout and nothing else.
+func checkoutLookup(filename string) (string, bool) {
+	if filename == "/srv/app/pay/charge.py" {
+		return "pay/charge.py", true
+	}
+	return "", false
+}
+
+func TestRenderGitHubAnnotations(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(4)
+	library := rollbar.Frame{Filename: "/usr/lib/python3/json.py", Lineno: 300}
+	charge := rollbar.Frame{Filename: "/srv/app/pay/charge.py", Lineno: 12, Colno: 5}
+
+	tests := []struct {
+		name   string
+		item   app.ItemFrames
+		lookup PathLookup
+		want   string
+	}{
+		{
+			name:   "placed on the first frame in the checkout",
+			lookup: checkoutLookup,
+			item:   app.ItemFrames{IssueSummary: app.IssueSummary{Counter: 7, Level: "error", Title: "KeyError: 'a,b'\nline two", Occurrences: &occurrences}, Frames: []rollbar.Frame{library, charge}},
+			want:   "::error file=pay/charge.py,line=12,col=5,title=Rollbar #7%3A 4 occurrences::KeyError: 'a,b'%0Aline two",
+		},
+		{
+			name:   "warning without a local frame",
+			lookup: checkoutLookup,
+			item:   app.ItemFrames{IssueSummary: app.IssueSummary{Counter: 8, Level: "warning", Title: "100% slow"}, Frames: []rollbar.Frame{library}},
+			want:   "::warning title=Rollbar #8%3A unknown occurrences::100%25 slow",
+		},
+		{
+			name: "info without a lookup",
+			item: app.ItemFrames{IssueSummary: app.IssueSummary{Counter: 9, Level: "info", Title: "hello"}, Frames: []rollbar.Frame{charge}},
+			want: "::notice title=Rollbar #9%3A unknown occurrences::hello",
+		},
+	}
+	for _, tc := range tests {
+		if got := RenderGitHubAnnotations([]app.ItemFrames{tc.item}, tc.lookup); got != tc.want {
+			t.Fatalf("%s: RenderGitHubAnnotations() = %q, want %q", tc.name, got, tc.want)
+		}
+	}
+}
@@ -12,6 +12,7 @@ import (
 	prettytext "github.com/jedib0t/go-pretty/v6/text"
 
 	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
 )
 
 const (
@@ -23,6 +24,10 @@ const (
 	minDetailValueWidth   = 40
 	maxDetailValueWidth   = 100
 	detailNonValueWidth   = 20
+	detailTraceFrames     = 5
+	itemTableNonTitle     = 60
+	// extraColumnWidth caps each --column column.
+	extraColumnWidth = 30
 )
 
 func RenderIssueListHuman(issues []app.IssueSummary) string {
@@ -53,12 +58,93 @@ func RenderIssueListHumanWithWidth(issues []app.IssueSummary, maxWidth int) stri
 	return strings.TrimRight(tw.Render(), "\n")
 }
 
+// RenderItemTableWithWidth renders the `items list` table, which leads with
+// level rather than status and environment since those are usually filtered on.
+func RenderItemTableWithWidth(issues []app.IssueSummary, maxWidth int) string {
+	return RenderItemTableWithColumns(issues, nil, maxWidth)
+}
+
+// RenderItemTableWithColumns is the `items list` table with a column after the
+// title for each --column path.
+func RenderItemTableWithColumns(issues []app.IssueSummary, columns []string, maxWidth int) string {
+	if len(issues) == 0 {
+		return "no issues found"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	titleWidth := min(max(targetWidth-itemTableNonTitle-len(columns)*extraColumnWidth, minListTitleWidth), maxListTitleWidth)
+
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs(append([]table.ColumnConfig{
+		{Number: 3, WidthMax: titleWidth, WidthMaxEnforcer: prettytext.Trim},
+		{Number: 4 + len(columns), Align: prettytext.AlignRight},
+	}, extraColumnConfigs(4, columns)...))
+	tw.AppendHeader(append(append(table.Row{"COUNTER", "LEVEL", "TITLE"}, columnHeaders(columns)...), "OCCURRENCES", "LAST_SEEN"))
+
+	for _, issue := range issues {
+		row := append(table.Row{issue.Counter.String(), fallback(issue.Level), fallback(issue.Title)}, columnCells(issue.Columns, columns)...)
+		tw.AppendRow(append(row, formatOccurrences(issue.Occurrences), formatTimestamp(issue.LastOccurrenceTimestamp)))
+	}
+
+	return strings.TrimRight(tw.Render(), "\n")
+}
+
+// extraColumnConfigs caps the width of the --column columns, numbered from
+// first.
+func extraColumnConfigs(first int, columns []string) []table.ColumnConfig {
+	configs := make([]table.ColumnConfig, 0, len(columns))
+	for index := range columns {
+		configs = append(configs, table.ColumnConfig{Number: first + index, WidthMax: extraColumnWidth, WidthMaxEnforcer: prettytext.Trim})
+	}
+
+	return configs
+}
+
+func columnHeaders(columns []string) table.Row {
+	row := make(table.Row, 0, len(columns))
+	for _, column := range columns {
+		row = append(row, column)
+	}
+
+	return row
+}
+
+func columnCells(values map[string]string, columns []string) table.Row {
+	row := make(table.Row, 0, len(columns))
+	for _, column := range columns {
+		row = append(row, fallback(values[column]))
+	}
+
+	return row
+}
+
 func RenderIssueDetailHuman(detail app.IssueDetail) string {
 	return RenderIssueDetailHumanWithWidth(detail, defaultDetailRowWidth)
 }
 
 func RenderIssueDetailHumanWithWidth(detail app.IssueDetail, maxWidth int) string {
+	return RenderIssueDetailHumanWithSource(detail, maxWidth, nil)
+}
+
+// RenderIssueDetailHumanWithSource is RenderIssueDetailHumanWithWidth with
+// local source lines, when lookup finds them, under each stack frame.
+func RenderIssueDetailHumanWithSource(detail app.IssueDetail, maxWidth int, lookup SourceLookup) string {
 	valueWidth := detailValueWidth(maxWidth)
+	rendered := renderDetailTable(detail, maxWidth, valueWidth)
+	if shouldIncludeMainErrorLine(detail) {
+		heading := "Main Error: " + prettytext.Trim(fallback(detail.MainError), valueWidth)
+		rendered = heading + "\n\n" + rendered
+	}
+	if trace, ok := topOfTrace(detail, lookup); ok {
+		rendered += "\n\nStack Trace:\n" + trace
+	}
+
+	return rendered
+}
+
+func renderDetailTable(detail app.IssueDetail, maxWidth int, valueWidth int) string {
 	rowWidth := normalizeWidth(maxWidth, defaultDetailRowWidth)
 
 	tw := table.NewWriter()
@@ -69,18 +155,30 @@ func RenderIssueDetailHumanWithWidth(detail app.IssueDetail, maxWidth int) strin
 	})
 	tw.AppendRow(table.Row{"Title", fallback(detail.Title)})
 	tw.AppendRow(table.Row{"Status", fallback(detail.Status)})
+	tw.AppendRow(table.Row{"Level", fallback(detail.Level)})
 	tw.AppendRow(table.Row{"Environment", fallback(detail.Environment)})
 	tw.AppendRow(table.Row{"Occurrences", formatOccurrences(detail.Occurrences)})
+	tw.AppendRow(table.Row{"First Seen", formatTimestamp(detail.FirstOccurrenceTimestamp)})
+	tw.AppendRow(table.Row{"Last Seen", formatTimestamp(detail.LastOccurrenceTimestamp)})
 	tw.AppendRow(table.Row{"Counter", detail.Counter.String()})
 	tw.AppendRow(table.Row{"Item ID", detail.ItemID.String()})
 
-	renderedTable := strings.TrimRight(tw.Render(), "\n")
-	if shouldIncludeMainErrorLine(detail) {
-		heading := "Main Error: " + prettytext.Trim(fallback(detail.MainError), valueWidth)
-		return heading + "\n\n" + renderedTable
+	return strings.TrimRight(tw.Render(), "\n")
+}
+
+// topOfTrace renders the most recent frames of the latest occurrence. Message
+// and crash report bodies are already covered by the main error line.
+func topOfTrace(detail app.IssueDetail, lookup SourceLookup) (string, bool) {
+	if detail.Instance == nil {
+		return "", false
+	}
+
+	body := detail.Instance.OccurrenceBody()
+	if body.Kind != rollbar.BodyKindTrace && body.Kind != rollbar.BodyKindTraceChain {
+		return "", false
 	}
 
-	return renderedTable
+	return RenderOccurrenceBody(body, TraceOptions{MaxFrames: detailTraceFrames, Source: lookup}), true
 }
 
 func RenderJSON(value any) (string, error) {
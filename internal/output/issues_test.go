@@ -1,12 +1,15 @@
 package output
 
 import (
+	"encoding/json"
 	"math"
 	"strings"
 	"testing"
+	"unicode/utf8"
 
 	"github.com/kevinsheth/rollbaz/internal/app"
 	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
 )
 
 func TestRenderIssueListHuman(t *testing.T) {
@@ -86,6 +89,48 @@ func TestRenderJSON(t *testing.T) {
 	}
 }
 
+func TestRenderItemTableWithWidth(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(10)
+	lastSeen := uint64(1700000000)
+	issues := []app.IssueSummary{{Counter: 269, Level: "error", Title: strings.Repeat("t", 200), Occurrences: &occurrences, LastOccurrenceTimestamp: &lastSeen}}
+
+	got := RenderItemTableWithWidth(issues, 100)
+	for _, want := range []string{"COUNTER", "LEVEL", "LAST_SEEN", "269", "error", "2023-11-14T22:13:20Z"} {
+		if !strings.Contains(got, want) {
+			t.Fatalf("expected %q in output:\n%s", want, got)
+		}
+	}
+	for _, line := range strings.Split(got, "\n") {
+		if width := utf8.RuneCountInString(line); width > 100 {
+			t.Fatalf("expected table within width, got %d: %q", width, line)
+		}
+	}
+	if got := RenderItemTableWithWidth(nil, 100); got != "no issues found" {
+		t.Fatalf("unexpected empty output: %q", got)
+	}
+}
+
+func TestRenderItemTableWithColumns(t *testing.T) {
+	t.Parallel()
+
+	issues := []app.IssueSummary{
+		{Counter: 1, Level: "error", Title: "boom", Columns: map[string]string{"data.custom.tenant_id": "acme"}},
+		{Counter: 2, Level: "error", Title: "bang", Columns: map[string]string{"data.custom.tenant_id": strings.Repeat("x", 80)}},
+	}
+
+	got := RenderItemTableWithColumns(issues, []string{"data.custom.tenant_id"}, 160)
+	for _, want := range []string{"DATA.CUSTOM.TENANT_ID", "acme", strings.Repeat("x", extraColumnWidth)} {
+		if !strings.Contains(got, want) {
+			t.Fatalf("expected %q in output:\n%s", want, got)
+		}
+	}
+	if strings.Contains(got, strings.Repeat("x", extraColumnWidth+1)) {
+		t.Fatalf("expected column capped at %d:\n%s", extraColumnWidth, got)
+	}
+}
+
 func TestRenderIssueDetailHuman(t *testing.T) {
 	t.Parallel()
 
@@ -96,6 +141,30 @@ func TestRenderIssueDetailHuman(t *testing.T) {
 	}
 }
 
+func TestRenderIssueDetailHumanIncludesTopOfTrace(t *testing.T) {
+	t.Parallel()
+
+	firstSeen := uint64(1700000000)
+	instance := &rollbar.ItemInstance{Data: json.RawMessage(`{"body":{"trace":{"exception":{"class":"KeyError","message":"id"},"frames":[{"filename":"a.py","lineno":1,"method":"outer"},{"filename":"b.py","lineno":2,"method":"inner"}]}}}`)}
+	detail := app.IssueDetail{
+		IssueSummary:             app.IssueSummary{Counter: 1, Level: "error"},
+		FirstOccurrenceTimestamp: &firstSeen,
+		Instance:                 instance,
+	}
+
+	got := RenderIssueDetailHuman(detail)
+	for _, want := range []string{"Level", "error", "First Seen", "2023-11-14T22:13:20Z", "Stack Trace:\nKeyError: id\n  at inner (b.py:2)\n  at outer (a.py:1)"} {
+		if !strings.Contains(got, want) {
+			t.Fatalf("expected %q in output:\n%s", want, got)
+		}
+	}
+
+	detail.Instance = &rollbar.ItemInstance{Data: json.RawMessage(`{"body":{"message":{"body":"hello"}}}`)}
+	if got := RenderIssueDetailHuman(detail); strings.Contains(got, "Stack Trace:") {
+		t.Fatalf("expected no trace section for message body:\n%s", got)
+	}
+}
+
 func TestRenderIssueDetailHumanTruncatesMainError(t *testing.T) {
 	t.Parallel()
 
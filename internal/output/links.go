@@ -0,0 +1,35 @@
+package output
+
+import (
+	"strconv"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const serviceLinkNonTemplate = 40
+
+// RenderServiceLinksWithWidth lists service links a row each.
+func RenderServiceLinksWithWidth(links []rollbar.ServiceLink, maxWidth int) string {
+	if len(links) == 0 {
+		return "no service links found"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 3, WidthMax: max(targetWidth-serviceLinkNonTemplate, minListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	tw.AppendHeader(table.Row{"ID", "NAME", "URL TEMPLATE"})
+
+	for _, link := range links {
+		tw.AppendRow(table.Row{strconv.FormatUint(link.ID, 10), fallback(link.Name), link.Template})
+	}
+
+	return strings.TrimRight(tw.Render(), "\n")
+}
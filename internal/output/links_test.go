@@ -0,0 +1,23 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderServiceLinks(t *testing.T) {
+	t.Parallel()
+
+	links := []rollbar.ServiceLink{{ID: 3, Name: "Runbook", Template: "https://runbooks.example.com/{{ title }}"}}
+	rendered := RenderServiceLinksWithWidth(links, 160)
+	for _, want := range []string{"URL TEMPLATE", "3", "Runbook", "https://runbooks.example.com/{{ title }}"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+	if got := RenderServiceLinksWithWidth(nil, 120); got != "no service links found" {
+		t.Fatalf("unexpected empty render %q", got)
+	}
+}
@@ -0,0 +1,54 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// RenderPrometheus renders the last successful scrape in the Prometheus text
+// exposition format. scrapeOK reports whether the most recent scrape
+// succeeded, so a failing API shows up as rollbar_exporter_scrape_success 0
+// while the last good values keep being served.
+func RenderPrometheus(metrics app.ItemMetrics, scrapeOK bool) string {
+	var builder strings.Builder
+
+	writeMetricHeader(&builder, "rollbar_item_occurrences_total", "counter", "Total occurrences of each active Rollbar item.")
+	for _, item := range metrics.Items {
+		occurrences := uint64(0)
+		if item.Occurrences != nil {
+			occurrences = *item.Occurrences
+		}
+		fmt.Fprintf(&builder, "rollbar_item_occurrences_total{item=%s,level=%s,environment=%s} %d\n",
+			promLabel(item.Counter.String()), promLabel(item.Level), promLabel(item.Environment), occurrences)
+	}
+
+	writeMetricHeader(&builder, "rollbar_active_items", "gauge", "Active Rollbar items by level and environment.")
+	for _, active := range metrics.Active {
+		fmt.Fprintf(&builder, "rollbar_active_items{level=%s,environment=%s} %d\n", promLabel(active.Level), promLabel(active.Environment), active.Count)
+	}
+
+	success := 0
+	if scrapeOK {
+		success = 1
+	}
+	writeMetricHeader(&builder, "rollbar_exporter_scrape_success", "gauge", "Whether the last scrape of the Rollbar API succeeded.")
+	fmt.Fprintf(&builder, "rollbar_exporter_scrape_success %d\n", success)
+	if !metrics.ScrapedAt.IsZero() {
+		writeMetricHeader(&builder, "rollbar_exporter_last_scrape_timestamp_seconds", "gauge", "Unix time of the last successful scrape.")
+		fmt.Fprintf(&builder, "rollbar_exporter_last_scrape_timestamp_seconds %d\n", metrics.ScrapedAt.Unix())
+	}
+
+	return builder.String()
+}
+
+func writeMetricHeader(builder *strings.Builder, name string, kind string, help string) {
+	fmt.Fprintf(builder, "# HELP %s %s\n# TYPE %s %s\n", name, help, name, kind)
+}
+
+// promLabel quotes a label value, escaping what the exposition format
+// requires.
+func promLabel(value string) string {
+	return `"` + strings.NewReplacer(`\`, `\\`, `"`, `\"`, "\n", `\n`).Replace(value) + `"`
+}
@@ -0,0 +1,39 @@
+package output
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderPrometheus(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(42)
+	metrics := app.ItemMetrics{
+		Items:     []app.IssueSummary{{Counter: 269, Level: "error", Environment: `prod "eu"`, Occurrences: &occurrences}},
+		Active:    []app.ActiveItemCount{{Level: "error", Environment: `prod "eu"`, Count: 1}},
+		ScrapedAt: time.Unix(1700000000, 0),
+	}
+
+	got := RenderPrometheus(metrics, true)
+	for _, want := range []string{
+		"# TYPE rollbar_item_occurrences_total counter\n",
+		`rollbar_item_occurrences_total{item="269",level="error",environment="prod \"eu\""} 42` + "\n",
+		"# TYPE rollbar_active_items gauge\n",
+		`rollbar_active_items{level="error",environment="prod \"eu\""} 1` + "\n",
+		"rollbar_exporter_scrape_success 1\n",
+		"rollbar_exporter_last_scrape_timestamp_seconds 1700000000\n",
+	} {
+		if !strings.Contains(got, want) {
+			t.Fatalf("expected %q in:\n%s", want, got)
+		}
+	}
+
+	empty := RenderPrometheus(app.ItemMetrics{}, false)
+	if !strings.Contains(empty, "rollbar_exporter_scrape_success 0\n") || strings.Contains(empty, "last_scrape_timestamp") {
+		t.Fatalf("unexpected output before a successful scrape:\n%s", empty)
+	}
+}
@@ -0,0 +1,88 @@
+package output
+
+import (
+	"fmt"
+	"sort"
+	"strconv"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	notificationFiltersWidth = 40
+	notificationNonConfig    = 86
+)
+
+// RenderNotificationRulesWithWidth lists notification rules a row each,
+// grouped by channel. Credentials in a rule's config, such as a PagerDuty
+// service key, are masked.
+func RenderNotificationRulesWithWidth(channels []app.ChannelRules, maxWidth int) string {
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 4, WidthMax: notificationFiltersWidth, WidthMaxEnforcer: prettytext.Trim},
+		{Number: 5, WidthMax: max(targetWidth-notificationNonConfig, minListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	tw.AppendHeader(table.Row{"CHANNEL", "ID", "TRIGGER", "FILTERS", "CONFIG"})
+
+	rows := 0
+	for _, channel := range channels {
+		for _, rule := range channel.Rules {
+			tw.AppendRow(table.Row{channel.Channel, strconv.FormatUint(rule.ID, 10), fallback(rule.Trigger), formatNotificationFilters(rule.Filters), formatNotificationConfig(rule.Config)})
+			rows++
+		}
+	}
+	if rows == 0 {
+		return "no notification rules found"
+	}
+
+	return strings.TrimRight(tw.Render(), "\n")
+}
+
+func formatNotificationFilters(filters []rollbar.NotificationFilter) string {
+	if len(filters) == 0 {
+		return "-"
+	}
+
+	formatted := make([]string, 0, len(filters))
+	for _, filter := range filters {
+		if filter.Type == "rate" {
+			formatted = append(formatted, fmt.Sprintf("rate %d in %ds", filter.Count, filter.Period))
+			continue
+		}
+		formatted = append(formatted, strings.TrimSpace(filter.Type+" "+filter.Operation+" "+filter.Value))
+	}
+
+	return strings.Join(formatted, ", ")
+}
+
+func formatNotificationConfig(config map[string]any) string {
+	if len(config) == 0 {
+		return "-"
+	}
+
+	keys := make([]string, 0, len(config))
+	for key := range config {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+
+	formatted := make([]string, 0, len(keys))
+	for _, key := range keys {
+		value := fmt.Sprint(config[key])
+		if redact.IsSensitiveKey(key) {
+			value = redact.Placeholder
+		}
+		formatted = append(formatted, key+"="+value)
+	}
+
+	return strings.Join(formatted, ", ")
+}
@@ -0,0 +1,38 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderNotificationRules(t *testing.T) {
+	t.Parallel()
+
+	channels := []app.ChannelRules{
+		{Channel: "email", Rules: []rollbar.NotificationRule{}},
+		{Channel: "slack", Rules: []rollbar.NotificationRule{{
+			ID:      5,
+			Trigger: "new_item",
+			Filters: []rollbar.NotificationFilter{{Type: "environment", Operation: "eq", Value: "production"}, {Type: "rate", Period: 300, Count: 10}},
+			Config:  map[string]any{"channel": "#alerts", "show_message_buttons": true},
+		}}},
+		{Channel: "pagerduty", Rules: []rollbar.NotificationRule{{ID: 6, Trigger: "deploy", Config: map[string]any{"service_key": "pd-secret"}}}},
+	}
+
+	rendered := RenderNotificationRulesWithWidth(channels, 200)
+	for _, want := range []string{"CHANNEL", "slack", "new_item", "environment eq production, rate 10 in 300s", "channel=#alerts, show_message_buttons=true", "service_key=[REDACTED]"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+	if strings.Contains(rendered, "pd-secret") {
+		t.Fatalf("expected the service key masked:\n%s", rendered)
+	}
+
+	if got := RenderNotificationRulesWithWidth(channels[:1], 120); got != "no notification rules found" {
+		t.Fatalf("unexpected empty render %q", got)
+	}
+}
@@ -0,0 +1,73 @@
+package output
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// maxDiffValueWidth trims long values such as whole request bodies so each
+// change stays on one readable line; --format json keeps them in full.
+const maxDiffValueWidth = 120
+
+// RenderOccurrenceDiff prints both occurrences, then each section's changes
+// marked "+" added, "-" removed, or "~" changed.
+func RenderOccurrenceDiff(diff app.OccurrenceDiff) string {
+	lines := []string{
+		occurrenceDiffHeading("before", diff.Before),
+		occurrenceDiffHeading("after", diff.After),
+	}
+
+	sections := []struct {
+		title   string
+		changes []app.DiffChange
+	}{
+		{title: "Versions", changes: diff.Versions},
+		{title: "Request", changes: diff.Request},
+		{title: "Stack trace", changes: diff.Trace},
+		{title: "Other", changes: diff.Other},
+	}
+	for _, section := range sections {
+		lines = append(lines, "", section.title)
+		if len(section.changes) == 0 {
+			lines = append(lines, "  no changes")
+			continue
+		}
+		for _, change := range section.changes {
+			lines = append(lines, "  "+renderDiffChange(change))
+		}
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+func occurrenceDiffHeading(label string, occurrence app.OccurrenceSummary) string {
+	return fmt.Sprintf("%-7s %s  %s  version %s", label, fallback(occurrence.UUID), formatTimestamp(occurrence.Timestamp), fallback(occurrence.CodeVersion))
+}
+
+func renderDiffChange(change app.DiffChange) string {
+	switch change.Kind {
+	case app.DiffAdded:
+		return "+ " + change.Path + ": " + diffValue(change.After)
+	case app.DiffRemoved:
+		return "- " + change.Path + ": " + diffValue(change.Before)
+	default:
+		return "~ " + change.Path + ": " + diffValue(change.Before) + " -> " + diffValue(change.After)
+	}
+}
+
+func diffValue(value any) string {
+	body, err := json.Marshal(value)
+	if err != nil {
+		return fmt.Sprint(value)
+	}
+
+	text := []rune(string(body))
+	if len(text) > maxDiffValueWidth {
+		return string(text[:maxDiffValueWidth-3]) + "..."
+	}
+
+	return string(text)
+}
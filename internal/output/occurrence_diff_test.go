@@ -0,0 +1,36 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderOccurrenceDiff(t *testing.T) {
+	t.Parallel()
+
+	timestamp := uint64(1700000000)
+	diff := app.OccurrenceDiff{
+		Before:   app.OccurrenceSummary{UUID: "u-1", Timestamp: &timestamp, CodeVersion: "v1"},
+		After:    app.OccurrenceSummary{UUID: "u-2", CodeVersion: "v2"},
+		Versions: []app.DiffChange{{Path: "code_version", Kind: app.DiffChanged, Before: "v1", After: "v2"}},
+		Request: []app.DiffChange{
+			{Path: "request.GET.debug", Kind: app.DiffAdded, After: "1"},
+			{Path: "request.body", Kind: app.DiffRemoved, Before: strings.Repeat("x", 200)},
+		},
+	}
+
+	got := RenderOccurrenceDiff(diff)
+	for _, want := range []string{
+		"before  u-1  2023-11-14",
+		"after   u-2  ",
+		"Versions\n  ~ code_version: \"v1\" -> \"v2\"",
+		"Request\n  + request.GET.debug: \"1\"\n  - request.body: \"xxx",
+		"...\n\nStack trace\n  no changes",
+	} {
+		if !strings.Contains(got, want) {
+			t.Fatalf("expected %q in:\n%s", want, got)
+		}
+	}
+}
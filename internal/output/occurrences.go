@@ -0,0 +1,126 @@
+package output
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const occurrenceNonMessageWidth = 110
+
+func RenderOccurrenceListHumanWithWidth(occurrences []app.OccurrenceSummary, maxWidth int) string {
+	return RenderOccurrenceListWithColumns(occurrences, nil, maxWidth)
+}
+
+// RenderOccurrenceListWithColumns is the occurrence table with a column before
+// the message for each --column path.
+func RenderOccurrenceListWithColumns(occurrences []app.OccurrenceSummary, columns []string, maxWidth int) string {
+	if len(occurrences) == 0 {
+		return "no occurrences found"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	messageWidth := min(max(targetWidth-occurrenceNonMessageWidth-len(columns)*extraColumnWidth, minListTitleWidth), maxListTitleWidth)
+
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetColumnConfigs(append([]table.ColumnConfig{
+		{Number: 7 + len(columns), WidthMax: messageWidth, WidthMaxEnforcer: prettytext.Trim},
+	}, extraColumnConfigs(7, columns)...))
+	tw.AppendHeader(append(append(table.Row{"ID", "TIMESTAMP", "UUID", "ENV", "LEVEL", "VERSION"}, columnHeaders(columns)...), "MESSAGE"))
+
+	for _, occurrence := range occurrences {
+		row := table.Row{
+			strconv.FormatUint(occurrence.ID, 10),
+			formatTimestamp(occurrence.Timestamp),
+			fallback(occurrence.UUID),
+			fallback(occurrence.Environment),
+			fallback(occurrence.Level),
+			fallback(occurrence.CodeVersion),
+		}
+		tw.AppendRow(append(append(row, columnCells(occurrence.Columns, columns)...), fallback(occurrence.MainError)))
+	}
+
+	return strings.TrimRight(tw.Render(), "\n")
+}
+
+// RenderOccurrencesFull prints each occurrence's complete payload as indented
+// JSON under a one-line heading, for `occurrences --full`.
+func RenderOccurrencesFull(occurrences []app.OccurrenceSummary) string {
+	if len(occurrences) == 0 {
+		return "no occurrences found"
+	}
+
+	sections := make([]string, 0, len(occurrences))
+	for _, occurrence := range occurrences {
+		heading := "Occurrence " + strconv.FormatUint(occurrence.ID, 10) + " at " + formatTimestamp(occurrence.Timestamp)
+		if occurrence.UUID != "" {
+			heading += " (" + occurrence.UUID + ")"
+		}
+		sections = append(sections, heading+"\n"+indentJSON(occurrence.Raw))
+	}
+
+	return strings.Join(sections, "\n\n")
+}
+
+// RenderOccurrenceDetail prints one occurrence's fields followed by its full
+// stack trace, or its message or raw body for other kinds.
+// RenderOccurrenceSample lists the files an occurrence sample was written to,
+// oldest occurrence first; files lines up with sample.Occurrences.
+func RenderOccurrenceSample(sample app.OccurrenceSample, files []string) string {
+	if len(sample.Occurrences) == 0 {
+		return "no occurrences found"
+	}
+
+	lines := []string{fmt.Sprintf("sampled %d of %d occurrences Rollbar keeps for the item", len(sample.Occurrences), sample.Available)}
+	for index, occurrence := range sample.Occurrences {
+		lines = append(lines, fmt.Sprintf("  %s  %d  %s", formatTimestamp(occurrence.Timestamp), occurrence.ID, files[index]))
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+func RenderOccurrenceDetail(detail app.OccurrenceDetail, options TraceOptions) string {
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.AppendRow(table.Row{"ID", strconv.FormatUint(detail.ID, 10)})
+	tw.AppendRow(table.Row{"UUID", fallback(detail.UUID)})
+	tw.AppendRow(table.Row{"Timestamp", formatTimestamp(detail.Timestamp)})
+	tw.AppendRow(table.Row{"Environment", fallback(detail.Environment)})
+	tw.AppendRow(table.Row{"Level", fallback(detail.Level)})
+	tw.AppendRow(table.Row{"Code Version", fallback(detail.CodeVersion)})
+	tw.AppendRow(table.Row{"Host", fallback(detail.Host)})
+	tw.AppendRow(table.Row{"Person", fallback(detail.PersonID)})
+
+	heading := "Body:"
+	if detail.Body.Kind == rollbar.BodyKindTrace || detail.Body.Kind == rollbar.BodyKindTraceChain {
+		heading = "Stack Trace:"
+	}
+	rendered := strings.TrimRight(tw.Render(), "\n") + "\n\n" + heading + "\n" + RenderOccurrenceBody(detail.Body, options)
+	if detail.ResolvedFrames > 0 {
+		rendered += "\n\n" + strconv.Itoa(detail.ResolvedFrames) + " frames resolved through source maps"
+	}
+
+	return rendered
+}
+
+func indentJSON(raw json.RawMessage) string {
+	if len(raw) == 0 {
+		return "{}"
+	}
+
+	var indented bytes.Buffer
+	if err := json.Indent(&indented, raw, "", "  "); err != nil {
+		return string(raw)
+	}
+
+	return indented.String()
+}
@@ -0,0 +1,110 @@
+package output
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderOccurrenceListHumanWithWidth(t *testing.T) {
+	t.Parallel()
+
+	timestamp := uint64(1700000000)
+	occurrences := []app.OccurrenceSummary{{ID: 12, UUID: "abc-123", Timestamp: &timestamp, Environment: "production", Level: "error", MainError: "boom"}}
+
+	got := RenderOccurrenceListHumanWithWidth(occurrences, 160)
+	for _, want := range []string{"TIMESTAMP", "12", "abc-123", "2023-11-14T22:13:20Z", "production", "boom", "unknown"} {
+		if !strings.Contains(got, want) {
+			t.Fatalf("expected %q in output:\n%s", want, got)
+		}
+	}
+	if got := RenderOccurrenceListHumanWithWidth(nil, 160); got != "no occurrences found" {
+		t.Fatalf("unexpected empty output: %q", got)
+	}
+}
+
+func TestRenderOccurrenceListWithColumns(t *testing.T) {
+	t.Parallel()
+
+	occurrences := []app.OccurrenceSummary{{ID: 12, MainError: "boom", Columns: map[string]string{"data.request.url": "https://example.com/pay"}}, {ID: 13}}
+
+	got := RenderOccurrenceListWithColumns(occurrences, []string{"data.request.url"}, 200)
+	for _, want := range []string{"DATA.REQUEST.URL", "https://example.com/pay", "boom"} {
+		if !strings.Contains(got, want) {
+			t.Fatalf("expected %q in output:\n%s", want, got)
+		}
+	}
+	if header := strings.Split(got, "\n")[1]; strings.Index(header, "DATA.REQUEST.URL") > strings.Index(header, "MESSAGE") {
+		t.Fatalf("expected the column before the message:\n%s", got)
+	}
+}
+
+func TestRenderOccurrencesFull(t *testing.T) {
+	t.Parallel()
+
+	occurrences := []app.OccurrenceSummary{
+		{ID: 1, UUID: "u-1", Raw: json.RawMessage(`{"id":1,"data":{"level":"error"}}`)},
+		{ID: 2, Raw: json.RawMessage(`not json`)},
+	}
+
+	got := RenderOccurrencesFull(occurrences)
+	want := "Occurrence 1 at unknown (u-1)\n{\n  \"id\": 1,\n  \"data\": {\n    \"level\": \"error\"\n  }\n}\n\nOccurrence 2 at unknown\nnot json"
+	if got != want {
+		t.Fatalf("RenderOccurrencesFull() =\n%s", got)
+	}
+	if got := RenderOccurrencesFull(nil); got != "no occurrences found" {
+		t.Fatalf("unexpected empty output: %q", got)
+	}
+}
+
+func TestRenderOccurrenceSample(t *testing.T) {
+	t.Parallel()
+
+	timestamp := uint64(1700000000)
+	sample := app.OccurrenceSample{Available: 40, Occurrences: []rollbar.ItemInstance{{ID: 3, Timestamp: &timestamp}, {ID: 9}}}
+	got := RenderOccurrenceSample(sample, []string{"out/001.json", "out/002.json"})
+	want := "sampled 2 of 40 occurrences Rollbar keeps for the item\n  2023-11-14T22:13:20Z  3  out/001.json\n  unknown  9  out/002.json"
+	if got != want {
+		t.Fatalf("RenderOccurrenceSample() =\n%s", got)
+	}
+	if got := RenderOccurrenceSample(app.OccurrenceSample{}, nil); got != "no occurrences found" {
+		t.Fatalf("unexpected empty output: %q", got)
+	}
+}
+
+func TestRenderOccurrenceDetail(t *testing.T) {
+	t.Parallel()
+
+	trace := rollbar.Trace{Exception: rollbar.Exception{Class: "TypeError", Message: "x is undefined"}, Frames: []rollbar.Frame{{Filename: "src/pay.ts", Lineno: 42, Method: "charge"}}}
+	tests := []struct {
+		name   string
+		detail app.OccurrenceDetail
+		want   []string
+	}{
+		{
+			name: "trace",
+			detail: app.OccurrenceDetail{
+				OccurrenceSummary: app.OccurrenceSummary{ID: 7, UUID: "u-7", CodeVersion: "abc"},
+				Body:              rollbar.OccurrenceBody{Kind: rollbar.BodyKindTrace, Trace: &trace},
+				ResolvedFrames:    1,
+			},
+			want: []string{"u-7", "Code Version", "Stack Trace:\nTypeError: x is undefined\n  at charge (src/pay.ts:42)", "1 frames resolved through source maps"},
+		},
+		{
+			name:   "message",
+			detail: app.OccurrenceDetail{Body: rollbar.OccurrenceBody{Kind: rollbar.BodyKindMessage, Message: &rollbar.Message{Body: "hello"}}},
+			want:   []string{"Body:\nhello"},
+		},
+	}
+	for _, tc := range tests {
+		got := RenderOccurrenceDetail(tc.detail, TraceOptions{})
+		for _, want := range tc.want {
+			if !strings.Contains(got, want) {
+				t.Fatalf("%s: expected %q in output:\n%s", tc.name, want, got)
+			}
+		}
+	}
+}
@@ -0,0 +1,71 @@
+package output
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// RenderOwnersResult names the suggested (or assigned) owner of an item, then
+// the in-project frames matched against CODEOWNERS and the ranked owners.
+func RenderOwnersResult(result app.OwnersResult) string {
+	heading := fmt.Sprintf("Owners of #%s %s", result.Issue.Counter, fallback(result.Issue.Title))
+	if len(result.Frames) == 0 {
+		return heading + "\n\nno frames of the latest stack trace are in the checkout"
+	}
+
+	frames := table.NewWriter()
+	frames.SetStyle(table.StyleLight)
+	frames.AppendHeader(table.Row{"FRAME", "METHOD", "RULE", "OWNERS"})
+	for _, frame := range result.Frames {
+		location := frame.Path
+		if frame.Lineno > 0 {
+			location += ":" + strconv.Itoa(frame.Lineno)
+		}
+		frames.AppendRow(table.Row{location, fallback(frame.Method), fallback(frame.Pattern), fallback(strings.Join(frame.Owners, " "))})
+	}
+
+	sections := []string{heading, ownersVerdict(result), strings.TrimRight(frames.Render(), "\n")}
+	if len(result.Candidates) > 0 {
+		candidates := table.NewWriter()
+		candidates.SetStyle(table.StyleLight)
+		candidates.SetColumnConfigs([]table.ColumnConfig{{Number: 2, Align: prettytext.AlignRight}})
+		candidates.AppendHeader(table.Row{"OWNER", "FRAMES", "ROLLBAR USER"})
+		for _, candidate := range result.Candidates {
+			candidates.AppendRow(table.Row{candidate.Owner, candidate.Frames, formatUser(candidate.User)})
+		}
+		sections = append(sections, strings.TrimRight(candidates.Render(), "\n"))
+	}
+
+	return strings.Join(sections, "\n\n")
+}
+
+func ownersVerdict(result app.OwnersResult) string {
+	switch {
+	case result.Assigned:
+		return "Assigned to " + formatUser(result.Assignee)
+	case result.Assignee != nil:
+		return "Suggested assignee: " + formatUser(result.Assignee) + " (rerun with --assign to assign)"
+	case len(result.Candidates) > 0:
+		return "No owner is a Rollbar user; @handles match usernames and emails match emails"
+	default:
+		return "No CODEOWNERS rule owns these frames"
+	}
+}
+
+func formatUser(user *rollbar.User) string {
+	if user == nil {
+		return "-"
+	}
+	if user.Email == "" {
+		return fallback(user.Username)
+	}
+
+	return fmt.Sprintf("%s <%s>", fallback(user.Username), user.Email)
+}
@@ -0,0 +1,44 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderOwnersResult(t *testing.T) {
+	t.Parallel()
+
+	ana := &rollbar.User{ID: 7, Username: "ana", Email: "ana@example.com"}
+	frames := []app.FrameOwners{
+		{CheckoutFrame: app.CheckoutFrame{Frame: rollbar.Frame{Filename: "/srv/app/pay/charge.py", Lineno: 12, Method: "charge"}, Path: "pay/charge.py"}, Pattern: "/pay/", Owners: []string{"@acme/payments", "@ana"}},
+		{CheckoutFrame: app.CheckoutFrame{Frame: rollbar.Frame{Filename: "/srv/app/web/view.py"}, Path: "web/view.py"}, Owners: []string{}},
+	}
+	candidates := []app.OwnerCandidate{{Owner: "@ana", Frames: 1, User: ana}, {Owner: "@acme/payments", Frames: 1}}
+
+	tests := []struct {
+		name   string
+		result app.OwnersResult
+		want   []string
+	}{
+		{name: "no frames", result: app.OwnersResult{Issue: app.IssueSummary{Counter: 42, Title: "KeyError"}}, want: []string{"Owners of #42 KeyError\n\nno frames of the latest stack trace are in the checkout"}},
+		{name: "suggested", result: app.OwnersResult{Issue: app.IssueSummary{Counter: 42}, Frames: frames, Candidates: candidates, Assignee: ana}, want: []string{"Suggested assignee: ana <ana@example.com>", "pay/charge.py:12", "@acme/payments @ana", "web/view.py"}},
+		{name: "assigned", result: app.OwnersResult{Frames: frames, Candidates: candidates, Assignee: &rollbar.User{Username: "ana"}, Assigned: true}, want: []string{"Assigned to ana\n"}},
+		{name: "no user", result: app.OwnersResult{Frames: frames, Candidates: candidates[1:]}, want: []string{"No owner is a Rollbar user"}},
+		{name: "no rule", result: app.OwnersResult{Frames: frames[1:]}, want: []string{"No CODEOWNERS rule owns these frames"}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			rendered := RenderOwnersResult(tt.result)
+			for _, want := range tt.want {
+				if !strings.Contains(rendered, want) {
+					t.Fatalf("expected %q in:\n%s", want, rendered)
+				}
+			}
+		})
+	}
+}
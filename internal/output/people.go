@@ -0,0 +1,46 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// RenderItemPeople summarizes who an item affected, then lists the most
+// affected people with when each was first and last hit.
+func RenderItemPeople(people app.ItemPeople) string {
+	lines := []string{
+		fmt.Sprintf("People affected by #%s across the %d newest occurrences", people.Counter, people.Scanned),
+		fmt.Sprintf("%d unique people, %d occurrences without a person", people.UniquePeople, people.Anonymous),
+	}
+	if people.UniquePeople == 0 {
+		return strings.Join(lines, "\n")
+	}
+	lines = append(lines, fmt.Sprintf("first affected %s, last affected %s", formatTimestamp(people.FirstAffected), formatTimestamp(people.LastAffected)))
+
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetColumnConfigs([]table.ColumnConfig{{Number: 3, Align: prettytext.AlignRight}})
+	tw.AppendHeader(table.Row{"PERSON", "USERNAME / EMAIL", "OCCURRENCES", "FIRST SEEN", "LAST SEEN"})
+	for _, person := range people.People {
+		tw.AppendRow(table.Row{person.ID, personName(person), person.Occurrences, formatTimestamp(person.FirstSeen), formatTimestamp(person.LastSeen)})
+	}
+	rendered := strings.Join(lines, "\n") + "\n\n" + strings.TrimRight(tw.Render(), "\n")
+	if hidden := people.UniquePeople - len(people.People); hidden > 0 {
+		rendered += fmt.Sprintf("\n+%d more; raise --limit to list them", hidden)
+	}
+
+	return rendered
+}
+
+func personName(person app.AffectedPerson) string {
+	if person.Email == "" {
+		return fallback(person.Username)
+	}
+
+	return fmt.Sprintf("%s <%s>", fallback(person.Username), person.Email)
+}
@@ -0,0 +1,37 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderItemPeople(t *testing.T) {
+	t.Parallel()
+
+	seen := uint64(1_700_000_000)
+	people := app.ItemPeople{Counter: 42, Scanned: 9, Anonymous: 2, UniquePeople: 3, FirstAffected: &seen, LastAffected: &seen, People: []app.AffectedPerson{
+		{ID: "u1", Username: "ann", Email: "ann@example.com", Occurrences: 5, FirstSeen: &seen, LastSeen: &seen},
+		{ID: "7", Occurrences: 2},
+	}}
+
+	rendered := RenderItemPeople(people)
+	for _, want := range []string{
+		"People affected by #42 across the 9 newest occurrences",
+		"3 unique people, 2 occurrences without a person",
+		"first affected 2023-11-14T22:13:20Z",
+		"ann <ann@example.com>",
+		"+1 more; raise --limit to list them",
+		"unknown",
+	} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("missing %q in:\n%s", want, rendered)
+		}
+	}
+
+	empty := RenderItemPeople(app.ItemPeople{Counter: 42, Scanned: 4, Anonymous: 4})
+	if strings.Contains(empty, "PERSON") || !strings.HasSuffix(empty, "0 unique people, 4 occurrences without a person") {
+		t.Fatalf("unexpected empty render:\n%s", empty)
+	}
+}
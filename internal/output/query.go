@@ -0,0 +1,65 @@
+package output
+
+import (
+	"bytes"
+	"encoding/csv"
+	"fmt"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+const maxQueryCellWidth = 60
+
+// RenderQueryTableWithWidth renders RQL rows under their column names,
+// trimming long cells so the table fits maxWidth where it can.
+func RenderQueryTableWithWidth(result app.QueryResult, maxWidth int) string {
+	if len(result.Rows) == 0 {
+		return "no rows"
+	}
+
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(normalizeWidth(maxWidth, defaultListRowWidth))
+
+	header := make(table.Row, 0, len(result.Columns))
+	configs := make([]table.ColumnConfig, 0, len(result.Columns))
+	for index, column := range result.Columns {
+		header = append(header, column)
+		configs = append(configs, table.ColumnConfig{Number: index + 1, WidthMax: maxQueryCellWidth, WidthMaxEnforcer: prettytext.Trim})
+	}
+	tw.AppendHeader(header)
+	tw.SetColumnConfigs(configs)
+
+	for _, cells := range result.Cells() {
+		row := make(table.Row, 0, len(cells))
+		for _, cell := range cells {
+			row = append(row, cell)
+		}
+		tw.AppendRow(row)
+	}
+
+	noun := "rows"
+	if len(result.Rows) == 1 {
+		noun = "row"
+	}
+
+	return fmt.Sprintf("%s\n%d %s", strings.TrimRight(tw.Render(), "\n"), len(result.Rows), noun)
+}
+
+// RenderQueryCSV renders RQL rows as CSV with a header line.
+func RenderQueryCSV(result app.QueryResult) (string, error) {
+	var buffer bytes.Buffer
+	writer := csv.NewWriter(&buffer)
+	if err := writer.Write(result.Columns); err != nil {
+		return "", fmt.Errorf("write csv header: %w", err)
+	}
+	if err := writer.WriteAll(result.Cells()); err != nil {
+		return "", fmt.Errorf("write csv rows: %w", err)
+	}
+
+	return strings.TrimRight(buffer.String(), "\n"), nil
+}
@@ -0,0 +1,43 @@
+package output
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderQuery(t *testing.T) {
+	t.Parallel()
+
+	result := app.QueryResult{
+		Columns: []string{"item.counter", "item.title"},
+		Rows: [][]json.RawMessage{
+			{json.RawMessage(`269`), json.RawMessage(`"RST_STREAM, reset"`)},
+			{json.RawMessage(`270`), json.RawMessage(`"` + strings.Repeat("x", 80) + `"`)},
+		},
+	}
+
+	rendered := RenderQueryTableWithWidth(result, 120)
+	for _, want := range []string{"ITEM.COUNTER", "RST_STREAM, reset", "2 rows"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in table:\n%s", want, rendered)
+		}
+	}
+	if strings.Contains(rendered, strings.Repeat("x", 80)) {
+		t.Fatalf("expected long cell to be trimmed:\n%s", rendered)
+	}
+	if got := RenderQueryTableWithWidth(app.QueryResult{Columns: []string{"a"}}, 120); got != "no rows" {
+		t.Fatalf("unexpected empty table: %q", got)
+	}
+
+	csvText, err := RenderQueryCSV(result)
+	if err != nil {
+		t.Fatalf("RenderQueryCSV() error = %v", err)
+	}
+	lines := strings.Split(csvText, "\n")
+	if len(lines) != 3 || lines[0] != "item.counter,item.title" || lines[1] != `269,"RST_STREAM, reset"` {
+		t.Fatalf("unexpected csv:\n%s", csvText)
+	}
+}
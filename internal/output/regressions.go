@@ -0,0 +1,53 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+const regressionNonTitle = 78
+
+// RenderRegressionReportWithWidth describes the deploy's window, then lists
+// the items it introduced with when each first appeared or came back.
+func RenderRegressionReportWithWidth(report app.RegressionReport, maxWidth int) string {
+	until := "now"
+	if report.Next != nil {
+		until = fmt.Sprintf("deploy of %s at %s", ShortRevision(report.Next.Revision), formatTimestamp(report.Until))
+	}
+	heading := fmt.Sprintf("Deploy %d of %s to %s, from %s until %s", report.Deploy.ID, ShortRevision(report.Deploy.Revision), fallback(report.Deploy.Environment), formatTimestamp(&report.Since), until)
+	if len(report.Items) == 0 {
+		return heading + "\n\nno new or reactivated items"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 4, Align: prettytext.AlignRight},
+		{Number: 6, WidthMax: min(max(targetWidth-regressionNonTitle, minListTitleWidth), maxListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	tw.AppendHeader(table.Row{"COUNTER", "KIND", "LEVEL", "OCCURRENCES", "SINCE", "TITLE"})
+
+	for _, regression := range report.Items {
+		since := regression.FirstOccurrenceTimestamp
+		if regression.Kind == app.RegressionReactivated {
+			since = regression.LastActivatedTimestamp
+		}
+		tw.AppendRow(table.Row{
+			regression.Counter.String(),
+			string(regression.Kind),
+			fallback(regression.Level),
+			formatOccurrences(regression.Occurrences),
+			formatTimestamp(since),
+			fallback(regression.Title),
+		})
+	}
+
+	return heading + "\n\n" + strings.TrimRight(tw.Render(), "\n")
+}
@@ -0,0 +1,35 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderRegressionReportWithWidth(t *testing.T) {
+	t.Parallel()
+
+	first, activated, until := uint64(1767225600), uint64(1767229200), uint64(1767312000)
+	report := app.RegressionReport{
+		Deploy: rollbar.Deploy{ID: 7, Environment: "production", Revision: "0123456789abcdef"},
+		Since:  first,
+		Items:  []app.Regression{},
+	}
+	if got := RenderRegressionReportWithWidth(report, 120); got != "Deploy 7 of 0123456789ab to production, from 2026-01-01T00:00:00Z until now\n\nno new or reactivated items" {
+		t.Fatalf("unexpected empty render %q", got)
+	}
+
+	report.Next, report.Until = &rollbar.Deploy{ID: 8, Revision: "fedcba"}, &until
+	report.Items = []app.Regression{
+		{IssueSummary: app.IssueSummary{Counter: 3, Title: "KeyError"}, Kind: app.RegressionNew, FirstOccurrenceTimestamp: &first},
+		{IssueSummary: app.IssueSummary{Counter: 1, Title: "Timeout"}, Kind: app.RegressionReactivated, FirstOccurrenceTimestamp: &first, LastActivatedTimestamp: &activated},
+	}
+	rendered := RenderRegressionReportWithWidth(report, 120)
+	for _, want := range []string{"until deploy of fedcba at 2026-01-02T00:00:00Z", "reactivated", "2026-01-01T01:00:00Z", "KeyError"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+}
@@ -0,0 +1,134 @@
+package output
+
+import (
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+const (
+	sarifVersion = "2.1.0"
+	sarifSchema  = "https://json.schemastore.org/sarif-2.1.0.json"
+	// sarifRootID is the uriBaseId artifact paths are relative to, which
+	// GitHub code scanning maps to the repository root.
+	sarifRootID      = "%SRCROOT%"
+	sarifUnknownRule = "rollbar-item"
+)
+
+// SARIFLog is a SARIF 2.1.0 log with one run, trimmed to the properties
+// rollbaz fills in.
+type SARIFLog struct {
+	Schema  string     `json:"$schema"`
+	Version string     `json:"version"`
+	Runs    []SARIFRun `json:"runs"`
+}
+
+type SARIFRun struct {
+	Tool    SARIFTool     `json:"tool"`
+	Results []SARIFResult `json:"results"`
+}
+
+type SARIFTool struct {
+	Driver SARIFDriver `json:"driver"`
+}
+
+type SARIFDriver struct {
+	Name           string      `json:"name"`
+	Version        string      `json:"version,omitempty"`
+	InformationURI string      `json:"informationUri"`
+	Rules          []SARIFRule `json:"rules"`
+}
+
+type SARIFRule struct {
+	ID               string       `json:"id"`
+	ShortDescription SARIFMessage `json:"shortDescription"`
+}
+
+type SARIFMessage struct {
+	Text string `json:"text"`
+}
+
+type SARIFResult struct {
+	RuleID              string            `json:"ruleId"`
+	Level               string            `json:"level"`
+	Message             SARIFMessage      `json:"message"`
+	Locations           []SARIFLocation   `json:"locations,omitempty"`
+	PartialFingerprints map[string]string `json:"partialFingerprints"`
+}
+
+type SARIFLocation struct {
+	PhysicalLocation SARIFPhysicalLocation `json:"physicalLocation"`
+}
+
+type SARIFPhysicalLocation struct {
+	ArtifactLocation SARIFArtifactLocation `json:"artifactLocation"`
+	Region           SARIFRegion           `json:"region"`
+}
+
+type SARIFArtifactLocation struct {
+	URI       string `json:"uri"`
+	URIBaseID string `json:"uriBaseId"`
+}
+
+type SARIFRegion struct {
+	StartLine   int `json:"startLine"`
+	StartColumn int `json:"startColumn,omitempty"`
+}
+
+// BuildSARIF maps each item to a SARIF result whose rule is its exception
+// class and whose location is its most recent frame found in the checkout.
+// Items without such a frame have no location, which GitHub code scanning
+// accepts but does not display. The Rollbar counter is the result's
+// fingerprint, so code scanning tracks an item across uploads.
+func BuildSARIF(items []app.ItemFrames, lookup PathLookup, version string) SARIFLog {
+	rules := map[string]bool{}
+	results := make([]SARIFResult, 0, len(items))
+	for _, item := range items {
+		rule := strings.TrimSpace(item.Exception)
+		if rule == "" {
+			rule = sarifUnknownRule
+		}
+		rules[rule] = true
+
+		result := SARIFResult{
+			RuleID:              rule,
+			Level:               sarifLevel(item.Level),
+			Message:             SARIFMessage{Text: fallback(item.Title)},
+			PartialFingerprints: map[string]string{"rollbarItem/v1": item.Counter.String()},
+		}
+		if file, frame, ok := checkoutFrame(item.Frames, lookup); ok {
+			result.Locations = []SARIFLocation{{PhysicalLocation: SARIFPhysicalLocation{
+				ArtifactLocation: SARIFArtifactLocation{URI: file, URIBaseID: sarifRootID},
+				Region:           SARIFRegion{StartLine: frame.Lineno, StartColumn: frame.Colno},
+			}}}
+		}
+		results = append(results, result)
+	}
+
+	return SARIFLog{Schema: sarifSchema, Version: sarifVersion, Runs: []SARIFRun{{
+		Tool:    SARIFTool{Driver: SARIFDriver{Name: "rollbaz", Version: version, InformationURI: "https://github.com/kevinsheth/rollbaz", Rules: sarifRules(rules)}},
+		Results: results,
+	}}}
+}
+
+func sarifRules(ids map[string]bool) []SARIFRule {
+	rules := make([]SARIFRule, 0, len(ids))
+	for id := range ids {
+		rules = append(rules, SARIFRule{ID: id, ShortDescription: SARIFMessage{Text: id}})
+	}
+	sort.Slice(rules, func(i int, j int) bool { return rules[i].ID < rules[j].ID })
+
+	return rules
+}
+
+func sarifLevel(level string) string {
+	switch strings.ToLower(level) {
+	case "warning":
+		return "warning"
+	case "info", "debug":
+		return "note"
+	default:
+		return "error"
+	}
+}
@@ -0,0 +1,67 @@
+package output
+
+import (
+	"fmt"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// checkSARIFDriver fails unless log is a single 2.1.0 run by version 1.2.3
+// with the KeyError and unknown rules.
+func checkSARIFDriver(t *testing.T, log SARIFLog) {
+	t.Helper()
+
+	if log.Version != "2.1.0" || len(log.Runs) != 1 || log.Runs[0].Tool.Driver.Version != "1.2.3" {
+		t.Fatalf("unexpected log: %+v", log)
+	}
+	rules := log.Runs[0].Tool.Driver.Rules
+	if len(rules) != 2 || rules[0].ID != "KeyError" || rules[1].ID != sarifUnknownRule {
+		t.Fatalf("unexpected rules: %+v", rules)
+	}
+}
+
+// sarifLocation formats the location of result as uri:line:column, or
+// returns "" when it has none.
+func sarifLocation(result SARIFResult) string {
+	if len(result.Locations) != 1 {
+		return ""
+	}
+	physical := result.Locations[0].PhysicalLocation
+	return fmt.Sprintf("%s:%d:%d", physical.ArtifactLocation.URI, physical.Region.StartLine, physical.Region.StartColumn)
+}
+
+func TestBuildSARIF(t *testing.T) {
+	t.Parallel()
+
+	items := []app.ItemFrames{
+		{
+			IssueSummary: app.IssueSummary{Counter: 7, Level: "error", Title: "KeyError: 'cart'"},
+			Exception:    "KeyError",
+			Frames:       []rollbar.Frame{{Filename: "/usr/lib/python3/json.py", Lineno: 300}, {Filename: "/srv/app/pay/charge.py", Lineno: 12, Colno: 4}},
+		},
+		{IssueSummary: app.IssueSummary{Counter: 8, Level: "info", Title: "cache warmed"}},
+		{IssueSummary: app.IssueSummary{Counter: 9, Level: "warning", Title: "KeyError: 'user'"}, Exception: "KeyError"},
+	}
+
+	log := BuildSARIF(items, checkoutLookup, "1.2.3")
+	checkSARIFDriver(t, log)
+	run := log.Runs[0]
+
+	tests := []struct {
+		rule     string
+		level    string
+		location string
+	}{
+		{rule: "KeyError", level: "error", location: "pay/charge.py:12:4"},
+		{rule: sarifUnknownRule, level: "note"},
+		{rule: "KeyError", level: "warning"},
+	}
+	for index, tc := range tests {
+		result := run.Results[index]
+		if result.RuleID != tc.rule || result.Level != tc.level || sarifLocation(result) != tc.location || result.PartialFingerprints["rollbarItem/v1"] != items[index].Counter.String() {
+			t.Fatalf("result %d = %+v", index, result)
+		}
+	}
+}
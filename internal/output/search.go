@@ -0,0 +1,22 @@
+package output
+
+import (
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// RenderSearchResultsWithWidth lists search matches best first in the issue
+// list layout.
+func RenderSearchResultsWithWidth(text string, results []app.SearchResult, maxWidth int) string {
+	if len(results) == 0 {
+		return fmt.Sprintf("no items match %q", text)
+	}
+
+	issues := make([]app.IssueSummary, 0, len(results))
+	for _, result := range results {
+		issues = append(issues, result.IssueSummary)
+	}
+
+	return RenderIssueListHumanWithWidth(issues, maxWidth)
+}
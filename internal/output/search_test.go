@@ -0,0 +1,25 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderSearchResultsWithWidth(t *testing.T) {
+	t.Parallel()
+
+	if got := RenderSearchResultsWithWidth("checkout", nil, 100); got != `no items match "checkout"` {
+		t.Fatalf("unexpected empty render %q", got)
+	}
+
+	results := []app.SearchResult{
+		{IssueSummary: app.IssueSummary{Counter: 9, Title: "checkout timeout", Status: "active"}, Score: 6},
+		{IssueSummary: app.IssueSummary{Counter: 3, Title: "checkout retry", Status: "resolved"}, Score: 3},
+	}
+	rendered := RenderSearchResultsWithWidth("checkout", results, 100)
+	if first, second := strings.Index(rendered, "checkout timeout"), strings.Index(rendered, "checkout retry"); first < 0 || second < first {
+		t.Fatalf("expected results in rank order:\n%s", rendered)
+	}
+}
@@ -0,0 +1,63 @@
+package output
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+var settingsMarkers = map[app.SettingsAction]string{
+	app.SettingsCreate:   "+",
+	app.SettingsUpdate:   "~",
+	app.SettingsDelete:   "-",
+	app.SettingsConflict: "!",
+}
+
+// RenderSettingsPlan prints a plan as a diff, a line per change marked + to
+// create, ~ to update, - to delete, or ! for a conflict, then a tally.
+// Credentials in rule configs are masked.
+func RenderSettingsPlan(plan app.SettingsPlan) string {
+	if len(plan.Changes) == 0 {
+		return "no changes: the project matches the settings file"
+	}
+
+	lines := make([]string, 0, len(plan.Changes)+2)
+	for _, change := range plan.Changes {
+		lines = append(lines, settingsMarkers[change.Action]+" "+describeSettingsChange(change))
+	}
+	lines = append(lines, "", fmt.Sprintf("%d to create, %d to update, %d to delete, %d in conflict",
+		plan.Count(app.SettingsCreate), plan.Count(app.SettingsUpdate), plan.Count(app.SettingsDelete), plan.Count(app.SettingsConflict)))
+
+	return strings.Join(lines, "\n")
+}
+
+func describeSettingsChange(change app.SettingsChange) string {
+	if change.Resource == app.ResourceNotificationRule {
+		rule := change.Rule
+		name := change.Channel + " rule"
+		if rule.ID != 0 {
+			name += " " + strconv.FormatUint(rule.ID, 10)
+		}
+		return fmt.Sprintf("%s: %s [%s] {%s}", name, rule.Trigger, formatNotificationFilters(rule.Filters), formatNotificationConfig(rule.Config))
+	}
+
+	name := "access token " + change.Token.Name
+	switch change.Action {
+	case app.SettingsCreate:
+		return fmt.Sprintf("%s: %s, rate limit %s", name, strings.Join(change.Token.Scopes, ", "), formatRateLimit(change.Token.RateLimitWindowSize, change.Token.RateLimitWindowCount))
+	case app.SettingsUpdate:
+		return fmt.Sprintf("%s: rate limit %s -> %s", name, formatRateLimit(change.Current.RateLimitWindowSize, change.Current.RateLimitWindowCount), formatRateLimit(change.Token.RateLimitWindowSize, change.Token.RateLimitWindowCount))
+	default:
+		return name + ": " + change.Detail
+	}
+}
+
+func formatRateLimit(size *uint64, count *uint64) string {
+	if size == nil || count == nil || *size == 0 {
+		return "none"
+	}
+
+	return fmt.Sprintf("%d/%ds", *count, *size)
+}
@@ -0,0 +1,40 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/settings"
+)
+
+func TestRenderSettingsPlan(t *testing.T) {
+	t.Parallel()
+
+	size, oldCount, newCount := uint64(60), uint64(100), uint64(1000)
+	plan := app.SettingsPlan{Changes: []app.SettingsChange{
+		{Action: app.SettingsCreate, Resource: app.ResourceNotificationRule, Channel: "pagerduty", Rule: &rollbar.NotificationRule{Trigger: "new_item", Config: map[string]any{"service_key": "pd-secret"}}},
+		{Action: app.SettingsDelete, Resource: app.ResourceNotificationRule, Channel: "slack", Rule: &rollbar.NotificationRule{ID: 2, Trigger: "occurrence", Filters: []rollbar.NotificationFilter{{Type: "level", Operation: "gte", Value: "error"}}}},
+		{Action: app.SettingsCreate, Resource: app.ResourceAccessToken, Token: &settings.AccessToken{Name: "ci", Scopes: []string{"post_server_item"}}},
+		{Action: app.SettingsUpdate, Resource: app.ResourceAccessToken, Token: &settings.AccessToken{Name: "reader", RateLimitWindowSize: &size, RateLimitWindowCount: &newCount}, Current: &rollbar.ProjectAccessToken{RateLimitWindowSize: &size, RateLimitWindowCount: &oldCount}},
+		{Action: app.SettingsConflict, Resource: app.ResourceAccessToken, Token: &settings.AccessToken{Name: "admin"}, Detail: "scopes are read, not read, write"},
+	}}
+
+	want := strings.Join([]string{
+		"+ pagerduty rule: new_item [-] {service_key=[REDACTED]}",
+		"- slack rule 2: occurrence [level gte error] {-}",
+		"+ access token ci: post_server_item, rate limit none",
+		"~ access token reader: rate limit 100/60s -> 1000/60s",
+		"! access token admin: scopes are read, not read, write",
+		"",
+		"2 to create, 1 to update, 1 to delete, 1 in conflict",
+	}, "\n")
+	if got := RenderSettingsPlan(plan); got != want {
+		t.Fatalf("RenderSettingsPlan() =\n%s\nwant\n%s", got, want)
+	}
+
+	if got := RenderSettingsPlan(app.SettingsPlan{}); !strings.HasPrefix(got, "no changes") {
+		t.Fatalf("unexpected empty plan %q", got)
+	}
+}
@@ -0,0 +1,53 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// similarNonTitleWidth is the room the counter, score, and occurrence columns
+// take in the similar-items table.
+const similarNonTitleWidth = 60
+
+// RenderSimilarReportWithWidth names the item compared, then lists probable
+// duplicates best first with the overall, title, and trace scores.
+func RenderSimilarReportWithWidth(report app.SimilarReport, maxWidth int) string {
+	heading := fmt.Sprintf("Items similar to #%s %s (compared %d active items)", report.Item.Counter, fallback(report.Item.Title), report.Compared)
+	if len(report.Similar) == 0 {
+		return heading + "\n\nno probable duplicates found"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	titleWidth := min(max(targetWidth-similarNonTitleWidth, minListTitleWidth), maxListTitleWidth)
+
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 5, Align: prettytext.AlignRight},
+		{Number: 6, WidthMax: titleWidth, WidthMaxEnforcer: prettytext.Trim},
+	})
+	tw.AppendHeader(table.Row{"COUNTER", "SCORE", "TITLE_SIM", "TRACE_SIM", "OCCURRENCES", "TITLE"})
+
+	for _, match := range report.Similar {
+		trace := "-"
+		if match.TraceScore != nil {
+			trace = fmt.Sprintf("%.2f", *match.TraceScore)
+		}
+		tw.AppendRow(table.Row{
+			match.Counter.String(),
+			fmt.Sprintf("%.2f", match.Score),
+			fmt.Sprintf("%.2f", match.TitleScore),
+			trace,
+			formatOccurrences(match.Occurrences),
+			fallback(match.Title),
+		})
+	}
+
+	return heading + "\n\n" + strings.TrimRight(tw.Render(), "\n")
+}
@@ -0,0 +1,30 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderSimilarReportWithWidth(t *testing.T) {
+	t.Parallel()
+
+	item := app.IssueSummary{Counter: 7, Title: "Payment timeout"}
+	empty := RenderSimilarReportWithWidth(app.SimilarReport{Item: item, Compared: 12}, 100)
+	if !strings.Contains(empty, "#7 Payment timeout (compared 12 active items)") || !strings.HasSuffix(empty, "no probable duplicates found") {
+		t.Fatalf("unexpected empty render:\n%s", empty)
+	}
+
+	trace := 0.75
+	report := app.SimilarReport{Item: item, Compared: 12, Similar: []app.SimilarItem{
+		{IssueSummary: app.IssueSummary{Counter: 9, Title: "Payment timed out"}, Score: 0.81, TitleScore: 0.9, TraceScore: &trace},
+		{IssueSummary: app.IssueSummary{Counter: 3, Title: "Payment timeout again"}, Score: 0.6, TitleScore: 0.6},
+	}}
+	rendered := RenderSimilarReportWithWidth(report, 100)
+	for _, want := range []string{"TRACE_SIM", "0.81", "0.75", "Payment timed out", " - "} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+}
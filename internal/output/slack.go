@@ -0,0 +1,58 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/slack"
+)
+
+// SlackCard is one item as a Slack message. Heading says why it was posted,
+// such as a watch event, and is empty for a manual post.
+type SlackCard struct {
+	Heading   string
+	Issue     app.IssueSummary
+	MainError string
+	Link      string
+}
+
+// RenderSlackMessage renders a compact card: the linked title, the main error
+// when the title does not already say it, the item's vitals, and a button to
+// open it in Rollbar.
+func RenderSlackMessage(card SlackCard) slack.Message {
+	issue := card.Issue
+	title := fmt.Sprintf("#%s %s", issue.Counter, fallback(issue.Title))
+	heading := fmt.Sprintf("*<%s|%s>*", card.Link, slack.Escape(title))
+	if card.Heading != "" {
+		heading = slack.Escape(card.Heading) + ": " + heading
+	}
+	if shouldIncludeMainErrorLine(app.IssueDetail{IssueSummary: issue, MainError: card.MainError}) {
+		heading += "\n`" + slack.Escape(strings.ReplaceAll(card.MainError, "`", "'")) + "`"
+	}
+
+	text := fmt.Sprintf("%s (%s, %s)", title, fallback(issue.Level), fallback(issue.Environment))
+	if card.Heading != "" {
+		text = card.Heading + ": " + text
+	}
+
+	return slack.Message{
+		Text: text,
+		Blocks: []slack.Block{
+			{Type: "section", Text: &slack.Text{Type: "mrkdwn", Text: heading}},
+			{Type: "section", Fields: []slack.Text{
+				slack.Markdown("*Level*\n" + slack.Escape(fallback(issue.Level))),
+				slack.Markdown("*Environment*\n" + slack.Escape(fallback(issue.Environment))),
+				slack.Markdown("*Status*\n" + slack.Escape(fallback(issue.Status))),
+				slack.Markdown("*Occurrences*\n" + formatOccurrences(issue.Occurrences)),
+			}},
+			{Type: "actions", Elements: []slack.Button{slack.LinkButton("Open in Rollbar", card.Link)}},
+		},
+	}
+}
+
+// RenderSlackPosted confirms a manual post.
+func RenderSlackPosted(counter domain.ItemCounter) string {
+	return "posted item " + counter.String() + " to slack"
+}
@@ -0,0 +1,51 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderSlackMessage(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(42)
+	issue := app.IssueSummary{Counter: 269, Title: "Timeout <db>", Level: "error", Environment: "production", Status: "active", Occurrences: &occurrences}
+
+	tests := []struct {
+		name        string
+		card        SlackCard
+		wantText    string
+		wantHeading string
+	}{
+		{
+			name:        "manual",
+			card:        SlackCard{Issue: issue, MainError: "Timeout <db>", Link: "https://rollbar.example/269"},
+			wantText:    "#269 Timeout <db> (error, production)",
+			wantHeading: "*<https://rollbar.example/269|#269 Timeout &lt;db&gt;>*",
+		},
+		{
+			name:        "watch event with main error",
+			card:        SlackCard{Heading: "New item (+3)", Issue: issue, MainError: "pq: `conn` reset", Link: "https://rollbar.example/269"},
+			wantText:    "New item (+3): #269 Timeout <db> (error, production)",
+			wantHeading: "New item (+3): *<https://rollbar.example/269|#269 Timeout &lt;db&gt;>*\n`pq: 'conn' reset`",
+		},
+	}
+	for _, tc := range tests {
+		message := RenderSlackMessage(tc.card)
+		if message.Text != tc.wantText || message.Blocks[0].Text.Text != tc.wantHeading {
+			t.Fatalf("%s: text %q, heading %q", tc.name, message.Text, message.Blocks[0].Text.Text)
+		}
+		fields := make([]string, 0, len(message.Blocks[1].Fields))
+		for _, field := range message.Blocks[1].Fields {
+			fields = append(fields, field.Text)
+		}
+		if got := strings.Join(fields, "|"); got != "*Level*\nerror|*Environment*\nproduction|*Status*\nactive|*Occurrences*\n42" {
+			t.Fatalf("%s: fields %q", tc.name, got)
+		}
+		if button := message.Blocks[2].Elements[0]; button.URL != "https://rollbar.example/269" || button.Text.Text != "Open in Rollbar" {
+			t.Fatalf("%s: button %+v", tc.name, button)
+		}
+	}
+}
@@ -0,0 +1,16 @@
+package output
+
+import (
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func RenderSourceMapUploaded(result app.SourceMapUploadResult) string {
+	rendered := fmt.Sprintf("uploaded %s for %s @ %s", result.Map, result.MinifiedURL, ShortRevision(result.Version))
+	if len(result.Sources) > 0 {
+		rendered += fmt.Sprintf(" with %d sources", len(result.Sources))
+	}
+
+	return rendered
+}
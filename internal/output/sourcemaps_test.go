@@ -0,0 +1,24 @@
+package output
+
+import (
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderSourceMapUploaded(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		result app.SourceMapUploadResult
+		want   string
+	}{
+		{result: app.SourceMapUploadResult{Version: "0123456789abcdef", MinifiedURL: "https://cdn.example.com/app.js", Map: "app.js.map"}, want: "uploaded app.js.map for https://cdn.example.com/app.js @ 0123456789ab"},
+		{result: app.SourceMapUploadResult{Version: "v1", MinifiedURL: "u", Map: "m", Sources: []string{"a", "b"}}, want: "uploaded m for u @ v1 with 2 sources"},
+	}
+	for _, tc := range tests {
+		if got := RenderSourceMapUploaded(tc.result); got != tc.want {
+			t.Fatalf("RenderSourceMapUploaded() = %q, want %q", got, tc.want)
+		}
+	}
+}
@@ -0,0 +1,17 @@
+package output
+
+import (
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// RenderSyncResult summarizes a sync and where the store lives.
+func RenderSyncResult(result app.SyncResult, path string) string {
+	summary := fmt.Sprintf("synced %d items and %d occurrences into %s", result.Items, result.Occurrences, path)
+	if result.HighWaterMark == 0 {
+		return summary
+	}
+
+	return summary + " (items seen up to " + formatTimestamp(&result.HighWaterMark) + ")"
+}
@@ -0,0 +1,24 @@
+package output
+
+import (
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderSyncResult(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		result app.SyncResult
+		want   string
+	}{
+		{result: app.SyncResult{}, want: "synced 0 items and 0 occurrences into /tmp/store.db"},
+		{result: app.SyncResult{Items: 2, Occurrences: 5, HighWaterMark: 1700000100}, want: "synced 2 items and 5 occurrences into /tmp/store.db (items seen up to 2023-11-14T22:15:00Z)"},
+	}
+	for _, tc := range tests {
+		if got := RenderSyncResult(tc.result, "/tmp/store.db"); got != tc.want {
+			t.Fatalf("RenderSyncResult(%+v) = %q, want %q", tc.result, got, tc.want)
+		}
+	}
+}
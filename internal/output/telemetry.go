@@ -0,0 +1,84 @@
+package output
+
+import (
+	"fmt"
+	"math"
+	"strings"
+	"time"
+
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const telemetryTimeLayout = "15:04:05.000"
+
+// telemetryLevelColors color a breadcrumb's level; other levels stay plain.
+var telemetryLevelColors = map[string]prettytext.Colors{
+	"debug":    {prettytext.Faint},
+	"warning":  {prettytext.FgYellow},
+	"error":    {prettytext.FgRed},
+	"critical": {prettytext.FgRed, prettytext.Bold},
+}
+
+// RenderTelemetry draws an occurrence's telemetry as a breadcrumb trail,
+// oldest first, one line per event with its UTC time, the seconds before the
+// error, its type, and what happened, ending with the error itself. With
+// color, levels, types, and the error are colored for a terminal.
+func RenderTelemetry(telemetry app.OccurrenceTelemetry, color bool) string {
+	lines := []string{fmt.Sprintf("Telemetry leading up to occurrence %s at %s", fallback(telemetry.UUID), formatTimestamp(telemetry.Timestamp))}
+	if len(telemetry.Events) == 0 {
+		lines = append(lines, "no telemetry recorded")
+	}
+	for _, event := range telemetry.Events {
+		lines = append(lines, fmt.Sprintf("%s %8s  %s %s  %s",
+			time.UnixMilli(event.TimestampMS).UTC().Format(telemetryTimeLayout),
+			telemetryOffset(event.TimestampMS, telemetry.Timestamp),
+			paint(color, prettytext.Colors{prettytext.FgCyan}, fmt.Sprintf("%-12s", fallback(event.Type))),
+			paint(color, telemetryLevelColors[event.Level], fmt.Sprintf("%-8s", event.Level)),
+			describeTelemetry(event)))
+	}
+	lines = append(lines, paint(color, prettytext.Colors{prettytext.FgRed, prettytext.Bold}, "✖ "+telemetry.MainError))
+
+	return strings.Join(lines, "\n")
+}
+
+// telemetryOffset is how long before the occurrence an event happened, like
+// "-1.250s", or "" when the occurrence time is unknown.
+func telemetryOffset(timestampMS int64, occurred *uint64) string {
+	if occurred == nil || *occurred > math.MaxInt64/1000 {
+		return ""
+	}
+
+	return fmt.Sprintf("%+.3fs", float64(timestampMS-int64(*occurred)*1000)/1000)
+}
+
+// describeTelemetry summarizes what an event recorded, by type.
+func describeTelemetry(event rollbar.TelemetryEvent) string {
+	body := event.Body
+	switch event.Type {
+	case rollbar.TelemetryNetwork:
+		return describeNetwork(body)
+	case rollbar.TelemetryNavigation:
+		return fmt.Sprintf("%s → %s", fallback(body.From), fallback(body.To))
+	case rollbar.TelemetryDOM:
+		return strings.TrimSpace(strings.Join([]string{body.Subtype, body.Element, body.Value}, " "))
+	case rollbar.TelemetryConnectivity:
+		return fallback(body.Change)
+	}
+
+	return fallback(body.Message)
+}
+
+func describeNetwork(body rollbar.TelemetryBody) string {
+	text := strings.TrimSpace(body.Method + " " + body.URL)
+	if body.StatusCode != "" {
+		text += " → " + body.StatusCode.String()
+	}
+	if body.EndTimeMS >= body.StartTimeMS && body.StartTimeMS > 0 {
+		text += fmt.Sprintf(" (%dms)", body.EndTimeMS-body.StartTimeMS)
+	}
+
+	return fallback(text)
+}
@@ -0,0 +1,45 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderTelemetry(t *testing.T) {
+	t.Parallel()
+
+	occurred := uint64(1_700_000_002)
+	telemetry := app.OccurrenceTelemetry{UUID: "u-9", Timestamp: &occurred, MainError: "TypeError: x is undefined", Events: []rollbar.TelemetryEvent{
+		{Type: rollbar.TelemetryNavigation, Level: "info", TimestampMS: 1_700_000_000_000, Body: rollbar.TelemetryBody{From: "/cart", To: "/checkout"}},
+		{Type: rollbar.TelemetryDOM, Level: "info", TimestampMS: 1_700_000_000_400, Body: rollbar.TelemetryBody{Subtype: "click", Element: "button#pay"}},
+		{Type: rollbar.TelemetryNetwork, Level: "error", TimestampMS: 1_700_000_001_000, Body: rollbar.TelemetryBody{Method: "POST", URL: "/api/pay", StatusCode: "502", StartTimeMS: 1_700_000_001_000, EndTimeMS: 1_700_000_001_120}},
+		{Type: rollbar.TelemetryConnectivity, Level: "warning", TimestampMS: 1_700_000_001_500, Body: rollbar.TelemetryBody{Change: "offline"}},
+		{Type: rollbar.TelemetryLog, Level: "error", TimestampMS: 1_700_000_002_000, Body: rollbar.TelemetryBody{Message: "payment failed"}},
+	}}
+
+	want := strings.Join([]string{
+		"Telemetry leading up to occurrence u-9 at 2023-11-14T22:13:22Z",
+		"22:13:20.000  -2.000s  navigation   info      /cart → /checkout",
+		"22:13:20.400  -1.600s  dom          info      click button#pay",
+		"22:13:21.000  -1.000s  network      error     POST /api/pay → 502 (120ms)",
+		"22:13:21.500  -0.500s  connectivity warning   offline",
+		"22:13:22.000  +0.000s  log          error     payment failed",
+		"✖ TypeError: x is undefined",
+	}, "\n")
+	if got := RenderTelemetry(telemetry, false); got != want {
+		t.Fatalf("RenderTelemetry() =\n%s\nwant\n%s", got, want)
+	}
+
+	colored := RenderTelemetry(telemetry, true)
+	if !strings.Contains(colored, "\x1b[31m") || !strings.Contains(colored, "payment failed") {
+		t.Fatalf("expected ANSI colors:\n%q", colored)
+	}
+
+	empty := RenderTelemetry(app.OccurrenceTelemetry{MainError: "boom"}, false)
+	if !strings.Contains(empty, "no telemetry recorded") || !strings.HasSuffix(empty, "✖ boom") {
+		t.Fatalf("unexpected empty render:\n%s", empty)
+	}
+}
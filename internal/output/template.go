@@ -0,0 +1,129 @@
+package output
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"strings"
+	"text/template"
+	"time"
+)
+
+// TemplateFuncs are the functions --template files may call besides the
+// text/template builtins.
+var TemplateFuncs = template.FuncMap{
+	"json":     templateJSON,
+	"time":     templateTime,
+	"default":  templateDefault,
+	"upper":    strings.ToUpper,
+	"lower":    strings.ToLower,
+	"join":     templateJoin,
+	"truncate": templateTruncate,
+}
+
+// ParseTemplate parses a --template file with TemplateFuncs.
+func ParseTemplate(name string, text string) (*template.Template, error) {
+	parsed, err := template.New(name).Funcs(TemplateFuncs).Parse(text)
+	if err != nil {
+		return nil, fmt.Errorf("parse template: %w", err)
+	}
+
+	return parsed, nil
+}
+
+// RenderTemplate runs tmpl against a command's payload as --format json
+// prints it, so fields go by their JSON names: {{range .issues}}{{.counter}}.
+// Whole numbers are int64, so ids print in full and compare with gt and lt.
+func RenderTemplate(tmpl *template.Template, payload any) (string, error) {
+	body, err := json.Marshal(payload)
+	if err != nil {
+		return "", fmt.Errorf("marshal template data: %w", err)
+	}
+	decoder := json.NewDecoder(bytes.NewReader(body))
+	decoder.UseNumber()
+	var data any
+	if err := decoder.Decode(&data); err != nil {
+		return "", fmt.Errorf("decode template data: %w", err)
+	}
+
+	var rendered bytes.Buffer
+	if err := tmpl.Execute(&rendered, templateNumbers(data)); err != nil {
+		return "", fmt.Errorf("render template: %w", err)
+	}
+
+	return strings.TrimRight(rendered.String(), "\n"), nil
+}
+
+// templateNumbers replaces the json.Numbers in value with int64 or float64.
+func templateNumbers(value any) any {
+	switch typed := value.(type) {
+	case json.Number:
+		if whole, err := typed.Int64(); err == nil {
+			return whole
+		}
+		number, _ := typed.Float64()
+		return number
+	case []any:
+		for index, element := range typed {
+			typed[index] = templateNumbers(element)
+		}
+	case map[string]any:
+		for key, field := range typed {
+			typed[key] = templateNumbers(field)
+		}
+	}
+
+	return value
+}
+
+// templateJSON encodes value as compact JSON, for embedding fields in JSON
+// documents such as Slack blocks.
+func templateJSON(value any) (string, error) {
+	body, err := json.Marshal(value)
+	if err != nil {
+		return "", fmt.Errorf("json: %w", err)
+	}
+
+	return string(body), nil
+}
+
+// templateTime formats unix seconds as RFC 3339 UTC, or "unknown" for
+// anything else.
+func templateTime(value any) string {
+	seconds, ok := value.(int64)
+	if !ok {
+		return "unknown"
+	}
+
+	return time.Unix(seconds, 0).UTC().Format(time.RFC3339)
+}
+
+// templateDefault is value, or fallback when value is missing or empty:
+// {{default "n/a" .environment}}.
+func templateDefault(fallback any, value any) any {
+	if value == nil || value == "" {
+		return fallback
+	}
+
+	return value
+}
+
+func templateJoin(separator string, values []any) string {
+	parts := make([]string, 0, len(values))
+	for _, value := range values {
+		parts = append(parts, fmt.Sprint(value))
+	}
+
+	return strings.Join(parts, separator)
+}
+
+// templateTruncate cuts text to width characters, ending it with "…" when
+// cut.
+func templateTruncate(width int, text string) string {
+	runes := []rune(text)
+	if width <= 0 || len(runes) <= width {
+		return text
+	}
+
+	return string(runes[:width-1]) + "…"
+}
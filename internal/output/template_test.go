@@ -0,0 +1,56 @@
+package output
+
+import (
+	"strings"
+	"testing"
+)
+
+type templateIssue struct {
+	ID          uint64   `json:"id"`
+	Title       string   `json:"title"`
+	Environment string   `json:"environment"`
+	LastSeen    *uint64  `json:"last_seen"`
+	Tags        []string `json:"tags"`
+	Rate        float64  `json:"rate"`
+}
+
+func TestRenderTemplate(t *testing.T) {
+	t.Parallel()
+
+	seen := uint64(1_700_000_000)
+	issue := templateIssue{ID: 9_007_199_254_740_993, Title: "Boom in checkout handler", LastSeen: &seen, Tags: []string{"a", "b"}, Rate: 1.5}
+
+	tests := []struct {
+		text string
+		want string
+	}{
+		{text: "{{.id}} {{.rate}}\n", want: "9007199254740993 1.5"},
+		{text: `{{if gt .id 1}}big{{end}}`, want: "big"},
+		{text: `{{time .last_seen}} {{time .missing}}`, want: "2023-11-14T22:13:20Z unknown"},
+		{text: `{{default "n/a" .environment}} {{default "n/a" .title | upper}}`, want: "n/a BOOM IN CHECKOUT HANDLER"},
+		{text: `{{join "," .tags}} {{truncate 7 .title}} {{truncate 0 .title | lower}}`, want: "a,b Boom i… boom in checkout handler"},
+		{text: `{"text": {{json .title}}}`, want: `{"text": "Boom in checkout handler"}`},
+	}
+
+	for _, tc := range tests {
+		tmpl, err := ParseTemplate("issue", tc.text)
+		if err != nil {
+			t.Fatalf("ParseTemplate(%q) error = %v", tc.text, err)
+		}
+		got, err := RenderTemplate(tmpl, issue)
+		if err != nil || got != tc.want {
+			t.Fatalf("%s = %q, %v; want %q", tc.text, got, err, tc.want)
+		}
+	}
+
+	if _, err := ParseTemplate("bad", "{{.id"); err == nil || !strings.Contains(err.Error(), "parse template") {
+		t.Fatalf("expected parse error, got %v", err)
+	}
+	tmpl, err := ParseTemplate("fails", "{{truncate .title 3}}")
+	if err != nil {
+		t.Fatalf("ParseTemplate() error = %v", err)
+	}
+	if _, err := RenderTemplate(tmpl, issue); err == nil || !strings.Contains(err.Error(), "render template") {
+		t.Fatalf("expected render error, got %v", err)
+	}
+}
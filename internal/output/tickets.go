@@ -0,0 +1,112 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/github"
+	"github.com/kevinsheth/rollbaz/internal/jira"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	ticketTraceFrames = 20
+	// maxGitHubTitle is GitHub's limit on issue titles, in characters.
+	maxGitHubTitle = 256
+	// maxJiraSummary is Jira's limit on issue summaries, in characters.
+	maxJiraSummary = 255
+)
+
+// RenderGitHubIssue builds a GitHub issue for an item: its title prefixed
+// with the counter, and a Markdown body with the item's vitals, the latest
+// stack trace, and a link back to Rollbar.
+func RenderGitHubIssue(source app.TicketSource) github.NewIssue {
+	detail := source.Detail
+	lines := []string{
+		fmt.Sprintf("**%s** in `%s` (%s)", fallback(detail.MainError), fallback(detail.Environment), fallback(detail.Level)),
+		"",
+		"| Rollbar item | Status | Occurrences | First seen | Last seen |",
+		"| --- | --- | --- | --- | --- |",
+		fmt.Sprintf("| [#%s](%s) | %s | %s | %s | %s |", detail.Counter, source.Link, fallback(detail.Status), formatOccurrences(detail.Occurrences), formatTimestamp(detail.FirstOccurrenceTimestamp), formatTimestamp(detail.LastOccurrenceTimestamp)),
+	}
+	if trace, ok := ticketTrace(detail); ok {
+		fence := codeFence(trace)
+		lines = append(lines, "", "### Stack trace", "", fence, trace, fence)
+	}
+	lines = append(lines, "", "[View in Rollbar]("+source.Link+")")
+
+	return github.NewIssue{Title: ticketTitle(detail, maxGitHubTitle), Body: strings.Join(lines, "\n") + "\n"}
+}
+
+// RenderJiraIssue builds the summary and wiki-markup description of a Jira
+// issue for an item, with the same content as RenderGitHubIssue.
+func RenderJiraIssue(source app.TicketSource) jira.NewIssue {
+	detail := source.Detail
+	lines := []string{
+		fmt.Sprintf("*%s* in {{%s}} (%s)", fallback(detail.MainError), fallback(detail.Environment), fallback(detail.Level)),
+		"",
+		"||Rollbar item||Status||Occurrences||First seen||Last seen||",
+		fmt.Sprintf("|[#%s|%s]|%s|%s|%s|%s|", detail.Counter, source.Link, fallback(detail.Status), formatOccurrences(detail.Occurrences), formatTimestamp(detail.FirstOccurrenceTimestamp), formatTimestamp(detail.LastOccurrenceTimestamp)),
+	}
+	if trace, ok := ticketTrace(detail); ok {
+		lines = append(lines, "", "h3. Stack trace", "{noformat}", strings.ReplaceAll(trace, "{noformat}", "{ noformat}"), "{noformat}")
+	}
+	lines = append(lines, "", "[View in Rollbar|"+source.Link+"]")
+
+	return jira.NewIssue{Summary: ticketTitle(detail, maxJiraSummary), Description: strings.Join(lines, "\n")}
+}
+
+// RenderTicketCreated confirms a filed ticket and prints its URL.
+func RenderTicketCreated(result app.TicketResult) string {
+	return fmt.Sprintf("created %s issue %s for item %s\n%s", result.Tracker, result.Key, result.Counter, result.URL)
+}
+
+func ticketTitle(detail app.IssueDetail, maxLength int) string {
+	title := strings.TrimSpace(detail.Title)
+	if title == "" {
+		title = fallback(detail.MainError)
+	}
+
+	return truncateRunes(fmt.Sprintf("[Rollbar #%s] %s", detail.Counter, title), maxLength)
+}
+
+// ticketTrace renders the latest occurrence's stack trace; items whose
+// latest occurrence is a message have none.
+func ticketTrace(detail app.IssueDetail) (string, bool) {
+	if detail.Instance == nil {
+		return "", false
+	}
+
+	body := detail.Instance.OccurrenceBody()
+	if body.Kind != rollbar.BodyKindTrace && body.Kind != rollbar.BodyKindTraceChain {
+		return "", false
+	}
+
+	return RenderOccurrenceBody(body, TraceOptions{MaxFrames: ticketTraceFrames}), true
+}
+
+// codeFence returns a backtick fence longer than any backtick run in text, so
+// the text cannot close the code block early.
+func codeFence(text string) string {
+	longest, run := 0, 0
+	for _, char := range text {
+		if char != '`' {
+			run = 0
+			continue
+		}
+		run++
+		longest = max(longest, run)
+	}
+
+	return strings.Repeat("`", max(3, longest+1))
+}
+
+func truncateRunes(value string, maxLength int) string {
+	runes := []rune(value)
+	if len(runes) <= maxLength {
+		return value
+	}
+
+	return string(runes[:maxLength-3]) + "..."
+}
@@ -0,0 +1,108 @@
+package output
+
+import (
+	"encoding/json"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderGitHubIssue(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(42)
+	firstSeen := uint64(1700000000)
+	trace := &rollbar.ItemInstance{Data: json.RawMessage("{\"body\":{\"trace\":{\"exception\":{\"class\":\"KeyError\",\"message\":\"```id\"},\"frames\":[{\"filename\":\"a.py\",\"lineno\":1,\"method\":\"outer\"}]}}}")}
+	message := &rollbar.ItemInstance{Data: json.RawMessage(`{"body":{"message":{"body":"disk full"}}}`)}
+
+	tests := []struct {
+		name      string
+		detail    app.IssueDetail
+		wantTitle string
+		want      []string
+		notWant   string
+	}{
+		{
+			name: "trace",
+			detail: app.IssueDetail{
+				IssueSummary:             app.IssueSummary{Counter: 269, Title: "KeyError: id", Status: "active", Level: "error", Environment: "production", Occurrences: &occurrences},
+				FirstOccurrenceTimestamp: &firstSeen,
+				MainError:                "KeyError: ```id",
+				Instance:                 trace,
+			},
+			wantTitle: "[Rollbar #269] KeyError: id",
+			want:      []string{"in `production` (error)", "| [#269](https://rollbar.example/269) | active | 42 | 2023-11-14T22:13:20Z | unknown |", "### Stack trace\n\n````\nKeyError: ```id\n  at outer (a.py:1)\n````", "[View in Rollbar](https://rollbar.example/269)"},
+		},
+		{
+			name:      "message",
+			detail:    app.IssueDetail{IssueSummary: app.IssueSummary{Counter: 7}, MainError: "disk full", Instance: message},
+			wantTitle: "[Rollbar #7] disk full",
+			want:      []string{"**disk full** in `unknown` (unknown)"},
+			notWant:   "Stack trace",
+		},
+	}
+	for _, tc := range tests {
+		issue := RenderGitHubIssue(app.TicketSource{Detail: tc.detail, Link: "https://rollbar.example/" + tc.detail.Counter.String()})
+		if issue.Title != tc.wantTitle {
+			t.Fatalf("%s: title = %q, want %q", tc.name, issue.Title, tc.wantTitle)
+		}
+		checkIssueBody(t, tc.name, issue.Body, tc.want, tc.notWant)
+	}
+}
+
+// checkIssueBody fails unless body contains every want and, when notWant is
+// set, does not contain notWant.
+func checkIssueBody(t *testing.T, name string, body string, want []string, notWant string) {
+	t.Helper()
+
+	for _, w := range want {
+		if !strings.Contains(body, w) {
+			t.Fatalf("%s: expected %q in body:\n%s", name, w, body)
+		}
+	}
+	if notWant != "" && strings.Contains(body, notWant) {
+		t.Fatalf("%s: unexpected %q in body:\n%s", name, notWant, body)
+	}
+}
+
+func TestRenderGitHubIssueLongTitle(t *testing.T) {
+	t.Parallel()
+
+	detail := app.IssueDetail{IssueSummary: app.IssueSummary{Counter: 8, Title: strings.Repeat("x", 300)}}
+	issue := RenderGitHubIssue(app.TicketSource{Detail: detail, Link: "https://rollbar.example/8"})
+	if want := "[Rollbar #8] " + strings.Repeat("x", 240) + "..."; issue.Title != want {
+		t.Fatalf("title = %q, want %q", issue.Title, want)
+	}
+}
+
+func TestRenderJiraIssue(t *testing.T) {
+	t.Parallel()
+
+	occurrences := uint64(42)
+	instance := &rollbar.ItemInstance{Data: json.RawMessage(`{"body":{"trace":{"exception":{"class":"KeyError","message":"{noformat}"},"frames":[{"filename":"a.py","lineno":1,"method":"outer"}]}}}`)}
+	source := app.TicketSource{
+		Detail: app.IssueDetail{IssueSummary: app.IssueSummary{Counter: 269, Title: "KeyError", Status: "active", Level: "error", Environment: "production", Occurrences: &occurrences}, MainError: "KeyError", Instance: instance},
+		Link:   "https://rollbar.example/269",
+	}
+
+	issue := RenderJiraIssue(source)
+	if issue.Summary != "[Rollbar #269] KeyError" {
+		t.Fatalf("summary = %q", issue.Summary)
+	}
+	for _, want := range []string{"*KeyError* in {{production}} (error)", "|[#269|https://rollbar.example/269]|active|42|unknown|unknown|", "h3. Stack trace\n{noformat}\nKeyError: { noformat}\n  at outer (a.py:1)\n{noformat}", "[View in Rollbar|https://rollbar.example/269]"} {
+		if !strings.Contains(issue.Description, want) {
+			t.Fatalf("expected %q in description:\n%s", want, issue.Description)
+		}
+	}
+}
+
+func TestRenderTicketCreated(t *testing.T) {
+	t.Parallel()
+
+	got := RenderTicketCreated(app.TicketResult{Counter: 269, Tracker: "github", Key: "acme/api#42", URL: "https://github.com/acme/api/issues/42"})
+	if got != "created github issue acme/api#42 for item 269\nhttps://github.com/acme/api/issues/42" {
+		t.Fatalf("RenderTicketCreated() = %q", got)
+	}
+}
@@ -0,0 +1,87 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// sparkRunes go from an empty bucket to the busiest one.
+var sparkRunes = []rune("▁▂▃▄▅▆▇█")
+
+const (
+	timelineLabelLayout = "2006-01-02 15:04"
+	minSparklineWidth   = 10
+)
+
+// RenderTimelineWithWidth draws an item's timeline as a sparkline scaled to
+// its busiest bucket, wrapped to maxWidth with each line labelled by the UTC
+// time its first bucket starts, then the total and the peak.
+func RenderTimelineWithWidth(timeline app.Timeline, maxWidth int) string {
+	bucket := time.Duration(timeline.BucketSeconds) * time.Second
+	lines := []string{fmt.Sprintf("Item #%s occurrences since %s in %s buckets", timeline.Counter, formatUnix(timeline.Since), formatBucket(bucket))}
+	if timeline.Environment != "" {
+		lines[0] += " (" + timeline.Environment + ")"
+	}
+
+	perLine := max(normalizeWidth(maxWidth, defaultListRowWidth)-len(timelineLabelLayout)-2, minSparklineWidth)
+	for start := 0; start < len(timeline.Buckets); start += perLine {
+		chunk := timeline.Buckets[start:min(start+perLine, len(timeline.Buckets))]
+		label := time.Unix(chunk[0].Timestamp, 0).UTC().Format(timelineLabelLayout)
+		lines = append(lines, label+"  "+Sparkline(chunk, timeline.Peak))
+	}
+
+	if timeline.Total == 0 {
+		return strings.Join(append(lines, "no occurrences in this window"), "\n")
+	}
+	peakAt := timeline.Buckets[0].Timestamp
+	for _, bucket := range timeline.Buckets {
+		if bucket.Count == timeline.Peak {
+			peakAt = bucket.Timestamp
+			break
+		}
+	}
+	lines = append(lines, fmt.Sprintf("total %d, peak %d at %s", timeline.Total, timeline.Peak, formatUnix(peakAt)))
+
+	return strings.Join(lines, "\n")
+}
+
+// Sparkline draws one rune per bucket: the lowest for an empty bucket, and
+// otherwise a height proportional to count/peak, so a single occurrence still
+// shows above an empty bucket.
+func Sparkline(buckets []app.TimelineBucket, peak uint64) string {
+	var builder strings.Builder
+	top := uint64(len(sparkRunes) - 1)
+	for _, bucket := range buckets {
+		level := uint64(0)
+		if bucket.Count > 0 && peak > 0 {
+			level = min((bucket.Count*top+peak-1)/peak, top)
+		}
+		builder.WriteRune(sparkRunes[level])
+	}
+
+	return builder.String()
+}
+
+func formatUnix(seconds int64) string {
+	return time.Unix(seconds, 0).UTC().Format(time.RFC3339)
+}
+
+// formatBucket prints whole days as "1d" rather than time.Duration's "24h0m0s".
+func formatBucket(bucket time.Duration) string {
+	if bucket >= 24*time.Hour && bucket%(24*time.Hour) == 0 {
+		return fmt.Sprintf("%dd", bucket/(24*time.Hour))
+	}
+
+	text := bucket.String()
+	if strings.HasSuffix(text, "m0s") {
+		text = strings.TrimSuffix(text, "0s")
+	}
+	if strings.HasSuffix(text, "h0m") {
+		text = strings.TrimSuffix(text, "0m")
+	}
+
+	return text
+}
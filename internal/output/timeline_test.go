@@ -0,0 +1,64 @@
+package output
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestSparkline(t *testing.T) {
+	t.Parallel()
+
+	buckets := []app.TimelineBucket{{Count: 0}, {Count: 1}, {Count: 4}, {Count: 7}, {Count: 8}}
+	if got := Sparkline(buckets, 8); got != "▁▂▅██" {
+		t.Fatalf("Sparkline() = %q", got)
+	}
+	if got := Sparkline(buckets[:1], 0); got != "▁" {
+		t.Fatalf("Sparkline() of an empty timeline = %q", got)
+	}
+}
+
+func TestRenderTimelineWithWidth(t *testing.T) {
+	t.Parallel()
+
+	since := time.Date(2026, 10, 1, 0, 0, 0, 0, time.UTC).Unix()
+	timeline := app.Timeline{Counter: 42, Environment: "production", Since: since, BucketSeconds: 3600}
+	for index := range 30 {
+		timeline.Buckets = append(timeline.Buckets, app.TimelineBucket{Timestamp: since + int64(index)*3600})
+	}
+
+	empty := RenderTimelineWithWidth(timeline, 40)
+	if !strings.Contains(empty, "Item #42 occurrences since 2026-10-01T00:00:00Z in 1h buckets (production)") || !strings.HasSuffix(empty, "no occurrences in this window") {
+		t.Fatalf("unexpected empty render:\n%s", empty)
+	}
+
+	timeline.Buckets[25].Count, timeline.Total, timeline.Peak = 9, 9, 9
+	lines := strings.Split(RenderTimelineWithWidth(timeline, 40), "\n")
+	want := []string{
+		"2026-10-01 00:00  ▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+		"2026-10-01 22:00  ▁▁▁█▁▁▁▁",
+		"total 9, peak 9 at 2026-10-02T01:00:00Z",
+	}
+	if len(lines) != 4 || strings.Join(lines[1:], "\n") != strings.Join(want, "\n") {
+		t.Fatalf("unexpected render:\n%s", strings.Join(lines, "\n"))
+	}
+}
+
+func TestFormatBucket(t *testing.T) {
+	t.Parallel()
+
+	tests := map[time.Duration]string{
+		time.Minute: "1m",
+		30 * time.Minute: "30m",
+		time.Hour: "1h",
+		90 * time.Minute: "1h30m",
+		48 * time.Hour:   "2d",
+	}
+	for bucket, want := range tests {
+		if got := formatBucket(bucket); got != want {
+			t.Fatalf("formatBucket(%s) = %q, want %q", bucket, got, want)
+		}
+	}
+}
@@ -0,0 +1,71 @@
+package output
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/jedib0t/go-pretty/v6/table"
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	tokenHintLength = 4
+	tokenNonScopes  = 76
+)
+
+// RenderTokenTableWithWidth lists project access tokens a row each. Only the
+// last characters of a token are shown, enough to tell tokens apart.
+func RenderTokenTableWithWidth(tokens []rollbar.ProjectAccessToken, maxWidth int) string {
+	if len(tokens) == 0 {
+		return "no access tokens found"
+	}
+
+	targetWidth := normalizeWidth(maxWidth, defaultListRowWidth)
+	tw := table.NewWriter()
+	tw.SetStyle(table.StyleLight)
+	tw.SetAllowedRowLength(targetWidth)
+	tw.SetColumnConfigs([]table.ColumnConfig{
+		{Number: 3, WidthMax: max(targetWidth-tokenNonScopes, minListTitleWidth), WidthMaxEnforcer: prettytext.Trim},
+	})
+	tw.AppendHeader(table.Row{"NAME", "STATUS", "SCOPES", "RATE LIMIT", "TOKEN", "CREATED"})
+
+	for _, token := range tokens {
+		tw.AppendRow(table.Row{
+			fallback(token.Name),
+			fallback(token.Status),
+			strings.Join(token.Scopes, ", "),
+			formatRateLimit(token.RateLimitWindowSize, token.RateLimitWindowCount),
+			TokenHint(token.AccessToken),
+			formatTimestamp(token.DateCreated),
+		})
+	}
+
+	return strings.TrimRight(tw.Render(), "\n")
+}
+
+// TokenHint is the end of a token, as Rollbar's settings page shows it.
+func TokenHint(token string) string {
+	if len(token) <= tokenHintLength {
+		return "-"
+	}
+
+	return "..." + token[len(token)-tokenHintLength:]
+}
+
+// RenderTokenRotation summarizes a rotation without the new token itself.
+func RenderTokenRotation(rotation app.TokenRotation) string {
+	lines := []string{
+		fmt.Sprintf("created access token %s (%s, %s) to replace %s",
+			rotation.New.Name, strings.Join(rotation.New.Scopes, ", "), TokenHint(rotation.New.AccessToken), rotation.Old.Name),
+	}
+	if rotation.Revoked {
+		lines = append(lines, fmt.Sprintf("revoked access token %s", rotation.Old.Name))
+	} else {
+		lines = append(lines, fmt.Sprintf("access token %s is still enabled; revoke it once clients use the new token", rotation.Old.Name))
+	}
+
+	return strings.Join(lines, "\n")
+}
@@ -0,0 +1,58 @@
+package output
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRenderTokenTable(t *testing.T) {
+	t.Parallel()
+
+	size, count, created := uint64(60), uint64(500), uint64(1767225600)
+	tokens := []rollbar.ProjectAccessToken{
+		{Name: "ci", AccessToken: "secret-token-abcd", Status: "enabled", Scopes: []string{"post_server_item"}, RateLimitWindowSize: &size, RateLimitWindowCount: &count, DateCreated: &created},
+		{Name: "old", AccessToken: "xyz", Status: "disabled", Scopes: []string{"read"}},
+	}
+
+	rendered := RenderTokenTableWithWidth(tokens, 160)
+	for _, want := range []string{"NAME", "ci", "post_server_item", "500/60s", "...abcd", "2026-01-01T00:00:00Z", "disabled", "none"} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("expected %q in:\n%s", want, rendered)
+		}
+	}
+	if strings.Contains(rendered, "secret-token") {
+		t.Fatalf("expected the token masked:\n%s", rendered)
+	}
+	if got := RenderTokenTableWithWidth(nil, 120); got != "no access tokens found" {
+		t.Fatalf("unexpected empty render %q", got)
+	}
+}
+
+func TestRenderTokenRotation(t *testing.T) {
+	t.Parallel()
+
+	rotation := app.TokenRotation{
+		Old: rollbar.ProjectAccessToken{Name: "ci", AccessToken: "old-token-1234"},
+		New: rollbar.ProjectAccessToken{Name: "ci-20261015", AccessToken: "new-token-5678", Scopes: []string{"post_server_item"}},
+	}
+	tests := []struct {
+		revoked bool
+		want    string
+	}{
+		{revoked: false, want: "access token ci is still enabled"},
+		{revoked: true, want: "revoked access token ci"},
+	}
+	for _, tc := range tests {
+		rotation.Revoked = tc.revoked
+		rendered := RenderTokenRotation(rotation)
+		if !strings.Contains(rendered, "created access token ci-20261015 (post_server_item, ...5678) to replace ci") || !strings.Contains(rendered, tc.want) {
+			t.Fatalf("unexpected rotation render:\n%s", rendered)
+		}
+		if strings.Contains(rendered, "new-token") {
+			t.Fatalf("expected the new token masked:\n%s", rendered)
+		}
+	}
+}
@@ -0,0 +1,193 @@
+package output
+
+import (
+	"fmt"
+	"strconv"
+	"strings"
+
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/inapp"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type TraceOptions struct {
+	// Context includes the surrounding source lines the SDK captured.
+	Context bool
+	// MaxFrames keeps only the most recent frames of each trace; zero keeps all.
+	MaxFrames int
+	// Source, when set, replaces each frame's SDK-captured code with the
+	// lines around it from a local checkout.
+	Source SourceLookup
+	// Style prints traces the way their language does; nil keeps the generic
+	// "at" lines, which tickets and reports rely on.
+	Style *TraceStyle
+	// Color highlights traces for a terminal: a Style's colors, and library
+	// frames dimmed.
+	Color bool
+	// InApp tells application frames from library frames, in place of a
+	// Style's vendor rules.
+	InApp *inapp.Rules
+}
+
+// SourceLookup returns the lines around line in filename and the number of
+// the first one, or false when there is no local copy. source.Root.Lines is
+// one.
+type SourceLookup func(filename string, line int) (int, []string, bool)
+
+// RenderOccurrenceBody renders any occurrence body as plain text suitable for a
+// terminal or a report.
+func RenderOccurrenceBody(body rollbar.OccurrenceBody, options TraceOptions) string {
+	switch body.Kind {
+	case rollbar.BodyKindTrace:
+		return RenderTrace(*body.Trace, options)
+	case rollbar.BodyKindTraceChain:
+		parts := make([]string, 0, len(body.TraceChain))
+		for _, trace := range body.TraceChain {
+			parts = append(parts, RenderTrace(trace, options))
+		}
+		return strings.Join(parts, "\n\nCaused by: ")
+	case rollbar.BodyKindMessage:
+		return fallback(body.Message.Body)
+	case rollbar.BodyKindCrashReport:
+		return fallback(body.CrashReport.Raw)
+	default:
+		return fallback(string(body.Raw))
+	}
+}
+
+// RenderTrace renders a trace as "Class: message" followed by one "at" line per
+// frame, most recent call first, or in options.Style. Rollbar stores frames
+// oldest first.
+func RenderTrace(trace rollbar.Trace, options TraceOptions) string {
+	if options.Style != nil {
+		return options.Style.Render(trace, options)
+	}
+
+	frames, hidden := recentFrames(trace, options.MaxFrames)
+	lines := []string{exceptionHeading(trace.Exception)}
+	for _, frame := range frames {
+		lines = append(lines, renderFrame(frame, options)...)
+	}
+	if hidden > 0 {
+		lines = append(lines, "  ... "+strconv.Itoa(hidden)+" more frames")
+	}
+
+	return strings.Join(lines, "\n")
+}
+
+// recentFrames is a trace's frames most recent first, cut to maxFrames when
+// it is positive, and how many were cut.
+func recentFrames(trace rollbar.Trace, maxFrames int) ([]rollbar.Frame, int) {
+	shown := len(trace.Frames)
+	if maxFrames > 0 && maxFrames < shown {
+		shown = maxFrames
+	}
+
+	frames := make([]rollbar.Frame, 0, shown)
+	for index := len(trace.Frames) - 1; index >= len(trace.Frames)-shown; index-- {
+		frames = append(frames, trace.Frames[index])
+	}
+
+	return frames, len(trace.Frames) - shown
+}
+
+func exceptionHeading(exception rollbar.Exception) string {
+	class := strings.TrimSpace(exception.Class)
+	message := strings.TrimSpace(exception.Message)
+	switch {
+	case class == "":
+		return fallback(message)
+	case message == "":
+		return class
+	default:
+		return class + ": " + message
+	}
+}
+
+func renderFrame(frame rollbar.Frame, options TraceOptions) []string {
+	method := strings.TrimSpace(frame.Method)
+	if method == "" {
+		method = "<unknown>"
+	}
+
+	line := "  at " + method + " (" + frameLocation(frame) + ")"
+	if options.library(frame, nil) {
+		line = paint(options.Color, prettytext.Colors{prettytext.Faint}, line)
+	}
+
+	return append([]string{line}, frameCode(frame, options)...)
+}
+
+// library reports whether a frame is library or framework code: by the
+// in_app rules when there are any, and otherwise by style's vendor rules.
+func (o TraceOptions) library(frame rollbar.Frame, style *TraceStyle) bool {
+	if o.InApp != nil {
+		return !o.InApp.InApp(frame.Filename)
+	}
+
+	return style != nil && style.vendor(frame)
+}
+
+// frameCode is the code under a frame: lines from options.Source when it has
+// the file, and otherwise the line the SDK captured, with its context when
+// options.Context is set.
+func frameCode(frame rollbar.Frame, options TraceOptions) []string {
+	if options.Source != nil {
+		if start, source, ok := options.Source(frame.Filename, frame.Lineno); ok {
+			return renderSourceLines(start, source, frame.Lineno)
+		}
+	}
+
+	var lines []string
+	if options.Context && frame.Context != nil {
+		lines = appendCodeLines(lines, frame.Context.Pre)
+	}
+	if code := strings.TrimSpace(frame.Code); code != "" {
+		lines = append(lines, "    > "+code)
+	}
+	if options.Context && frame.Context != nil {
+		lines = appendCodeLines(lines, frame.Context.Post)
+	}
+
+	return lines
+}
+
+func frameLocation(frame rollbar.Frame) string {
+	location := strings.TrimSpace(frame.Filename)
+	if location == "" {
+		location = "<unknown>"
+	}
+	if frame.Lineno > 0 {
+		location += ":" + strconv.Itoa(frame.Lineno)
+		if frame.Colno > 0 {
+			location += ":" + strconv.Itoa(frame.Colno)
+		}
+	}
+
+	return location
+}
+
+// renderSourceLines numbers local source lines like a compiler diagnostic,
+// marking the failing line with ">".
+func renderSourceLines(start int, source []string, failing int) []string {
+	width := len(strconv.Itoa(start + len(source) - 1))
+	lines := make([]string, 0, len(source))
+	for offset, text := range source {
+		marker := " "
+		if start+offset == failing {
+			marker = ">"
+		}
+		lines = append(lines, fmt.Sprintf("    %s %*d | %s", marker, width, start+offset, strings.TrimRight(text, " \t\r")))
+	}
+
+	return lines
+}
+
+func appendCodeLines(lines []string, code []string) []string {
+	for _, line := range code {
+		lines = append(lines, "      "+strings.TrimRight(line, " \t"))
+	}
+
+	return lines
+}
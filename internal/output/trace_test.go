@@ -0,0 +1,111 @@
+package output
+
+import (
+	"encoding/json"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// renderedTrace is a trace of a panic in main.handler, called from main.main,
+// under an unnamed frame.
+var renderedTrace = rollbar.Trace{
+	Exception: rollbar.Exception{Class: "PanicError", Message: "boom"},
+	Frames: []rollbar.Frame{
+		{Filename: "main.go", Lineno: 5, Method: "main.main"},
+		{Filename: "app.go", Lineno: 12, Colno: 3, Method: "main.handler", Code: "panic(err)", Context: &rollbar.FrameContext{Pre: []string{"if err != nil {"}, Post: []string{"}"}}},
+		{},
+	},
+}
+
+// renderedTraceHead is how every rendering of renderedTrace starts.
+const renderedTraceHead = "PanicError: boom\n" +
+	"  at <unknown> (<unknown>)\n" +
+	"  at main.handler (app.go:12:3)\n"
+
+func TestRenderTrace(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name    string
+		options TraceOptions
+		want    string
+	}{
+		{
+			name: "without context",
+			want: renderedTraceHead +
+				"    > panic(err)\n" +
+				"  at main.main (main.go:5)",
+		},
+		{
+			name:    "with context",
+			options: TraceOptions{Context: true},
+			want: renderedTraceHead +
+				"      if err != nil {\n" +
+				"    > panic(err)\n" +
+				"      }\n" +
+				"  at main.main (main.go:5)",
+		},
+		{
+			name:    "max frames",
+			options: TraceOptions{MaxFrames: 2},
+			want: renderedTraceHead +
+				"    > panic(err)\n" +
+				"  ... 1 more frames",
+		},
+	}
+
+	for _, tc := range tests {
+		if got := RenderTrace(renderedTrace, tc.options); got != tc.want {
+			t.Fatalf("%s: unexpected render:\n%s", tc.name, got)
+		}
+	}
+}
+
+func TestRenderTraceLocalSource(t *testing.T) {
+	t.Parallel()
+
+	source := func(filename string, line int) (int, []string, bool) {
+		if filename != "app.go" {
+			return 0, nil, false
+		}
+		return line - 2, []string{"if err != nil {", "\tlog(err)", "\tpanic(err)", "}"}, true
+	}
+	want := renderedTraceHead +
+		"      10 | if err != nil {\n" +
+		"      11 | \tlog(err)\n" +
+		"    > 12 | \tpanic(err)\n" +
+		"      13 | }\n" +
+		"  at main.main (main.go:5)"
+
+	if got := RenderTrace(renderedTrace, TraceOptions{Source: source}); got != want {
+		t.Fatalf("unexpected render:\n%s", got)
+	}
+}
+
+func TestRenderOccurrenceBody(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name string
+		raw  string
+		want string
+	}{
+		{
+			name: "trace chain",
+			raw:  `{"trace_chain":[{"frames":[],"exception":{"class":"Outer","message":"wrapped"}},{"frames":[],"exception":{"message":"cause"}}]}`,
+			want: "Outer: wrapped\n\nCaused by: cause",
+		},
+		{name: "class only", raw: `{"trace":{"frames":[],"exception":{"class":"Timeout"}}}`, want: "Timeout"},
+		{name: "message", raw: `{"message":{"body":"hello"}}`, want: "hello"},
+		{name: "crash report", raw: `{"crash_report":{"raw":"Thread 0 Crashed"}}`, want: "Thread 0 Crashed"},
+		{name: "unknown", raw: `{"other":1}`, want: `{"other":1}`},
+	}
+
+	for _, tc := range tests {
+		body := rollbar.ParseOccurrenceBody(json.RawMessage(tc.raw))
+		if got := RenderOccurrenceBody(body, TraceOptions{}); got != tc.want {
+			t.Fatalf("%s: RenderOccurrenceBody() = %q, want %q", tc.name, got, tc.want)
+		}
+	}
+}
@@ -0,0 +1,154 @@
+package output
+
+import (
+	"path"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// pythonStyle prints a traceback, oldest call first and the exception last.
+func pythonStyle() *TraceStyle {
+	style := &TraceStyle{
+		Name:        "python",
+		Languages:   []string{"python"},
+		Extensions:  []string{".py"},
+		VendorPaths: []string{"site-packages/", "dist-packages/", "/lib/python", "<frozen "},
+	}
+	style.Render = func(trace rollbar.Trace, options TraceOptions) string {
+		frames, hidden := recentFrames(trace, options.MaxFrames)
+		slices.Reverse(frames)
+		lines := append([]string{"Traceback (most recent call last):"}, moreFrames(hidden)...)
+		for _, frame := range frames {
+			h := style.highlighter(frame, options)
+			lines = append(lines, h.finish(`  File "`+h.file(fallback(frame.Filename))+`", line `+h.line(frame.Lineno)+", in "+h.method(fallback(frame.Method))))
+			lines = append(lines, frameCode(frame, options)...)
+		}
+		class, message := exceptionText(trace.Exception, options.Color)
+
+		return strings.Join(append(lines, withMessage(class, message)), "\n")
+	}
+
+	return style
+}
+
+// rubyStyle prints the raise site with the message, then a "from" line per
+// caller.
+func rubyStyle() *TraceStyle {
+	style := &TraceStyle{
+		Name:        "ruby",
+		Languages:   []string{"ruby"},
+		Extensions:  []string{".rb"},
+		VendorPaths: []string{"/gems/", "/vendor/bundle/", "/lib/ruby/"},
+	}
+	style.Render = func(trace rollbar.Trace, options TraceOptions) string {
+		frames, hidden := recentFrames(trace, options.MaxFrames)
+		class, message := exceptionText(trace.Exception, options.Color)
+		lines := []string{fallback(message)}
+		if class != "" {
+			lines[0] += " (" + class + ")"
+		}
+		for index, frame := range frames {
+			h := style.highlighter(frame, options)
+			location := h.file(fallback(frame.Filename)) + ":" + h.line(frame.Lineno) + ":in `" + h.method(fallback(frame.Method)) + "'"
+			if index == 0 {
+				lines[0] = h.finish(location) + ": " + lines[0]
+			} else {
+				lines = append(lines, h.finish("\tfrom "+location))
+			}
+			lines = append(lines, frameCode(frame, options)...)
+		}
+
+		return strings.Join(append(lines, moreFrames(hidden)...), "\n")
+	}
+
+	return style
+}
+
+// javaScriptStyle prints V8's "at" lines, most recent call first.
+func javaScriptStyle() *TraceStyle {
+	style := &TraceStyle{
+		Name:        "javascript",
+		Languages:   []string{"javascript", "js", "node", "typescript"},
+		Extensions:  []string{".js", ".mjs", ".cjs", ".jsx", ".ts", ".tsx"},
+		VendorPaths: []string{"node_modules/", "node:", "<anonymous>"},
+	}
+	style.Render = func(trace rollbar.Trace, options TraceOptions) string {
+		frames, hidden := recentFrames(trace, options.MaxFrames)
+		lines := []string{withMessage(exceptionText(trace.Exception, options.Color))}
+		for _, frame := range frames {
+			h := style.highlighter(frame, options)
+			location := h.location(frame)
+			if method := strings.TrimSpace(frame.Method); method != "" {
+				location = h.method(method) + " (" + location + ")"
+			}
+			lines = append(lines, h.finish("    at "+location))
+			lines = append(lines, frameCode(frame, options)...)
+		}
+
+		return strings.Join(append(lines, moreFrames(hidden)...), "\n")
+	}
+
+	return style
+}
+
+// javaStyle prints a JVM stack trace, most recent call first.
+func javaStyle() *TraceStyle {
+	style := &TraceStyle{
+		Name:           "java",
+		Languages:      []string{"java", "kotlin", "scala"},
+		Extensions:     []string{".java", ".kt", ".scala"},
+		VendorPrefixes: []string{"java.", "javax.", "jdk.", "sun.", "kotlin.", "scala.", "org.springframework.", "org.apache."},
+	}
+	style.Render = func(trace rollbar.Trace, options TraceOptions) string {
+		frames, hidden := recentFrames(trace, options.MaxFrames)
+		lines := []string{withMessage(exceptionText(trace.Exception, options.Color))}
+		for _, frame := range frames {
+			h := style.highlighter(frame, options)
+			method := fallback(frame.Method)
+			if frame.ClassName != "" {
+				method = frame.ClassName + "." + method
+			}
+			lines = append(lines, h.finish("\tat "+h.method(method)+"("+h.file(path.Base(fallback(frame.Filename)))+":"+h.line(frame.Lineno)+")"))
+			lines = append(lines, frameCode(frame, options)...)
+		}
+		if hidden > 0 {
+			lines = append(lines, "\t... "+strconv.Itoa(hidden)+" more")
+		}
+
+		return strings.Join(lines, "\n")
+	}
+
+	return style
+}
+
+// rustStyle prints a RUST_BACKTRACE backtrace, frames numbered from the most
+// recent call.
+func rustStyle() *TraceStyle {
+	style := &TraceStyle{
+		Name:           "rust",
+		Languages:      []string{"rust"},
+		Extensions:     []string{".rs"},
+		VendorPaths:    []string{"/rustc/", ".cargo/registry/", "/library/std/", "/library/core/"},
+		VendorPrefixes: []string{"std::", "core::", "alloc::", "tokio::"},
+	}
+	style.Render = func(trace rollbar.Trace, options TraceOptions) string {
+		frames, hidden := recentFrames(trace, options.MaxFrames)
+		lines := []string{withMessage(exceptionText(trace.Exception, options.Color)), "stack backtrace:"}
+		for index, frame := range frames {
+			h := style.highlighter(frame, options)
+			lines = append(lines, h.finish(padLeft(strconv.Itoa(index), 4)+": "+h.method(fallback(frame.Method))), h.finish("             at "+h.location(frame)))
+			lines = append(lines, frameCode(frame, options)...)
+		}
+
+		return strings.Join(append(lines, moreFrames(hidden)...), "\n")
+	}
+
+	return style
+}
+
+func padLeft(text string, width int) string {
+	return strings.Repeat(" ", max(width-len(text), 0)) + text
+}
@@ -0,0 +1,205 @@
+package output
+
+import (
+	"path"
+	"slices"
+	"strconv"
+	"strings"
+
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// TraceStyle prints traces the way one language's runtime does, with in-app
+// frames emphasized and vendor frames dimmed when colored. in_app rules, when
+// configured, decide which frames are vendor frames instead.
+type TraceStyle struct {
+	// Name selects the style, as in --trace-style python.
+	Name string
+	// Languages are the data.language values SDKs report for it.
+	Languages []string
+	// Extensions recognize its frames by file name when the language is not
+	// reported.
+	Extensions []string
+	// VendorPaths and VendorPrefixes mark library frames: a file path
+	// containing one of the paths, or a method, with its class, starting with
+	// one of the prefixes.
+	VendorPaths    []string
+	VendorPrefixes []string
+	// Render prints one trace; TraceOptions.Style is this style.
+	Render func(trace rollbar.Trace, options TraceOptions) string
+}
+
+var traceStyles = []*TraceStyle{pythonStyle(), rubyStyle(), javaScriptStyle(), javaStyle(), rustStyle()}
+
+// RegisterTraceStyle adds a style for TraceStyleNamed and DetectTraceStyle,
+// replacing any of the same name.
+func RegisterTraceStyle(style *TraceStyle) {
+	traceStyles = slices.DeleteFunc(traceStyles, func(existing *TraceStyle) bool {
+		return existing.Name == style.Name
+	})
+	traceStyles = append(traceStyles, style)
+}
+
+// TraceStyleNames lists the registered styles.
+func TraceStyleNames() []string {
+	names := make([]string, 0, len(traceStyles))
+	for _, style := range traceStyles {
+		names = append(names, style.Name)
+	}
+
+	return names
+}
+
+func TraceStyleNamed(name string) (*TraceStyle, bool) {
+	for _, style := range traceStyles {
+		if strings.EqualFold(style.Name, name) {
+			return style, true
+		}
+	}
+
+	return nil, false
+}
+
+// DetectTraceStyle picks the style for an occurrence from the language its
+// SDK reported or else the file names of its outermost trace's frames, most
+// recent first. It is nil for messages and for traces it does not recognize.
+func DetectTraceStyle(language string, body rollbar.OccurrenceBody) *TraceStyle {
+	var trace rollbar.Trace
+	switch body.Kind {
+	case rollbar.BodyKindTrace:
+		trace = *body.Trace
+	case rollbar.BodyKindTraceChain:
+		trace = body.TraceChain[0]
+	default:
+		return nil
+	}
+
+	language = strings.ToLower(strings.TrimSpace(language))
+	for _, style := range traceStyles {
+		if slices.Contains(style.Languages, language) {
+			return style
+		}
+	}
+	frames, _ := recentFrames(trace, 0)
+	for _, frame := range frames {
+		extension := path.Ext(frame.Filename)
+		for _, style := range traceStyles {
+			if extension != "" && slices.Contains(style.Extensions, extension) {
+				return style
+			}
+		}
+	}
+
+	return nil
+}
+
+func (s *TraceStyle) vendor(frame rollbar.Frame) bool {
+	for _, vendorPath := range s.VendorPaths {
+		if strings.Contains(frame.Filename, vendorPath) {
+			return true
+		}
+	}
+	qualified := frame.Method
+	if frame.ClassName != "" {
+		qualified = frame.ClassName + "." + frame.Method
+	}
+	for _, prefix := range s.VendorPrefixes {
+		if strings.HasPrefix(qualified, prefix) {
+			return true
+		}
+	}
+
+	return false
+}
+
+// highlighter colors the parts of one trace line: the whole line is dimmed
+// for a vendor frame, and otherwise its parts are highlighted.
+type highlighter struct {
+	color  bool
+	vendor bool
+}
+
+func (s *TraceStyle) highlighter(frame rollbar.Frame, options TraceOptions) highlighter {
+	return highlighter{color: options.Color, vendor: options.library(frame, s)}
+}
+
+func (h highlighter) method(text string) string {
+	return h.part(prettytext.Colors{prettytext.FgYellow, prettytext.Bold}, text)
+}
+
+func (h highlighter) file(text string) string {
+	return h.part(prettytext.Colors{prettytext.FgCyan}, text)
+}
+
+func (h highlighter) line(number int) string {
+	return h.part(prettytext.Colors{prettytext.FgGreen}, strconv.Itoa(number))
+}
+
+// location is file:line, with :column when the SDK sent one.
+func (h highlighter) location(frame rollbar.Frame) string {
+	location := h.file(fallback(frame.Filename)) + ":" + h.line(frame.Lineno)
+	if frame.Colno > 0 {
+		location += ":" + h.line(frame.Colno)
+	}
+
+	return location
+}
+
+func (h highlighter) part(colors prettytext.Colors, text string) string {
+	if h.vendor {
+		return text
+	}
+
+	return paint(h.color, colors, text)
+}
+
+// finish dims a vendor frame's line once it is assembled.
+func (h highlighter) finish(line string) string {
+	if !h.vendor {
+		return line
+	}
+
+	return paint(h.color, prettytext.Colors{prettytext.Faint}, line)
+}
+
+// exceptionText colors an exception class and leaves its message plain.
+func exceptionText(exception rollbar.Exception, color bool) (string, string) {
+	class := strings.TrimSpace(exception.Class)
+	if class != "" {
+		class = paint(color, prettytext.Colors{prettytext.FgRed, prettytext.Bold}, class)
+	}
+
+	return class, strings.TrimSpace(exception.Message)
+}
+
+// paint wraps text in ANSI colors when color is set. The caller decides, so
+// go-pretty's own NO_COLOR check is not consulted.
+func paint(color bool, colors prettytext.Colors, text string) string {
+	if !color || len(colors) == 0 {
+		return text
+	}
+
+	return colors.EscapeSeq() + text + prettytext.EscapeReset
+}
+
+// withMessage joins a class and message as most runtimes print them.
+func withMessage(class string, message string) string {
+	switch {
+	case class == "":
+		return fallback(message)
+	case message == "":
+		return class
+	default:
+		return class + ": " + message
+	}
+}
+
+func moreFrames(hidden int) []string {
+	if hidden == 0 {
+		return nil
+	}
+
+	return []string{"  ... " + strconv.Itoa(hidden) + " more frames"}
+}
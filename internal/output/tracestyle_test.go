@@ -0,0 +1,202 @@
+package output
+
+import (
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/inapp"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// traceStyleTests renders a trace in each built-in style, one capped at a
+// single frame and one without frames.
+var traceStyleTests = []struct {
+	style     string
+	trace     rollbar.Trace
+	maxFrames int
+	want      string
+}{
+	{
+		style: "python",
+		trace: rollbar.Trace{Exception: rollbar.Exception{Class: "ValueError", Message: "bad cart"}, Frames: []rollbar.Frame{
+			{Filename: "/usr/lib/python3.12/site-packages/flask/app.py", Lineno: 880, Method: "full_dispatch_request"},
+			{Filename: "app/views.py", Lineno: 12, Method: "checkout", Code: "charge(cart)"},
+		}},
+		want: "Traceback (most recent call last):\n" +
+			"  File \"/usr/lib/python3.12/site-packages/flask/app.py\", line 880, in full_dispatch_request\n" +
+			"  File \"app/views.py\", line 12, in checkout\n" +
+			"    > charge(cart)\n" +
+			"ValueError: bad cart",
+	},
+	{
+		style: "ruby",
+		trace: rollbar.Trace{Exception: rollbar.Exception{Class: "RuntimeError", Message: "boom"}, Frames: []rollbar.Frame{
+			{Filename: "/gems/rack-3/lib/rack.rb", Lineno: 10, Method: "call"},
+			{Filename: "app/models/cart.rb", Lineno: 7, Method: "total"},
+		}},
+		want: "app/models/cart.rb:7:in `total': boom (RuntimeError)\n" +
+			"\tfrom /gems/rack-3/lib/rack.rb:10:in `call'",
+	},
+	{
+		style: "javascript",
+		trace: rollbar.Trace{Exception: rollbar.Exception{Class: "TypeError", Message: "x is undefined"}, Frames: []rollbar.Frame{
+			{Filename: "node_modules/express/router.js", Lineno: 5, Colno: 2, Method: "handle"},
+			{Filename: "src/pay.ts", Lineno: 3, Colno: 9, Method: "charge"},
+			{Filename: "src/index.js", Lineno: 1},
+		}},
+		want: "TypeError: x is undefined\n" +
+			"    at src/index.js:1\n" +
+			"    at charge (src/pay.ts:3:9)\n" +
+			"    at handle (node_modules/express/router.js:5:2)",
+	},
+	{
+		style: "java",
+		trace: rollbar.Trace{Exception: rollbar.Exception{Class: "java.lang.IllegalStateException", Message: "empty cart"}, Frames: []rollbar.Frame{
+			{Filename: "Thread.java", Lineno: 833, Method: "run", ClassName: "java.lang.Thread"},
+			{Filename: "src/main/java/com/acme/Cart.java", Lineno: 42, Method: "total", ClassName: "com.acme.Cart"},
+		}},
+		maxFrames: 1,
+		want: "java.lang.IllegalStateException: empty cart\n" +
+			"\tat com.acme.Cart.total(Cart.java:42)\n" +
+			"\t... 1 more",
+	},
+	{
+		style: "rust",
+		trace: rollbar.Trace{Exception: rollbar.Exception{Class: "panic", Message: "index out of bounds"}, Frames: []rollbar.Frame{
+			{Filename: "/rustc/abc/library/std/src/rt.rs", Lineno: 148, Method: "std::rt::lang_start"},
+			{Filename: "src/main.rs", Lineno: 12, Colno: 5, Method: "app::main"},
+		}},
+		want: "panic: index out of bounds\n" +
+			"stack backtrace:\n" +
+			"   0: app::main\n" +
+			"             at src/main.rs:12:5\n" +
+			"   1: std::rt::lang_start\n" +
+			"             at /rustc/abc/library/std/src/rt.rs:148",
+	},
+	{
+		style: "ruby",
+		trace: rollbar.Trace{Exception: rollbar.Exception{Message: "no frames"}},
+		want:  "no frames",
+	},
+}
+
+func TestTraceStyles(t *testing.T) {
+	t.Parallel()
+
+	for _, tc := range traceStyleTests {
+		style, ok := TraceStyleNamed(tc.style)
+		if !ok {
+			t.Fatalf("no %s style", tc.style)
+		}
+		if got := RenderTrace(tc.trace, TraceOptions{Style: style, MaxFrames: tc.maxFrames}); got != tc.want {
+			t.Fatalf("%s: unexpected render:\n%s", tc.style, got)
+		}
+	}
+}
+
+func TestTraceStyleColors(t *testing.T) {
+	t.Parallel()
+
+	style, _ := TraceStyleNamed("javascript")
+	trace := rollbar.Trace{Exception: rollbar.Exception{Class: "TypeError", Message: "x is undefined"}, Frames: []rollbar.Frame{
+		{Filename: "node_modules/express/router.js", Lineno: 5, Colno: 2, Method: "handle"},
+		{Filename: "src/pay.ts", Lineno: 3, Colno: 9, Method: "charge"},
+	}}
+
+	rendered := RenderTrace(trace, TraceOptions{Style: style, Color: true})
+	for _, want := range []string{
+		"\x1b[31;1mTypeError\x1b[0m: x is undefined",
+		"    at \x1b[33;1mcharge\x1b[0m (\x1b[36msrc/pay.ts\x1b[0m:\x1b[32m3\x1b[0m:\x1b[32m9\x1b[0m)",
+		"\x1b[2m    at handle (node_modules/express/router.js:5:2)\x1b[0m",
+	} {
+		if !strings.Contains(rendered, want) {
+			t.Fatalf("missing %q in:\n%q", want, rendered)
+		}
+	}
+}
+
+func TestTraceInApp(t *testing.T) {
+	t.Parallel()
+
+	rules, err := inapp.Parse([]string{"node_modules/express/**"})
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+	style, _ := TraceStyleNamed("javascript")
+	trace := rollbar.Trace{Exception: rollbar.Exception{Class: "TypeError"}, Frames: []rollbar.Frame{
+		{Filename: "node_modules/express/router.js", Lineno: 5, Method: "handle"},
+		{Filename: "src/pay.ts", Lineno: 3, Method: "charge"},
+	}}
+
+	styled := RenderTrace(trace, TraceOptions{Style: style, Color: true, InApp: rules})
+	for _, want := range []string{
+		"\x1b[2m    at charge (src/pay.ts:3)\x1b[0m",
+		"    at \x1b[33;1mhandle\x1b[0m (\x1b[36mnode_modules/express/router.js\x1b[0m:\x1b[32m5\x1b[0m)",
+	} {
+		if !strings.Contains(styled, want) {
+			t.Fatalf("missing %q in:\n%q", want, styled)
+		}
+	}
+
+	generic := RenderTrace(trace, TraceOptions{Color: true, InApp: rules})
+	if want := "TypeError\n\x1b[2m  at charge (src/pay.ts:3)\x1b[0m\n  at handle (node_modules/express/router.js:5)"; generic != want {
+		t.Fatalf("unexpected generic render:\n%q", generic)
+	}
+	if plain := RenderTrace(trace, TraceOptions{InApp: rules}); strings.Contains(plain, "\x1b") {
+		t.Fatalf("expected no colors without Color:\n%q", plain)
+	}
+}
+
+func TestDetectTraceStyle(t *testing.T) {
+	t.Parallel()
+
+	trace := func(filenames ...string) *rollbar.Trace {
+		frames := make([]rollbar.Frame, 0, len(filenames))
+		for _, filename := range filenames {
+			frames = append(frames, rollbar.Frame{Filename: filename})
+		}
+		return &rollbar.Trace{Frames: frames}
+	}
+
+	tests := []struct {
+		name     string
+		language string
+		body     rollbar.OccurrenceBody
+		want     string
+	}{
+		{name: "language", language: " Python ", body: rollbar.OccurrenceBody{Kind: rollbar.BodyKindTrace, Trace: trace("main.go")}, want: "python"},
+		{name: "recent frame", body: rollbar.OccurrenceBody{Kind: rollbar.BodyKindTrace, Trace: trace("boot.rs", "Makefile", "app/cart.rb")}, want: "ruby"},
+		{name: "chain", language: "go", body: rollbar.OccurrenceBody{Kind: rollbar.BodyKindTraceChain, TraceChain: []rollbar.Trace{*trace("Cart.kt")}}, want: "java"},
+		{name: "unknown", body: rollbar.OccurrenceBody{Kind: rollbar.BodyKindTrace, Trace: trace("main.go")}},
+		{name: "message", language: "python", body: rollbar.OccurrenceBody{Kind: rollbar.BodyKindMessage, Message: &rollbar.Message{Body: "hi"}}},
+	}
+
+	for _, tc := range tests {
+		got := DetectTraceStyle(tc.language, tc.body)
+		if (got == nil && tc.want != "") || (got != nil && got.Name != tc.want) {
+			t.Fatalf("%s: DetectTraceStyle() = %+v, want %q", tc.name, got, tc.want)
+		}
+	}
+}
+
+func TestRegisterTraceStyle(t *testing.T) {
+	original := slices.Clone(traceStyles)
+	t.Cleanup(func() { traceStyles = original })
+
+	RegisterTraceStyle(&TraceStyle{Name: "go", Extensions: []string{".go"}, Render: func(trace rollbar.Trace, options TraceOptions) string {
+		return "goroutine 1 [running]:"
+	}})
+	RegisterTraceStyle(&TraceStyle{Name: "ruby", Render: func(trace rollbar.Trace, options TraceOptions) string { return "replaced" }})
+
+	if names := TraceStyleNames(); !slices.Equal(names, []string{"python", "javascript", "java", "rust", "go", "ruby"}) {
+		t.Fatalf("TraceStyleNames() = %v", names)
+	}
+	body := rollbar.OccurrenceBody{Kind: rollbar.BodyKindTrace, Trace: &rollbar.Trace{Frames: []rollbar.Frame{{Filename: "main.go"}}}}
+	if got := RenderOccurrenceBody(body, TraceOptions{Style: DetectTraceStyle("", body)}); got != "goroutine 1 [running]:" {
+		t.Fatalf("unexpected render %q", got)
+	}
+	if _, ok := TraceStyleNamed("cobol"); ok {
+		t.Fatal("expected no cobol style")
+	}
+}
@@ -0,0 +1,56 @@
+package output
+
+import (
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// RenderWatchEvent renders one streamed watch line:
+// "15:04:05 NEW        #269 error production RST_STREAM (+7)".
+func RenderWatchEvent(event app.WatchEvent, at time.Time) string {
+	parts := []string{
+		at.Format(time.TimeOnly),
+		padRight(strings.ToUpper(string(event.Kind)), len(app.WatchEventReoccurred)),
+		"#" + event.Issue.Counter.String(),
+		fallback(event.Issue.Level),
+		fallback(event.Issue.Environment),
+		fallback(event.Issue.Title),
+	}
+	line := strings.Join(parts, " ")
+	if event.NewOccurrences > 0 {
+		line += " (+" + strconv.FormatUint(event.NewOccurrences, 10) + ")"
+	}
+
+	return line
+}
+
+// WatchEventHeading describes an event in words for notifications:
+// "New item" or "Occurred again (+7)".
+func WatchEventHeading(event app.WatchEvent) string {
+	heading := "New item"
+	if event.Kind == app.WatchEventReoccurred {
+		heading = "Occurred again"
+	}
+	if event.NewOccurrences > 0 {
+		heading += " (+" + strconv.FormatUint(event.NewOccurrences, 10) + ")"
+	}
+
+	return heading
+}
+
+// RenderWatchNotification is the title and body of a desktop notification:
+// "Rollbar #269: New item" over the item title.
+func RenderWatchNotification(event app.WatchEvent) (string, string) {
+	return "Rollbar #" + event.Issue.Counter.String() + ": " + WatchEventHeading(event), fallback(event.Issue.Title)
+}
+
+func padRight(value string, width int) string {
+	if len(value) >= width {
+		return value
+	}
+
+	return value + strings.Repeat(" ", width-len(value))
+}
@@ -0,0 +1,59 @@
+package output
+
+import (
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func TestRenderWatchEvent(t *testing.T) {
+	t.Parallel()
+
+	at := time.Date(2026, 2, 19, 15, 4, 5, 0, time.UTC)
+	tests := []struct {
+		event app.WatchEvent
+		want  string
+	}{
+		{
+			event: app.WatchEvent{Kind: app.WatchEventNew, NewOccurrences: 7, Issue: app.IssueSummary{Counter: 269, Level: "error", Environment: "production", Title: "RST_STREAM"}},
+			want:  "15:04:05 NEW        #269 error production RST_STREAM (+7)",
+		},
+		{
+			event: app.WatchEvent{Kind: app.WatchEventReoccurred, Issue: app.IssueSummary{Counter: 3}},
+			want:  "15:04:05 REOCCURRED #3 unknown unknown unknown",
+		},
+	}
+
+	for _, tc := range tests {
+		if got := RenderWatchEvent(tc.event, at); got != tc.want {
+			t.Fatalf("RenderWatchEvent() = %q, want %q", got, tc.want)
+		}
+	}
+}
+
+func TestWatchEventHeading(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		event app.WatchEvent
+		want  string
+	}{
+		{event: app.WatchEvent{Kind: app.WatchEventNew}, want: "New item"},
+		{event: app.WatchEvent{Kind: app.WatchEventReoccurred, NewOccurrences: 7}, want: "Occurred again (+7)"},
+	}
+	for _, tc := range tests {
+		if got := WatchEventHeading(tc.event); got != tc.want {
+			t.Fatalf("WatchEventHeading() = %q, want %q", got, tc.want)
+		}
+	}
+}
+
+func TestRenderWatchNotification(t *testing.T) {
+	t.Parallel()
+
+	title, body := RenderWatchNotification(app.WatchEvent{Kind: app.WatchEventNew, Issue: app.IssueSummary{Counter: 269, Title: "KeyError: id"}})
+	if title != "Rollbar #269: New item" || body != "KeyError: id" {
+		t.Fatalf("RenderWatchNotification() = %q, %q", title, body)
+	}
+}
@@ -0,0 +1,45 @@
+package output
+
+import (
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/webhook"
+)
+
+var webhookHeadings = map[string]string{
+	webhook.EventNewItem:         "New item",
+	webhook.EventOccurrence:      "Occurrence",
+	webhook.EventRepeatItem:      "Repeated",
+	webhook.EventItemVelocity:    "High velocity",
+	webhook.EventReactivatedItem: "Reactivated",
+	webhook.EventReopenedItem:    "Reopened",
+	webhook.EventResolvedItem:    "Resolved",
+	webhook.EventDeploy:          "Deploy",
+	webhook.EventTest:            "Test",
+}
+
+// WebhookEventHeading describes a webhook event in words for notifications;
+// unknown events keep their Rollbar name.
+func WebhookEventHeading(name string) string {
+	if heading, ok := webhookHeadings[name]; ok {
+		return heading
+	}
+
+	return name
+}
+
+// RenderWebhookEvent renders one received webhook as a log line:
+// "15:04:05 new_item #4 error production RST_STREAM" for items, and
+// "15:04:05 deploy production abc123" for deploys.
+func RenderWebhookEvent(event webhook.Event, at time.Time) string {
+	parts := []string{at.Format(time.TimeOnly), event.Name}
+	if issue, ok := app.WebhookIssue(event); ok {
+		parts = append(parts, "#"+issue.Counter.String(), fallback(issue.Level), fallback(issue.Environment), fallback(issue.Title))
+	} else if deploy := event.Data.Deploy; deploy != nil {
+		parts = append(parts, fallback(deploy.Environment), fallback(deploy.Revision))
+	}
+
+	return strings.Join(parts, " ")
+}
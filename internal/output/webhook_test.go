@@ -0,0 +1,44 @@
+package output
+
+import (
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+	"github.com/kevinsheth/rollbaz/internal/webhook"
+)
+
+func TestRenderWebhookEvent(t *testing.T) {
+	t.Parallel()
+
+	at := time.Date(2026, 1, 2, 15, 4, 5, 0, time.UTC)
+	tests := []struct {
+		event webhook.Event
+		want  string
+	}{
+		{
+			event: webhook.Event{Name: webhook.EventNewItem, Data: webhook.Data{Item: &rollbar.Item{Counter: 4, Title: "boom", Level: "error", Environment: "production"}}},
+			want:  "15:04:05 new_item #4 error production boom",
+		},
+		{
+			event: webhook.Event{Name: webhook.EventDeploy, Data: webhook.Data{Deploy: &rollbar.Deploy{Environment: "production", Revision: "abc123"}}},
+			want:  "15:04:05 deploy production abc123",
+		},
+		{event: webhook.Event{Name: webhook.EventTest}, want: "15:04:05 test"},
+	}
+	for _, tc := range tests {
+		if got := RenderWebhookEvent(tc.event, at); got != tc.want {
+			t.Fatalf("RenderWebhookEvent() = %q, want %q", got, tc.want)
+		}
+	}
+}
+
+func TestWebhookEventHeading(t *testing.T) {
+	t.Parallel()
+
+	for name, want := range map[string]string{webhook.EventReactivatedItem: "Reactivated", "custom_event": "custom_event"} {
+		if got := WebhookEventHeading(name); got != want {
+			t.Fatalf("WebhookEventHeading(%q) = %q, want %q", name, got, want)
+		}
+	}
+}
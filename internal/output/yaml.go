@@ -0,0 +1,127 @@
+package output
+
+import (
+	"encoding/json"
+	"fmt"
+	"regexp"
+	"strings"
+)
+
+// plainYAMLScalar matches strings that YAML reads back as the same string
+// without quoting.
+var plainYAMLScalar = regexp.MustCompile(`^[A-Za-z_/][A-Za-z0-9_./ -]*$`)
+
+var yamlReserved = map[string]bool{
+	"true": true, "false": true, "yes": true, "no": true, "on": true, "off": true,
+	"null": true, "y": true, "n": true,
+}
+
+// RenderYAML encodes value as block-style YAML with sorted keys. Strings are
+// double-quoted with JSON escaping whenever a plain scalar could be misread.
+func RenderYAML(value any) (string, error) {
+	generic, err := toGeneric(value)
+	if err != nil {
+		return "", err
+	}
+
+	var builder strings.Builder
+	writeYAML(&builder, generic, 0)
+
+	return strings.TrimRight(builder.String(), "\n"), nil
+}
+
+func writeYAML(builder *strings.Builder, value any, indent int) {
+	prefix := strings.Repeat("  ", indent)
+	switch typed := value.(type) {
+	case map[string]any:
+		if len(typed) == 0 {
+			break
+		}
+		for _, key := range sortedKeys(typed) {
+			builder.WriteString(prefix + yamlString(key) + ":")
+			writeYAMLChild(builder, typed[key], indent+1)
+		}
+		return
+	case []any:
+		if len(typed) == 0 {
+			break
+		}
+		for _, element := range typed {
+			writeYAMLListElement(builder, element, indent)
+		}
+		return
+	}
+
+	builder.WriteString(prefix + yamlInline(value) + "\n")
+}
+
+func writeYAMLListElement(builder *strings.Builder, element any, indent int) {
+	prefix := strings.Repeat("  ", indent)
+	if !isBlock(element) {
+		builder.WriteString(prefix + "- " + yamlInline(element) + "\n")
+		return
+	}
+
+	// Put the first line of a nested block on the dash: "- key: value".
+	var nested strings.Builder
+	writeYAML(&nested, element, indent+1)
+	builder.WriteString(prefix + "- " + strings.TrimPrefix(nested.String(), prefix+"  "))
+}
+
+// writeYAMLChild continues a "key:" line: scalars and empty collections stay
+// inline, anything else starts an indented block.
+func writeYAMLChild(builder *strings.Builder, value any, indent int) {
+	if !isBlock(value) {
+		builder.WriteString(" " + yamlInline(value) + "\n")
+		return
+	}
+
+	builder.WriteString("\n")
+	writeYAML(builder, value, indent)
+}
+
+func isBlock(value any) bool {
+	switch typed := value.(type) {
+	case map[string]any:
+		return len(typed) > 0
+	case []any:
+		return len(typed) > 0
+	default:
+		return false
+	}
+}
+
+func yamlInline(value any) string {
+	switch typed := value.(type) {
+	case nil:
+		return "null"
+	case map[string]any:
+		return "{}"
+	case []any:
+		return "[]"
+	case bool:
+		if typed {
+			return "true"
+		}
+		return "false"
+	case json.Number:
+		return typed.String()
+	case string:
+		return yamlString(typed)
+	default:
+		return yamlString(fmt.Sprint(typed))
+	}
+}
+
+func yamlString(value string) string {
+	if plainYAMLScalar.MatchString(value) && !yamlReserved[strings.ToLower(value)] && !strings.HasSuffix(value, " ") {
+		return value
+	}
+
+	quoted, err := json.Marshal(value)
+	if err != nil {
+		return `""`
+	}
+
+	return string(quoted)
+}
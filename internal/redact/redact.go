@@ -7,21 +7,24 @@ import (
 	"strings"
 )
 
+// Placeholder stands in for a redacted secret.
+const Placeholder = "[REDACTED]"
+
 var accessTokenQueryPattern = regexp.MustCompile(`([?&]access_token=)[^&\s]+`)
 
-var sensitiveKeyWords = []string{"token", "authorization", "secret", "password", "api_key", "apikey"}
+var sensitiveKeyWords = []string{"token", "authorization", "secret", "password", "api_key", "apikey", "service_key", "routing_key"}
 
 func String(value string, token string) string {
 	if value == "" {
 		return value
 	}
 
-	redacted := accessTokenQueryPattern.ReplaceAllString(value, `${1}[REDACTED]`)
+	redacted := accessTokenQueryPattern.ReplaceAllString(value, "${1}"+Placeholder)
 	if token == "" {
 		return redacted
 	}
 
-	return strings.ReplaceAll(redacted, token, "[REDACTED]")
+	return strings.ReplaceAll(redacted, token, Placeholder)
 }
 
 func Value(value any, token string) any {
@@ -40,8 +43,8 @@ func Value(value any, token string) any {
 func redactMap(value map[string]any, token string) map[string]any {
 	clean := make(map[string]any, len(value))
 	for key, nested := range value {
-		if isSensitiveKey(key) {
-			clean[key] = "[REDACTED]"
+		if IsSensitiveKey(key) {
+			clean[key] = Placeholder
 			continue
 		}
 		clean[key] = Value(nested, token)
@@ -81,7 +84,9 @@ func redactStructured(value any, token string) any {
 	return Value(decoded, token)
 }
 
-func isSensitiveKey(key string) bool {
+// IsSensitiveKey reports whether a map key names a credential, such as a
+// PagerDuty service_key in a notification config, whose value is redacted.
+func IsSensitiveKey(key string) bool {
 	lower := strings.ToLower(key)
 	for _, keyword := range sensitiveKeyWords {
 		if strings.Contains(lower, keyword) {
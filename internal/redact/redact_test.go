@@ -100,3 +100,24 @@ func TestValueStruct(t *testing.T) {
 		t.Fatalf("expected struct token redacted, got %v", got["access_token"])
 	}
 }
+
+func TestIsSensitiveKey(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		key  string
+		want bool
+	}{
+		{key: "access_token", want: true},
+		{key: "Service_Key", want: true},
+		{key: "routing_key", want: true},
+		{key: "channel", want: false},
+		{key: "url", want: false},
+	}
+
+	for _, tt := range tests {
+		if got := IsSensitiveKey(tt.key); got != tt.want {
+			t.Fatalf("IsSensitiveKey(%q) = %v, want %v", tt.key, got, tt.want)
+		}
+	}
+}
@@ -0,0 +1,90 @@
+package redact
+
+import (
+	"encoding/json"
+	"testing"
+)
+
+func TestScrubberScrub(t *testing.T) {
+	t.Parallel()
+
+	scrubber, err := NewScrubber([]string{"Email", " "}, []string{`(ssn=)\d{3}-\d{2}-\d{4}`, `\b\d{16}\b`})
+	if err != nil {
+		t.Fatalf("NewScrubber() error = %v", err)
+	}
+
+	type person struct {
+		Email string `json:"email"`
+		Name  string `json:"name"`
+	}
+
+	tests := []struct {
+		name  string
+		input any
+		want  string
+	}{
+		{name: "field match", input: map[string]any{"user_EMAIL": "a@b.c", "id": 1}, want: `{"id":1,"user_EMAIL":"*****"}`},
+		{name: "pattern keeps prefix", input: "lookup ssn=123-45-6789 failed", want: `"lookup ssn=***** failed"`},
+		{name: "pattern without group", input: []any{"card 4242424242424242"}, want: `["card *****"]`},
+		{name: "struct", input: person{Email: "a@b.c", Name: "Ann"}, want: `{"email":"*****","name":"Ann"}`},
+		{name: "typed map", input: map[string]string{"email": "a@b.c"}, want: `{"email":"*****"}`},
+		{name: "raw json", input: json.RawMessage(`{"email":"a@b.c"}`), want: `{"email":"*****"}`},
+		{name: "scalar", input: 42, want: `42`},
+		{name: "nil", input: nil, want: `null`},
+	}
+
+	for _, tc := range tests {
+		encoded, err := json.Marshal(scrubber.Scrub(tc.input))
+		if err != nil {
+			t.Fatalf("%s: marshal: %v", tc.name, err)
+		}
+		if string(encoded) != tc.want {
+			t.Fatalf("%s: Scrub() = %s, want %s", tc.name, encoded, tc.want)
+		}
+	}
+}
+
+func TestDefaultScrubberScrubJSON(t *testing.T) {
+	t.Parallel()
+
+	scrubber := DefaultScrubber()
+	tests := []struct {
+		name  string
+		input string
+		want  string
+	}{
+		{
+			name:  "headers and url",
+			input: `{"headers":{"Cookie":"sid=1","X-Rollbar-Access-Token":"abc","Accept":"*/*"},"url":"https://x/?access_token=abc&page=2"}`,
+			want:  `{"headers":{"Accept":"*/*","Cookie":"*****","X-Rollbar-Access-Token":"*****"},"url":"https://x/?access_token=*****&page=2"}`,
+		},
+		{name: "invalid json unchanged", input: `{nope`, want: `{nope`},
+		{name: "empty unchanged", input: ``, want: ``},
+	}
+
+	for _, tc := range tests {
+		if got := string(scrubber.ScrubJSON(json.RawMessage(tc.input))); got != tc.want {
+			t.Fatalf("%s: ScrubJSON() = %s, want %s", tc.name, got, tc.want)
+		}
+	}
+}
+
+func TestNewScrubberInvalidPattern(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewScrubber(nil, []string{"("}); err == nil {
+		t.Fatalf("expected pattern compile error")
+	}
+}
+
+func TestScrubField(t *testing.T) {
+	t.Parallel()
+
+	scrubber := DefaultScrubber()
+	if got := scrubber.ScrubField("Set-Cookie", "sid=1"); got != Scrubbed {
+		t.Fatalf("ScrubField(Set-Cookie) = %q", got)
+	}
+	if got := scrubber.ScrubField("Referer", "https://x/?access_token=abc"); got != "https://x/?access_token=*****" {
+		t.Fatalf("ScrubField(Referer) = %q", got)
+	}
+}
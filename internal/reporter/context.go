@@ -0,0 +1,118 @@
+package reporter
+
+import (
+	"net"
+	"net/http"
+	"net/url"
+	"os"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// RequestFromHTTP captures the parts of an inbound request worth attaching to
+// an occurrence. Headers, path params, and query values pass through the
+// default scrubber, so cookies, authorization, and tokens never leave the
+// process; the URL is recorded without its query for the same reason. params
+// are router path parameters and may be nil.
+func RequestFromHTTP(request *http.Request, params map[string]string) *rollbar.Request {
+	scrubber := redact.DefaultScrubber()
+
+	headers := make(map[string]string, len(request.Header))
+	for key := range request.Header {
+		headers[key] = request.Header.Get(key)
+	}
+
+	captured := &rollbar.Request{
+		URL:         requestURL(request),
+		Method:      request.Method,
+		Headers:     scrubStrings(scrubber, headers),
+		Params:      scrubStrings(scrubber, params),
+		Query:       scrubQuery(scrubber, request),
+		QueryString: scrubQueryString(scrubber, request.URL.RawQuery),
+	}
+	if host, _, err := net.SplitHostPort(request.RemoteAddr); err == nil {
+		captured.UserIP = host
+	}
+
+	return captured
+}
+
+// LocalServer describes the host the process runs on.
+func LocalServer(codeVersion string) *rollbar.Server {
+	server := &rollbar.Server{CodeVersion: codeVersion}
+	if host, err := os.Hostname(); err == nil {
+		server.Host = host
+	}
+	if root, err := os.Getwd(); err == nil {
+		server.Root = root
+	}
+
+	return server
+}
+
+func requestURL(request *http.Request) string {
+	scheme := "http"
+	if request.TLS != nil {
+		scheme = "https"
+	}
+	host := request.Host
+	if request.URL.IsAbs() {
+		scheme, host = request.URL.Scheme, request.URL.Host
+	}
+
+	return scheme + "://" + host + request.URL.EscapedPath()
+}
+
+// scrubQueryString scrubs each parameter in place so the raw string keeps its
+// original order and encoding.
+func scrubQueryString(scrubber *redact.Scrubber, rawQuery string) string {
+	if rawQuery == "" {
+		return ""
+	}
+
+	parts := strings.Split(rawQuery, "&")
+	for index, part := range parts {
+		key, value, found := strings.Cut(part, "=")
+		if !found {
+			continue
+		}
+		name, err := url.QueryUnescape(key)
+		if err != nil {
+			name = key
+		}
+		parts[index] = key + "=" + scrubber.ScrubField(name, value)
+	}
+
+	return strings.Join(parts, "&")
+}
+
+func scrubStrings(scrubber *redact.Scrubber, values map[string]string) map[string]string {
+	if len(values) == 0 {
+		return nil
+	}
+
+	clean := make(map[string]string, len(values))
+	for key, value := range values {
+		clean[key] = scrubber.ScrubField(key, value)
+	}
+
+	return clean
+}
+
+func scrubQuery(scrubber *redact.Scrubber, request *http.Request) map[string][]string {
+	query := request.URL.Query()
+	if len(query) == 0 {
+		return nil
+	}
+
+	clean := make(map[string][]string, len(query))
+	for key, values := range query {
+		for _, value := range values {
+			clean[key] = append(clean[key], scrubber.ScrubField(key, value))
+		}
+	}
+
+	return clean
+}
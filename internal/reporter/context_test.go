@@ -0,0 +1,83 @@
+package reporter
+
+import (
+	"context"
+	"encoding/json"
+	"net/http/httptest"
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestRequestFromHTTPScrubs(t *testing.T) {
+	t.Parallel()
+
+	request := httptest.NewRequest("POST", "https://shop.example.com/checkout/42?access_token=abc&step=2&password=hunter2", nil)
+	request.Header.Set("Cookie", "sid=abc")
+	request.Header.Set("Authorization", "Bearer abc")
+	request.Header.Set("Accept", "text/html")
+	request.RemoteAddr = "203.0.113.9:51234"
+
+	captured := RequestFromHTTP(request, map[string]string{"order": "42", "session_id": "s1"})
+
+	if captured.URL != "https://shop.example.com/checkout/42" || captured.Method != "POST" || captured.UserIP != "203.0.113.9" {
+		t.Fatalf("unexpected request basics: %+v", captured)
+	}
+	if !reflect.DeepEqual(captured.Headers, map[string]string{"Cookie": redact.Scrubbed, "Authorization": redact.Scrubbed, "Accept": "text/html"}) {
+		t.Fatalf("unexpected headers: %v", captured.Headers)
+	}
+	if !reflect.DeepEqual(captured.Params, map[string]string{"order": "42", "session_id": redact.Scrubbed}) {
+		t.Fatalf("unexpected params: %v", captured.Params)
+	}
+	if !reflect.DeepEqual(captured.Query, map[string][]string{"step": {"2"}, "password": {redact.Scrubbed}, "access_token": {redact.Scrubbed}}) {
+		t.Fatalf("unexpected query: %v", captured.Query)
+	}
+	if captured.QueryString != "access_token=*****&step=2&password=*****" {
+		t.Fatalf("query string not scrubbed: %q", captured.QueryString)
+	}
+}
+
+func TestRequestFromHTTPMinimal(t *testing.T) {
+	t.Parallel()
+
+	request := httptest.NewRequest("GET", "/health", nil)
+	request.RemoteAddr = "not-an-address"
+
+	captured := RequestFromHTTP(request, nil)
+	if captured.URL != "http://example.com/health" || captured.Params != nil || captured.Query != nil || captured.QueryString != "" || captured.UserIP != "" {
+		t.Fatalf("unexpected minimal request: %+v", captured)
+	}
+}
+
+func TestReportCarriesContextBlocks(t *testing.T) {
+	t.Parallel()
+
+	data := rollbar.NewMessageReport("production", rollbar.LevelError, "x")
+	data.Person = &rollbar.Person{ID: "7", Email: "a@example.com"}
+	data.Server = LocalServer("abc123")
+	data.Request = RequestFromHTTP(httptest.NewRequest("GET", "/", nil), nil)
+
+	encoded, err := json.Marshal(data)
+	if err != nil {
+		t.Fatalf("marshal: %v", err)
+	}
+	for _, want := range []string{`"person":{"id":"7","email":"a@example.com"}`, `"code_version":"abc123"`, `"request":{"url":"http://example.com/"`} {
+		if !strings.Contains(string(encoded), want) {
+			t.Fatalf("expected %s in %s", want, encoded)
+		}
+	}
+	if data.Server.Host == "" || data.Server.Root == "" {
+		t.Fatalf("expected host and root: %+v", data.Server)
+	}
+
+	sender := &fakeSender{}
+	if _, err := Scrub(sender, redact.DefaultScrubber()).ReportItem(context.Background(), data); err != nil {
+		t.Fatalf("ReportItem() error = %v", err)
+	}
+	if sent := sender.sent()[0]; sent.Person == nil || sent.Person.ID != "7" {
+		t.Fatalf("person should survive scrubbing: %+v", sent.Person)
+	}
+}
@@ -0,0 +1,74 @@
+package reporter
+
+import (
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// fingerprintLength keeps fingerprints within the 40 characters Rollbar
+// stores verbatim.
+const fingerprintLength = 40
+
+// FingerprintFunc picks the Rollbar grouping key for an occurrence. Returning
+// "" leaves grouping to Rollbar.
+type FingerprintFunc func(data rollbar.ReportData) string
+
+type fingerprintingSender struct {
+	next        Sender
+	fingerprint FingerprintFunc
+}
+
+// Fingerprint returns a Sender that sets data.Fingerprint from fingerprint
+// unless the caller already chose one.
+func Fingerprint(next Sender, fingerprint FingerprintFunc) Sender {
+	return fingerprintingSender{next: next, fingerprint: fingerprint}
+}
+
+func (s fingerprintingSender) ReportItem(ctx context.Context, data rollbar.ReportData) (string, error) {
+	if data.Fingerprint == "" {
+		data.Fingerprint = s.fingerprint(data)
+	}
+
+	uuid, err := s.next.ReportItem(ctx, data)
+	if err != nil {
+		return "", fmt.Errorf("send fingerprinted occurrence: %w", err)
+	}
+
+	return uuid, nil
+}
+
+// ByExceptionAndTopFrame groups trace occurrences by exception class and the
+// most recent frame whose filename contains appPathPrefix, so the same failure
+// reached through different library code lands in one item. Messages and
+// traces without an app frame fall back to Rollbar's grouping.
+func ByExceptionAndTopFrame(appPathPrefix string) FingerprintFunc {
+	return func(data rollbar.ReportData) string {
+		trace := data.Body.Trace
+		if trace == nil && len(data.Body.TraceChain) > 0 {
+			trace = &data.Body.TraceChain[0]
+		}
+		if trace == nil {
+			return ""
+		}
+
+		for index := len(trace.Frames) - 1; index >= 0; index-- {
+			frame := trace.Frames[index]
+			if strings.Contains(frame.Filename, appPathPrefix) {
+				return hashFingerprint(trace.Exception.Class, frame.Filename, frame.Method)
+			}
+		}
+
+		return ""
+	}
+}
+
+func hashFingerprint(parts ...string) string {
+	sum := sha256.Sum256([]byte(strings.Join(parts, "\x00")))
+
+	return hex.EncodeToString(sum[:])[:fingerprintLength]
+}
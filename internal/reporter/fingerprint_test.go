@@ -0,0 +1,66 @@
+package reporter
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestFingerprintSetsGroupingKey(t *testing.T) {
+	t.Parallel()
+
+	sender := &fakeSender{}
+	fingerprinted := Fingerprint(sender, func(data rollbar.ReportData) string { return "group-" + data.Environment })
+
+	explicit := rollbar.NewMessageReport("production", rollbar.LevelError, "x")
+	explicit.Fingerprint = "chosen"
+	for _, data := range []rollbar.ReportData{rollbar.NewMessageReport("production", rollbar.LevelError, "x"), explicit} {
+		if _, err := fingerprinted.ReportItem(context.Background(), data); err != nil {
+			t.Fatalf("ReportItem() error = %v", err)
+		}
+	}
+
+	sent := sender.sent()
+	if sent[0].Fingerprint != "group-production" || sent[1].Fingerprint != "chosen" {
+		t.Fatalf("unexpected fingerprints: %q %q", sent[0].Fingerprint, sent[1].Fingerprint)
+	}
+
+	failing := Fingerprint(&fakeSender{err: errSend}, ByExceptionAndTopFrame("/app/"))
+	if _, err := failing.ReportItem(context.Background(), explicit); !errors.Is(err, errSend) {
+		t.Fatalf("expected send error, got %v", err)
+	}
+}
+
+func TestByExceptionAndTopFrame(t *testing.T) {
+	t.Parallel()
+
+	fingerprint := ByExceptionAndTopFrame("/app/")
+	trace := func(class string, frames ...rollbar.Frame) rollbar.ReportData {
+		return rollbar.NewTraceReport("production", rollbar.LevelError, rollbar.Trace{Exception: rollbar.Exception{Class: class}, Frames: frames})
+	}
+	handler := rollbar.Frame{Filename: "/app/handler.go", Method: "handle"}
+
+	first := fingerprint(trace("Timeout", handler, rollbar.Frame{Filename: "/go/pkg/net/http.go", Method: "Do"}))
+	second := fingerprint(trace("Timeout", rollbar.Frame{Filename: "/app/main.go"}, handler, rollbar.Frame{Filename: "/go/pkg/sql.go"}))
+	other := fingerprint(trace("Canceled", handler))
+
+	if len(first) != fingerprintLength || first != second {
+		t.Fatalf("same class and app frame should group together: %q %q", first, second)
+	}
+	if first == other {
+		t.Fatalf("different classes should not group together")
+	}
+
+	chain := rollbar.ReportData{Body: rollbar.ReportBody{TraceChain: []rollbar.Trace{{Exception: rollbar.Exception{Class: "Timeout"}, Frames: []rollbar.Frame{handler}}}}}
+	if fingerprint(chain) != first {
+		t.Fatalf("trace chains should use the outermost trace")
+	}
+	if fingerprint(trace("Timeout", rollbar.Frame{Filename: "/go/pkg/sql.go"})) != "" {
+		t.Fatalf("traces without app frames should fall back to default grouping")
+	}
+	if fingerprint(rollbar.NewMessageReport("production", rollbar.LevelError, "x")) != "" {
+		t.Fatalf("messages should fall back to default grouping")
+	}
+}
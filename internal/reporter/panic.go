@@ -0,0 +1,92 @@
+package reporter
+
+import (
+	"context"
+	"fmt"
+	"runtime"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	defaultPanicTimeout = 5 * time.Second
+	maxPanicFrames      = 64
+)
+
+type PanicOptions struct {
+	Environment string
+	CodeVersion string
+	// Timeout bounds the report; zero means five seconds.
+	Timeout time.Duration
+	// Repanic re-raises the panic after reporting instead of swallowing it.
+	Repanic bool
+	// OnError receives report failures, which are otherwise dropped.
+	OnError func(error)
+}
+
+// Recover reports a panic in progress as a critical trace occurrence. It must
+// be deferred directly:
+//
+//	defer reporter.Recover(client, reporter.PanicOptions{Environment: "production", Repanic: true})
+func Recover(sender Sender, options PanicOptions) {
+	value := recover()
+	if value == nil {
+		return
+	}
+
+	ReportPanic(sender, options, value, panicTrace(value, 3))
+	if options.Repanic {
+		panic(value)
+	}
+}
+
+// ReportPanic sends an already recovered panic value together with its trace.
+func ReportPanic(sender Sender, options PanicOptions, value any, trace rollbar.Trace) {
+	timeout := options.Timeout
+	if timeout <= 0 {
+		timeout = defaultPanicTimeout
+	}
+	ctx, cancel := context.WithTimeout(context.Background(), timeout)
+	defer cancel()
+
+	data := rollbar.NewTraceReport(options.Environment, rollbar.LevelCritical, trace)
+	data.CodeVersion = options.CodeVersion
+	if _, err := sender.ReportItem(ctx, data); err != nil && options.OnError != nil {
+		options.OnError(fmt.Errorf("report panic: %w", err))
+	}
+}
+
+// panicTrace captures the current goroutine's stack, oldest frame first,
+// dropping the runtime's own panic machinery and skip frames of the caller.
+func panicTrace(value any, skip int) rollbar.Trace {
+	pcs := make([]uintptr, maxPanicFrames)
+	count := runtime.Callers(skip, pcs)
+	callers := runtime.CallersFrames(pcs[:count])
+
+	var frames []rollbar.Frame
+	for {
+		frame, more := callers.Next()
+		if !strings.HasPrefix(frame.Function, "runtime.") {
+			frames = append(frames, rollbar.Frame{Filename: frame.File, Lineno: frame.Line, Method: frame.Function})
+		}
+		if !more {
+			break
+		}
+	}
+
+	for left, right := 0, len(frames)-1; left < right; left, right = left+1, right-1 {
+		frames[left], frames[right] = frames[right], frames[left]
+	}
+
+	return rollbar.Trace{Frames: frames, Exception: panicException(value)}
+}
+
+func panicException(value any) rollbar.Exception {
+	if err, ok := value.(error); ok {
+		return rollbar.Exception{Class: fmt.Sprintf("%T", err), Message: err.Error()}
+	}
+
+	return rollbar.Exception{Class: "panic", Message: fmt.Sprint(value)}
+}
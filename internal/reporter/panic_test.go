@@ -0,0 +1,97 @@
+package reporter
+
+import (
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func panicsWith(sender Sender, options PanicOptions, value any) {
+	defer Recover(sender, options)
+	panic(value)
+}
+
+func TestRecoverSwallowsAndReports(t *testing.T) {
+	t.Parallel()
+
+	sender := &fakeSender{}
+	panicsWith(sender, PanicOptions{Environment: "production", CodeVersion: "abc"}, "boom")
+
+	reports := sender.sent()
+	if len(reports) != 1 {
+		t.Fatalf("expected one report, got %d", len(reports))
+	}
+	report := reports[0]
+	if report.Level != rollbar.LevelCritical || report.CodeVersion != "abc" || report.Environment != "production" {
+		t.Fatalf("unexpected report: %+v", report)
+	}
+	trace := report.Body.Trace
+	if trace == nil || trace.Exception.Class != "panic" || trace.Exception.Message != "boom" {
+		t.Fatalf("unexpected trace: %+v", trace)
+	}
+	checkPanicFrames(t, trace.Frames)
+}
+
+// checkPanicFrames checks that frames end at panicsWith and leave out the
+// runtime.
+func checkPanicFrames(t *testing.T, frames []rollbar.Frame) {
+	t.Helper()
+
+	last := frames[len(frames)-1]
+	if !strings.HasSuffix(last.Method, "reporter.panicsWith") || !strings.HasSuffix(last.Filename, "panic_test.go") {
+		t.Fatalf("innermost frame should be the panicking function, got %+v", last)
+	}
+	for _, frame := range frames {
+		if strings.HasPrefix(frame.Method, "runtime.") {
+			t.Fatalf("runtime frames should be dropped: %+v", frame)
+		}
+	}
+}
+
+func TestRecoverRepanics(t *testing.T) {
+	t.Parallel()
+
+	sender := &fakeSender{}
+	cause := errors.New("bad state")
+
+	recovered := func() (value any) {
+		defer func() { value = recover() }()
+		panicsWith(sender, PanicOptions{Environment: "production", Repanic: true}, cause)
+		return nil
+	}()
+
+	if recovered != cause {
+		t.Fatalf("expected the original panic value, got %v", recovered)
+	}
+	reports := sender.sent()
+	if len(reports) != 1 || reports[0].Body.Trace.Exception.Class != "*errors.errorString" || reports[0].Body.Trace.Exception.Message != "bad state" {
+		t.Fatalf("unexpected reports: %+v", reports)
+	}
+}
+
+func TestRecoverWithoutPanic(t *testing.T) {
+	t.Parallel()
+
+	sender := &fakeSender{}
+	func() {
+		defer Recover(sender, PanicOptions{Environment: "production"})
+	}()
+
+	if len(sender.sent()) != 0 {
+		t.Fatalf("nothing should be reported without a panic")
+	}
+}
+
+func TestReportPanicSurfacesErrors(t *testing.T) {
+	t.Parallel()
+
+	var got error
+	sender := &fakeSender{err: errSend}
+	ReportPanic(sender, PanicOptions{Environment: "production", OnError: func(err error) { got = err }}, "boom", rollbar.Trace{})
+
+	if !errors.Is(got, errSend) {
+		t.Fatalf("expected send error, got %v", got)
+	}
+}
@@ -0,0 +1,283 @@
+package reporter
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"sync"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	defaultQueueCapacity  = 1000
+	defaultQueueBatchSize = 50
+	defaultQueueAttempts  = 3
+	defaultQueueRetry     = time.Second
+	defaultQueueTimeout   = 10 * time.Second
+)
+
+var (
+	ErrQueueFull   = errors.New("reporter queue is full")
+	ErrQueueClosed = errors.New("reporter queue is closed")
+)
+
+type QueueOptions struct {
+	// Capacity bounds buffered occurrences; zero means 1000. Reports beyond it
+	// are dropped with ErrQueueFull rather than blocking the caller.
+	Capacity int
+	// BatchSize is how many buffered occurrences are sent per wake-up; zero
+	// means 50.
+	BatchSize int
+	// MinInterval spaces out consecutive sends to stay under Rollbar's limits.
+	MinInterval time.Duration
+	// MaxAttempts counts the first send; zero means three.
+	MaxAttempts int
+	// RetryDelay is the wait between attempts when Rollbar gives no
+	// Retry-After; zero means one second.
+	RetryDelay time.Duration
+	// Timeout bounds each send; zero means ten seconds.
+	Timeout time.Duration
+	// Spool, when set, keeps occurrences that exhausted their attempts and
+	// replays them after the next successful send.
+	Spool *Spool
+	// OnError receives occurrences that could not be delivered or spooled.
+	OnError func(error)
+}
+
+// Queue buffers occurrences and sends them from one background goroutine so
+// reporting never blocks the caller. It implements Sender, so it can sit
+// between Handler or Recover and the client.
+type Queue struct {
+	sender  Sender
+	options QueueOptions
+	entries chan queueEntry
+	done    chan struct{}
+	sleep   func(context.Context, time.Duration) error
+
+	mu     sync.RWMutex
+	closed bool
+}
+
+type queueEntry struct {
+	data    rollbar.ReportData
+	flushed chan struct{}
+}
+
+func NewQueue(sender Sender, options QueueOptions) *Queue {
+	options = withQueueDefaults(options)
+	queue := &Queue{
+		sender:  sender,
+		options: options,
+		entries: make(chan queueEntry, options.Capacity),
+		done:    make(chan struct{}),
+		sleep:   sleepContext,
+	}
+	go queue.run()
+
+	return queue
+}
+
+func withQueueDefaults(options QueueOptions) QueueOptions {
+	if options.Capacity <= 0 {
+		options.Capacity = defaultQueueCapacity
+	}
+	if options.BatchSize <= 0 {
+		options.BatchSize = defaultQueueBatchSize
+	}
+	if options.MaxAttempts <= 0 {
+		options.MaxAttempts = defaultQueueAttempts
+	}
+	if options.RetryDelay <= 0 {
+		options.RetryDelay = defaultQueueRetry
+	}
+	if options.Timeout <= 0 {
+		options.Timeout = defaultQueueTimeout
+	}
+
+	return options
+}
+
+// ReportItem enqueues data without waiting for delivery. The returned UUID is
+// always empty because Rollbar has not seen the occurrence yet.
+func (q *Queue) ReportItem(_ context.Context, data rollbar.ReportData) (string, error) {
+	q.mu.RLock()
+	defer q.mu.RUnlock()
+
+	if q.closed {
+		return "", ErrQueueClosed
+	}
+
+	select {
+	case q.entries <- queueEntry{data: data}:
+		return "", nil
+	default:
+		return "", ErrQueueFull
+	}
+}
+
+// Flush waits until every occurrence enqueued before the call has been sent or
+// given up on.
+func (q *Queue) Flush(ctx context.Context) error {
+	flushed := make(chan struct{})
+	if err := q.enqueueFlush(ctx, flushed); err != nil {
+		return err
+	}
+
+	select {
+	case <-flushed:
+		return nil
+	case <-ctx.Done():
+		return fmt.Errorf("flush reporter queue: %w", ctx.Err())
+	}
+}
+
+// Close stops accepting occurrences, delivers what is buffered, and stops the
+// background goroutine. Call it before the process exits.
+func (q *Queue) Close(ctx context.Context) error {
+	q.mu.Lock()
+	if q.closed {
+		q.mu.Unlock()
+		return ErrQueueClosed
+	}
+	q.closed = true
+	close(q.entries)
+	q.mu.Unlock()
+
+	select {
+	case <-q.done:
+		return nil
+	case <-ctx.Done():
+		return fmt.Errorf("close reporter queue: %w", ctx.Err())
+	}
+}
+
+// enqueueFlush holds the read lock while blocked on a full buffer so Close
+// cannot close the channel underneath it; the worker keeps draining meanwhile.
+func (q *Queue) enqueueFlush(ctx context.Context, flushed chan struct{}) error {
+	q.mu.RLock()
+	defer q.mu.RUnlock()
+
+	if q.closed {
+		return ErrQueueClosed
+	}
+
+	select {
+	case q.entries <- queueEntry{flushed: flushed}:
+		return nil
+	case <-ctx.Done():
+		return fmt.Errorf("flush reporter queue: %w", ctx.Err())
+	}
+}
+
+func (q *Queue) run() {
+	defer close(q.done)
+
+	for entry := range q.entries {
+		batch := q.collectBatch(entry)
+		for _, queued := range batch {
+			if queued.flushed != nil {
+				close(queued.flushed)
+				continue
+			}
+			q.deliver(queued.data)
+		}
+	}
+}
+
+// collectBatch gathers up to BatchSize entries that are already buffered.
+func (q *Queue) collectBatch(first queueEntry) []queueEntry {
+	batch := []queueEntry{first}
+	for len(batch) < q.options.BatchSize {
+		select {
+		case entry, ok := <-q.entries:
+			if !ok {
+				return batch
+			}
+			batch = append(batch, entry)
+		default:
+			return batch
+		}
+	}
+
+	return batch
+}
+
+func (q *Queue) deliver(data rollbar.ReportData) {
+	if err := q.sendWithRetries(data); err != nil {
+		q.handleFailure(data, err)
+	} else {
+		q.replaySpool()
+	}
+	if q.options.MinInterval > 0 {
+		_ = q.sleep(context.Background(), q.options.MinInterval)
+	}
+}
+
+func (q *Queue) sendWithRetries(data rollbar.ReportData) error {
+	var err error
+	for attempt := 1; attempt <= q.options.MaxAttempts; attempt++ {
+		if err = q.send(data); err == nil {
+			return nil
+		}
+		if attempt < q.options.MaxAttempts {
+			_ = q.sleep(context.Background(), q.retryDelay(err))
+		}
+	}
+
+	return fmt.Errorf("deliver occurrence after %d attempts: %w", q.options.MaxAttempts, err)
+}
+
+func (q *Queue) handleFailure(data rollbar.ReportData, err error) {
+	if q.options.Spool != nil {
+		err = q.options.Spool.Write(data)
+	}
+	if err != nil && q.options.OnError != nil {
+		q.options.OnError(err)
+	}
+}
+
+func (q *Queue) replaySpool() {
+	spool := q.options.Spool
+	if spool == nil || !spool.Pending() {
+		return
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), q.options.Timeout)
+	defer cancel()
+	if _, err := spool.Replay(ctx, q.sender); err != nil && q.options.OnError != nil {
+		q.options.OnError(err)
+	}
+}
+
+func (q *Queue) send(data rollbar.ReportData) error {
+	ctx, cancel := context.WithTimeout(context.Background(), q.options.Timeout)
+	defer cancel()
+
+	if _, err := q.sender.ReportItem(ctx, data); err != nil {
+		return fmt.Errorf("send occurrence: %w", err)
+	}
+
+	return nil
+}
+
+func (q *Queue) retryDelay(err error) time.Duration {
+	if wait, limited := rollbar.IsRateLimited(err); limited && wait > 0 {
+		return wait
+	}
+
+	return q.options.RetryDelay
+}
+
+func sleepContext(ctx context.Context, delay time.Duration) error {
+	timer := time.NewTimer(delay)
+	defer timer.Stop()
+
+	select {
+	case <-ctx.Done():
+		return fmt.Errorf("context done: %w", ctx.Err())
+	case <-timer.C:
+		return nil
+	}
+}
@@ -0,0 +1,159 @@
+package reporter
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type flakySender struct {
+	failures atomic.Int32
+	err      error
+	fakeSender
+}
+
+func (f *flakySender) ReportItem(ctx context.Context, data rollbar.ReportData) (string, error) {
+	if f.failures.Add(-1) >= 0 {
+		return "", f.err
+	}
+
+	return f.fakeSender.ReportItem(ctx, data)
+}
+
+func newTestQueue(sender Sender, options QueueOptions) (*Queue, *[]time.Duration) {
+	queue := NewQueue(sender, options)
+	var mu sync.Mutex
+	slept := []time.Duration{}
+	queue.sleep = func(_ context.Context, delay time.Duration) error {
+		mu.Lock()
+		defer mu.Unlock()
+		slept = append(slept, delay)
+		return nil
+	}
+
+	return queue, &slept
+}
+
+func TestQueueDeliversOnFlushAndClose(t *testing.T) {
+	t.Parallel()
+
+	sender := &fakeSender{}
+	queue := NewQueue(sender, QueueOptions{BatchSize: 2})
+
+	enqueueMessages(t, queue, "a", "b", "c")
+	if err := queue.Flush(context.Background()); err != nil {
+		t.Fatalf("Flush() error = %v", err)
+	}
+	if got := len(sender.sent()); got != 3 {
+		t.Fatalf("expected 3 delivered after flush, got %d", got)
+	}
+
+	enqueueMessages(t, queue, "d")
+	if err := queue.Close(context.Background()); err != nil {
+		t.Fatalf("Close() error = %v", err)
+	}
+
+	reports := sender.sent()
+	if len(reports) != 4 || reports[3].Body.Message.Body != "d" {
+		t.Fatalf("unexpected reports after close: %d", len(reports))
+	}
+	if _, err := queue.ReportItem(context.Background(), reports[0]); !errors.Is(err, ErrQueueClosed) {
+		t.Fatalf("expected ErrQueueClosed, got %v", err)
+	}
+	if err := queue.Flush(context.Background()); !errors.Is(err, ErrQueueClosed) {
+		t.Fatalf("expected ErrQueueClosed from Flush, got %v", err)
+	}
+	if err := queue.Close(context.Background()); !errors.Is(err, ErrQueueClosed) {
+		t.Fatalf("expected ErrQueueClosed from second Close, got %v", err)
+	}
+}
+
+func enqueueMessages(t *testing.T, queue *Queue, messages ...string) {
+	t.Helper()
+
+	for _, message := range messages {
+		if _, err := queue.ReportItem(context.Background(), rollbar.NewMessageReport("production", rollbar.LevelError, message)); err != nil {
+			t.Fatalf("ReportItem() error = %v", err)
+		}
+	}
+}
+
+func TestQueueRetriesAndHonorsRateLimits(t *testing.T) {
+	t.Parallel()
+
+	sender := &flakySender{err: &rollbar.Error{Kind: rollbar.ErrorKindRateLimited, StatusCode: http.StatusTooManyRequests, RetryAfter: 7 * time.Second}}
+	sender.failures.Store(1)
+	queue, slept := newTestQueue(sender, QueueOptions{MaxAttempts: 3, MinInterval: time.Second})
+
+	_, _ = queue.ReportItem(context.Background(), rollbar.NewMessageReport("production", rollbar.LevelError, "x"))
+	if err := queue.Close(context.Background()); err != nil {
+		t.Fatalf("Close() error = %v", err)
+	}
+
+	if len(sender.sent()) != 1 {
+		t.Fatalf("expected delivery after retry")
+	}
+	if len(*slept) != 2 || (*slept)[0] != 7*time.Second || (*slept)[1] != time.Second {
+		t.Fatalf("unexpected sleeps: %v", *slept)
+	}
+}
+
+func TestQueueGivesUpAfterMaxAttempts(t *testing.T) {
+	t.Parallel()
+
+	var failed atomic.Value
+	sender := &flakySender{err: errSend}
+	sender.failures.Store(10)
+	queue, _ := newTestQueue(sender, QueueOptions{MaxAttempts: 2, OnError: func(err error) { failed.Store(err) }})
+
+	_, _ = queue.ReportItem(context.Background(), rollbar.NewMessageReport("production", rollbar.LevelError, "x"))
+	if err := queue.Close(context.Background()); err != nil {
+		t.Fatalf("Close() error = %v", err)
+	}
+
+	err, _ := failed.Load().(error)
+	if !errors.Is(err, errSend) || sender.failures.Load() != 8 {
+		t.Fatalf("expected two failed attempts, err=%v remaining=%d", err, sender.failures.Load())
+	}
+}
+
+func TestQueueDropsWhenFull(t *testing.T) {
+	t.Parallel()
+
+	block := make(chan struct{})
+	sender := &blockingSender{release: block}
+	queue := NewQueue(sender, QueueOptions{Capacity: 1})
+
+	data := rollbar.NewMessageReport("production", rollbar.LevelError, "x")
+	var sawFull bool
+	for range 5 {
+		if _, err := queue.ReportItem(context.Background(), data); errors.Is(err, ErrQueueFull) {
+			sawFull = true
+		}
+	}
+	close(block)
+	if !sawFull {
+		t.Fatalf("expected ErrQueueFull once the buffer filled")
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), 5*time.Second)
+	defer cancel()
+	if err := queue.Close(ctx); err != nil {
+		t.Fatalf("Close() error = %v", err)
+	}
+}
+
+type blockingSender struct {
+	release chan struct{}
+}
+
+func (b *blockingSender) ReportItem(context.Context, rollbar.ReportData) (string, error) {
+	<-b.release
+	return "", nil
+}
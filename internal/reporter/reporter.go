@@ -0,0 +1,12 @@
+package reporter
+
+import (
+	"context"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// Sender delivers one occurrence to Rollbar. *rollbar.Client satisfies it.
+type Sender interface {
+	ReportItem(ctx context.Context, data rollbar.ReportData) (string, error)
+}
@@ -0,0 +1,36 @@
+package reporter
+
+import (
+	"context"
+	"errors"
+	"sync"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeSender struct {
+	mu      sync.Mutex
+	reports []rollbar.ReportData
+	err     error
+}
+
+func (f *fakeSender) ReportItem(_ context.Context, data rollbar.ReportData) (string, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	if f.err != nil {
+		return "", f.err
+	}
+	f.reports = append(f.reports, data)
+
+	return "uuid", nil
+}
+
+func (f *fakeSender) sent() []rollbar.ReportData {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+
+	return append([]rollbar.ReportData(nil), f.reports...)
+}
+
+var errSend = errors.New("send failed")
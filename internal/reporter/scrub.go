@@ -0,0 +1,50 @@
+package reporter
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type scrubbingSender struct {
+	next     Sender
+	scrubber *redact.Scrubber
+}
+
+// Scrub returns a Sender that scrubs each occurrence before handing it to next.
+// Put it outermost, in front of any Queue, so buffered and spooled copies are
+// already clean.
+func Scrub(next Sender, scrubber *redact.Scrubber) Sender {
+	return scrubbingSender{next: next, scrubber: scrubber}
+}
+
+func (s scrubbingSender) ReportItem(ctx context.Context, data rollbar.ReportData) (string, error) {
+	clean, err := scrubReport(s.scrubber, data)
+	if err != nil {
+		return "", err
+	}
+
+	uuid, err := s.next.ReportItem(ctx, clean)
+	if err != nil {
+		return "", fmt.Errorf("send scrubbed occurrence: %w", err)
+	}
+
+	return uuid, nil
+}
+
+func scrubReport(scrubber *redact.Scrubber, data rollbar.ReportData) (rollbar.ReportData, error) {
+	encoded, err := json.Marshal(data)
+	if err != nil {
+		return rollbar.ReportData{}, fmt.Errorf("encode occurrence for scrubbing: %w", err)
+	}
+
+	var clean rollbar.ReportData
+	if err := json.Unmarshal(scrubber.ScrubJSON(encoded), &clean); err != nil {
+		return rollbar.ReportData{}, fmt.Errorf("decode scrubbed occurrence: %w", err)
+	}
+
+	return clean, nil
+}
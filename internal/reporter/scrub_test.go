@@ -0,0 +1,48 @@
+package reporter
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestScrubCleansBeforeSending(t *testing.T) {
+	t.Parallel()
+
+	scrubber, err := redact.NewScrubber([]string{"email"}, []string{`(card=)\d+`})
+	if err != nil {
+		t.Fatalf("NewScrubber() error = %v", err)
+	}
+
+	sender := &fakeSender{}
+	data := rollbar.NewMessageReport("production", rollbar.LevelError, "charge failed card=4242424242424242")
+	data.Custom = map[string]any{"user_email": "a@example.com", "plan": "pro"}
+
+	if _, err := Scrub(sender, scrubber).ReportItem(context.Background(), data); err != nil {
+		t.Fatalf("ReportItem() error = %v", err)
+	}
+
+	sent := sender.sent()[0]
+	if sent.Body.Message.Body != "charge failed card=*****" {
+		t.Fatalf("message not scrubbed: %q", sent.Body.Message.Body)
+	}
+	custom, ok := sent.Custom.(map[string]any)
+	if !ok || custom["user_email"] != redact.Scrubbed || custom["plan"] != "pro" {
+		t.Fatalf("custom data not scrubbed: %#v", sent.Custom)
+	}
+	if data.Custom.(map[string]any)["user_email"] != "a@example.com" {
+		t.Fatalf("caller's data should not be modified")
+	}
+}
+
+func TestScrubSurfacesSendErrors(t *testing.T) {
+	t.Parallel()
+
+	sender := Scrub(&fakeSender{err: errSend}, redact.DefaultScrubber())
+	if _, err := sender.ReportItem(context.Background(), rollbar.NewMessageReport("production", rollbar.LevelError, "x")); !errors.Is(err, errSend) {
+		t.Fatalf("expected send error, got %v", err)
+	}
+}
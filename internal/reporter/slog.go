@@ -0,0 +1,200 @@
+package reporter
+
+import (
+	"context"
+	"fmt"
+	"log/slog"
+	"sync"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const defaultHandlerTimeout = 10 * time.Second
+
+type HandlerOptions struct {
+	Environment string
+	CodeVersion string
+	// Level is the minimum record level reported; nil means slog.LevelError.
+	Level slog.Leveler
+	// Timeout bounds each report; zero means ten seconds.
+	Timeout time.Duration
+	// OnError receives report failures, which are otherwise dropped.
+	OnError func(error)
+}
+
+// Handler is a slog.Handler that reports records at or above Level to
+// Rollbar in the background and passes every record on to next. Attributes,
+// including those added with WithAttrs and WithGroup, become custom data.
+type Handler struct {
+	next    slog.Handler
+	sender  Sender
+	options HandlerOptions
+	context []groupedAttr
+	groups  []string
+	pending *sync.WaitGroup
+}
+
+type groupedAttr struct {
+	groups []string
+	attr   slog.Attr
+}
+
+// NewHandler wraps next, which may be nil when records should only go to
+// Rollbar.
+func NewHandler(next slog.Handler, sender Sender, options HandlerOptions) *Handler {
+	if options.Level == nil {
+		options.Level = slog.LevelError
+	}
+	if options.Timeout <= 0 {
+		options.Timeout = defaultHandlerTimeout
+	}
+
+	return &Handler{next: next, sender: sender, options: options, pending: &sync.WaitGroup{}}
+}
+
+func (h *Handler) Enabled(ctx context.Context, level slog.Level) bool {
+	if level >= h.options.Level.Level() {
+		return true
+	}
+
+	return h.next != nil && h.next.Enabled(ctx, level)
+}
+
+func (h *Handler) Handle(ctx context.Context, record slog.Record) error {
+	if record.Level >= h.options.Level.Level() {
+		h.dispatch(h.reportFor(record))
+	}
+	if h.next == nil || !h.next.Enabled(ctx, record.Level) {
+		return nil
+	}
+	if err := h.next.Handle(ctx, record); err != nil {
+		return fmt.Errorf("next handler: %w", err)
+	}
+
+	return nil
+}
+
+func (h *Handler) WithAttrs(attrs []slog.Attr) slog.Handler {
+	clone := h.clone()
+	for _, attr := range attrs {
+		clone.context = append(clone.context, groupedAttr{groups: clone.groups, attr: attr})
+	}
+	if h.next != nil {
+		clone.next = h.next.WithAttrs(attrs)
+	}
+
+	return clone
+}
+
+func (h *Handler) WithGroup(name string) slog.Handler {
+	if name == "" {
+		return h
+	}
+
+	clone := h.clone()
+	clone.groups = append(append([]string(nil), h.groups...), name)
+	if h.next != nil {
+		clone.next = h.next.WithGroup(name)
+	}
+
+	return clone
+}
+
+// Wait blocks until every report started so far has finished.
+func (h *Handler) Wait() {
+	h.pending.Wait()
+}
+
+func (h *Handler) clone() *Handler {
+	clone := *h
+	clone.context = append([]groupedAttr(nil), h.context...)
+
+	return &clone
+}
+
+func (h *Handler) dispatch(data rollbar.ReportData) {
+	h.pending.Add(1)
+	go func() {
+		defer h.pending.Done()
+
+		ctx, cancel := context.WithTimeout(context.Background(), h.options.Timeout)
+		defer cancel()
+		if _, err := h.sender.ReportItem(ctx, data); err != nil && h.options.OnError != nil {
+			h.options.OnError(fmt.Errorf("report log record: %w", err))
+		}
+	}()
+}
+
+func (h *Handler) reportFor(record slog.Record) rollbar.ReportData {
+	custom := map[string]any{}
+	for _, entry := range h.context {
+		insertAttr(custom, entry.groups, entry.attr)
+	}
+	record.Attrs(func(attr slog.Attr) bool {
+		insertAttr(custom, h.groups, attr)
+		return true
+	})
+
+	data := rollbar.NewMessageReport(h.options.Environment, levelFor(record.Level), record.Message)
+	data.CodeVersion = h.options.CodeVersion
+	if !record.Time.IsZero() {
+		data.Timestamp = record.Time.Unix()
+	}
+	if len(custom) > 0 {
+		data.Custom = custom
+	}
+
+	return data
+}
+
+func insertAttr(target map[string]any, groups []string, attr slog.Attr) {
+	for _, group := range groups {
+		nested, ok := target[group].(map[string]any)
+		if !ok {
+			nested = map[string]any{}
+			target[group] = nested
+		}
+		target = nested
+	}
+
+	value := attr.Value.Resolve()
+	if value.Kind() != slog.KindGroup {
+		target[attr.Key] = attrValue(value)
+		return
+	}
+
+	groupPath := []string{attr.Key}
+	if attr.Key == "" {
+		groupPath = nil
+	}
+	for _, member := range value.Group() {
+		insertAttr(target, groupPath, member)
+	}
+}
+
+func attrValue(value slog.Value) any {
+	if err, ok := value.Any().(error); ok {
+		return err.Error()
+	}
+	if value.Kind() == slog.KindTime || value.Kind() == slog.KindDuration {
+		return value.String()
+	}
+
+	return value.Any()
+}
+
+func levelFor(level slog.Level) rollbar.Level {
+	switch {
+	case level >= slog.LevelError+4:
+		return rollbar.LevelCritical
+	case level >= slog.LevelError:
+		return rollbar.LevelError
+	case level >= slog.LevelWarn:
+		return rollbar.LevelWarning
+	case level >= slog.LevelInfo:
+		return rollbar.LevelInfo
+	default:
+		return rollbar.LevelDebug
+	}
+}
@@ -0,0 +1,113 @@
+package reporter
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"log/slog"
+	"reflect"
+	"strings"
+	"sync"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestHandlerReportsErrorsAndForwards(t *testing.T) {
+	t.Parallel()
+
+	var buffer bytes.Buffer
+	sender := &fakeSender{}
+	handler := NewHandler(slog.NewTextHandler(&buffer, nil), sender, HandlerOptions{Environment: "production", CodeVersion: "abc"})
+	logger := slog.New(handler).With("service", "api").WithGroup("req")
+
+	logger.Info("listening", "port", 8080)
+	logger.Error("payment failed", "id", 42, "err", errors.New("card declined"), slog.Group("user", "plan", "pro"))
+	handler.Wait()
+
+	if !strings.Contains(buffer.String(), "listening") || !strings.Contains(buffer.String(), "payment failed") {
+		t.Fatalf("records should reach the next handler: %s", buffer.String())
+	}
+
+	reports := sender.sent()
+	if len(reports) != 1 {
+		t.Fatalf("expected only the error to be reported, got %d", len(reports))
+	}
+	report := reports[0]
+	if report.Level != rollbar.LevelError || report.Body.Message.Body != "payment failed" || report.CodeVersion != "abc" {
+		t.Fatalf("unexpected report: %+v", report)
+	}
+
+	wantCustom := map[string]any{
+		"service": "api",
+		"req":     map[string]any{"id": int64(42), "err": "card declined", "user": map[string]any{"plan": "pro"}},
+	}
+	if !reflect.DeepEqual(report.Custom, wantCustom) {
+		t.Fatalf("unexpected custom data: %#v", report.Custom)
+	}
+}
+
+func TestHandlerWithoutNext(t *testing.T) {
+	t.Parallel()
+
+	sender := &fakeSender{}
+	handler := NewHandler(nil, sender, HandlerOptions{Environment: "production", Level: slog.LevelWarn})
+	if handler.Enabled(context.Background(), slog.LevelInfo) {
+		t.Fatalf("info should be disabled without a next handler")
+	}
+
+	logger := slog.New(handler)
+	logger.Warn("slow query")
+	logger.Info("ignored")
+	handler.Wait()
+
+	reports := sender.sent()
+	if len(reports) != 1 || reports[0].Level != rollbar.LevelWarning || reports[0].Custom != nil {
+		t.Fatalf("unexpected reports: %+v", reports)
+	}
+}
+
+func TestHandlerReportsFailures(t *testing.T) {
+	t.Parallel()
+
+	var mu sync.Mutex
+	var failures []error
+	handler := NewHandler(nil, &fakeSender{err: errSend}, HandlerOptions{
+		Environment: "production",
+		OnError: func(err error) {
+			mu.Lock()
+			defer mu.Unlock()
+			failures = append(failures, err)
+		},
+	})
+
+	slog.New(handler).Error("boom")
+	handler.Wait()
+
+	mu.Lock()
+	defer mu.Unlock()
+	if len(failures) != 1 || !errors.Is(failures[0], errSend) {
+		t.Fatalf("unexpected failures: %v", failures)
+	}
+}
+
+func TestLevelFor(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		level slog.Level
+		want  rollbar.Level
+	}{
+		{level: slog.LevelDebug, want: rollbar.LevelDebug},
+		{level: slog.LevelInfo, want: rollbar.LevelInfo},
+		{level: slog.LevelWarn, want: rollbar.LevelWarning},
+		{level: slog.LevelError, want: rollbar.LevelError},
+		{level: slog.LevelError + 4, want: rollbar.LevelCritical},
+	}
+
+	for _, tc := range tests {
+		if got := levelFor(tc.level); got != tc.want {
+			t.Fatalf("levelFor(%s) = %s, want %s", tc.level, got, tc.want)
+		}
+	}
+}
@@ -0,0 +1,252 @@
+package reporter
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"sync"
+	"sync/atomic"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	defaultSpoolMaxBytes = 10 << 20
+	defaultSpoolMaxAge   = 7 * 24 * time.Hour
+	spoolFileSuffix      = ".ndjson"
+)
+
+type SpoolOptions struct {
+	Dir string
+	// MaxBytes caps the spool directory; the oldest files go first. Zero means
+	// 10 MiB.
+	MaxBytes int64
+	// MaxAge drops files last written longer ago than this. Zero means 7 days.
+	MaxAge time.Duration
+}
+
+// Spool keeps occurrences that could not be delivered as newline-delimited
+// JSON files so they can be replayed once Rollbar is reachable again. One
+// process should own a spool directory at a time.
+type Spool struct {
+	options SpoolOptions
+	mu      sync.Mutex
+	current string
+	pending atomic.Bool
+	now     func() time.Time
+}
+
+func NewSpool(options SpoolOptions) (*Spool, error) {
+	if strings.TrimSpace(options.Dir) == "" {
+		return nil, errors.New("spool directory is required")
+	}
+	if options.MaxBytes <= 0 {
+		options.MaxBytes = defaultSpoolMaxBytes
+	}
+	if options.MaxAge <= 0 {
+		options.MaxAge = defaultSpoolMaxAge
+	}
+	if err := os.MkdirAll(options.Dir, 0o700); err != nil {
+		return nil, fmt.Errorf("create spool directory: %w", err)
+	}
+
+	spool := &Spool{options: options, now: time.Now}
+	files, err := spool.files()
+	if err != nil {
+		return nil, err
+	}
+	spool.pending.Store(len(files) > 0)
+
+	return spool, nil
+}
+
+// Pending reports whether any spooled occurrences may be waiting.
+func (s *Spool) Pending() bool {
+	return s.pending.Load()
+}
+
+func (s *Spool) Write(data rollbar.ReportData) error {
+	line, err := json.Marshal(data)
+	if err != nil {
+		return fmt.Errorf("encode spooled occurrence: %w", err)
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	if s.current == "" {
+		s.current = strconv.FormatInt(s.now().UnixNano(), 10) + spoolFileSuffix
+	}
+	file, err := os.OpenFile(filepath.Clean(filepath.Join(s.options.Dir, s.current)), os.O_CREATE|os.O_APPEND|os.O_WRONLY, 0o600)
+	if err != nil {
+		return fmt.Errorf("open spool file: %w", err)
+	}
+	_, writeErr := file.Write(append(line, '\n'))
+	closeErr := file.Close()
+	if err := errors.Join(writeErr, closeErr); err != nil {
+		return fmt.Errorf("write spool file: %w", err)
+	}
+	s.pending.Store(true)
+
+	return s.prune()
+}
+
+// Replay sends spooled occurrences oldest first. It stops at the first failed
+// send, keeping that occurrence and everything after it, and returns how many
+// were delivered. Lines that no longer decode are dropped.
+func (s *Spool) Replay(ctx context.Context, sender Sender) (int, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	if err := s.prune(); err != nil {
+		return 0, err
+	}
+	files, err := s.files()
+	if err != nil {
+		return 0, err
+	}
+
+	sent := 0
+	for _, name := range files {
+		delivered, err := s.replayFile(ctx, sender, name)
+		sent += delivered
+		if err != nil {
+			return sent, err
+		}
+	}
+	s.current = ""
+	s.pending.Store(false)
+
+	return sent, nil
+}
+
+func (s *Spool) replayFile(ctx context.Context, sender Sender, name string) (int, error) {
+	path := filepath.Join(s.options.Dir, name)
+	lines, err := readLines(path)
+	if err != nil {
+		return 0, err
+	}
+
+	for index, line := range lines {
+		var data rollbar.ReportData
+		if err := json.Unmarshal(line, &data); err != nil {
+			continue
+		}
+		if _, err := sender.ReportItem(ctx, data); err != nil {
+			return index, errors.Join(fmt.Errorf("replay spooled occurrence: %w", err), rewriteLines(path, lines[index:]))
+		}
+	}
+
+	if err := os.Remove(path); err != nil {
+		return len(lines), fmt.Errorf("remove replayed spool file: %w", err)
+	}
+
+	return len(lines), nil
+}
+
+// prune enforces MaxAge and then MaxBytes, oldest files first. Callers hold mu.
+func (s *Spool) prune() error {
+	files, err := s.files()
+	if err != nil {
+		return err
+	}
+
+	cutoff := s.now().Add(-s.options.MaxAge)
+	var total int64
+	sizes := make([]int64, len(files))
+	for index, name := range files {
+		info, err := os.Stat(filepath.Join(s.options.Dir, name))
+		if err != nil {
+			return fmt.Errorf("stat spool file: %w", err)
+		}
+		if info.ModTime().Before(cutoff) {
+			sizes[index] = -1
+			continue
+		}
+		sizes[index] = info.Size()
+		total += info.Size()
+	}
+
+	for index, name := range files {
+		if sizes[index] >= 0 && total <= s.options.MaxBytes {
+			continue
+		}
+		total -= max(sizes[index], 0)
+		if err := s.remove(name); err != nil {
+			return err
+		}
+	}
+
+	return nil
+}
+
+func (s *Spool) remove(name string) error {
+	if name == s.current {
+		s.current = ""
+	}
+	if err := os.Remove(filepath.Join(s.options.Dir, name)); err != nil && !errors.Is(err, os.ErrNotExist) {
+		return fmt.Errorf("remove spool file: %w", err)
+	}
+
+	return nil
+}
+
+// files lists spool files oldest first. Names are Unix nanosecond timestamps,
+// so a shorter name is always older.
+func (s *Spool) files() ([]string, error) {
+	entries, err := os.ReadDir(s.options.Dir)
+	if err != nil {
+		return nil, fmt.Errorf("read spool directory: %w", err)
+	}
+
+	var names []string
+	for _, entry := range entries {
+		if !entry.IsDir() && strings.HasSuffix(entry.Name(), spoolFileSuffix) {
+			names = append(names, entry.Name())
+		}
+	}
+	sort.Slice(names, func(i int, j int) bool {
+		if len(names[i]) != len(names[j]) {
+			return len(names[i]) < len(names[j])
+		}
+		return names[i] < names[j]
+	})
+
+	return names, nil
+}
+
+func readLines(path string) ([][]byte, error) {
+	body, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return nil, fmt.Errorf("read spool file: %w", err)
+	}
+
+	var lines [][]byte
+	scanner := bufio.NewScanner(bytes.NewReader(body))
+	scanner.Buffer(make([]byte, 0, 64*1024), len(body)+1)
+	for scanner.Scan() {
+		if line := bytes.TrimSpace(scanner.Bytes()); len(line) > 0 {
+			lines = append(lines, append([]byte(nil), line...))
+		}
+	}
+
+	return lines, nil
+}
+
+func rewriteLines(path string, lines [][]byte) error {
+	body := append(bytes.Join(lines, []byte("\n")), '\n')
+	if err := os.WriteFile(path, body, 0o600); err != nil {
+		return fmt.Errorf("rewrite spool file: %w", err)
+	}
+
+	return nil
+}
@@ -0,0 +1,166 @@
+package reporter
+
+import (
+	"context"
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func newTestSpool(t *testing.T, messages ...string) *Spool {
+	t.Helper()
+
+	spool, err := NewSpool(SpoolOptions{Dir: filepath.Join(t.TempDir(), "spool")})
+	if err != nil {
+		t.Fatalf("NewSpool() error = %v", err)
+	}
+	for _, message := range messages {
+		if err := spool.Write(rollbar.NewMessageReport("production", rollbar.LevelError, message)); err != nil {
+			t.Fatalf("Write() error = %v", err)
+		}
+	}
+
+	return spool
+}
+
+func TestSpoolWriteAndReplay(t *testing.T) {
+	t.Parallel()
+
+	if newTestSpool(t).Pending() {
+		t.Fatalf("new spool should be empty")
+	}
+
+	spool := newTestSpool(t, "a", "b", "c")
+	sender := &fakeSender{}
+	sent, err := spool.Replay(context.Background(), sender)
+	if err != nil || sent != 3 {
+		t.Fatalf("Replay() = %d, %v", sent, err)
+	}
+	reports := sender.sent()
+	if reports[0].Body.Message.Body != "a" || reports[2].Body.Message.Body != "c" || spool.Pending() {
+		t.Fatalf("unexpected replay order or pending state: %+v", reports)
+	}
+	if files, _ := spool.files(); len(files) != 0 {
+		t.Fatalf("replayed files should be removed: %v", files)
+	}
+}
+
+func TestSpoolFailedReplayKeepsOccurrences(t *testing.T) {
+	t.Parallel()
+
+	spool := newTestSpool(t, "a", "b", "c")
+	failing := &fakeSender{err: errSend}
+	if sent, err := spool.Replay(context.Background(), failing); !errors.Is(err, errSend) || sent != 0 {
+		t.Fatalf("expected failed replay, sent=%d err=%v", sent, err)
+	}
+	if !spool.Pending() {
+		t.Fatalf("failed replay should keep occurrences")
+	}
+}
+
+func TestSpoolReplayKeepsUnsentTail(t *testing.T) {
+	t.Parallel()
+
+	spool, err := NewSpool(SpoolOptions{Dir: t.TempDir()})
+	if err != nil {
+		t.Fatalf("NewSpool() error = %v", err)
+	}
+	for _, message := range []string{"a", "b", "c"} {
+		_ = spool.Write(rollbar.NewMessageReport("production", rollbar.LevelError, message))
+	}
+
+	partial := &failAfterSender{remaining: 1}
+	if sent, err := spool.Replay(context.Background(), partial); err == nil || sent != 1 {
+		t.Fatalf("expected partial replay, sent=%d err=%v", sent, err)
+	}
+
+	sender := &fakeSender{}
+	if sent, err := spool.Replay(context.Background(), sender); err != nil || sent != 2 {
+		t.Fatalf("Replay() = %d, %v", sent, err)
+	}
+	if got := sender.sent()[0].Body.Message.Body; got != "b" {
+		t.Fatalf("expected replay to resume at b, got %s", got)
+	}
+}
+
+func TestSpoolPrunesByAgeAndSize(t *testing.T) {
+	t.Parallel()
+
+	dir := t.TempDir()
+	old := filepath.Join(dir, "1000"+spoolFileSuffix)
+	if err := os.WriteFile(old, []byte("{}\n"), 0o600); err != nil {
+		t.Fatalf("write old spool file: %v", err)
+	}
+	stale := time.Now().Add(-48 * time.Hour)
+	if err := os.Chtimes(old, stale, stale); err != nil {
+		t.Fatalf("age spool file: %v", err)
+	}
+
+	spool, err := NewSpool(SpoolOptions{Dir: dir, MaxAge: 24 * time.Hour, MaxBytes: 1})
+	if err != nil {
+		t.Fatalf("NewSpool() error = %v", err)
+	}
+	if !spool.Pending() {
+		t.Fatalf("existing files should mark the spool pending")
+	}
+
+	if err := spool.Write(rollbar.NewMessageReport("production", rollbar.LevelError, "x")); err != nil {
+		t.Fatalf("Write() error = %v", err)
+	}
+	if files, _ := spool.files(); len(files) != 0 {
+		t.Fatalf("expected old and oversized files to be pruned, got %v", files)
+	}
+}
+
+func TestNewSpoolRequiresDir(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewSpool(SpoolOptions{}); err == nil {
+		t.Fatalf("expected missing directory error")
+	}
+}
+
+func TestQueueSpoolsFailuresAndReplays(t *testing.T) {
+	t.Parallel()
+
+	spool, err := NewSpool(SpoolOptions{Dir: t.TempDir()})
+	if err != nil {
+		t.Fatalf("NewSpool() error = %v", err)
+	}
+
+	sender := &flakySender{err: errSend}
+	sender.failures.Store(1)
+	queue, _ := newTestQueue(sender, QueueOptions{MaxAttempts: 1, Spool: spool})
+
+	_, _ = queue.ReportItem(context.Background(), rollbar.NewMessageReport("production", rollbar.LevelError, "offline"))
+	_, _ = queue.ReportItem(context.Background(), rollbar.NewMessageReport("production", rollbar.LevelError, "online"))
+	if err := queue.Close(context.Background()); err != nil {
+		t.Fatalf("Close() error = %v", err)
+	}
+
+	reports := sender.sent()
+	if len(reports) != 2 || reports[0].Body.Message.Body != "online" || reports[1].Body.Message.Body != "offline" {
+		t.Fatalf("expected the spooled occurrence to replay after the next success, got %+v", reports)
+	}
+	if spool.Pending() {
+		t.Fatalf("spool should be empty after replay")
+	}
+}
+
+type failAfterSender struct {
+	remaining int
+	fakeSender
+}
+
+func (f *failAfterSender) ReportItem(ctx context.Context, data rollbar.ReportData) (string, error) {
+	if f.remaining == 0 {
+		return "", errSend
+	}
+	f.remaining--
+
+	return f.fakeSender.ReportItem(ctx, data)
+}
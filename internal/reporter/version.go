@@ -0,0 +1,32 @@
+package reporter
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type codeVersionSender struct {
+	next        Sender
+	codeVersion string
+}
+
+// CodeVersion returns a Sender that fills in code_version on occurrences that
+// lack one, typically with the SHA from vcs.Revision.
+func CodeVersion(next Sender, codeVersion string) Sender {
+	return codeVersionSender{next: next, codeVersion: codeVersion}
+}
+
+func (s codeVersionSender) ReportItem(ctx context.Context, data rollbar.ReportData) (string, error) {
+	if data.CodeVersion == "" {
+		data.CodeVersion = s.codeVersion
+	}
+
+	uuid, err := s.next.ReportItem(ctx, data)
+	if err != nil {
+		return "", fmt.Errorf("send versioned occurrence: %w", err)
+	}
+
+	return uuid, nil
+}
@@ -0,0 +1,33 @@
+package reporter
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestCodeVersionFillsMissingVersion(t *testing.T) {
+	t.Parallel()
+
+	sender := &fakeSender{}
+	versioned := CodeVersion(sender, "abc123")
+
+	pinned := rollbar.NewMessageReport("production", rollbar.LevelError, "x")
+	pinned.CodeVersion = "v1.0.0"
+	for _, data := range []rollbar.ReportData{rollbar.NewMessageReport("production", rollbar.LevelError, "x"), pinned} {
+		if _, err := versioned.ReportItem(context.Background(), data); err != nil {
+			t.Fatalf("ReportItem() error = %v", err)
+		}
+	}
+
+	sent := sender.sent()
+	if sent[0].CodeVersion != "abc123" || sent[1].CodeVersion != "v1.0.0" {
+		t.Fatalf("unexpected code versions: %q %q", sent[0].CodeVersion, sent[1].CodeVersion)
+	}
+
+	if _, err := CodeVersion(&fakeSender{err: errSend}, "abc").ReportItem(context.Background(), pinned); !errors.Is(err, errSend) {
+		t.Fatalf("expected send error, got %v", err)
+	}
+}
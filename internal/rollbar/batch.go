@@ -0,0 +1,95 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"sync"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// defaultBatchConcurrency is the fan-out of an unthrottled client.
+const defaultBatchConcurrency = 4
+
+// GetItems fetches items by id with at most concurrency requests in flight
+// and returns them in the order of ids. See fetchAll for the limit and error
+// behaviour.
+func (c *Client) GetItems(ctx context.Context, ids []domain.ItemID, concurrency int) ([]Item, error) {
+	return fetchAll(ctx, c.batchConcurrency(concurrency), ids, c.GetItem)
+}
+
+// ResolveItemIDsByCounter maps project counters to item ids like
+// ResolveItemIDByCounter, concurrently, keeping the order of counters.
+func (c *Client) ResolveItemIDsByCounter(ctx context.Context, counters []domain.ItemCounter, concurrency int) ([]domain.ItemID, error) {
+	return fetchAll(ctx, c.batchConcurrency(concurrency), counters, c.ResolveItemIDByCounter)
+}
+
+// batchConcurrency picks the fan-out when the caller passes zero or less:
+// the throttle's limit when SetThrottle is on, so a batch never queues behind
+// its own slots, and defaultBatchConcurrency otherwise.
+func (c *Client) batchConcurrency(concurrency int) int {
+	if concurrency > 0 {
+		return concurrency
+	}
+	if c.limits.slots != nil {
+		return cap(c.limits.slots)
+	}
+
+	return defaultBatchConcurrency
+}
+
+// fetchAll runs fetch for every input with at most concurrency calls at once
+// and returns the outputs in input order. The first failure, prefixed with
+// its input, cancels the calls still running and is returned on its own.
+func fetchAll[In any, Out any](ctx context.Context, concurrency int, inputs []In, fetch func(context.Context, In) (Out, error)) ([]Out, error) {
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+
+	outputs := make([]Out, len(inputs))
+	slots := make(chan struct{}, concurrency)
+	var wg sync.WaitGroup
+	var once sync.Once
+	var firstErr error
+	for index, input := range inputs {
+		if !acquireSlot(ctx, slots) {
+			break
+		}
+
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			defer func() { <-slots }()
+
+			output, err := fetch(ctx, input)
+			if err != nil {
+				once.Do(func() {
+					firstErr = fmt.Errorf("%v: %w", input, err)
+					cancel()
+				})
+				return
+			}
+			outputs[index] = output
+		}()
+	}
+	wg.Wait()
+
+	if firstErr != nil {
+		return nil, firstErr
+	}
+	if err := ctx.Err(); err != nil {
+		return nil, err //nolint:wrapcheck // The caller's own context error.
+	}
+
+	return outputs, nil
+}
+
+// acquireSlot waits for a free slot and reports whether the context is still
+// live once it has one.
+func acquireSlot(ctx context.Context, slots chan<- struct{}) bool {
+	select {
+	case slots <- struct{}{}:
+	case <-ctx.Done():
+	}
+
+	return ctx.Err() == nil
+}
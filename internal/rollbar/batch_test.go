@@ -0,0 +1,108 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"slices"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestClientGetItems(t *testing.T) {
+	t.Parallel()
+
+	var inFlight, peak atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		current := inFlight.Add(1)
+		defer inFlight.Add(-1)
+		for {
+			seen := peak.Load()
+			if current <= seen || peak.CompareAndSwap(seen, current) {
+				break
+			}
+		}
+
+		id := strings.TrimPrefix(r.URL.Path, "/item/")
+		// Earlier ids answer last, so ordering cannot come from completion.
+		if id == "1" {
+			time.Sleep(20 * time.Millisecond)
+		}
+		_, _ = fmt.Fprintf(w, `{"err":0,"result":{"id":%s,"counter":%s0}}`, id, id)
+	})
+
+	items, err := client.GetItems(context.Background(), []domain.ItemID{1, 2, 3, 4, 5}, 2)
+	if err != nil {
+		t.Fatalf("GetItems() error = %v", err)
+	}
+	counters := make([]uint64, 0, len(items))
+	for _, item := range items {
+		counters = append(counters, item.Counter)
+	}
+	if !slices.Equal(counters, []uint64{10, 20, 30, 40, 50}) {
+		t.Fatalf("unexpected order: %v", counters)
+	}
+	if peak.Load() > 2 {
+		t.Fatalf("expected at most 2 requests in flight, saw %d", peak.Load())
+	}
+}
+
+func TestClientResolveItemIDsByCounter(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		counter := strings.TrimPrefix(r.URL.Path, "/item_by_counter/")
+		if counter == "404" {
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprint(w, `{"err":1,"message":"not found"}`)
+			return
+		}
+		_, _ = fmt.Fprintf(w, `{"err":0,"result":{"itemId":%s00}}`, counter)
+	})
+
+	ids, err := client.ResolveItemIDsByCounter(context.Background(), []domain.ItemCounter{3, 1, 2}, 0)
+	if err != nil || !slices.Equal(ids, []domain.ItemID{300, 100, 200}) {
+		t.Fatalf("ResolveItemIDsByCounter() = %v, %v", ids, err)
+	}
+
+	_, err = client.ResolveItemIDsByCounter(context.Background(), []domain.ItemCounter{1, 404, 2}, 1)
+	if err == nil || !strings.HasPrefix(err.Error(), "404: ") {
+		t.Fatalf("expected the failing counter in the error, got %v", err)
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+	if _, err := client.ResolveItemIDsByCounter(ctx, []domain.ItemCounter{1}, 1); err == nil {
+		t.Fatal("expected an error for a cancelled context")
+	}
+}
+
+func TestBatchConcurrency(t *testing.T) {
+	t.Parallel()
+
+	client, err := New("token")
+	if err != nil {
+		t.Fatalf("New() error = %v", err)
+	}
+
+	tests := []struct {
+		throttle    int
+		concurrency int
+		want        int
+	}{
+		{concurrency: 8, want: 8},
+		{want: defaultBatchConcurrency},
+		{throttle: 2, want: 2},
+		{throttle: 2, concurrency: 6, want: 6},
+	}
+	for _, tc := range tests {
+		client.SetThrottle(tc.throttle)
+		if got := client.batchConcurrency(tc.concurrency); got != tc.want {
+			t.Fatalf("%+v: batchConcurrency() = %d", tc, got)
+		}
+	}
+}
@@ -0,0 +1,126 @@
+package rollbar
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sync"
+)
+
+// maxMemoryCacheEntries bounds the in-memory layer; past it the layer starts
+// over, and the disk layer, when configured, still answers.
+const maxMemoryCacheEntries = 512
+
+// ResponseCache keeps GET responses that carried an ETag or Last-Modified
+// header so repeat requests can be sent conditionally and a 304 answered
+// from the cache. Entries are keyed by a hash of the access token and URL,
+// so the token is never written and projects never share entries. With a
+// directory the cache also persists across runs; disk failures only cost a
+// full request.
+type ResponseCache struct {
+	mu     sync.Mutex
+	memory map[string]cachedResponse
+	dir    string
+}
+
+type cachedResponse struct {
+	ETag         string `json:"etag,omitempty"`
+	LastModified string `json:"last_modified,omitempty"`
+	Body         []byte `json:"body"`
+}
+
+// NewResponseCache returns a cache held in memory and, when dir is not empty,
+// in files under dir.
+func NewResponseCache(dir string) *ResponseCache {
+	if dir != "" {
+		dir = filepath.Clean(dir)
+	}
+
+	return &ResponseCache{memory: map[string]cachedResponse{}, dir: dir}
+}
+
+// SetResponseCache turns on conditional GET requests backed by cache; nil
+// turns them off. Call it before the client is shared.
+func (c *Client) SetResponseCache(cache *ResponseCache) {
+	c.cache = cache
+}
+
+// conditional adds validators from a cached response to a GET request and
+// returns the cache key with the entry, if any.
+func (c *Client) conditional(req *http.Request) (string, *cachedResponse) {
+	if c.cache == nil || req.Method != http.MethodGet {
+		return "", nil
+	}
+
+	sum := sha256.Sum256([]byte(c.accessToken + "\n" + req.URL.String()))
+	key := hex.EncodeToString(sum[:])
+	entry, ok := c.cache.load(key)
+	if !ok {
+		return key, nil
+	}
+	if entry.ETag != "" {
+		req.Header.Set("If-None-Match", entry.ETag)
+	}
+	if entry.LastModified != "" {
+		req.Header.Set("If-Modified-Since", entry.LastModified)
+	}
+
+	return key, &entry
+}
+
+// cacheResponse stores a successful response under key when it can be
+// revalidated.
+func (c *Client) cacheResponse(key string, header http.Header, body []byte) {
+	if key == "" {
+		return
+	}
+
+	entry := cachedResponse{ETag: header.Get("ETag"), LastModified: header.Get("Last-Modified"), Body: body}
+	if entry.ETag == "" && entry.LastModified == "" {
+		return
+	}
+	c.cache.store(key, entry)
+}
+
+func (r *ResponseCache) load(key string) (cachedResponse, bool) {
+	r.mu.Lock()
+	entry, ok := r.memory[key]
+	r.mu.Unlock()
+	if ok || r.dir == "" {
+		return entry, ok
+	}
+
+	body, err := os.ReadFile(filepath.Join(r.dir, key+".json"))
+	if err != nil || json.Unmarshal(body, &entry) != nil {
+		return cachedResponse{}, false
+	}
+	r.remember(key, entry)
+
+	return entry, true
+}
+
+func (r *ResponseCache) store(key string, entry cachedResponse) {
+	r.remember(key, entry)
+	if r.dir == "" {
+		return
+	}
+
+	encoded, err := json.Marshal(entry)
+	if err != nil || os.MkdirAll(r.dir, 0o700) != nil {
+		return
+	}
+	_ = os.WriteFile(filepath.Join(r.dir, key+".json"), encoded, 0o600)
+}
+
+func (r *ResponseCache) remember(key string, entry cachedResponse) {
+	r.mu.Lock()
+	defer r.mu.Unlock()
+
+	if len(r.memory) >= maxMemoryCacheEntries {
+		r.memory = map[string]cachedResponse{}
+	}
+	r.memory[key] = entry
+}
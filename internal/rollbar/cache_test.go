@@ -0,0 +1,148 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync/atomic"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// cacheServer serves item 1 with an ETag and item 2 without validators,
+// counting full and not-modified responses.
+type cacheServer struct {
+	url         string
+	full        atomic.Int32
+	notModified atomic.Int32
+}
+
+func newCacheServer(t *testing.T) *cacheServer {
+	t.Helper()
+
+	cache := &cacheServer{}
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.URL.Path == "/item/1" && r.Header.Get("If-None-Match") == `"v1"`:
+			cache.notModified.Add(1)
+			w.WriteHeader(http.StatusNotModified)
+		case r.URL.Path == "/item/1":
+			cache.full.Add(1)
+			w.Header().Set("ETag", `"v1"`)
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1,"counter":7,"title":"boom"}}`)
+		default:
+			cache.full.Add(1)
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":2,"counter":8,"title":"no validators"}}`)
+		}
+	}))
+	t.Cleanup(server.Close)
+	cache.url = server.URL
+
+	return cache
+}
+
+// client returns a client for token that caches responses in dir.
+func (c *cacheServer) client(t *testing.T, dir string, token string) *Client {
+	t.Helper()
+
+	client, err := NewWithOptions(token, WithBaseURL(c.url), WithResponseCache(NewResponseCache(dir)))
+	if err != nil {
+		t.Fatalf("NewWithOptions() error = %v", err)
+	}
+	return client
+}
+
+func TestResponseCacheConditionalRequests(t *testing.T) {
+	t.Parallel()
+
+	server := newCacheServer(t)
+	dir := t.TempDir()
+	client := server.client(t, dir, "token-a")
+	for range 2 {
+		item, err := client.GetItem(context.Background(), domain.ItemID(1))
+		if err != nil || item.Title != "boom" {
+			t.Fatalf("GetItem() = %+v, %v", item, err)
+		}
+	}
+	if server.full.Load() != 1 || server.notModified.Load() != 1 {
+		t.Fatalf("expected one full and one conditional request, got %d and %d", server.full.Load(), server.notModified.Load())
+	}
+
+	// A fresh process reads the validators back from disk.
+	if item, err := server.client(t, dir, "token-a").GetItem(context.Background(), domain.ItemID(1)); err != nil || item.Counter != 7 || server.notModified.Load() != 2 {
+		t.Fatalf("expected a disk-backed conditional request, got %+v, %v", item, err)
+	}
+}
+
+func TestResponseCacheIsPerToken(t *testing.T) {
+	t.Parallel()
+
+	server := newCacheServer(t)
+	dir := t.TempDir()
+	for _, token := range []string{"token-a", "token-b"} {
+		if _, err := server.client(t, dir, token).GetItem(context.Background(), domain.ItemID(1)); err != nil {
+			t.Fatalf("%s: GetItem() error = %v", token, err)
+		}
+	}
+	// Another token never sees the first token's entries.
+	if server.full.Load() != 2 {
+		t.Fatalf("expected a full request for another token, got %d full", server.full.Load())
+	}
+
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		t.Fatalf("ReadDir() error = %v", err)
+	}
+	for _, entry := range entries {
+		body, _ := os.ReadFile(filepath.Join(dir, entry.Name()))
+		if strings.Contains(entry.Name()+string(body), "token-a") {
+			t.Fatalf("cache file %s leaks the token", entry.Name())
+		}
+	}
+	if len(entries) != 2 {
+		t.Fatalf("expected one cache file per token, got %d", len(entries))
+	}
+}
+
+func TestResponseCacheSkipsUnvalidatedResponses(t *testing.T) {
+	t.Parallel()
+
+	server := newCacheServer(t)
+	client := server.client(t, t.TempDir(), "token-a")
+	for range 2 {
+		if _, err := client.GetItem(context.Background(), domain.ItemID(2)); err != nil {
+			t.Fatalf("GetItem() error = %v", err)
+		}
+	}
+	if server.full.Load() != 2 {
+		t.Fatalf("expected uncached requests, got %d full", server.full.Load())
+	}
+}
+
+func TestResponseCacheSkipsWrites(t *testing.T) {
+	t.Parallel()
+
+	var conditional atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Header.Get("If-None-Match") != "" {
+			conditional.Add(1)
+		}
+		w.Header().Set("ETag", `"v1"`)
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+	})
+	client.SetResponseCache(NewResponseCache(""))
+
+	for range 2 {
+		if err := client.UpdateItem(context.Background(), domain.ItemID(1), ItemPatch{Status: StatusResolved}); err != nil {
+			t.Fatalf("UpdateItem() error = %v", err)
+		}
+	}
+	if conditional.Load() != 0 {
+		t.Fatalf("expected writes to bypass the cache, got %d conditional requests", conditional.Load())
+	}
+}
@@ -0,0 +1,211 @@
+package rollbar
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+// cassetteHeaders are the response headers worth replaying; everything else,
+// cookies included, is dropped on write.
+var cassetteHeaders = []string{
+	"Content-Type", "ETag", "Last-Modified", "Retry-After",
+	"X-Rate-Limit-Limit", "X-Rate-Limit-Remaining", "X-Rate-Limit-Reset",
+}
+
+// Cassette records API responses to a JSON file and replays them, so a bug
+// report can ship the exact responses that reproduce it and tests can run
+// without the network. A file that does not exist yet is recorded into; one
+// that exists is replayed, and a request it holds no recording for fails
+// without reaching Rollbar. The access token is scrubbed from URLs and bodies
+// before anything is written.
+type Cassette struct {
+	mu           sync.Mutex
+	path         string
+	replay       bool
+	interactions []interaction
+	played       map[string]int
+}
+
+type interaction struct {
+	Method  string            `json:"method"`
+	URI     string            `json:"uri"`
+	Status  int               `json:"status"`
+	Headers map[string]string `json:"headers,omitempty"`
+	Body    string            `json:"body"`
+}
+
+type cassetteFile struct {
+	Interactions []interaction `json:"interactions"`
+}
+
+// OpenCassette replays path when it exists and records into it otherwise.
+func OpenCassette(path string) (*Cassette, error) {
+	cassette := &Cassette{path: filepath.Clean(path), played: map[string]int{}}
+
+	content, err := os.ReadFile(cassette.path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return cassette, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("read cassette: %w", err)
+	}
+
+	var file cassetteFile
+	if err := json.Unmarshal(content, &file); err != nil {
+		return nil, fmt.Errorf("decode cassette %s: %w", cassette.path, err)
+	}
+	cassette.replay = true
+	cassette.interactions = file.Interactions
+
+	return cassette, nil
+}
+
+// Replaying reports whether the cassette answers requests itself.
+func (c *Cassette) Replaying() bool {
+	return c.replay
+}
+
+// Middleware records or replays every request; install it with Client.Use,
+// passing Client.MaxResponseBytes. Identical requests replay in the order they
+// were recorded, and the last recording repeats once they run out. A response
+// longer than maxBytes, streamed ones included, fails with
+// ErrResponseTooLarge rather than being recorded cut short; non-positive
+// values use the client default.
+func (c *Cassette) Middleware(maxBytes int) Middleware {
+	if maxBytes <= 0 {
+		maxBytes = maxResponseBodyBytes
+	}
+
+	return func(next http.RoundTripper) http.RoundTripper {
+		return RoundTripperFunc(func(req *http.Request) (*http.Response, error) {
+			if c.replay {
+				return c.play(req)
+			}
+
+			return c.record(req, next, maxBytes)
+		})
+	}
+}
+
+func (c *Cassette) play(req *http.Request) (*http.Response, error) {
+	uri := redact.String(req.URL.RequestURI(), req.Header.Get("X-Rollbar-Access-Token"))
+
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	var matches []interaction
+	for _, recorded := range c.interactions {
+		if recorded.Method == req.Method && recorded.URI == uri {
+			matches = append(matches, recorded)
+		}
+	}
+	if len(matches) == 0 {
+		return nil, fmt.Errorf("cassette %s has no recording for %s %s", c.path, req.Method, uri)
+	}
+
+	key := req.Method + " " + uri
+	recorded := matches[min(c.played[key], len(matches)-1)]
+	c.played[key]++
+
+	header := http.Header{}
+	for name, value := range recorded.Headers {
+		header.Set(name, value)
+	}
+
+	return &http.Response{
+		Status:        fmt.Sprintf("%d %s", recorded.Status, http.StatusText(recorded.Status)),
+		StatusCode:    recorded.Status,
+		Header:        header,
+		Body:          io.NopCloser(strings.NewReader(recorded.Body)),
+		ContentLength: int64(len(recorded.Body)),
+		Request:       req,
+	}, nil
+}
+
+func (c *Cassette) record(req *http.Request, next http.RoundTripper, maxBytes int) (*http.Response, error) {
+	response, err := next.RoundTrip(req)
+	if err != nil {
+		return nil, err //nolint:wrapcheck // Transport errors are not recorded and pass through unchanged.
+	}
+
+	body, err := io.ReadAll(io.LimitReader(response.Body, int64(maxBytes)+1))
+	_ = response.Body.Close()
+	if err != nil {
+		return nil, fmt.Errorf("record response: %w", err)
+	}
+	if len(body) > maxBytes {
+		return nil, fmt.Errorf("record response: %w: exceeds %d bytes", ErrResponseTooLarge, maxBytes)
+	}
+	response.Body = io.NopCloser(bytes.NewReader(body))
+
+	token := req.Header.Get("X-Rollbar-Access-Token")
+	recorded := interaction{
+		Method:  req.Method,
+		URI:     redact.String(req.URL.RequestURI(), token),
+		Status:  response.StatusCode,
+		Headers: map[string]string{},
+		Body:    redactBody(body, token),
+	}
+	for _, name := range cassetteHeaders {
+		if value := response.Header.Get(name); value != "" {
+			recorded.Headers[name] = value
+		}
+	}
+
+	if err := c.save(recorded); err != nil {
+		_ = response.Body.Close()
+		return nil, err
+	}
+
+	return response, nil
+}
+
+// redactBody scrubs the token and, in JSON bodies, every sensitive field such
+// as the project tokens listed by /project/{id}/access_tokens.
+func redactBody(body []byte, token string) string {
+	decoder := json.NewDecoder(bytes.NewReader(body))
+	decoder.UseNumber()
+	var decoded any
+	if err := decoder.Decode(&decoded); err != nil {
+		return redact.String(string(body), token)
+	}
+
+	encoded, err := json.Marshal(redact.Value(decoded, token))
+	if err != nil {
+		return redact.String(string(body), token)
+	}
+
+	return string(encoded)
+}
+
+// save adds an interaction and rewrites the file, so a run that is cut
+// short still leaves a usable cassette.
+func (c *Cassette) save(recorded interaction) error {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+
+	c.interactions = append(c.interactions, recorded)
+	encoded, err := json.MarshalIndent(cassetteFile{Interactions: c.interactions}, "", "  ")
+	if err != nil {
+		return fmt.Errorf("encode cassette: %w", err)
+	}
+	if err := os.MkdirAll(filepath.Dir(c.path), 0o700); err != nil {
+		return fmt.Errorf("create cassette directory: %w", err)
+	}
+	if err := os.WriteFile(c.path, append(encoded, '\n'), 0o600); err != nil {
+		return fmt.Errorf("write cassette: %w", err)
+	}
+
+	return nil
+}
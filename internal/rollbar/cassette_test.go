@@ -0,0 +1,111 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync/atomic"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// recordCassette records two GetItem calls to path against a server that
+// echoes the access token and sets a session cookie.
+func recordCassette(t *testing.T, path string) {
+	t.Helper()
+
+	var served atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		title := fmt.Sprintf("call %d echoes %s", served.Add(1), r.Header.Get("X-Rollbar-Access-Token"))
+		w.Header().Set("Set-Cookie", "session=secret")
+		_, _ = fmt.Fprintf(w, `{"err":0,"result":{"id":1,"title":%q,"access_token":"project-secret"}}`, title)
+	})
+	recorder, err := OpenCassette(path)
+	if err != nil || recorder.Replaying() {
+		t.Fatalf("OpenCassette() = %v, replaying %v", err, recorder.Replaying())
+	}
+	client.Use(recorder.Middleware(client.MaxResponseBytes()))
+	for range 2 {
+		if _, err := client.GetItem(context.Background(), domain.ItemID(1)); err != nil {
+			t.Fatalf("GetItem() error = %v", err)
+		}
+	}
+}
+
+// checkCassetteScrubbed fails if the cassette at path keeps the token, the
+// project secret or the session cookie.
+func checkCassetteScrubbed(t *testing.T, path string) {
+	t.Helper()
+
+	content, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatalf("ReadFile() error = %v", err)
+	}
+	for _, secret := range []string{"echoes token", "project-secret", "session"} {
+		if strings.Contains(string(content), secret) {
+			t.Fatalf("cassette keeps %q:\n%s", secret, content)
+		}
+	}
+}
+
+func TestCassetteRecordAndReplay(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "cassettes", "items.json")
+	recordCassette(t, path)
+	checkCassetteScrubbed(t, path)
+
+	player, err := OpenCassette(path)
+	if err != nil || !player.Replaying() {
+		t.Fatalf("OpenCassette() = %v, replaying %v", err, player.Replaying())
+	}
+	offline := newTestClient(t, "http://127.0.0.1:1")
+	offline.Use(player.Middleware(offline.MaxResponseBytes()))
+	for _, want := range []string{"call 1", "call 2", "call 2"} {
+		item, err := offline.GetItem(context.Background(), domain.ItemID(1))
+		if err != nil || !strings.HasPrefix(item.Title, want) {
+			t.Fatalf("GetItem() = %q, %v; want %s", item.Title, err, want)
+		}
+	}
+	if _, err := offline.GetItem(context.Background(), domain.ItemID(2)); err == nil || !strings.Contains(err.Error(), "no recording") {
+		t.Fatalf("expected a missing recording, got %v", err)
+	}
+}
+
+func TestOpenCassetteRejectsCorruptFiles(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "broken.json")
+	if err := os.WriteFile(path, []byte("{"), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	if _, err := OpenCassette(path); err == nil {
+		t.Fatal("expected a decode error")
+	}
+}
+
+func TestCassetteRejectsOversizedResponses(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "instance.json")
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprintf(w, `{"err":0,"result":{"id":42,"data":{"body":%q}}}`, strings.Repeat("x", 256))
+	})
+	recorder, err := OpenCassette(path)
+	if err != nil {
+		t.Fatalf("OpenCassette() error = %v", err)
+	}
+	client.Use(recorder.Middleware(128))
+
+	if _, err := client.GetInstanceFields(context.Background(), 42, []string{"id"}); !errors.Is(err, ErrResponseTooLarge) {
+		t.Fatalf("expected ErrResponseTooLarge, got %v", err)
+	}
+	if _, err := os.Stat(path); !errors.Is(err, os.ErrNotExist) {
+		t.Fatalf("expected nothing recorded, got %v", err)
+	}
+}
@@ -7,45 +7,105 @@ import (
 	"errors"
 	"fmt"
 	"io"
+	"log/slog"
 	"net"
 	"net/http"
 	"net/url"
-	"strconv"
 	"strings"
 	"time"
 
 	"github.com/kevinsheth/rollbaz/internal/domain"
-	"github.com/kevinsheth/rollbaz/internal/redact"
 )
 
 const defaultBaseURL = "https://api.rollbar.com/api/1"
 
+// maxResponseBodyBytes is the default response size cap; see
+// WithMaxResponseBytes.
 const maxResponseBodyBytes = 4 << 20
 
+// ErrResponseTooLarge is wrapped by errors for responses over the size cap.
+var ErrResponseTooLarge = errors.New("response too large")
+
 type Client struct {
-	http        *http.Client
-	baseURL     string
-	accessToken string
+	http            *http.Client
+	baseURL         string
+	accessToken     string
+	userAgent       string
+	rqlPollInterval time.Duration
+	retry           RetryPolicy
+	sleep           func(context.Context, time.Duration) error
+	limits          *rateLimiter
+	cache           *ResponseCache
+	middleware      []Middleware
+	logger          *slog.Logger
+	// maxResponseBytes caps how much of a response body is read.
+	maxResponseBytes int
 }
 
 type apiEnvelope struct {
 	Err     int             `json:"err"`
 	Result  json.RawMessage `json:"result"`
+	Data    json.RawMessage `json:"data"`
 	Message string          `json:"message"`
 }
 
 func New(accessToken string) (*Client, error) {
-	return NewWithBaseURL(accessToken, defaultBaseURL)
+	return NewWithOptions(accessToken)
 }
 
 func NewWithBaseURL(accessToken string, baseURL string) (*Client, error) {
+	return NewWithOptions(accessToken, WithBaseURL(baseURL))
+}
+
+func NewWithOptions(accessToken string, options ...Option) (*Client, error) {
 	if strings.TrimSpace(accessToken) == "" {
 		return nil, errors.New("rollbar access token is required")
 	}
 
+	cfg := defaultClientConfig()
+	for _, option := range options {
+		option(&cfg)
+	}
+
+	client := &Client{
+		http:             newHTTPClient(cfg),
+		baseURL:          cfg.baseURL,
+		accessToken:      accessToken,
+		userAgent:        cfg.userAgent,
+		rqlPollInterval:  defaultRQLPollInterval,
+		retry:            cfg.retry,
+		sleep:            sleepContext,
+		limits:           &rateLimiter{},
+		cache:            cfg.cache,
+		middleware:       cfg.middleware,
+		logger:           cfg.logger,
+		maxResponseBytes: cfg.maxResponseBytes,
+	}
+	client.SetThrottle(cfg.maxConcurrent)
+
+	return client, nil
+}
+
+// BaseURL is the API root requests are sent to.
+func (c *Client) BaseURL() string {
+	return c.baseURL
+}
+
+// MaxResponseBytes is the response size cap; see WithMaxResponseBytes.
+func (c *Client) MaxResponseBytes() int {
+	return c.maxResponseBytes
+}
+
+func newHTTPClient(cfg clientConfig) *http.Client {
+	proxy := http.ProxyFromEnvironment
+	if cfg.proxy != nil {
+		proxy = http.ProxyURL(cfg.proxy)
+	}
+
 	transport := &http.Transport{
-		Proxy:                 http.ProxyFromEnvironment,
-		DialContext:           (&net.Dialer{Timeout: 3 * time.Second, KeepAlive: 30 * time.Second}).DialContext,
+		Proxy:                 proxy,
+		DialContext:           (&net.Dialer{Timeout: cfg.connectTimeout, KeepAlive: 30 * time.Second}).DialContext,
+		TLSClientConfig:       cfg.tlsConfig(),
 		MaxIdleConns:          100,
 		MaxIdleConnsPerHost:   100,
 		IdleConnTimeout:       90 * time.Second,
@@ -53,16 +113,10 @@ func NewWithBaseURL(accessToken string, baseURL string) (*Client, error) {
 		ExpectContinueTimeout: 1 * time.Second,
 	}
 
-	httpClient := &http.Client{
-		Timeout:   8 * time.Second,
+	return &http.Client{
+		Timeout:   cfg.requestTimeout,
 		Transport: transport,
 	}
-
-	return &Client{
-		http:        httpClient,
-		baseURL:     baseURL,
-		accessToken: accessToken,
-	}, nil
 }
 
 func (c *Client) ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error) {
@@ -78,7 +132,7 @@ func (c *Client) ResolveItemIDByCounter(ctx context.Context, counter domain.Item
 
 	resolvedID, err := result.resolvedID()
 	if err != nil {
-		return 0, c.wrap(err, "resolve item_id")
+		return 0, c.fail(ErrorKindMissingResult, err, "resolve item_id")
 	}
 
 	return resolvedID, nil
@@ -100,29 +154,9 @@ func (c *Client) GetItem(ctx context.Context, itemID domain.ItemID) (Item, error
 }
 
 func (c *Client) UpdateItem(ctx context.Context, itemID domain.ItemID, patch ItemPatch) error {
-	body, err := json.Marshal(patch)
-	if err != nil {
-		return c.wrap(err, "encode update item request")
-	}
+	_, err := c.writeResult(ctx, http.MethodPatch, "/item/"+itemID.String(), patch, "update item")
 
-	raw, err := c.doPatch(ctx, "/item/"+itemID.String(), body, "update item")
-	if err != nil {
-		return err
-	}
-
-	var envelope apiEnvelope
-	if err := json.Unmarshal(raw, &envelope); err != nil {
-		return c.wrap(err, "decode update item envelope")
-	}
-	if envelope.Err != 0 {
-		message := envelope.Message
-		if message == "" {
-			message = "unknown error from Rollbar"
-		}
-		return c.wrap(errors.New(message), "rollbar update item")
-	}
-
-	return nil
+	return err
 }
 
 func (c *Client) ListActiveItems(ctx context.Context, limit int) ([]Item, error) {
@@ -131,7 +165,7 @@ func (c *Client) ListActiveItems(ctx context.Context, limit int) ([]Item, error)
 		return nil, err
 	}
 
-	var wrapped []topActiveItem
+	var wrapped []TopActiveItem
 	if err := json.Unmarshal(raw, &wrapped); err == nil {
 		items := make([]Item, 0, len(wrapped))
 		for _, entry := range wrapped {
@@ -148,30 +182,67 @@ func (c *Client) ListActiveItems(ctx context.Context, limit int) ([]Item, error)
 	return trimItems(items, limit), nil
 }
 
-func (c *Client) ListItems(ctx context.Context, status string, page int) ([]Item, error) {
-	query := "/items"
-	params := make([]string, 0, 2)
-	if status != "" {
-		params = append(params, "status="+url.QueryEscape(status))
+func (c *Client) AssignItem(ctx context.Context, itemID domain.ItemID, userID uint64) error {
+	return c.UpdateItem(ctx, itemID, ItemPatch{AssignedUserID: &userID})
+}
+
+func (c *Client) UnassignItem(ctx context.Context, itemID domain.ItemID) error {
+	_, err := c.writeResult(ctx, http.MethodPatch, "/item/"+itemID.String(), unassignPatch{}, "unassign item")
+
+	return err
+}
+
+// TopActiveItems returns the most active items together with their hourly
+// occurrence counts, oldest bucket first.
+func (c *Client) TopActiveItems(ctx context.Context, params TopActiveItemsParams) ([]TopActiveItem, error) {
+	raw, err := c.getResult(ctx, withQuery("/reports/top_active_items", params.encode()), "top active items")
+	if err != nil {
+		return nil, err
 	}
-	if page > 0 {
-		params = append(params, "page="+strconv.Itoa(page))
+
+	var entries []TopActiveItem
+	if err := json.Unmarshal(raw, &entries); err != nil {
+		return nil, c.wrap(err, "decode top active items")
 	}
-	if len(params) > 0 {
-		query += "?" + strings.Join(params, "&")
+	for index := range entries {
+		entries[index].Item = hydrateItem(entries[index].Item, "active")
 	}
 
-	raw, err := c.getResult(ctx, query, "items")
+	return entries, nil
+}
+
+func (c *Client) ListItems(ctx context.Context, status string, page int) ([]Item, error) {
+	result, err := c.ListItemsPage(ctx, ItemQuery{Status: status, Page: page})
 	if err != nil {
 		return nil, err
 	}
 
-	items, err := parseItems(raw)
+	return result.Items, nil
+}
+
+func (c *Client) ListItemsPage(ctx context.Context, query ItemQuery) (ItemPage, error) {
+	raw, err := c.getResult(ctx, withQuery("/items", query.encode()), "items")
 	if err != nil {
-		return nil, c.wrap(err, "decode items response")
+		return ItemPage{}, err
+	}
+
+	result, err := parseItemPage(raw, query.Page)
+	if err != nil {
+		return ItemPage{}, c.wrap(err, "decode items response")
+	}
+
+	return result, nil
+}
+
+// SearchItems runs Rollbar's free-text item search, returning one page of
+// the items it matches; page 0 and 1 are both the first page.
+func (c *Client) SearchItems(ctx context.Context, text string, page int) (ItemPage, error) {
+	trimmed := strings.TrimSpace(text)
+	if trimmed == "" {
+		return ItemPage{}, errors.New("search text is required")
 	}
 
-	return items, nil
+	return c.ListItemsPage(ctx, ItemQuery{Query: trimmed, Page: page})
 }
 
 func (c *Client) GetLatestInstance(ctx context.Context, itemID domain.ItemID) (*ItemInstance, error) {
@@ -233,6 +304,24 @@ func parseItems(raw json.RawMessage) ([]Item, error) {
 	return wrapped.Items, nil
 }
 
+func parseItemPage(raw json.RawMessage, requestedPage int) (ItemPage, error) {
+	items, err := parseItems(raw)
+	if err != nil {
+		return ItemPage{}, err
+	}
+
+	result := ItemPage{Items: items, Page: requestedPage}
+	var meta itemPageMeta
+	if err := json.Unmarshal(raw, &meta); err == nil {
+		if meta.Page > 0 {
+			result.Page = meta.Page
+		}
+		result.TotalCount = meta.TotalCount
+	}
+
+	return result, nil
+}
+
 func trimItems(items []Item, limit int) []Item {
 	if limit <= 0 || len(items) <= limit {
 		return items
@@ -241,7 +330,7 @@ func trimItems(items []Item, limit int) []Item {
 	return items[:limit]
 }
 
-func hydrateItem(item Item, defaultStatus string) Item {
+func hydrateItem(item Item, defaultStatus Status) Item {
 	if defaultStatus != "" && item.Status == "" {
 		item.Status = defaultStatus
 	}
@@ -272,6 +361,39 @@ func (c *Client) getResult(ctx context.Context, endpointPath string, op string)
 		return nil, err
 	}
 
+	result, err := c.decodeEnvelope(body, op)
+	if err != nil {
+		return nil, err
+	}
+
+	if len(result) == 0 || string(result) == "null" {
+		return nil, c.fail(ErrorKindMissingResult, errors.New("missing result"), op+" response")
+	}
+
+	return result, nil
+}
+
+func (c *Client) writeResult(ctx context.Context, method string, endpointPath string, payload any, op string) (json.RawMessage, error) {
+	var requestBody []byte
+	contentType := ""
+	if payload != nil {
+		body, err := json.Marshal(payload)
+		if err != nil {
+			return nil, c.wrap(err, "encode "+op+" request")
+		}
+		requestBody = body
+		contentType = "application/json"
+	}
+
+	responseBody, err := c.doRequest(ctx, method, endpointPath, requestBody, contentType, op)
+	if err != nil {
+		return nil, err
+	}
+
+	return c.decodeEnvelope(responseBody, op)
+}
+
+func (c *Client) decodeEnvelope(body []byte, op string) (json.RawMessage, error) {
 	var envelope apiEnvelope
 	if err := json.Unmarshal(body, &envelope); err != nil {
 		return nil, c.wrap(err, "decode "+op+" envelope")
@@ -282,11 +404,12 @@ func (c *Client) getResult(ctx context.Context, endpointPath string, op string)
 		if message == "" {
 			message = "unknown error from Rollbar"
 		}
-		return nil, c.wrap(errors.New(message), "rollbar "+op)
+		return nil, c.fail(ErrorKindAPI, errors.New(message), "rollbar "+op)
 	}
 
-	if len(envelope.Result) == 0 || string(envelope.Result) == "null" {
-		return nil, c.wrap(errors.New("missing result"), op+" response")
+	// The deploy endpoint answers with "data" where everything else uses "result".
+	if len(envelope.Result) == 0 {
+		return envelope.Data, nil
 	}
 
 	return envelope.Result, nil
@@ -296,50 +419,126 @@ func (c *Client) doGet(ctx context.Context, endpointPath string, op string) ([]b
 	return c.doRequest(ctx, http.MethodGet, endpointPath, nil, "", op)
 }
 
-func (c *Client) doPatch(ctx context.Context, endpointPath string, body []byte, op string) ([]byte, error) {
-	return c.doRequest(ctx, http.MethodPatch, endpointPath, bytes.NewReader(body), "application/json", op)
+func (c *Client) doRequest(ctx context.Context, method string, endpointPath string, requestBody []byte, contentType string, op string) (body []byte, err error) {
+	call := c.startCall(method, endpointPath, op)
+	defer func() { call.end(ctx, err) }()
+
+	for attempt := 1; ; attempt++ {
+		call.attempts = attempt
+		body, err = c.doOnce(ctx, method, endpointPath, requestBody, contentType, op)
+		if err == nil || attempt >= c.retry.MaxAttempts || !c.retry.shouldRetry(method, err) {
+			return body, err
+		}
+		wait := c.retry.delay(attempt, err)
+		if outlastsDeadline(ctx, wait) {
+			return nil, err
+		}
+		if sleepErr := c.sleep(ctx, wait); sleepErr != nil {
+			return nil, err
+		}
+	}
 }
 
-func (c *Client) doRequest(ctx context.Context, method string, endpointPath string, requestBody io.Reader, contentType string, op string) ([]byte, error) {
+func (c *Client) newRequest(ctx context.Context, method string, endpointPath string, requestBody []byte, contentType string, op string) (*http.Request, error) {
 	requestURL, err := buildURL(c.baseURL, endpointPath)
 	if err != nil {
-		return nil, c.wrap(err, "build "+op+" URL")
+		return nil, c.fail(ErrorKindRequest, err, "build "+op+" URL")
+	}
+
+	var bodyReader io.Reader
+	if requestBody != nil {
+		bodyReader = bytes.NewReader(requestBody)
 	}
 
-	req, err := http.NewRequestWithContext(ctx, method, requestURL, requestBody)
+	req, err := http.NewRequestWithContext(ctx, method, requestURL, bodyReader)
 	if err != nil {
-		return nil, c.wrap(err, "build "+op+" request")
+		return nil, c.fail(ErrorKindRequest, err, "build "+op+" request")
 	}
 
 	req.Header.Set("X-Rollbar-Access-Token", c.accessToken)
+	if c.userAgent != "" {
+		req.Header.Set("User-Agent", c.userAgent)
+	}
 	if contentType != "" {
 		req.Header.Set("Content-Type", contentType)
 	}
 
-	response, err := c.http.Do(req)
+	return req, nil
+}
+
+func (c *Client) doOnce(ctx context.Context, method string, endpointPath string, requestBody []byte, contentType string, op string) ([]byte, error) {
+	req, err := c.newRequest(ctx, method, endpointPath, requestBody, contentType, op)
+	if err != nil {
+		return nil, err
+	}
+	cacheKey, cached := c.conditional(req)
+
+	response, release, err := c.send(ctx, req, op)
+	if err != nil {
+		return nil, err
+	}
+	defer release()
+	if cached != nil && response.StatusCode == http.StatusNotModified {
+		return cached.Body, nil
+	}
+
+	body, err := c.readResponse(ctx, response, op)
+	if err == nil {
+		c.cacheResponse(cacheKey, response.Header, body)
+	}
+
+	return body, err
+}
+
+// send runs req through the middleware stack. release closes the response
+// body, which also frees its throttle slot, once the caller is done reading.
+func (c *Client) send(ctx context.Context, req *http.Request, op string) (*http.Response, func(), error) {
+	response, err := c.transport().RoundTrip(req)
 	if err != nil {
-		return nil, c.wrap(err, "request "+op)
+		apiErr := c.fail(ErrorKindRequest, err, "request "+op)
+		apiErr.transient = ctx.Err() == nil && isTransportError(err)
+		return nil, nil, apiErr
 	}
-	defer func() {
-		_ = response.Body.Close()
-	}()
 
+	return response, func() { _ = response.Body.Close() }, nil
+}
+
+func (c *Client) readResponse(ctx context.Context, response *http.Response, op string) ([]byte, error) {
 	if response.StatusCode < http.StatusOK || response.StatusCode >= http.StatusMultipleChoices {
 		limited, _ := io.ReadAll(io.LimitReader(response.Body, 2048))
-		return nil, c.wrap(fmt.Errorf("status %d: %s", response.StatusCode, strings.TrimSpace(string(limited))), op+" returned non-success status")
+		return nil, c.statusError(response, limited, op)
 	}
 
-	responseBody, err := io.ReadAll(io.LimitReader(response.Body, maxResponseBodyBytes+1))
+	responseBody, err := io.ReadAll(io.LimitReader(response.Body, int64(c.maxResponseBytes)+1))
 	if err != nil {
-		return nil, c.wrap(err, "read "+op+" response")
+		apiErr := c.fail(ErrorKindRequest, err, "read "+op+" response")
+		apiErr.transient = ctx.Err() == nil
+		return nil, apiErr
 	}
-	if len(responseBody) > maxResponseBodyBytes {
-		return nil, c.wrap(fmt.Errorf("response exceeds %d bytes", maxResponseBodyBytes), "read "+op+" response")
+	if len(responseBody) > c.maxResponseBytes {
+		return nil, c.fail(ErrorKindRequest, fmt.Errorf("%w: exceeds %d bytes", ErrResponseTooLarge, c.maxResponseBytes), "read "+op+" response")
 	}
 
 	return responseBody, nil
 }
 
+func withQuery(endpointPath string, encodedQuery string) string {
+	if encodedQuery == "" {
+		return endpointPath
+	}
+
+	return endpointPath + "?" + encodedQuery
+}
+
+func appendParam(params []string, key string, value string) []string {
+	trimmed := strings.TrimSpace(value)
+	if trimmed == "" {
+		return params
+	}
+
+	return append(params, key+"="+url.QueryEscape(trimmed))
+}
+
 func buildURL(baseURL string, endpointPath string) (string, error) {
 	parsed, err := url.Parse(baseURL)
 	if err != nil {
@@ -368,10 +567,12 @@ func joinPathPreserveTrailing(base string, suffix string) string {
 	return trimmedBase + "/" + suffix
 }
 
+// wrap reports a payload that could not be encoded or decoded, which is the
+// failure mode left once a request has returned a valid envelope.
 func (c *Client) wrap(err error, operation string) error {
 	if err == nil {
 		return nil
 	}
 
-	return fmt.Errorf("%s: %s", operation, redact.String(err.Error(), c.accessToken))
+	return c.fail(ErrorKindDecode, err, operation)
 }
@@ -119,6 +119,59 @@ func TestUpdateItem(t *testing.T) {
 	}
 }
 
+func TestUpdateItemEncodesLevelTitleAndAssignee(t *testing.T) {
+	t.Parallel()
+
+	assignee := uint64(42)
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		want := `{"level":"warning","title":"renamed","assigned_user_id":42}`
+		if string(body) != want {
+			t.Fatalf("unexpected patch body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+	})
+
+	err := client.UpdateItem(context.Background(), domain.ItemID(1), ItemPatch{Level: "warning", Title: "renamed", AssignedUserID: &assignee})
+	if err != nil {
+		t.Fatalf("UpdateItem() error = %v", err)
+	}
+}
+
+func TestAssignAndUnassignItem(t *testing.T) {
+	t.Parallel()
+
+	bodies := make(chan string, 2)
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPatch || r.URL.Path != "/item/5" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		bodies <- string(body)
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+	})
+
+	if err := client.AssignItem(context.Background(), domain.ItemID(5), 42); err != nil {
+		t.Fatalf("AssignItem() error = %v", err)
+	}
+	if got := <-bodies; got != `{"assigned_user_id":42}` {
+		t.Fatalf("unexpected assign body: %s", got)
+	}
+
+	if err := client.UnassignItem(context.Background(), domain.ItemID(5)); err != nil {
+		t.Fatalf("UnassignItem() error = %v", err)
+	}
+	if got := <-bodies; got != `{"assigned_user_id":null}` {
+		t.Fatalf("unexpected unassign body: %s", got)
+	}
+}
+
 func TestUpdateItemEnvelopeError(t *testing.T) {
 	t.Parallel()
 
@@ -347,6 +400,43 @@ func TestListActiveItemsFallbackToItemsParse(t *testing.T) {
 	}
 }
 
+func TestTopActiveItems(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/reports/top_active_items" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		if r.URL.RawQuery != "hours=12&environments=production%2Cstaging" {
+			t.Fatalf("unexpected query: %s", r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"item":{"id":1,"counter":2,"title":"x","occurrences":3},"counts":[0,1,2]}]}`)
+	})
+
+	entries, err := client.TopActiveItems(context.Background(), TopActiveItemsParams{Hours: 12, Environments: []string{"production", " ", "staging"}})
+	if err != nil {
+		t.Fatalf("TopActiveItems() error = %v", err)
+	}
+	if len(entries) != 1 || len(entries[0].Counts) != 3 || entries[0].Counts[2] != 2 {
+		t.Fatalf("unexpected entries: %+v", entries)
+	}
+	if entries[0].Item.Status != "active" || entries[0].Item.TotalOccurrences == nil {
+		t.Fatalf("expected hydrated item, got %+v", entries[0].Item)
+	}
+}
+
+func TestTopActiveItemsDecodeError(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[]}}`)
+	})
+
+	if _, err := client.TopActiveItems(context.Background(), TopActiveItemsParams{}); err == nil {
+		t.Fatalf("expected decode error")
+	}
+}
+
 func TestListItemsWrapped(t *testing.T) {
 	t.Parallel()
 
@@ -394,6 +484,69 @@ func TestListItemsStatusAndPageQuery(t *testing.T) {
 	}
 }
 
+func TestListItemsPageEncodesFilters(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		want := "status=active&level=error&level=critical&environment=production&assigned_user=alice&query=payment+timeout&page=3"
+		if r.URL.RawQuery != want {
+			t.Fatalf("unexpected query: %s", r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":2,"title":"x"}],"page":3,"total_count":41}}`)
+	})
+	page, err := client.ListItemsPage(context.Background(), ItemQuery{
+		Status:       "active",
+		Levels:       []string{"error", "critical"},
+		Environments: []string{"production", " "},
+		AssignedUser: "alice",
+		Query:        "payment timeout",
+		Page:         3,
+	})
+	if err != nil {
+		t.Fatalf("ListItemsPage() error = %v", err)
+	}
+	if page.Page != 3 || page.TotalCount != 41 || len(page.Items) != 1 {
+		t.Fatalf("unexpected page: %+v", page)
+	}
+}
+
+func TestSearchItems(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.RawQuery != "query=NullPointerException+checkout&page=2" {
+			t.Fatalf("unexpected query: %s", r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":2,"title":"NullPointerException in checkout"}],"page":2,"total_count":21}}`)
+	})
+
+	page, err := client.SearchItems(context.Background(), " NullPointerException checkout ", 2)
+	if err != nil || len(page.Items) != 1 || page.TotalCount != 21 {
+		t.Fatalf("SearchItems() = %+v, %v", page, err)
+	}
+	if _, err := client.SearchItems(context.Background(), " ", 1); err == nil {
+		t.Fatal("expected an error for empty search text")
+	}
+}
+
+func TestListItemsPageListShapeKeepsRequestedPage(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.RawQuery != "" {
+			t.Fatalf("unexpected query: %s", r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":1,"counter":2,"title":"x"}]}`)
+	})
+	page, err := client.ListItemsPage(context.Background(), ItemQuery{})
+	if err != nil {
+		t.Fatalf("ListItemsPage() error = %v", err)
+	}
+	if page.Page != 0 || page.TotalCount != 0 || len(page.Items) != 1 {
+		t.Fatalf("unexpected page: %+v", page)
+	}
+}
+
 func TestParseItemsInvalid(t *testing.T) {
 	t.Parallel()
 
@@ -428,6 +581,7 @@ func newTestClient(t *testing.T, baseURL string) *Client {
 	if err != nil {
 		t.Fatalf("New() error = %v", err)
 	}
+	client.SetRetryPolicy(RetryPolicy{MaxAttempts: 1})
 	return client
 }
 
@@ -0,0 +1,124 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"net/http"
+	"strconv"
+	"strings"
+)
+
+const (
+	DeployStatusStarted   = "started"
+	DeployStatusSucceeded = "succeeded"
+	DeployStatusFailed    = "failed"
+	DeployStatusTimedOut  = "timed_out"
+)
+
+type Deploy struct {
+	ID            uint64  `json:"id"`
+	ProjectID     uint64  `json:"project_id"`
+	Environment   string  `json:"environment"`
+	Revision      string  `json:"revision"`
+	LocalUsername string  `json:"local_username"`
+	UserID        *uint64 `json:"user_id"`
+	Comment       string  `json:"comment"`
+	Status        string  `json:"status"`
+	StartTime     *uint64 `json:"start_time"`
+	FinishTime    *uint64 `json:"finish_time"`
+}
+
+type DeployPage struct {
+	Deploys []Deploy `json:"deploys"`
+	Page    int      `json:"page"`
+}
+
+type DeployReport struct {
+	Environment     string `json:"environment"`
+	Revision        string `json:"revision"`
+	RollbarUsername string `json:"rollbar_username,omitempty"`
+	LocalUsername   string `json:"local_username,omitempty"`
+	Comment         string `json:"comment,omitempty"`
+	Status          string `json:"status,omitempty"`
+}
+
+type DeployUpdate struct {
+	Status string `json:"status"`
+}
+
+type deployReportResult struct {
+	DeployID uint64 `json:"deploy_id"`
+}
+
+func (c *Client) ListDeploys(ctx context.Context, page int) (DeployPage, error) {
+	query := ""
+	if page > 0 {
+		query = "page=" + strconv.Itoa(page)
+	}
+
+	raw, err := c.getResult(ctx, withQuery("/deploys", query), "deploys")
+	if err != nil {
+		return DeployPage{}, err
+	}
+
+	var result DeployPage
+	if err := json.Unmarshal(raw, &result); err != nil {
+		return DeployPage{}, c.wrap(err, "decode deploys response")
+	}
+	if result.Page == 0 {
+		result.Page = page
+	}
+
+	return result, nil
+}
+
+func (c *Client) GetDeploy(ctx context.Context, deployID uint64) (Deploy, error) {
+	raw, err := c.getResult(ctx, "/deploy/"+strconv.FormatUint(deployID, 10), "deploy")
+	if err != nil {
+		return Deploy{}, err
+	}
+
+	var deploy Deploy
+	if err := json.Unmarshal(raw, &deploy); err != nil {
+		return Deploy{}, c.wrap(err, "decode deploy response")
+	}
+
+	return deploy, nil
+}
+
+func (c *Client) ReportDeploy(ctx context.Context, report DeployReport) (uint64, error) {
+	report.Environment = strings.TrimSpace(report.Environment)
+	report.Revision = strings.TrimSpace(report.Revision)
+	if report.Environment == "" {
+		return 0, errors.New("deploy environment is required")
+	}
+	if report.Revision == "" {
+		return 0, errors.New("deploy revision is required")
+	}
+
+	raw, err := c.writeResult(ctx, http.MethodPost, "/deploy", report, "report deploy")
+	if err != nil {
+		return 0, err
+	}
+
+	var result deployReportResult
+	if err := json.Unmarshal(raw, &result); err != nil {
+		return 0, c.wrap(err, "decode report deploy response")
+	}
+	if result.DeployID == 0 {
+		return 0, c.fail(ErrorKindMissingResult, errors.New("missing deploy_id"), "report deploy response")
+	}
+
+	return result.DeployID, nil
+}
+
+func (c *Client) UpdateDeploy(ctx context.Context, deployID uint64, update DeployUpdate) error {
+	if strings.TrimSpace(update.Status) == "" {
+		return errors.New("deploy status is required")
+	}
+
+	_, err := c.writeResult(ctx, http.MethodPatch, "/deploy/"+strconv.FormatUint(deployID, 10), update, "update deploy")
+
+	return err
+}
@@ -0,0 +1,121 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"testing"
+)
+
+func TestListDeploys(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/deploys" || r.URL.RawQuery != "page=2" {
+			t.Fatalf("unexpected request: %s?%s", r.URL.Path, r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"deploys":[{"id":9,"environment":"production","revision":"abc123","status":"succeeded","start_time":1700000000}]}}`)
+	})
+
+	page, err := client.ListDeploys(context.Background(), 2)
+	if err != nil {
+		t.Fatalf("ListDeploys() error = %v", err)
+	}
+	if page.Page != 2 || len(page.Deploys) != 1 || page.Deploys[0].Revision != "abc123" {
+		t.Fatalf("unexpected deploys page: %+v", page)
+	}
+}
+
+func TestGetDeploy(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/deploy/9" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":9,"environment":"staging","revision":"def456"}}`)
+	})
+
+	deploy, err := client.GetDeploy(context.Background(), 9)
+	if err != nil {
+		t.Fatalf("GetDeploy() error = %v", err)
+	}
+	if deploy.ID != 9 || deploy.Environment != "staging" {
+		t.Fatalf("unexpected deploy: %+v", deploy)
+	}
+}
+
+func TestReportDeploy(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/deploy" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		var report DeployReport
+		if err := json.Unmarshal(body, &report); err != nil {
+			t.Fatalf("Unmarshal() error = %v", err)
+		}
+		if report.Environment != "production" || report.Revision != "abc123" || report.Status != DeployStatusStarted {
+			t.Fatalf("unexpected report: %+v", report)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"data":{"deploy_id":77}}`)
+	})
+
+	deployID, err := client.ReportDeploy(context.Background(), DeployReport{Environment: " production ", Revision: "abc123", Status: DeployStatusStarted})
+	if err != nil {
+		t.Fatalf("ReportDeploy() error = %v", err)
+	}
+	if deployID != 77 {
+		t.Fatalf("ReportDeploy() = %d", deployID)
+	}
+}
+
+func TestReportDeployValidationAndMissingID(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{}}`)
+	})
+
+	if _, err := client.ReportDeploy(context.Background(), DeployReport{Revision: "abc"}); err == nil {
+		t.Fatalf("expected missing environment error")
+	}
+	if _, err := client.ReportDeploy(context.Background(), DeployReport{Environment: "production"}); err == nil {
+		t.Fatalf("expected missing revision error")
+	}
+	if _, err := client.ReportDeploy(context.Background(), DeployReport{Environment: "production", Revision: "abc"}); err == nil {
+		t.Fatalf("expected missing deploy_id error")
+	}
+}
+
+func TestUpdateDeploy(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPatch || r.URL.Path != "/deploy/77" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		if string(body) != `{"status":"succeeded"}` {
+			t.Fatalf("unexpected body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":77}}`)
+	})
+
+	if err := client.UpdateDeploy(context.Background(), 77, DeployUpdate{Status: DeployStatusSucceeded}); err != nil {
+		t.Fatalf("UpdateDeploy() error = %v", err)
+	}
+	if err := client.UpdateDeploy(context.Background(), 77, DeployUpdate{}); err == nil {
+		t.Fatalf("expected missing status error")
+	}
+}
@@ -0,0 +1,15 @@
+// Package rollbar is a small client for the Rollbar REST API.
+//
+// Every Client method is an ordinary blocking call: it returns once the
+// response has been read, and the context passed in only bounds how long it
+// may take. There is no separate synchronous variant because none is needed;
+// a plain main function can call client.GetItem(context.Background(), id)
+// directly. Methods are safe for concurrent use, so callers that want
+// parallelism can fan out with goroutines, or use GetItems and
+// ResolveItemIDsByCounter for the common bounded, ordered fan-out. Ping
+// checks connectivity, for example at startup, and reports the latency.
+//
+// Custom headers, audit logging, and record/replay plug in as Middleware
+// through WithMiddleware or Client.Use rather than by wrapping the client,
+// and WithLogger traces every call through log/slog.
+package rollbar
@@ -0,0 +1,79 @@
+package rollbar
+
+import (
+	"math"
+	"strings"
+	"time"
+)
+
+// Status is an item's workflow state. Unknown values from the API are kept
+// as-is rather than rejected.
+type Status string
+
+const (
+	StatusActive   Status = "active"
+	StatusResolved Status = "resolved"
+	StatusMuted    Status = "muted"
+	StatusArchived Status = "archived"
+)
+
+func (s Status) Known() bool {
+	switch s {
+	case StatusActive, StatusResolved, StatusMuted, StatusArchived:
+		return true
+	default:
+		return false
+	}
+}
+
+// Level is an item or occurrence severity. Numeric levels from the API are
+// normalized to these names when items are decoded.
+type Level string
+
+const (
+	LevelDebug    Level = "debug"
+	LevelInfo     Level = "info"
+	LevelWarning  Level = "warning"
+	LevelError    Level = "error"
+	LevelCritical Level = "critical"
+)
+
+// Severity orders levels from debug (10) to critical (50), matching Rollbar's
+// numeric levels. Unknown levels are 0.
+func (l Level) Severity() int {
+	switch Level(strings.ToLower(string(l))) {
+	case LevelDebug:
+		return 10
+	case LevelInfo:
+		return 20
+	case LevelWarning:
+		return 30
+	case LevelError:
+		return 40
+	case LevelCritical:
+		return 50
+	default:
+		return 0
+	}
+}
+
+// AtLeast reports whether l is as severe as other.
+func (l Level) AtLeast(other Level) bool {
+	return l.Severity() >= other.Severity()
+}
+
+func (i Item) FirstOccurrenceTime() (time.Time, bool) {
+	return unixTime(i.FirstOccurrenceTimestamp)
+}
+
+func (i Item) LastOccurrenceTime() (time.Time, bool) {
+	return unixTime(i.LastOccurrenceTimestamp)
+}
+
+func unixTime(seconds *uint64) (time.Time, bool) {
+	if seconds == nil || *seconds > math.MaxInt64 {
+		return time.Time{}, false
+	}
+
+	return time.Unix(int64(*seconds), 0).UTC(), true
+}
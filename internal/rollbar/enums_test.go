@@ -0,0 +1,107 @@
+package rollbar
+
+import (
+	"encoding/json"
+	"math"
+	"testing"
+	"time"
+)
+
+func TestStatusKnown(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		status Status
+		want   bool
+	}{
+		{status: StatusActive, want: true},
+		{status: StatusResolved, want: true},
+		{status: StatusMuted, want: true},
+		{status: StatusArchived, want: true},
+		{status: "snoozed", want: false},
+		{status: "", want: false},
+	}
+
+	for _, tc := range tests {
+		if got := tc.status.Known(); got != tc.want {
+			t.Fatalf("Status(%q).Known() = %v, want %v", tc.status, got, tc.want)
+		}
+	}
+}
+
+func TestLevelSeverity(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		level Level
+		want  int
+	}{
+		{level: LevelDebug, want: 10},
+		{level: LevelInfo, want: 20},
+		{level: LevelWarning, want: 30},
+		{level: "ERROR", want: 40},
+		{level: LevelCritical, want: 50},
+		{level: "fatal", want: 0},
+	}
+
+	for _, tc := range tests {
+		if got := tc.level.Severity(); got != tc.want {
+			t.Fatalf("Level(%q).Severity() = %d, want %d", tc.level, got, tc.want)
+		}
+	}
+
+	if !LevelCritical.AtLeast(LevelError) || LevelWarning.AtLeast(LevelError) {
+		t.Fatalf("unexpected AtLeast ordering")
+	}
+}
+
+// timedItemJSON is a resolved error item with every timestamp set.
+const timedItemJSON = `{"id":1,"status":"resolved","level":40,"first_occurrence_timestamp":1700000000,"last_occurrence_timestamp":1700000600,"last_activated_timestamp":1700000300,"last_resolved_timestamp":1700000900}`
+
+func decodeTimedItem(t *testing.T) Item {
+	t.Helper()
+
+	var item Item
+	if err := json.Unmarshal([]byte(timedItemJSON), &item); err != nil {
+		t.Fatalf("unmarshal item: %v", err)
+	}
+
+	return item
+}
+
+func TestItemStatusTimestamps(t *testing.T) {
+	t.Parallel()
+
+	item := decodeTimedItem(t)
+	if item.Status != StatusResolved || item.Level != LevelError {
+		t.Fatalf("unexpected enums: status=%q level=%q", item.Status, item.Level)
+	}
+	if item.LastActivatedTimestamp == nil || *item.LastActivatedTimestamp != 1700000300 {
+		t.Fatalf("unexpected last activation: %v", item.LastActivatedTimestamp)
+	}
+	if item.LastResolvedTimestamp == nil || *item.LastResolvedTimestamp != 1700000900 {
+		t.Fatalf("unexpected last resolution: %v", item.LastResolvedTimestamp)
+	}
+}
+
+func TestItemOccurrenceTimes(t *testing.T) {
+	t.Parallel()
+
+	item := decodeTimedItem(t)
+	first, ok := item.FirstOccurrenceTime()
+	if !ok || !first.Equal(time.Unix(1700000000, 0)) {
+		t.Fatalf("unexpected first occurrence: %v %v", first, ok)
+	}
+	last, ok := item.LastOccurrenceTime()
+	if !ok || last.Sub(first) != 10*time.Minute || last.Location() != time.UTC {
+		t.Fatalf("unexpected last occurrence: %v %v", last, ok)
+	}
+
+	overflow := uint64(math.MaxUint64)
+	if _, ok := (Item{LastOccurrenceTimestamp: &overflow}).LastOccurrenceTime(); ok {
+		t.Fatalf("overflowing timestamps should be rejected")
+	}
+	if _, ok := (Item{}).FirstOccurrenceTime(); ok {
+		t.Fatalf("missing timestamps should be rejected")
+	}
+}
@@ -0,0 +1,30 @@
+package rollbar
+
+import (
+	"context"
+	"strconv"
+)
+
+type Environment struct {
+	ID          uint64 `json:"id"`
+	ProjectID   uint64 `json:"project_id"`
+	Environment string `json:"environment"`
+}
+
+type environmentsEnvelope struct {
+	Environments []Environment `json:"environments"`
+}
+
+func (c *Client) ListEnvironments(ctx context.Context, page int) ([]Environment, error) {
+	query := ""
+	if page > 0 {
+		query = "page=" + strconv.Itoa(page)
+	}
+
+	var wrapped environmentsEnvelope
+	if err := c.getInto(ctx, withQuery("/environments", query), "environments", &wrapped); err != nil {
+		return nil, err
+	}
+
+	return wrapped.Environments, nil
+}
@@ -0,0 +1,27 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"testing"
+)
+
+func TestListEnvironments(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/environments" || r.URL.RawQuery != "page=1" {
+			t.Fatalf("unexpected request: %s?%s", r.URL.Path, r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"environments":[{"id":1,"project_id":2,"environment":"production"},{"id":2,"project_id":2,"environment":"staging"}]}}`)
+	})
+
+	environments, err := client.ListEnvironments(context.Background(), 1)
+	if err != nil {
+		t.Fatalf("ListEnvironments() error = %v", err)
+	}
+	if len(environments) != 2 || environments[1].Environment != "staging" {
+		t.Fatalf("unexpected environments: %+v", environments)
+	}
+}
@@ -0,0 +1,140 @@
+package rollbar
+
+import (
+	"errors"
+	"net/http"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/redact"
+)
+
+// ErrorKind classifies why a Rollbar call failed.
+type ErrorKind int
+
+const (
+	// ErrorKindRequest covers failures before a response arrived: bad URLs,
+	// transport errors, timeouts, and unreadable bodies.
+	ErrorKindRequest ErrorKind = iota + 1
+	// ErrorKindHTTP is a non-2xx status other than 429.
+	ErrorKindHTTP
+	// ErrorKindRateLimited is a 429 response.
+	ErrorKindRateLimited
+	// ErrorKindAPI is a 2xx response whose envelope carries err != 0.
+	ErrorKindAPI
+	// ErrorKindDecode is a response or request body that failed to (de)serialize.
+	ErrorKindDecode
+	// ErrorKindMissingResult is a successful envelope without the expected payload.
+	ErrorKindMissingResult
+)
+
+func (k ErrorKind) String() string {
+	switch k {
+	case ErrorKindRequest:
+		return "request"
+	case ErrorKindHTTP:
+		return "http"
+	case ErrorKindRateLimited:
+		return "rate_limited"
+	case ErrorKindAPI:
+		return "api"
+	case ErrorKindDecode:
+		return "decode"
+	case ErrorKindMissingResult:
+		return "missing_result"
+	default:
+		return "unknown"
+	}
+}
+
+// Error is returned by every Client method that talks to Rollbar. It is a
+// plain error, so callers that only print it need nothing new; callers that
+// need to branch on rate limits or 404s can use errors.As. Message is always
+// redacted of the access token.
+type Error struct {
+	Kind       ErrorKind
+	Op         string
+	StatusCode int
+	RetryAfter time.Duration
+	Message    string
+	cause      error
+	transient  bool
+}
+
+func (e *Error) Error() string {
+	return e.Op + ": " + e.Message
+}
+
+func (e *Error) Unwrap() error {
+	return e.cause
+}
+
+// IsNotFound reports whether err is a Rollbar 404.
+func IsNotFound(err error) bool {
+	var apiErr *Error
+	return errors.As(err, &apiErr) && apiErr.StatusCode == http.StatusNotFound
+}
+
+// IsRateLimited reports whether err is a Rollbar 429, returning how long the
+// server asked us to wait (zero when it did not say).
+func IsRateLimited(err error) (time.Duration, bool) {
+	var apiErr *Error
+	if !errors.As(err, &apiErr) || apiErr.Kind != ErrorKindRateLimited {
+		return 0, false
+	}
+
+	return apiErr.RetryAfter, true
+}
+
+func (c *Client) fail(kind ErrorKind, err error, operation string) *Error {
+	return &Error{
+		Kind:    kind,
+		Op:      operation,
+		Message: redact.String(err.Error(), c.accessToken),
+		cause:   err,
+	}
+}
+
+func (c *Client) statusError(response *http.Response, body []byte, op string) *Error {
+	message := "status " + strconv.Itoa(response.StatusCode) + ": " + strings.TrimSpace(string(body))
+	apiErr := c.fail(ErrorKindHTTP, errors.New(message), op+" returned non-success status")
+	apiErr.StatusCode = response.StatusCode
+	if response.StatusCode == http.StatusTooManyRequests {
+		apiErr.Kind = ErrorKindRateLimited
+		apiErr.RetryAfter = parseRetryAfter(response.Header.Get("Retry-After"), time.Now())
+		if apiErr.RetryAfter == 0 {
+			apiErr.RetryAfter = untilRateLimitReset(response.Header, time.Now())
+		}
+	}
+
+	return apiErr
+}
+
+// parseRetryAfter accepts both forms allowed by RFC 9110: delay seconds or an
+// HTTP date.
+func parseRetryAfter(value string, now time.Time) time.Duration {
+	trimmed := strings.TrimSpace(value)
+	if trimmed == "" {
+		return 0
+	}
+	if seconds, err := strconv.Atoi(trimmed); err == nil {
+		return time.Duration(max(seconds, 0)) * time.Second
+	}
+	if at, err := http.ParseTime(trimmed); err == nil && at.After(now) {
+		return at.Sub(now)
+	}
+
+	return 0
+}
+
+// untilRateLimitReset falls back to X-Rate-Limit-Reset when a 429 arrives
+// without Retry-After.
+func untilRateLimitReset(header http.Header, now time.Time) time.Duration {
+	status, ok := parseRateLimit(header)
+	if !ok || !status.Reset.After(now) {
+		return 0
+	}
+
+	return status.Reset.Sub(now)
+}
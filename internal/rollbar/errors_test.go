@@ -0,0 +1,131 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestClientErrorKinds(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		status     int
+		header     string
+		body       string
+		kind       ErrorKind
+		statusCode int
+		retryAfter time.Duration
+	}{
+		{name: "not found", status: http.StatusNotFound, body: `{"err":1,"message":"Not found"}`, kind: ErrorKindHTTP, statusCode: http.StatusNotFound},
+		{name: "rate limited", status: http.StatusTooManyRequests, header: "7", body: `{}`, kind: ErrorKindRateLimited, statusCode: http.StatusTooManyRequests, retryAfter: 7 * time.Second},
+		{name: "api error", status: http.StatusOK, body: `{"err":1,"message":"bad"}`, kind: ErrorKindAPI},
+		{name: "decode error", status: http.StatusOK, body: `not-json`, kind: ErrorKindDecode},
+		{name: "missing result", status: http.StatusOK, body: `{"err":0}`, kind: ErrorKindMissingResult},
+	}
+
+	for _, tc := range tests {
+		tc := tc
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+
+			client := newStatusTestClient(t, tc.status, tc.header, tc.body)
+			_, err := client.GetItem(context.Background(), domain.ItemID(1))
+			var apiErr *Error
+			if !errors.As(err, &apiErr) {
+				t.Fatalf("expected *Error, got %T: %v", err, err)
+			}
+			if apiErr.Kind != tc.kind || apiErr.StatusCode != tc.statusCode || apiErr.RetryAfter != tc.retryAfter {
+				t.Fatalf("unexpected error: kind=%s status=%d retry=%s", apiErr.Kind, apiErr.StatusCode, apiErr.RetryAfter)
+			}
+		})
+	}
+}
+
+// newStatusTestClient answers every request with status and body, and a
+// Retry-After of retryAfter when it is not empty.
+func newStatusTestClient(t *testing.T, status int, retryAfter string, body string) *Client {
+	t.Helper()
+
+	return newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		if retryAfter != "" {
+			w.Header().Set("Retry-After", retryAfter)
+		}
+		w.WriteHeader(status)
+		_, _ = fmt.Fprint(w, body)
+	})
+}
+
+func TestErrorHelpers(t *testing.T) {
+	t.Parallel()
+
+	notFound := fmt.Errorf("get item: %w", &Error{Kind: ErrorKindHTTP, StatusCode: http.StatusNotFound})
+	if !IsNotFound(notFound) {
+		t.Fatalf("expected IsNotFound through wrapping")
+	}
+	if _, limited := IsRateLimited(notFound); limited {
+		t.Fatalf("404 should not be rate limited")
+	}
+
+	limitedErr := &Error{Kind: ErrorKindRateLimited, StatusCode: http.StatusTooManyRequests, RetryAfter: time.Second}
+	if wait, limited := IsRateLimited(limitedErr); !limited || wait != time.Second {
+		t.Fatalf("unexpected IsRateLimited: %s %v", wait, limited)
+	}
+	if IsNotFound(errors.New("plain")) {
+		t.Fatalf("plain errors are not 404s")
+	}
+}
+
+func TestErrorUnwrapsCause(t *testing.T) {
+	t.Parallel()
+
+	client, err := New("token")
+	if err != nil {
+		t.Fatalf("New() error = %v", err)
+	}
+
+	wrapped := client.fail(ErrorKindRequest, context.Canceled, "request item")
+	if !errors.Is(wrapped, context.Canceled) {
+		t.Fatalf("expected cause to be reachable")
+	}
+	if wrapped.Error() != "request item: context canceled" {
+		t.Fatalf("unexpected message: %s", wrapped.Error())
+	}
+}
+
+func TestParseRetryAfter(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2024, 1, 1, 0, 0, 0, 0, time.UTC)
+	tests := []struct {
+		value string
+		want  time.Duration
+	}{
+		{value: "", want: 0},
+		{value: "3", want: 3 * time.Second},
+		{value: "-1", want: 0},
+		{value: now.Add(5 * time.Second).Format(http.TimeFormat), want: 5 * time.Second},
+		{value: now.Add(-5 * time.Second).Format(http.TimeFormat), want: 0},
+		{value: "soon", want: 0},
+	}
+
+	for _, tc := range tests {
+		if got := parseRetryAfter(tc.value, now); got != tc.want {
+			t.Fatalf("parseRetryAfter(%q) = %s, want %s", tc.value, got, tc.want)
+		}
+	}
+}
+
+func TestErrorKindString(t *testing.T) {
+	t.Parallel()
+
+	if ErrorKindRateLimited.String() != "rate_limited" || ErrorKind(0).String() != "unknown" {
+		t.Fatalf("unexpected kind strings")
+	}
+}
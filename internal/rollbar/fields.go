@@ -0,0 +1,199 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// errFieldsFound stops a field walk once every wanted path has been read.
+var errFieldsFound = errors.New("all fields found")
+
+// GetInstanceFields fetches one occurrence but keeps only the values at
+// paths: dot-separated, relative to the occurrence, with array elements by
+// index, such as "data.request.url" or "data.body.trace_chain.0". The body is
+// decoded as it streams and the connection is dropped once every path has
+// been found, so a multi-megabyte occurrence is never held whole and is not
+// subject to the response size cap; each kept value still is. Paths that are
+// absent are missing from the result.
+func (c *Client) GetInstanceFields(ctx context.Context, instanceID domain.InstanceID, paths []string) (map[string]json.RawMessage, error) {
+	wanted := make([]string, 0, len(paths))
+	for _, path := range paths {
+		if trimmed := strings.Trim(strings.TrimSpace(path), "."); trimmed != "" {
+			wanted = append(wanted, "result."+trimmed)
+		}
+	}
+	if len(wanted) == 0 {
+		return nil, errors.New("at least one field path is required")
+	}
+
+	var found map[string]json.RawMessage
+	err := c.doStream(ctx, "/instance/"+instanceID.String(), "instance fields", func(body io.Reader) error {
+		var err error
+		found, err = extractFields(body, wanted, c.maxResponseBytes)
+		return err
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	fields := make(map[string]json.RawMessage, len(found))
+	for path, value := range found {
+		fields[strings.TrimPrefix(path, "result.")] = value
+	}
+
+	return fields, nil
+}
+
+// doStream is doOnce for a GET whose successful body is consumed as it
+// arrives rather than read into memory.
+func (c *Client) doStream(ctx context.Context, endpointPath string, op string, consume func(io.Reader) error) (err error) {
+	call := c.startCall(http.MethodGet, endpointPath, op)
+	defer func() { call.end(ctx, err) }()
+
+	req, err := c.newRequest(ctx, http.MethodGet, endpointPath, nil, "", op)
+	if err != nil {
+		return err
+	}
+
+	response, release, err := c.send(ctx, req, op)
+	if err != nil {
+		return err
+	}
+	defer release()
+
+	if response.StatusCode < http.StatusOK || response.StatusCode >= http.StatusMultipleChoices {
+		limited, _ := io.ReadAll(io.LimitReader(response.Body, 2048))
+		return c.statusError(response, limited, op)
+	}
+	if err := consume(response.Body); err != nil {
+		return c.fail(ErrorKindDecode, err, "decode "+op+" response")
+	}
+
+	return nil
+}
+
+// fieldExtractor walks a JSON document token by token, decoding only the
+// wanted values and skipping everything else. A single huge string is still
+// read whole by the decoder, but no document tree is ever built.
+type fieldExtractor struct {
+	decoder  *json.Decoder
+	wanted   map[string]bool
+	parents  map[string]bool
+	found    map[string]json.RawMessage
+	maxBytes int
+}
+
+func extractFields(body io.Reader, paths []string, maxBytes int) (map[string]json.RawMessage, error) {
+	extractor := &fieldExtractor{
+		decoder:  json.NewDecoder(body),
+		wanted:   map[string]bool{},
+		parents:  map[string]bool{"": true},
+		found:    map[string]json.RawMessage{},
+		maxBytes: maxBytes,
+	}
+	for _, path := range paths {
+		extractor.wanted[path] = true
+		for index := range path {
+			if path[index] == '.' {
+				extractor.parents[path[:index]] = true
+			}
+		}
+	}
+
+	if err := extractor.walk(""); err != nil && !errors.Is(err, errFieldsFound) {
+		return nil, err
+	}
+
+	return extractor.found, nil
+}
+
+// walk reads the value at path: kept when wanted, descended into when a
+// wanted path lies below it, and skipped otherwise.
+func (e *fieldExtractor) walk(path string) error {
+	if e.wanted[path] {
+		return e.keep(path)
+	}
+	if !e.parents[path] {
+		return e.skip()
+	}
+
+	token, err := e.decoder.Token()
+	if err != nil {
+		return fmt.Errorf("read %s: %w", displayPath(path), err)
+	}
+	delim, ok := token.(json.Delim)
+	if !ok {
+		return nil
+	}
+
+	for index := 0; e.decoder.More(); index++ {
+		child := strconv.Itoa(index)
+		if delim == '{' {
+			key, err := e.decoder.Token()
+			if err != nil {
+				return fmt.Errorf("read key in %s: %w", displayPath(path), err)
+			}
+			child, _ = key.(string)
+		}
+		if err := e.walk(strings.TrimPrefix(path+"."+child, ".")); err != nil {
+			return err
+		}
+	}
+	if _, err := e.decoder.Token(); err != nil {
+		return fmt.Errorf("read end of %s: %w", displayPath(path), err)
+	}
+
+	return nil
+}
+
+func (e *fieldExtractor) keep(path string) error {
+	var value json.RawMessage
+	if err := e.decoder.Decode(&value); err != nil {
+		return fmt.Errorf("read %s: %w", path, err)
+	}
+	if e.maxBytes > 0 && len(value) > e.maxBytes {
+		return fmt.Errorf("%s: %w (over %d bytes)", path, ErrResponseTooLarge, e.maxBytes)
+	}
+	e.found[path] = value
+	if len(e.found) == len(e.wanted) {
+		return errFieldsFound
+	}
+
+	return nil
+}
+
+// skip consumes one value of any shape.
+func (e *fieldExtractor) skip() error {
+	depth := 0
+	for {
+		token, err := e.decoder.Token()
+		if err != nil {
+			return fmt.Errorf("skip value: %w", err)
+		}
+		switch token {
+		case json.Delim('{'), json.Delim('['):
+			depth++
+		case json.Delim('}'), json.Delim(']'):
+			depth--
+		}
+		if depth == 0 {
+			return nil
+		}
+	}
+}
+
+func displayPath(path string) string {
+	if path == "" {
+		return "document"
+	}
+
+	return path
+}
@@ -0,0 +1,108 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestGetInstanceFields(t *testing.T) {
+	t.Parallel()
+
+	huge := strings.Repeat("x", maxResponseBodyBytes+1024)
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/instance/42" {
+			w.WriteHeader(http.StatusNotFound)
+			_, _ = fmt.Fprint(w, `{"err":1,"message":"not found"}`)
+			return
+		}
+		_, _ = fmt.Fprintf(w, `{"err":0,"result":{"id":42,"data":{"request":{"body":"%s","url":"https://example.com/checkout"},"body":{"trace_chain":[{"exception":{"class":"A"}},{"exception":{"class":"B"}}]}}}}`, huge)
+	})
+
+	fields, err := client.GetInstanceFields(context.Background(), 42, []string{"data.request.url", "data.body.trace_chain.1.exception", "data.missing"})
+	if err != nil {
+		t.Fatalf("GetInstanceFields() error = %v", err)
+	}
+	if string(fields["data.request.url"]) != `"https://example.com/checkout"` || string(fields["data.body.trace_chain.1.exception"]) != `{"class":"B"}` {
+		t.Fatalf("unexpected fields: %s", fields)
+	}
+	if _, ok := fields["data.missing"]; ok || len(fields) != 2 {
+		t.Fatalf("expected missing paths to be absent: %s", fields)
+	}
+
+	_, err = client.GetInstanceFields(context.Background(), 42, []string{"data.request.body"})
+	if !errors.Is(err, ErrResponseTooLarge) {
+		t.Fatalf("expected a capped field, got %v", err)
+	}
+	if _, err := client.GetInstanceFields(context.Background(), 42, []string{" ", "."}); err == nil {
+		t.Fatal("expected an error without paths")
+	}
+	if _, err := client.GetInstanceFields(context.Background(), 7, []string{"id"}); !IsNotFound(err) {
+		t.Fatalf("expected a 404, got %v", err)
+	}
+}
+
+func TestExtractFields(t *testing.T) {
+	t.Parallel()
+
+	document := `{"a":{"b":[1,{"c":true}],"d":"skip me"},"e":null}`
+	tests := []struct {
+		paths   []string
+		want    map[string]string
+		wantErr bool
+	}{
+		{paths: []string{"a.b.1.c", "e"}, want: map[string]string{"a.b.1.c": "true", "e": "null"}},
+		{paths: []string{"a.b"}, want: map[string]string{"a.b": `[1,{"c":true}]`}},
+		{paths: []string{"a.d.x", "z"}, want: map[string]string{}},
+	}
+	for _, tc := range tests {
+		got, err := extractFields(strings.NewReader(document), tc.paths, 0)
+		if err != nil {
+			t.Fatalf("%v: extractFields() error = %v", tc.paths, err)
+		}
+		if len(got) != len(tc.want) {
+			t.Fatalf("%v: got %s", tc.paths, got)
+		}
+		for path, value := range tc.want {
+			if string(got[path]) != value {
+				t.Fatalf("%v: %s = %s, want %s", tc.paths, path, got[path], value)
+			}
+		}
+	}
+
+	// The walk stops at the last wanted field, so trailing garbage is never read.
+	if _, err := extractFields(strings.NewReader(`{"a":1,"b":`), []string{"a"}, 0); err != nil {
+		t.Fatalf("expected an early stop, got %v", err)
+	}
+	if _, err := extractFields(strings.NewReader(`{"a":`), []string{"b"}, 0); err == nil {
+		t.Fatal("expected an error for truncated JSON")
+	}
+}
+
+func TestWithMaxResponseBytes(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1,"title":"a title longer than the cap"}}`)
+	})
+	client.maxResponseBytes = 32
+
+	if _, err := client.GetItem(context.Background(), domain.ItemID(1)); !errors.Is(err, ErrResponseTooLarge) {
+		t.Fatalf("expected ErrResponseTooLarge, got %v", err)
+	}
+
+	cfg := defaultClientConfig()
+	WithMaxResponseBytes(0)(&cfg)
+	if cfg.maxResponseBytes != maxResponseBodyBytes {
+		t.Fatalf("expected non-positive caps to be ignored, got %d", cfg.maxResponseBytes)
+	}
+	WithMaxResponseBytes(32)(&cfg)
+	if cfg.maxResponseBytes != 32 {
+		t.Fatalf("expected the cap to apply, got %d", cfg.maxResponseBytes)
+	}
+}
@@ -0,0 +1,86 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"iter"
+	"net/url"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+type InstancePage struct {
+	Instances []ItemInstance `json:"instances"`
+	Page      int            `json:"page"`
+}
+
+func (c *Client) ListInstances(ctx context.Context, itemID domain.ItemID, page int) (InstancePage, error) {
+	query := ""
+	if page > 0 {
+		query = "page=" + strconv.Itoa(page)
+	}
+
+	raw, err := c.getResult(ctx, withQuery("/item/"+itemID.String()+"/instances", query), "item instances")
+	if err != nil {
+		return InstancePage{}, err
+	}
+
+	instances, err := parseInstances(raw)
+	if err != nil {
+		return InstancePage{}, c.wrap(err, "decode instances response")
+	}
+
+	return InstancePage{Instances: instances, Page: page}, nil
+}
+
+// Instances walks every occurrence of an item, following pages until Rollbar
+// returns an empty page. Iteration stops at the first error.
+func (c *Client) Instances(ctx context.Context, itemID domain.ItemID) iter.Seq2[ItemInstance, error] {
+	return func(yield func(ItemInstance, error) bool) {
+		for page := 1; ; page++ {
+			result, err := c.ListInstances(ctx, itemID, page)
+			if err != nil {
+				yield(ItemInstance{}, err)
+				return
+			}
+			if len(result.Instances) == 0 {
+				return
+			}
+			for _, instance := range result.Instances {
+				if !yield(instance, nil) {
+					return
+				}
+			}
+		}
+	}
+}
+
+func (c *Client) GetInstance(ctx context.Context, instanceID domain.InstanceID) (ItemInstance, error) {
+	return c.getInstance(ctx, "/instance/"+instanceID.String(), "instance")
+}
+
+func (c *Client) GetInstanceByUUID(ctx context.Context, uuid string) (ItemInstance, error) {
+	trimmed := strings.TrimSpace(uuid)
+	if trimmed == "" {
+		return ItemInstance{}, errors.New("occurrence uuid is required")
+	}
+
+	return c.getInstance(ctx, "/instance/uuid/"+url.PathEscape(trimmed), "instance by uuid")
+}
+
+func (c *Client) getInstance(ctx context.Context, endpointPath string, op string) (ItemInstance, error) {
+	raw, err := c.getResult(ctx, endpointPath, op)
+	if err != nil {
+		return ItemInstance{}, err
+	}
+
+	var instance ItemInstance
+	if err := json.Unmarshal(raw, &instance); err != nil {
+		return ItemInstance{}, c.wrap(err, "decode "+op+" response")
+	}
+
+	return hydrateInstance(instance), nil
+}
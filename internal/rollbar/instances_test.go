@@ -0,0 +1,138 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"sync/atomic"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestListInstancesPageQuery(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/item/7/instances" || r.URL.RawQuery != "page=2" {
+			t.Fatalf("unexpected request: %s?%s", r.URL.Path, r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":10},{"id":11}],"page":2}}`)
+	})
+
+	page, err := client.ListInstances(context.Background(), domain.ItemID(7), 2)
+	if err != nil {
+		t.Fatalf("ListInstances() error = %v", err)
+	}
+	if page.Page != 2 || len(page.Instances) != 2 || page.Instances[1].ID != 11 {
+		t.Fatalf("unexpected page: %+v", page)
+	}
+}
+
+func TestInstancesFollowsPagination(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.RawQuery {
+		case "page=1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":1},{"id":2}]}}`)
+		case "page=2":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[{"id":3}]}}`)
+		default:
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"instances":[]}}`)
+		}
+	})
+
+	ids := make([]uint64, 0, 3)
+	for instance, err := range client.Instances(context.Background(), domain.ItemID(7)) {
+		if err != nil {
+			t.Fatalf("Instances() error = %v", err)
+		}
+		ids = append(ids, instance.ID)
+	}
+	if len(ids) != 3 || ids[2] != 3 {
+		t.Fatalf("unexpected instance ids: %v", ids)
+	}
+}
+
+func TestInstancesStopsEarlyAndSurfacesErrors(t *testing.T) {
+	t.Parallel()
+
+	var requests atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		requests.Add(1)
+		if r.URL.RawQuery == "page=2" {
+			_, _ = fmt.Fprint(w, `{"err":1,"message":"boom"}`)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":1},{"id":2}]}`)
+	})
+
+	for range client.Instances(context.Background(), domain.ItemID(7)) {
+		break
+	}
+	if got := requests.Load(); got != 1 {
+		t.Fatalf("expected early stop after first page, got %d requests", got)
+	}
+
+	var lastErr error
+	for _, err := range client.Instances(context.Background(), domain.ItemID(7)) {
+		lastErr = err
+	}
+	if lastErr == nil {
+		t.Fatalf("expected pagination error")
+	}
+}
+
+func TestGetInstance(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/instance/501" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":501,"timestamp":1700000000,"data":{"uuid":"abc"}}}`)
+	})
+
+	instance, err := client.GetInstance(context.Background(), domain.InstanceID(501))
+	if err != nil {
+		t.Fatalf("GetInstance() error = %v", err)
+	}
+	if instance.ID != 501 || len(instance.Raw) == 0 {
+		t.Fatalf("unexpected instance: %+v", instance)
+	}
+}
+
+func TestGetInstanceByUUID(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/instance/uuid/8a4e1c2b-0d7e-4a59-9d16-3b1a2c3d4e5f" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":502}}`)
+	})
+
+	instance, err := client.GetInstanceByUUID(context.Background(), " 8a4e1c2b-0d7e-4a59-9d16-3b1a2c3d4e5f ")
+	if err != nil {
+		t.Fatalf("GetInstanceByUUID() error = %v", err)
+	}
+	if instance.ID != 502 {
+		t.Fatalf("unexpected instance: %+v", instance)
+	}
+}
+
+func TestGetInstanceErrors(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":"not-an-instance"}`)
+	})
+
+	if _, err := client.GetInstanceByUUID(context.Background(), "  "); err == nil {
+		t.Fatalf("expected blank uuid error")
+	}
+	if _, err := client.GetInstance(context.Background(), domain.InstanceID(1)); err == nil {
+		t.Fatalf("expected decode error")
+	}
+}
@@ -0,0 +1,116 @@
+package rollbar
+
+import (
+	"errors"
+	"io"
+	"net/http"
+	"net/url"
+	"sync"
+)
+
+// Middleware wraps the round trip every request attempt takes, so callers
+// can add headers, audit traffic, or record and replay responses without
+// forking the client. Retries run above the stack, so each attempt passes
+// through it again; the built-in rate limiting is the innermost layer, so a
+// middleware that answers a request itself never waits for a throttle slot.
+type Middleware func(next http.RoundTripper) http.RoundTripper
+
+// RoundTripperFunc adapts a function to http.RoundTripper.
+type RoundTripperFunc func(*http.Request) (*http.Response, error)
+
+func (f RoundTripperFunc) RoundTrip(req *http.Request) (*http.Response, error) {
+	return f(req)
+}
+
+// Hooks builds a Middleware from callbacks for the common cases. Either may
+// be nil.
+type Hooks struct {
+	// OnRequest runs before each attempt on a copy of the request, after the
+	// access token header is set; it may add headers. An error aborts the
+	// attempt and is not retried.
+	OnRequest func(*http.Request) error
+	// OnResponse runs after each attempt with its response or transport
+	// error. It must not read the response body.
+	OnResponse func(*http.Request, *http.Response, error)
+}
+
+// Middleware returns the hooks as a Middleware.
+func (h Hooks) Middleware() Middleware {
+	return func(next http.RoundTripper) http.RoundTripper {
+		return RoundTripperFunc(func(req *http.Request) (*http.Response, error) {
+			if h.OnRequest != nil {
+				req = req.Clone(req.Context())
+				if err := h.OnRequest(req); err != nil {
+					return nil, err
+				}
+			}
+
+			response, err := next.RoundTrip(req)
+			if h.OnResponse != nil {
+				h.OnResponse(req, response, err)
+			}
+
+			return response, err //nolint:wrapcheck // A middleware passes the next layer's result through unchanged.
+		})
+	}
+}
+
+// Use appends middleware to the stack; the first one added is the outermost.
+// Call it before the client is shared.
+func (c *Client) Use(middleware ...Middleware) {
+	c.middleware = append(c.middleware, middleware...)
+}
+
+// transport assembles the stack for one attempt: the caller's middleware,
+// then rate limiting, then the HTTP client.
+func (c *Client) transport() http.RoundTripper {
+	var transport http.RoundTripper = RoundTripperFunc(c.http.Do)
+	transport = c.rateLimit(transport)
+	for index := len(c.middleware) - 1; index >= 0; index-- {
+		transport = c.middleware[index](transport)
+	}
+
+	return transport
+}
+
+// rateLimit is the built-in layer that holds a throttle slot for the life of
+// the response body and records the X-Rate-Limit-* headers.
+func (c *Client) rateLimit(next http.RoundTripper) http.RoundTripper {
+	return RoundTripperFunc(func(req *http.Request) (*http.Response, error) {
+		releaseSlot, err := c.acquireSlot(req.Context())
+		if err != nil {
+			return nil, err
+		}
+
+		response, err := next.RoundTrip(req)
+		if err != nil {
+			releaseSlot()
+			return nil, err //nolint:wrapcheck // send classifies transport errors.
+		}
+		c.limits.observe(response.Header)
+		response.Body = &releasingBody{ReadCloser: response.Body, release: releaseSlot}
+
+		return response, nil
+	})
+}
+
+// isTransportError reports whether err came from the HTTP client rather than
+// from a middleware, which is what makes it worth retrying.
+func isTransportError(err error) bool {
+	var urlErr *url.Error
+	return errors.As(err, &urlErr)
+}
+
+// releasingBody frees a throttle slot when the response body is closed.
+type releasingBody struct {
+	io.ReadCloser
+	release func()
+	once    sync.Once
+}
+
+func (b *releasingBody) Close() error {
+	err := b.ReadCloser.Close()
+	b.once.Do(b.release)
+
+	return err //nolint:wrapcheck // Close errors pass through like the body's own.
+}
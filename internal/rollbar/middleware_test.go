@@ -0,0 +1,119 @@
+package rollbar
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"slices"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestHooksSeeEveryAttempt(t *testing.T) {
+	t.Parallel()
+
+	var served atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Header.Get("X-Audit") != "on" {
+			w.WriteHeader(http.StatusBadRequest)
+			return
+		}
+		if served.Add(1) == 1 {
+			w.WriteHeader(http.StatusServiceUnavailable)
+			return
+		}
+		w.Header().Set("X-Rate-Limit-Remaining", "9")
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1,"title":"boom"}}`)
+	})
+	client.SetRetryPolicy(RetryPolicy{MaxAttempts: 2})
+	client.sleep = func(context.Context, time.Duration) error { return nil }
+
+	var statuses []int
+	client.Use(Hooks{
+		OnRequest: func(req *http.Request) error {
+			req.Header.Set("X-Audit", "on")
+			return nil
+		},
+		OnResponse: func(_ *http.Request, response *http.Response, err error) {
+			if err == nil {
+				statuses = append(statuses, response.StatusCode)
+			}
+		},
+	}.Middleware())
+
+	if item, err := client.GetItem(context.Background(), domain.ItemID(1)); err != nil || item.Title != "boom" {
+		t.Fatalf("GetItem() = %+v, %v", item, err)
+	}
+	if !slices.Equal(statuses, []int{http.StatusServiceUnavailable, http.StatusOK}) {
+		t.Fatalf("expected the hook to see both attempts, got %v", statuses)
+	}
+	if status, ok := client.RateLimitStatus(); !ok || status.Remaining != 9 {
+		t.Fatalf("expected rate limits to still be observed, got %+v, %v", status, ok)
+	}
+}
+
+func TestMiddlewareErrorsAreNotRetried(t *testing.T) {
+	t.Parallel()
+
+	var attempts atomic.Int32
+	refused := errors.New("refused by policy")
+	client := newTestClientWithHandler(t, func(http.ResponseWriter, *http.Request) {
+		t.Error("expected no request to reach the server")
+	})
+	client.SetRetryPolicy(RetryPolicy{MaxAttempts: 3})
+	client.Use(Hooks{OnRequest: func(*http.Request) error {
+		attempts.Add(1)
+		return refused
+	}}.Middleware())
+
+	_, err := client.GetItem(context.Background(), domain.ItemID(1))
+	if !errors.Is(err, refused) || attempts.Load() != 1 {
+		t.Fatalf("expected one refused attempt, got %d: %v", attempts.Load(), err)
+	}
+}
+
+func TestMiddlewareOrderAndReplay(t *testing.T) {
+	t.Parallel()
+
+	var order []string
+	trace := func(name string) Middleware {
+		return func(next http.RoundTripper) http.RoundTripper {
+			return RoundTripperFunc(func(req *http.Request) (*http.Response, error) {
+				order = append(order, name)
+				return next.RoundTrip(req)
+			})
+		}
+	}
+	replay := func(http.RoundTripper) http.RoundTripper {
+		return RoundTripperFunc(func(req *http.Request) (*http.Response, error) {
+			return &http.Response{
+				StatusCode: http.StatusOK,
+				Header:     http.Header{},
+				Body:       io.NopCloser(bytes.NewBufferString(`{"err":0,"result":{"id":3,"title":"replayed"}}`)),
+				Request:    req,
+			}, nil
+		})
+	}
+
+	// The throttle is full, so only a middleware that never reaches it can answer.
+	client, err := NewWithOptions("token", WithBaseURL("http://127.0.0.1:1"), WithThrottle(1), WithMiddleware(trace("outer"), trace("inner"), replay))
+	if err != nil {
+		t.Fatalf("NewWithOptions() error = %v", err)
+	}
+	client.limits.slots <- struct{}{}
+
+	item, err := client.GetItem(context.Background(), domain.ItemID(3))
+	if err != nil || item.Title != "replayed" {
+		t.Fatalf("GetItem() = %+v, %v", item, err)
+	}
+	if strings.Join(order, ",") != "outer,inner" {
+		t.Fatalf("unexpected middleware order %v", order)
+	}
+}
@@ -4,45 +4,95 @@ import (
 	"encoding/json"
 	"fmt"
 	"strconv"
+	"strings"
 
 	"github.com/kevinsheth/rollbaz/internal/domain"
 )
 
 type Item struct {
-	ID                      domain.ItemID   `json:"id"`
-	ProjectID               uint64          `json:"project_id"`
-	Counter                 uint64          `json:"counter"`
-	Title                   string          `json:"title"`
-	Status                  string          `json:"status"`
-	Environment             string          `json:"environment"`
-	Level                   string          `json:"level"`
-	LastOccurrenceID        *uint64         `json:"last_occurrence_id"`
-	LastOccurrenceTimestamp *uint64         `json:"last_occurrence_timestamp"`
-	Occurrences             *uint64         `json:"occurrences"`
-	TotalOccurrences        *uint64         `json:"total_occurrences"`
-	Raw                     json.RawMessage `json:"-"`
+	ID                       domain.ItemID   `json:"id"`
+	ProjectID                uint64          `json:"project_id"`
+	Counter                  uint64          `json:"counter"`
+	Title                    string          `json:"title"`
+	Status                   Status          `json:"status"`
+	Environment              string          `json:"environment"`
+	Level                    Level           `json:"level"`
+	FirstOccurrenceID        *uint64         `json:"first_occurrence_id"`
+	FirstOccurrenceTimestamp *uint64         `json:"first_occurrence_timestamp"`
+	LastOccurrenceID         *uint64         `json:"last_occurrence_id"`
+	LastOccurrenceTimestamp  *uint64         `json:"last_occurrence_timestamp"`
+	LastActivatedTimestamp   *uint64         `json:"last_activated_timestamp"`
+	LastResolvedTimestamp    *uint64         `json:"last_resolved_timestamp"`
+	Occurrences              *uint64         `json:"occurrences"`
+	TotalOccurrences         *uint64         `json:"total_occurrences"`
+	Raw                      json.RawMessage `json:"-"`
+}
+
+type ItemQuery struct {
+	Status       string
+	Levels       []string
+	Environments []string
+	AssignedUser string
+	Query        string
+	Page         int
+}
+
+type ItemPage struct {
+	Items      []Item `json:"items"`
+	Page       int    `json:"page"`
+	TotalCount uint64 `json:"total_count"`
+}
+
+func (q ItemQuery) encode() string {
+	params := make([]string, 0, 6)
+	params = appendParam(params, "status", q.Status)
+	for _, level := range q.Levels {
+		params = appendParam(params, "level", level)
+	}
+	for _, environment := range q.Environments {
+		params = appendParam(params, "environment", environment)
+	}
+	params = appendParam(params, "assigned_user", q.AssignedUser)
+	params = appendParam(params, "query", q.Query)
+	if q.Page > 0 {
+		params = append(params, "page="+strconv.Itoa(q.Page))
+	}
+
+	return strings.Join(params, "&")
 }
 
 type ItemPatch struct {
-	Status                    string `json:"status,omitempty"`
-	ResolvedInVersion         string `json:"resolved_in_version,omitempty"`
-	SnoozeEnabled             *bool  `json:"snooze_enabled,omitempty"`
-	SnoozeExpirationInSeconds *int64 `json:"snooze_expiration_in_seconds,omitempty"`
+	Status                    Status  `json:"status,omitempty"`
+	ResolvedInVersion         string  `json:"resolved_in_version,omitempty"`
+	Level                     Level   `json:"level,omitempty"`
+	Title                     string  `json:"title,omitempty"`
+	AssignedUserID            *uint64 `json:"assigned_user_id,omitempty"`
+	SnoozeEnabled             *bool   `json:"snooze_enabled,omitempty"`
+	SnoozeExpirationInSeconds *int64  `json:"snooze_expiration_in_seconds,omitempty"`
+}
+
+// unassignPatch always serializes assigned_user_id, so a nil pointer clears it.
+type unassignPatch struct {
+	AssignedUserID *uint64 `json:"assigned_user_id"`
 }
 
 func (i *Item) UnmarshalJSON(data []byte) error {
 	type itemDTO struct {
-		ID                      flexibleUint64 `json:"id"`
-		ProjectID               uint64         `json:"project_id"`
-		Counter                 uint64         `json:"counter"`
-		Title                   string         `json:"title"`
-		Status                  string         `json:"status"`
-		Environment             string         `json:"environment"`
-		Level                   flexibleLevel  `json:"level"`
-		LastOccurrenceID        *uint64        `json:"last_occurrence_id"`
-		LastOccurrenceTimestamp *uint64        `json:"last_occurrence_timestamp"`
-		Occurrences             *uint64        `json:"occurrences"`
-		TotalOccurrences        *uint64        `json:"total_occurrences"`
+		ID                       flexibleUint64 `json:"id"`
+		ProjectID                uint64         `json:"project_id"`
+		Counter                  uint64         `json:"counter"`
+		Title                    string         `json:"title"`
+		Status                   flexibleStatus `json:"status"`
+		Environment              string         `json:"environment"`
+		Level                    flexibleLevel  `json:"level"`
+		FirstOccurrenceID        *uint64        `json:"first_occurrence_id"`
+		FirstOccurrenceTimestamp *uint64        `json:"first_occurrence_timestamp"`
+		LastOccurrenceID         *uint64        `json:"last_occurrence_id"`
+		LastOccurrenceTimestamp  *uint64        `json:"last_occurrence_timestamp"`
+		LastActivatedTimestamp   *uint64        `json:"last_activated_timestamp"`
+		LastResolvedTimestamp    *uint64        `json:"last_resolved_timestamp"`
+		Occurrences              *uint64        `json:"occurrences"`
+		TotalOccurrences         *uint64        `json:"total_occurrences"`
 	}
 
 	var dto itemDTO
@@ -54,11 +104,15 @@ func (i *Item) UnmarshalJSON(data []byte) error {
 	i.ProjectID = dto.ProjectID
 	i.Counter = dto.Counter
 	i.Title = dto.Title
-	i.Status = dto.Status
+	i.Status = Status(dto.Status)
 	i.Environment = dto.Environment
-	i.Level = string(dto.Level)
+	i.Level = Level(dto.Level)
+	i.FirstOccurrenceID = dto.FirstOccurrenceID
+	i.FirstOccurrenceTimestamp = dto.FirstOccurrenceTimestamp
 	i.LastOccurrenceID = dto.LastOccurrenceID
 	i.LastOccurrenceTimestamp = dto.LastOccurrenceTimestamp
+	i.LastActivatedTimestamp = dto.LastActivatedTimestamp
+	i.LastResolvedTimestamp = dto.LastResolvedTimestamp
 	i.Occurrences = dto.Occurrences
 	i.TotalOccurrences = dto.TotalOccurrences
 
@@ -98,14 +152,79 @@ type itemsEnvelope struct {
 	Items []Item `json:"items"`
 }
 
-type topActiveItem struct {
-	Item Item `json:"item"`
+type itemPageMeta struct {
+	Page       int    `json:"page"`
+	TotalCount uint64 `json:"total_count"`
+}
+
+type TopActiveItem struct {
+	Item   Item     `json:"item"`
+	Counts []uint64 `json:"counts"`
+}
+
+type TopActiveItemsParams struct {
+	Hours        int
+	Environments []string
+}
+
+func (p TopActiveItemsParams) encode() string {
+	params := make([]string, 0, 2)
+	if p.Hours > 0 {
+		params = append(params, "hours="+strconv.Itoa(p.Hours))
+	}
+
+	environments := make([]string, 0, len(p.Environments))
+	for _, environment := range p.Environments {
+		if trimmed := strings.TrimSpace(environment); trimmed != "" {
+			environments = append(environments, trimmed)
+		}
+	}
+	params = appendParam(params, "environments", strings.Join(environments, ","))
+
+	return strings.Join(params, "&")
 }
 
 type flexibleUint64 uint64
 
 type flexibleLevel string
 
+// flexibleStatus accepts the numeric statuses webhook payloads carry as well
+// as the API's names.
+type flexibleStatus string
+
+func (v *flexibleStatus) UnmarshalJSON(data []byte) error {
+	if len(data) == 0 || string(data) == "null" {
+		*v = ""
+		return nil
+	}
+
+	var statusString string
+	if err := json.Unmarshal(data, &statusString); err == nil {
+		*v = flexibleStatus(statusString)
+		return nil
+	}
+
+	var statusNumber int
+	if err := json.Unmarshal(data, &statusNumber); err != nil {
+		return fmt.Errorf("decode status: %w", err)
+	}
+
+	switch statusNumber {
+	case 1:
+		*v = flexibleStatus(StatusActive)
+	case 2:
+		*v = flexibleStatus(StatusResolved)
+	case 3:
+		*v = flexibleStatus(StatusMuted)
+	case 4:
+		*v = flexibleStatus(StatusArchived)
+	default:
+		*v = flexibleStatus(strconv.Itoa(statusNumber))
+	}
+
+	return nil
+}
+
 func (v *flexibleLevel) UnmarshalJSON(data []byte) error {
 	if len(data) == 0 || string(data) == "null" {
 		*v = ""
@@ -45,6 +45,28 @@ func TestFlexibleUnmarshalJSON(t *testing.T) {
 	}
 }
 
+func TestFlexibleStatusUnmarshalJSON(t *testing.T) {
+	t.Parallel()
+
+	statusCases := map[string]struct {
+		input string
+		want  flexibleStatus
+	}{
+		"numeric status":         {input: `2`, want: "resolved"},
+		"string status":          {input: `"muted"`, want: "muted"},
+		"unknown numeric status": {input: `9`, want: "9"},
+	}
+	for name, tc := range statusCases {
+		var status flexibleStatus
+		if err := json.Unmarshal([]byte(tc.input), &status); err != nil {
+			t.Fatalf("%s: unmarshal error = %v", name, err)
+		}
+		if status != tc.want {
+			t.Fatalf("%s: status = %q, want %q", name, status, tc.want)
+		}
+	}
+}
+
 func TestFlexibleUint64UnmarshalJSONInvalid(t *testing.T) {
 	t.Parallel()
 
@@ -0,0 +1,100 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+	"sync"
+)
+
+// MultiProjectClient holds one Client per Rollbar project, since access
+// tokens are scoped to a single project.
+type MultiProjectClient struct {
+	clients map[string]*Client
+	names   []string
+}
+
+// ProjectItem is an item tagged with the project it came from.
+type ProjectItem struct {
+	Project string `json:"project"`
+	Item    Item   `json:"item"`
+}
+
+// NewMultiProjectClient builds a client per project from a project name to
+// access token map. Options apply to every underlying client. Names are
+// trimmed, so two keys that differ only in surrounding spaces are an error.
+func NewMultiProjectClient(tokens map[string]string, options ...Option) (*MultiProjectClient, error) {
+	if len(tokens) == 0 {
+		return nil, errors.New("at least one project token is required")
+	}
+
+	multi := &MultiProjectClient{clients: make(map[string]*Client, len(tokens))}
+	for name, token := range tokens {
+		trimmed := strings.TrimSpace(name)
+		if trimmed == "" {
+			return nil, errors.New("project name is required")
+		}
+		if _, ok := multi.clients[trimmed]; ok {
+			return nil, fmt.Errorf("project %s is configured twice", trimmed)
+		}
+
+		client, err := NewWithOptions(token, options...)
+		if err != nil {
+			return nil, fmt.Errorf("project %s: %w", trimmed, err)
+		}
+		multi.clients[trimmed] = client
+		multi.names = append(multi.names, trimmed)
+	}
+	sort.Strings(multi.names)
+
+	return multi, nil
+}
+
+// Projects returns the configured project names in sorted order.
+func (m *MultiProjectClient) Projects() []string {
+	return append([]string(nil), m.names...)
+}
+
+// Project returns the client that holds the token for the named project.
+func (m *MultiProjectClient) Project(name string) (*Client, error) {
+	client, ok := m.clients[strings.TrimSpace(name)]
+	if !ok {
+		return nil, fmt.Errorf("unknown project %q", name)
+	}
+
+	return client, nil
+}
+
+// SearchItems runs the same item query against every project concurrently.
+// Results are grouped by project in name order. When some projects fail, the
+// items from the others are still returned alongside the joined errors.
+func (m *MultiProjectClient) SearchItems(ctx context.Context, query ItemQuery) ([]ProjectItem, error) {
+	pages := make([]ItemPage, len(m.names))
+	errs := make([]error, len(m.names))
+
+	var wg sync.WaitGroup
+	for index, name := range m.names {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			page, err := m.clients[name].ListItemsPage(ctx, query)
+			if err != nil {
+				errs[index] = fmt.Errorf("project %s: %w", name, err)
+				return
+			}
+			pages[index] = page
+		}()
+	}
+	wg.Wait()
+
+	var results []ProjectItem
+	for index, page := range pages {
+		for _, item := range page.Items {
+			results = append(results, ProjectItem{Project: m.names[index], Item: item})
+		}
+	}
+
+	return results, errors.Join(errs...)
+}
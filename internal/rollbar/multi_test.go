@@ -0,0 +1,96 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+func TestMultiProjectClientSearchItems(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(projectSearchHandler(t)))
+	t.Cleanup(server.Close)
+
+	multi, err := NewMultiProjectClient(map[string]string{
+		"web":    "web-token",
+		"api":    "api-token",
+		"broken": "bad-token",
+	}, WithBaseURL(server.URL), WithRetryPolicy(RetryPolicy{MaxAttempts: 1}))
+	if err != nil {
+		t.Fatalf("NewMultiProjectClient() error = %v", err)
+	}
+
+	results, err := multi.SearchItems(context.Background(), ItemQuery{Query: "timeout"})
+	var apiErr *Error
+	if !errors.As(err, &apiErr) || apiErr.StatusCode != http.StatusUnauthorized || !strings.Contains(err.Error(), "project broken") {
+		t.Fatalf("expected the broken project error, got %v", err)
+	}
+	if len(results) != 3 || results[0].Project != "api" || results[1].Project != "web" || results[2].Item.ID != 3 {
+		t.Fatalf("unexpected results: %+v", results)
+	}
+}
+
+// projectSearchHandler answers a "timeout" search with one item for
+// api-token, two for web-token, and 401 for any other token.
+func projectSearchHandler(t *testing.T) func(http.ResponseWriter, *http.Request) {
+	t.Helper()
+
+	return func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Query().Get("query") != "timeout" {
+			t.Fatalf("unexpected query: %s", r.URL.RawQuery)
+		}
+		switch r.Header.Get("X-Rollbar-Access-Token") {
+		case "api-token":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":1,"counter":10,"title":"api timeout"}]}}`)
+		case "web-token":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":2,"counter":20,"title":"web timeout"},{"id":3,"counter":21,"title":"cdn timeout"}]}}`)
+		default:
+			w.WriteHeader(http.StatusUnauthorized)
+		}
+	}
+}
+
+func TestMultiProjectClientLookup(t *testing.T) {
+	t.Parallel()
+
+	multi, err := NewMultiProjectClient(map[string]string{"b": "token-b", " a ": "token-a"})
+	if err != nil {
+		t.Fatalf("NewMultiProjectClient() error = %v", err)
+	}
+
+	if names := multi.Projects(); len(names) != 2 || names[0] != "a" || names[1] != "b" {
+		t.Fatalf("unexpected projects: %v", names)
+	}
+	client, err := multi.Project("a")
+	if err != nil || client.accessToken != "token-a" {
+		t.Fatalf("unexpected client for a: %v", err)
+	}
+	if _, err := multi.Project("missing"); err == nil {
+		t.Fatalf("expected unknown project error")
+	}
+}
+
+func TestNewMultiProjectClientValidation(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name   string
+		tokens map[string]string
+	}{
+		{name: "empty", tokens: map[string]string{}},
+		{name: "blank name", tokens: map[string]string{" ": "token"}},
+		{name: "blank token", tokens: map[string]string{"api": " "}},
+		{name: "duplicate trimmed name", tokens: map[string]string{"api": "token-a", "api ": "token-b"}},
+	}
+
+	for _, tc := range tests {
+		if _, err := NewMultiProjectClient(tc.tokens); err == nil {
+			t.Fatalf("%s: expected validation error", tc.name)
+		}
+	}
+}
@@ -0,0 +1,102 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+)
+
+// Notification channels with rule endpoints.
+const (
+	NotificationSlack     = "slack"
+	NotificationEmail     = "email"
+	NotificationPagerDuty = "pagerduty"
+	NotificationWebhook   = "webhook"
+)
+
+// NotificationChannels lists the channels in the order Rollbar's settings
+// page shows them.
+var NotificationChannels = []string{NotificationEmail, NotificationSlack, NotificationPagerDuty, NotificationWebhook}
+
+// NotificationFilter narrows when a rule fires: most compare a field such as
+// environment, level, or title with Operation (eq, neq, gte, lte, within,
+// nwithin, regex, nregex) and Value; rate filters count Count occurrences in
+// Period seconds instead.
+type NotificationFilter struct {
+	Type      string `json:"type"`
+	Operation string `json:"operation,omitempty"`
+	Value     string `json:"value,omitempty"`
+	Period    int    `json:"period,omitempty"`
+	Count     int    `json:"count,omitempty"`
+}
+
+// NotificationRule sends Trigger events (new_item, occurrence, reactivated_item,
+// exp_repeat_item, item_velocity, deploy, ...) that pass every filter to its
+// channel. Config is channel-specific, such as the Slack channel or email
+// recipients, so it is kept as Rollbar returns it.
+type NotificationRule struct {
+	ID      uint64               `json:"id,omitempty"`
+	Trigger string               `json:"trigger"`
+	Filters []NotificationFilter `json:"filters"`
+	Config  map[string]any       `json:"config,omitempty"`
+}
+
+func (c *Client) ListNotificationRules(ctx context.Context, channel string) ([]NotificationRule, error) {
+	raw, err := c.getResult(ctx, notificationsPath(channel)+"/rules", channel+" notification rules")
+	if err != nil {
+		return nil, err
+	}
+
+	return c.decodeNotificationRules(raw, "decode notification rules response")
+}
+
+// CreateNotificationRules adds rules to a channel, keeping the ones there,
+// and returns them as created.
+func (c *Client) CreateNotificationRules(ctx context.Context, channel string, rules []NotificationRule) ([]NotificationRule, error) {
+	if len(rules) == 0 {
+		return nil, errors.New("at least one notification rule is required")
+	}
+	rules = append([]NotificationRule(nil), rules...)
+	for index := range rules {
+		rules[index].ID = 0
+		if strings.TrimSpace(rules[index].Trigger) == "" {
+			return nil, errors.New("notification rule trigger is required")
+		}
+		if rules[index].Filters == nil {
+			rules[index].Filters = []NotificationFilter{}
+		}
+	}
+
+	raw, err := c.writeResult(ctx, http.MethodPost, notificationsPath(channel)+"/rules", rules, "create "+channel+" notification rules")
+	if err != nil {
+		return nil, err
+	}
+
+	return c.decodeNotificationRules(raw, "decode create notification rules response")
+}
+
+func (c *Client) DeleteNotificationRule(ctx context.Context, channel string, ruleID uint64) error {
+	_, err := c.writeResult(ctx, http.MethodDelete, notificationsPath(channel)+"/rule/"+strconv.FormatUint(ruleID, 10), nil, "delete "+channel+" notification rule")
+
+	return err
+}
+
+func (c *Client) decodeNotificationRules(raw json.RawMessage, op string) ([]NotificationRule, error) {
+	rules := []NotificationRule{}
+	if len(raw) == 0 || string(raw) == "null" {
+		return rules, nil
+	}
+	if err := json.Unmarshal(raw, &rules); err != nil {
+		return nil, c.wrap(err, op)
+	}
+
+	return rules, nil
+}
+
+func notificationsPath(channel string) string {
+	return "/notifications/" + url.PathEscape(strings.TrimSpace(channel))
+}
@@ -0,0 +1,85 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/http"
+	"testing"
+)
+
+func TestListNotificationRules(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodGet || r.URL.Path != "/notifications/slack/rules" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":5,"trigger":"new_item","filters":[{"type":"environment","operation":"eq","value":"production"},{"type":"rate","period":300,"count":10}],"config":{"channel":"#alerts"}}]}`)
+	})
+
+	rules, err := client.ListNotificationRules(context.Background(), "slack")
+	if err != nil {
+		t.Fatalf("ListNotificationRules() error = %v", err)
+	}
+	if len(rules) != 1 || rules[0].ID != 5 || rules[0].Filters[0].Value != "production" || rules[0].Filters[1].Period != 300 || rules[0].Config["channel"] != "#alerts" {
+		t.Fatalf("unexpected rules: %+v", rules)
+	}
+}
+
+func TestCreateNotificationRules(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/notifications/email/rules" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		if string(body) != `[{"trigger":"deploy","filters":[]}]` {
+			t.Fatalf("unexpected body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":8,"trigger":"deploy","filters":[]}]}`)
+	})
+
+	requested := []NotificationRule{{ID: 3, Trigger: "deploy"}}
+	rules, err := client.CreateNotificationRules(context.Background(), "email", requested)
+	if err != nil || len(rules) != 1 || rules[0].ID != 8 {
+		t.Fatalf("CreateNotificationRules() = %+v, %v", rules, err)
+	}
+	if requested[0].ID != 3 {
+		t.Fatalf("expected the caller's rules untouched, got %+v", requested)
+	}
+}
+
+func TestCreateNotificationRulesInvalid(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+	})
+
+	if _, err := client.CreateNotificationRules(context.Background(), "email", nil); err == nil {
+		t.Fatal("expected an error without rules")
+	}
+	if _, err := client.CreateNotificationRules(context.Background(), "email", []NotificationRule{{}}); err == nil {
+		t.Fatal("expected an error without a trigger")
+	}
+}
+
+func TestDeleteNotificationRule(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodDelete || r.URL.Path != "/notifications/pagerduty/rule/12" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	})
+
+	if err := client.DeleteNotificationRule(context.Background(), "pagerduty", 12); err != nil {
+		t.Fatalf("DeleteNotificationRule() error = %v", err)
+	}
+}
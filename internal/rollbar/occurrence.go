@@ -0,0 +1,143 @@
+package rollbar
+
+import (
+	"encoding/json"
+)
+
+type BodyKind string
+
+const (
+	BodyKindTrace       BodyKind = "trace"
+	BodyKindTraceChain  BodyKind = "trace_chain"
+	BodyKindMessage     BodyKind = "message"
+	BodyKindCrashReport BodyKind = "crash_report"
+	BodyKindUnknown     BodyKind = "unknown"
+)
+
+// OccurrenceBody is the typed form of an occurrence's body. Exactly one of
+// Trace, TraceChain, Message, or CrashReport is set according to Kind; Raw
+// always holds the original JSON so nothing Rollbar sent is lost.
+type OccurrenceBody struct {
+	Kind        BodyKind        `json:"kind"`
+	Trace       *Trace          `json:"trace,omitempty"`
+	TraceChain  []Trace         `json:"trace_chain,omitempty"`
+	Message     *Message        `json:"message,omitempty"`
+	CrashReport *CrashReport    `json:"crash_report,omitempty"`
+	Raw         json.RawMessage `json:"-"`
+}
+
+type Trace struct {
+	Frames    []Frame   `json:"frames"`
+	Exception Exception `json:"exception"`
+}
+
+type Exception struct {
+	Class       string `json:"class"`
+	Message     string `json:"message"`
+	Description string `json:"description,omitempty"`
+}
+
+type Frame struct {
+	Filename string        `json:"filename"`
+	Lineno   int           `json:"lineno,omitempty"`
+	Colno    int           `json:"colno,omitempty"`
+	Method   string        `json:"method,omitempty"`
+	Code     string        `json:"code,omitempty"`
+	Context  *FrameContext `json:"context,omitempty"`
+	// ClassName is the class JVM SDKs send apart from Method.
+	ClassName string `json:"class_name,omitempty"`
+}
+
+// FrameContext holds the source lines around Frame.Code when the SDK sent them.
+type FrameContext struct {
+	Pre  []string `json:"pre,omitempty"`
+	Post []string `json:"post,omitempty"`
+}
+
+type Message struct {
+	Body string `json:"body"`
+}
+
+type CrashReport struct {
+	Raw string `json:"raw"`
+}
+
+type occurrenceBodyWire struct {
+	Trace       *Trace       `json:"trace"`
+	TraceChain  []Trace      `json:"trace_chain"`
+	Message     *Message     `json:"message"`
+	CrashReport *CrashReport `json:"crash_report"`
+}
+
+type instanceDataBody struct {
+	Body json.RawMessage `json:"body"`
+}
+
+// ParseOccurrenceBody decodes an occurrence body. Shapes it does not
+// recognize, including malformed JSON, come back as BodyKindUnknown with Raw
+// set rather than as an error.
+func ParseOccurrenceBody(raw json.RawMessage) OccurrenceBody {
+	body := OccurrenceBody{Kind: BodyKindUnknown, Raw: append(json.RawMessage(nil), raw...)}
+
+	var wire occurrenceBodyWire
+	if err := json.Unmarshal(raw, &wire); err != nil {
+		return body
+	}
+
+	switch {
+	case wire.Trace != nil:
+		body.Kind, body.Trace = BodyKindTrace, wire.Trace
+	case len(wire.TraceChain) > 0:
+		body.Kind, body.TraceChain = BodyKindTraceChain, wire.TraceChain
+	case wire.Message != nil:
+		body.Kind, body.Message = BodyKindMessage, wire.Message
+	case wire.CrashReport != nil:
+		body.Kind, body.CrashReport = BodyKindCrashReport, wire.CrashReport
+	}
+
+	return body
+}
+
+// OccurrenceBody parses the instance body, falling back to data.body for
+// instances that only carry the full occurrence payload.
+func (i ItemInstance) OccurrenceBody() OccurrenceBody {
+	return ParseOccurrenceBody(i.bodyJSON())
+}
+
+func (i ItemInstance) bodyJSON() json.RawMessage {
+	if len(i.Body) > 0 && string(i.Body) != "null" {
+		return i.Body
+	}
+
+	var data instanceDataBody
+	if err := json.Unmarshal(i.Data, &data); err == nil && len(data.Body) > 0 {
+		return data.Body
+	}
+
+	return i.Body
+}
+
+// Exception returns the outermost exception for trace and trace_chain bodies.
+func (b OccurrenceBody) Exception() (Exception, bool) {
+	switch b.Kind {
+	case BodyKindTrace:
+		return b.Trace.Exception, true
+	case BodyKindTraceChain:
+		return b.TraceChain[0].Exception, true
+	default:
+		return Exception{}, false
+	}
+}
+
+// Frames returns the outermost trace's frames, oldest call first, for trace
+// and trace_chain bodies.
+func (b OccurrenceBody) Frames() []Frame {
+	switch b.Kind {
+	case BodyKindTrace:
+		return b.Trace.Frames
+	case BodyKindTraceChain:
+		return b.TraceChain[0].Frames
+	default:
+		return nil
+	}
+}
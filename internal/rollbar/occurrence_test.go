@@ -0,0 +1,85 @@
+package rollbar
+
+import (
+	"encoding/json"
+	"testing"
+)
+
+func TestParseOccurrenceBody(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name      string
+		raw       string
+		kind      BodyKind
+		exception string
+	}{
+		{name: "trace", raw: `{"trace":{"frames":[{"filename":"app.go","lineno":12,"method":"main"}],"exception":{"class":"PanicError","message":"boom"}}}`, kind: BodyKindTrace, exception: "PanicError"},
+		{name: "trace chain", raw: `{"trace_chain":[{"frames":[],"exception":{"class":"Outer","message":"wrapped"}},{"frames":[],"exception":{"class":"Inner","message":"cause"}}]}`, kind: BodyKindTraceChain, exception: "Outer"},
+		{name: "message", raw: `{"message":{"body":"hello","extra":1}}`, kind: BodyKindMessage},
+		{name: "crash report", raw: `{"crash_report":{"raw":"Thread 0 Crashed"}}`, kind: BodyKindCrashReport},
+		{name: "unknown shape", raw: `{"telemetry":[]}`, kind: BodyKindUnknown},
+		{name: "malformed", raw: `{"trace":"nope"}`, kind: BodyKindUnknown},
+	}
+
+	for _, tc := range tests {
+		tc := tc
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+
+			body := ParseOccurrenceBody(json.RawMessage(tc.raw))
+			if body.Kind != tc.kind {
+				t.Fatalf("Kind = %s, want %s", body.Kind, tc.kind)
+			}
+			if string(body.Raw) != tc.raw {
+				t.Fatalf("raw not preserved: %s", body.Raw)
+			}
+			exception, ok := body.Exception()
+			if ok != (tc.exception != "") || exception.Class != tc.exception {
+				t.Fatalf("Exception() = %+v, %v", exception, ok)
+			}
+		})
+	}
+}
+
+func TestParseOccurrenceBodyFields(t *testing.T) {
+	t.Parallel()
+
+	body := ParseOccurrenceBody(json.RawMessage(`{"trace":{"frames":[{"filename":"app.go","lineno":12,"colno":3,"method":"main","code":"panic(err)"}],"exception":{"class":"E","message":"m","description":"d"}}}`))
+	want := Frame{Filename: "app.go", Lineno: 12, Colno: 3, Method: "main", Code: "panic(err)"}
+	if len(body.Trace.Frames) != 1 || body.Trace.Frames[0] != want {
+		t.Fatalf("unexpected frames: %+v", body.Trace.Frames)
+	}
+	if frames := body.Frames(); len(frames) != 1 || frames[0] != want {
+		t.Fatalf("Frames() = %+v", frames)
+	}
+	if body.Trace.Exception.Description != "d" {
+		t.Fatalf("unexpected exception: %+v", body.Trace.Exception)
+	}
+
+	message := ParseOccurrenceBody(json.RawMessage(`{"message":{"body":"hello"}}`))
+	if message.Message.Body != "hello" || message.Frames() != nil {
+		t.Fatalf("unexpected message: %+v", message.Message)
+	}
+}
+
+func TestItemInstanceOccurrenceBody(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		instance ItemInstance
+		kind     BodyKind
+	}{
+		{name: "body field", instance: ItemInstance{Body: json.RawMessage(`{"message":{"body":"x"}}`)}, kind: BodyKindMessage},
+		{name: "data body", instance: ItemInstance{Data: json.RawMessage(`{"body":{"crash_report":{"raw":"x"}}}`)}, kind: BodyKindCrashReport},
+		{name: "null body uses data", instance: ItemInstance{Body: json.RawMessage(`null`), Data: json.RawMessage(`{"body":{"message":{"body":"x"}}}`)}, kind: BodyKindMessage},
+		{name: "nothing", instance: ItemInstance{}, kind: BodyKindUnknown},
+	}
+
+	for _, tc := range tests {
+		if got := tc.instance.OccurrenceBody().Kind; got != tc.kind {
+			t.Fatalf("%s: Kind = %s, want %s", tc.name, got, tc.kind)
+		}
+	}
+}
@@ -0,0 +1,143 @@
+package rollbar
+
+import (
+	"crypto/tls"
+	"crypto/x509"
+	"log/slog"
+	"net/url"
+	"time"
+)
+
+const (
+	defaultConnectTimeout = 3 * time.Second
+	defaultRequestTimeout = 8 * time.Second
+)
+
+// Option customizes a Client built by NewWithOptions.
+type Option func(*clientConfig)
+
+type clientConfig struct {
+	baseURL          string
+	connectTimeout   time.Duration
+	requestTimeout   time.Duration
+	proxy            *url.URL
+	rootCAs          *x509.CertPool
+	userAgent        string
+	retry            RetryPolicy
+	maxConcurrent    int
+	cache            *ResponseCache
+	maxResponseBytes int
+	middleware       []Middleware
+	logger           *slog.Logger
+}
+
+func defaultClientConfig() clientConfig {
+	return clientConfig{
+		baseURL:          defaultBaseURL,
+		connectTimeout:   defaultConnectTimeout,
+		requestTimeout:   defaultRequestTimeout,
+		retry:            DefaultRetryPolicy(),
+		maxResponseBytes: maxResponseBodyBytes,
+	}
+}
+
+// WithBaseURL points the client at an on-prem or enterprise Rollbar API, for
+// example https://rollbar.example.com/api/1.
+func WithBaseURL(baseURL string) Option {
+	return func(cfg *clientConfig) {
+		cfg.baseURL = baseURL
+	}
+}
+
+// WithConnectTimeout bounds dialing a connection. Non-positive values are ignored.
+func WithConnectTimeout(timeout time.Duration) Option {
+	return func(cfg *clientConfig) {
+		if timeout > 0 {
+			cfg.connectTimeout = timeout
+		}
+	}
+}
+
+// WithRequestTimeout bounds each request attempt end to end. Non-positive
+// values are ignored.
+func WithRequestTimeout(timeout time.Duration) Option {
+	return func(cfg *clientConfig) {
+		if timeout > 0 {
+			cfg.requestTimeout = timeout
+		}
+	}
+}
+
+// WithProxy routes requests through an HTTP(S) proxy instead of the one taken
+// from HTTPS_PROXY/NO_PROXY.
+func WithProxy(proxy *url.URL) Option {
+	return func(cfg *clientConfig) {
+		cfg.proxy = proxy
+	}
+}
+
+// WithRootCAs replaces the system trust store, for installs behind a private CA.
+func WithRootCAs(pool *x509.CertPool) Option {
+	return func(cfg *clientConfig) {
+		cfg.rootCAs = pool
+	}
+}
+
+func WithUserAgent(userAgent string) Option {
+	return func(cfg *clientConfig) {
+		cfg.userAgent = userAgent
+	}
+}
+
+func WithRetryPolicy(policy RetryPolicy) Option {
+	return func(cfg *clientConfig) {
+		cfg.retry = policy
+	}
+}
+
+// WithThrottle enables client-side throttling; see Client.SetThrottle.
+func WithThrottle(maxConcurrent int) Option {
+	return func(cfg *clientConfig) {
+		cfg.maxConcurrent = maxConcurrent
+	}
+}
+
+// WithResponseCache enables conditional GET requests; see Client.SetResponseCache.
+func WithResponseCache(cache *ResponseCache) Option {
+	return func(cfg *clientConfig) {
+		cfg.cache = cache
+	}
+}
+
+// WithMaxResponseBytes caps how much of a response is read, so a tool that
+// only needs small payloads fails fast with ErrResponseTooLarge instead of
+// buffering a multi-megabyte occurrence. Non-positive values are ignored.
+func WithMaxResponseBytes(maxBytes int) Option {
+	return func(cfg *clientConfig) {
+		if maxBytes > 0 {
+			cfg.maxResponseBytes = maxBytes
+		}
+	}
+}
+
+// WithMiddleware adds middleware to every request; see Client.Use.
+func WithMiddleware(middleware ...Middleware) Option {
+	return func(cfg *clientConfig) {
+		cfg.middleware = append(cfg.middleware, middleware...)
+	}
+}
+
+// WithLogger traces every API call on logger; see Client.SetLogger.
+func WithLogger(logger *slog.Logger) Option {
+	return func(cfg *clientConfig) {
+		cfg.logger = logger
+	}
+}
+
+func (cfg clientConfig) tlsConfig() *tls.Config {
+	if cfg.rootCAs == nil {
+		return nil
+	}
+
+	return &tls.Config{MinVersion: tls.VersionTLS12, RootCAs: cfg.rootCAs}
+}
@@ -0,0 +1,102 @@
+package rollbar
+
+import (
+	"context"
+	"crypto/x509"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestNewWithOptionsAppliesSettings(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/api/1/item/1/" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		if r.Header.Get("User-Agent") != "rollbaz-test/1.0" {
+			w.WriteHeader(http.StatusBadRequest)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1}}`)
+	}))
+	t.Cleanup(server.Close)
+
+	retry := RetryPolicy{MaxAttempts: 1}
+	client, err := NewWithOptions("token",
+		WithBaseURL(server.URL+"/api/1"),
+		WithUserAgent("rollbaz-test/1.0"),
+		WithRequestTimeout(2*time.Second),
+		WithConnectTimeout(time.Second),
+		WithRetryPolicy(retry),
+		WithThrottle(4),
+	)
+	if err != nil {
+		t.Fatalf("NewWithOptions() error = %v", err)
+	}
+
+	if _, err := client.GetItem(context.Background(), domain.ItemID(1)); err != nil {
+		t.Fatalf("GetItem() error = %v", err)
+	}
+	if client.http.Timeout != 2*time.Second || client.retry != retry || cap(client.limits.slots) != 4 {
+		t.Fatalf("options not applied: timeout=%s retry=%+v slots=%d", client.http.Timeout, client.retry, cap(client.limits.slots))
+	}
+}
+
+func TestNewWithOptionsTransport(t *testing.T) {
+	t.Parallel()
+
+	proxyURL, err := url.Parse("http://proxy.internal:3128")
+	if err != nil {
+		t.Fatalf("parse proxy: %v", err)
+	}
+	pool := x509.NewCertPool()
+
+	client, err := NewWithOptions("token", WithProxy(proxyURL), WithRootCAs(pool), WithRequestTimeout(0))
+	if err != nil {
+		t.Fatalf("NewWithOptions() error = %v", err)
+	}
+
+	transport, ok := client.http.Transport.(*http.Transport)
+	if !ok {
+		t.Fatalf("unexpected transport %T", client.http.Transport)
+	}
+	request := httptest.NewRequest(http.MethodGet, "https://api.rollbar.com/api/1/items", nil)
+	proxied, err := transport.Proxy(request)
+	if err != nil || proxied.String() != proxyURL.String() {
+		t.Fatalf("unexpected proxy: %v %v", proxied, err)
+	}
+	if transport.TLSClientConfig == nil || transport.TLSClientConfig.RootCAs != pool {
+		t.Fatalf("root CAs not applied")
+	}
+	if client.http.Timeout != defaultRequestTimeout || client.baseURL != defaultBaseURL {
+		t.Fatalf("defaults not kept: timeout=%s base=%s", client.http.Timeout, client.baseURL)
+	}
+}
+
+func TestNewWithOptionsRequiresToken(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewWithOptions(" ", WithBaseURL("https://example.com")); err == nil {
+		t.Fatalf("expected token validation error")
+	}
+}
+
+func TestDefaultTransportHasNoTLSOverride(t *testing.T) {
+	t.Parallel()
+
+	client, err := New("token")
+	if err != nil {
+		t.Fatalf("New() error = %v", err)
+	}
+	transport, ok := client.http.Transport.(*http.Transport)
+	if !ok || transport.TLSClientConfig != nil {
+		t.Fatalf("default client should use the system TLS config")
+	}
+}
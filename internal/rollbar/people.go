@@ -0,0 +1,124 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"strconv"
+	"strings"
+)
+
+// ProjectPerson is a user of the monitored application that Rollbar tracks
+// from the person field of occurrences, not a Rollbar user. ID is Rollbar's;
+// PersonID is the id the application reported.
+type ProjectPerson struct {
+	ID        uint64 `json:"id"`
+	ProjectID uint64 `json:"project_id,omitempty"`
+	PersonID  string `json:"person_id"`
+	Username  string `json:"username,omitempty"`
+	Email     string `json:"email,omitempty"`
+}
+
+// UnmarshalJSON accepts person ids reported as numbers as well as strings.
+func (p *ProjectPerson) UnmarshalJSON(data []byte) error {
+	type plain ProjectPerson
+	var decoded struct {
+		plain
+		PersonID json.RawMessage `json:"person_id"`
+	}
+	if err := json.Unmarshal(data, &decoded); err != nil {
+		return fmt.Errorf("decode person: %w", err)
+	}
+
+	*p = ProjectPerson(decoded.plain)
+	var text string
+	if err := json.Unmarshal(decoded.PersonID, &text); err != nil {
+		text = strings.TrimSpace(string(decoded.PersonID))
+	}
+	if text != "null" {
+		p.PersonID = text
+	}
+
+	return nil
+}
+
+// PeopleQuery filters GET /people by the id, email, or username occurrences
+// reported; an empty query lists every person.
+type PeopleQuery struct {
+	PersonID string
+	Email    string
+	Username string
+	Page     int
+}
+
+func (q PeopleQuery) encode() string {
+	params := appendParam(nil, "person_id", q.PersonID)
+	params = appendParam(params, "email", q.Email)
+	params = appendParam(params, "username", q.Username)
+	if q.Page > 0 {
+		params = append(params, "page="+strconv.Itoa(q.Page))
+	}
+
+	return strings.Join(params, "&")
+}
+
+type peopleEnvelope struct {
+	People []ProjectPerson `json:"people"`
+}
+
+// ListPeople returns one page of the people matching query.
+func (c *Client) ListPeople(ctx context.Context, query PeopleQuery) ([]ProjectPerson, error) {
+	raw, err := c.getResult(ctx, withQuery("/people", query.encode()), "people")
+	if err != nil {
+		return nil, err
+	}
+
+	people := []ProjectPerson{}
+	if len(raw) == 0 || string(raw) == "null" {
+		return people, nil
+	}
+	if err := json.Unmarshal(raw, &people); err == nil {
+		return people, nil
+	}
+	var wrapped peopleEnvelope
+	if err := json.Unmarshal(raw, &wrapped); err != nil {
+		return nil, c.wrap(err, "decode people response")
+	}
+	if wrapped.People == nil {
+		return []ProjectPerson{}, nil
+	}
+
+	return wrapped.People, nil
+}
+
+// GetPerson returns a person by Rollbar's id for them.
+func (c *Client) GetPerson(ctx context.Context, personID uint64) (ProjectPerson, error) {
+	var person ProjectPerson
+	err := c.getInto(ctx, personPath(personID), "person", &person)
+
+	return person, err
+}
+
+// ListPersonItems returns one page of the items whose occurrences reported
+// the person.
+func (c *Client) ListPersonItems(ctx context.Context, personID uint64, page int) (ItemPage, error) {
+	query := ""
+	if page > 0 {
+		query = "page=" + strconv.Itoa(page)
+	}
+	raw, err := c.getResult(ctx, withQuery(personPath(personID)+"/items", query), "person items")
+	if err != nil {
+		return ItemPage{}, err
+	}
+
+	result, err := parseItemPage(raw, page)
+	if err != nil {
+		return ItemPage{}, c.wrap(err, "decode person items response")
+	}
+
+	return result, nil
+}
+
+func personPath(personID uint64) string {
+	return "/person/" + strconv.FormatUint(personID, 10)
+}
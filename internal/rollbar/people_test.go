@@ -0,0 +1,94 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"reflect"
+	"testing"
+)
+
+func TestListPeople(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name      string
+		query     PeopleQuery
+		wantQuery string
+		body      string
+		want      []ProjectPerson
+	}{
+		{
+			name:      "list",
+			query:     PeopleQuery{Email: " ann@example.com "},
+			wantQuery: "email=ann%40example.com",
+			body:      `{"err":0,"result":[{"id":5,"project_id":9,"person_id":"u1","username":"ann","email":"ann@example.com"}]}`,
+			want:      []ProjectPerson{{ID: 5, ProjectID: 9, PersonID: "u1", Username: "ann", Email: "ann@example.com"}},
+		},
+		{
+			name:      "wrapped numeric ids",
+			query:     PeopleQuery{PersonID: "42", Username: "bo", Page: 2},
+			wantQuery: "person_id=42&username=bo&page=2",
+			body:      `{"err":0,"result":{"people":[{"id":6,"person_id":42},{"id":7,"person_id":null}]}}`,
+			want:      []ProjectPerson{{ID: 6, PersonID: "42"}, {ID: 7}},
+		},
+		{name: "none", body: `{"err":0,"result":null}`, want: []ProjectPerson{}},
+		{name: "empty envelope", body: `{"err":0,"result":{}}`, want: []ProjectPerson{}},
+	}
+	for _, tc := range tests {
+		client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+			if r.URL.Path != "/people" || r.URL.RawQuery != tc.wantQuery {
+				t.Fatalf("%s: unexpected request: %s?%s", tc.name, r.URL.Path, r.URL.RawQuery)
+			}
+			_, _ = fmt.Fprint(w, tc.body)
+		})
+
+		people, err := client.ListPeople(context.Background(), tc.query)
+		if err != nil || !reflect.DeepEqual(people, tc.want) {
+			t.Fatalf("%s: ListPeople() = %+v, %v", tc.name, people, err)
+		}
+	}
+}
+
+func TestListPeopleDecodeError(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":"nope"}`)
+	})
+	if _, err := client.ListPeople(context.Background(), PeopleQuery{}); err == nil {
+		t.Fatal("expected a decode error")
+	}
+}
+
+func TestGetPerson(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/person/5" {
+			t.Fatalf("unexpected request: %s", r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":5,"person_id":"u1","email":"ann@example.com"}}`)
+	})
+
+	person, err := client.GetPerson(context.Background(), 5)
+	if err != nil || person != (ProjectPerson{ID: 5, PersonID: "u1", Email: "ann@example.com"}) {
+		t.Fatalf("GetPerson() = %+v, %v", person, err)
+	}
+}
+
+func TestListPersonItems(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/person/5/items" || r.URL.RawQuery != "page=2" {
+			t.Fatalf("unexpected request: %s?%s", r.URL.Path, r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"items":[{"id":11,"counter":3,"title":"boom","status":"active"}],"page":2,"total_count":21}}`)
+	})
+
+	page, err := client.ListPersonItems(context.Background(), 5, 2)
+	if err != nil || len(page.Items) != 1 || page.Items[0].Counter != 3 || page.Page != 2 || page.TotalCount != 21 {
+		t.Fatalf("ListPersonItems() = %+v, %v", page, err)
+	}
+}
@@ -0,0 +1,177 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"net/http"
+	"net/url"
+	"strconv"
+	"strings"
+)
+
+// Project and token administration endpoints require an account access token
+// rather than a project token.
+
+type Project struct {
+	ID           uint64  `json:"id"`
+	AccountID    uint64  `json:"account_id"`
+	Name         string  `json:"name"`
+	Status       string  `json:"status"`
+	DateCreated  *uint64 `json:"date_created"`
+	DateModified *uint64 `json:"date_modified"`
+}
+
+type ProjectAccessToken struct {
+	ProjectID            uint64   `json:"project_id"`
+	AccessToken          string   `json:"access_token"`
+	Name                 string   `json:"name"`
+	Status               string   `json:"status"`
+	Scopes               []string `json:"scopes"`
+	RateLimitWindowSize  *uint64  `json:"rate_limit_window_size"`
+	RateLimitWindowCount *uint64  `json:"rate_limit_window_count"`
+	DateCreated          *uint64  `json:"date_created"`
+	DateModified         *uint64  `json:"date_modified"`
+}
+
+// AccessTokenRequest creates a project access token. The rate limit allows
+// RateLimitWindowCount calls per RateLimitWindowSize seconds; both unset
+// leaves the token unlimited.
+type AccessTokenRequest struct {
+	Name                 string   `json:"name"`
+	Scopes               []string `json:"scopes"`
+	Status               string   `json:"status,omitempty"`
+	RateLimitWindowSize  *uint64  `json:"rate_limit_window_size,omitempty"`
+	RateLimitWindowCount *uint64  `json:"rate_limit_window_count,omitempty"`
+}
+
+// TokenRateLimit is a project access token's rate limit: WindowCount calls per
+// WindowSize seconds.
+type TokenRateLimit struct {
+	WindowSize  uint64 `json:"rate_limit_window_size"`
+	WindowCount uint64 `json:"rate_limit_window_count"`
+}
+
+type accessTokenStatus struct {
+	Status string `json:"status"`
+}
+
+type createProjectRequest struct {
+	Name string `json:"name"`
+}
+
+func (c *Client) ListProjects(ctx context.Context) ([]Project, error) {
+	raw, err := c.getResult(ctx, "/projects", "projects")
+	if err != nil {
+		return nil, err
+	}
+
+	var projects []Project
+	if err := json.Unmarshal(raw, &projects); err != nil {
+		return nil, c.wrap(err, "decode projects response")
+	}
+
+	return projects, nil
+}
+
+func (c *Client) GetProject(ctx context.Context, projectID uint64) (Project, error) {
+	raw, err := c.getResult(ctx, projectPath(projectID), "project")
+	if err != nil {
+		return Project{}, err
+	}
+
+	return c.decodeProject(raw)
+}
+
+func (c *Client) CreateProject(ctx context.Context, name string) (Project, error) {
+	trimmed := strings.TrimSpace(name)
+	if trimmed == "" {
+		return Project{}, errors.New("project name is required")
+	}
+
+	raw, err := c.writeResult(ctx, http.MethodPost, "/projects", createProjectRequest{Name: trimmed}, "create project")
+	if err != nil {
+		return Project{}, err
+	}
+
+	return c.decodeProject(raw)
+}
+
+func (c *Client) DeleteProject(ctx context.Context, projectID uint64) error {
+	_, err := c.writeResult(ctx, http.MethodDelete, projectPath(projectID), nil, "delete project")
+
+	return err
+}
+
+func (c *Client) ListProjectAccessTokens(ctx context.Context, projectID uint64) ([]ProjectAccessToken, error) {
+	raw, err := c.getResult(ctx, projectPath(projectID)+"/access_tokens", "project access tokens")
+	if err != nil {
+		return nil, err
+	}
+
+	var tokens []ProjectAccessToken
+	if err := json.Unmarshal(raw, &tokens); err != nil {
+		return nil, c.wrap(err, "decode project access tokens response")
+	}
+
+	return tokens, nil
+}
+
+func (c *Client) CreateProjectAccessToken(ctx context.Context, projectID uint64, request AccessTokenRequest) (ProjectAccessToken, error) {
+	if strings.TrimSpace(request.Name) == "" {
+		return ProjectAccessToken{}, errors.New("access token name is required")
+	}
+	if len(request.Scopes) == 0 {
+		return ProjectAccessToken{}, errors.New("access token scopes are required")
+	}
+
+	raw, err := c.writeResult(ctx, http.MethodPost, projectPath(projectID)+"/access_tokens", request, "create project access token")
+	if err != nil {
+		return ProjectAccessToken{}, err
+	}
+
+	var token ProjectAccessToken
+	if err := json.Unmarshal(raw, &token); err != nil {
+		return ProjectAccessToken{}, c.wrap(err, "decode create project access token response")
+	}
+
+	return token, nil
+}
+
+// UpdateProjectAccessTokenRateLimit changes a token's rate limit. Rollbar
+// addresses the token by its value, so callers redact it from errors.
+func (c *Client) UpdateProjectAccessTokenRateLimit(ctx context.Context, projectID uint64, accessToken string, limit TokenRateLimit) error {
+	if strings.TrimSpace(accessToken) == "" {
+		return errors.New("access token is required")
+	}
+
+	_, err := c.writeResult(ctx, http.MethodPatch, projectPath(projectID)+"/access_token/"+url.PathEscape(accessToken), limit, "update project access token rate limit")
+
+	return err
+}
+
+// DisableProjectAccessToken revokes a token by setting its status to
+// disabled. Like UpdateProjectAccessTokenRateLimit, it addresses the token by
+// its value.
+func (c *Client) DisableProjectAccessToken(ctx context.Context, projectID uint64, accessToken string) error {
+	if strings.TrimSpace(accessToken) == "" {
+		return errors.New("access token is required")
+	}
+
+	_, err := c.writeResult(ctx, http.MethodPatch, projectPath(projectID)+"/access_token/"+url.PathEscape(accessToken), accessTokenStatus{Status: "disabled"}, "disable project access token")
+
+	return err
+}
+
+func (c *Client) decodeProject(raw json.RawMessage) (Project, error) {
+	var project Project
+	if err := json.Unmarshal(raw, &project); err != nil {
+		return Project{}, c.wrap(err, "decode project response")
+	}
+
+	return project, nil
+}
+
+func projectPath(projectID uint64) string {
+	return "/project/" + strconv.FormatUint(projectID, 10)
+}
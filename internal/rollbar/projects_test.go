@@ -0,0 +1,186 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/http"
+	"testing"
+)
+
+func TestListAndGetProjects(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/projects":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":[{"id":1,"account_id":2,"name":"billing-api","status":"enabled"}]}`)
+		case "/project/1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1,"account_id":2,"name":"billing-api","status":"enabled"}}`)
+		default:
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+	})
+
+	projects, err := client.ListProjects(context.Background())
+	if err != nil {
+		t.Fatalf("ListProjects() error = %v", err)
+	}
+	if len(projects) != 1 || projects[0].Name != "billing-api" {
+		t.Fatalf("unexpected projects: %+v", projects)
+	}
+
+	project, err := client.GetProject(context.Background(), 1)
+	if err != nil {
+		t.Fatalf("GetProject() error = %v", err)
+	}
+	if project.AccountID != 2 {
+		t.Fatalf("unexpected project: %+v", project)
+	}
+}
+
+func TestCreateProject(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/projects" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		if string(body) != `{"name":"new-service"}` {
+			t.Fatalf("unexpected body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":3,"name":"new-service"}}`)
+	})
+
+	project, err := client.CreateProject(context.Background(), " new-service ")
+	if err != nil {
+		t.Fatalf("CreateProject() error = %v", err)
+	}
+	if project.ID != 3 {
+		t.Fatalf("unexpected project: %+v", project)
+	}
+	if _, err := client.CreateProject(context.Background(), ""); err == nil {
+		t.Fatalf("expected blank name error")
+	}
+}
+
+func TestDeleteProject(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodDelete || r.URL.Path != "/project/3" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		if r.Header.Get("Content-Type") != "" {
+			t.Fatalf("unexpected content type on delete")
+		}
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	})
+
+	if err := client.DeleteProject(context.Background(), 3); err != nil {
+		t.Fatalf("DeleteProject() error = %v", err)
+	}
+}
+
+func TestListProjectAccessTokens(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/project/1/access_tokens" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"project_id":1,"access_token":"abc","name":"read","status":"enabled","scopes":["read"]}]}`)
+	})
+
+	tokens, err := client.ListProjectAccessTokens(context.Background(), 1)
+	if err != nil {
+		t.Fatalf("ListProjectAccessTokens() error = %v", err)
+	}
+	if len(tokens) != 1 || tokens[0].Scopes[0] != "read" {
+		t.Fatalf("unexpected tokens: %+v", tokens)
+	}
+}
+
+func TestCreateProjectAccessToken(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/project/1/access_tokens" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		if string(body) != `{"name":"ci","scopes":["post_server_item"],"rate_limit_window_size":60,"rate_limit_window_count":500}` {
+			t.Fatalf("unexpected body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"project_id":1,"access_token":"new-token","name":"ci","status":"enabled","scopes":["post_server_item"]}}`)
+	})
+
+	size, count := uint64(60), uint64(500)
+	token, err := client.CreateProjectAccessToken(context.Background(), 1, AccessTokenRequest{Name: "ci", Scopes: []string{"post_server_item"}, RateLimitWindowSize: &size, RateLimitWindowCount: &count})
+	if err != nil || token.AccessToken != "new-token" {
+		t.Fatalf("CreateProjectAccessToken() = %+v, %v", token, err)
+	}
+	if _, err := client.CreateProjectAccessToken(context.Background(), 1, AccessTokenRequest{Scopes: []string{"read"}}); err == nil {
+		t.Fatal("expected an error without a name")
+	}
+	if _, err := client.CreateProjectAccessToken(context.Background(), 1, AccessTokenRequest{Name: "ci"}); err == nil {
+		t.Fatal("expected an error without scopes")
+	}
+}
+
+func TestUpdateProjectAccessTokenRateLimit(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPatch || r.URL.Path != "/project/1/access_token/abc123" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		if string(body) != `{"rate_limit_window_size":60,"rate_limit_window_count":1000}` {
+			t.Fatalf("unexpected body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	})
+
+	if err := client.UpdateProjectAccessTokenRateLimit(context.Background(), 1, "abc123", TokenRateLimit{WindowSize: 60, WindowCount: 1000}); err != nil {
+		t.Fatalf("UpdateProjectAccessTokenRateLimit() error = %v", err)
+	}
+	if err := client.UpdateProjectAccessTokenRateLimit(context.Background(), 1, " ", TokenRateLimit{}); err == nil {
+		t.Fatal("expected an error without a token")
+	}
+}
+
+func TestDisableProjectAccessToken(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPatch || r.URL.Path != "/project/1/access_token/abc123" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		if string(body) != `{"status":"disabled"}` {
+			t.Fatalf("unexpected body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	})
+
+	if err := client.DisableProjectAccessToken(context.Background(), 1, "abc123"); err != nil {
+		t.Fatalf("DisableProjectAccessToken() error = %v", err)
+	}
+	if err := client.DisableProjectAccessToken(context.Background(), 1, ""); err == nil {
+		t.Fatal("expected an error without a token")
+	}
+}
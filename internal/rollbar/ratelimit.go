@@ -0,0 +1,115 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+)
+
+// RateLimit is the most recent rate-limit window Rollbar reported through the
+// X-Rate-Limit-* response headers.
+type RateLimit struct {
+	Limit     int       `json:"limit"`
+	Remaining int       `json:"remaining"`
+	Reset     time.Time `json:"reset"`
+}
+
+type rateLimiter struct {
+	mu     sync.Mutex
+	status RateLimit
+	known  bool
+	slots  chan struct{}
+}
+
+// RateLimitStatus returns the last observed rate-limit window. The boolean is
+// false until a response carrying the headers has been seen.
+func (c *Client) RateLimitStatus() (RateLimit, bool) {
+	c.limits.mu.Lock()
+	defer c.limits.mu.Unlock()
+
+	return c.limits.status, c.limits.known
+}
+
+// SetThrottle caps the number of in-flight requests at maxConcurrent and makes
+// new requests wait for the window to reset once Rollbar reports none remain.
+// Zero or less turns throttling off. Call it before the client is shared.
+func (c *Client) SetThrottle(maxConcurrent int) {
+	if maxConcurrent <= 0 {
+		c.limits.slots = nil
+		return
+	}
+
+	c.limits.slots = make(chan struct{}, maxConcurrent)
+}
+
+func (c *Client) acquireSlot(ctx context.Context) (func(), error) {
+	if c.limits.slots == nil {
+		return func() {}, nil
+	}
+
+	if wait := c.limits.untilReset(time.Now()); wait > 0 {
+		if err := c.sleep(ctx, wait); err != nil {
+			return nil, err
+		}
+	}
+
+	select {
+	case c.limits.slots <- struct{}{}:
+		return func() { <-c.limits.slots }, nil
+	case <-ctx.Done():
+		return nil, fmt.Errorf("wait for request slot: %w", ctx.Err())
+	}
+}
+
+func (l *rateLimiter) untilReset(now time.Time) time.Duration {
+	l.mu.Lock()
+	defer l.mu.Unlock()
+
+	if !l.known || l.status.Remaining > 0 || !l.status.Reset.After(now) {
+		return 0
+	}
+
+	return l.status.Reset.Sub(now)
+}
+
+func (l *rateLimiter) observe(header http.Header) {
+	status, ok := parseRateLimit(header)
+	if !ok {
+		return
+	}
+
+	l.mu.Lock()
+	defer l.mu.Unlock()
+	l.status = status
+	l.known = true
+}
+
+func parseRateLimit(header http.Header) (RateLimit, bool) {
+	remaining, ok := headerInt(header, "X-Rate-Limit-Remaining")
+	if !ok {
+		return RateLimit{}, false
+	}
+
+	status := RateLimit{Remaining: remaining}
+	if limit, ok := headerInt(header, "X-Rate-Limit-Limit"); ok {
+		status.Limit = limit
+	}
+	if reset, ok := headerInt(header, "X-Rate-Limit-Reset"); ok {
+		status.Reset = time.Unix(int64(reset), 0).UTC()
+	}
+
+	return status, true
+}
+
+func headerInt(header http.Header, key string) (int, bool) {
+	value, err := strconv.Atoi(strings.TrimSpace(header.Get(key)))
+	if err != nil {
+		return 0, false
+	}
+
+	return value, true
+}
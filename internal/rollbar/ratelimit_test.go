@@ -0,0 +1,153 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"strconv"
+	"sync"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestRateLimitStatusFromHeaders(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		w.Header().Set("X-Rate-Limit-Limit", "5000")
+		w.Header().Set("X-Rate-Limit-Remaining", "4998")
+		w.Header().Set("X-Rate-Limit-Reset", "1700000060")
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1}}`)
+	})
+
+	if _, known := client.RateLimitStatus(); known {
+		t.Fatalf("status should be unknown before any request")
+	}
+	if _, err := client.GetItem(context.Background(), domain.ItemID(1)); err != nil {
+		t.Fatalf("GetItem() error = %v", err)
+	}
+
+	status, known := client.RateLimitStatus()
+	want := RateLimit{Limit: 5000, Remaining: 4998, Reset: time.Unix(1700000060, 0).UTC()}
+	if !known || status != want {
+		t.Fatalf("unexpected status: %+v known=%v", status, known)
+	}
+}
+
+func TestParseRateLimit(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name   string
+		header http.Header
+		want   RateLimit
+		ok     bool
+	}{
+		{name: "missing", header: http.Header{}, ok: false},
+		{name: "remaining only", header: http.Header{"X-Rate-Limit-Remaining": {"3"}}, want: RateLimit{Remaining: 3}, ok: true},
+		{name: "invalid remaining", header: http.Header{"X-Rate-Limit-Remaining": {"many"}}, ok: false},
+	}
+
+	for _, tc := range tests {
+		got, ok := parseRateLimit(tc.header)
+		if ok != tc.ok || got != tc.want {
+			t.Fatalf("%s: parseRateLimit() = %+v, %v", tc.name, got, ok)
+		}
+	}
+}
+
+func TestThrottleWaitsForResetWhenExhausted(t *testing.T) {
+	t.Parallel()
+
+	reset := time.Now().Add(time.Hour).Unix()
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		w.Header().Set("X-Rate-Limit-Remaining", "0")
+		w.Header().Set("X-Rate-Limit-Reset", strconv.FormatInt(reset, 10))
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1}}`)
+	})
+	client.SetThrottle(2)
+
+	var slept []time.Duration
+	client.sleep = func(_ context.Context, delay time.Duration) error {
+		slept = append(slept, delay)
+		return nil
+	}
+
+	for range 2 {
+		if _, err := client.GetItem(context.Background(), domain.ItemID(1)); err != nil {
+			t.Fatalf("GetItem() error = %v", err)
+		}
+	}
+	if len(slept) != 1 || slept[0] <= 59*time.Minute {
+		t.Fatalf("expected one wait close to an hour, got %v", slept)
+	}
+}
+
+func TestThrottleCapsConcurrency(t *testing.T) {
+	t.Parallel()
+
+	var inFlight, peak atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		current := inFlight.Add(1)
+		defer inFlight.Add(-1)
+		for {
+			seen := peak.Load()
+			if current <= seen || peak.CompareAndSwap(seen, current) {
+				break
+			}
+		}
+		time.Sleep(10 * time.Millisecond)
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1}}`)
+	})
+	client.SetThrottle(2)
+
+	var wg sync.WaitGroup
+	for range 6 {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			_, _ = client.GetItem(context.Background(), domain.ItemID(1))
+		}()
+	}
+	wg.Wait()
+
+	if peak.Load() > 2 {
+		t.Fatalf("peak concurrency = %d, want <= 2", peak.Load())
+	}
+}
+
+func TestThrottleRespectsContext(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClient(t, "http://127.0.0.1:1")
+	client.SetThrottle(1)
+	client.limits.slots <- struct{}{}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+
+	if _, err := client.GetItem(ctx, domain.ItemID(1)); err == nil {
+		t.Fatalf("expected context error while waiting for a slot")
+	}
+
+	client.SetThrottle(0)
+	if client.limits.slots != nil {
+		t.Fatalf("SetThrottle(0) should disable throttling")
+	}
+}
+
+func TestRateLimitedErrorFallsBackToReset(t *testing.T) {
+	t.Parallel()
+
+	now := time.Unix(1700000000, 0)
+	header := http.Header{"X-Rate-Limit-Remaining": {"0"}, "X-Rate-Limit-Reset": {"1700000030"}}
+	if got := untilRateLimitReset(header, now); got != 30*time.Second {
+		t.Fatalf("untilRateLimitReset() = %s", got)
+	}
+	if got := untilRateLimitReset(http.Header{}, now); got != 0 {
+		t.Fatalf("expected zero without headers, got %s", got)
+	}
+}
@@ -0,0 +1,21 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+)
+
+// GetRaw calls an endpoint the client does not wrap yet, relative to the base
+// URL (for example "/item/1/instances?page=2"), and returns the envelope
+// result. Authentication, retries, and error typing are the same as for the
+// typed methods.
+func (c *Client) GetRaw(ctx context.Context, endpointPath string) (json.RawMessage, error) {
+	return c.getResult(ctx, endpointPath, "GET "+endpointPath)
+}
+
+// PostRaw sends body as JSON to an unwrapped endpoint and returns the envelope
+// result. A nil body sends no request body.
+func (c *Client) PostRaw(ctx context.Context, endpointPath string, body any) (json.RawMessage, error) {
+	return c.writeResult(ctx, http.MethodPost, endpointPath, body, "POST "+endpointPath)
+}
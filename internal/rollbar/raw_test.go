@@ -0,0 +1,63 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"testing"
+)
+
+func TestGetRaw(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/people" || r.URL.RawQuery != "page=2" {
+			t.Fatalf("unexpected request: %s?%s", r.URL.Path, r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"people":[1,2]}}`)
+	})
+
+	raw, err := client.GetRaw(context.Background(), "/people?page=2")
+	if err != nil {
+		t.Fatalf("GetRaw() error = %v", err)
+	}
+	if string(raw) != `{"people":[1,2]}` {
+		t.Fatalf("unexpected raw result: %s", raw)
+	}
+}
+
+func TestPostRaw(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+		if r.Method != http.MethodPost || string(body) != `{"name":"x"}` {
+			t.Fatalf("unexpected request: %s %s", r.Method, body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":9}}`)
+	})
+
+	raw, err := client.PostRaw(context.Background(), "/things", map[string]string{"name": "x"})
+	if err != nil {
+		t.Fatalf("PostRaw() error = %v", err)
+	}
+	if string(raw) != `{"id":9}` {
+		t.Fatalf("unexpected raw result: %s", raw)
+	}
+}
+
+func TestGetRawReturnsTypedErrors(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		w.WriteHeader(http.StatusNotFound)
+	})
+
+	_, err := client.GetRaw(context.Background(), "/missing")
+	var apiErr *Error
+	if !errors.As(err, &apiErr) || !IsNotFound(err) || apiErr.Op != "GET /missing returned non-success status" {
+		t.Fatalf("unexpected error: %v", err)
+	}
+}
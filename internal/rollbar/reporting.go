@@ -0,0 +1,154 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"net/http"
+	"strings"
+	"time"
+)
+
+const notifierName = "rollbaz"
+
+// ReportData is the "data" object of an occurrence sent to POST /item/. Body
+// must carry exactly one of a trace, a trace chain, or a message.
+type ReportData struct {
+	Environment string     `json:"environment"`
+	Body        ReportBody `json:"body"`
+	Level       Level      `json:"level,omitempty"`
+	Timestamp   int64      `json:"timestamp,omitempty"`
+	CodeVersion string     `json:"code_version,omitempty"`
+	Platform    string     `json:"platform,omitempty"`
+	Language    string     `json:"language,omitempty"`
+	Framework   string     `json:"framework,omitempty"`
+	Title       string     `json:"title,omitempty"`
+	Fingerprint string     `json:"fingerprint,omitempty"`
+	UUID        string     `json:"uuid,omitempty"`
+	Custom      any        `json:"custom,omitempty"`
+	Person      *Person    `json:"person,omitempty"`
+	Request     *Request   `json:"request,omitempty"`
+	Server      *Server    `json:"server,omitempty"`
+	Notifier    Notifier   `json:"notifier"`
+}
+
+// Person identifies the affected user. ID is required by Rollbar.
+type Person struct {
+	ID       string `json:"id"`
+	Username string `json:"username,omitempty"`
+	Email    string `json:"email,omitempty"`
+}
+
+type Request struct {
+	URL         string              `json:"url,omitempty"`
+	Method      string              `json:"method,omitempty"`
+	Headers     map[string]string   `json:"headers,omitempty"`
+	Params      map[string]string   `json:"params,omitempty"`
+	Query       map[string][]string `json:"GET,omitempty"`
+	QueryString string              `json:"query_string,omitempty"`
+	UserIP      string              `json:"user_ip,omitempty"`
+}
+
+type Server struct {
+	Host        string `json:"host,omitempty"`
+	Root        string `json:"root,omitempty"`
+	Branch      string `json:"branch,omitempty"`
+	CodeVersion string `json:"code_version,omitempty"`
+}
+
+type ReportBody struct {
+	Trace      *Trace   `json:"trace,omitempty"`
+	TraceChain []Trace  `json:"trace_chain,omitempty"`
+	Message    *Message `json:"message,omitempty"`
+}
+
+type Notifier struct {
+	Name    string `json:"name"`
+	Version string `json:"version,omitempty"`
+}
+
+type reportPayload struct {
+	Data ReportData `json:"data"`
+}
+
+type reportResult struct {
+	UUID string `json:"uuid"`
+}
+
+// NewMessageReport starts an occurrence that carries a plain log message.
+func NewMessageReport(environment string, level Level, message string) ReportData {
+	return newReport(environment, level, ReportBody{Message: &Message{Body: message}})
+}
+
+// NewTraceReport starts an occurrence that carries an exception and its frames,
+// oldest frame first.
+func NewTraceReport(environment string, level Level, trace Trace) ReportData {
+	if trace.Frames == nil {
+		trace.Frames = []Frame{}
+	}
+
+	return newReport(environment, level, ReportBody{Trace: &trace})
+}
+
+func newReport(environment string, level Level, body ReportBody) ReportData {
+	return ReportData{
+		Environment: environment,
+		Body:        body,
+		Level:       level,
+		Timestamp:   time.Now().Unix(),
+		Platform:    "go",
+		Language:    "go",
+		Notifier:    Notifier{Name: notifierName},
+	}
+}
+
+func (d ReportData) validate() error {
+	if strings.TrimSpace(d.Environment) == "" {
+		return errors.New("report environment is required")
+	}
+	if d.Person != nil && strings.TrimSpace(d.Person.ID) == "" {
+		return errors.New("report person id is required")
+	}
+
+	bodies := 0
+	if d.Body.Trace != nil {
+		bodies++
+	}
+	if len(d.Body.TraceChain) > 0 {
+		bodies++
+	}
+	if d.Body.Message != nil {
+		bodies++
+	}
+	if bodies != 1 {
+		return errors.New("report body needs exactly one of trace, trace_chain, or message")
+	}
+
+	return nil
+}
+
+// ReportItem sends one occurrence and returns the UUID Rollbar assigned to it.
+// The client's token needs the post_server_item scope.
+func (c *Client) ReportItem(ctx context.Context, data ReportData) (string, error) {
+	if err := data.validate(); err != nil {
+		return "", err
+	}
+	if data.Notifier.Name == "" {
+		data.Notifier.Name = notifierName
+	}
+
+	raw, err := c.writeResult(ctx, http.MethodPost, "/item/", reportPayload{Data: data}, "report item")
+	if err != nil {
+		return "", err
+	}
+
+	var result reportResult
+	if err := json.Unmarshal(raw, &result); err != nil {
+		return "", c.wrap(err, "decode report item response")
+	}
+	if result.UUID == "" {
+		return "", c.fail(ErrorKindMissingResult, errors.New("missing uuid"), "report item response")
+	}
+
+	return result.UUID, nil
+}
@@ -0,0 +1,113 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"testing"
+)
+
+func TestReportItem(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/item/" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		checkReportedItem(t, r)
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":null,"uuid":"d4c7acef-55bf-4f43-9a6f-2a1c7e1c9f61"}}`)
+	})
+
+	report := NewMessageReport("production", LevelError, "disk full")
+	report.CodeVersion = "abc123"
+	report.Custom = map[string]int{"shard": 3}
+
+	uuid, err := client.ReportItem(context.Background(), report)
+	if err != nil {
+		t.Fatalf("ReportItem() error = %v", err)
+	}
+	if uuid != "d4c7acef-55bf-4f43-9a6f-2a1c7e1c9f61" {
+		t.Fatalf("unexpected uuid: %s", uuid)
+	}
+}
+
+// checkReportedItem checks the payload TestReportItem posts.
+func checkReportedItem(t *testing.T, r *http.Request) {
+	t.Helper()
+
+	var payload struct {
+		Data struct {
+			Environment string          `json:"environment"`
+			Level       string          `json:"level"`
+			CodeVersion string          `json:"code_version"`
+			Custom      json.RawMessage `json:"custom"`
+			Body        json.RawMessage `json:"body"`
+			Notifier    Notifier        `json:"notifier"`
+		} `json:"data"`
+	}
+	if err := json.NewDecoder(r.Body).Decode(&payload); err != nil {
+		t.Fatalf("decode payload: %v", err)
+	}
+	data := payload.Data
+	if data.Environment != "production" || data.Level != "error" || data.CodeVersion != "abc123" || data.Notifier.Name != "rollbaz" {
+		t.Fatalf("unexpected data: %+v", data)
+	}
+	if string(data.Body) != `{"message":{"body":"disk full"}}` || string(data.Custom) != `{"shard":3}` {
+		t.Fatalf("unexpected body/custom: %s %s", data.Body, data.Custom)
+	}
+}
+
+func TestReportItemValidation(t *testing.T) {
+	t.Parallel()
+
+	trace := NewTraceReport("production", LevelCritical, Trace{Exception: Exception{Class: "E"}})
+	both := trace
+	both.Body.Message = &Message{Body: "x"}
+
+	tests := []struct {
+		name string
+		data ReportData
+	}{
+		{name: "missing environment", data: NewMessageReport(" ", LevelError, "x")},
+		{name: "missing body", data: ReportData{Environment: "production"}},
+		{name: "two bodies", data: both},
+	}
+
+	client := newTestClient(t, "http://127.0.0.1:1")
+	for _, tc := range tests {
+		if _, err := client.ReportItem(context.Background(), tc.data); err == nil {
+			t.Fatalf("%s: expected validation error", tc.name)
+		}
+	}
+	if err := trace.validate(); err != nil {
+		t.Fatalf("trace report should be valid: %v", err)
+	}
+}
+
+func TestReportItemMissingUUID(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":null}}`)
+	})
+
+	if _, err := client.ReportItem(context.Background(), NewMessageReport("production", LevelInfo, "x")); err == nil {
+		t.Fatalf("expected missing uuid error")
+	}
+}
+
+func TestReportItemRequiresPersonID(t *testing.T) {
+	t.Parallel()
+
+	data := NewMessageReport("production", LevelError, "x")
+	data.Person = &Person{Email: "a@example.com"}
+	if err := data.validate(); err == nil {
+		t.Fatalf("expected person id validation error")
+	}
+
+	data.Person.ID = "7"
+	if err := data.validate(); err != nil {
+		t.Fatalf("validate() error = %v", err)
+	}
+}
@@ -0,0 +1,75 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+type CountBucket struct {
+	Timestamp uint64 `json:"timestamp"`
+	Count     uint64 `json:"count"`
+}
+
+type CountReportParams struct {
+	BucketSize  int
+	Environment string
+	ItemID      domain.ItemID
+}
+
+func (b *CountBucket) UnmarshalJSON(data []byte) error {
+	var pair []uint64
+	if err := json.Unmarshal(data, &pair); err == nil {
+		if len(pair) != 2 {
+			return fmt.Errorf("count bucket has %d values, want 2", len(pair))
+		}
+		b.Timestamp = pair[0]
+		b.Count = pair[1]
+		return nil
+	}
+
+	type bucketDTO CountBucket
+	var dto bucketDTO
+	if err := json.Unmarshal(data, &dto); err != nil {
+		return fmt.Errorf("decode count bucket: %w", err)
+	}
+	*b = CountBucket(dto)
+
+	return nil
+}
+
+func (p CountReportParams) encode() string {
+	params := make([]string, 0, 3)
+	if p.BucketSize > 0 {
+		params = append(params, "bucket_size="+strconv.Itoa(p.BucketSize))
+	}
+	params = appendParam(params, "environment", p.Environment)
+	if p.ItemID != 0 {
+		params = append(params, "item_id="+p.ItemID.String())
+	}
+
+	return strings.Join(params, "&")
+}
+
+// OccurrenceCounts returns occurrence totals per time bucket, oldest first.
+func (c *Client) OccurrenceCounts(ctx context.Context, params CountReportParams) ([]CountBucket, error) {
+	return c.countReport(ctx, "/reports/occurrence_counts", params, "occurrence counts")
+}
+
+// ActivatedCounts returns how many items were activated or reactivated per time bucket.
+func (c *Client) ActivatedCounts(ctx context.Context, params CountReportParams) ([]CountBucket, error) {
+	return c.countReport(ctx, "/reports/activated_counts", params, "activated counts")
+}
+
+func (c *Client) countReport(ctx context.Context, endpointPath string, params CountReportParams, op string) ([]CountBucket, error) {
+	var buckets []CountBucket
+	if err := c.getInto(ctx, withQuery(endpointPath, params.encode()), op, &buckets); err != nil {
+		return nil, err
+	}
+
+	return buckets, nil
+}
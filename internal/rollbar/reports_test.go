@@ -0,0 +1,63 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestOccurrenceCounts(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/reports/occurrence_counts" {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		if r.URL.RawQuery != "bucket_size=3600&environment=production&item_id=42" {
+			t.Fatalf("unexpected query: %s", r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[[1700000000,3],[1700003600,5]]}`)
+	})
+
+	buckets, err := client.OccurrenceCounts(context.Background(), CountReportParams{BucketSize: 3600, Environment: "production", ItemID: domain.ItemID(42)})
+	if err != nil {
+		t.Fatalf("OccurrenceCounts() error = %v", err)
+	}
+	if len(buckets) != 2 || buckets[1].Timestamp != 1700003600 || buckets[1].Count != 5 {
+		t.Fatalf("unexpected buckets: %+v", buckets)
+	}
+}
+
+func TestActivatedCounts(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/reports/activated_counts" || r.URL.RawQuery != "" {
+			t.Fatalf("unexpected request: %s?%s", r.URL.Path, r.URL.RawQuery)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":[{"timestamp":1700000000,"count":1}]}`)
+	})
+
+	buckets, err := client.ActivatedCounts(context.Background(), CountReportParams{})
+	if err != nil {
+		t.Fatalf("ActivatedCounts() error = %v", err)
+	}
+	if len(buckets) != 1 || buckets[0].Count != 1 {
+		t.Fatalf("unexpected buckets: %+v", buckets)
+	}
+}
+
+func TestCountBucketUnmarshalInvalid(t *testing.T) {
+	t.Parallel()
+
+	for _, input := range []string{`[1]`, `"x"`} {
+		var bucket CountBucket
+		if err := json.Unmarshal([]byte(input), &bucket); err == nil {
+			t.Fatalf("expected error for %s", input)
+		}
+	}
+}
@@ -0,0 +1,96 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"math/rand/v2"
+	"net/http"
+	"time"
+)
+
+// RetryPolicy controls how the client retries failed requests. Idempotent
+// requests are retried on transport errors, 429, and 5xx gateway-style
+// statuses; POST and PATCH are retried only on 429, where Rollbar has not
+// processed the write.
+type RetryPolicy struct {
+	// MaxAttempts counts the first try; one or less disables retries.
+	MaxAttempts int
+	BaseDelay   time.Duration
+	MaxDelay    time.Duration
+	// Jitter is the fraction of each delay that is randomized away, 0 to 1.
+	Jitter float64
+}
+
+func DefaultRetryPolicy() RetryPolicy {
+	return RetryPolicy{
+		MaxAttempts: 3,
+		BaseDelay:   250 * time.Millisecond,
+		MaxDelay:    5 * time.Second,
+		Jitter:      0.2,
+	}
+}
+
+func (c *Client) SetRetryPolicy(policy RetryPolicy) {
+	c.retry = policy
+}
+
+func (p RetryPolicy) shouldRetry(method string, err error) bool {
+	var apiErr *Error
+	if !errors.As(err, &apiErr) {
+		return false
+	}
+	if apiErr.Kind == ErrorKindRateLimited {
+		return true
+	}
+	if !isIdempotent(method) {
+		return false
+	}
+
+	return apiErr.transient || isRetryableStatus(apiErr.StatusCode)
+}
+
+// delay honors Retry-After when Rollbar sends it and otherwise backs off
+// exponentially from BaseDelay, capped at MaxDelay.
+func (p RetryPolicy) delay(attempt int, err error) time.Duration {
+	if wait, limited := IsRateLimited(err); limited && wait > 0 {
+		return wait
+	}
+
+	delay := p.BaseDelay << min(attempt-1, 30)
+	if delay <= 0 || (p.MaxDelay > 0 && delay > p.MaxDelay) {
+		delay = p.MaxDelay
+	}
+	if p.Jitter > 0 {
+		//nolint:gosec // jitter only spreads retries out; it needs no cryptographic source
+		delay -= time.Duration(rand.Float64() * min(p.Jitter, 1) * float64(delay))
+	}
+
+	return delay
+}
+
+// outlastsDeadline reports whether waiting would run past ctx's deadline, as
+// a Retry-After of several minutes does under a command timeout; the request
+// then fails with the rate-limit error at once instead of sleeping until the
+// timeout.
+func outlastsDeadline(ctx context.Context, wait time.Duration) bool {
+	deadline, ok := ctx.Deadline()
+	return ok && time.Until(deadline) < wait
+}
+
+func isIdempotent(method string) bool {
+	switch method {
+	case http.MethodGet, http.MethodHead, http.MethodPut, http.MethodDelete, http.MethodOptions:
+		return true
+	default:
+		return false
+	}
+}
+
+func isRetryableStatus(status int) bool {
+	switch status {
+	case http.StatusInternalServerError, http.StatusBadGateway, http.StatusServiceUnavailable, http.StatusGatewayTimeout:
+		return true
+	default:
+		return false
+	}
+}
@@ -0,0 +1,176 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"sync/atomic"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestRequestRetries(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name      string
+		method    string
+		failures  []int
+		wantHits  int32
+		wantError bool
+	}{
+		{name: "get retries 503", method: http.MethodGet, failures: []int{http.StatusServiceUnavailable}, wantHits: 2},
+		{name: "get gives up after max attempts", method: http.MethodGet, failures: []int{502, 502, 502, 502}, wantHits: 3, wantError: true},
+		{name: "get does not retry 404", method: http.MethodGet, failures: []int{http.StatusNotFound}, wantHits: 1, wantError: true},
+		{name: "post does not retry 503", method: http.MethodPost, failures: []int{http.StatusServiceUnavailable}, wantHits: 1, wantError: true},
+		{name: "post retries 429", method: http.MethodPost, failures: []int{http.StatusTooManyRequests}, wantHits: 2},
+	}
+
+	for _, tc := range tests {
+		tc := tc
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+
+			var hits atomic.Int32
+			client := newTestClientWithHandler(t, failThenServeItem(&hits, tc.failures))
+			client.SetRetryPolicy(RetryPolicy{MaxAttempts: 3, BaseDelay: time.Millisecond})
+			client.sleep = func(context.Context, time.Duration) error { return nil }
+
+			_, err := client.writeResult(context.Background(), tc.method, "/item/1", nil, "item")
+			if (err != nil) != tc.wantError {
+				t.Fatalf("unexpected error state: %v", err)
+			}
+			if hits.Load() != tc.wantHits {
+				t.Fatalf("hits = %d, want %d", hits.Load(), tc.wantHits)
+			}
+		})
+	}
+}
+
+// failThenServeItem answers with each of failures in turn, then with an
+// item, counting requests in hits.
+func failThenServeItem(hits *atomic.Int32, failures []int) func(http.ResponseWriter, *http.Request) {
+	return func(w http.ResponseWriter, _ *http.Request) {
+		hit := int(hits.Add(1))
+		if hit <= len(failures) {
+			w.WriteHeader(failures[hit-1])
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1}}`)
+	}
+}
+
+func TestRetryHonorsRetryAfter(t *testing.T) {
+	t.Parallel()
+
+	var hits atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		if hits.Add(1) == 1 {
+			w.Header().Set("Retry-After", "4")
+			w.WriteHeader(http.StatusTooManyRequests)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1}}`)
+	})
+	client.SetRetryPolicy(RetryPolicy{MaxAttempts: 2, BaseDelay: time.Millisecond})
+
+	var slept []time.Duration
+	client.sleep = func(_ context.Context, delay time.Duration) error {
+		slept = append(slept, delay)
+		return nil
+	}
+
+	if _, err := client.GetItem(context.Background(), domain.ItemID(1)); err != nil {
+		t.Fatalf("GetItem() error = %v", err)
+	}
+	if len(slept) != 1 || slept[0] != 4*time.Second {
+		t.Fatalf("unexpected sleeps: %v", slept)
+	}
+}
+
+func TestRetryFailsFastPastDeadline(t *testing.T) {
+	t.Parallel()
+
+	var hits atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		hits.Add(1)
+		w.Header().Set("Retry-After", "3600")
+		w.WriteHeader(http.StatusTooManyRequests)
+	})
+	client.SetRetryPolicy(DefaultRetryPolicy())
+	client.sleep = func(context.Context, time.Duration) error {
+		t.Fatal("expected no sleep past the deadline")
+		return nil
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), time.Minute)
+	defer cancel()
+	_, err := client.GetItem(ctx, domain.ItemID(1))
+	if wait, limited := IsRateLimited(err); !limited || wait != time.Hour || hits.Load() != 1 {
+		t.Fatalf("expected one rate-limited attempt, hits=%d err=%v", hits.Load(), err)
+	}
+}
+
+func TestRetryStopsWhenSleepFails(t *testing.T) {
+	t.Parallel()
+
+	var hits atomic.Int32
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, _ *http.Request) {
+		hits.Add(1)
+		w.WriteHeader(http.StatusServiceUnavailable)
+	})
+	client.SetRetryPolicy(DefaultRetryPolicy())
+	client.sleep = func(context.Context, time.Duration) error { return context.Canceled }
+
+	_, err := client.GetItem(context.Background(), domain.ItemID(1))
+	if err == nil || hits.Load() != 1 {
+		t.Fatalf("expected single attempt and error, hits=%d err=%v", hits.Load(), err)
+	}
+}
+
+func TestRetryPolicyDelay(t *testing.T) {
+	t.Parallel()
+
+	policy := RetryPolicy{BaseDelay: 100 * time.Millisecond, MaxDelay: time.Second}
+	plain := errors.New("boom")
+
+	tests := []struct {
+		attempt int
+		want    time.Duration
+	}{
+		{attempt: 1, want: 100 * time.Millisecond},
+		{attempt: 2, want: 200 * time.Millisecond},
+		{attempt: 4, want: 800 * time.Millisecond},
+		{attempt: 5, want: time.Second},
+		{attempt: 80, want: time.Second},
+	}
+
+	for _, tc := range tests {
+		if got := policy.delay(tc.attempt, plain); got != tc.want {
+			t.Fatalf("delay(%d) = %s, want %s", tc.attempt, got, tc.want)
+		}
+	}
+
+	policy.Jitter = 0.5
+	for range 20 {
+		got := policy.delay(1, plain)
+		if got < 50*time.Millisecond || got > 100*time.Millisecond {
+			t.Fatalf("jittered delay out of range: %s", got)
+		}
+	}
+}
+
+func TestNewUsesDefaultRetryPolicy(t *testing.T) {
+	t.Parallel()
+
+	client, err := New("token")
+	if err != nil {
+		t.Fatalf("New() error = %v", err)
+	}
+	if client.retry != DefaultRetryPolicy() {
+		t.Fatalf("unexpected retry policy: %+v", client.retry)
+	}
+}
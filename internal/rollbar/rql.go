@@ -0,0 +1,153 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"strconv"
+	"strings"
+	"time"
+)
+
+const (
+	defaultRQLPollInterval = 500 * time.Millisecond
+	maxRQLPollInterval     = 5 * time.Second
+)
+
+const (
+	RQLStatusNew       = "new"
+	RQLStatusRunning   = "running"
+	RQLStatusSuccess   = "success"
+	RQLStatusFailed    = "failed"
+	RQLStatusCancelled = "cancelled"
+	RQLStatusTimedOut  = "timed_out"
+)
+
+type RQLJob struct {
+	ID           uint64  `json:"id"`
+	ProjectID    uint64  `json:"project_id"`
+	QueryString  string  `json:"query_string"`
+	Status       string  `json:"status"`
+	DateCreated  *uint64 `json:"date_created"`
+	DateModified *uint64 `json:"date_modified"`
+}
+
+type RQLResult struct {
+	JobID    uint64              `json:"job_id"`
+	Columns  []string            `json:"columns"`
+	Rows     [][]json.RawMessage `json:"rows"`
+	RowCount int                 `json:"rowcount"`
+}
+
+type rqlJobRequest struct {
+	QueryString  string `json:"query_string"`
+	ForceRefresh bool   `json:"force_refresh"`
+}
+
+type rqlResultEnvelope struct {
+	JobID  uint64    `json:"job_id"`
+	Result RQLResult `json:"result"`
+}
+
+func (j RQLJob) Done() bool {
+	switch j.Status {
+	case RQLStatusSuccess, RQLStatusFailed, RQLStatusCancelled, RQLStatusTimedOut:
+		return true
+	default:
+		return false
+	}
+}
+
+func (c *Client) CreateRQLJob(ctx context.Context, query string) (RQLJob, error) {
+	trimmed := strings.TrimSpace(query)
+	if trimmed == "" {
+		return RQLJob{}, errors.New("rql query is required")
+	}
+
+	raw, err := c.writeResult(ctx, http.MethodPost, "/rql/jobs", rqlJobRequest{QueryString: trimmed}, "create rql job")
+	if err != nil {
+		return RQLJob{}, err
+	}
+
+	var job RQLJob
+	if err := json.Unmarshal(raw, &job); err != nil {
+		return RQLJob{}, c.wrap(err, "decode rql job")
+	}
+
+	return job, nil
+}
+
+func (c *Client) GetRQLJob(ctx context.Context, jobID uint64) (RQLJob, error) {
+	raw, err := c.getResult(ctx, "/rql/job/"+strconv.FormatUint(jobID, 10), "rql job")
+	if err != nil {
+		return RQLJob{}, err
+	}
+
+	var job RQLJob
+	if err := json.Unmarshal(raw, &job); err != nil {
+		return RQLJob{}, c.wrap(err, "decode rql job")
+	}
+
+	return job, nil
+}
+
+func (c *Client) GetRQLJobResult(ctx context.Context, jobID uint64) (RQLResult, error) {
+	raw, err := c.getResult(ctx, "/rql/job/"+strconv.FormatUint(jobID, 10)+"/result", "rql job result")
+	if err != nil {
+		return RQLResult{}, err
+	}
+
+	var envelope rqlResultEnvelope
+	if err := json.Unmarshal(raw, &envelope); err != nil {
+		return RQLResult{}, c.wrap(err, "decode rql job result")
+	}
+
+	result := envelope.Result
+	if result.JobID == 0 {
+		result.JobID = jobID
+	}
+
+	return result, nil
+}
+
+// RunRQL submits a query and polls the job with exponential backoff until it
+// finishes, then fetches its rows. Cancel ctx to bound the total wait.
+func (c *Client) RunRQL(ctx context.Context, query string) (RQLResult, error) {
+	job, err := c.CreateRQLJob(ctx, query)
+	if err != nil {
+		return RQLResult{}, err
+	}
+
+	interval := c.rqlPollInterval
+	for !job.Done() {
+		if err := sleepContext(ctx, interval); err != nil {
+			return RQLResult{}, fmt.Errorf("wait for rql job %d: %w", job.ID, err)
+		}
+		interval = min(interval*2, maxRQLPollInterval)
+
+		job, err = c.GetRQLJob(ctx, job.ID)
+		if err != nil {
+			return RQLResult{}, err
+		}
+	}
+
+	if job.Status != RQLStatusSuccess {
+		return RQLResult{}, fmt.Errorf("rql job %d finished with status %q", job.ID, job.Status)
+	}
+
+	return c.GetRQLJobResult(ctx, job.ID)
+}
+
+func sleepContext(ctx context.Context, delay time.Duration) error {
+	timer := time.NewTimer(delay)
+	defer timer.Stop()
+
+	select {
+	case <-ctx.Done():
+		return fmt.Errorf("context done: %w", ctx.Err())
+	case <-timer.C:
+		return nil
+	}
+}
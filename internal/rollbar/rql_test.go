@@ -0,0 +1,125 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+	"sync/atomic"
+	"testing"
+	"time"
+)
+
+func TestRunRQLPollsUntilSuccess(t *testing.T) {
+	t.Parallel()
+
+	var polls atomic.Int32
+	client := newTestClientWithHandler(t, rqlJobHandler(t, &polls))
+	client.rqlPollInterval = time.Millisecond
+
+	result, err := client.RunRQL(context.Background(), " SELECT 1 ")
+	if err != nil {
+		t.Fatalf("RunRQL() error = %v", err)
+	}
+	if result.JobID != 5 || len(result.Columns) != 2 || result.RowCount != 1 {
+		t.Fatalf("unexpected result: %+v", result)
+	}
+	if string(result.Rows[0][0]) != "269" {
+		t.Fatalf("unexpected first cell: %s", result.Rows[0][0])
+	}
+	if polls.Load() != 2 {
+		t.Fatalf("expected two polls, got %d", polls.Load())
+	}
+}
+
+// rqlJobHandler serves job 5 for "SELECT 1", running on the first poll and
+// done on the second.
+func rqlJobHandler(t *testing.T, polls *atomic.Int32) func(http.ResponseWriter, *http.Request) {
+	t.Helper()
+
+	return func(w http.ResponseWriter, r *http.Request) {
+		switch {
+		case r.Method == http.MethodPost && r.URL.Path == "/rql/jobs":
+			body, err := io.ReadAll(r.Body)
+			if err != nil {
+				t.Fatalf("ReadAll() error = %v", err)
+			}
+			var request rqlJobRequest
+			if err := json.Unmarshal(body, &request); err != nil || request.QueryString != "SELECT 1" {
+				t.Fatalf("unexpected job request: %s", body)
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":5,"status":"new"}}`)
+		case r.URL.Path == "/rql/job/5":
+			status := "running"
+			if polls.Add(1) > 1 {
+				status = "success"
+			}
+			_, _ = fmt.Fprintf(w, `{"err":0,"result":{"id":5,"status":%q}}`, status)
+		case r.URL.Path == "/rql/job/5/result":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"job_id":5,"result":{"columns":["item.counter","count(*)"],"rows":[[269,"12"]],"rowcount":1}}}`)
+		default:
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+	}
+}
+
+func TestRunRQLFailedJob(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path == "/rql/jobs" {
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":6,"status":"running"}}`)
+			return
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":6,"status":"failed"}}`)
+	})
+	client.rqlPollInterval = time.Millisecond
+
+	_, err := client.RunRQL(context.Background(), "SELECT bad")
+	if err == nil || !strings.Contains(err.Error(), `"failed"`) {
+		t.Fatalf("expected failed status error, got %v", err)
+	}
+}
+
+func TestRunRQLHonorsContext(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":7,"status":"running"}}`)
+	})
+	client.rqlPollInterval = time.Hour
+
+	ctx, cancel := context.WithTimeout(context.Background(), 20*time.Millisecond)
+	defer cancel()
+	if _, err := client.RunRQL(ctx, "SELECT 1"); err == nil {
+		t.Fatalf("expected context error")
+	}
+}
+
+func TestCreateRQLJobRejectsBlankQuery(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClient(t, "https://api.rollbar.com/api/1")
+	if _, err := client.CreateRQLJob(context.Background(), "   "); err == nil {
+		t.Fatalf("expected blank query error")
+	}
+}
+
+func TestRQLJobDone(t *testing.T) {
+	t.Parallel()
+
+	for status, want := range map[string]bool{
+		RQLStatusNew:       false,
+		RQLStatusRunning:   false,
+		RQLStatusSuccess:   true,
+		RQLStatusFailed:    true,
+		RQLStatusCancelled: true,
+		RQLStatusTimedOut:  true,
+	} {
+		if got := (RQLJob{Status: status}).Done(); got != want {
+			t.Fatalf("Done(%q) = %v, want %v", status, got, want)
+		}
+	}
+}
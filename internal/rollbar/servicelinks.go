@@ -0,0 +1,55 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"net/http"
+	"strconv"
+)
+
+// ServiceLink is a link Rollbar shows on every item of the project, such as a
+// runbook or a log search. Template is a URL with {{ }} placeholders filled
+// from the item, like https://logs.example.com/?q={{ environment }}.
+type ServiceLink struct {
+	ID       uint64 `json:"id,omitempty"`
+	Name     string `json:"name"`
+	Template string `json:"template"`
+}
+
+func (c *Client) ListServiceLinks(ctx context.Context) ([]ServiceLink, error) {
+	raw, err := c.getResult(ctx, "/service_links", "service links")
+	if err != nil {
+		return nil, err
+	}
+
+	links := []ServiceLink{}
+	if len(raw) == 0 || string(raw) == "null" {
+		return links, nil
+	}
+	if err := json.Unmarshal(raw, &links); err != nil {
+		return nil, c.wrap(err, "decode service links response")
+	}
+
+	return links, nil
+}
+
+// CreateServiceLink adds a service link and returns it as created.
+func (c *Client) CreateServiceLink(ctx context.Context, name string, template string) (ServiceLink, error) {
+	raw, err := c.writeResult(ctx, http.MethodPost, "/service_links", ServiceLink{Name: name, Template: template}, "create service link")
+	if err != nil {
+		return ServiceLink{}, err
+	}
+
+	var link ServiceLink
+	if err := json.Unmarshal(raw, &link); err != nil {
+		return ServiceLink{}, c.wrap(err, "decode create service link response")
+	}
+
+	return link, nil
+}
+
+func (c *Client) DeleteServiceLink(ctx context.Context, linkID uint64) error {
+	_, err := c.writeResult(ctx, http.MethodDelete, "/service_links/"+strconv.FormatUint(linkID, 10), nil, "delete service link")
+
+	return err
+}
@@ -0,0 +1,73 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/http"
+	"testing"
+)
+
+func TestListServiceLinks(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name string
+		body string
+		want int
+	}{
+		{name: "links", body: `{"err":0,"result":[{"id":3,"name":"Runbook","template":"https://runbooks.example.com/{{ title }}"}]}`, want: 1},
+		{name: "none", body: `{"err":0,"result":null}`, want: 0},
+	}
+	for _, tc := range tests {
+		client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+			if r.Method != http.MethodGet || r.URL.Path != "/service_links" {
+				t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+			}
+			_, _ = fmt.Fprint(w, tc.body)
+		})
+
+		links, err := client.ListServiceLinks(context.Background())
+		if err != nil || len(links) != tc.want {
+			t.Fatalf("%s: ListServiceLinks() = %+v, %v", tc.name, links, err)
+		}
+	}
+}
+
+func TestCreateServiceLink(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/service_links" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		body, err := io.ReadAll(r.Body)
+		if err != nil {
+			t.Fatalf("ReadAll() error = %v", err)
+		}
+		if string(body) != `{"name":"Logs","template":"https://logs.example.com/?env={{ environment }}"}` {
+			t.Fatalf("unexpected body: %s", body)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":9,"name":"Logs","template":"https://logs.example.com/?env={{ environment }}"}}`)
+	})
+
+	link, err := client.CreateServiceLink(context.Background(), "Logs", "https://logs.example.com/?env={{ environment }}")
+	if err != nil || link.ID != 9 || link.Name != "Logs" {
+		t.Fatalf("CreateServiceLink() = %+v, %v", link, err)
+	}
+}
+
+func TestDeleteServiceLink(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodDelete || r.URL.Path != "/service_links/9" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, `{"err":0}`)
+	})
+
+	if err := client.DeleteServiceLink(context.Background(), 9); err != nil {
+		t.Fatalf("DeleteServiceLink() error = %v", err)
+	}
+}
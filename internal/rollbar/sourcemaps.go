@@ -0,0 +1,101 @@
+package rollbar
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"mime/multipart"
+	"net/http"
+	"sort"
+	"strings"
+)
+
+// SourceMapUpload is the source map for one minified file, for the
+// sourcemap endpoint. Rollbar matches it to frames by MinifiedURL and the
+// occurrence's code version.
+type SourceMapUpload struct {
+	Version     string
+	MinifiedURL string
+	SourceMap   []byte
+	// MapName is the file name sent with SourceMap.
+	MapName string
+	// Sources holds original files, keyed by their path in the map, for maps
+	// without sourcesContent.
+	Sources map[string][]byte
+}
+
+// UploadSourceMap posts a source map as multipart form data.
+func (c *Client) UploadSourceMap(ctx context.Context, upload SourceMapUpload) error {
+	upload.Version = strings.TrimSpace(upload.Version)
+	upload.MinifiedURL = strings.TrimSpace(upload.MinifiedURL)
+	switch {
+	case upload.Version == "":
+		return errors.New("sourcemap version is required")
+	case upload.MinifiedURL == "":
+		return errors.New("sourcemap minified url is required")
+	case len(upload.SourceMap) == 0:
+		return errors.New("sourcemap is empty")
+	}
+
+	body, contentType, err := encodeSourceMapUpload(upload)
+	if err != nil {
+		return c.wrap(err, "encode upload sourcemap request")
+	}
+
+	responseBody, err := c.doRequest(ctx, http.MethodPost, "/sourcemap", body, contentType, "upload sourcemap")
+	if err != nil {
+		return err
+	}
+	_, err = c.decodeEnvelope(responseBody, "upload sourcemap")
+
+	return err
+}
+
+func encodeSourceMapUpload(upload SourceMapUpload) ([]byte, string, error) {
+	var body bytes.Buffer
+	writer := multipart.NewWriter(&body)
+	if err := writer.WriteField("version", upload.Version); err != nil {
+		return nil, "", fmt.Errorf("write version: %w", err)
+	}
+	if err := writer.WriteField("minified_url", upload.MinifiedURL); err != nil {
+		return nil, "", fmt.Errorf("write minified_url: %w", err)
+	}
+
+	mapName := upload.MapName
+	if mapName == "" {
+		mapName = "source.js.map"
+	}
+	if err := writeFormFile(writer, "source_map", mapName, upload.SourceMap); err != nil {
+		return nil, "", err
+	}
+
+	paths := make([]string, 0, len(upload.Sources))
+	for path := range upload.Sources {
+		paths = append(paths, path)
+	}
+	sort.Strings(paths)
+	for _, path := range paths {
+		if err := writeFormFile(writer, path, path, upload.Sources[path]); err != nil {
+			return nil, "", err
+		}
+	}
+
+	if err := writer.Close(); err != nil {
+		return nil, "", fmt.Errorf("close multipart body: %w", err)
+	}
+
+	return body.Bytes(), writer.FormDataContentType(), nil
+}
+
+func writeFormFile(writer *multipart.Writer, field string, filename string, content []byte) error {
+	part, err := writer.CreateFormFile(field, filename)
+	if err != nil {
+		return fmt.Errorf("create %s part: %w", field, err)
+	}
+	if _, err := part.Write(content); err != nil {
+		return fmt.Errorf("write %s part: %w", field, err)
+	}
+
+	return nil
+}
@@ -0,0 +1,82 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+	"testing"
+)
+
+// checkUploadFiles fails unless r carries the app.min.js.map source map and
+// the src/app.ts source as files.
+func checkUploadFiles(t *testing.T, r *http.Request) {
+	t.Helper()
+
+	for field, want := range map[string]string{"source_map": `{"version":3}`, "src/app.ts": "export {}"} {
+		file, header, err := r.FormFile(field)
+		if err != nil {
+			t.Fatalf("FormFile(%q) error = %v", field, err)
+		}
+		content, _ := io.ReadAll(file)
+		_ = file.Close()
+		if string(content) != want || (field == "source_map" && header.Filename != "app.min.js.map") {
+			t.Fatalf("%s: got %q (%s)", field, content, header.Filename)
+		}
+	}
+}
+
+func TestUploadSourceMap(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost || r.URL.Path != "/sourcemap" {
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+		if err := r.ParseMultipartForm(1 << 20); err != nil {
+			t.Fatalf("ParseMultipartForm() error = %v", err)
+		}
+		if r.FormValue("version") != "abc123" || r.FormValue("minified_url") != "https://cdn.example.com/app.min.js" || r.FormValue("access_token") != "" {
+			t.Fatalf("unexpected fields: %v", r.MultipartForm.Value)
+		}
+		checkUploadFiles(t, r)
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"msg":"ok"}}`)
+	})
+
+	upload := SourceMapUpload{
+		Version:     " abc123 ",
+		MinifiedURL: "https://cdn.example.com/app.min.js",
+		SourceMap:   []byte(`{"version":3}`),
+		MapName:     "app.min.js.map",
+		Sources:     map[string][]byte{"src/app.ts": []byte("export {}")},
+	}
+	if err := client.UploadSourceMap(context.Background(), upload); err != nil {
+		t.Fatalf("UploadSourceMap() error = %v", err)
+	}
+}
+
+func TestUploadSourceMapErrors(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":1,"message":"Invalid minified_url"}`)
+	})
+
+	tests := []struct {
+		name   string
+		upload SourceMapUpload
+		want   string
+	}{
+		{name: "version", upload: SourceMapUpload{MinifiedURL: "u", SourceMap: []byte("{}")}, want: "version is required"},
+		{name: "url", upload: SourceMapUpload{Version: "v", SourceMap: []byte("{}")}, want: "minified url is required"},
+		{name: "map", upload: SourceMapUpload{Version: "v", MinifiedURL: "u"}, want: "sourcemap is empty"},
+		{name: "api", upload: SourceMapUpload{Version: "v", MinifiedURL: "u", SourceMap: []byte("{}")}, want: "Invalid minified_url"},
+	}
+	for _, tc := range tests {
+		err := client.UploadSourceMap(context.Background(), tc.upload)
+		if err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%s: expected %q error, got %v", tc.name, tc.want, err)
+		}
+	}
+}
@@ -0,0 +1,52 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"strings"
+	"time"
+)
+
+const pingPath = "/status/ping"
+
+// Ping calls Rollbar's status endpoint once, without retries or the response
+// cache, and returns how long the round trip took. It needs no particular
+// token scope, so apps can call it at startup to check connectivity before
+// relying on the client.
+func (c *Client) Ping(ctx context.Context) (latency time.Duration, err error) {
+	const op = "status ping"
+	call := c.startCall(http.MethodGet, pingPath, op)
+	defer func() { call.end(ctx, err) }()
+
+	req, err := c.newRequest(ctx, http.MethodGet, pingPath, nil, "", op)
+	if err != nil {
+		return 0, err
+	}
+	started := time.Now()
+	response, release, err := c.send(ctx, req, op)
+	if err != nil {
+		return 0, err
+	}
+	defer release()
+
+	body, err := c.readResponse(ctx, response, op)
+	latency = time.Since(started)
+	if err != nil {
+		return 0, err
+	}
+	if reply := strings.TrimSpace(string(body)); !strings.Contains(reply, "pong") {
+		return 0, c.fail(ErrorKindDecode, fmt.Errorf("unexpected reply %q", truncateReply(reply)), op)
+	}
+
+	return latency, nil
+}
+
+func truncateReply(reply string) string {
+	const maxReply = 64
+	if len(reply) > maxReply {
+		return reply[:maxReply] + "..."
+	}
+
+	return reply
+}
@@ -0,0 +1,47 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"testing"
+)
+
+func TestPing(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name     string
+		status   int
+		body     string
+		wantKind ErrorKind
+	}{
+		{name: "pong", status: http.StatusOK, body: "pong\n"},
+		{name: "unavailable", status: http.StatusServiceUnavailable, body: "down", wantKind: ErrorKindHTTP},
+		{name: "proxy page", status: http.StatusOK, body: "<html>captive portal</html>", wantKind: ErrorKindDecode},
+	}
+	for _, tc := range tests {
+		calls := 0
+		client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+			calls++
+			if r.Method != http.MethodGet || r.URL.Path != "/status/ping" {
+				t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+			}
+			w.WriteHeader(tc.status)
+			_, _ = fmt.Fprint(w, tc.body)
+		})
+
+		latency, err := client.Ping(context.Background())
+		if tc.wantKind == 0 {
+			if err != nil || latency <= 0 {
+				t.Fatalf("%s: Ping() = %s, %v", tc.name, latency, err)
+			}
+			continue
+		}
+		var apiErr *Error
+		if !errors.As(err, &apiErr) || apiErr.Kind != tc.wantKind || calls != 1 {
+			t.Fatalf("%s: expected a %s error after one call, got %v (%d calls)", tc.name, tc.wantKind, err, calls)
+		}
+	}
+}
@@ -0,0 +1,80 @@
+package rollbar
+
+import (
+	"cmp"
+	"encoding/json"
+	"slices"
+)
+
+// Telemetry event types SDKs such as rollbar.js record as breadcrumbs.
+const (
+	TelemetryLog          = "log"
+	TelemetryNetwork      = "network"
+	TelemetryNavigation   = "navigation"
+	TelemetryDOM          = "dom"
+	TelemetryError        = "error"
+	TelemetryManual       = "manual"
+	TelemetryConnectivity = "connectivity"
+)
+
+// TelemetryEvent is one breadcrumb from an occurrence's body.telemetry. Body
+// holds the fields of every event type; which are set depends on Type.
+type TelemetryEvent struct {
+	Type        string        `json:"type"`
+	Level       string        `json:"level,omitempty"`
+	Source      string        `json:"source,omitempty"`
+	TimestampMS int64         `json:"timestamp_ms"`
+	Body        TelemetryBody `json:"body"`
+}
+
+// TelemetryBody is the body of a telemetry event: Message for log, error, and
+// manual events; Method, URL, and StatusCode for network requests; From and
+// To for navigation; Element and Value for DOM events; Change for
+// connectivity.
+type TelemetryBody struct {
+	Message     string      `json:"message,omitempty"`
+	Subtype     string      `json:"subtype,omitempty"`
+	Method      string      `json:"method,omitempty"`
+	URL         string      `json:"url,omitempty"`
+	StatusCode  json.Number `json:"status_code,omitempty"`
+	StartTimeMS int64       `json:"start_time_ms,omitempty"`
+	EndTimeMS   int64       `json:"end_time_ms,omitempty"`
+	From        string      `json:"from,omitempty"`
+	To          string      `json:"to,omitempty"`
+	Element     string      `json:"element,omitempty"`
+	Value       string      `json:"value,omitempty"`
+	Change      string      `json:"change,omitempty"`
+}
+
+type telemetryWire struct {
+	Telemetry []json.RawMessage `json:"telemetry"`
+}
+
+// ParseTelemetry decodes the telemetry of an occurrence body, oldest first.
+// Events that do not decode are skipped, so one odd breadcrumb does not hide
+// the rest.
+func ParseTelemetry(body json.RawMessage) []TelemetryEvent {
+	var wire telemetryWire
+	if err := json.Unmarshal(body, &wire); err != nil {
+		return []TelemetryEvent{}
+	}
+
+	events := make([]TelemetryEvent, 0, len(wire.Telemetry))
+	for _, raw := range wire.Telemetry {
+		var event TelemetryEvent
+		if err := json.Unmarshal(raw, &event); err == nil {
+			events = append(events, event)
+		}
+	}
+	slices.SortStableFunc(events, func(a, b TelemetryEvent) int {
+		return cmp.Compare(a.TimestampMS, b.TimestampMS)
+	})
+
+	return events
+}
+
+// Telemetry returns the instance's telemetry, read from its body like
+// OccurrenceBody.
+func (i ItemInstance) Telemetry() []TelemetryEvent {
+	return ParseTelemetry(i.bodyJSON())
+}
@@ -0,0 +1,39 @@
+package rollbar
+
+import (
+	"encoding/json"
+	"reflect"
+	"testing"
+)
+
+func TestParseTelemetry(t *testing.T) {
+	t.Parallel()
+
+	body := `{"trace":{"frames":[],"exception":{"class":"TypeError","message":"x is undefined"}},"telemetry":[
+		{"level":"info","type":"navigation","source":"client","timestamp_ms":1700000001000,"body":{"from":"/cart","to":"/checkout"}},
+		{"level":"info","type":"network","source":"client","timestamp_ms":1700000000500,"body":{"subtype":"xhr","method":"POST","url":"/api/pay","status_code":"502","start_time_ms":1700000000500,"end_time_ms":1700000000620}},
+		{"level":"error","type":"log","timestamp_ms":1700000001000,"body":{"message":"payment failed"}},
+		{"type":"dom","timestamp_ms":"soon","body":{}}
+	]}`
+	want := []TelemetryEvent{
+		{Type: TelemetryNetwork, Level: "info", Source: "client", TimestampMS: 1700000000500, Body: TelemetryBody{Subtype: "xhr", Method: "POST", URL: "/api/pay", StatusCode: "502", StartTimeMS: 1700000000500, EndTimeMS: 1700000000620}},
+		{Type: TelemetryNavigation, Level: "info", Source: "client", TimestampMS: 1700000001000, Body: TelemetryBody{From: "/cart", To: "/checkout"}},
+		{Type: TelemetryLog, Level: "error", TimestampMS: 1700000001000, Body: TelemetryBody{Message: "payment failed"}},
+	}
+
+	tests := []struct {
+		name     string
+		instance ItemInstance
+		want     []TelemetryEvent
+	}{
+		{name: "body", instance: ItemInstance{Body: json.RawMessage(body)}, want: want},
+		{name: "data body", instance: ItemInstance{Data: json.RawMessage(`{"body":` + body + `}`)}, want: want},
+		{name: "none", instance: ItemInstance{Body: json.RawMessage(`{"message":{"body":"hi"}}`)}, want: []TelemetryEvent{}},
+		{name: "malformed", instance: ItemInstance{Body: json.RawMessage(`{"telemetry":{}}`)}, want: []TelemetryEvent{}},
+	}
+	for _, tc := range tests {
+		if got := tc.instance.Telemetry(); !reflect.DeepEqual(got, tc.want) {
+			t.Fatalf("%s: Telemetry() = %+v, want %+v", tc.name, got, tc.want)
+		}
+	}
+}
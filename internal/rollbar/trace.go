@@ -0,0 +1,78 @@
+package rollbar
+
+import (
+	"context"
+	"errors"
+	"log/slog"
+	"net/http"
+	"strings"
+	"time"
+)
+
+// SetLogger records every API call on logger once its retries are done: the
+// operation, method, endpoint path, final status, duration, attempt count,
+// and the rate-limit remaining at that point. Successful calls log at debug
+// level and failures at warn, so a handler at info stays quiet until
+// something breaks. Query strings and the access token are never logged.
+// nil turns tracing off. Call it before the client is shared.
+func (c *Client) SetLogger(logger *slog.Logger) {
+	c.logger = logger
+}
+
+// callTrace follows one client call across its attempts.
+type callTrace struct {
+	client   *Client
+	method   string
+	path     string
+	op       string
+	started  time.Time
+	attempts int
+}
+
+func (c *Client) startCall(method string, endpointPath string, op string) *callTrace {
+	path, _, _ := strings.Cut(endpointPath, "?")
+
+	return &callTrace{client: c, method: method, path: path, op: op, started: time.Now(), attempts: 1}
+}
+
+func (t *callTrace) end(ctx context.Context, err error) {
+	logger := t.client.logger
+	level := slog.LevelDebug
+	if err != nil {
+		level = slog.LevelWarn
+	}
+	if logger == nil || !logger.Enabled(ctx, level) {
+		return
+	}
+
+	attrs := []slog.Attr{
+		slog.String("op", t.op),
+		slog.String("method", t.method),
+		slog.String("endpoint", t.path),
+		slog.Int("status", traceStatus(err)),
+		slog.Duration("duration", time.Since(t.started)),
+		slog.Int("attempts", t.attempts),
+	}
+	if limit, ok := t.client.RateLimitStatus(); ok {
+		attrs = append(attrs, slog.Int("rate_limit_remaining", limit.Remaining))
+	}
+	if err != nil {
+		attrs = append(attrs, slog.String("error", err.Error()))
+	}
+	logger.LogAttrs(ctx, level, "rollbar api call", attrs...)
+}
+
+// traceStatus is the final HTTP status of a call, or zero when no response
+// arrived.
+func traceStatus(err error) int {
+	if err == nil {
+		return http.StatusOK
+	}
+
+	var apiErr *Error
+	if errors.As(err, &apiErr) {
+		return apiErr.StatusCode
+	}
+
+	return 0
+}
@@ -0,0 +1,87 @@
+package rollbar
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"log/slog"
+	"net/http"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// serveTracedItem serves item 1, a 404 for item 404, and 41 remaining calls
+// on every response.
+func serveTracedItem(w http.ResponseWriter, r *http.Request) {
+	w.Header().Set("X-Rate-Limit-Remaining", "41")
+	if r.URL.Path == "/item/404/" {
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprint(w, `{"err":1,"message":"not found"}`)
+		return
+	}
+	_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":1}}`)
+}
+
+// checkTraceRecord fails unless line is a single-attempt item trace record
+// at level with status.
+func checkTraceRecord(t *testing.T, line string, level string, status float64) {
+	t.Helper()
+
+	var record map[string]any
+	if err := json.Unmarshal([]byte(line), &record); err != nil {
+		t.Fatalf("decode record: %v", err)
+	}
+	if record["level"] != level || record["status"] != status || record["op"] != "item" || record["rate_limit_remaining"] != float64(41) || record["attempts"] != float64(1) {
+		t.Fatalf("unexpected record %v", record)
+	}
+}
+
+func TestSetLoggerTracesCalls(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, serveTracedItem)
+	client.SetRetryPolicy(RetryPolicy{MaxAttempts: 2})
+	client.sleep = func(context.Context, time.Duration) error { return nil }
+
+	var logs bytes.Buffer
+	client.SetLogger(slog.New(slog.NewJSONHandler(&logs, &slog.HandlerOptions{Level: slog.LevelDebug})))
+
+	if _, err := client.GetItem(context.Background(), domain.ItemID(1)); err != nil {
+		t.Fatalf("GetItem() error = %v", err)
+	}
+	if _, err := client.GetItem(context.Background(), domain.ItemID(404)); !IsNotFound(err) {
+		t.Fatalf("expected a 404, got %v", err)
+	}
+
+	lines := strings.Split(strings.TrimSpace(logs.String()), "\n")
+	if len(lines) != 2 {
+		t.Fatalf("expected one record per call, got %q", logs.String())
+	}
+	tests := []struct {
+		level  string
+		status float64
+	}{
+		{level: "DEBUG", status: http.StatusOK},
+		{level: "WARN", status: http.StatusNotFound},
+	}
+	for index, tc := range tests {
+		checkTraceRecord(t, lines[index], tc.level, tc.status)
+	}
+	if strings.Contains(logs.String(), "token") {
+		t.Fatalf("trace leaks the token: %s", logs.String())
+	}
+}
+
+func TestTracingOffByDefault(t *testing.T) {
+	t.Parallel()
+
+	call := (&Client{limits: &rateLimiter{}}).startCall(http.MethodGet, "/items/?status=active", "items")
+	if call.path != "/items/" {
+		t.Fatalf("expected the query to be dropped, got %q", call.path)
+	}
+	call.end(context.Background(), nil)
+}
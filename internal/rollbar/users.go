@@ -0,0 +1,164 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"strconv"
+	"strings"
+)
+
+type User struct {
+	ID           uint64 `json:"id"`
+	Username     string `json:"username"`
+	Email        string `json:"email"`
+	EmailEnabled bool   `json:"email_enabled"`
+}
+
+type Team struct {
+	ID          uint64 `json:"id"`
+	AccountID   uint64 `json:"account_id"`
+	Name        string `json:"name"`
+	AccessLevel string `json:"access_level"`
+}
+
+type TeamUser struct {
+	TeamID uint64 `json:"team_id"`
+	UserID uint64 `json:"user_id"`
+}
+
+type TeamProject struct {
+	TeamID    uint64 `json:"team_id"`
+	ProjectID uint64 `json:"project_id"`
+}
+
+type Invite struct {
+	ID           uint64  `json:"id"`
+	FromUserID   uint64  `json:"from_user_id"`
+	TeamID       uint64  `json:"team_id"`
+	ToEmail      string  `json:"to_email"`
+	Status       string  `json:"status"`
+	DateCreated  *uint64 `json:"date_created"`
+	DateRedeemed *uint64 `json:"date_redeemed"`
+}
+
+type usersEnvelope struct {
+	Users []User `json:"users"`
+}
+
+type inviteRequest struct {
+	Email string `json:"email"`
+}
+
+func (c *Client) ListUsers(ctx context.Context) ([]User, error) {
+	raw, err := c.getResult(ctx, "/users", "users")
+	if err != nil {
+		return nil, err
+	}
+
+	var wrapped usersEnvelope
+	if err := json.Unmarshal(raw, &wrapped); err != nil {
+		return nil, c.wrap(err, "decode users response")
+	}
+
+	return wrapped.Users, nil
+}
+
+func (c *Client) FindUserByEmail(ctx context.Context, email string) (User, error) {
+	trimmed := strings.TrimSpace(email)
+	if trimmed == "" {
+		return User{}, errors.New("user email is required")
+	}
+
+	users, err := c.ListUsers(ctx)
+	if err != nil {
+		return User{}, err
+	}
+
+	for _, user := range users {
+		if strings.EqualFold(user.Email, trimmed) {
+			return user, nil
+		}
+	}
+
+	return User{}, fmt.Errorf("no user with email %q", trimmed)
+}
+
+func (c *Client) GetUser(ctx context.Context, userID uint64) (User, error) {
+	var user User
+	err := c.getInto(ctx, "/user/"+strconv.FormatUint(userID, 10), "user", &user)
+
+	return user, err
+}
+
+func (c *Client) ListTeams(ctx context.Context) ([]Team, error) {
+	var teams []Team
+	err := c.getInto(ctx, "/teams", "teams", &teams)
+
+	return teams, err
+}
+
+func (c *Client) ListTeamUsers(ctx context.Context, teamID uint64) ([]TeamUser, error) {
+	var users []TeamUser
+	err := c.getInto(ctx, teamPath(teamID)+"/users", "team users", &users)
+
+	return users, err
+}
+
+func (c *Client) ListTeamProjects(ctx context.Context, teamID uint64) ([]TeamProject, error) {
+	var projects []TeamProject
+	err := c.getInto(ctx, teamPath(teamID)+"/projects", "team projects", &projects)
+
+	return projects, err
+}
+
+func (c *Client) ListTeamInvites(ctx context.Context, teamID uint64) ([]Invite, error) {
+	var invites []Invite
+	err := c.getInto(ctx, teamPath(teamID)+"/invites", "team invites", &invites)
+
+	return invites, err
+}
+
+func (c *Client) InviteToTeam(ctx context.Context, teamID uint64, email string) (Invite, error) {
+	trimmed := strings.TrimSpace(email)
+	if trimmed == "" {
+		return Invite{}, errors.New("invite email is required")
+	}
+
+	raw, err := c.writeResult(ctx, http.MethodPost, teamPath(teamID)+"/invites", inviteRequest{Email: trimmed}, "invite to team")
+	if err != nil {
+		return Invite{}, err
+	}
+
+	var invite Invite
+	if err := json.Unmarshal(raw, &invite); err != nil {
+		return Invite{}, c.wrap(err, "decode invite response")
+	}
+
+	return invite, nil
+}
+
+func (c *Client) AddUserToTeam(ctx context.Context, teamID uint64, userID uint64) error {
+	_, err := c.writeResult(ctx, http.MethodPut, teamPath(teamID)+"/user/"+strconv.FormatUint(userID, 10), nil, "add user to team")
+
+	return err
+}
+
+func (c *Client) getInto(ctx context.Context, endpointPath string, op string, target any) error {
+	raw, err := c.getResult(ctx, endpointPath, op)
+	if err != nil {
+		return err
+	}
+
+	if err := json.Unmarshal(raw, target); err != nil {
+		return c.wrap(err, "decode "+op+" response")
+	}
+
+	return nil
+}
+
+func teamPath(teamID uint64) string {
+	return "/team/" + strconv.FormatUint(teamID, 10)
+}
@@ -0,0 +1,138 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"net/http"
+	"reflect"
+	"testing"
+)
+
+// usersAPIResponses are the account users and teams API bodies, by path.
+var usersAPIResponses = map[string]string{
+	"/users":            `{"err":0,"result":{"users":[{"id":1,"username":"alice","email":"alice@example.com"}]}}`,
+	"/user/1":           `{"err":0,"result":{"id":1,"username":"alice","email":"alice@example.com","email_enabled":true}}`,
+	"/teams":            `{"err":0,"result":[{"id":10,"account_id":2,"name":"payments","access_level":"standard"}]}`,
+	"/team/10/users":    `{"err":0,"result":[{"team_id":10,"user_id":1}]}`,
+	"/team/10/projects": `{"err":0,"result":[{"team_id":10,"project_id":3}]}`,
+	"/team/10/invites":  `{"err":0,"result":[{"id":4,"team_id":10,"to_email":"bob@example.com","status":"pending"}]}`,
+}
+
+func newUsersTestClient(t *testing.T) *Client {
+	t.Helper()
+
+	return newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		body, ok := usersAPIResponses[r.URL.Path]
+		if !ok {
+			t.Fatalf("unexpected path: %s", r.URL.Path)
+		}
+		_, _ = fmt.Fprint(w, body)
+	})
+}
+
+func TestUsers(t *testing.T) {
+	t.Parallel()
+
+	client := newUsersTestClient(t)
+	ctx := context.Background()
+
+	users, err := client.ListUsers(ctx)
+	if err != nil || !reflect.DeepEqual(users, []User{{ID: 1, Username: "alice", Email: "alice@example.com"}}) {
+		t.Fatalf("ListUsers() = %+v, err=%v", users, err)
+	}
+	user, err := client.GetUser(ctx, 1)
+	if err != nil || !user.EmailEnabled {
+		t.Fatalf("GetUser() = %+v, err=%v", user, err)
+	}
+}
+
+func TestTeams(t *testing.T) {
+	t.Parallel()
+
+	client := newUsersTestClient(t)
+	ctx := context.Background()
+
+	teams, err := client.ListTeams(ctx)
+	if err != nil || !reflect.DeepEqual(teams, []Team{{ID: 10, AccountID: 2, Name: "payments", AccessLevel: "standard"}}) {
+		t.Fatalf("ListTeams() = %+v, err=%v", teams, err)
+	}
+	members, err := client.ListTeamUsers(ctx, 10)
+	if err != nil || !reflect.DeepEqual(members, []TeamUser{{TeamID: 10, UserID: 1}}) {
+		t.Fatalf("ListTeamUsers() = %+v, err=%v", members, err)
+	}
+	projects, err := client.ListTeamProjects(ctx, 10)
+	if err != nil || !reflect.DeepEqual(projects, []TeamProject{{TeamID: 10, ProjectID: 3}}) {
+		t.Fatalf("ListTeamProjects() = %+v, err=%v", projects, err)
+	}
+	invites, err := client.ListTeamInvites(ctx, 10)
+	if err != nil || !reflect.DeepEqual(invites, []Invite{{ID: 4, TeamID: 10, ToEmail: "bob@example.com", Status: "pending"}}) {
+		t.Fatalf("ListTeamInvites() = %+v, err=%v", invites, err)
+	}
+}
+
+func TestInviteAndAddUserToTeam(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		switch r.Method + " " + r.URL.Path {
+		case http.MethodPost + " /team/10/invites":
+			body, err := io.ReadAll(r.Body)
+			if err != nil {
+				t.Fatalf("ReadAll() error = %v", err)
+			}
+			if string(body) != `{"email":"bob@example.com"}` {
+				t.Fatalf("unexpected invite body: %s", body)
+			}
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"id":5,"team_id":10,"to_email":"bob@example.com","status":"pending"}}`)
+		case http.MethodPut + " /team/10/user/1":
+			_, _ = fmt.Fprint(w, `{"err":0,"result":{"team_id":10,"user_id":1}}`)
+		default:
+			t.Fatalf("unexpected request: %s %s", r.Method, r.URL.Path)
+		}
+	})
+	ctx := context.Background()
+
+	invite, err := client.InviteToTeam(ctx, 10, " bob@example.com ")
+	if err != nil || invite.ID != 5 {
+		t.Fatalf("InviteToTeam() = %+v, err=%v", invite, err)
+	}
+	if _, err := client.InviteToTeam(ctx, 10, ""); err == nil {
+		t.Fatalf("expected blank email error")
+	}
+	if err := client.AddUserToTeam(ctx, 10, 1); err != nil {
+		t.Fatalf("AddUserToTeam() error = %v", err)
+	}
+}
+
+func TestGetIntoDecodeError(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"not":"a list"}}`)
+	})
+
+	if _, err := client.ListTeams(context.Background()); err == nil {
+		t.Fatalf("expected decode error")
+	}
+}
+
+func TestFindUserByEmail(t *testing.T) {
+	t.Parallel()
+
+	client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+		_, _ = fmt.Fprint(w, `{"err":0,"result":{"users":[{"id":1,"email":"alice@example.com"},{"id":2,"email":"Bob@Example.com"}]}}`)
+	})
+	ctx := context.Background()
+
+	user, err := client.FindUserByEmail(ctx, "bob@example.com")
+	if err != nil || user.ID != 2 {
+		t.Fatalf("FindUserByEmail() = %+v, err=%v", user, err)
+	}
+	if _, err := client.FindUserByEmail(ctx, "carol@example.com"); err == nil {
+		t.Fatalf("expected missing user error")
+	}
+	if _, err := client.FindUserByEmail(ctx, " "); err == nil {
+		t.Fatalf("expected blank email error")
+	}
+}
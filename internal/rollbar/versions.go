@@ -0,0 +1,53 @@
+package rollbar
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+type ItemVersion struct {
+	Version                  string  `json:"version"`
+	Environment              string  `json:"environment"`
+	TotalOccurrences         uint64  `json:"total_occurrences"`
+	FirstOccurrenceID        *uint64 `json:"first_occurrence_id"`
+	FirstOccurrenceTimestamp *uint64 `json:"first_occurrence_timestamp"`
+	LastOccurrenceID         *uint64 `json:"last_occurrence_id"`
+	LastOccurrenceTimestamp  *uint64 `json:"last_occurrence_timestamp"`
+}
+
+type versionsEnvelope struct {
+	Versions []ItemVersion `json:"versions"`
+}
+
+// GetItemVersions returns per code_version occurrence counts for an item, which
+// shows the release that introduced or reintroduced it.
+func (c *Client) GetItemVersions(ctx context.Context, itemID domain.ItemID) ([]ItemVersion, error) {
+	raw, err := c.getResult(ctx, "/item/"+itemID.String()+"/versions", "item versions")
+	if err != nil {
+		return nil, err
+	}
+
+	versions, err := parseVersions(raw)
+	if err != nil {
+		return nil, c.wrap(err, "decode item versions")
+	}
+
+	return versions, nil
+}
+
+func parseVersions(raw json.RawMessage) ([]ItemVersion, error) {
+	var list []ItemVersion
+	if err := json.Unmarshal(raw, &list); err == nil {
+		return list, nil
+	}
+
+	var wrapped versionsEnvelope
+	if err := json.Unmarshal(raw, &wrapped); err != nil {
+		return nil, fmt.Errorf("decode wrapped versions: %w", err)
+	}
+
+	return wrapped.Versions, nil
+}
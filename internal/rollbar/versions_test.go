@@ -0,0 +1,52 @@
+package rollbar
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+func TestGetItemVersionsSupportsListAndWrapped(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name string
+		body string
+	}{
+		{name: "list shape", body: `{"err":0,"result":[{"version":"abc123","environment":"production","total_occurrences":4,"first_occurrence_timestamp":1700000000}]}`},
+		{name: "wrapped shape", body: `{"err":0,"result":{"item_id":5,"versions":[{"version":"abc123","environment":"production","total_occurrences":4}]}}`},
+	}
+
+	for _, tc := range tests {
+		tc := tc
+		t.Run(tc.name, func(t *testing.T) {
+			t.Parallel()
+
+			client := newTestClientWithHandler(t, func(w http.ResponseWriter, r *http.Request) {
+				if r.URL.Path != "/item/5/versions" {
+					t.Fatalf("unexpected path: %s", r.URL.Path)
+				}
+				_, _ = fmt.Fprint(w, tc.body)
+			})
+
+			versions, err := client.GetItemVersions(context.Background(), domain.ItemID(5))
+			if err != nil {
+				t.Fatalf("GetItemVersions() error = %v", err)
+			}
+			if len(versions) != 1 || versions[0].Version != "abc123" || versions[0].TotalOccurrences != 4 {
+				t.Fatalf("unexpected versions: %+v", versions)
+			}
+		})
+	}
+}
+
+func TestParseVersionsInvalid(t *testing.T) {
+	t.Parallel()
+
+	if _, err := parseVersions([]byte(`123`)); err == nil {
+		t.Fatalf("expected parse error")
+	}
+}
@@ -0,0 +1,82 @@
+package rollbartest
+
+import (
+	"encoding/json"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// Fixture timestamps, in unix seconds.
+const (
+	fixtureFirstSeen = 1767225600 // 2026-01-01T00:00:00Z
+	fixtureLastSeen  = 1767312000 // 2026-01-02T00:00:00Z
+)
+
+// Items are the items a new Server starts with: two active production items
+// with occurrences and a resolved staging item without any.
+func Items() []rollbar.Item {
+	return []rollbar.Item{
+		fixtureItem(1001, 1, "TypeError: Cannot read properties of undefined (reading 'id')", rollbar.StatusActive, rollbar.LevelError, "production", 2),
+		fixtureItem(1002, 2, "Timeout talking to payments", rollbar.StatusActive, rollbar.LevelCritical, "production", 1),
+		fixtureItem(1003, 3, "Deprecated config key cache_ttl", rollbar.StatusResolved, rollbar.LevelWarning, "staging", 0),
+	}
+}
+
+// Instances are the occurrences a new Server starts with, newest first, keyed
+// by item id.
+func Instances() map[domain.ItemID][]rollbar.ItemInstance {
+	return map[domain.ItemID][]rollbar.ItemInstance{
+		1001: {
+			fixtureInstance(5002, fixtureLastSeen, "TypeError", "Cannot read properties of undefined (reading 'id')"),
+			fixtureInstance(5001, fixtureFirstSeen, "TypeError", "Cannot read properties of undefined (reading 'id')"),
+		},
+		1002: {
+			fixtureInstance(5003, fixtureLastSeen, "TimeoutError", "payments did not answer within 5s"),
+		},
+	}
+}
+
+// RQLResult is what every RQL job on a new Server returns.
+func RQLResult() rollbar.RQLResult {
+	return rollbar.RQLResult{
+		Columns: []string{"item.counter", "count(*)"},
+		Rows: [][]json.RawMessage{
+			{json.RawMessage("1"), json.RawMessage("2")},
+			{json.RawMessage("2"), json.RawMessage("1")},
+		},
+		RowCount: 2,
+	}
+}
+
+func fixtureItem(id domain.ItemID, counter uint64, title string, status rollbar.Status, level rollbar.Level, environment string, occurrences uint64) rollbar.Item {
+	first, last := uint64(fixtureFirstSeen), uint64(fixtureLastSeen)
+
+	return rollbar.Item{
+		ID:                       id,
+		ProjectID:                1,
+		Counter:                  counter,
+		Title:                    title,
+		Status:                   status,
+		Level:                    level,
+		Environment:              environment,
+		FirstOccurrenceTimestamp: &first,
+		LastOccurrenceTimestamp:  &last,
+		Occurrences:              &occurrences,
+		TotalOccurrences:         &occurrences,
+	}
+}
+
+func fixtureInstance(id uint64, timestamp uint64, class string, message string) rollbar.ItemInstance {
+	data, _ := json.Marshal(map[string]any{
+		"environment": "production",
+		"body": map[string]any{
+			"trace": map[string]any{
+				"exception": map[string]string{"class": class, "message": message},
+				"frames":    []map[string]any{{"filename": "app/checkout.js", "lineno": 42, "method": "submit"}},
+			},
+		},
+	})
+
+	return rollbar.ItemInstance{ID: id, Timestamp: &timestamp, Data: data}
+}
@@ -0,0 +1,184 @@
+package rollbartest
+
+import (
+	"cmp"
+	"encoding/json"
+	"net/http"
+	"slices"
+	"strconv"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func (s *Server) getItem(w http.ResponseWriter, r *http.Request) {
+	id, ok := pathID(w, r, "id")
+	if !ok {
+		return
+	}
+
+	s.withItem(w, func(item rollbar.Item) bool { return uint64(item.ID) == id }, func(item *rollbar.Item) {
+		writeResult(w, item)
+	})
+}
+
+// updateItem applies the status, level, and title of a patch.
+func (s *Server) updateItem(w http.ResponseWriter, r *http.Request) {
+	id, ok := pathID(w, r, "id")
+	if !ok {
+		return
+	}
+	var patch rollbar.ItemPatch
+	if err := json.NewDecoder(r.Body).Decode(&patch); err != nil {
+		writeError(w, http.StatusBadRequest, "invalid JSON body")
+		return
+	}
+
+	s.withItem(w, func(item rollbar.Item) bool { return uint64(item.ID) == id }, func(item *rollbar.Item) {
+		if patch.Status != "" {
+			item.Status = patch.Status
+		}
+		if patch.Level != "" {
+			item.Level = patch.Level
+		}
+		if patch.Title != "" {
+			item.Title = patch.Title
+		}
+		writeResult(w, item)
+	})
+}
+
+func (s *Server) itemByCounter(w http.ResponseWriter, r *http.Request) {
+	counter, ok := pathID(w, r, "counter")
+	if !ok {
+		return
+	}
+
+	s.withItem(w, func(item rollbar.Item) bool { return item.Counter == counter }, func(item *rollbar.Item) {
+		writeResult(w, map[string]any{"itemId": item.ID})
+	})
+}
+
+// withItem runs found on the first item matching match under the lock, or
+// answers 404.
+func (s *Server) withItem(w http.ResponseWriter, match func(rollbar.Item) bool, found func(*rollbar.Item)) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	index := slices.IndexFunc(s.items, match)
+	if index < 0 {
+		writeError(w, http.StatusNotFound, "item not found")
+		return
+	}
+	found(&s.items[index])
+}
+
+// listItems filters by status, level, and environment, newest counter first.
+func (s *Server) listItems(w http.ResponseWriter, r *http.Request) {
+	query := r.URL.Query()
+
+	s.mu.Lock()
+	matched := make([]rollbar.Item, 0, len(s.items))
+	for _, item := range s.items {
+		if matches(query["status"], string(item.Status)) && matches(query["level"], string(item.Level)) && matches(query["environment"], item.Environment) {
+			matched = append(matched, item)
+		}
+	}
+	s.mu.Unlock()
+
+	slices.SortFunc(matched, func(a, b rollbar.Item) int { return cmp.Compare(b.Counter, a.Counter) })
+	number, _ := strconv.Atoi(query.Get("page"))
+	writeResult(w, map[string]any{
+		"items":       page(matched, r, itemsPerPage),
+		"page":        max(number, 1),
+		"total_count": len(matched),
+	})
+}
+
+func matches(allowed []string, value string) bool {
+	return len(allowed) == 0 || slices.Contains(allowed, value)
+}
+
+func (s *Server) listInstances(w http.ResponseWriter, r *http.Request) {
+	id, ok := pathID(w, r, "id")
+	if !ok {
+		return
+	}
+	size, err := strconv.Atoi(r.URL.Query().Get("per_page"))
+	if err != nil || size < 1 {
+		size = instancesPerPage
+	}
+
+	s.mu.Lock()
+	instances := page(s.instances[domain.ItemID(id)], r, size)
+	writeResult(w, map[string]any{"instances": instances})
+	s.mu.Unlock()
+}
+
+func (s *Server) getInstance(w http.ResponseWriter, r *http.Request) {
+	id, ok := pathID(w, r, "id")
+	if !ok {
+		return
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	for _, instances := range s.instances {
+		if index := slices.IndexFunc(instances, func(instance rollbar.ItemInstance) bool { return instance.ID == id }); index >= 0 {
+			writeResult(w, instances[index])
+			return
+		}
+	}
+	writeError(w, http.StatusNotFound, "instance not found")
+}
+
+func (s *Server) createRQLJob(w http.ResponseWriter, r *http.Request) {
+	var request struct {
+		QueryString string `json:"query_string"`
+	}
+	if err := json.NewDecoder(r.Body).Decode(&request); err != nil || request.QueryString == "" {
+		writeError(w, http.StatusBadRequest, "query_string is required")
+		return
+	}
+
+	s.mu.Lock()
+	s.rqlJobs = append(s.rqlJobs, request.QueryString)
+	job := rollbar.RQLJob{ID: uint64(len(s.rqlJobs)), ProjectID: 1, QueryString: request.QueryString, Status: rollbar.RQLStatusNew}
+	s.mu.Unlock()
+
+	writeResult(w, job)
+}
+
+func (s *Server) getRQLJob(w http.ResponseWriter, r *http.Request) {
+	if job, ok := s.rqlJob(w, r); ok {
+		writeResult(w, job)
+	}
+}
+
+func (s *Server) getRQLResult(w http.ResponseWriter, r *http.Request) {
+	job, ok := s.rqlJob(w, r)
+	if !ok {
+		return
+	}
+
+	result := RQLResult()
+	result.JobID = job.ID
+	writeResult(w, map[string]any{"job_id": job.ID, "result": result})
+}
+
+// rqlJob looks up a job by its path id; every job has already succeeded.
+func (s *Server) rqlJob(w http.ResponseWriter, r *http.Request) (rollbar.RQLJob, bool) {
+	id, ok := pathID(w, r, "id")
+	if !ok {
+		return rollbar.RQLJob{}, false
+	}
+
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if id == 0 || id > uint64(len(s.rqlJobs)) {
+		writeError(w, http.StatusNotFound, "job not found")
+		return rollbar.RQLJob{}, false
+	}
+
+	return rollbar.RQLJob{ID: id, ProjectID: 1, QueryString: s.rqlJobs[id-1], Status: rollbar.RQLStatusSuccess}, true
+}
@@ -0,0 +1,173 @@
+// Package rollbartest runs an in-memory fake of the Rollbar REST API, so code
+// built on the rollbar client can be tested without the real service or a
+// real token. NewServer starts it seeded with the fixtures in this package;
+// AddItem, AddInstances, and Fail tailor it per test.
+package rollbartest
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"slices"
+	"strconv"
+	"sync"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// Token is the only access token the fake accepts.
+const Token = "rollbartest-token"
+
+// Page sizes match Rollbar's defaults.
+const (
+	itemsPerPage     = 100
+	instancesPerPage = 20
+)
+
+// Server is the fake API. It supports items (get, list, update, lookup by
+// counter), occurrences (per item and by id), and RQL jobs, which finish
+// immediately with RQLResult. Everything else is a 404 error envelope.
+type Server struct {
+	*httptest.Server
+
+	mu        sync.Mutex
+	items     []rollbar.Item
+	instances map[domain.ItemID][]rollbar.ItemInstance
+	rqlJobs   []string
+	failures  map[string]failure
+	requests  []string
+}
+
+type failure struct {
+	status  int
+	message string
+}
+
+// NewServer starts a fake seeded with Items and Instances and closes it when
+// the test ends.
+func NewServer(t testing.TB) *Server {
+	t.Helper()
+
+	server := &Server{items: Items(), instances: Instances(), failures: map[string]failure{}}
+	server.Server = httptest.NewServer(server.routes())
+	t.Cleanup(server.Close)
+
+	return server
+}
+
+// Client returns a rollbar client for the fake with retries off, so injected
+// failures surface on the first attempt. options are applied after those
+// defaults.
+func (s *Server) Client(t testing.TB, options ...rollbar.Option) *rollbar.Client {
+	t.Helper()
+
+	defaults := []rollbar.Option{rollbar.WithBaseURL(s.URL), rollbar.WithRetryPolicy(rollbar.RetryPolicy{MaxAttempts: 1})}
+	client, err := rollbar.NewWithOptions(Token, append(defaults, options...)...)
+	if err != nil {
+		t.Fatalf("rollbartest: new client: %v", err)
+	}
+
+	return client
+}
+
+// AddItem adds an item, or replaces the one with the same id.
+func (s *Server) AddItem(item rollbar.Item) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.items = slices.DeleteFunc(s.items, func(existing rollbar.Item) bool { return existing.ID == item.ID })
+	s.items = append(s.items, item)
+}
+
+// AddInstances prepends occurrences to an item's list, so pass them newest
+// first.
+func (s *Server) AddInstances(itemID domain.ItemID, instances ...rollbar.ItemInstance) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.instances[itemID] = append(slices.Clone(instances), s.instances[itemID]...)
+}
+
+// Fail makes every request for method and path, without its query string,
+// answer with status and a Rollbar error envelope carrying message.
+func (s *Server) Fail(method string, path string, status int, message string) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	s.failures[method+" "+path] = failure{status: status, message: message}
+}
+
+// Requests lists the requests served so far as "METHOD /path?query".
+func (s *Server) Requests() []string {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	return slices.Clone(s.requests)
+}
+
+func (s *Server) routes() http.Handler {
+	mux := http.NewServeMux()
+	mux.HandleFunc("GET /item/{id}/{$}", s.getItem)
+	mux.HandleFunc("PATCH /item/{id}", s.updateItem)
+	mux.HandleFunc("GET /item_by_counter/{counter}", s.itemByCounter)
+	mux.HandleFunc("GET /items", s.listItems)
+	mux.HandleFunc("GET /item/{id}/instances", s.listInstances)
+	mux.HandleFunc("GET /instance/{id}", s.getInstance)
+	mux.HandleFunc("POST /rql/jobs", s.createRQLJob)
+	mux.HandleFunc("GET /rql/job/{id}", s.getRQLJob)
+	mux.HandleFunc("GET /rql/job/{id}/result", s.getRQLResult)
+	mux.HandleFunc("/", func(w http.ResponseWriter, _ *http.Request) {
+		writeError(w, http.StatusNotFound, "not found")
+	})
+
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		s.mu.Lock()
+		s.requests = append(s.requests, r.Method+" "+r.URL.RequestURI())
+		injected, failing := s.failures[r.Method+" "+r.URL.Path]
+		s.mu.Unlock()
+
+		switch {
+		case r.Header.Get("X-Rollbar-Access-Token") != Token:
+			writeError(w, http.StatusUnauthorized, "invalid access token")
+		case failing:
+			writeError(w, injected.status, injected.message)
+		default:
+			mux.ServeHTTP(w, r)
+		}
+	})
+}
+
+func writeResult(w http.ResponseWriter, result any) {
+	w.Header().Set("Content-Type", "application/json")
+	_ = json.NewEncoder(w).Encode(map[string]any{"err": 0, "result": result})
+}
+
+func writeError(w http.ResponseWriter, status int, message string) {
+	w.Header().Set("Content-Type", "application/json")
+	w.WriteHeader(status)
+	_ = json.NewEncoder(w).Encode(map[string]any{"err": 1, "message": message})
+}
+
+// pathID parses a numeric path segment, answering 404 when it is not one.
+func pathID(w http.ResponseWriter, r *http.Request, name string) (uint64, bool) {
+	id, err := strconv.ParseUint(r.PathValue(name), 10, 64)
+	if err != nil {
+		writeError(w, http.StatusNotFound, "not found")
+		return 0, false
+	}
+
+	return id, true
+}
+
+// page returns the 1-based page of values, size per page.
+func page[T any](values []T, r *http.Request, size int) []T {
+	number, err := strconv.Atoi(r.URL.Query().Get("page"))
+	if err != nil || number < 1 {
+		number = 1
+	}
+
+	start := min((number-1)*size, len(values))
+	return values[start:min(start+size, len(values))]
+}
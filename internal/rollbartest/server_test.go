@@ -0,0 +1,136 @@
+package rollbartest
+
+import (
+	"context"
+	"errors"
+	"net/http"
+	"slices"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func TestServerUpdateItem(t *testing.T) {
+	t.Parallel()
+
+	server := NewServer(t)
+	client := server.Client(t)
+	ctx := context.Background()
+
+	id, err := client.ResolveItemIDByCounter(ctx, domain.ItemCounter(2))
+	if err != nil || id != 1002 {
+		t.Fatalf("ResolveItemIDByCounter() = %d, %v", id, err)
+	}
+	if err := client.UpdateItem(ctx, id, rollbar.ItemPatch{Status: rollbar.StatusResolved}); err != nil {
+		t.Fatalf("UpdateItem() error = %v", err)
+	}
+	if item, err := client.GetItem(ctx, id); err != nil || item.Status != rollbar.StatusResolved || item.Title != "Timeout talking to payments" {
+		t.Fatalf("GetItem() = %+v, %v", item, err)
+	}
+}
+
+func TestServerListItems(t *testing.T) {
+	t.Parallel()
+
+	server := NewServer(t)
+	client := server.Client(t)
+	ctx := context.Background()
+
+	if err := client.UpdateItem(ctx, domain.ItemID(1002), rollbar.ItemPatch{Status: rollbar.StatusResolved}); err != nil {
+		t.Fatalf("UpdateItem() error = %v", err)
+	}
+	server.AddItem(rollbar.Item{ID: 1004, Counter: 4, Title: "new", Status: rollbar.StatusActive, Environment: "production"})
+	tests := []struct {
+		query rollbar.ItemQuery
+		want  []domain.ItemID
+	}{
+		{query: rollbar.ItemQuery{}, want: []domain.ItemID{1004, 1003, 1002, 1001}},
+		{query: rollbar.ItemQuery{Status: "active"}, want: []domain.ItemID{1004, 1001}},
+		{query: rollbar.ItemQuery{Environments: []string{"staging"}}, want: []domain.ItemID{1003}},
+		{query: rollbar.ItemQuery{Page: 2}, want: []domain.ItemID{}},
+	}
+	for _, tc := range tests {
+		page, err := client.ListItemsPage(ctx, tc.query)
+		if err != nil {
+			t.Fatalf("%+v: ListItemsPage() error = %v", tc.query, err)
+		}
+		got := make([]domain.ItemID, 0, len(page.Items))
+		for _, item := range page.Items {
+			got = append(got, item.ID)
+		}
+		if !slices.Equal(got, tc.want) || page.TotalCount != uint64(len(tc.want)) && tc.query.Page == 0 {
+			t.Fatalf("%+v: got %v (total %d), want %v", tc.query, got, page.TotalCount, tc.want)
+		}
+	}
+}
+
+func TestServerInstances(t *testing.T) {
+	t.Parallel()
+
+	server := NewServer(t)
+	client := server.Client(t)
+	ctx := context.Background()
+
+	latest, err := client.GetLatestInstance(ctx, domain.ItemID(1001))
+	if err != nil || latest == nil || latest.ID != 5002 {
+		t.Fatalf("GetLatestInstance() = %+v, %v", latest, err)
+	}
+	server.AddInstances(1003, rollbar.ItemInstance{ID: 6001})
+	if page, err := client.ListInstances(ctx, domain.ItemID(1003), 1); err != nil || len(page.Instances) != 1 {
+		t.Fatalf("ListInstances() = %+v, %v", page, err)
+	}
+	if instance, err := client.GetInstance(ctx, domain.InstanceID(5003)); err != nil || instance.ID != 5003 {
+		t.Fatalf("GetInstance() = %+v, %v", instance, err)
+	}
+	if _, err := client.GetInstance(ctx, domain.InstanceID(9)); !rollbar.IsNotFound(err) {
+		t.Fatalf("expected a missing instance, got %v", err)
+	}
+}
+
+func TestServerRQL(t *testing.T) {
+	t.Parallel()
+
+	client := NewServer(t).Client(t)
+	result, err := client.RunRQL(context.Background(), "select item.counter, count(*) from item_occurrence group by 1")
+	if err != nil || result.JobID != 1 || result.RowCount != 2 {
+		t.Fatalf("RunRQL() = %+v, %v", result, err)
+	}
+}
+
+func TestServerFailuresAndAuth(t *testing.T) {
+	t.Parallel()
+
+	server := NewServer(t)
+	ctx := context.Background()
+
+	server.Fail(http.MethodGet, "/item/1001/", http.StatusServiceUnavailable, "maintenance")
+	if _, err := server.Client(t).GetItem(ctx, domain.ItemID(1001)); statusOf(err) != http.StatusServiceUnavailable {
+		t.Fatalf("expected the injected failure, got %v", err)
+	}
+
+	intruder, err := rollbar.NewWithOptions("wrong", rollbar.WithBaseURL(server.URL), rollbar.WithRetryPolicy(rollbar.RetryPolicy{MaxAttempts: 1}))
+	if err != nil {
+		t.Fatalf("NewWithOptions() error = %v", err)
+	}
+	if _, err := intruder.GetItem(ctx, domain.ItemID(1002)); statusOf(err) != http.StatusUnauthorized {
+		t.Fatalf("expected an auth failure, got %v", err)
+	}
+	if _, err := server.Client(t).GetDeploy(ctx, 1); !rollbar.IsNotFound(err) {
+		t.Fatalf("expected unsupported endpoints to 404, got %v", err)
+	}
+
+	want := []string{"GET /item/1001/", "GET /item/1002/", "GET /deploy/1"}
+	if got := server.Requests(); !slices.Equal(got, want) {
+		t.Fatalf("Requests() = %v, want %v", got, want)
+	}
+}
+
+func statusOf(err error) int {
+	var apiErr *rollbar.Error
+	if !errors.As(err, &apiErr) {
+		return 0
+	}
+
+	return apiErr.StatusCode
+}
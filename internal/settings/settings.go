@@ -0,0 +1,219 @@
+// Package settings reads the project settings files behind config diff and
+// config apply: the notification rules and access tokens a Rollbar project
+// should have, written in YAML.
+package settings
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"slices"
+	"strconv"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// envReference is ${NAME} in a string value, replaced by the environment
+// variable so secrets such as PagerDuty service keys stay out of the file.
+var envReference = regexp.MustCompile(`\$\{([A-Za-z_][A-Za-z0-9_]*)\}`)
+
+// File is a decoded settings file:
+//
+//	project: billing-api
+//	notifications:
+//	  slack:
+//	    - trigger: new_item
+//	      filters:
+//	        - {type: environment, operation: eq, value: production}
+//	      config:
+//	        channel: "#alerts"
+//	  pagerduty:
+//	    - trigger: new_item
+//	      config:
+//	        service_key: ${PAGERDUTY_SERVICE_KEY}
+//	access_tokens:
+//	  - name: ci
+//	    scopes: [post_server_item]
+//	    rate_limit_window_size: 60
+//	    rate_limit_window_count: 1000
+//
+// Each channel under notifications lists all of its rules; channels left out
+// are not managed. Access tokens are matched by name and never deleted.
+type File struct {
+	Project       string                                `json:"project,omitempty"`
+	Notifications map[string][]rollbar.NotificationRule `json:"notifications,omitempty"`
+	AccessTokens  []AccessToken                         `json:"access_tokens,omitempty"`
+}
+
+// AccessToken is a project access token the project should have. The rate
+// limit allows RateLimitWindowCount calls per RateLimitWindowSize seconds and
+// is only managed when both are set.
+type AccessToken struct {
+	Name                 string   `json:"name"`
+	Scopes               []string `json:"scopes"`
+	RateLimitWindowSize  *uint64  `json:"rate_limit_window_size,omitempty"`
+	RateLimitWindowCount *uint64  `json:"rate_limit_window_count,omitempty"`
+}
+
+// Load reads the settings file at path, filling ${NAME} references from the
+// environment.
+func Load(path string) (File, error) {
+	body, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return File{}, fmt.Errorf("read settings: %w", err)
+	}
+
+	file, err := Parse(body, os.LookupEnv)
+	if err != nil {
+		return File{}, fmt.Errorf("parse %s: %w", path, err)
+	}
+
+	return file, nil
+}
+
+// Parse decodes and validates a settings file, resolving ${NAME} references
+// in string values with lookupEnv.
+func Parse(body []byte, lookupEnv func(string) (string, bool)) (File, error) {
+	tree, err := decodeYAML(body)
+	if err != nil {
+		return File{}, err
+	}
+	root, ok := tree.(map[string]any)
+	if !ok {
+		return File{}, errors.New("settings must be a mapping of project, notifications, and access_tokens")
+	}
+	if _, ok := root["grouping"]; ok {
+		return File{}, errors.New("grouping: Rollbar has no API for custom grouping rules; manage them in the project settings page")
+	}
+	if id, ok := root["project"].(int64); ok {
+		// A numeric project id reads as a number; project is a string.
+		root["project"] = strconv.FormatInt(id, 10)
+	}
+	expanded, err := expandEnv(root, lookupEnv)
+	if err != nil {
+		return File{}, err
+	}
+
+	encoded, err := json.Marshal(expanded)
+	if err != nil {
+		return File{}, fmt.Errorf("encode settings: %w", err)
+	}
+	decoder := json.NewDecoder(bytes.NewReader(encoded))
+	decoder.DisallowUnknownFields()
+	var file File
+	if err := decoder.Decode(&file); err != nil {
+		return File{}, fmt.Errorf("decode settings: %w", err)
+	}
+
+	if err := file.normalize(); err != nil {
+		return File{}, err
+	}
+
+	return file, nil
+}
+
+func expandEnv(value any, lookupEnv func(string) (string, bool)) (any, error) {
+	switch typed := value.(type) {
+	case string:
+		return expandString(typed, lookupEnv)
+	case map[string]any:
+		for key, nested := range typed {
+			expanded, err := expandEnv(nested, lookupEnv)
+			if err != nil {
+				return nil, err
+			}
+			typed[key] = expanded
+		}
+	case []any:
+		for index, nested := range typed {
+			expanded, err := expandEnv(nested, lookupEnv)
+			if err != nil {
+				return nil, err
+			}
+			typed[index] = expanded
+		}
+	}
+
+	return value, nil
+}
+
+func expandString(value string, lookupEnv func(string) (string, bool)) (string, error) {
+	var missing []string
+	expanded := envReference.ReplaceAllStringFunc(value, func(reference string) string {
+		name := envReference.FindStringSubmatch(reference)[1]
+		resolved, ok := lookupEnv(name)
+		if !ok {
+			missing = append(missing, name)
+		}
+		return resolved
+	})
+	if len(missing) > 0 {
+		return "", fmt.Errorf("environment variable %s is not set", strings.Join(missing, ", "))
+	}
+
+	return expanded, nil
+}
+
+// normalize lowercases channel names and checks what Rollbar would reject.
+func (f *File) normalize() error {
+	f.Project = strings.TrimSpace(f.Project)
+	channels := make(map[string][]rollbar.NotificationRule, len(f.Notifications))
+	for name, rules := range f.Notifications {
+		channel := strings.ToLower(strings.TrimSpace(name))
+		if !slices.Contains(rollbar.NotificationChannels, channel) {
+			return fmt.Errorf("notifications: unsupported channel %q (use %s)", name, strings.Join(rollbar.NotificationChannels, ", "))
+		}
+		if err := normalizeRules(channel, rules); err != nil {
+			return err
+		}
+		channels[channel] = rules
+	}
+	f.Notifications = channels
+
+	names := map[string]bool{}
+	for index := range f.AccessTokens {
+		token := &f.AccessTokens[index]
+		token.Name = strings.TrimSpace(token.Name)
+		if err := token.validate(names); err != nil {
+			return err
+		}
+		names[token.Name] = true
+	}
+
+	return nil
+}
+
+func normalizeRules(channel string, rules []rollbar.NotificationRule) error {
+	for index := range rules {
+		rules[index].ID = 0
+		rules[index].Trigger = strings.TrimSpace(rules[index].Trigger)
+		if rules[index].Trigger == "" {
+			return fmt.Errorf("notifications.%s[%d]: trigger is required", channel, index)
+		}
+		if rules[index].Filters == nil {
+			rules[index].Filters = []rollbar.NotificationFilter{}
+		}
+	}
+
+	return nil
+}
+
+func (t AccessToken) validate(seen map[string]bool) error {
+	switch {
+	case t.Name == "":
+		return errors.New("access_tokens: name is required")
+	case seen[t.Name]:
+		return fmt.Errorf("access_tokens: duplicate name %q", t.Name)
+	case len(t.Scopes) == 0:
+		return fmt.Errorf("access_tokens.%s: scopes are required", t.Name)
+	case (t.RateLimitWindowSize == nil) != (t.RateLimitWindowCount == nil):
+		return fmt.Errorf("access_tokens.%s: set both rate_limit_window_size and rate_limit_window_count", t.Name)
+	}
+
+	return nil
+}
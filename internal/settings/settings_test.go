@@ -0,0 +1,115 @@
+package settings
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func lookup(values map[string]string) func(string) (string, bool) {
+	return func(name string) (string, bool) {
+		value, ok := values[name]
+		return value, ok
+	}
+}
+
+const parseBody = `project: billing-api
+notifications:
+  Slack:
+    - trigger: new_item
+      config:
+        channel: "#alerts"
+  pagerduty:
+    - trigger: deploy
+      filters: [{type: environment, operation: eq, value: production}]
+      config:
+        service_key: ${PD_KEY}
+  email: []
+access_tokens:
+  - name: ci
+    scopes: [post_server_item]
+    rate_limit_window_size: 60
+    rate_limit_window_count: 1000
+`
+
+// checkParsedNotifications fails unless file holds the slack and pagerduty
+// rules of parseBody with the service key expanded.
+func checkParsedNotifications(t *testing.T, file File) {
+	t.Helper()
+	slack := file.Notifications["slack"]
+	if len(slack) != 1 || slack[0].Trigger != "new_item" || slack[0].Filters == nil || slack[0].Config["channel"] != "#alerts" {
+		t.Fatalf("unexpected slack rules: %+v", slack)
+	}
+	pagerduty := file.Notifications["pagerduty"]
+	if pagerduty[0].Config["service_key"] != "pd-secret" || pagerduty[0].Filters[0].Value != "production" {
+		t.Fatalf("unexpected pagerduty rules: %+v", pagerduty)
+	}
+}
+
+func TestParse(t *testing.T) {
+	t.Parallel()
+
+	file, err := Parse([]byte(parseBody), lookup(map[string]string{"PD_KEY": "pd-secret"}))
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+	if file.Project != "billing-api" || len(file.Notifications) != 3 || len(file.Notifications["email"]) != 0 {
+		t.Fatalf("unexpected settings: %+v", file)
+	}
+	checkParsedNotifications(t, file)
+	token := file.AccessTokens[0]
+	if token.Name != "ci" || token.Scopes[0] != "post_server_item" || *token.RateLimitWindowSize != 60 || *token.RateLimitWindowCount != 1000 {
+		t.Fatalf("unexpected access token: %+v", token)
+	}
+}
+
+func TestParseErrors(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name string
+		body string
+		want string
+	}{
+		{name: "not a mapping", body: "- a", want: "settings must be a mapping"},
+		{name: "grouping", body: "grouping:\n  - fingerprint: x", want: "no API for custom grouping rules"},
+		{name: "unknown key", body: "alerts: []", want: `unknown field "alerts"`},
+		{name: "channel", body: "notifications:\n  sms: []", want: `unsupported channel "sms"`},
+		{name: "trigger", body: "notifications:\n  slack:\n    - config: {channel: x}", want: "notifications.slack[0]: trigger is required"},
+		{name: "env", body: "notifications:\n  pagerduty:\n    - trigger: new_item\n      config: {service_key: '${MISSING}'}", want: "MISSING is not set"},
+		{name: "token name", body: "access_tokens:\n  - scopes: [read]", want: "name is required"},
+		{name: "duplicate token", body: "access_tokens:\n  - {name: ci, scopes: [read]}\n  - {name: ci, scopes: [write]}", want: `duplicate name "ci"`},
+		{name: "scopes", body: "access_tokens:\n  - name: ci", want: "access_tokens.ci: scopes are required"},
+		{name: "rate limit", body: "access_tokens:\n  - {name: ci, scopes: [read], rate_limit_window_size: 60}", want: "set both"},
+		{name: "type", body: "project: [a]", want: "decode settings"},
+	}
+	for _, tc := range tests {
+		if _, err := Parse([]byte(tc.body), lookup(nil)); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%s: expected %q error, got %v", tc.name, tc.want, err)
+		}
+	}
+}
+
+func TestLoad(t *testing.T) {
+	t.Parallel()
+
+	path := filepath.Join(t.TempDir(), "settings.yaml")
+	if err := os.WriteFile(path, []byte("project: web\n"), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	file, err := Load(path)
+	if err != nil || file.Project != "web" {
+		t.Fatalf("Load() = %+v, %v", file, err)
+	}
+	if err := os.WriteFile(path, []byte("project: 42\n"), 0o600); err != nil {
+		t.Fatalf("WriteFile() error = %v", err)
+	}
+	file, err = Load(path)
+	if err != nil || file.Project != "42" {
+		t.Fatalf("Load() = %+v, %v", file, err)
+	}
+	if _, err := Load(filepath.Join(t.TempDir(), "missing.yaml")); err == nil || !strings.Contains(err.Error(), "read settings") {
+		t.Fatalf("expected a read error, got %v", err)
+	}
+}
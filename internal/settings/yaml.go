@@ -0,0 +1,431 @@
+package settings
+
+import (
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+)
+
+// The settings file is read with a small YAML subset rather than a YAML
+// library: block mappings and sequences, flow collections on one line, plain
+// and quoted scalars, and comments. Everything else (anchors and aliases,
+// tags, block scalars, directives, explicit and merge keys, and more than one
+// document) is rejected with the line it is on, never read as plain text.
+
+var (
+	yamlInteger = regexp.MustCompile(`^[-+]?[0-9]+$`)
+	yamlFloat   = regexp.MustCompile(`^[-+]?([0-9]+\.[0-9]*|\.[0-9]+)([eE][-+]?[0-9]+)?$`)
+)
+
+// yamlIndicators are the characters that open a construct outside the subset
+// when a key or value starts with them.
+var yamlIndicators = map[byte]string{
+	'|': "block scalar",
+	'>': "block scalar",
+	'&': "anchor",
+	'*': "alias",
+	'!': "tag",
+	'%': "directive",
+	'@': "reserved indicator",
+	'`': "reserved indicator",
+}
+
+type yamlLine struct {
+	number int
+	indent int
+	text   string
+}
+
+type yamlParser struct {
+	lines []yamlLine
+	pos   int
+}
+
+// decodeYAML reads body into maps, slices, strings, int64s, float64s, bools,
+// and nils.
+func decodeYAML(body []byte) (any, error) {
+	lines, err := yamlLines(string(body))
+	if err != nil {
+		return nil, err
+	}
+	if len(lines) == 0 {
+		return map[string]any{}, nil
+	}
+
+	parser := &yamlParser{lines: lines}
+	value, err := parser.block(lines[0].indent)
+	if err != nil {
+		return nil, err
+	}
+	if parser.pos < len(lines) {
+		return nil, parser.errorf("unexpected indentation")
+	}
+
+	return value, nil
+}
+
+func yamlLines(body string) ([]yamlLine, error) {
+	lines := make([]yamlLine, 0)
+	for index, raw := range strings.Split(strings.ReplaceAll(body, "\r\n", "\n"), "\n") {
+		text := strings.TrimRight(stripYAMLComment(raw), " \t")
+		trimmed := strings.TrimLeft(text, " ")
+		switch {
+		case trimmed == "" || (text == "---" && len(lines) == 0):
+			continue
+		case text == "---":
+			return nil, fmt.Errorf("line %d: multiple YAML documents are not supported", index+1)
+		case text == "...":
+			return nil, fmt.Errorf("line %d: YAML document end markers are not supported", index+1)
+		case strings.HasPrefix(text, "%"):
+			return nil, fmt.Errorf("line %d: %w", index+1, checkYAMLSupported(text))
+		case strings.HasPrefix(trimmed, "\t"):
+			return nil, fmt.Errorf("line %d: indent with spaces, not tabs", index+1)
+		}
+		lines = append(lines, yamlLine{number: index + 1, indent: len(text) - len(trimmed), text: trimmed})
+	}
+
+	return lines, nil
+}
+
+// stripYAMLComment drops a # that starts the line or follows whitespace,
+// outside quotes, and everything after it.
+func stripYAMLComment(line string) string {
+	var quote rune
+	for index, char := range line {
+		switch {
+		case quote != 0:
+			if char == quote {
+				quote = 0
+			}
+		case isQuote(char) && opensQuote(line, index):
+			quote = char
+		case char == '#' && (index == 0 || strings.ContainsRune(" \t", rune(line[index-1]))):
+			return line[:index]
+		}
+	}
+
+	return line
+}
+
+// opensQuote reports whether the quote at index starts a quoted scalar rather
+// than sitting inside plain text, as in don't.
+func opensQuote(text string, index int) bool {
+	return index == 0 || strings.ContainsRune(" \t[{,:", rune(text[index-1]))
+}
+
+func (p *yamlParser) errorf(format string, args ...any) error {
+	line := p.lines[min(p.pos, len(p.lines)-1)].number
+	return fmt.Errorf("line %d: %s", line, fmt.Sprintf(format, args...))
+}
+
+func (p *yamlParser) block(indent int) (any, error) {
+	if isSequenceItem(p.lines[p.pos].text) {
+		return p.sequence(indent)
+	}
+
+	return p.mapping(indent)
+}
+
+func isSequenceItem(text string) bool {
+	return text == "-" || strings.HasPrefix(text, "- ")
+}
+
+func (p *yamlParser) mapping(indent int) (map[string]any, error) {
+	values := map[string]any{}
+	for p.pos < len(p.lines) && p.lines[p.pos].indent == indent && !isSequenceItem(p.lines[p.pos].text) {
+		key, rest, err := p.mappingKey()
+		if err != nil {
+			return nil, err
+		}
+		if _, seen := values[key]; seen {
+			return nil, p.errorf("duplicate key %q", key)
+		}
+		value, err := p.entryValue(indent, rest, false)
+		if err != nil {
+			return nil, err
+		}
+		values[key] = value
+	}
+	if p.pos < len(p.lines) && p.lines[p.pos].indent > indent {
+		return nil, p.errorf("unexpected indentation")
+	}
+
+	return values, nil
+}
+
+// mappingKey splits the current line into its key and the rest.
+func (p *yamlParser) mappingKey() (string, string, error) {
+	text := p.lines[p.pos].text
+	if err := checkYAMLSupported(text); err != nil {
+		return "", "", p.errorf("%v", err)
+	}
+	key, rest, ok := splitYAMLKey(text)
+	if !ok {
+		return "", "", p.errorf("expected key: value, got %q", text)
+	}
+	if err := checkYAMLSupported(key); err != nil {
+		return "", "", p.errorf("%v", err)
+	}
+
+	return key, rest, nil
+}
+
+func (p *yamlParser) sequence(indent int) ([]any, error) {
+	values := []any{}
+	for p.pos < len(p.lines) && p.lines[p.pos].indent == indent && isSequenceItem(p.lines[p.pos].text) {
+		value, err := p.sequenceItem(indent)
+		if err != nil {
+			return nil, err
+		}
+		values = append(values, value)
+	}
+	if p.pos < len(p.lines) && p.lines[p.pos].indent > indent {
+		return nil, p.errorf("unexpected indentation")
+	}
+
+	return values, nil
+}
+
+// sequenceItem reads the "- " entry on the current line.
+func (p *yamlParser) sequenceItem(indent int) (any, error) {
+	line := p.lines[p.pos]
+	rest := strings.TrimLeft(strings.TrimPrefix(line.text, "-"), " ")
+	if err := checkYAMLSupported(rest); err != nil {
+		return nil, p.errorf("%v", err)
+	}
+	if _, _, ok := splitYAMLKey(rest); ok && !isFlow(rest) {
+		// "- key: value" opens a mapping whose keys line up with key.
+		p.lines[p.pos] = yamlLine{number: line.number, indent: line.indent + len(line.text) - len(rest), text: rest}
+		return p.mapping(p.lines[p.pos].indent)
+	}
+
+	return p.entryValue(indent, rest, true)
+}
+
+// entryValue reads the value of the entry on the current line: rest when it
+// is there, else the indented block below it. A mapping's sequence may also
+// sit at the mapping's own indent.
+func (p *yamlParser) entryValue(indent int, rest string, inSequence bool) (any, error) {
+	p.pos++
+	if rest != "" {
+		return p.scalar(rest)
+	}
+	if p.pos == len(p.lines) {
+		return nil, nil
+	}
+
+	next := p.lines[p.pos]
+	if next.indent > indent || (!inSequence && next.indent == indent && isSequenceItem(next.text)) {
+		return p.block(next.indent)
+	}
+
+	return nil, nil
+}
+
+// splitYAMLKey splits "key: value" or "key:" at the first colon followed by a
+// space or the end of the line.
+func splitYAMLKey(text string) (string, string, bool) {
+	if strings.HasPrefix(text, "\"") || strings.HasPrefix(text, "'") {
+		return "", "", false
+	}
+	for index := 0; index < len(text); index++ {
+		if text[index] != ':' || (index+1 < len(text) && text[index+1] != ' ') {
+			continue
+		}
+		key := strings.TrimSpace(text[:index])
+		if key == "" {
+			return "", "", false
+		}
+		return key, strings.TrimSpace(text[index+1:]), true
+	}
+
+	return "", "", false
+}
+
+func isFlow(text string) bool {
+	return strings.HasPrefix(text, "[") || strings.HasPrefix(text, "{")
+}
+
+func (p *yamlParser) scalar(text string) (any, error) {
+	value, err := parseYAMLScalar(text)
+	if err != nil {
+		p.pos--
+		return nil, p.errorf("%v", err)
+	}
+
+	return value, nil
+}
+
+// checkYAMLSupported rejects a key or value that opens a construct outside
+// the subset.
+func checkYAMLSupported(text string) error {
+	construct := ""
+	switch {
+	case text == "":
+		return nil
+	case text == "<<":
+		construct = "merge key"
+	case text == "?" || strings.HasPrefix(text, "? "):
+		construct = "explicit key"
+	case isSequenceItem(text):
+		construct = "sequence entry on the same line as its parent"
+	default:
+		construct = yamlIndicators[text[0]]
+	}
+	if construct == "" {
+		return nil
+	}
+
+	return fmt.Errorf("unsupported YAML %s %q", construct, text)
+}
+
+func parseYAMLScalar(text string) (any, error) {
+	if err := checkYAMLSupported(text); err != nil {
+		return nil, err
+	}
+	switch {
+	case text == "":
+		return "", nil
+	case strings.HasPrefix(text, "["):
+		return parseFlowSequence(text)
+	case strings.HasPrefix(text, "{"):
+		return parseFlowMapping(text)
+	case strings.HasPrefix(text, "\"") || strings.HasPrefix(text, "'"):
+		return parseQuoted(text)
+	case strings.Contains(text+" ", ": "):
+		return nil, fmt.Errorf("unsupported YAML mapping inside the plain scalar %q: quote it", text)
+	}
+
+	return plainYAMLScalar(text), nil
+}
+
+func parseQuoted(text string) (string, error) {
+	if text[0] == '"' {
+		value, err := strconv.Unquote(text)
+		if err != nil {
+			return "", fmt.Errorf("invalid double-quoted string %s", text)
+		}
+		return value, nil
+	}
+
+	inner := strings.TrimPrefix(text, "'")
+	if !strings.HasSuffix(inner, "'") || strings.Contains(strings.ReplaceAll(inner[:len(inner)-1], "''", ""), "'") {
+		return "", fmt.Errorf("invalid single-quoted string %s", text)
+	}
+
+	return strings.ReplaceAll(inner[:len(inner)-1], "''", "'"), nil
+}
+
+func plainYAMLScalar(text string) any {
+	switch text {
+	case "~", "null", "Null", "NULL":
+		return nil
+	case "true", "True", "TRUE":
+		return true
+	case "false", "False", "FALSE":
+		return false
+	}
+	if yamlInteger.MatchString(text) {
+		if value, err := strconv.ParseInt(text, 10, 64); err == nil {
+			return value
+		}
+	}
+	if yamlFloat.MatchString(text) {
+		if value, err := strconv.ParseFloat(text, 64); err == nil {
+			return value
+		}
+	}
+
+	return text
+}
+
+func parseFlowSequence(text string) ([]any, error) {
+	if !strings.HasSuffix(text, "]") {
+		return nil, fmt.Errorf("unterminated flow sequence %s", text)
+	}
+
+	values := []any{}
+	for _, item := range splitFlow(text[1 : len(text)-1]) {
+		value, err := parseYAMLScalar(item)
+		if err != nil {
+			return nil, err
+		}
+		values = append(values, value)
+	}
+
+	return values, nil
+}
+
+func parseFlowMapping(text string) (map[string]any, error) {
+	if !strings.HasSuffix(text, "}") {
+		return nil, fmt.Errorf("unterminated flow mapping %s", text)
+	}
+
+	values := map[string]any{}
+	for _, item := range splitFlow(text[1 : len(text)-1]) {
+		key, rest, ok := splitYAMLKey(item)
+		if !ok {
+			return nil, fmt.Errorf("invalid flow mapping entry %q", item)
+		}
+		if err := checkYAMLSupported(key); err != nil {
+			return nil, err
+		}
+		if _, seen := values[key]; seen {
+			return nil, fmt.Errorf("duplicate key %q", key)
+		}
+		value, err := parseYAMLScalar(rest)
+		if err != nil {
+			return nil, err
+		}
+		values[key] = value
+	}
+
+	return values, nil
+}
+
+// splitFlow splits the inside of a flow collection at commas outside quotes
+// and nested collections.
+func splitFlow(text string) []string {
+	items := make([]string, 0)
+	var quote rune
+	start, depth := 0, 0
+	for index, char := range text {
+		if quote != 0 {
+			if char == quote {
+				quote = 0
+			}
+			continue
+		}
+		switch {
+		case isQuote(char) && opensQuote(text, index):
+			quote = char
+		case char == ',' && depth == 0:
+			items = append(items, strings.TrimSpace(text[start:index]))
+			start = index + 1
+		default:
+			depth += nesting(char)
+		}
+	}
+	if last := strings.TrimSpace(text[start:]); last != "" || len(items) > 0 {
+		items = append(items, last)
+	}
+
+	return items
+}
+
+// nesting is how char changes the depth of nested flow collections.
+func nesting(char rune) int {
+	switch char {
+	case '[', '{':
+		return 1
+	case ']', '}':
+		return -1
+	default:
+		return 0
+	}
+}
+
+func isQuote(char rune) bool {
+	return char == '"' || char == '\''
+}
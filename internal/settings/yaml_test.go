@@ -0,0 +1,92 @@
+package settings
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+)
+
+const decodeYAMLBody = `# project settings
+project: billing-api
+notifications:
+  slack:
+  - trigger: new_item   # every new item
+    filters:
+      - type: environment
+        operation: eq
+        value: production
+      - {type: title, operation: regex, value: "^Timeout, db"}
+    config:
+      channel: "#alerts"
+      note: 'don''t page'
+      buttons: true
+  email: []
+tags: [a, 'b, c', 3, {k: [v]}]
+empty:
+ratio: 1.5
+`
+
+func TestDecodeYAML(t *testing.T) {
+	t.Parallel()
+
+	got, err := decodeYAML([]byte(decodeYAMLBody))
+	if err != nil {
+		t.Fatalf("decodeYAML() error = %v", err)
+	}
+	want := map[string]any{
+		"project": "billing-api",
+		"notifications": map[string]any{
+			"slack": []any{map[string]any{
+				"trigger": "new_item",
+				"filters": []any{
+					map[string]any{"type": "environment", "operation": "eq", "value": "production"},
+					map[string]any{"type": "title", "operation": "regex", "value": "^Timeout, db"},
+				},
+				"config": map[string]any{"channel": "#alerts", "note": "don't page", "buttons": true},
+			}},
+			"email": []any{},
+		},
+		"tags":  []any{"a", "b, c", int64(3), map[string]any{"k": []any{"v"}}},
+		"empty": nil,
+		"ratio": 1.5,
+	}
+	if !reflect.DeepEqual(got, want) {
+		t.Fatalf("decodeYAML() = %#v\nwant %#v", got, want)
+	}
+}
+
+func TestDecodeYAMLErrors(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		body string
+		want string
+	}{
+		{body: "a: 1\na: 2", want: `line 2: duplicate key "a"`},
+		{body: "a: 1\n  b: 2", want: "line 2: unexpected indentation"},
+		{body: "a:\n\t- b", want: "line 2: indent with spaces"},
+		{body: "just text", want: "line 1: expected key: value"},
+		{body: "a: |\n  text", want: `line 1: unsupported YAML block scalar "|"`},
+		{body: "a: 1\nb: >-\n  text", want: `line 2: unsupported YAML block scalar ">-"`},
+		{body: "base: &base\n  a: 1", want: `line 1: unsupported YAML anchor "&base"`},
+		{body: "a:\n  - *base", want: `line 2: unsupported YAML alias "*base"`},
+		{body: "a: !!str 1", want: `line 1: unsupported YAML tag "!!str 1"`},
+		{body: "!tag a: 1", want: `line 1: unsupported YAML tag "!tag a: 1"`},
+		{body: "a:\n  <<: {b: 1}", want: `line 2: unsupported YAML merge key "<<"`},
+		{body: "? a\n: 1", want: `line 1: unsupported YAML explicit key "? a"`},
+		{body: "%YAML 1.2\n---\na: 1", want: `line 1: unsupported YAML directive "%YAML 1.2"`},
+		{body: "a:\n  - - b", want: `line 2: unsupported YAML sequence entry on the same line as its parent "- b"`},
+		{body: "a: b: c", want: `line 1: unsupported YAML mapping inside the plain scalar "b: c"`},
+		{body: "a: {b: 1, b: 2}", want: `line 1: duplicate key "b"`},
+		{body: "---\na: 1\n---\nb: 2", want: "line 3: multiple YAML documents are not supported"},
+		{body: "a: 1\n...", want: "line 2: YAML document end markers are not supported"},
+		{body: "a: [b, c", want: "line 1: unterminated flow sequence"},
+		{body: "a: 'b", want: "line 1: invalid single-quoted string"},
+		{body: "a: {b}", want: `line 1: invalid flow mapping entry "b"`},
+	}
+	for _, tc := range tests {
+		if _, err := decodeYAML([]byte(tc.body)); err == nil || !strings.Contains(err.Error(), tc.want) {
+			t.Fatalf("%q: expected %q error, got %v", tc.body, tc.want, err)
+		}
+	}
+}
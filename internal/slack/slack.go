@@ -0,0 +1,162 @@
+// Package slack posts Block Kit messages through an incoming webhook or the
+// chat.postMessage API.
+package slack
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// DefaultAPIURL is the root of Slack's Web API.
+const DefaultAPIURL = "https://slack.com/api"
+
+const (
+	requestTimeout   = 10 * time.Second
+	maxResponseBytes = 1 << 20
+)
+
+// Message is a chat message. Text is the notification and fallback text;
+// Blocks is what Slack renders.
+type Message struct {
+	Channel string  `json:"channel,omitempty"`
+	Text    string  `json:"text"`
+	Blocks  []Block `json:"blocks,omitempty"`
+}
+
+// Block is a section (Text and Fields) or an actions block (Elements).
+type Block struct {
+	Type     string   `json:"type"`
+	Text     *Text    `json:"text,omitempty"`
+	Fields   []Text   `json:"fields,omitempty"`
+	Elements []Button `json:"elements,omitempty"`
+}
+
+type Text struct {
+	Type string `json:"type"`
+	Text string `json:"text"`
+}
+
+// Button is a link button in an actions block.
+type Button struct {
+	Type string `json:"type"`
+	Text Text   `json:"text"`
+	URL  string `json:"url"`
+}
+
+// Markdown is a mrkdwn text object.
+func Markdown(text string) Text {
+	return Text{Type: "mrkdwn", Text: text}
+}
+
+// LinkButton is a button that opens url.
+func LinkButton(label string, url string) Button {
+	return Button{Type: "button", Text: Text{Type: "plain_text", Text: label}, URL: url}
+}
+
+// Escape escapes the characters mrkdwn treats as control sequences.
+func Escape(text string) string {
+	return strings.NewReplacer("&", "&amp;", "<", "&lt;", ">", "&gt;").Replace(text)
+}
+
+// Client posts to one destination: a webhook URL, or a channel through the
+// API with a bot token. Both the webhook URL and the token are secrets and
+// never appear in errors.
+type Client struct {
+	http       *http.Client
+	webhookURL string
+	apiURL     string
+	token      string
+	channel    string
+}
+
+func NewWebhookClient(webhookURL string) (*Client, error) {
+	if strings.TrimSpace(webhookURL) == "" {
+		return nil, errors.New("slack webhook url is required")
+	}
+
+	return &Client{http: &http.Client{Timeout: requestTimeout}, webhookURL: webhookURL}, nil
+}
+
+// NewAPIClient posts to channel with token; an empty apiURL uses
+// DefaultAPIURL.
+func NewAPIClient(token string, channel string, apiURL string) (*Client, error) {
+	switch {
+	case strings.TrimSpace(token) == "":
+		return nil, errors.New("slack token is required")
+	case strings.TrimSpace(channel) == "":
+		return nil, errors.New("slack channel is required")
+	}
+	if apiURL == "" {
+		apiURL = DefaultAPIURL
+	}
+
+	return &Client{http: &http.Client{Timeout: requestTimeout}, apiURL: strings.TrimRight(apiURL, "/"), token: token, channel: channel}, nil
+}
+
+func (c *Client) Post(ctx context.Context, message Message) error {
+	endpoint := c.webhookURL
+	if endpoint == "" {
+		endpoint = c.apiURL + "/chat.postMessage"
+		message.Channel = c.channel
+	}
+
+	payload, err := json.Marshal(message)
+	if err != nil {
+		return fmt.Errorf("encode slack message: %w", err)
+	}
+	request, err := http.NewRequestWithContext(ctx, http.MethodPost, endpoint, bytes.NewReader(payload))
+	if err != nil {
+		return errors.New("build slack request: invalid url")
+	}
+	request.Header.Set("Content-Type", "application/json; charset=utf-8")
+	if c.token != "" {
+		request.Header.Set("Authorization", "Bearer "+c.token)
+	}
+
+	return c.do(request)
+}
+
+func (c *Client) do(request *http.Request) error {
+	response, err := c.http.Do(request)
+	if err != nil {
+		// url.Error repeats the request URL, which is the secret for webhooks.
+		var urlErr *url.Error
+		if errors.As(err, &urlErr) {
+			err = urlErr.Err
+		}
+		return fmt.Errorf("slack request: %w", err)
+	}
+	defer func() { _ = response.Body.Close() }()
+
+	body, err := io.ReadAll(io.LimitReader(response.Body, maxResponseBytes))
+	if err != nil {
+		return fmt.Errorf("read slack response: %w", err)
+	}
+	if response.StatusCode < 200 || response.StatusCode > 299 {
+		return fmt.Errorf("slack returned status %d: %s", response.StatusCode, strings.TrimSpace(string(body)))
+	}
+	if c.webhookURL != "" {
+		return nil
+	}
+
+	var result struct {
+		OK    bool   `json:"ok"`
+		Error string `json:"error"`
+	}
+	if err := json.Unmarshal(body, &result); err != nil {
+		return fmt.Errorf("decode slack response: %w", err)
+	}
+	if !result.OK {
+		return fmt.Errorf("slack returned error: %s", result.Error)
+	}
+
+	return nil
+}
@@ -0,0 +1,121 @@
+package slack
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+func TestPostWebhook(t *testing.T) {
+	t.Parallel()
+
+	var got Message
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/services/T0/B0/secret" || r.Header.Get("Authorization") != "" {
+			t.Fatalf("unexpected request: %s (auth %q)", r.URL.Path, r.Header.Get("Authorization"))
+		}
+		_ = json.NewDecoder(r.Body).Decode(&got)
+		_, _ = fmt.Fprint(w, "ok")
+	}))
+	t.Cleanup(server.Close)
+
+	client, err := NewWebhookClient(server.URL + "/services/T0/B0/secret")
+	if err != nil {
+		t.Fatalf("NewWebhookClient() error = %v", err)
+	}
+	message := Message{Text: "boom", Blocks: []Block{{Type: "actions", Elements: []Button{LinkButton("Open", "https://rollbar.example")}}}}
+	if err := client.Post(context.Background(), message); err != nil {
+		t.Fatalf("Post() error = %v", err)
+	}
+	if got.Text != "boom" || got.Channel != "" || got.Blocks[0].Elements[0].URL != "https://rollbar.example" {
+		t.Fatalf("unexpected message: %+v", got)
+	}
+}
+
+// servePostMessage accepts chat.postMessage calls from the xoxb-1 token and
+// only knows the #alerts channel.
+func servePostMessage(w http.ResponseWriter, r *http.Request) {
+	var message Message
+	_ = json.NewDecoder(r.Body).Decode(&message)
+	switch {
+	case r.URL.Path != "/api/chat.postMessage" || r.Header.Get("Authorization") != "Bearer xoxb-1":
+		w.WriteHeader(http.StatusNotFound)
+	case message.Channel != "#alerts":
+		_, _ = fmt.Fprint(w, `{"ok":false,"error":"channel_not_found"}`)
+	default:
+		_, _ = fmt.Fprint(w, `{"ok":true}`)
+	}
+}
+
+func TestPostAPI(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(servePostMessage))
+	t.Cleanup(server.Close)
+
+	tests := []struct {
+		channel string
+		wantErr string
+	}{
+		{channel: "#alerts"},
+		{channel: "#nope", wantErr: "slack returned error: channel_not_found"},
+	}
+	for _, tc := range tests {
+		client, err := NewAPIClient("xoxb-1", tc.channel, server.URL+"/api/")
+		if err != nil {
+			t.Fatalf("NewAPIClient() error = %v", err)
+		}
+		err = client.Post(context.Background(), Message{Text: "boom"})
+		if (tc.wantErr == "" && err != nil) || (tc.wantErr != "" && (err == nil || err.Error() != tc.wantErr)) {
+			t.Fatalf("%s: Post() error = %v, want %q", tc.channel, err, tc.wantErr)
+		}
+	}
+}
+
+func TestPostErrorsHideWebhookURL(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusNotFound)
+		_, _ = fmt.Fprint(w, "no_service")
+	}))
+	secretURL := server.URL + "/services/T0/B0/secret"
+
+	client, err := NewWebhookClient(secretURL)
+	if err != nil {
+		t.Fatalf("NewWebhookClient() error = %v", err)
+	}
+	if err := client.Post(context.Background(), Message{Text: "boom"}); err == nil || err.Error() != "slack returned status 404: no_service" {
+		t.Fatalf("expected status error, got %v", err)
+	}
+
+	server.Close()
+	if err := client.Post(context.Background(), Message{Text: "boom"}); err == nil || strings.Contains(err.Error(), "secret") {
+		t.Fatalf("expected connection error without the webhook url, got %v", err)
+	}
+}
+
+func TestNewClientValidation(t *testing.T) {
+	t.Parallel()
+
+	if _, err := NewWebhookClient(" "); err == nil {
+		t.Fatal("expected missing webhook url error")
+	}
+	for _, args := range [][2]string{{"", "#alerts"}, {"xoxb-1", ""}} {
+		if _, err := NewAPIClient(args[0], args[1], ""); err == nil {
+			t.Fatalf("NewAPIClient(%q, %q) expected error", args[0], args[1])
+		}
+	}
+}
+
+func TestEscape(t *testing.T) {
+	t.Parallel()
+
+	if got := Escape("a < b && c > d"); got != "a &lt; b &amp;&amp; c &gt; d" {
+		t.Fatalf("Escape() = %q", got)
+	}
+}
@@ -0,0 +1,140 @@
+// Package source maps stack frame filenames onto a local checkout so traces
+// can show the code around each failing line.
+package source
+
+import (
+	"errors"
+	"fmt"
+	"io"
+	"net/url"
+	"os"
+	"path"
+	"strings"
+)
+
+// Radius is how many lines either side of the failing line Lines returns.
+const Radius = 3
+
+// maxFileSize skips anything too large to be a source file.
+const maxFileSize = 4 << 20
+
+// Root reads source files from one directory, never outside it, caching each
+// file (or its absence) by frame filename.
+type Root struct {
+	root  *os.Root
+	files map[string][]string
+}
+
+// Open opens dir as the checkout frame filenames are resolved against.
+func Open(dir string) (*Root, error) {
+	root, err := os.OpenRoot(dir)
+	if err != nil {
+		return nil, fmt.Errorf("open source root: %w", err)
+	}
+
+	return &Root{root: root, files: map[string][]string{}}, nil
+}
+
+func (r *Root) Close() error {
+	if err := r.root.Close(); err != nil {
+		return fmt.Errorf("close source root: %w", err)
+	}
+
+	return nil
+}
+
+// Lines returns up to Radius lines either side of line (1-based) from the
+// local copy of filename, and the number of the first line returned. It
+// reports false when no local file matches or the line is out of range.
+func (r *Root) Lines(filename string, line int) (int, []string, bool) {
+	if line <= 0 {
+		return 0, nil, false
+	}
+	lines := r.file(filename)
+	if line > len(lines) {
+		return 0, nil, false
+	}
+
+	start := max(line-Radius, 1)
+	end := min(line+Radius, len(lines))
+
+	return start, lines[start-1 : end], true
+}
+
+// Path returns the checkout-relative path of the local file filename maps
+// to, trying Candidates in order, or false when none is a regular file.
+func (r *Root) Path(filename string) (string, bool) {
+	for _, candidate := range Candidates(filename) {
+		info, err := r.root.Stat(candidate)
+		if err == nil && info.Mode().IsRegular() {
+			return candidate, true
+		}
+	}
+
+	return "", false
+}
+
+func (r *Root) file(filename string) []string {
+	if lines, ok := r.files[filename]; ok {
+		return lines
+	}
+
+	var lines []string
+	for _, candidate := range Candidates(filename) {
+		body, err := r.read(candidate)
+		if err == nil {
+			lines = strings.Split(strings.TrimSuffix(string(body), "\n"), "\n")
+			break
+		}
+	}
+	r.files[filename] = lines
+
+	return lines
+}
+
+func (r *Root) read(name string) ([]byte, error) {
+	file, err := r.root.Open(name)
+	if err != nil {
+		return nil, fmt.Errorf("open %s: %w", name, err)
+	}
+	defer func() { _ = file.Close() }()
+
+	info, err := file.Stat()
+	if err != nil {
+		return nil, fmt.Errorf("stat %s: %w", name, err)
+	}
+	if !info.Mode().IsRegular() || info.Size() > maxFileSize {
+		return nil, errors.New(name + " is not a source file")
+	}
+
+	body, err := io.ReadAll(file)
+	if err != nil {
+		return nil, fmt.Errorf("read %s: %w", name, err)
+	}
+
+	return body, nil
+}
+
+// Candidates lists the relative paths tried for a frame filename, longest
+// first, since servers rarely run from the same directory as the checkout:
+// "/srv/app/pay/charge.py" tries srv/app/pay/charge.py, app/pay/charge.py,
+// pay/charge.py, then charge.py. URLs are reduced to their path.
+func Candidates(filename string) []string {
+	name := strings.TrimSpace(filename)
+	if parsed, err := url.Parse(name); err == nil && parsed.Scheme != "" && parsed.Host != "" {
+		name = parsed.Path
+	}
+	parts := strings.FieldsFunc(name, func(char rune) bool {
+		return char == '/' || char == '\\'
+	})
+
+	paths := make([]string, 0, len(parts))
+	for index := range parts {
+		candidate := path.Join(parts[index:]...)
+		if candidate != "." && (len(paths) == 0 || paths[len(paths)-1] != candidate) {
+			paths = append(paths, candidate)
+		}
+	}
+
+	return paths
+}
@@ -0,0 +1,107 @@
+package source
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestCandidates(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		filename string
+		want     string
+	}{
+		{filename: "/srv/app/pay/charge.py", want: "srv/app/pay/charge.py,app/pay/charge.py,pay/charge.py,charge.py"},
+		{filename: "https://cdn.example.com/static/app.js?v=2", want: "static/app.js,app.js"},
+		{filename: "webpack:///./src/app.js", want: "webpack:/src/app.js,src/app.js,app.js"},
+		{filename: `C:\build\lib\util.cs`, want: "C:/build/lib/util.cs,build/lib/util.cs,lib/util.cs,util.cs"},
+		{filename: "", want: ""},
+	}
+	for _, tc := range tests {
+		if got := strings.Join(Candidates(tc.filename), ","); got != tc.want {
+			t.Fatalf("Candidates(%q) = %q, want %q", tc.filename, got, tc.want)
+		}
+	}
+}
+
+// openTestRoot opens a source root holding pay/charge.py, whose nine lines
+// are l1 to l9.
+func openTestRoot(t *testing.T) *Root {
+	t.Helper()
+
+	dir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(dir, "pay"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	body := "l1\nl2\nl3\nl4\nl5\nl6\nl7\nl8\nl9\n"
+	if err := os.WriteFile(filepath.Join(dir, "pay", "charge.py"), []byte(body), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	root, err := Open(dir)
+	if err != nil {
+		t.Fatalf("Open() error = %v", err)
+	}
+	t.Cleanup(func() { _ = root.Close() })
+
+	return root
+}
+
+func TestRootLines(t *testing.T) {
+	t.Parallel()
+
+	root := openTestRoot(t)
+	tests := []struct {
+		filename  string
+		line      int
+		wantStart int
+		want      string
+		wantOK    bool
+	}{
+		{filename: "/srv/app/pay/charge.py", line: 5, wantStart: 2, want: "l2,l3,l4,l5,l6,l7,l8", wantOK: true},
+		{filename: "pay/charge.py", line: 1, wantStart: 1, want: "l1,l2,l3,l4", wantOK: true},
+		{filename: "pay/charge.py", line: 9, wantStart: 6, want: "l6,l7,l8,l9", wantOK: true},
+		{filename: "pay/charge.py", line: 10},
+		{filename: "pay/refund.py", line: 1},
+		{filename: "../outside.py", line: 1},
+	}
+	for _, tc := range tests {
+		start, lines, ok := root.Lines(tc.filename, tc.line)
+		if ok != tc.wantOK || start != tc.wantStart || strings.Join(lines, ",") != tc.want {
+			t.Fatalf("Lines(%q, %d) = %d, %v, %v", tc.filename, tc.line, start, lines, ok)
+		}
+	}
+}
+
+func TestRootPath(t *testing.T) {
+	t.Parallel()
+
+	root := openTestRoot(t)
+	tests := []struct {
+		filename string
+		want     string
+		wantOK   bool
+	}{
+		{filename: "/srv/app/pay/charge.py", want: "pay/charge.py", wantOK: true},
+		{filename: "https://cdn.example.com/pay/charge.py", want: "pay/charge.py", wantOK: true},
+		{filename: "/srv/app/pay"},
+		{filename: "pay/refund.py"},
+	}
+	for _, tc := range tests {
+		got, ok := root.Path(tc.filename)
+		if ok != tc.wantOK || got != tc.want {
+			t.Fatalf("Path(%q) = %q, %v", tc.filename, got, ok)
+		}
+	}
+}
+
+func TestOpenMissingRoot(t *testing.T) {
+	t.Parallel()
+
+	if _, err := Open(filepath.Join(t.TempDir(), "missing")); err == nil {
+		t.Fatal("expected error for a missing source root")
+	}
+}
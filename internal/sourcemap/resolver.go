@@ -0,0 +1,243 @@
+package sourcemap
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"encoding/base64"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/kevinsheth/rollbaz/internal/source"
+)
+
+const (
+	downloadTimeout = 10 * time.Second
+	// maxFileSize bounds both minified scripts and maps.
+	maxFileSize = 32 << 20
+)
+
+// Resolver finds the source map for each minified file a frame names: first
+// in a local directory of .map files, then, when downloads are enabled and
+// the filename is an http(s) URL, from the script's sourceMappingURL. Maps
+// and misses are cached by filename.
+type Resolver struct {
+	dir    *os.Root
+	client *http.Client
+	maps   map[string]*Map
+}
+
+// NewResolver reads maps from mapDir when it is set and downloads them when
+// download is true.
+func NewResolver(mapDir string, download bool) (*Resolver, error) {
+	resolver := &Resolver{maps: map[string]*Map{}}
+	if mapDir != "" {
+		dir, err := os.OpenRoot(mapDir)
+		if err != nil {
+			return nil, fmt.Errorf("open map dir: %w", err)
+		}
+		resolver.dir = dir
+	}
+	if download {
+		resolver.client = &http.Client{Timeout: downloadTimeout}
+	}
+
+	return resolver, nil
+}
+
+func (r *Resolver) Close() error {
+	if r.dir == nil {
+		return nil
+	}
+	if err := r.dir.Close(); err != nil {
+		return fmt.Errorf("close map dir: %w", err)
+	}
+
+	return nil
+}
+
+// Resolve maps a 1-based line and column in the minified file filename to
+// its original position, reporting false when no map covers it.
+func (r *Resolver) Resolve(ctx context.Context, filename string, line int, column int) (Position, bool) {
+	sourceMap, ok := r.maps[filename]
+	if !ok {
+		sourceMap = r.localMap(filename)
+		if sourceMap == nil {
+			sourceMap = r.downloadMap(ctx, filename)
+		}
+		r.maps[filename] = sourceMap
+	}
+	if sourceMap == nil {
+		return Position{}, false
+	}
+
+	return sourceMap.Lookup(line, column)
+}
+
+// localMap looks for <file>.map under the map directory, dropping leading
+// directories of the frame filename as source.Candidates does.
+func (r *Resolver) localMap(filename string) *Map {
+	if r.dir == nil {
+		return nil
+	}
+
+	for _, candidate := range source.Candidates(filename) {
+		file, err := r.dir.Open(candidate + ".map")
+		if err != nil {
+			continue
+		}
+		body, err := readLimited(file)
+		_ = file.Close()
+		if err != nil {
+			continue
+		}
+		if parsed, err := Parse(body); err == nil {
+			return parsed
+		}
+	}
+
+	return nil
+}
+
+func (r *Resolver) downloadMap(ctx context.Context, filename string) *Map {
+	if r.client == nil {
+		return nil
+	}
+	scriptURL, err := url.Parse(strings.TrimSpace(filename))
+	if err != nil || (scriptURL.Scheme != "http" && scriptURL.Scheme != "https") {
+		return nil
+	}
+
+	body, err := r.fetchMap(ctx, r.mapURL(ctx, scriptURL))
+	if err != nil {
+		return nil
+	}
+	parsed, err := Parse(body)
+	if err != nil {
+		return nil
+	}
+
+	return parsed
+}
+
+// mapURL is the script's SourceMap header or sourceMappingURL comment,
+// resolved against the script URL, or the script URL plus ".map".
+func (r *Resolver) mapURL(ctx context.Context, scriptURL *url.URL) string {
+	fallback := *scriptURL
+	fallback.RawQuery, fallback.Fragment = "", ""
+	fallback.Path += ".map"
+
+	body, header, err := r.get(ctx, scriptURL.String())
+	if err != nil {
+		return fallback.String()
+	}
+	reference := header.Get("SourceMap")
+	if reference == "" {
+		reference = header.Get("X-SourceMap")
+	}
+	if reference == "" {
+		reference = sourceMappingComment(body)
+	}
+	if reference == "" {
+		return fallback.String()
+	}
+	if strings.HasPrefix(reference, "data:") {
+		return reference
+	}
+
+	resolved, err := scriptURL.Parse(reference)
+	if err != nil {
+		return fallback.String()
+	}
+
+	return resolved.String()
+}
+
+func (r *Resolver) fetchMap(ctx context.Context, mapURL string) ([]byte, error) {
+	if data, ok := strings.CutPrefix(mapURL, "data:"); ok {
+		return decodeDataURI(data)
+	}
+
+	body, _, err := r.get(ctx, mapURL)
+	return body, err
+}
+
+func (r *Resolver) get(ctx context.Context, target string) ([]byte, http.Header, error) {
+	request, err := http.NewRequestWithContext(ctx, http.MethodGet, target, nil)
+	if err != nil {
+		return nil, nil, fmt.Errorf("build request: %w", err)
+	}
+	response, err := r.client.Do(request)
+	if err != nil {
+		return nil, nil, fmt.Errorf("get %s: %w", target, err)
+	}
+	defer func() { _ = response.Body.Close() }()
+	if response.StatusCode != http.StatusOK {
+		return nil, nil, fmt.Errorf("get %s: status %d", target, response.StatusCode)
+	}
+
+	body, err := readLimited(response.Body)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	return body, response.Header, nil
+}
+
+// sourceMappingComment returns the last //# sourceMappingURL= (or legacy
+// //@) reference in a script.
+func sourceMappingComment(script []byte) string {
+	reference := ""
+	scanner := bufio.NewScanner(bytes.NewReader(script))
+	scanner.Buffer(make([]byte, 0, 64*1024), maxFileSize)
+	for scanner.Scan() {
+		line := strings.TrimSpace(scanner.Text())
+		for _, prefix := range []string{"//# sourceMappingURL=", "//@ sourceMappingURL="} {
+			if value, ok := strings.CutPrefix(line, prefix); ok {
+				reference = strings.TrimSpace(value)
+			}
+		}
+	}
+
+	return reference
+}
+
+// decodeDataURI decodes the part of an inline data: URI after "data:".
+func decodeDataURI(data string) ([]byte, error) {
+	meta, payload, ok := strings.Cut(data, ",")
+	if !ok {
+		return nil, errors.New("malformed data uri")
+	}
+	if strings.HasSuffix(meta, ";base64") {
+		decoded, err := base64.StdEncoding.DecodeString(payload)
+		if err != nil {
+			return nil, fmt.Errorf("decode data uri: %w", err)
+		}
+		return decoded, nil
+	}
+
+	decoded, err := url.PathUnescape(payload)
+	if err != nil {
+		return nil, fmt.Errorf("decode data uri: %w", err)
+	}
+
+	return []byte(decoded), nil
+}
+
+func readLimited(reader io.Reader) ([]byte, error) {
+	body, err := io.ReadAll(io.LimitReader(reader, maxFileSize+1))
+	if err != nil {
+		return nil, fmt.Errorf("read: %w", err)
+	}
+	if len(body) > maxFileSize {
+		return nil, errors.New("file is larger than 32 MiB")
+	}
+
+	return body, nil
+}
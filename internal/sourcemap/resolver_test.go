@@ -0,0 +1,111 @@
+package sourcemap
+
+import (
+	"context"
+	"encoding/base64"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestResolverLocalMaps(t *testing.T) {
+	t.Parallel()
+
+	dir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(dir, "static"), 0o750); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(dir, "static", "app.min.js.map"), []byte(testMap), 0o600); err != nil {
+		t.Fatal(err)
+	}
+
+	resolver, err := NewResolver(dir, false)
+	if err != nil {
+		t.Fatalf("NewResolver() error = %v", err)
+	}
+	t.Cleanup(func() { _ = resolver.Close() })
+
+	tests := []struct {
+		filename string
+		want     string
+	}{
+		{filename: "https://cdn.example.com/static/app.min.js", want: "src/app.ts:5"},
+		{filename: "/var/www/static/app.min.js", want: "src/app.ts:5"},
+		{filename: "https://cdn.example.com/static/vendor.min.js", want: ""},
+	}
+	for _, tc := range tests {
+		got := ""
+		if position, ok := resolver.Resolve(context.Background(), tc.filename, 1, 15); ok {
+			got = fmt.Sprintf("%s:%d", position.Source, position.Line)
+		}
+		if got != tc.want {
+			t.Fatalf("Resolve(%q) = %q, want %q", tc.filename, got, tc.want)
+		}
+	}
+
+	if _, err := NewResolver(filepath.Join(dir, "missing"), false); err == nil {
+		t.Fatal("expected error for a missing map dir")
+	}
+}
+
+// serveScripts serves scripts that reference testMap through a
+// sourceMappingURL comment, a SourceMap header, an inline data URL, and the
+// conventional .map sibling.
+func serveScripts(w http.ResponseWriter, r *http.Request) {
+	inline := "data:application/json;base64," + base64.StdEncoding.EncodeToString([]byte(testMap))
+	switch r.URL.Path {
+	case "/js/app.js":
+		_, _ = fmt.Fprint(w, "var a=1;\n//# sourceMappingURL=maps/app.js.map\n")
+	case "/js/maps/app.js.map", "/js/plain.js.map":
+		_, _ = fmt.Fprint(w, testMap)
+	case "/js/header.js":
+		w.Header().Set("SourceMap", "/js/maps/app.js.map")
+	case "/js/inline.js":
+		_, _ = fmt.Fprint(w, "var a=1;\n//# sourceMappingURL="+inline+"\n")
+	case "/js/plain.js":
+		_, _ = fmt.Fprint(w, "var a=1;\n")
+	default:
+		w.WriteHeader(http.StatusNotFound)
+	}
+}
+
+func TestResolverDownloads(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(serveScripts))
+	t.Cleanup(server.Close)
+
+	resolver, err := NewResolver("", true)
+	if err != nil {
+		t.Fatalf("NewResolver() error = %v", err)
+	}
+	offline, _ := NewResolver("", false)
+
+	for _, name := range []string{"app.js", "header.js", "inline.js", "plain.js?v=3"} {
+		position, ok := resolver.Resolve(context.Background(), server.URL+"/js/"+name, 2, 6)
+		if !ok || position.Source != "src/util.ts" {
+			t.Fatalf("%s: Resolve() = %+v, %v", name, position, ok)
+		}
+		if _, ok := offline.Resolve(context.Background(), server.URL+"/js/"+name, 2, 6); ok {
+			t.Fatalf("%s: expected no resolution with downloads off", name)
+		}
+	}
+}
+
+func TestResolverMissingDownload(t *testing.T) {
+	t.Parallel()
+
+	server := httptest.NewServer(http.HandlerFunc(serveScripts))
+	t.Cleanup(server.Close)
+
+	resolver, err := NewResolver("", true)
+	if err != nil {
+		t.Fatalf("NewResolver() error = %v", err)
+	}
+	if _, ok := resolver.Resolve(context.Background(), server.URL+"/js/missing.js", 1, 1); ok {
+		t.Fatal("expected no resolution for a missing map")
+	}
+}
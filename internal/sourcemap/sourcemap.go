@@ -0,0 +1,194 @@
+// Package sourcemap decodes version 3 source maps and finds the map for a
+// minified JavaScript file, locally or over HTTP, so stack frames can point
+// at the original source.
+package sourcemap
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+)
+
+// Position is an original source location. Line and Column are 1-based.
+type Position struct {
+	Source string `json:"source"`
+	Line   int    `json:"line"`
+	Column int    `json:"column"`
+	Name   string `json:"name,omitempty"`
+}
+
+// Map is a decoded source map.
+type Map struct {
+	sources []string
+	names   []string
+	lines   [][]segment
+}
+
+// segment is one decoded mapping; source and name are -1 when absent.
+type segment struct {
+	column       int
+	source       int
+	line         int
+	sourceColumn int
+	name         int
+}
+
+type mapFile struct {
+	Version    int             `json:"version"`
+	SourceRoot string          `json:"sourceRoot"`
+	Sources    []string        `json:"sources"`
+	Names      []string        `json:"names"`
+	Mappings   string          `json:"mappings"`
+	Sections   json.RawMessage `json:"sections"`
+}
+
+const vlqAlphabet = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+
+// Parse decodes a version 3 source map. Index maps with sections are not
+// supported.
+func Parse(data []byte) (*Map, error) {
+	// Maps may start with an XSSI guard line such as )]}'.
+	if bytes.HasPrefix(data, []byte(")]}")) {
+		if _, rest, ok := bytes.Cut(data, []byte("\n")); ok {
+			data = rest
+		}
+	}
+
+	var file mapFile
+	if err := json.Unmarshal(data, &file); err != nil {
+		return nil, fmt.Errorf("decode source map: %w", err)
+	}
+	if file.Version != 3 {
+		return nil, fmt.Errorf("unsupported source map version %d", file.Version)
+	}
+	if len(file.Sections) > 0 {
+		return nil, errors.New("index source maps with sections are not supported")
+	}
+
+	lines, err := decodeMappings(file.Mappings)
+	if err != nil {
+		return nil, err
+	}
+
+	sources := make([]string, 0, len(file.Sources))
+	for _, source := range file.Sources {
+		if file.SourceRoot != "" {
+			source = strings.TrimSuffix(file.SourceRoot, "/") + "/" + source
+		}
+		sources = append(sources, source)
+	}
+
+	return &Map{sources: sources, names: file.Names, lines: lines}, nil
+}
+
+// Lookup maps a 1-based generated line and column to the original position
+// of the closest mapping at or before it. A column of zero means unknown and
+// matches the start of the line.
+func (m *Map) Lookup(line int, column int) (Position, bool) {
+	if line <= 0 || line > len(m.lines) {
+		return Position{}, false
+	}
+
+	segments := m.lines[line-1]
+	target := max(column-1, 0)
+	index := sort.Search(len(segments), func(i int) bool { return segments[i].column > target }) - 1
+	if index < 0 {
+		return Position{}, false
+	}
+
+	found := segments[index]
+	if found.source < 0 || found.source >= len(m.sources) {
+		return Position{}, false
+	}
+	position := Position{Source: m.sources[found.source], Line: found.line + 1, Column: found.sourceColumn + 1}
+	if found.name >= 0 && found.name < len(m.names) {
+		position.Name = m.names[found.name]
+	}
+
+	return position, true
+}
+
+// decodeMappings decodes the mappings string: lines separated by ";",
+// segments by ",". The generated column resets each line; every other field
+// is relative to the previous segment with that field.
+func decodeMappings(mappings string) ([][]segment, error) {
+	var state [5]int
+	lines := make([][]segment, 0, strings.Count(mappings, ";")+1)
+	for lineIndex, line := range strings.Split(mappings, ";") {
+		state[0] = 0
+		segments := make([]segment, 0)
+		for _, field := range strings.Split(line, ",") {
+			if field == "" {
+				continue
+			}
+			decoded, err := decodeSegment(field, &state)
+			if err != nil {
+				return nil, fmt.Errorf("mappings line %d: %w", lineIndex+1, err)
+			}
+			segments = append(segments, decoded)
+		}
+		sort.SliceStable(segments, func(i, j int) bool { return segments[i].column < segments[j].column })
+		lines = append(lines, segments)
+	}
+
+	return lines, nil
+}
+
+func decodeSegment(field string, state *[5]int) (segment, error) {
+	values, err := decodeVLQ(field)
+	if err != nil {
+		return segment{}, err
+	}
+	if len(values) != 1 && len(values) != 4 && len(values) != 5 {
+		return segment{}, fmt.Errorf("segment %q has %d fields", field, len(values))
+	}
+
+	for index, value := range values {
+		state[index] += value
+	}
+	decoded := segment{column: state[0], source: -1, name: -1}
+	if len(values) >= 4 {
+		decoded.source, decoded.line, decoded.sourceColumn = state[1], state[2], state[3]
+	}
+	if len(values) == 5 {
+		decoded.name = state[4]
+	}
+
+	return decoded, nil
+}
+
+// decodeVLQ decodes base64 VLQ values: five data bits per digit, low bits
+// first, with bit 6 marking continuation and the first bit the sign.
+func decodeVLQ(field string) ([]int, error) {
+	values := make([]int, 0, 5)
+	value, shift := 0, 0
+	for index := 0; index < len(field); index++ {
+		digit := strings.IndexByte(vlqAlphabet, field[index])
+		if digit < 0 {
+			return nil, fmt.Errorf("invalid mapping character %q", field[index])
+		}
+		value += (digit & 31) << shift
+		if digit&32 != 0 {
+			shift += 5
+			if shift > 30 {
+				return nil, fmt.Errorf("mapping value in %q is too large", field)
+			}
+			continue
+		}
+
+		if value&1 != 0 {
+			values = append(values, -(value >> 1))
+		} else {
+			values = append(values, value>>1)
+		}
+		value, shift = 0, 0
+	}
+	if shift != 0 {
+		return nil, fmt.Errorf("truncated mapping segment %q", field)
+	}
+
+	return values, nil
+}
@@ -0,0 +1,88 @@
+package sourcemap
+
+import (
+	"strings"
+	"testing"
+)
+
+const testMap = `{"version":3,"sources":["src/app.ts","src/util.ts"],"names":["main","charge"],"mappings":"AAAAA,UAAIEC;KCJF"}`
+
+func TestMapLookup(t *testing.T) {
+	t.Parallel()
+
+	sourceMap, err := Parse([]byte(testMap))
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+
+	tests := []struct {
+		line   int
+		column int
+		want   Position
+		wantOK bool
+	}{
+		{line: 1, column: 1, want: Position{Source: "src/app.ts", Line: 1, Column: 1, Name: "main"}, wantOK: true},
+		{line: 1, column: 15, want: Position{Source: "src/app.ts", Line: 5, Column: 3, Name: "charge"}, wantOK: true},
+		{line: 1, column: 0, want: Position{Source: "src/app.ts", Line: 1, Column: 1, Name: "main"}, wantOK: true},
+		{line: 2, column: 6, want: Position{Source: "src/util.ts", Line: 1, Column: 1}, wantOK: true},
+		{line: 2, column: 3},
+		{line: 3, column: 1},
+		{line: 0, column: 1},
+	}
+	for _, tc := range tests {
+		got, ok := sourceMap.Lookup(tc.line, tc.column)
+		if ok != tc.wantOK || got != tc.want {
+			t.Fatalf("Lookup(%d, %d) = %+v, %v; want %+v, %v", tc.line, tc.column, got, ok, tc.want, tc.wantOK)
+		}
+	}
+}
+
+func TestParse(t *testing.T) {
+	t.Parallel()
+
+	guarded, err := Parse([]byte(")]}'\n" + strings.Replace(testMap, `"sources"`, `"sourceRoot":"webpack:///","sources"`, 1)))
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+	if got, _ := guarded.Lookup(1, 1); got.Source != "webpack:///src/app.ts" {
+		t.Fatalf("expected sourceRoot to prefix sources, got %+v", got)
+	}
+
+	for _, body := range []string{
+		`{"version":2,"sources":[],"mappings":""}`,
+		`{"version":3,"sections":[{"offset":{"line":0,"column":0}}]}`,
+		`{"version":3,"sources":["a.js"],"mappings":"A!AA"}`,
+		`{"version":3,"sources":["a.js"],"mappings":"g"}`,
+		`{"version":3,"sources":["a.js"],"mappings":"AA"}`,
+		`not json`,
+	} {
+		if _, err := Parse([]byte(body)); err == nil {
+			t.Fatalf("Parse(%s): expected error", body)
+		}
+	}
+}
+
+func TestDecodeVLQ(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		field string
+		want  []int
+	}{
+		{field: "AAAA", want: []int{0, 0, 0, 0}},
+		{field: "UAIEC", want: []int{10, 0, 4, 2, 1}},
+		{field: "KCJF", want: []int{5, 1, -4, -2}},
+		{field: "w+B", want: []int{1000}},
+	}
+	for _, tc := range tests {
+		got, err := decodeVLQ(tc.field)
+		if err != nil || len(got) != len(tc.want) {
+			t.Fatalf("decodeVLQ(%q) = %v, %v", tc.field, got, err)
+		}
+		for index := range got {
+			if got[index] != tc.want[index] {
+				t.Fatalf("decodeVLQ(%q) = %v, want %v", tc.field, got, tc.want)
+			}
+		}
+	}
+}
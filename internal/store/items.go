@@ -0,0 +1,303 @@
+package store
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+const (
+	// Page sizes match the Rollbar API's so paging callers see the same
+	// boundaries offline.
+	itemsPerPage     = 100
+	instancesPerPage = 20
+
+	highWaterMarkKey = "items_high_water_mark"
+)
+
+type rawRow struct {
+	Raw   string `json:"raw"`
+	Total uint64 `json:"total"`
+}
+
+type idRow struct {
+	ID uint64 `json:"id"`
+}
+
+// SaveItems inserts or replaces items by id.
+func (s *Store) SaveItems(ctx context.Context, items []rollbar.Item) error {
+	statements := make([]string, 0, len(items))
+	for _, item := range items {
+		raw, err := itemJSON(item)
+		if err != nil {
+			return err
+		}
+		statements = append(statements, fmt.Sprintf(
+			"INSERT OR REPLACE INTO items (id, counter, status, level, environment, title, last_occurrence_timestamp, raw) VALUES (%s, %s, %s, %s, %s, %s, %s, %s)",
+			item.ID, itoa(item.Counter), quote(string(item.Status)), quote(string(item.Level)), quote(item.Environment), quote(item.Title), itoa(uint64Value(item.LastOccurrenceTimestamp)), quote(raw),
+		))
+	}
+	if err := s.exec(ctx, statements); err != nil {
+		return fmt.Errorf("save items: %w", err)
+	}
+
+	return nil
+}
+
+// SaveInstances inserts or replaces occurrences of an item by id.
+func (s *Store) SaveInstances(ctx context.Context, itemID domain.ItemID, instances []rollbar.ItemInstance) error {
+	statements := make([]string, 0, len(instances))
+	for _, instance := range instances {
+		raw := instance.Raw
+		if len(raw) == 0 {
+			encoded, err := json.Marshal(instance)
+			if err != nil {
+				return fmt.Errorf("encode occurrence %d: %w", instance.ID, err)
+			}
+			raw = encoded
+		}
+		statements = append(statements, fmt.Sprintf(
+			"INSERT OR REPLACE INTO occurrences (id, item_id, timestamp, raw) VALUES (%s, %s, %s, %s)",
+			itoa(instance.ID), itemID, itoa(uint64Value(instance.Timestamp)), quote(string(raw)),
+		))
+	}
+	if err := s.exec(ctx, statements); err != nil {
+		return fmt.Errorf("save occurrences: %w", err)
+	}
+
+	return nil
+}
+
+// LatestInstanceID is the newest stored occurrence id of an item, or 0.
+func (s *Store) LatestInstanceID(ctx context.Context, itemID domain.ItemID) (uint64, error) {
+	var rows []idRow
+	if err := s.query(ctx, "SELECT id FROM occurrences WHERE item_id = "+itemID.String()+" ORDER BY id DESC LIMIT 1", &rows); err != nil {
+		return 0, fmt.Errorf("read latest occurrence: %w", err)
+	}
+	if len(rows) == 0 {
+		return 0, nil
+	}
+
+	return rows[0].ID, nil
+}
+
+// HighWaterMark is the newest last_occurrence_timestamp a completed sync
+// has stored, or 0 before the first sync.
+func (s *Store) HighWaterMark(ctx context.Context) (uint64, error) {
+	var rows []struct {
+		Value uint64 `json:"value"`
+	}
+	if err := s.query(ctx, "SELECT value FROM sync_state WHERE key = "+quote(highWaterMarkKey), &rows); err != nil {
+		return 0, fmt.Errorf("read high-water mark: %w", err)
+	}
+	if len(rows) == 0 {
+		return 0, nil
+	}
+
+	return rows[0].Value, nil
+}
+
+func (s *Store) SetHighWaterMark(ctx context.Context, mark uint64) error {
+	statement := "INSERT OR REPLACE INTO sync_state (key, value) VALUES (" + quote(highWaterMarkKey) + ", " + itoa(mark) + ")"
+	if err := s.exec(ctx, []string{statement}); err != nil {
+		return fmt.Errorf("save high-water mark: %w", err)
+	}
+
+	return nil
+}
+
+// The methods below serve app.RollbarAPI from the store.
+
+func (s *Store) ResolveItemIDByCounter(ctx context.Context, counter domain.ItemCounter) (domain.ItemID, error) {
+	var rows []idRow
+	if err := s.query(ctx, "SELECT id FROM items WHERE counter = "+counter.String(), &rows); err != nil {
+		return 0, fmt.Errorf("resolve item #%s: %w", counter, err)
+	}
+	if len(rows) == 0 {
+		return 0, fmt.Errorf("item #%s: %w", counter, ErrNotSynced)
+	}
+
+	return domain.ItemID(rows[0].ID), nil
+}
+
+func (s *Store) GetItem(ctx context.Context, itemID domain.ItemID) (rollbar.Item, error) {
+	items, _, err := s.items(ctx, "id = "+itemID.String(), "", 1, 0)
+	if err != nil {
+		return rollbar.Item{}, err
+	}
+	if len(items) == 0 {
+		return rollbar.Item{}, fmt.Errorf("item %s: %w", itemID, ErrNotSynced)
+	}
+
+	return items[0], nil
+}
+
+func (s *Store) UpdateItem(context.Context, domain.ItemID, rollbar.ItemPatch) error {
+	return fmt.Errorf("update item: %w", ErrOffline)
+}
+
+func (s *Store) FindUserByEmail(context.Context, string) (rollbar.User, error) {
+	return rollbar.User{}, fmt.Errorf("find user: %w", ErrOffline)
+}
+
+func (s *Store) ListActiveItems(ctx context.Context, limit int) ([]rollbar.Item, error) {
+	items, _, err := s.items(ctx, "status = 'active'", "", limit, 0)
+
+	return items, err
+}
+
+func (s *Store) ListItems(ctx context.Context, status string, page int) ([]rollbar.Item, error) {
+	result, err := s.ListItemsPage(ctx, rollbar.ItemQuery{Status: status, Page: page})
+	if err != nil {
+		return nil, err
+	}
+
+	return result.Items, nil
+}
+
+// ListItemsPage applies the query like /items, most recently seen first.
+// Query matches titles case-insensitively; AssignedUser needs the API.
+func (s *Store) ListItemsPage(ctx context.Context, query rollbar.ItemQuery) (rollbar.ItemPage, error) {
+	if query.AssignedUser != "" {
+		return rollbar.ItemPage{}, fmt.Errorf("filter by assignee: %w", ErrOffline)
+	}
+	page := max(query.Page, 1)
+	items, total, err := s.items(ctx, itemConditions(query), "count(*) OVER () AS total, ", itemsPerPage, (page-1)*itemsPerPage)
+	if err != nil {
+		return rollbar.ItemPage{}, err
+	}
+
+	return rollbar.ItemPage{Items: items, Page: page, TotalCount: total}, nil
+}
+
+func itemConditions(query rollbar.ItemQuery) string {
+	conditions := []string{"1 = 1"}
+	if query.Status != "" {
+		conditions = append(conditions, "status = "+quote(query.Status))
+	}
+	if len(query.Levels) > 0 {
+		conditions = append(conditions, "level IN ("+quoteList(query.Levels)+")")
+	}
+	if len(query.Environments) > 0 {
+		conditions = append(conditions, "environment IN ("+quoteList(query.Environments)+")")
+	}
+	if text := strings.TrimSpace(query.Query); text != "" {
+		conditions = append(conditions, "instr(lower(title), lower("+quote(text)+")) > 0")
+	}
+
+	return strings.Join(conditions, " AND ")
+}
+
+// items selects matching items newest first; total is the match count before
+// limit and offset when columns selects it.
+func (s *Store) items(ctx context.Context, where string, columns string, limit int, offset int) ([]rollbar.Item, uint64, error) {
+	sql := "SELECT " + columns + "raw FROM items WHERE " + where + " ORDER BY last_occurrence_timestamp DESC, counter DESC"
+	if limit > 0 {
+		sql += fmt.Sprintf(" LIMIT %d OFFSET %d", limit, offset)
+	}
+
+	var rows []rawRow
+	if err := s.query(ctx, sql, &rows); err != nil {
+		return nil, 0, fmt.Errorf("read items: %w", err)
+	}
+
+	items := make([]rollbar.Item, 0, len(rows))
+	for _, row := range rows {
+		var item rollbar.Item
+		if err := json.Unmarshal([]byte(row.Raw), &item); err != nil {
+			return nil, 0, fmt.Errorf("decode stored item: %w", err)
+		}
+		item.Raw = json.RawMessage(row.Raw)
+		items = append(items, item)
+	}
+	total := uint64(0)
+	if len(rows) > 0 {
+		total = rows[0].Total
+	}
+
+	return items, total, nil
+}
+
+func (s *Store) GetLatestInstance(ctx context.Context, itemID domain.ItemID) (*rollbar.ItemInstance, error) {
+	instances, err := s.instances(ctx, itemID, 1, 0)
+	if err != nil || len(instances) == 0 {
+		return nil, err
+	}
+
+	return &instances[0], nil
+}
+
+// ListInstances pages occurrences newest first, like the API.
+func (s *Store) ListInstances(ctx context.Context, itemID domain.ItemID, page int) (rollbar.InstancePage, error) {
+	instances, err := s.instances(ctx, itemID, instancesPerPage, (max(page, 1)-1)*instancesPerPage)
+	if err != nil {
+		return rollbar.InstancePage{}, err
+	}
+
+	return rollbar.InstancePage{Instances: instances, Page: page}, nil
+}
+
+func (s *Store) instances(ctx context.Context, itemID domain.ItemID, limit int, offset int) ([]rollbar.ItemInstance, error) {
+	var rows []rawRow
+	sql := fmt.Sprintf("SELECT raw FROM occurrences WHERE item_id = %s ORDER BY id DESC LIMIT %d OFFSET %d", itemID, limit, offset)
+	if err := s.query(ctx, sql, &rows); err != nil {
+		return nil, fmt.Errorf("read occurrences: %w", err)
+	}
+
+	instances := make([]rollbar.ItemInstance, 0, len(rows))
+	for _, row := range rows {
+		var instance rollbar.ItemInstance
+		if err := json.Unmarshal([]byte(row.Raw), &instance); err != nil {
+			return nil, fmt.Errorf("decode stored occurrence: %w", err)
+		}
+		instance.Raw = json.RawMessage(row.Raw)
+		instances = append(instances, instance)
+	}
+
+	return instances, nil
+}
+
+// ListEnvironments lists the environments of stored items on one page.
+func (s *Store) ListEnvironments(ctx context.Context, page int) ([]rollbar.Environment, error) {
+	if page > 1 {
+		return nil, nil
+	}
+
+	var rows []struct {
+		Environment string `json:"environment"`
+	}
+	if err := s.query(ctx, "SELECT DISTINCT environment FROM items WHERE environment != '' ORDER BY environment", &rows); err != nil {
+		return nil, fmt.Errorf("read environments: %w", err)
+	}
+
+	environments := make([]rollbar.Environment, 0, len(rows))
+	for _, row := range rows {
+		environments = append(environments, rollbar.Environment{Environment: row.Environment})
+	}
+
+	return environments, nil
+}
+
+func itemJSON(item rollbar.Item) (string, error) {
+	if len(item.Raw) > 0 {
+		return string(item.Raw), nil
+	}
+	encoded, err := json.Marshal(item)
+	if err != nil {
+		return "", fmt.Errorf("encode item %s: %w", item.ID, err)
+	}
+
+	return string(encoded), nil
+}
+
+func uint64Value(value *uint64) uint64 {
+	if value == nil {
+		return 0
+	}
+
+	return *value
+}
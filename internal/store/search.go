@@ -0,0 +1,38 @@
+package store
+
+import (
+	"context"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// exceptionClassesSQL reads the exception class of each item's newest stored
+// occurrence, from a single trace or the first of a trace chain.
+const exceptionClassesSQL = `SELECT item_id AS id, coalesce(
+	json_extract(raw, '$.data.body.trace.exception.class'),
+	json_extract(raw, '$.data.body.trace_chain[0].exception.class')
+) AS class
+FROM occurrences
+WHERE id IN (SELECT max(id) FROM occurrences GROUP BY item_id)`
+
+// ExceptionClasses maps stored items to the exception class of their newest
+// stored occurrence; items without one are left out.
+func (s *Store) ExceptionClasses(ctx context.Context) (map[domain.ItemID]string, error) {
+	var rows []struct {
+		ID    uint64  `json:"id"`
+		Class *string `json:"class"`
+	}
+	if err := s.query(ctx, exceptionClassesSQL, &rows); err != nil {
+		return nil, fmt.Errorf("read exception classes: %w", err)
+	}
+
+	classes := make(map[domain.ItemID]string, len(rows))
+	for _, row := range rows {
+		if row.Class != nil && *row.Class != "" {
+			classes[domain.ItemID(row.ID)] = *row.Class
+		}
+	}
+
+	return classes, nil
+}
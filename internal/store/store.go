@@ -0,0 +1,178 @@
+// Package store keeps a local SQLite copy of one project's items and
+// occurrences, filled by `rollbaz sync` and read by --offline. Like package
+// keyring it drives a command-line tool, sqlite3, so rollbaz needs no cgo;
+// without sqlite3 on PATH, Open reports ErrUnavailable.
+//
+// A Store serves the read half of the Rollbar API that app.Service uses, so
+// commands and the TUI run against it unchanged.
+package store
+
+import (
+	"bytes"
+	"context"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"time"
+)
+
+var (
+	// ErrUnavailable is returned when the sqlite3 tool is not installed.
+	ErrUnavailable = errors.New("local store unavailable: sqlite3 not found on PATH")
+	// ErrNotSynced is returned for records the store does not hold.
+	ErrNotSynced = errors.New("not in the local store; run `rollbaz sync`")
+	// ErrOffline is returned for API calls the store cannot answer.
+	ErrOffline = errors.New("not available offline")
+)
+
+const commandTimeout = time.Minute
+
+const schema = `
+CREATE TABLE IF NOT EXISTS items (
+	id INTEGER PRIMARY KEY,
+	counter INTEGER NOT NULL UNIQUE,
+	status TEXT NOT NULL,
+	level TEXT NOT NULL,
+	environment TEXT NOT NULL,
+	title TEXT NOT NULL,
+	last_occurrence_timestamp INTEGER NOT NULL,
+	raw TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS occurrences (
+	id INTEGER PRIMARY KEY,
+	item_id INTEGER NOT NULL,
+	timestamp INTEGER NOT NULL,
+	raw TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS occurrences_by_item ON occurrences (item_id, id DESC);
+CREATE TABLE IF NOT EXISTS sync_state (
+	key TEXT PRIMARY KEY,
+	value INTEGER NOT NULL
+);
+`
+
+// runner executes sqlite3 against a database, feeding the SQL on stdin and
+// returning stdout.
+type runner func(ctx context.Context, path string, sql string) (string, error)
+
+type Store struct {
+	path string
+	run  runner
+}
+
+// DefaultPath is the database for the project token in the user cache
+// directory. The file is named after a hash of the token, which is never
+// stored.
+func DefaultPath(token string) (string, error) {
+	cacheRoot, err := os.UserCacheDir()
+	if err != nil {
+		return "", fmt.Errorf("resolve cache dir: %w", err)
+	}
+	sum := sha256.Sum256([]byte(token))
+
+	return filepath.Join(cacheRoot, "rollbaz", "store", hex.EncodeToString(sum[:8])+".db"), nil
+}
+
+// Open creates the database at path if needed and brings its schema up to
+// date.
+func Open(ctx context.Context, path string) (*Store, error) {
+	if _, err := exec.LookPath("sqlite3"); err != nil {
+		return nil, ErrUnavailable
+	}
+	path = filepath.Clean(path)
+	if err := os.MkdirAll(filepath.Dir(path), 0o700); err != nil {
+		return nil, fmt.Errorf("create store directory: %w", err)
+	}
+
+	store := &Store{path: path, run: runSQLite}
+	if _, err := store.run(ctx, path, schema); err != nil {
+		return nil, fmt.Errorf("create store schema: %w", err)
+	}
+	if err := os.Chmod(path, 0o600); err != nil {
+		return nil, fmt.Errorf("restrict store permissions: %w", err)
+	}
+
+	return store, nil
+}
+
+func (s *Store) Path() string {
+	return s.path
+}
+
+func runSQLite(ctx context.Context, path string, sql string) (string, error) {
+	ctx, cancel := context.WithTimeout(ctx, commandTimeout)
+	defer cancel()
+
+	//nolint:gosec // G204: sqlite3 is fixed and the path is a cleaned file name.
+	cmd := exec.CommandContext(ctx, "sqlite3", "-bail", "-json", path)
+	cmd.Stdin = strings.NewReader(".timeout 5000\n" + sql)
+	var stdout, stderr bytes.Buffer
+	cmd.Stdout = &stdout
+	cmd.Stderr = &stderr
+
+	if err := cmd.Run(); err != nil {
+		if message := strings.TrimSpace(stderr.String()); message != "" {
+			return "", fmt.Errorf("sqlite3: %s", message)
+		}
+		return "", fmt.Errorf("run sqlite3: %w", err)
+	}
+
+	return stdout.String(), nil
+}
+
+// query runs one SELECT and decodes its rows into target, a pointer to a
+// slice of structs tagged with the column names.
+func (s *Store) query(ctx context.Context, sql string, target any) error {
+	out, err := s.run(ctx, s.path, sql)
+	if err != nil {
+		return err
+	}
+	// sqlite3 prints nothing rather than [] when no row matches.
+	if strings.TrimSpace(out) == "" {
+		out = "[]"
+	}
+	if err := json.Unmarshal([]byte(out), target); err != nil {
+		return fmt.Errorf("decode sqlite3 output: %w", err)
+	}
+
+	return nil
+}
+
+// exec runs statements in one transaction so a failed batch leaves the store
+// as it was.
+func (s *Store) exec(ctx context.Context, statements []string) error {
+	if len(statements) == 0 {
+		return nil
+	}
+	_, err := s.run(ctx, s.path, "BEGIN;\n"+strings.Join(statements, ";\n")+";\nCOMMIT;\n")
+
+	return err
+}
+
+// quote is a SQL string literal. sqlite3 text cannot hold NUL, which JSON
+// encoding never produces, so it is dropped from other text.
+func quote(value string) string {
+	value = strings.ReplaceAll(value, "\x00", "")
+
+	return "'" + strings.ReplaceAll(value, "'", "''") + "'"
+}
+
+func quoteList(values []string) string {
+	quoted := make([]string, 0, len(values))
+	for _, value := range values {
+		quoted = append(quoted, quote(value))
+	}
+
+	return strings.Join(quoted, ", ")
+}
+
+func itoa(value uint64) string {
+	return strconv.FormatUint(value, 10)
+}
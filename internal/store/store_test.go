@@ -0,0 +1,220 @@
+package store
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+func openTestStore(t *testing.T) *Store {
+	t.Helper()
+
+	if _, err := exec.LookPath("sqlite3"); err != nil {
+		t.Skip("sqlite3 not installed")
+	}
+	store, err := Open(context.Background(), filepath.Join(t.TempDir(), "nested", "store.db"))
+	if err != nil {
+		t.Fatalf("Open() error = %v", err)
+	}
+
+	return store
+}
+
+func storeItem(id uint64, status rollbar.Status, environment string, title string, lastSeen uint64) rollbar.Item {
+	return rollbar.Item{ID: domain.ItemID(id), Counter: id + 100, Title: title, Status: status, Level: rollbar.LevelError, Environment: environment, LastOccurrenceTimestamp: &lastSeen}
+}
+
+// openItemsStore opens a store holding items 101 to 103 across production
+// and staging, with 102 resolved.
+func openItemsStore(t *testing.T) *Store {
+	t.Helper()
+
+	store := openTestStore(t)
+	items := []rollbar.Item{
+		storeItem(1, "active", "production", "Can't connect", 300),
+		storeItem(2, "resolved", "staging", "Timeout", 100),
+		storeItem(3, "active", "staging", "can't parse", 200),
+	}
+	if err := store.SaveItems(context.Background(), items); err != nil {
+		t.Fatalf("SaveItems() error = %v", err)
+	}
+
+	return store
+}
+
+func TestStoreItems(t *testing.T) {
+	t.Parallel()
+
+	ctx := context.Background()
+	store := openItemsStore(t)
+
+	tests := []struct {
+		query rollbar.ItemQuery
+		want  []uint64
+	}{
+		{query: rollbar.ItemQuery{}, want: []uint64{101, 103, 102}},
+		{query: rollbar.ItemQuery{Status: "active"}, want: []uint64{101, 103}},
+		{query: rollbar.ItemQuery{Environments: []string{"staging"}}, want: []uint64{103, 102}},
+		{query: rollbar.ItemQuery{Query: "CAN'T"}, want: []uint64{101, 103}},
+		{query: rollbar.ItemQuery{Page: 2}, want: []uint64{}},
+	}
+	for _, tc := range tests {
+		page, err := store.ListItemsPage(ctx, tc.query)
+		if err != nil {
+			t.Fatalf("%+v: ListItemsPage() error = %v", tc.query, err)
+		}
+		got := make([]uint64, 0, len(page.Items))
+		for _, item := range page.Items {
+			got = append(got, item.Counter)
+		}
+		if !slices.Equal(got, tc.want) {
+			t.Fatalf("%+v: got %v, want %v", tc.query, got, tc.want)
+		}
+	}
+}
+
+func TestStoreItemLookups(t *testing.T) {
+	t.Parallel()
+
+	ctx := context.Background()
+	store := openItemsStore(t)
+	id, err := store.ResolveItemIDByCounter(ctx, 103)
+	if err != nil || id != 3 {
+		t.Fatalf("ResolveItemIDByCounter() = %d, %v", id, err)
+	}
+	if _, err := store.ResolveItemIDByCounter(ctx, 999); !errors.Is(err, ErrNotSynced) {
+		t.Fatalf("expected ErrNotSynced, got %v", err)
+	}
+	item, err := store.GetItem(ctx, 1)
+	if err != nil || item.Title != "Can't connect" || item.Status != "active" || len(item.Raw) == 0 {
+		t.Fatalf("GetItem() = %+v, %v", item, err)
+	}
+}
+
+func TestStoreItemSummaries(t *testing.T) {
+	t.Parallel()
+
+	ctx := context.Background()
+	store := openItemsStore(t)
+	active, err := store.ListActiveItems(ctx, 1)
+	if err != nil || len(active) != 1 || active[0].Counter != 101 {
+		t.Fatalf("ListActiveItems() = %+v, %v", active, err)
+	}
+	environments, err := store.ListEnvironments(ctx, 1)
+	if err != nil || len(environments) != 2 || environments[0].Environment != "production" {
+		t.Fatalf("ListEnvironments() = %+v, %v", environments, err)
+	}
+	if err := store.UpdateItem(ctx, 1, rollbar.ItemPatch{Status: "resolved"}); !errors.Is(err, ErrOffline) {
+		t.Fatalf("expected ErrOffline, got %v", err)
+	}
+}
+
+// saveInstances saves occurrences 1 to count of itemID, each with uuid u.
+func saveInstances(t *testing.T, store *Store, itemID domain.ItemID, count uint64) {
+	t.Helper()
+
+	instances := make([]rollbar.ItemInstance, 0, count)
+	for id := uint64(1); id <= count; id++ {
+		instances = append(instances, rollbar.ItemInstance{ID: id, Data: json.RawMessage(`{"uuid":"u"}`)})
+	}
+	if err := store.SaveInstances(context.Background(), itemID, instances); err != nil {
+		t.Fatalf("SaveInstances() error = %v", err)
+	}
+}
+
+func TestStoreInstances(t *testing.T) {
+	t.Parallel()
+
+	ctx := context.Background()
+	store := openTestStore(t)
+	saveInstances(t, store, 7, 25)
+
+	latest, err := store.GetLatestInstance(ctx, 7)
+	if err != nil || latest == nil || latest.ID != 25 || string(latest.Data) != `{"uuid":"u"}` {
+		t.Fatalf("GetLatestInstance() = %+v, %v", latest, err)
+	}
+	page, err := store.ListInstances(ctx, 7, 2)
+	if err != nil || len(page.Instances) != 5 || page.Instances[0].ID != 5 {
+		t.Fatalf("ListInstances() = %+v, %v", page, err)
+	}
+	if id, err := store.LatestInstanceID(ctx, 8); err != nil || id != 0 {
+		t.Fatalf("LatestInstanceID() for unknown item = %d, %v", id, err)
+	}
+}
+
+func TestStoreHighWaterMark(t *testing.T) {
+	t.Parallel()
+
+	ctx := context.Background()
+	store := openTestStore(t)
+	if mark, err := store.HighWaterMark(ctx); err != nil || mark != 0 {
+		t.Fatalf("HighWaterMark() before sync = %d, %v", mark, err)
+	}
+	if err := store.SetHighWaterMark(ctx, 1700000000); err != nil {
+		t.Fatalf("SetHighWaterMark() error = %v", err)
+	}
+	if mark, err := store.HighWaterMark(ctx); err != nil || mark != 1700000000 {
+		t.Fatalf("HighWaterMark() = %d, %v", mark, err)
+	}
+}
+
+func TestStoreExceptionClasses(t *testing.T) {
+	t.Parallel()
+
+	ctx := context.Background()
+	store := openTestStore(t)
+	saves := []struct {
+		itemID domain.ItemID
+		id     uint64
+		data   string
+	}{
+		{itemID: 7, id: 1, data: `{"body":{"trace":{"exception":{"class":"OldError"}}}}`},
+		{itemID: 7, id: 2, data: `{"body":{"trace_chain":[{"exception":{"class":"PaymentTimeout"}}]}}`},
+		{itemID: 8, id: 3, data: `{"body":{"message":{"body":"no trace"}}}`},
+	}
+	for _, save := range saves {
+		if err := store.SaveInstances(ctx, save.itemID, []rollbar.ItemInstance{{ID: save.id, Data: json.RawMessage(save.data)}}); err != nil {
+			t.Fatalf("SaveInstances() error = %v", err)
+		}
+	}
+
+	classes, err := store.ExceptionClasses(ctx)
+	if err != nil || len(classes) != 1 || classes[7] != "PaymentTimeout" {
+		t.Fatalf("ExceptionClasses() = %v, %v", classes, err)
+	}
+}
+
+func TestDefaultPathHidesToken(t *testing.T) {
+	t.Setenv("XDG_CACHE_HOME", t.TempDir())
+
+	path, err := DefaultPath("secret-token")
+	if err != nil {
+		t.Fatalf("DefaultPath() error = %v", err)
+	}
+	if strings.Contains(path, "secret-token") || filepath.Ext(path) != ".db" {
+		t.Fatalf("unexpected path %q", path)
+	}
+	other, _ := DefaultPath("other-token")
+	if other == path {
+		t.Fatal("expected one database per token")
+	}
+}
+
+func TestQuote(t *testing.T) {
+	t.Parallel()
+
+	tests := map[string]string{"plain": "'plain'", "it's": "'it''s'", "nul\x00": "'nul'"}
+	for input, want := range tests {
+		if got := quote(input); got != want {
+			t.Fatalf("quote(%q) = %q, want %q", input, got, want)
+		}
+	}
+}
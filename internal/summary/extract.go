@@ -9,6 +9,9 @@ var preferredErrorPaths = [][]string{
 	{"trace_chain", "0", "exception", "message"},
 	{"body", "trace_chain", "0", "exception", "description"},
 	{"body", "trace_chain", "0", "exception", "message"},
+	{"body", "trace", "exception", "description"},
+	{"body", "trace", "exception", "message"},
+	{"body", "message", "body"},
 	{"exception", "description"},
 	{"exception", "message"},
 	{"message", "body"},
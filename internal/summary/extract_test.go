@@ -41,6 +41,16 @@ var mainErrorTests = []struct {
 		data: `{"body":{"trace_chain":[{"exception":{"message":"nested chain message"}}]}}`,
 		want: "nested chain message",
 	},
+	{
+		name: "supports nested body trace and message",
+		data: `{"body":{"trace":{"exception":{"class":"KeyError","message":"nested trace message"}}}}`,
+		want: "nested trace message",
+	},
+	{
+		name: "supports nested body message",
+		data: `{"body":{"message":{"body":"nested message body"}}}`,
+		want: "nested message body",
+	},
 }
 
 func TestMainError(t *testing.T) {
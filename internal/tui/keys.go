@@ -0,0 +1,91 @@
+package tui
+
+import "unicode/utf8"
+
+// Key is a decoded keypress. Printable input arrives as KeyRune.
+type Key int
+
+const (
+	KeyNone Key = iota
+	KeyRune
+	KeyUp
+	KeyDown
+	KeyPageUp
+	KeyPageDown
+	KeyEnter
+	KeyEscape
+	KeyBackspace
+	KeyInterrupt
+)
+
+type KeyPress struct {
+	Key  Key
+	Rune rune
+}
+
+var escapeSequences = map[string]Key{
+	"\x1b[A":  KeyUp,
+	"\x1bOA":  KeyUp,
+	"\x1b[B":  KeyDown,
+	"\x1bOB":  KeyDown,
+	"\x1b[5~": KeyPageUp,
+	"\x1b[6~": KeyPageDown,
+}
+
+// ParseKeys decodes one read from a raw-mode terminal, which may hold several
+// keypresses. Unrecognised escape sequences are dropped.
+func ParseKeys(data []byte) []KeyPress {
+	keys := make([]KeyPress, 0, len(data))
+	for len(data) > 0 {
+		key, size := parseKey(data)
+		if key.Key != KeyNone {
+			keys = append(keys, key)
+		}
+		data = data[size:]
+	}
+
+	return keys
+}
+
+func parseKey(data []byte) (KeyPress, int) {
+	switch data[0] {
+	case 0x03:
+		return KeyPress{Key: KeyInterrupt}, 1
+	case '\r', '\n':
+		return KeyPress{Key: KeyEnter}, 1
+	case 0x7f, 0x08:
+		return KeyPress{Key: KeyBackspace}, 1
+	case 0x1b:
+		return parseEscape(data)
+	}
+
+	r, size := utf8.DecodeRune(data)
+	if r == utf8.RuneError || r < ' ' {
+		return KeyPress{}, size
+	}
+
+	return KeyPress{Key: KeyRune, Rune: r}, size
+}
+
+func parseEscape(data []byte) (KeyPress, int) {
+	if len(data) == 1 {
+		return KeyPress{Key: KeyEscape}, 1
+	}
+	for sequence, key := range escapeSequences {
+		if len(data) >= len(sequence) && string(data[:len(sequence)]) == sequence {
+			return KeyPress{Key: key}, len(sequence)
+		}
+	}
+	if data[1] != '[' && data[1] != 'O' {
+		return KeyPress{Key: KeyEscape}, 1
+	}
+
+	// Skip an unknown CSI sequence up to its final byte.
+	for index := 2; index < len(data); index++ {
+		if data[index] >= 0x40 && data[index] <= 0x7e {
+			return KeyPress{}, index + 1
+		}
+	}
+
+	return KeyPress{}, len(data)
+}
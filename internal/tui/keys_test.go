@@ -0,0 +1,30 @@
+package tui
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestParseKeys(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name  string
+		input string
+		want  []KeyPress
+	}{
+		{name: "runes", input: "jé", want: []KeyPress{{Key: KeyRune, Rune: 'j'}, {Key: KeyRune, Rune: 'é'}}},
+		{name: "arrows", input: "\x1b[A\x1bOB", want: []KeyPress{{Key: KeyUp}, {Key: KeyDown}}},
+		{name: "paging", input: "\x1b[5~\x1b[6~", want: []KeyPress{{Key: KeyPageUp}, {Key: KeyPageDown}}},
+		{name: "controls", input: "\r\x7f\x03", want: []KeyPress{{Key: KeyEnter}, {Key: KeyBackspace}, {Key: KeyInterrupt}}},
+		{name: "bare escape", input: "\x1b", want: []KeyPress{{Key: KeyEscape}}},
+		{name: "unknown sequence skipped", input: "\x1b[1;5Cq", want: []KeyPress{{Key: KeyRune, Rune: 'q'}}},
+		{name: "other control bytes dropped", input: "\x01", want: []KeyPress{}},
+	}
+
+	for _, tc := range tests {
+		if got := ParseKeys([]byte(tc.input)); !reflect.DeepEqual(got, tc.want) {
+			t.Fatalf("%s: ParseKeys() = %+v, want %+v", tc.name, got, tc.want)
+		}
+	}
+}
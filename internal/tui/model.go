@@ -0,0 +1,241 @@
+package tui
+
+import (
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+// Action is work the event loop performs after a keypress.
+type Action int
+
+const (
+	ActionNone Action = iota
+	ActionQuit
+	ActionRefresh
+	ActionLoadDetail
+	ActionResolve
+	ActionMute
+	ActionAssign
+)
+
+type mode int
+
+const (
+	modeBrowse mode = iota
+	modeConfirm
+	modeAssign
+	modeSearch
+)
+
+// itemView is what the detail and timeline panes show for one item.
+type itemView struct {
+	detail      app.IssueDetail
+	occurrences []app.OccurrenceSummary
+}
+
+// Model is the TUI state. It holds no I/O, so key handling and rendering can
+// be tested without a terminal.
+type Model struct {
+	Items  []app.IssueSummary
+	Cursor int
+	Status string
+	// Input is the assignee email or search text being typed.
+	Input string
+
+	mode    mode
+	pending Action
+	views   map[domain.ItemCounter]itemView
+	// filter is the active "/" search; all holds the unfiltered list while
+	// it is set.
+	filter string
+	all    []app.IssueSummary
+}
+
+func NewModel() *Model {
+	return &Model{views: make(map[domain.ItemCounter]itemView)}
+}
+
+// Selected returns the item under the cursor.
+func (m *Model) Selected() (app.IssueSummary, bool) {
+	if m.Cursor < 0 || m.Cursor >= len(m.Items) {
+		return app.IssueSummary{}, false
+	}
+
+	return m.Items[m.Cursor], true
+}
+
+// HandleKey applies a keypress and reports the action to run. Writes need a
+// "y" to confirm, like the CLI's confirmation prompt.
+func (m *Model) HandleKey(key KeyPress) Action {
+	if key.Key == KeyInterrupt {
+		return ActionQuit
+	}
+
+	switch m.mode {
+	case modeConfirm:
+		return m.handleConfirm(key)
+	case modeAssign:
+		return m.handleAssign(key)
+	case modeSearch:
+		return m.handleSearch(key)
+	default:
+		return m.handleBrowse(key)
+	}
+}
+
+func (m *Model) handleBrowse(key KeyPress) Action {
+	switch key.Key {
+	case KeyUp:
+		m.move(-1)
+	case KeyDown:
+		m.move(1)
+	case KeyPageUp:
+		m.move(-pageStep)
+	case KeyPageDown:
+		m.move(pageStep)
+	case KeyEnter:
+		return m.withSelection(ActionLoadDetail)
+	case KeyRune:
+		return m.handleBrowseRune(key.Rune)
+	}
+
+	return ActionNone
+}
+
+func (m *Model) handleBrowseRune(r rune) Action {
+	switch r {
+	case 'q':
+		return ActionQuit
+	case 'j':
+		m.move(1)
+	case 'k':
+		m.move(-1)
+	case 'R':
+		return ActionRefresh
+	case '/':
+		m.startSearch()
+	case 'r':
+		m.confirm(ActionResolve, "Resolve")
+	case 'm':
+		m.confirm(ActionMute, "Mute")
+	case 'a':
+		if _, ok := m.Selected(); ok {
+			m.mode = modeAssign
+			m.Input = ""
+			m.Status = "Assign to (email): "
+		}
+	}
+
+	return ActionNone
+}
+
+func (m *Model) handleConfirm(key KeyPress) Action {
+	action := m.pending
+	m.mode = modeBrowse
+	m.pending = ActionNone
+	if key.Key == KeyRune && (key.Rune == 'y' || key.Rune == 'Y') {
+		return action
+	}
+	m.Status = "Cancelled."
+
+	return ActionNone
+}
+
+func (m *Model) handleAssign(key KeyPress) Action {
+	switch key.Key {
+	case KeyEnter:
+		m.mode = modeBrowse
+		if m.Input == "" {
+			m.Status = "Cancelled."
+			return ActionNone
+		}
+		return ActionAssign
+	case KeyEscape:
+		m.mode = modeBrowse
+		m.Input = ""
+		m.Status = "Cancelled."
+	case KeyBackspace:
+		if runes := []rune(m.Input); len(runes) > 0 {
+			m.Input = string(runes[:len(runes)-1])
+		}
+	case KeyRune:
+		m.Input += string(key.Rune)
+	}
+
+	return ActionNone
+}
+
+func (m *Model) confirm(action Action, verb string) {
+	selected, ok := m.Selected()
+	if !ok {
+		return
+	}
+	m.mode = modeConfirm
+	m.pending = action
+	m.Status = verb + " #" + selected.Counter.String() + "? (y/n)"
+}
+
+func (m *Model) withSelection(action Action) Action {
+	if _, ok := m.Selected(); !ok {
+		return ActionNone
+	}
+
+	return action
+}
+
+func (m *Model) move(delta int) {
+	if len(m.Items) == 0 {
+		return
+	}
+	m.Cursor = min(max(m.Cursor+delta, 0), len(m.Items)-1)
+}
+
+// SetItems replaces the list and drops cached detail, keeping the cursor on
+// the same item when it is still listed.
+func (m *Model) SetItems(items []app.IssueSummary) {
+	selected, hadSelection := m.Selected()
+	m.Items = items
+	if m.filter != "" {
+		m.all = items
+		m.Items = filterIssues(items, m.filter)
+	}
+	m.views = make(map[domain.ItemCounter]itemView)
+	m.Cursor = 0
+	if !hadSelection {
+		return
+	}
+	for index, item := range items {
+		if item.Counter == selected.Counter {
+			m.Cursor = index
+			return
+		}
+	}
+}
+
+// SetView caches the detail and occurrences shown for an item.
+func (m *Model) SetView(detail app.IssueDetail, occurrences []app.OccurrenceSummary) {
+	m.views[detail.Counter] = itemView{detail: detail, occurrences: occurrences}
+}
+
+// ApplyResult records a successful write: the list row takes the updated
+// summary and the cached detail is dropped so it reloads fresh.
+func (m *Model) ApplyResult(result app.ItemActionResult) {
+	for _, list := range [][]app.IssueSummary{m.Items, m.all} {
+		for index := range list {
+			if list[index].Counter == result.Issue.Counter {
+				list[index] = result.Issue
+			}
+		}
+	}
+	delete(m.views, result.Issue.Counter)
+	m.Status = "#" + result.Issue.Counter.String() + " " + result.Action + "."
+}
+
+func (m *Model) SetError(err error) {
+	m.Status = "Error: " + err.Error()
+}
+
+func (m *Model) view(counter domain.ItemCounter) (itemView, bool) {
+	view, ok := m.views[counter]
+	return view, ok
+}
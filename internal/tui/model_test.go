@@ -0,0 +1,151 @@
+package tui
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+func runeKey(r rune) KeyPress {
+	return KeyPress{Key: KeyRune, Rune: r}
+}
+
+// typeKeys presses each rune of text in turn.
+func typeKeys(model *Model, text string) {
+	for _, r := range text {
+		model.HandleKey(runeKey(r))
+	}
+}
+
+func testItems() []app.IssueSummary {
+	return []app.IssueSummary{
+		{Counter: 1, Title: "first", Status: "active", Level: "error"},
+		{Counter: 2, Title: "second", Status: "active", Level: "warning"},
+		{Counter: 3, Title: "third", Status: "active", Level: "critical"},
+	}
+}
+
+func TestModelNavigation(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		keys       []KeyPress
+		wantCursor int
+		wantAction Action
+	}{
+		{name: "down twice", keys: []KeyPress{runeKey('j'), {Key: KeyDown}}, wantCursor: 2},
+		{name: "clamped at bottom", keys: []KeyPress{{Key: KeyPageDown}, runeKey('j')}, wantCursor: 2},
+		{name: "clamped at top", keys: []KeyPress{runeKey('k'), {Key: KeyUp}}, wantCursor: 0},
+		{name: "enter loads detail", keys: []KeyPress{runeKey('j'), {Key: KeyEnter}}, wantCursor: 1, wantAction: ActionLoadDetail},
+		{name: "refresh", keys: []KeyPress{runeKey('R')}, wantAction: ActionRefresh},
+		{name: "quit", keys: []KeyPress{runeKey('q')}, wantAction: ActionQuit},
+		{name: "interrupt", keys: []KeyPress{{Key: KeyInterrupt}}, wantAction: ActionQuit},
+	}
+
+	for _, tc := range tests {
+		model := NewModel()
+		model.SetItems(testItems())
+		action := ActionNone
+		for _, key := range tc.keys {
+			action = model.HandleKey(key)
+		}
+		if model.Cursor != tc.wantCursor || action != tc.wantAction {
+			t.Fatalf("%s: cursor=%d action=%d, want cursor=%d action=%d", tc.name, model.Cursor, action, tc.wantCursor, tc.wantAction)
+		}
+	}
+}
+
+func TestModelWritesNeedConfirmation(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		keys       []KeyPress
+		wantAction Action
+		wantStatus string
+		wantInput  string
+	}{
+		{name: "resolve confirmed", keys: []KeyPress{runeKey('r'), runeKey('y')}, wantAction: ActionResolve, wantStatus: "Resolve #1? (y/n)"},
+		{name: "mute declined", keys: []KeyPress{runeKey('m'), runeKey('n')}, wantStatus: "Cancelled."},
+		{name: "assign typed", keys: []KeyPress{runeKey('a'), runeKey('d'), runeKey('x'), {Key: KeyBackspace}, runeKey('@'), {Key: KeyEnter}}, wantAction: ActionAssign, wantStatus: "Assign to (email): ", wantInput: "d@"},
+		{name: "assign escaped", keys: []KeyPress{runeKey('a'), runeKey('d'), {Key: KeyEscape}}, wantStatus: "Cancelled."},
+		{name: "assign empty", keys: []KeyPress{runeKey('a'), {Key: KeyEnter}}, wantStatus: "Cancelled."},
+	}
+
+	for _, tc := range tests {
+		model := NewModel()
+		model.SetItems(testItems())
+		action := ActionNone
+		for _, key := range tc.keys {
+			action = model.HandleKey(key)
+		}
+		if action != tc.wantAction || model.Status != tc.wantStatus || model.Input != tc.wantInput {
+			t.Fatalf("%s: action=%d status=%q input=%q", tc.name, action, model.Status, model.Input)
+		}
+	}
+}
+
+func TestModelUpdates(t *testing.T) {
+	t.Parallel()
+
+	model := NewModel()
+	model.SetItems(testItems())
+	model.HandleKey(runeKey('j'))
+	model.SetView(app.IssueDetail{IssueSummary: model.Items[1]}, nil)
+
+	model.ApplyResult(app.ItemActionResult{Action: "resolved", Issue: app.IssueSummary{Counter: 2, Title: "second", Status: "resolved"}})
+	if model.Items[1].Status != "resolved" || model.Status != "#2 resolved." {
+		t.Fatalf("unexpected model after result: %+v status=%q", model.Items[1], model.Status)
+	}
+	if _, ok := model.view(2); ok {
+		t.Fatal("expected cached detail to be dropped after a write")
+	}
+
+	model.SetItems([]app.IssueSummary{{Counter: 9}, {Counter: 2}})
+	if model.Cursor != 1 {
+		t.Fatalf("expected cursor to follow item 2, got %d", model.Cursor)
+	}
+
+	model.SetError(errors.New("boom"))
+	if model.Status != "Error: boom" {
+		t.Fatalf("unexpected status %q", model.Status)
+	}
+}
+
+func TestModelSearch(t *testing.T) {
+	t.Parallel()
+
+	model := NewModel()
+	model.SetItems(testItems())
+	typeKeys(model, "/scnd")
+	if len(model.Items) != 1 || model.Items[0].Counter != 2 || statusLine(model) != "/scnd" {
+		t.Fatalf("expected a fuzzy match on second, got %+v status=%q", model.Items, statusLine(model))
+	}
+
+	model.HandleKey(KeyPress{Key: KeyBackspace})
+	model.HandleKey(KeyPress{Key: KeyEnter})
+	if model.mode != modeBrowse || model.filter != "scn" || headerLine(model) != ` rollbaz · 1 items of 3 matching "scn"` {
+		t.Fatalf("expected the filter to stay after enter, got mode=%d header=%q", model.mode, headerLine(model))
+	}
+}
+
+func TestModelSearchRefreshAndClear(t *testing.T) {
+	t.Parallel()
+
+	model := NewModel()
+	model.SetItems(testItems())
+	typeKeys(model, "/scn")
+	model.HandleKey(KeyPress{Key: KeyEnter})
+	model.SetItems(append(testItems(), app.IssueSummary{Counter: 4, Title: "second again"}))
+	if len(model.Items) != 2 {
+		t.Fatalf("expected a refresh to keep filtering, got %+v", model.Items)
+	}
+
+	model.HandleKey(runeKey('/'))
+	model.HandleKey(KeyPress{Key: KeyEscape})
+	if len(model.Items) != 4 || model.filter != "" {
+		t.Fatalf("expected escape to clear the filter, got %+v", model.Items)
+	}
+}
@@ -0,0 +1,176 @@
+package tui
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"io"
+	"os"
+	"strings"
+	"time"
+
+	"golang.org/x/term"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+)
+
+const (
+	requestTimeout = 20 * time.Second
+	fallbackWidth  = 120
+	fallbackHeight = 40
+
+	enterScreen = "\x1b[?1049h\x1b[?25l"
+	leaveScreen = "\x1b[?25h\x1b[?1049l"
+	homeCursor  = "\x1b[H"
+	clearLine   = "\x1b[K"
+	clearBelow  = "\x1b[J"
+)
+
+// Backend is the data the TUI needs. The CLI adapts app.Service to it so
+// errors are redacted before they reach the screen.
+type Backend interface {
+	List(ctx context.Context) ([]app.IssueSummary, error)
+	Show(ctx context.Context, counter domain.ItemCounter) (app.IssueDetail, []app.OccurrenceSummary, error)
+	Resolve(ctx context.Context, counter domain.ItemCounter) (app.ItemActionResult, error)
+	Mute(ctx context.Context, counter domain.ItemCounter) (app.ItemActionResult, error)
+	Assign(ctx context.Context, counter domain.ItemCounter, email string) (app.ItemActionResult, error)
+}
+
+// Run takes over the terminal until the user quits or ctx is cancelled.
+func Run(ctx context.Context, backend Backend, in *os.File, out *os.File) error {
+	inFD := int(in.Fd())
+	if !term.IsTerminal(inFD) || !term.IsTerminal(int(out.Fd())) {
+		return errors.New("tui needs an interactive terminal")
+	}
+
+	state, err := term.MakeRaw(inFD)
+	if err != nil {
+		return fmt.Errorf("enter raw mode: %w", err)
+	}
+	defer func() { _ = term.Restore(inFD, state) }()
+
+	_, _ = io.WriteString(out, enterScreen)
+	defer func() { _, _ = io.WriteString(out, leaveScreen) }()
+
+	draw := func(m *Model) {
+		width, height, sizeErr := term.GetSize(int(out.Fd()))
+		if sizeErr != nil {
+			width, height = fallbackWidth, fallbackHeight
+		}
+		_, _ = io.WriteString(out, Frame(View(m, width, height)))
+	}
+
+	return Loop(ctx, backend, readKeys(in), draw)
+}
+
+// Frame turns rendered lines into one redraw. Raw mode needs explicit
+// carriage returns.
+func Frame(lines []string) string {
+	return homeCursor + strings.Join(lines, clearLine+"\r\n") + clearLine + clearBelow
+}
+
+// Loop loads the item list, then redraws and handles keys until quit. It is
+// separate from Run so tests can drive it without a terminal.
+func Loop(ctx context.Context, backend Backend, keys <-chan KeyPress, draw func(*Model)) error {
+	model := NewModel()
+	model.Status = "Loading..."
+	draw(model)
+	perform(ctx, backend, model, ActionRefresh)
+
+	for {
+		draw(model)
+		select {
+		case <-ctx.Done():
+			return nil
+		case key, ok := <-keys:
+			if !ok {
+				return nil
+			}
+			action := model.HandleKey(key)
+			if action == ActionQuit {
+				return nil
+			}
+			if action != ActionNone {
+				model.Status = "Working..."
+				draw(model)
+				perform(ctx, backend, model, action)
+			}
+		}
+	}
+}
+
+func perform(parent context.Context, backend Backend, model *Model, action Action) {
+	ctx, cancel := context.WithTimeout(parent, requestTimeout)
+	defer cancel()
+
+	if action == ActionRefresh {
+		items, err := backend.List(ctx)
+		if err != nil {
+			model.SetError(err)
+			return
+		}
+		model.SetItems(items)
+		model.Status = ""
+		return
+	}
+
+	selected, ok := model.Selected()
+	if !ok {
+		return
+	}
+	if err := performOnItem(ctx, backend, model, action, selected.Counter); err != nil {
+		model.SetError(err)
+	}
+}
+
+func performOnItem(ctx context.Context, backend Backend, model *Model, action Action, counter domain.ItemCounter) error {
+	var (
+		result app.ItemActionResult
+		err    error
+	)
+	switch action {
+	case ActionLoadDetail:
+		detail, occurrences, showErr := backend.Show(ctx, counter)
+		if showErr != nil {
+			return showErr
+		}
+		model.SetView(detail, occurrences)
+		model.Status = ""
+		return nil
+	case ActionResolve:
+		result, err = backend.Resolve(ctx, counter)
+	case ActionMute:
+		result, err = backend.Mute(ctx, counter)
+	case ActionAssign:
+		result, err = backend.Assign(ctx, counter, model.Input)
+		model.Input = ""
+	default:
+		return nil
+	}
+	if err != nil {
+		return err
+	}
+	model.ApplyResult(result)
+
+	return nil
+}
+
+func readKeys(in io.Reader) <-chan KeyPress {
+	keys := make(chan KeyPress)
+	go func() {
+		defer close(keys)
+		buffer := make([]byte, 64)
+		for {
+			read, err := in.Read(buffer)
+			for _, key := range ParseKeys(buffer[:read]) {
+				keys <- key
+			}
+			if err != nil {
+				return
+			}
+		}
+	}()
+
+	return keys
+}
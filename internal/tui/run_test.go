@@ -0,0 +1,123 @@
+package tui
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/domain"
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+type fakeBackend struct {
+	assignedTo string
+	resolved   []domain.ItemCounter
+}
+
+func (f *fakeBackend) List(ctx context.Context) ([]app.IssueSummary, error) {
+	return testItems(), nil
+}
+
+func (f *fakeBackend) Show(ctx context.Context, counter domain.ItemCounter) (app.IssueDetail, []app.OccurrenceSummary, error) {
+	seen := uint64(1771513445)
+	instance := &rollbar.ItemInstance{Body: []byte(`{"trace":{"frames":[{"filename":"main.go","lineno":5,"method":"main.main"}],"exception":{"class":"PanicError","message":"boom"}}}`)}
+	detail := app.IssueDetail{IssueSummary: app.IssueSummary{Counter: counter, Title: "first", Status: "active", LastOccurrenceTimestamp: &seen}, Instance: instance}
+	occurrences := []app.OccurrenceSummary{{ID: 1, Timestamp: &seen, Environment: "production", MainError: "PanicError: boom"}}
+
+	return detail, occurrences, nil
+}
+
+func (f *fakeBackend) Resolve(ctx context.Context, counter domain.ItemCounter) (app.ItemActionResult, error) {
+	f.resolved = append(f.resolved, counter)
+	return app.ItemActionResult{Action: "resolved", Issue: app.IssueSummary{Counter: counter, Status: "resolved"}}, nil
+}
+
+func (f *fakeBackend) Mute(ctx context.Context, counter domain.ItemCounter) (app.ItemActionResult, error) {
+	return app.ItemActionResult{}, errors.New("mute failed")
+}
+
+func (f *fakeBackend) Assign(ctx context.Context, counter domain.ItemCounter, email string) (app.ItemActionResult, error) {
+	f.assignedTo = email
+	return app.ItemActionResult{Action: "assigned", Issue: app.IssueSummary{Counter: counter, Status: "active"}}, nil
+}
+
+func sendKeys(input string) <-chan KeyPress {
+	keys := make(chan KeyPress, len(input))
+	for _, key := range ParseKeys([]byte(input)) {
+		keys <- key
+	}
+	close(keys)
+
+	return keys
+}
+
+func resolvedSecond(f *fakeBackend) bool {
+	return len(f.resolved) == 1 && f.resolved[0] == 2
+}
+
+func assignedToDev(f *fakeBackend) bool {
+	return f.assignedTo == "dev@example.com"
+}
+
+func TestLoop(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name       string
+		input      string
+		wantScreen []string
+		check      func(*fakeBackend) bool
+	}{
+		{
+			name:       "detail with trace and timeline",
+			input:      "\r",
+			wantScreen: []string{"#1 first", "Stack Trace:", "PanicError: boom", "at main.main (main.go:5)", "Timeline:", "2026-02-19 15:04  production  PanicError: boom"},
+		},
+		{name: "resolve after confirmation", input: "jry", wantScreen: []string{"#2 resolved."}, check: resolvedSecond},
+		{name: "assign", input: "adev@example.com\r", wantScreen: []string{"#1 assigned."}, check: assignedToDev},
+		{name: "errors reach the status line", input: "my", wantScreen: []string{"Error: mute failed"}},
+	}
+
+	for _, tc := range tests {
+		backend := &fakeBackend{}
+		screen := ""
+		draw := func(m *Model) { screen = strings.Join(View(m, 140, 30), "\n") }
+		if err := Loop(context.Background(), backend, sendKeys(tc.input), draw); err != nil {
+			t.Fatalf("%s: Loop() error = %v", tc.name, err)
+		}
+		for _, want := range tc.wantScreen {
+			if !strings.Contains(screen, want) {
+				t.Fatalf("%s: screen missing %q:\n%s", tc.name, want, screen)
+			}
+		}
+		if tc.check != nil && !tc.check(backend) {
+			t.Fatalf("%s: unexpected backend state: %+v", tc.name, backend)
+		}
+	}
+}
+
+func TestViewLayout(t *testing.T) {
+	t.Parallel()
+
+	model := NewModel()
+	model.SetItems(testItems())
+	model.HandleKey(runeKey('j'))
+
+	lines := View(model, 100, 8)
+	if len(lines) != 8 {
+		t.Fatalf("expected 8 lines, got %d", len(lines))
+	}
+	if !strings.Contains(lines[0], "3 items") || lines[len(lines)-1] != helpLine {
+		t.Fatalf("unexpected header/footer: %q / %q", lines[0], lines[len(lines)-1])
+	}
+	if !strings.HasPrefix(lines[2], reverseVideo+"#2 warning ") || !strings.Contains(lines[3], "Press enter to load details.") {
+		t.Fatalf("unexpected body:\n%s", strings.Join(lines, "\n"))
+	}
+
+	frame := Frame([]string{"a", "b"})
+	if frame != homeCursor+"a"+clearLine+"\r\nb"+clearLine+clearBelow {
+		t.Fatalf("unexpected frame %q", frame)
+	}
+}
@@ -0,0 +1,79 @@
+package tui
+
+import (
+	"sort"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+)
+
+// startSearch opens the "/" prompt, seeded with the active filter.
+func (m *Model) startSearch() {
+	if m.filter == "" {
+		m.all = m.Items
+	}
+	m.mode = modeSearch
+	m.Input = m.filter
+	m.Status = "/"
+}
+
+// handleSearch narrows the list as the text is typed. Enter keeps the
+// filter and escape drops it.
+func (m *Model) handleSearch(key KeyPress) Action {
+	switch key.Key {
+	case KeyEnter:
+		m.mode = modeBrowse
+		m.Status = ""
+		return ActionNone
+	case KeyEscape:
+		m.mode = modeBrowse
+		m.Input = ""
+		m.Status = ""
+	case KeyBackspace:
+		if runes := []rune(m.Input); len(runes) > 0 {
+			m.Input = string(runes[:len(runes)-1])
+		}
+	case KeyRune:
+		m.Input += string(key.Rune)
+	default:
+		return ActionNone
+	}
+	m.setFilter(m.Input)
+
+	return ActionNone
+}
+
+func (m *Model) setFilter(filter string) {
+	m.filter = strings.TrimSpace(filter)
+	m.Items = filterIssues(m.all, m.filter)
+	m.Cursor = 0
+	if m.filter == "" {
+		m.all = nil
+	}
+}
+
+// filterIssues keeps the items whose titles fuzzy-match filter, best first.
+func filterIssues(items []app.IssueSummary, filter string) []app.IssueSummary {
+	if filter == "" {
+		return items
+	}
+
+	type match struct {
+		issue app.IssueSummary
+		score int
+	}
+	matches := make([]match, 0, len(items))
+	for _, issue := range items {
+		if score, ok := app.FuzzyScore(filter, issue.Title); ok {
+			matches = append(matches, match{issue: issue, score: score})
+		}
+	}
+	sort.SliceStable(matches, func(i int, j int) bool { return matches[i].score > matches[j].score })
+
+	filtered := make([]app.IssueSummary, 0, len(matches))
+	for _, found := range matches {
+		filtered = append(filtered, found.issue)
+	}
+
+	return filtered
+}
@@ -0,0 +1,183 @@
+package tui
+
+import (
+	"math"
+	"strconv"
+	"strings"
+	"time"
+
+	prettytext "github.com/jedib0t/go-pretty/v6/text"
+
+	"github.com/kevinsheth/rollbaz/internal/app"
+	"github.com/kevinsheth/rollbaz/internal/output"
+)
+
+const (
+	pageStep        = 10
+	minListWidth    = 30
+	paneSeparator   = " │ "
+	detailMaxFrames = 8
+	timelineLimit   = 10
+	helpLine        = "j/k move  enter details  / search  r resolve  m mute  a assign  R refresh  q quit"
+	reverseVideo    = "\x1b[7m"
+	resetStyle      = "\x1b[0m"
+)
+
+// View renders the whole screen as width x height lines: the item list on the
+// left, the selected item's detail and occurrence timeline on the right, and
+// the status and key help at the bottom.
+func View(m *Model, width int, height int) []string {
+	bodyHeight := max(height-3, 1)
+	listWidth := max(width*2/5, minListWidth)
+	detailWidth := max(width-listWidth-len([]rune(paneSeparator)), 1)
+
+	left := listLines(m, listWidth, bodyHeight)
+	right := detailLines(m, detailWidth)
+
+	lines := make([]string, 0, height)
+	lines = append(lines, fit(headerLine(m), width))
+	for row := range bodyHeight {
+		line := pad(left[row], listWidth) + paneSeparator
+		if row < len(right) {
+			line += fit(right[row], detailWidth)
+		}
+		lines = append(lines, line)
+	}
+	lines = append(lines, fit(statusLine(m), width), fit(helpLine, width))
+
+	return lines
+}
+
+func headerLine(m *Model) string {
+	header := " rollbaz · " + strconv.Itoa(len(m.Items)) + " items"
+	if m.filter != "" {
+		header += " of " + strconv.Itoa(len(m.all)) + " matching " + strconv.Quote(m.filter)
+	}
+
+	return header
+}
+
+func statusLine(m *Model) string {
+	if m.mode == modeAssign || m.mode == modeSearch {
+		return m.Status + m.Input
+	}
+
+	return m.Status
+}
+
+// listLines renders the visible window of the item list, scrolled so the
+// cursor stays on screen. The selected row is shown in reverse video.
+func listLines(m *Model, width int, height int) []string {
+	lines := make([]string, height)
+	if len(m.Items) == 0 {
+		lines[0] = "no issues found"
+		return lines
+	}
+
+	offset := max(m.Cursor-height+1, 0)
+	for row := range height {
+		index := offset + row
+		if index >= len(m.Items) {
+			break
+		}
+		item := m.Items[index]
+		line := fit("#"+item.Counter.String()+" "+padLevel(item.Level)+" "+item.Title, width)
+		if index == m.Cursor {
+			line = reverseVideo + pad(line, width) + resetStyle
+		}
+		lines[row] = line
+	}
+
+	return lines
+}
+
+func detailLines(m *Model, width int) []string {
+	selected, ok := m.Selected()
+	if !ok {
+		return nil
+	}
+	view, ok := m.view(selected.Counter)
+	if !ok {
+		return []string{"#" + selected.Counter.String() + " " + selected.Title, "", "Press enter to load details."}
+	}
+
+	detail := view.detail
+	lines := []string{
+		"#" + detail.Counter.String() + " " + detail.Title,
+		"",
+		"Status:      " + detail.Status,
+		"Level:       " + orUnknown(detail.Level),
+		"Environment: " + orUnknown(detail.Environment),
+		"First seen:  " + formatTime(detail.FirstOccurrenceTimestamp),
+		"Last seen:   " + formatTime(detail.LastOccurrenceTimestamp),
+		"Occurrences: " + formatCount(detail.Occurrences),
+	}
+	if detail.Instance != nil {
+		trace := output.RenderOccurrenceBody(detail.Instance.OccurrenceBody(), output.TraceOptions{MaxFrames: detailMaxFrames})
+		lines = append(lines, "", "Stack Trace:")
+		lines = append(lines, strings.Split(trace, "\n")...)
+	}
+
+	return append(lines, timelineLines(view.occurrences, width)...)
+}
+
+func timelineLines(occurrences []app.OccurrenceSummary, width int) []string {
+	lines := []string{"", "Timeline:"}
+	if len(occurrences) == 0 {
+		return append(lines, "  no occurrences found")
+	}
+	for _, occurrence := range occurrences[:min(len(occurrences), timelineLimit)] {
+		line := "  " + formatTime(occurrence.Timestamp) + "  " + orUnknown(occurrence.Environment) + "  " + occurrence.MainError
+		lines = append(lines, fit(line, width))
+	}
+	if len(occurrences) > timelineLimit {
+		lines = append(lines, "  ... "+strconv.Itoa(len(occurrences)-timelineLimit)+" more")
+	}
+
+	return lines
+}
+
+func fit(value string, width int) string {
+	value = strings.ReplaceAll(value, "\t", " ")
+	if prettytext.StringWidthWithoutEscSequences(value) <= width {
+		return value
+	}
+
+	return prettytext.Trim(value, max(width-1, 0)) + "…"
+}
+
+func pad(value string, width int) string {
+	if gap := width - prettytext.StringWidthWithoutEscSequences(value); gap > 0 {
+		return value + strings.Repeat(" ", gap)
+	}
+
+	return value
+}
+
+func padLevel(level string) string {
+	return pad(orUnknown(level), len("critical"))
+}
+
+func orUnknown(value string) string {
+	if strings.TrimSpace(value) == "" {
+		return "unknown"
+	}
+
+	return value
+}
+
+func formatTime(unixSeconds *uint64) string {
+	if unixSeconds == nil || *unixSeconds > math.MaxInt64 {
+		return "unknown"
+	}
+
+	return time.Unix(int64(*unixSeconds), 0).UTC().Format("2006-01-02 15:04")
+}
+
+func formatCount(count *uint64) string {
+	if count == nil {
+		return "unknown"
+	}
+
+	return strconv.FormatUint(*count, 10)
+}
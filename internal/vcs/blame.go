@@ -0,0 +1,101 @@
+package vcs
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"os/exec"
+	"strconv"
+	"strings"
+)
+
+// BlameLine is the commit that last changed one line of a file.
+type BlameLine struct {
+	Commit  string `json:"commit"`
+	Author  string `json:"author"`
+	Email   string `json:"email,omitempty"`
+	Time    int64  `json:"time"`
+	Summary string `json:"summary"`
+	Line    int    `json:"line"`
+	Code    string `json:"code"`
+}
+
+// Blame runs `git blame` in dir on line (1-based) of path, relative to dir.
+func Blame(ctx context.Context, dir string, path string, line int) (BlameLine, error) {
+	if line <= 0 {
+		return BlameLine{}, errors.New("frame has no line number")
+	}
+
+	//nolint:gosec // G204: git is fixed; the line range and path are separate arguments after --.
+	command := exec.CommandContext(ctx, "git", "blame", "--porcelain", "-L", fmt.Sprintf("%d,%d", line, line), "--", path)
+	command.Dir = dir
+	var stderr bytes.Buffer
+	command.Stderr = &stderr
+
+	output, err := command.Output()
+	if err != nil {
+		if message := strings.TrimSpace(stderr.String()); message != "" {
+			return BlameLine{}, fmt.Errorf("git blame %s: %s", path, firstLine(message))
+		}
+		return BlameLine{}, fmt.Errorf("git blame %s: %w", path, err)
+	}
+
+	return parsePorcelain(output)
+}
+
+// parsePorcelain reads the single line `git blame --porcelain` reports: a
+// "<commit> <original line> <final line> <count>" header, the commit's
+// headers, then the line itself after a tab.
+func parsePorcelain(output []byte) (BlameLine, error) {
+	scanner := bufio.NewScanner(bytes.NewReader(output))
+	if !scanner.Scan() {
+		return BlameLine{}, errors.New("git blame printed nothing")
+	}
+	header := strings.Fields(scanner.Text())
+	if len(header) < 3 {
+		return BlameLine{}, fmt.Errorf("unexpected git blame header %q", scanner.Text())
+	}
+	line, err := strconv.Atoi(header[2])
+	if err != nil {
+		return BlameLine{}, fmt.Errorf("unexpected git blame header %q", scanner.Text())
+	}
+
+	blame := BlameLine{Commit: header[0], Line: line}
+	for scanner.Scan() {
+		text := scanner.Text()
+		if code, ok := strings.CutPrefix(text, "\t"); ok {
+			blame.Code = code
+			break
+		}
+		blame.setHeader(text)
+	}
+
+	return blame, nil
+}
+
+func (b *BlameLine) setHeader(text string) {
+	key, value, _ := strings.Cut(text, " ")
+	switch key {
+	case "author":
+		b.Author = value
+	case "author-mail":
+		b.Email = strings.Trim(value, "<>")
+	case "author-time":
+		b.Time, _ = strconv.ParseInt(value, 10, 64)
+	case "summary":
+		b.Summary = value
+	}
+}
+
+// Uncommitted reports whether the line has changes not committed yet, which
+// git blames on an all-zero commit.
+func (b BlameLine) Uncommitted() bool {
+	return strings.Trim(b.Commit, "0") == ""
+}
+
+func firstLine(text string) string {
+	first, _, _ := strings.Cut(text, "\n")
+	return first
+}
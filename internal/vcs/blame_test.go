@@ -0,0 +1,98 @@
+package vcs
+
+import (
+	"context"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestParsePorcelain(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name   string
+		output string
+		want   BlameLine
+		err    string
+	}{
+		{
+			name:   "committed",
+			output: testSHA + " 10 12 1\nauthor Ana Lopez\nauthor-mail <ana@example.com>\nauthor-time 1767225600\nauthor-tz +0000\nsummary Retry declined charges\nfilename pay/charge.py\n\tcharge(order)\n",
+			want:   BlameLine{Commit: testSHA, Author: "Ana Lopez", Email: "ana@example.com", Time: 1767225600, Summary: "Retry declined charges", Line: 12, Code: "charge(order)"},
+		},
+		{name: "empty", output: "", err: "printed nothing"},
+		{name: "short header", output: testSHA + " 10\n", err: "unexpected git blame header"},
+		{name: "bad line", output: testSHA + " 10 x 1\n", err: "unexpected git blame header"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			got, err := parsePorcelain([]byte(tt.output))
+			if tt.err != "" {
+				if err == nil || !strings.Contains(err.Error(), tt.err) {
+					t.Fatalf("parsePorcelain() error = %v, want %q", err, tt.err)
+				}
+				return
+			}
+			if err != nil || got != tt.want {
+				t.Fatalf("parsePorcelain() = %+v, %v; want %+v", got, err, tt.want)
+			}
+		})
+	}
+}
+
+// runGit runs git with args in dir.
+func runGit(t *testing.T, dir string, args ...string) {
+	t.Helper()
+
+	command := exec.Command("git", args...)
+	command.Dir = dir
+	if output, err := command.CombinedOutput(); err != nil {
+		t.Fatalf("git %v: %v\n%s", args, err, output)
+	}
+}
+
+// commitCharge returns a repository where Ana Lopez committed a two-line
+// pay/charge.py, skipping the test when git is missing.
+func commitCharge(t *testing.T) string {
+	t.Helper()
+
+	if _, err := exec.LookPath("git"); err != nil {
+		t.Skip("git is not installed")
+	}
+	dir := t.TempDir()
+	runGit(t, dir, "init", "-q")
+	writeFile(t, filepath.Join(dir, "pay", "charge.py"), "def charge():\n    raise KeyError\n")
+	runGit(t, dir, "add", ".")
+	runGit(t, dir, "-c", "user.name=Ana Lopez", "-c", "user.email=ana@example.com", "commit", "-q", "-m", "Raise on unknown carts")
+	return dir
+}
+
+func TestBlame(t *testing.T) {
+	dir := commitCharge(t)
+	got, err := Blame(context.Background(), dir, "pay/charge.py", 2)
+	if err != nil || got.Author != "Ana Lopez" || got.Email != "ana@example.com" || got.Summary != "Raise on unknown carts" || got.Code != "    raise KeyError" || got.Uncommitted() {
+		t.Fatalf("Blame() = %+v, %v", got, err)
+	}
+}
+
+func TestBlameUncommitted(t *testing.T) {
+	dir := commitCharge(t)
+	writeFile(t, filepath.Join(dir, "pay", "charge.py"), "def charge():\n    raise ValueError\n")
+	if got, err := Blame(context.Background(), dir, "pay/charge.py", 2); err != nil || !got.Uncommitted() {
+		t.Fatalf("expected an uncommitted line, got %+v, %v", got, err)
+	}
+}
+
+func TestBlameErrors(t *testing.T) {
+	dir := commitCharge(t)
+	if _, err := Blame(context.Background(), dir, "pay/charge.py", 40); err == nil || !strings.Contains(err.Error(), "git blame pay/charge.py") {
+		t.Fatalf("expected a line range error, got %v", err)
+	}
+	if _, err := Blame(context.Background(), dir, "pay/charge.py", 0); err == nil {
+		t.Fatal("expected an error for a frame without a line")
+	}
+}
@@ -0,0 +1,163 @@
+package vcs
+
+import (
+	"bufio"
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"regexp"
+	"strings"
+)
+
+// RevisionEnv overrides detection, for builds where .git is not shipped.
+const RevisionEnv = "GIT_SHA"
+
+var shaPattern = regexp.MustCompile(`^[0-9a-f]{7,64}$`)
+
+// Revision returns the commit SHA for dir, trying $GIT_SHA, then .git/HEAD in
+// dir or its parents, then `git rev-parse HEAD`.
+func Revision(ctx context.Context, dir string) (string, error) {
+	if sha := strings.TrimSpace(os.Getenv(RevisionEnv)); sha != "" {
+		return sha, nil
+	}
+
+	if sha, err := revisionFromGitDir(dir); err == nil {
+		return sha, nil
+	}
+
+	sha, err := revisionFromGit(ctx, dir)
+	if err != nil {
+		return "", fmt.Errorf("detect git revision: %w", err)
+	}
+
+	return sha, nil
+}
+
+func revisionFromGitDir(dir string) (string, error) {
+	gitDir, err := findGitDir(dir)
+	if err != nil {
+		return "", err
+	}
+
+	head, err := readTrimmed(filepath.Join(gitDir, "HEAD"))
+	if err != nil {
+		return "", err
+	}
+
+	ref, isRef := strings.CutPrefix(head, "ref: ")
+	if !isRef {
+		return validSHA(head)
+	}
+
+	if sha, err := readTrimmed(filepath.Join(gitDir, filepath.FromSlash(ref))); err == nil {
+		return validSHA(sha)
+	}
+
+	return packedRef(filepath.Join(commonDir(gitDir), "packed-refs"), ref)
+}
+
+// findGitDir walks up from dir to the nearest .git, following the "gitdir:"
+// pointer files used by worktrees and submodules.
+func findGitDir(dir string) (string, error) {
+	current, err := filepath.Abs(dir)
+	if err != nil {
+		return "", fmt.Errorf("resolve directory: %w", err)
+	}
+
+	for {
+		candidate := filepath.Join(current, ".git")
+		info, err := os.Stat(candidate)
+		if err == nil && info.IsDir() {
+			return candidate, nil
+		}
+		if err == nil {
+			return gitDirFromFile(candidate)
+		}
+
+		parent := filepath.Dir(current)
+		if parent == current {
+			return "", errors.New("no .git directory found")
+		}
+		current = parent
+	}
+}
+
+func gitDirFromFile(path string) (string, error) {
+	content, err := readTrimmed(path)
+	if err != nil {
+		return "", err
+	}
+
+	target, ok := strings.CutPrefix(content, "gitdir: ")
+	if !ok {
+		return "", fmt.Errorf("unrecognized .git file %s", path)
+	}
+	if !filepath.IsAbs(target) {
+		target = filepath.Join(filepath.Dir(path), target)
+	}
+
+	return target, nil
+}
+
+// commonDir resolves the shared git directory of a worktree, where refs live.
+func commonDir(gitDir string) string {
+	common, err := readTrimmed(filepath.Join(gitDir, "commondir"))
+	if err != nil {
+		return gitDir
+	}
+	if filepath.IsAbs(common) {
+		return common
+	}
+
+	return filepath.Join(gitDir, common)
+}
+
+func packedRef(path string, ref string) (string, error) {
+	body, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return "", fmt.Errorf("read packed refs: %w", err)
+	}
+
+	scanner := bufio.NewScanner(bytes.NewReader(body))
+	for scanner.Scan() {
+		sha, name, ok := strings.Cut(scanner.Text(), " ")
+		if ok && name == ref {
+			return validSHA(sha)
+		}
+	}
+
+	return "", fmt.Errorf("ref %s not found", ref)
+}
+
+func revisionFromGit(ctx context.Context, dir string) (string, error) {
+	command := exec.CommandContext(ctx, "git", "rev-parse", "HEAD")
+	command.Dir = dir
+
+	output, err := command.Output()
+	if err != nil {
+		return "", fmt.Errorf("git rev-parse: %w", err)
+	}
+
+	return validSHA(strings.TrimSpace(string(output)))
+}
+
+func readTrimmed(path string) (string, error) {
+	body, err := os.ReadFile(filepath.Clean(path))
+	if err != nil {
+		return "", fmt.Errorf("read %s: %w", filepath.Base(path), err)
+	}
+
+	return strings.TrimSpace(string(body)), nil
+}
+
+func validSHA(value string) (string, error) {
+	if !shaPattern.MatchString(value) {
+		return "", fmt.Errorf("invalid revision %q", value)
+	}
+
+	return value, nil
+}
@@ -0,0 +1,113 @@
+package vcs
+
+import (
+	"context"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+const testSHA = "0123456789abcdef0123456789abcdef01234567"
+
+func writeFile(t *testing.T, path string, content string) {
+	t.Helper()
+	if err := os.MkdirAll(filepath.Dir(path), 0o700); err != nil {
+		t.Fatalf("mkdir: %v", err)
+	}
+	if err := os.WriteFile(path, []byte(content), 0o600); err != nil {
+		t.Fatalf("write %s: %v", path, err)
+	}
+}
+
+// nestedBranchRepo checks out main in root and returns a directory inside it.
+func nestedBranchRepo(t *testing.T, root string) string {
+	t.Helper()
+	writeFile(t, filepath.Join(root, ".git", "HEAD"), "ref: refs/heads/main\n")
+	writeFile(t, filepath.Join(root, ".git", "refs", "heads", "main"), testSHA+"\n")
+	nested := filepath.Join(root, "cmd", "app")
+	if err := os.MkdirAll(nested, 0o700); err != nil {
+		t.Fatalf("mkdir: %v", err)
+	}
+	return nested
+}
+
+func detachedRepo(t *testing.T, root string) string {
+	t.Helper()
+	writeFile(t, filepath.Join(root, ".git", "HEAD"), testSHA+"\n")
+	return root
+}
+
+func packedRefRepo(t *testing.T, root string) string {
+	t.Helper()
+	writeFile(t, filepath.Join(root, ".git", "HEAD"), "ref: refs/heads/release\n")
+	writeFile(t, filepath.Join(root, ".git", "packed-refs"), "# pack-refs with: peeled\n"+testSHA+" refs/heads/release\n")
+	return root
+}
+
+// worktreeRepo adds a feature worktree of a repo under root and returns the
+// worktree, whose .git is a gitdir file.
+func worktreeRepo(t *testing.T, root string) string {
+	t.Helper()
+	common := filepath.Join(root, "main", ".git")
+	worktreeGit := filepath.Join(common, "worktrees", "feature")
+	writeFile(t, filepath.Join(worktreeGit, "HEAD"), "ref: refs/heads/feature\n")
+	writeFile(t, filepath.Join(worktreeGit, "commondir"), "../..\n")
+	writeFile(t, filepath.Join(common, "refs", "heads", "feature"), testSHA+"\n")
+	writeFile(t, filepath.Join(root, "feature", ".git"), "gitdir: "+worktreeGit+"\n")
+	return filepath.Join(root, "feature")
+}
+
+func TestRevisionFromGitDir(t *testing.T) {
+	tests := []struct {
+		name  string
+		setup func(t *testing.T, root string) string
+	}{
+		{name: "branch ref from nested dir", setup: nestedBranchRepo},
+		{name: "detached head", setup: detachedRepo},
+		{name: "packed ref", setup: packedRefRepo},
+		{name: "worktree gitdir file", setup: worktreeRepo},
+	}
+
+	for _, tc := range tests {
+		t.Run(tc.name, func(t *testing.T) {
+			dir := tc.setup(t, t.TempDir())
+			got, err := revisionFromGitDir(dir)
+			if err != nil || got != testSHA {
+				t.Fatalf("revisionFromGitDir() = %q, %v", got, err)
+			}
+		})
+	}
+}
+
+func TestRevisionPrefersEnv(t *testing.T) {
+	t.Setenv(RevisionEnv, " abc1234 ")
+
+	got, err := Revision(context.Background(), t.TempDir())
+	if err != nil || got != "abc1234" {
+		t.Fatalf("Revision() = %q, %v", got, err)
+	}
+}
+
+func TestRevisionInvalidHead(t *testing.T) {
+	t.Setenv(RevisionEnv, "")
+
+	root := t.TempDir()
+	writeFile(t, filepath.Join(root, ".git", "HEAD"), "garbage\n")
+	if _, err := revisionFromGitDir(root); err == nil {
+		t.Fatalf("expected invalid revision error")
+	}
+
+	writeFile(t, filepath.Join(root, ".git", "HEAD"), "ref: refs/heads/missing\n")
+	if _, err := revisionFromGitDir(root); err == nil {
+		t.Fatalf("expected missing ref error")
+	}
+}
+
+func TestGitDirFromFileRejectsUnknownFormat(t *testing.T) {
+	root := t.TempDir()
+	writeFile(t, filepath.Join(root, ".git"), "something else")
+
+	if _, err := revisionFromGitDir(root); err == nil {
+		t.Fatalf("expected unrecognized .git file error")
+	}
+}
@@ -0,0 +1,84 @@
+package webhook
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// ErrUntypedEvent is returned by Payload for events without a typed form;
+// their Event.Data is still decoded.
+var ErrUntypedEvent = errors.New("no typed payload for webhook event")
+
+// Payload is one of NewItem, Reactivated, ItemVelocity, or Deploy, for use in
+// a type switch.
+type Payload interface {
+	EventName() string
+}
+
+// NewItem is the first occurrence of a new item.
+type NewItem struct {
+	Item       rollbar.Item
+	Occurrence json.RawMessage
+}
+
+// Reactivated is a resolved item occurring again.
+type Reactivated struct {
+	Item       rollbar.Item
+	Occurrence json.RawMessage
+}
+
+// ItemVelocity is an item occurring faster than a notification rule allows.
+type ItemVelocity struct {
+	Item       rollbar.Item
+	Occurrence json.RawMessage
+	Trigger    Trigger
+}
+
+// Deploy is a deploy reported to the project.
+type Deploy struct {
+	Deploy rollbar.Deploy
+}
+
+func (NewItem) EventName() string { return EventNewItem }
+func (Reactivated) EventName() string { return EventReactivatedItem }
+func (ItemVelocity) EventName() string { return EventItemVelocity }
+func (Deploy) EventName() string { return EventDeploy }
+
+// Payload returns the delivery in its typed form. Events other than new_item,
+// reactivated_item, item_velocity, and deploy return ErrUntypedEvent.
+func (e Event) Payload() (Payload, error) {
+	switch e.Name {
+	case EventNewItem, EventReactivatedItem, EventItemVelocity:
+		return e.itemPayload()
+	case EventDeploy:
+		if e.Data.Deploy == nil {
+			return nil, fmt.Errorf("%s webhook has no deploy", e.Name)
+		}
+		return Deploy{Deploy: *e.Data.Deploy}, nil
+	default:
+		return nil, fmt.Errorf("%w: %s", ErrUntypedEvent, e.Name)
+	}
+}
+
+func (e Event) itemPayload() (Payload, error) {
+	if e.Data.Item == nil {
+		return nil, fmt.Errorf("%s webhook has no item", e.Name)
+	}
+	item := *e.Data.Item
+
+	switch e.Name {
+	case EventReactivatedItem:
+		return Reactivated{Item: item, Occurrence: e.Data.Occurrence}, nil
+	case EventItemVelocity:
+		velocity := ItemVelocity{Item: item, Occurrence: e.Data.Occurrence}
+		if e.Data.Trigger != nil {
+			velocity.Trigger = *e.Data.Trigger
+		}
+		return velocity, nil
+	default:
+		return NewItem{Item: item, Occurrence: e.Data.Occurrence}, nil
+	}
+}
@@ -0,0 +1,95 @@
+package webhook
+
+import (
+	"errors"
+	"fmt"
+	"testing"
+)
+
+// payloadSummary describes the fields of p that TestEventPayload checks.
+func payloadSummary(p Payload) string {
+	switch p := p.(type) {
+	case NewItem:
+		return fmt.Sprintf("new item %d %s", p.Item.Counter, p.Occurrence)
+	case Reactivated:
+		return fmt.Sprintf("reactivated %d", p.Item.Counter)
+	case ItemVelocity:
+		return fmt.Sprintf("velocity %d %d/%d", p.Item.Counter, p.Trigger.Threshold, p.Trigger.WindowSize)
+	case Deploy:
+		return "deploy " + p.Deploy.Revision
+	default:
+		return fmt.Sprintf("%T", p)
+	}
+}
+
+func TestEventPayload(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		body string
+		want string
+	}{
+		{body: newItemPayload, want: `new item 4 {"uuid":"0f1e"}`},
+		{body: `{"event_name":"reactivated_item","data":{"item":{"counter":7,"status":"active"}}}`, want: "reactivated 7"},
+		{body: `{"event_name":"item_velocity","data":{"item":{"counter":8},"trigger":{"window_size":300,"window_size_description":"5 minutes","threshold":10}}}`, want: "velocity 8 10/300"},
+		{body: `{"event_name":"deploy","data":{"deploy":{"environment":"production","revision":"abc123"}}}`, want: "deploy abc123"},
+	}
+	for _, tc := range tests {
+		event, err := Parse([]byte(tc.body))
+		if err != nil {
+			t.Fatalf("Parse(%s) error = %v", tc.body, err)
+		}
+		payload, err := event.Payload()
+		if err != nil || payloadSummary(payload) != tc.want || payload.EventName() != event.Name {
+			t.Fatalf("%s: unexpected payload %#v, %v", tc.body, payload, err)
+		}
+	}
+}
+
+func TestEventPayloadErrors(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		body    string
+		wantErr string
+	}{
+		{body: `{"event_name":"new_item","data":{}}`, wantErr: "new_item webhook has no item"},
+		{body: `{"event_name":"deploy","data":{}}`, wantErr: "deploy webhook has no deploy"},
+		{body: `{"event_name":"resolved_item","data":{}}`, wantErr: "no typed payload for webhook event: resolved_item"},
+	}
+	for _, tc := range tests {
+		event, err := Parse([]byte(tc.body))
+		if err != nil {
+			t.Fatalf("Parse(%s) error = %v", tc.body, err)
+		}
+		if _, err := event.Payload(); err == nil || err.Error() != tc.wantErr {
+			t.Fatalf("%s: expected %q error, got %v", tc.body, tc.wantErr, err)
+		}
+	}
+
+	if _, err := (Event{Name: EventTest}).Payload(); !errors.Is(err, ErrUntypedEvent) {
+		t.Fatalf("expected ErrUntypedEvent, got %v", err)
+	}
+}
+
+func TestVerifySignature(t *testing.T) {
+	t.Parallel()
+
+	body := []byte(newItemPayload)
+	tests := []struct {
+		secret    string
+		signature string
+		want      bool
+	}{
+		{secret: "s3cret", signature: Sign("s3cret", body), want: true},
+		{secret: "s3cret", signature: "sha256=" + Sign("s3cret", body), want: true},
+		{secret: "s3cret", signature: Sign("other", body)},
+		{secret: "", signature: Sign("", body)},
+		{secret: "s3cret"},
+	}
+	for _, tc := range tests {
+		if got := VerifySignature(tc.secret, body, tc.signature); got != tc.want {
+			t.Fatalf("VerifySignature(%q, %q) = %v, want %v", tc.secret, tc.signature, got, tc.want)
+		}
+	}
+}
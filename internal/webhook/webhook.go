@@ -0,0 +1,152 @@
+// Package webhook decodes and authenticates Rollbar webhook deliveries and
+// serves them over HTTP.
+package webhook
+
+import (
+	"crypto/hmac"
+	"crypto/sha256"
+	"crypto/subtle"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"net/http"
+	"strings"
+
+	"github.com/kevinsheth/rollbaz/internal/rollbar"
+)
+
+// Event names Rollbar sends. Others are passed through unchanged.
+const (
+	EventNewItem         = "new_item"
+	EventOccurrence      = "occurrence"
+	EventRepeatItem      = "exp_repeat_item"
+	EventItemVelocity    = "item_velocity"
+	EventReactivatedItem = "reactivated_item"
+	EventReopenedItem    = "reopened_item"
+	EventResolvedItem    = "resolved_item"
+	EventDeploy          = "deploy"
+	EventTest            = "test"
+)
+
+// SignatureHeader carries the hex HMAC-SHA256 of the request body, keyed
+// with the shared secret, optionally prefixed with "sha256=".
+const SignatureHeader = "X-Rollbar-Signature"
+
+const maxBodyBytes = 1 << 20
+
+// Event is one webhook delivery. Item events carry Data.Item and usually
+// Data.Occurrence; deploy events carry Data.Deploy.
+type Event struct {
+	Name string `json:"event_name"`
+	Data Data   `json:"data"`
+	// Raw is the delivery exactly as received.
+	Raw json.RawMessage `json:"-"`
+}
+
+type Data struct {
+	Item       *rollbar.Item   `json:"item,omitempty"`
+	Occurrence json.RawMessage `json:"occurrence,omitempty"`
+	Deploy     *rollbar.Deploy `json:"deploy,omitempty"`
+	// Occurrences is the count that triggered exp_repeat_item.
+	Occurrences uint64 `json:"occurrences,omitempty"`
+	// Trigger is the rate that set off item_velocity.
+	Trigger *Trigger `json:"trigger,omitempty"`
+	URL     string   `json:"url,omitempty"`
+}
+
+// Trigger is an item_velocity rule: Threshold occurrences within WindowSize
+// seconds.
+type Trigger struct {
+	WindowSize            uint64 `json:"window_size"`
+	WindowSizeDescription string `json:"window_size_description,omitempty"`
+	Threshold             uint64 `json:"threshold"`
+}
+
+// Parse decodes a delivery body.
+func Parse(body []byte) (Event, error) {
+	var event Event
+	if err := json.Unmarshal(body, &event); err != nil {
+		return Event{}, fmt.Errorf("decode webhook: %w", err)
+	}
+	if event.Name == "" {
+		return Event{}, errors.New("decode webhook: event_name is missing")
+	}
+	event.Raw = append(json.RawMessage(nil), body...)
+
+	return event, nil
+}
+
+// Sign is the signature Verify expects for body.
+func Sign(secret string, body []byte) string {
+	mac := hmac.New(sha256.New, []byte(secret))
+	_, _ = mac.Write(body)
+
+	return hex.EncodeToString(mac.Sum(nil))
+}
+
+// VerifySignature reports whether signature, the SignatureHeader value, is
+// body signed with secret.
+func VerifySignature(secret string, body []byte, signature string) bool {
+	if secret == "" || signature == "" {
+		return false
+	}
+
+	return hmac.Equal([]byte(strings.TrimPrefix(signature, "sha256=")), []byte(Sign(secret, body)))
+}
+
+// Verify authenticates a delivery by its SignatureHeader or, because
+// Rollbar's own webhook settings cannot sign requests, by a "secret" query
+// parameter in the configured webhook URL. A signature that is present must
+// match; the query parameter is only consulted without one.
+func Verify(secret string, request *http.Request, body []byte) bool {
+	if signature := request.Header.Get(SignatureHeader); signature != "" {
+		return VerifySignature(secret, body, signature)
+	}
+
+	given := request.URL.Query().Get("secret")
+
+	return given != "" && subtle.ConstantTimeCompare([]byte(given), []byte(secret)) == 1
+}
+
+// Handler accepts POSTed deliveries, authenticates them with secret, and
+// passes each one to accept, answering 204 when it takes the event and 503
+// when it is too busy to. Rollbar only learns whether the delivery was
+// accepted, so accept should queue the event without waiting rather than act
+// on it, and failures afterwards are the caller's to report.
+func Handler(secret string, accept func(event Event) bool) http.Handler {
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodPost {
+			w.Header().Set("Allow", http.MethodPost)
+			http.Error(w, "method not allowed", http.StatusMethodNotAllowed)
+			return
+		}
+
+		body, err := io.ReadAll(http.MaxBytesReader(w, r.Body, maxBodyBytes))
+		if err != nil {
+			http.Error(w, "request body too large", http.StatusRequestEntityTooLarge)
+			return
+		}
+		if !Verify(secret, r, body) {
+			http.Error(w, "invalid signature", http.StatusUnauthorized)
+			return
+		}
+
+		event, err := Parse(body)
+		if err != nil {
+			http.Error(w, err.Error(), http.StatusBadRequest)
+			return
+		}
+
+		if !accept(event) {
+			w.Header().Set("Retry-After", "60")
+			http.Error(w, "too many deliveries queued", http.StatusServiceUnavailable)
+			return
+		}
+		w.WriteHeader(http.StatusNoContent)
+		if flusher, ok := w.(http.Flusher); ok {
+			flusher.Flush()
+		}
+	})
+}
@@ -0,0 +1,109 @@
+package webhook
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+)
+
+const newItemPayload = `{"event_name":"new_item","data":{"item":{"id":272505123,"counter":4,"title":"testing","environment":"production","level":40,"status":1,"total_occurrences":1,"project_id":90},"occurrence":{"uuid":"0f1e"}}}`
+
+func TestParse(t *testing.T) {
+	t.Parallel()
+
+	event, err := Parse([]byte(newItemPayload))
+	if err != nil {
+		t.Fatalf("Parse() error = %v", err)
+	}
+	item := event.Data.Item
+	if event.Name != EventNewItem || item == nil || item.Counter != 4 || item.Level != "error" || item.Status != "active" || string(event.Raw) != newItemPayload {
+		t.Fatalf("unexpected event: %+v (item %+v)", event, item)
+	}
+}
+
+func TestParseDeploy(t *testing.T) {
+	t.Parallel()
+
+	deploy, err := Parse([]byte(`{"event_name":"deploy","data":{"deploy":{"id":7,"environment":"production","revision":"abc123"}}}`))
+	if err != nil || deploy.Data.Deploy == nil || deploy.Data.Deploy.Revision != "abc123" || deploy.Data.Item != nil {
+		t.Fatalf("unexpected deploy event: %+v, %v", deploy, err)
+	}
+}
+
+func TestParseErrors(t *testing.T) {
+	t.Parallel()
+
+	for _, body := range []string{`{`, `{"data":{}}`} {
+		if _, err := Parse([]byte(body)); err == nil {
+			t.Fatalf("Parse(%s) expected error", body)
+		}
+	}
+}
+
+func TestVerify(t *testing.T) {
+	t.Parallel()
+
+	body := []byte(newItemPayload)
+	tests := []struct {
+		name      string
+		target    string
+		signature string
+		want      bool
+	}{
+		{name: "signature", target: "/", signature: Sign("s3cret", body), want: true},
+		{name: "prefixed signature", target: "/", signature: "sha256=" + Sign("s3cret", body), want: true},
+		{name: "wrong signature", target: "/?secret=s3cret", signature: Sign("other", body)},
+		{name: "query secret", target: "/?secret=s3cret", want: true},
+		{name: "wrong query secret", target: "/?secret=nope"},
+		{name: "nothing", target: "/"},
+	}
+	for _, tc := range tests {
+		request := httptest.NewRequest(http.MethodPost, tc.target, nil)
+		if tc.signature != "" {
+			request.Header.Set(SignatureHeader, tc.signature)
+		}
+		if got := Verify("s3cret", request, body); got != tc.want {
+			t.Fatalf("%s: Verify() = %v, want %v", tc.name, got, tc.want)
+		}
+	}
+}
+
+func TestHandler(t *testing.T) {
+	t.Parallel()
+
+	var received []string
+	busy := false
+	handler := Handler("s3cret", func(event Event) bool {
+		if busy {
+			return false
+		}
+		received = append(received, event.Name)
+		return true
+	})
+
+	tests := []struct {
+		method string
+		target string
+		body   string
+		busy   bool
+		want   int
+	}{
+		{method: http.MethodPost, target: "/?secret=s3cret", body: newItemPayload, want: http.StatusNoContent},
+		{method: http.MethodPost, target: "/?secret=s3cret", body: newItemPayload, busy: true, want: http.StatusServiceUnavailable},
+		{method: http.MethodPost, target: "/?secret=nope", body: newItemPayload, want: http.StatusUnauthorized},
+		{method: http.MethodPost, target: "/?secret=s3cret", body: `not json`, want: http.StatusBadRequest},
+		{method: http.MethodGet, target: "/?secret=s3cret", want: http.StatusMethodNotAllowed},
+	}
+	for _, tc := range tests {
+		busy = tc.busy
+		recorder := httptest.NewRecorder()
+		handler.ServeHTTP(recorder, httptest.NewRequest(tc.method, tc.target, strings.NewReader(tc.body)))
+		if recorder.Code != tc.want || recorder.Flushed != (tc.want == http.StatusNoContent) {
+			t.Fatalf("%s %s: status = %d (flushed %t), want %d", tc.method, tc.target, recorder.Code, recorder.Flushed, tc.want)
+		}
+	}
+	if len(received) != 1 || received[0] != EventNewItem {
+		t.Fatalf("unexpected dispatches: %v", received)
+	}
+}